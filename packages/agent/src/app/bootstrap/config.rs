@@ -11,9 +11,11 @@ pub struct ServerConfig {
     pub port: u16,
     /// Maximum concurrent WebSocket connections.
     pub max_connections: usize,
-    /// Heartbeat interval in milliseconds.
+    /// Interval between `/engine` WebSocket pings in milliseconds.
     pub heartbeat_interval_ms: u64,
-    /// Heartbeat timeout in milliseconds (close after this many missed pongs).
+    /// Silence budget in milliseconds; a client that misses
+    /// `ceil(timeout / interval)` consecutive pings is closed with close code
+    /// 4000.
     pub heartbeat_timeout_ms: u64,
     /// Max WebSocket message size in bytes (default 150 MB).
     pub max_message_size: usize,
@@ -40,16 +42,15 @@ impl Default for ServerConfig {
 
 impl ServerConfig {
     /// Build runtime server config from CLI-owned bind values and settings-owned
-    /// heartbeat tuning.
+    /// heartbeat tuning. The timeout is raised to one interval when configured
+    /// lower, so a client always gets at least one ping to answer.
     pub fn from_settings(
         host: String,
         port: u16,
         settings: &crate::domains::settings::types::ServerSettings,
     ) -> Self {
         let heartbeat_interval_ms = settings.heartbeat_interval_ms;
-        let heartbeat_timeout_ms = heartbeat_interval_ms
-            .saturating_mul(3)
-            .max(heartbeat_interval_ms);
+        let heartbeat_timeout_ms = settings.heartbeat_timeout_ms.max(heartbeat_interval_ms);
         Self {
             host,
             port,
@@ -58,6 +59,14 @@ impl ServerConfig {
             ..Self::default()
         }
     }
+
+    /// Heartbeat/idle-disconnect policy applied to each `/engine` connection.
+    pub fn heartbeat_policy(&self) -> crate::transport::engine::socket::HeartbeatPolicy {
+        crate::transport::engine::socket::HeartbeatPolicy::new(
+            std::time::Duration::from_millis(self.heartbeat_interval_ms),
+            std::time::Duration::from_millis(self.heartbeat_timeout_ms),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -94,6 +103,35 @@ mod tests {
         assert_eq!(cfg.heartbeat_timeout_ms, 90_000);
     }
 
+    #[test]
+    fn heartbeat_policy_uses_configured_interval_and_timeout() {
+        let cfg = ServerConfig {
+            heartbeat_interval_ms: 10_000,
+            heartbeat_timeout_ms: 25_000,
+            ..ServerConfig::default()
+        };
+        let policy = cfg.heartbeat_policy();
+        assert_eq!(policy.interval, std::time::Duration::from_secs(10));
+        assert_eq!(policy.max_missed_pongs(), 3);
+    }
+
+    #[test]
+    fn from_settings_uses_the_configured_heartbeat_timeout() {
+        let mut settings = crate::domains::settings::types::ServerSettings {
+            heartbeat_interval_ms: 10_000,
+            heartbeat_timeout_ms: 45_000,
+            ..Default::default()
+        };
+        let cfg = ServerConfig::from_settings("127.0.0.1".into(), 0, &settings);
+        assert_eq!(cfg.heartbeat_timeout_ms, 45_000);
+        assert_eq!(cfg.heartbeat_policy().max_missed_pongs(), 5);
+
+        settings.heartbeat_timeout_ms = 1_000;
+        let cfg = ServerConfig::from_settings("127.0.0.1".into(), 0, &settings);
+        assert_eq!(cfg.heartbeat_timeout_ms, 10_000);
+        assert_eq!(cfg.heartbeat_policy().max_missed_pongs(), 1);
+    }
+
     #[test]
     fn default_max_message_size() {
         let cfg = ServerConfig::default();
@@ -168,6 +206,6 @@ mod tests {
         assert_eq!(cfg.host, "127.0.0.1");
         assert_eq!(cfg.port, 9847);
         assert_eq!(cfg.heartbeat_interval_ms, 12_345);
-        assert_eq!(cfg.heartbeat_timeout_ms, 37_035);
+        assert_eq!(cfg.heartbeat_timeout_ms, 90_000);
     }
 }
@@ -16,19 +16,31 @@ pub mod server;
 use crate::app::bootstrap::config::ServerConfig;
 use crate::app::bootstrap::server::TronServer;
 use crate::app::cli::{Cli, run_subcommand};
-use crate::domains::agent::r#loop::{Orchestrator, SessionManager, recover_incomplete_turns};
-use crate::domains::model::responder::{DefaultModelResponderFactory, ModelResponderFactory};
-use crate::domains::session::event_store::{ConnectionConfig, EventStore};
+use crate::domains::agent::r#loop::{
+    Orchestrator, ProfileRuntime, SessionManager, recover_incomplete_turns,
+};
+use crate::domains::model::responder::{
+    DefaultModelResponderFactory, ModelResponderFactory, set_provider_wire_logging,
+};
+use crate::domains::model::tokens::pricing_overrides::set_pricing_overrides;
+use crate::domains::session::event_store::redaction::PayloadKeyRedaction;
+use crate::domains::session::event_store::{
+    CheckpointMode, ConnectionConfig, ConnectionPool, EventStore,
+};
 use crate::domains::settings::db_path_policy::resolve_production_db_path;
 use crate::shared::server::context::{
-    AgentDeps, ServerRuntimeContext, register_blocking_supervisor_shutdown,
+    AgentDeps, ServerRuntimeContext, register_blocking_supervisor_shutdown, run_blocking_task,
 };
+use crate::shared::server::errors::CapabilityError;
 use crate::transport::runtime::streams::EngineStreamEventPump;
 
+/// Cadence of the background `TRUNCATE` WAL checkpoint.
+const WAL_CHECKPOINT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(300);
+
 /// Run either the requested CLI subcommand or the long-running server.
 pub async fn run(args: Cli) -> Result<()> {
     if let Some(ref cmd) = args.command {
-        return run_subcommand(cmd);
+        return run_subcommand(cmd).await;
     }
     run_server(args).await
 }
@@ -119,7 +131,7 @@ pub(crate) fn init_directories() -> Result<crate::shared::foundation::constituti
 pub(crate) fn init_database(
     db_path_override: Option<PathBuf>,
 ) -> Result<(
-    r2d2::Pool<r2d2_sqlite::SqliteConnectionManager>,
+    ConnectionPool,
     PathBuf,
     crate::domains::session::event_store::DatabaseLock,
 )> {
@@ -367,30 +379,38 @@ fn build_server_runtime_context(
     }
 }
 
-/// TTL for idle session cache eviction. Sessions idle beyond this are
-/// dropped from the in-memory cache by the background eviction task.
-const IDLE_SESSION_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(3600);
-
 /// Spawn background maintenance tasks for primitive server state.
 ///
 /// INVARIANT: ordinary startup must not touch macOS TCC permissions. The
 /// Mac onboarding wrapper owns that UX after the install heartbeat, and a
 /// daemon-side startup probe can surface permission prompts while the user is
 /// still on the install step.
-fn spawn_background_tasks(session_manager: &Arc<SessionManager>, server: &TronServer) {
-    // Periodic session cache eviction (prevents unbounded memory growth)
-    let eviction_mgr = session_manager.clone();
+fn spawn_background_tasks(
+    orchestrator: &Arc<Orchestrator>,
+    profile_runtime: &Arc<ProfileRuntime>,
+    pool: &ConnectionPool,
+    server: &TronServer,
+) {
+    // Periodic idle-session sweep. Frees session slots and prevents unbounded
+    // cache growth; the timeout is re-read each tick so settings edits apply
+    // without a restart.
+    let eviction_orchestrator = orchestrator.clone();
+    let eviction_profile = profile_runtime.clone();
     let eviction_shutdown = server.shutdown().token();
-    let cache_ttl = IDLE_SESSION_CACHE_TTL;
     let eviction_task = tokio::spawn(async move {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
         let _ = interval.tick().await; // first tick is immediate, skip it
         loop {
             tokio::select! {
                 _ = interval.tick() => {
-                    let evicted = eviction_mgr.evict_idle_sessions(cache_ttl);
-                    if evicted > 0 {
-                        tracing::debug!(evicted, "session cache eviction sweep");
+                    let idle_timeout = std::time::Duration::from_secs(
+                        eviction_profile.current().settings.session.idle_timeout_secs,
+                    );
+                    let swept = eviction_orchestrator
+                        .sweep_idle_sessions(idle_timeout, std::time::Instant::now())
+                        .await;
+                    if !swept.is_empty() {
+                        tracing::debug!(swept = swept.len(), "idle session sweep");
                     }
                 }
                 () = eviction_shutdown.cancelled() => break,
@@ -398,6 +418,72 @@ fn spawn_background_tasks(session_manager: &Arc<SessionManager>, server: &TronSe
         }
     });
     server.shutdown().register_task(eviction_task);
+
+    // SQLite only auto-checkpoints in PASSIVE mode, which never shrinks the
+    // `-wal` file; truncate it periodically so write-heavy sessions do not
+    // leave a large sidecar behind.
+    let checkpoint_pool = pool.clone();
+    let checkpoint_shutdown = server.shutdown().token();
+    let checkpoint_task = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(WAL_CHECKPOINT_INTERVAL);
+        let _ = interval.tick().await; // first tick is immediate, skip it
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let pool = checkpoint_pool.clone();
+                    let result = run_blocking_task("event_store::wal_checkpoint", move || {
+                        pool.checkpoint(CheckpointMode::Truncate)
+                            .map(|stats| (stats, pool.db_size_bytes()))
+                            .map_err(|error| CapabilityError::Internal {
+                                message: error.to_string(),
+                            })
+                    })
+                    .await;
+                    match result {
+                        Ok((stats, size)) => tracing::debug!(
+                            busy = stats.busy,
+                            checkpointed_frames = stats.checkpointed_frames,
+                            main_bytes = size.main_bytes,
+                            wal_bytes = size.wal_bytes,
+                            "periodic WAL checkpoint"
+                        ),
+                        Err(error) => {
+                            tracing::warn!(error = %error, "periodic WAL checkpoint failed");
+                        }
+                    }
+                }
+                () = checkpoint_shutdown.cancelled() => break,
+            }
+        }
+    });
+    server.shutdown().register_task(checkpoint_task);
+
+    // Provider wire logging and pricing overrides follow settings reloads so
+    // debugging and negotiated rates can change without a restart.
+    let mut settings_changes = crate::domains::settings::profile::subscribe_settings_changes();
+    let settings_reload_shutdown = server.shutdown().token();
+    let settings_reload_task = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                changed = settings_changes.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    let settings = settings_changes.borrow_and_update().clone();
+                    set_provider_wire_logging(
+                        settings
+                            .as_ref()
+                            .is_some_and(|settings| settings.logging.provider_wire_debug),
+                    );
+                    if let Some(settings) = settings {
+                        set_pricing_overrides(&settings.agent.pricing_overrides);
+                    }
+                }
+                () = settings_reload_shutdown.cancelled() => break,
+            }
+        }
+    });
+    server.shutdown().register_task(settings_reload_task);
 }
 
 pub(crate) async fn run_server(args: Cli) -> Result<()> {
@@ -423,6 +509,8 @@ pub(crate) async fn run_server(args: Cli) -> Result<()> {
     let origin = format!("localhost:{}", args.port);
     let (log_handle, flush_task) =
         init_logging(&db_path, &settings, args.log_level.as_deref(), !args.quiet)?;
+    set_provider_wire_logging(settings.logging.provider_wire_debug);
+    set_pricing_overrides(&settings.agent.pricing_overrides);
     if settings.storage.retention_enabled {
         match crate::shared::storage::StorageRuntime::new(db_path.clone())
             .retention_run(false, settings.observability.verbose_retention_days)
@@ -465,15 +553,17 @@ pub(crate) async fn run_server(args: Cli) -> Result<()> {
             ),
         }
     }
-    let event_store = Arc::new(EventStore::new(pool));
+    let checkpoint_pool = pool.clone();
+    let event_store = Arc::new(EventStore::new(pool).with_payload_key_redaction(
+        PayloadKeyRedaction::new(&settings.storage.redacted_payload_keys),
+    ));
     let engine_host = init_engine_host(&db_path)?;
 
     // Phase 3: Core services (orchestrator, providers, primitive agent deps)
     let services = init_services(event_store, &settings).await?;
 
     // Phase 4: Runtime context
-    let session_manager_for_startup = services.session_manager.clone();
-    let orchestrator_for_stream_events = services.orchestrator.clone();
+    let orchestrator_for_startup = services.orchestrator.clone();
     let profile_runtime_for_watcher = profile_runtime.clone();
     let runtime_context = build_server_runtime_context(
         services,
@@ -499,16 +589,21 @@ pub(crate) async fn run_server(args: Cli) -> Result<()> {
 
     // Stream pump: orchestrator events -> engine streams.
     let pump = EngineStreamEventPump::new(
-        orchestrator_for_stream_events.subscribe(),
+        orchestrator_for_startup.subscribe(),
         server.runtime_context().engine_host.clone(),
         server.shutdown().token(),
-        orchestrator_for_stream_events.turn_accumulators().clone(),
+        orchestrator_for_startup.turn_accumulators().clone(),
     );
     let stream_event_pump_handle = tokio::spawn(pump.run());
     crate::transport::runtime::EngineRuntimeServices::start(&server);
 
     // Phase 6: Background tasks and bind
-    spawn_background_tasks(&session_manager_for_startup, &server);
+    spawn_background_tasks(
+        &orchestrator_for_startup,
+        &profile_runtime_for_watcher,
+        &checkpoint_pool,
+        &server,
+    );
     server
         .shutdown()
         .register_task(profile_runtime_for_watcher.spawn_watcher(server.shutdown().token()));
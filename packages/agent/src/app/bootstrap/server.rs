@@ -1,4 +1,8 @@
 //! `TronServer` — Axum HTTP + WebSocket server.
+//!
+//! Liveness (`/health`, `/health/deep`) and `/metrics` routes live in
+//! `liveness` and `metrics_route`; this module owns the router, middleware,
+//! engine WebSocket upgrades, and the listener.
 
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -12,7 +16,7 @@ use axum::extract::State;
 use axum::extract::ws::WebSocketUpgrade;
 use axum::http::{HeaderMap, StatusCode};
 use axum::middleware::{self, Next};
-use axum::response::{IntoResponse, Json, Response};
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use tokio::net::TcpListener;
 use tower_http::catch_panic::CatchPanicLayer;
@@ -28,7 +32,6 @@ use tracing::{info, instrument};
 use metrics_exporter_prometheus::PrometheusHandle;
 
 use crate::app::bootstrap::config::ServerConfig;
-use crate::app::health::{self, HealthResponse};
 use crate::app::lifecycle::shutdown::ShutdownCoordinator;
 use crate::transport::engine::socket::{EngineClientRegistry, run_engine_ws_session};
 use crate::transport::http::auth::{BearerTokenStore, verify_bearer_header};
@@ -36,6 +39,12 @@ use crate::transport::runtime::external_workers::{
     SharedExternalWorkerRuntime, run_external_worker_socket,
 };
 
+mod liveness;
+mod metrics_route;
+
+use liveness::{deep_health_handler, health_handler};
+use metrics_route::metrics_handler;
+
 /// Generates `UUIDv7` request IDs.
 #[derive(Clone)]
 struct UuidV7RequestId;
@@ -217,10 +226,11 @@ async fn engine_upgrade_handler(
     let ctx = state.runtime_context;
     let clients = state.engine_clients;
     let max_message_size = state.config.max_message_size;
+    let heartbeat = state.config.heartbeat_policy();
     Ok(ws
         .max_message_size(max_message_size)
         .on_upgrade(move |socket| async move {
-            run_engine_ws_session(socket, client_id, ctx, clients).await;
+            run_engine_ws_session(socket, client_id, ctx, clients, heartbeat).await;
         }))
 }
 
@@ -245,54 +255,6 @@ fn ensure_worker_peer_is_loopback(addr: SocketAddr) -> Result<(), StatusCode> {
     Ok(())
 }
 
-/// GET /health
-async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
-    let connections = state.engine_clients.connection_count();
-    let sessions = state.runtime_context.orchestrator.active_session_count();
-    let resp = health::health_check(state.start_time, connections, sessions);
-    Json(resp)
-}
-
-/// GET /health/deep — Deep health check with per-subsystem results.
-async fn deep_health_handler(State(state): State<AppState>) -> Json<health::DeepHealthResponse> {
-    let connections = state.engine_clients.connection_count();
-    let sessions = state.runtime_context.orchestrator.active_session_count();
-    let event_store = state.runtime_context.event_store.clone();
-    let tron_home = crate::domains::settings::profile::tron_home_dir();
-    let response = state
-        .runtime_context
-        .run_blocking("http.health.deep", move || {
-            Ok(health::deep_health_check(
-                state.start_time,
-                connections,
-                sessions,
-                &event_store,
-                &tron_home,
-            ))
-        })
-        .await;
-
-    match response {
-        Ok(resp) => Json(resp),
-        Err(error) => Json(health::DeepHealthResponse {
-            status: "unhealthy".into(),
-            uptime_secs: state.start_time.elapsed().as_secs(),
-            connections,
-            active_sessions: sessions,
-            checks: vec![health::DeepHealthCheck {
-                name: "deepHealth".into(),
-                status: "fail".into(),
-                detail: Some(serde_json::json!({ "error": error.to_string() })),
-            }],
-        }),
-    }
-}
-
-/// GET /metrics — Prometheus text format.
-async fn metrics_handler(State(state): State<AppState>) -> String {
-    state.metrics_handle.render()
-}
-
 async fn ws_auth_gate(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -311,13 +273,13 @@ mod tests {
     use axum::http::{Request, StatusCode};
     use tower::ServiceExt;
 
-    fn make_metrics_handle() -> PrometheusHandle {
+    pub(super) fn make_metrics_handle() -> PrometheusHandle {
         metrics_exporter_prometheus::PrometheusBuilder::new()
             .build_recorder()
             .handle()
     }
 
-    fn make_server() -> TronServer {
+    pub(super) fn make_server() -> TronServer {
         let ctx = make_test_context();
         TronServer::new(ServerConfig::default(), ctx, make_metrics_handle())
     }
@@ -383,27 +345,6 @@ mod tests {
         assert!(ctx.orchestrator.can_accept_session());
     }
 
-    #[tokio::test]
-    async fn health_endpoint_returns_ok() {
-        let server = make_server();
-        let app = server.router();
-
-        let req = Request::builder()
-            .uri("/health")
-            .body(Body::empty())
-            .unwrap();
-
-        let resp = app.oneshot(req).await.unwrap();
-        assert_eq!(resp.status(), StatusCode::OK);
-
-        let body = axum::body::to_bytes(resp.into_body(), 10_000)
-            .await
-            .unwrap();
-        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
-        assert_eq!(parsed["status"], "ok");
-        assert!(parsed["connections"].is_number());
-    }
-
     #[tokio::test]
     async fn engine_endpoint_requires_upgrade() {
         let (server, _dir, token) = make_server_with_auth();
@@ -562,53 +503,6 @@ mod tests {
         assert_eq!(server.config().max_connections, 10);
     }
 
-    #[tokio::test]
-    async fn health_response_has_expected_fields() {
-        let server = make_server();
-        let app = server.router();
-
-        let req = Request::builder()
-            .uri("/health")
-            .body(Body::empty())
-            .unwrap();
-
-        let resp = app.oneshot(req).await.unwrap();
-        let body = axum::body::to_bytes(resp.into_body(), 10_000)
-            .await
-            .unwrap();
-        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
-
-        assert!(parsed.get("status").is_some());
-        assert!(parsed.get("uptime_secs").is_some());
-        assert!(parsed.get("connections").is_some());
-        assert_eq!(parsed["active_sessions"], 0);
-    }
-
-    #[tokio::test]
-    async fn health_reports_active_sessions_from_orchestrator() {
-        let ctx = make_test_context();
-        // Create a session so orchestrator reports 1
-        assert!(
-            ctx.session_manager
-                .create_session("claude-opus-4-6", "/tmp", None)
-                .is_ok()
-        );
-        let server = TronServer::new(ServerConfig::default(), ctx, make_metrics_handle());
-        let app = server.router();
-
-        let req = Request::builder()
-            .uri("/health")
-            .body(Body::empty())
-            .unwrap();
-
-        let resp = app.oneshot(req).await.unwrap();
-        let body = axum::body::to_bytes(resp.into_body(), 10_000)
-            .await
-            .unwrap();
-        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
-        assert_eq!(parsed["active_sessions"], 1);
-    }
-
     #[tokio::test]
     async fn shutdown_propagates_to_coordinator() {
         let server = make_server();
@@ -670,26 +564,4 @@ mod tests {
         server.shutdown().shutdown();
         let _ = handle.await;
     }
-
-    #[tokio::test]
-    async fn deep_health_endpoint_returns_200() {
-        let server = make_server();
-        let app = server.router();
-
-        let req = Request::builder()
-            .uri("/health/deep")
-            .body(Body::empty())
-            .unwrap();
-
-        let resp = app.oneshot(req).await.unwrap();
-        assert_eq!(resp.status(), StatusCode::OK);
-
-        let body = axum::body::to_bytes(resp.into_body(), 10_000)
-            .await
-            .unwrap();
-        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
-        assert!(["healthy", "degraded", "unhealthy"].contains(&parsed["status"].as_str().unwrap()));
-        assert!(parsed["checks"].is_array());
-        assert!(parsed["uptimeSecs"].is_number());
-    }
 }
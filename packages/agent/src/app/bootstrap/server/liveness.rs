@@ -0,0 +1,154 @@
+//! Liveness routes: `/health` and the per-subsystem `/health/deep` check.
+
+use axum::extract::State;
+use axum::response::Json;
+
+use super::AppState;
+use crate::app::health::{self, HealthResponse};
+
+/// GET /health
+pub(super) async fn health_handler(State(state): State<AppState>) -> Json<HealthResponse> {
+    let connections = state.engine_clients.connection_count();
+    let sessions = state.runtime_context.orchestrator.active_session_count();
+    let resp = health::health_check(state.start_time, connections, sessions);
+    Json(resp)
+}
+
+/// GET /health/deep — Deep health check with per-subsystem results.
+pub(super) async fn deep_health_handler(
+    State(state): State<AppState>,
+) -> Json<health::DeepHealthResponse> {
+    let connections = state.engine_clients.connection_count();
+    let sessions = state.runtime_context.orchestrator.active_session_count();
+    let event_store = state.runtime_context.event_store.clone();
+    let tron_home = crate::domains::settings::profile::tron_home_dir();
+    let response = state
+        .runtime_context
+        .run_blocking("http.health.deep", move || {
+            Ok(health::deep_health_check(
+                state.start_time,
+                connections,
+                sessions,
+                &event_store,
+                &tron_home,
+            ))
+        })
+        .await;
+
+    match response {
+        Ok(resp) => Json(resp),
+        Err(error) => Json(health::DeepHealthResponse {
+            status: "unhealthy".into(),
+            uptime_secs: state.start_time.elapsed().as_secs(),
+            connections,
+            active_sessions: sessions,
+            checks: vec![health::DeepHealthCheck {
+                name: "deepHealth".into(),
+                status: "fail".into(),
+                detail: Some(serde_json::json!({ "error": error.to_string() })),
+            }],
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    use super::super::TronServer;
+    use super::super::tests::{make_metrics_handle, make_server};
+    use crate::app::bootstrap::config::ServerConfig;
+    use crate::shared::server::test_support::make_test_context;
+
+    #[tokio::test]
+    async fn health_endpoint_returns_ok() {
+        let server = make_server();
+        let app = server.router();
+
+        let req = Request::builder()
+            .uri("/health")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), 10_000)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed["status"], "ok");
+        assert!(parsed["connections"].is_number());
+    }
+
+    #[tokio::test]
+    async fn health_response_has_expected_fields() {
+        let server = make_server();
+        let app = server.router();
+
+        let req = Request::builder()
+            .uri("/health")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 10_000)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert!(parsed.get("status").is_some());
+        assert!(parsed.get("uptime_secs").is_some());
+        assert!(parsed.get("connections").is_some());
+        assert_eq!(parsed["active_sessions"], 0);
+    }
+
+    #[tokio::test]
+    async fn health_reports_active_sessions_from_orchestrator() {
+        let ctx = make_test_context();
+        // Create a session so orchestrator reports 1
+        assert!(
+            ctx.session_manager
+                .create_session("claude-opus-4-6", "/tmp", None)
+                .is_ok()
+        );
+        let server = TronServer::new(ServerConfig::default(), ctx, make_metrics_handle());
+        let app = server.router();
+
+        let req = Request::builder()
+            .uri("/health")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), 10_000)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed["active_sessions"], 1);
+    }
+
+    #[tokio::test]
+    async fn deep_health_endpoint_returns_200() {
+        let server = make_server();
+        let app = server.router();
+
+        let req = Request::builder()
+            .uri("/health/deep")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(resp.into_body(), 10_000)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(["healthy", "degraded", "unhealthy"].contains(&parsed["status"].as_str().unwrap()));
+        assert!(parsed["checks"].is_array());
+        assert!(parsed["uptimeSecs"].is_number());
+    }
+}
@@ -0,0 +1,89 @@
+//! `/metrics` route rendering the Prometheus recorder in text format.
+
+use axum::extract::State;
+use axum::response::IntoResponse;
+
+use super::AppState;
+use crate::app::health;
+
+/// GET /metrics — Prometheus text format.
+pub(super) async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            health::metrics::PROMETHEUS_CONTENT_TYPE,
+        )],
+        state.metrics_handle.render(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    use super::super::TronServer;
+    use crate::app::bootstrap::config::ServerConfig;
+    use crate::shared::server::test_support::make_test_context;
+
+    #[tokio::test]
+    async fn metrics_endpoint_renders_prometheus_text() {
+        use crate::app::health::metrics as m;
+
+        let recorder = m::prometheus_builder().build_recorder();
+        let server = TronServer::new(
+            ServerConfig::default(),
+            make_test_context(),
+            recorder.handle(),
+        );
+        metrics::with_local_recorder(&recorder, || {
+            metrics::counter!(m::ENGINE_REQUESTS_TOTAL, "type" => "invoke").increment(3);
+            metrics::gauge!(m::SESSIONS_ACTIVE).set(2.0);
+            metrics::histogram!(m::CAPABILITY_INVOCATION_DURATION_SECONDS, "capability" => "fs::read")
+                .record(0.04);
+        });
+
+        let req = Request::builder()
+            .uri("/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let resp = server.router().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()[axum::http::header::CONTENT_TYPE],
+            m::PROMETHEUS_CONTENT_TYPE
+        );
+        let body = axum::body::to_bytes(resp.into_body(), 100_000)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            if line.starts_with('#') {
+                assert!(
+                    line.starts_with("# TYPE ") || line.starts_with("# HELP "),
+                    "{line}"
+                );
+                continue;
+            }
+            let (series, value) = line.rsplit_once(' ').expect("sample has a value");
+            assert!(value.parse::<f64>().is_ok(), "bad sample value: {line}");
+            let name = series.split('{').next().unwrap();
+            assert!(
+                name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+                "bad metric name: {line}"
+            );
+        }
+        assert!(text.contains("engine_requests_total{type=\"invoke\"} 3"));
+        assert!(text.contains("sessions_active 2"));
+        assert!(text.contains(
+            "capability_invocation_duration_seconds_bucket{capability=\"fs::read\",le=\"0.05\"} 1"
+        ));
+        assert!(
+            text.contains(
+                "capability_invocation_duration_seconds_count{capability=\"fs::read\"} 1"
+            )
+        );
+    }
+}
@@ -178,3 +178,24 @@ fn run_subcommand_auth_rotate_invalidates_prior_token() {
         "post-rotation reads must observe the rotated token, not the original"
     );
 }
+
+#[test]
+fn cli_parses_replay_subcommand() {
+    let cli = Cli::parse_from([
+        "tron",
+        "replay",
+        "transcript.json",
+        "--session-id",
+        "sess_repro",
+    ]);
+    match cli.command {
+        Some(Command::Replay {
+            transcript,
+            session_id,
+        }) => {
+            assert_eq!(transcript, std::path::PathBuf::from("transcript.json"));
+            assert_eq!(session_id.as_deref(), Some("sess_repro"));
+        }
+        other => panic!("expected Some(Replay {{ .. }}), got {other:?}"),
+    }
+}
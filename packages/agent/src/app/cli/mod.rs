@@ -56,6 +56,21 @@ pub(crate) enum Command {
         #[command(subcommand)]
         action: AuthAction,
     },
+
+    /// Rerun a recorded provider transcript through the agent turn loop.
+    ///
+    /// Prints every emitted event as one JSON line on stdout and the run
+    /// summary on stderr. Capabilities are dispatched against an empty
+    /// in-memory engine host, so recorded calls never repeat side effects.
+    Replay {
+        /// Path to the recorded transcript JSON.
+        transcript: PathBuf,
+
+        /// Session id the replayed events are attributed to (defaults to
+        /// `replay-<transcript file stem>-<fresh session id>`).
+        #[arg(long)]
+        session_id: Option<String>,
+    },
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -78,11 +93,15 @@ pub(crate) enum AuthAction {
 /// human-readable result on stdout (the user is at a terminal) and a
 /// single-line summary on stderr (so `--quiet` redirection still leaves
 /// the audit trail visible).
-pub(crate) fn run_subcommand(cmd: &Command) -> Result<()> {
+pub(crate) async fn run_subcommand(cmd: &Command) -> Result<()> {
     match cmd {
         Command::Auth { action } => match action {
             AuthAction::Rotate => rotate_bearer_token_cli(),
         },
+        Command::Replay {
+            transcript,
+            session_id,
+        } => replay_transcript_cli(transcript, session_id.as_deref()).await,
     }
 }
 
@@ -94,3 +113,42 @@ fn rotate_bearer_token_cli() -> Result<()> {
     println!("{token}");
     Ok(())
 }
+
+async fn replay_transcript_cli(
+    transcript: &std::path::Path,
+    session_id: Option<&str>,
+) -> Result<()> {
+    let session_id = session_id.map_or_else(
+        || {
+            let stem = transcript
+                .file_stem()
+                .map_or_else(|| "transcript".into(), |stem| stem.to_string_lossy());
+            format!(
+                "replay-{stem}-{}",
+                crate::shared::foundation::ids::SessionId::new()
+            )
+        },
+        ToOwned::to_owned,
+    );
+    let replay = crate::domains::agent::r#loop::orchestrator::agent_replay::run_agent_replay(
+        &session_id,
+        transcript,
+    )
+    .await
+    .with_context(|| format!("Failed to replay transcript {}", transcript.display()))?;
+    for event in &replay.events {
+        println!("{}", serde_json::to_string(event)?);
+    }
+    eprintln!(
+        "Replayed {} as {session_id}: {} turns, stop reason {:?}{}",
+        transcript.display(),
+        replay.result.turns_executed,
+        replay.result.stop_reason,
+        replay
+            .result
+            .error
+            .as_deref()
+            .map_or_else(String::new, |error| format!(", error: {error}")),
+    );
+    Ok(())
+}
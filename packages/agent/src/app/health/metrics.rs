@@ -1,15 +1,35 @@
 //! Prometheus metrics recorder and `/metrics` endpoint handler.
+//!
+//! Histograms are exported as Prometheus histograms (`_bucket`/`_sum`/`_count`)
+//! over [`DURATION_BUCKETS_SECONDS`] so scrapers can aggregate them across
+//! instances and compute quantiles with `histogram_quantile`.
 
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
 use tracing::info;
 
+/// Prometheus exposition content type served by `/metrics`.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Histogram bucket upper bounds (seconds) shared by every duration metric.
+///
+/// Spans sub-10ms capability calls through multi-minute agent turns.
+pub const DURATION_BUCKETS_SECONDS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0,
+];
+
+/// Prometheus builder configured with Tron's histogram buckets.
+pub fn prometheus_builder() -> PrometheusBuilder {
+    PrometheusBuilder::new()
+        .set_buckets(DURATION_BUCKETS_SECONDS)
+        .expect("duration buckets are non-empty")
+}
+
 /// Install the Prometheus metrics recorder (global).
 ///
 /// Returns the `PrometheusHandle` used to render the `/metrics` endpoint.
 /// Must be called once at server startup before any metrics are recorded.
 pub fn install_recorder() -> PrometheusHandle {
-    let builder = PrometheusBuilder::new();
-    let handle = builder
+    let handle = prometheus_builder()
         .install_recorder()
         .expect("failed to install metrics recorder");
     info!("prometheus metrics recorder installed");
@@ -37,6 +57,8 @@ pub const ENGINE_WS_DISCONNECTIONS_TOTAL: &str = "engine_ws_disconnections_total
 pub const ENGINE_WS_CONNECTIONS_ACTIVE: &str = "engine_ws_connections_active";
 /// Engine client stream delivery drops total (counter).
 pub const ENGINE_WS_STREAM_DROPS_TOTAL: &str = "engine_ws_stream_drops_total";
+/// Engine client WebSocket connections closed for missed heartbeats (counter).
+pub const ENGINE_WS_HEARTBEAT_TIMEOUTS_TOTAL: &str = "engine_ws_heartbeat_timeouts_total";
 /// Active agent runs (gauge).
 pub const AGENT_RUNS_ACTIVE: &str = "agent_runs_active";
 /// Agent turns total (counter, labels: model).
@@ -89,6 +111,22 @@ mod tests {
         assert!(output.is_empty() || output.contains('#') || output.contains('\n'));
     }
 
+    #[test]
+    fn histograms_render_buckets_sum_and_count() {
+        let recorder = prometheus_builder().build_recorder();
+        let handle = recorder.handle();
+        metrics::with_local_recorder(&recorder, || {
+            metrics::histogram!(COMPACTION_DURATION_SECONDS).record(0.2);
+        });
+
+        let output = handle.render();
+        assert!(output.contains("# TYPE compaction_duration_seconds histogram"));
+        assert!(output.contains("compaction_duration_seconds_bucket{le=\"0.25\"} 1"));
+        assert!(output.contains("compaction_duration_seconds_bucket{le=\"0.1\"} 0"));
+        assert!(output.contains("compaction_duration_seconds_sum 0.2"));
+        assert!(output.contains("compaction_duration_seconds_count 1"));
+    }
+
     #[test]
     fn metric_constants_are_snake_case() {
         let names = [
@@ -99,6 +137,7 @@ mod tests {
             ENGINE_WS_DISCONNECTIONS_TOTAL,
             ENGINE_WS_CONNECTIONS_ACTIVE,
             ENGINE_WS_STREAM_DROPS_TOTAL,
+            ENGINE_WS_HEARTBEAT_TIMEOUTS_TOTAL,
             AGENT_RUNS_ACTIVE,
             AGENT_TURNS_TOTAL,
             AGENT_TURN_DURATION_SECONDS,
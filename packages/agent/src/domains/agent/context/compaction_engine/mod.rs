@@ -27,9 +27,11 @@ use crate::shared::protocol::content::AssistantContent;
 use crate::shared::protocol::messages::{Message, UserMessageContent};
 use tracing::{instrument, trace};
 
-use super::constants::{COMPACTION_ACK_TEXT, COMPACTION_SUMMARY_PREFIX};
+use super::constants::{
+    CHARS_PER_TOKEN, COMPACTION_ACK_TEXT, COMPACTION_SUMMARY_PREFIX, ESTIMATED_SUMMARY_TOKENS,
+};
 use super::summarizer::Summarizer;
-use super::types::{CompactionPreview, CompactionResult, ExtractedData};
+use super::types::{CompactionEstimate, CompactionPreview, CompactionResult, ExtractedData};
 
 // =============================================================================
 // Dependencies trait
@@ -239,6 +241,41 @@ impl<D: CompactionDeps> CompactionEngine<D> {
         })
     }
 
+    /// Estimate what compacting `messages` would save, without summarizing.
+    ///
+    /// Pure and synchronous: it never calls the summarizer, never touches
+    /// context state, and emits no events. The post-compaction size assumes
+    /// a summary of at most [`ESTIMATED_SUMMARY_TOKENS`], capped at the size
+    /// of the summarized window itself.
+    #[must_use]
+    pub fn estimate(&self, messages: &[Message]) -> CompactionEstimate {
+        let split = self.compute_split_point(messages);
+        let to_summarize = &messages[..split];
+        let preserved = &messages[split..];
+
+        let token_sum = |msgs: &[Message]| -> u64 {
+            msgs.iter().map(|m| self.deps.get_message_tokens(m)).sum()
+        };
+        let tokens_before = token_sum(messages);
+        let estimated_tokens_after = if to_summarize.is_empty() {
+            tokens_before
+        } else {
+            let summary_tokens = token_sum(to_summarize).min(ESTIMATED_SUMMARY_TOKENS);
+            self.estimate_tokens_with_summary_tokens(summary_tokens, preserved)
+        };
+
+        CompactionEstimate {
+            tokens_before,
+            estimated_tokens_after,
+            tokens_saved: tokens_before.saturating_sub(estimated_tokens_after),
+            preserved_messages: preserved.len(),
+            summarized_messages: to_summarize.len(),
+            preserved_turns: Self::count_real_turns(preserved),
+            summarized_turns: Self::count_real_turns(to_summarize),
+            split_index: split,
+        }
+    }
+
     /// Execute compaction and update messages.
     #[instrument(skip_all, fields(edited = edited_summary.is_some()))]
     pub async fn execute(
@@ -393,7 +430,16 @@ impl<D: CompactionDeps> CompactionEngine<D> {
         preserved_messages: &[Message],
     ) -> u64 {
         #[allow(clippy::cast_possible_truncation)]
-        let summary_tokens = summary.len().div_ceil(4) as u64;
+        let summary_tokens = summary.len().div_ceil(CHARS_PER_TOKEN as usize) as u64;
+        self.estimate_tokens_with_summary_tokens(summary_tokens, preserved_messages)
+    }
+
+    /// Estimate tokens after compaction for a summary of known token size.
+    fn estimate_tokens_with_summary_tokens(
+        &self,
+        summary_tokens: u64,
+        preserved_messages: &[Message],
+    ) -> u64 {
         let context_message_tokens: u64 = 50; // Overhead for context wrapper
         let ack_message_tokens: u64 = 50; // Assistant acknowledgment
 
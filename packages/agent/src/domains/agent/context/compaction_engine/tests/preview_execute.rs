@@ -237,3 +237,75 @@ async fn execute_returns_compression_ratio() {
 // ========================================================================
 // message_only_tokens
 // ========================================================================
+
+// ========================================================================
+// estimate
+// ========================================================================
+
+#[test]
+fn estimate_preserves_recent_turns_and_reports_savings() {
+    // 6 messages, 3 turns, 100 tokens each.
+    let deps = MockDeps::new(vec![]);
+    let engine = CompactionEngine::new(0.70, 2, deps);
+
+    let estimate = engine.estimate(&default_messages());
+
+    assert_eq!(estimate.split_index, 2);
+    assert_eq!(estimate.summarized_messages, 2);
+    assert_eq!(estimate.preserved_messages, 4);
+    assert_eq!(estimate.summarized_turns, 1);
+    assert_eq!(estimate.preserved_turns, 2);
+    assert_eq!(estimate.tokens_before, 600);
+    // Summary capped at the 200-token window, plus 100 wrapper tokens and 400 preserved.
+    assert_eq!(estimate.estimated_tokens_after, 700);
+    assert_eq!(estimate.tokens_saved, 0);
+}
+
+#[test]
+fn estimate_caps_summary_at_estimated_summary_tokens() {
+    let messages: Vec<Message> = (0..10)
+        .flat_map(|i| {
+            [
+                Message::user(format!("question {i}")),
+                Message::assistant("answer"),
+            ]
+        })
+        .collect();
+    let deps = MockDeps::new(vec![]).with_token_fn(|_| 1_000);
+    let engine = CompactionEngine::new(0.70, 2, deps);
+
+    let estimate = engine.estimate(&messages);
+
+    assert_eq!(estimate.summarized_messages, 16);
+    assert_eq!(estimate.preserved_turns, 2);
+    assert_eq!(estimate.tokens_before, 20_000);
+    assert_eq!(
+        estimate.estimated_tokens_after,
+        ESTIMATED_SUMMARY_TOKENS + 100 + 4_000
+    );
+    assert_eq!(estimate.tokens_saved, 20_000 - 6_100);
+}
+
+#[test]
+fn estimate_nothing_to_summarize_is_unchanged() {
+    let deps = MockDeps::new(vec![]);
+    let engine = CompactionEngine::new(0.70, 5, deps);
+
+    let estimate = engine.estimate(&default_messages());
+
+    assert_eq!(estimate.split_index, 0);
+    assert_eq!(estimate.summarized_messages, 0);
+    assert_eq!(estimate.estimated_tokens_after, estimate.tokens_before);
+    assert_eq!(estimate.tokens_saved, 0);
+}
+
+#[test]
+fn estimate_does_not_mutate_state() {
+    let deps = MockDeps::new(default_messages());
+    let engine = CompactionEngine::new(0.70, 1, deps);
+
+    let messages = engine.deps.get_messages();
+    let _ = engine.estimate(&messages);
+
+    assert_eq!(engine.deps.get_messages().len(), 6);
+}
@@ -1,6 +1,7 @@
 //! Smart compaction trigger.
 //!
-//! Determines when context compaction should happen based on token pressure.
+//! Determines when context compaction should happen based on token pressure
+//! and single-turn capability output spikes.
 
 use super::types::CompactionTriggerConfig;
 use super::types::CompactionTriggerInput;
@@ -18,23 +19,43 @@ pub struct CompactionTriggerResult {
 /// Token-pressure compaction trigger.
 ///
 /// Evaluates whether compaction should run after each agent turn.
-/// The primitive loop compacts only when the token ratio exceeds
-/// `trigger_token_threshold`; task-specific progress signals are agent-owned
-/// state, not host policy.
+/// The primitive loop compacts when the token ratio exceeds
+/// `trigger_token_threshold`, or when one turn's capability output exceeds
+/// `large_capability_output_tokens` before the ratio catches up.
+/// Task-specific progress signals are agent-owned state, not host policy.
+///
+/// Compaction preserves the latest turn, so capability output that already
+/// triggered a compaction is still counted afterwards. The trigger keeps a
+/// watermark of the output a compaction has covered and only measures the
+/// spike above it.
 #[derive(Debug)]
 pub struct CompactionTrigger {
     config: CompactionTriggerConfig,
+    /// Capability output tokens seen on the latest evaluation.
+    last_capability_output_tokens: u64,
+    /// Capability output tokens already covered by the last compaction.
+    spike_watermark: u64,
 }
 
 impl CompactionTrigger {
     /// Create a new trigger with the given configuration.
     #[must_use]
     pub fn new(config: CompactionTriggerConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            last_capability_output_tokens: 0,
+            spike_watermark: 0,
+        }
     }
 
     /// Evaluate whether compaction should run.
     pub fn should_compact(&mut self, input: &CompactionTriggerInput) -> CompactionTriggerResult {
+        // Output below the watermark means a new user turn started.
+        if input.last_turn_capability_output_tokens < self.spike_watermark {
+            self.spike_watermark = 0;
+        }
+        self.last_capability_output_tokens = input.last_turn_capability_output_tokens;
+
         // 1. Token threshold — primary trigger
         if input.current_token_ratio >= self.config.trigger_token_threshold {
             #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
@@ -45,15 +66,31 @@ impl CompactionTrigger {
             };
         }
 
+        // 2. Capability output spike — one oversized turn since the last
+        //    compaction
+        let spike_threshold = self.config.large_capability_output_tokens;
+        let uncompacted_output = input
+            .last_turn_capability_output_tokens
+            .saturating_sub(self.spike_watermark);
+        if spike_threshold > 0 && uncompacted_output >= spike_threshold {
+            return CompactionTriggerResult {
+                compact: true,
+                reason: format!(
+                    "capability output {uncompacted_output} tokens >= {spike_threshold} in one turn"
+                ),
+            };
+        }
+
         CompactionTriggerResult {
             compact: false,
             reason: "no trigger".to_string(),
         }
     }
 
-    /// Reset the trigger state (called after compaction completes).
+    /// Record that compaction covered the capability output seen so far
+    /// (called after compaction completes).
     pub fn reset(&mut self) {
-        // No mutable state to reset — trigger is purely functional.
+        self.spike_watermark = self.last_capability_output_tokens;
     }
 }
 
@@ -66,6 +103,14 @@ mod tests {
             current_token_ratio: ratio,
             recent_event_types: Vec::new(),
             recent_capability_invocations: Vec::new(),
+            last_turn_capability_output_tokens: 0,
+        }
+    }
+
+    fn spike_input(ratio: f64, output_tokens: u64) -> CompactionTriggerInput {
+        CompactionTriggerInput {
+            last_turn_capability_output_tokens: output_tokens,
+            ..default_input(ratio)
         }
     }
 
@@ -109,4 +154,60 @@ mod tests {
         let result = trigger.should_compact(&default_input(0.3));
         assert!(!result.compact);
     }
+
+    #[test]
+    fn test_capability_output_spike_triggers_below_ratio() {
+        let mut trigger = CompactionTrigger::new(CompactionTriggerConfig::default());
+        let result = trigger.should_compact(&spike_input(0.2, 50_000));
+        assert!(result.compact);
+        assert!(result.reason.contains("capability output 50000 tokens"));
+    }
+
+    #[test]
+    fn test_capability_output_below_spike_threshold_no_trigger() {
+        let mut trigger = CompactionTrigger::new(CompactionTriggerConfig::default());
+        let result = trigger.should_compact(&spike_input(0.2, 39_999));
+        assert!(!result.compact);
+    }
+
+    #[test]
+    fn test_capability_output_spike_disabled_by_zero_threshold() {
+        let mut trigger = CompactionTrigger::new(CompactionTriggerConfig {
+            large_capability_output_tokens: 0,
+            ..CompactionTriggerConfig::default()
+        });
+        let result = trigger.should_compact(&spike_input(0.2, 1_000_000));
+        assert!(!result.compact);
+    }
+
+    #[test]
+    fn test_token_ratio_reason_wins_over_spike() {
+        let mut trigger = CompactionTrigger::new(CompactionTriggerConfig::default());
+        let result = trigger.should_compact(&spike_input(0.9, 50_000));
+        assert!(result.compact);
+        assert!(result.reason.contains("token ratio"));
+    }
+
+    #[test]
+    fn test_capability_output_spike_fires_once_until_new_output_accumulates() {
+        let mut trigger = CompactionTrigger::new(CompactionTriggerConfig::default());
+        let mut compactions = 0;
+        // One 50k result, then inner turns that keep it (compaction preserves
+        // the latest turn) and add small results.
+        for output in [50_000, 50_000, 51_000, 52_000] {
+            if trigger.should_compact(&spike_input(0.2, output)).compact {
+                compactions += 1;
+                trigger.reset();
+            }
+        }
+        assert_eq!(compactions, 1);
+
+        // Another 40k of fresh output in the same turn is a new spike.
+        assert!(trigger.should_compact(&spike_input(0.2, 92_000)).compact);
+        trigger.reset();
+
+        // A new user turn drops the count and clears the watermark.
+        assert!(!trigger.should_compact(&spike_input(0.2, 0)).compact);
+        assert!(trigger.should_compact(&spike_input(0.2, 45_000)).compact);
+    }
 }
@@ -36,6 +36,12 @@ pub const COMPACTION_SUMMARY_PREFIX: &str = "[Context from earlier in this conve
 pub const COMPACTION_ACK_TEXT: &str =
     "I understand the previous context. Let me continue helping you.";
 
+/// Assumed summary size when estimating compaction savings without a summarizer.
+pub const ESTIMATED_SUMMARY_TOKENS: u64 = 2_000;
+
+/// Capability output in a single turn that forces a compaction recommendation.
+pub const LARGE_CAPABILITY_OUTPUT_TOKENS: u64 = 40_000;
+
 // =============================================================================
 // Context Thresholds
 // =============================================================================
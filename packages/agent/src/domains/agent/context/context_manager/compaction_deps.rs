@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use crate::shared::protocol::messages::Message;
 
 use super::super::compaction_engine::CompactionDeps;
-use super::super::token_estimator;
+use super::super::estimation_strategy::TokenEstimator;
 use super::ContextManager;
 
 /// Projects context manager state for the compaction engine.
@@ -15,6 +17,7 @@ pub(super) struct ManagerCompactionDeps {
     pub(super) context_limit: u64,
     pub(super) system_prompt_tokens: u64,
     pub(super) capabilities_tokens: u64,
+    pub(super) token_estimator: Arc<dyn TokenEstimator>,
 }
 
 impl ManagerCompactionDeps {
@@ -25,6 +28,7 @@ impl ManagerCompactionDeps {
             context_limit: manager.get_context_limit(),
             system_prompt_tokens: manager.estimate_system_prompt_tokens(),
             capabilities_tokens: manager.estimate_capabilities_tokens(),
+            token_estimator: Arc::clone(&manager.token_estimator),
         }
     }
 }
@@ -55,6 +59,6 @@ impl CompactionDeps for ManagerCompactionDeps {
     }
 
     fn get_message_tokens(&self, msg: &Message) -> u64 {
-        u64::from(token_estimator::estimate_message_tokens(msg))
+        u64::from(self.token_estimator.estimate_message(msg))
     }
 }
@@ -6,6 +6,7 @@
 
 use std::sync::Arc;
 
+use crate::domains::model::tokens::pressure::{ContextPressureCrossing, ContextPressureTracker};
 use crate::shared::protocol::messages::Message;
 
 use super::compaction_engine::{CompactionDeps, CompactionEngine};
@@ -13,11 +14,11 @@ use super::constants::{
     CAPABILITY_RESULT_MAX_CHARS, CAPABILITY_RESULT_MIN_TOKENS, CHARS_PER_TOKEN, Thresholds,
 };
 use super::context_snapshot_builder::ContextSnapshotBuilder;
+use super::estimation_strategy::{TokenEstimator, estimator_for};
 use super::message_store::MessageStore;
 use super::summarizer::Summarizer;
-use super::token_estimator;
 use super::types::{
-    CompactionPreview, CompactionResult, ContextManagerConfig, ContextSnapshot,
+    CompactionEstimate, CompactionPreview, CompactionResult, ContextManagerConfig, ContextSnapshot,
     DetailedContextSnapshot, ExportedState, ExtractedData, PreTurnValidation,
     ProcessedCapabilityResult,
 };
@@ -31,7 +32,9 @@ use snapshot_deps::ManagerSnapshotDeps;
 pub struct ContextManager {
     pub(super) config: ContextManagerConfig,
     messages: MessageStore,
+    token_estimator: Arc<dyn TokenEstimator>,
     api_context_tokens: Option<u64>,
+    context_pressure: ContextPressureTracker,
     system_prompt: String,
     last_extracted_data: Option<ExtractedData>,
     server_origin: Option<String>,
@@ -49,11 +52,18 @@ impl ContextManager {
         let system_prompt = config.system_prompt.clone().unwrap_or_else(|| {
             panic!("ContextManagerConfig.system_prompt must be resolved before construction")
         });
+        let context_pressure =
+            ContextPressureTracker::new(&config.compaction.pressure_warning_thresholds);
+        let token_estimator = estimator_for(config.compaction.token_estimator);
+        let mut messages = MessageStore::new();
+        messages.set_estimator(Arc::clone(&token_estimator));
 
         Self {
             config,
-            messages: MessageStore::new(),
+            messages,
+            token_estimator,
             api_context_tokens: None,
+            context_pressure,
             system_prompt,
             last_extracted_data: None,
             server_origin: None,
@@ -62,6 +72,14 @@ impl ContextManager {
         }
     }
 
+    /// Replace the configured estimator used for local token budgeting.
+    #[must_use]
+    pub fn with_token_estimator(mut self, estimator: Arc<dyn TokenEstimator>) -> Self {
+        self.messages.set_estimator(Arc::clone(&estimator));
+        self.token_estimator = estimator;
+        self
+    }
+
     pub fn begin_turn(&mut self) {
         self.turn_generation = self.turn_generation.saturating_add(1);
     }
@@ -129,6 +147,25 @@ impl ContextManager {
         self.api_context_tokens = Some(tokens);
     }
 
+    /// Record API-reported context usage for pressure tracking and return
+    /// the warning thresholds this turn newly crossed.
+    pub fn record_context_pressure(&mut self, tokens: u64) -> Vec<ContextPressureCrossing> {
+        let context_limit = self.get_context_limit();
+        self.context_pressure.record(tokens, context_limit)
+    }
+
+    /// Pressure tracking state, kept by the session between prompts.
+    #[must_use]
+    pub fn context_pressure(&self) -> &ContextPressureTracker {
+        &self.context_pressure
+    }
+
+    /// Continue pressure tracking from an earlier prompt of the session so a
+    /// threshold already warned about stays quiet until usage drops below it.
+    pub fn resume_context_pressure(&mut self, previous: &ContextPressureTracker) {
+        self.context_pressure.resume_from(previous);
+    }
+
     #[must_use]
     pub fn get_api_context_tokens(&self) -> Option<u64> {
         self.api_context_tokens
@@ -151,17 +188,15 @@ impl ContextManager {
 
     #[must_use]
     pub fn estimate_system_prompt_tokens(&self) -> u64 {
-        u64::from(token_estimator::estimate_system_prompt_tokens(
-            &self.system_prompt,
-            None,
-        ))
+        u64::from(self.token_estimator.estimate_text(&self.system_prompt))
     }
 
     #[must_use]
     pub fn estimate_capabilities_tokens(&self) -> u64 {
-        u64::from(token_estimator::estimate_capabilities_tokens(
-            &self.config.capabilities,
-        ))
+        u64::from(
+            self.token_estimator
+                .estimate_capabilities(&self.config.capabilities),
+        )
     }
 
     #[must_use]
@@ -180,7 +215,19 @@ impl ContextManager {
 
     #[must_use]
     pub fn get_message_tokens(&self, msg: &Message) -> u64 {
-        u64::from(token_estimator::estimate_message_tokens(msg))
+        u64::from(self.token_estimator.estimate_message(msg))
+    }
+
+    /// Estimated tokens of capability results since the latest real user turn.
+    #[must_use]
+    pub fn last_turn_capability_output_tokens(&self) -> u64 {
+        self.messages_slice()
+            .iter()
+            .rev()
+            .take_while(|msg| !msg.is_real_user_turn())
+            .filter(|msg| msg.is_capability_result())
+            .map(|msg| self.get_message_tokens(msg))
+            .sum()
     }
 
     #[must_use]
@@ -275,6 +322,18 @@ impl ContextManager {
         engine.has_summarizable_messages()
     }
 
+    /// Estimate compaction savings for the current messages without summarizing.
+    #[must_use]
+    pub fn estimate_compaction(&self) -> CompactionEstimate {
+        let deps = ManagerCompactionDeps::from_manager(self);
+        let engine = CompactionEngine::new(
+            self.config.compaction.threshold,
+            self.config.compaction.preserve_recent_turns,
+            deps,
+        );
+        engine.estimate(self.messages_slice())
+    }
+
     pub async fn preview_compaction(
         &self,
         summarizer: &dyn Summarizer,
@@ -371,6 +430,8 @@ impl ContextManager {
             agent_state_context: None,
             memory_prompt_context: None,
             server_origin: None,
+            system_context_token_ceiling: Some(self.config.compaction.system_context_token_ceiling),
+            pinned_context_blocks: self.config.compaction.pinned_context_blocks.clone(),
         }
     }
 
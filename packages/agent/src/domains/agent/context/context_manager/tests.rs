@@ -11,6 +11,7 @@ fn test_config() -> ContextManagerConfig {
             threshold: 0.70,
             preserve_recent_turns: 2,
             context_limit: 10_000,
+            ..CompactionConfig::default()
         },
     }
 }
@@ -107,3 +108,67 @@ fn switch_model_updates_context_limit_and_clears_api_tokens() {
     assert_eq!(manager.get_context_limit(), 123);
     assert!(manager.get_api_context_tokens().is_none());
 }
+
+#[test]
+fn configured_token_estimator_reprices_messages_and_compaction() {
+    use crate::domains::agent::context::estimation_strategy::ScriptAwareEstimator;
+    use crate::domains::settings::TokenEstimatorKind;
+    use crate::shared::protocol::messages::Message;
+
+    let cjk = "日本語のテキストを処理する";
+    let mut default_manager = manager();
+    default_manager.add_message(Message::user(cjk));
+
+    let mut config = test_config();
+    config.compaction.token_estimator = TokenEstimatorKind::ScriptAware;
+    let mut manager = ContextManager::new(config);
+    manager.add_message(Message::user(cjk));
+
+    assert!(manager.get_messages_tokens() > default_manager.get_messages_tokens());
+    assert_eq!(
+        manager.estimate_compaction().tokens_before,
+        manager.get_messages_tokens()
+    );
+
+    let swapped = default_manager.with_token_estimator(Arc::new(ScriptAwareEstimator));
+    assert_eq!(swapped.get_messages_tokens(), manager.get_messages_tokens());
+}
+
+#[test]
+fn last_turn_capability_output_counts_only_results_since_latest_user_turn() {
+    use crate::shared::protocol::messages::{CapabilityResultMessageContent, Message};
+
+    let result = |id: &str, chars: usize| Message::CapabilityResult {
+        invocation_id: id.into(),
+        content: CapabilityResultMessageContent::Text("x".repeat(chars)),
+        is_error: None,
+    };
+    let mut manager = manager();
+    manager.add_message(Message::user("read the big file"));
+    manager.add_message(result("old", 4_000));
+    manager.add_message(Message::user("now the huge one"));
+    manager.add_message(Message::assistant("reading"));
+    let latest = result("new", 200_000);
+    let expected = manager.get_message_tokens(&latest);
+    manager.add_message(latest);
+
+    assert_eq!(manager.last_turn_capability_output_tokens(), expected);
+    assert!(expected > 50_000);
+
+    manager.add_message(Message::user("thanks"));
+    assert_eq!(manager.last_turn_capability_output_tokens(), 0);
+}
+
+#[test]
+fn context_pressure_warns_once_per_threshold_against_context_limit() {
+    let mut manager = manager();
+    assert!(manager.record_context_pressure(6_000).is_empty());
+    let crossed: Vec<f64> = manager
+        .record_context_pressure(7_500)
+        .iter()
+        .map(|crossing| crossing.threshold)
+        .collect();
+    assert_eq!(crossed, vec![0.7]);
+    assert!(manager.record_context_pressure(8_000).is_empty());
+    assert_eq!(manager.record_context_pressure(9_500).len(), 1);
+}
@@ -0,0 +1,227 @@
+//! Pluggable token estimation strategies.
+//!
+//! [`TokenEstimator`] abstracts how context content is priced in tokens.
+//! `context.tokenEstimator` selects the strategy through [`estimator_for`]:
+//! [`CharEstimator`] wraps the chars/4 free functions in
+//! [`super::token_estimator`] and is the default, while
+//! [`ScriptAwareEstimator`] prices non-ASCII characters one token each so CJK
+//! and other multibyte text is not undercounted. Other strategies implement
+//! [`TokenEstimator::estimate_text`] and inherit per-block message walking.
+//! Provider-reported usage remains the source of truth after a call.
+
+use std::sync::Arc;
+
+use crate::domains::settings::TokenEstimatorKind;
+use crate::shared::protocol::content::{AssistantContent, CapabilityResultContent, UserContent};
+use crate::shared::protocol::messages::{
+    CapabilityResultMessageContent, Message, UserMessageContent,
+};
+use crate::shared::protocol::model_capabilities::ModelCapability;
+
+use super::token_estimator::{
+    ImageSource, chars_to_tokens, estimate_capabilities_tokens, estimate_image_tokens,
+    estimate_message_tokens,
+};
+
+/// Strategy for turning context content into an approximate token count.
+///
+/// [`CharEstimator`] is the default. The context manager builds the
+/// configured strategy and also accepts one via
+/// `ContextManager::with_token_estimator`.
+pub trait TokenEstimator: Send + Sync + std::fmt::Debug {
+    /// Estimate tokens for a run of text.
+    fn estimate_text(&self, text: &str) -> u32;
+
+    /// Estimate tokens for a message, including role and structure overhead.
+    fn estimate_message(&self, message: &Message) -> u32 {
+        estimate_message_tokens_with(self, message)
+    }
+
+    /// Estimate tokens for model capability definitions.
+    fn estimate_capabilities(&self, capabilities: &[ModelCapability]) -> u32 {
+        capabilities
+            .iter()
+            .map(|t| self.estimate_text(&serde_json::to_string(t).unwrap_or_default()))
+            .sum()
+    }
+}
+
+/// The chars/4 heuristic used by the free functions in this module.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CharEstimator;
+
+impl TokenEstimator for CharEstimator {
+    fn estimate_text(&self, text: &str) -> u32 {
+        chars_to_tokens(text.len())
+    }
+
+    fn estimate_message(&self, message: &Message) -> u32 {
+        estimate_message_tokens(message)
+    }
+
+    fn estimate_capabilities(&self, capabilities: &[ModelCapability]) -> u32 {
+        estimate_capabilities_tokens(capabilities)
+    }
+}
+
+/// chars/4 for ASCII and one token per non-ASCII character.
+///
+/// Byte-based chars/4 prices a three-byte CJK character at under one token,
+/// while tokenizers spend about one token per character on such scripts.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScriptAwareEstimator;
+
+impl TokenEstimator for ScriptAwareEstimator {
+    fn estimate_text(&self, text: &str) -> u32 {
+        let ascii = text.bytes().filter(u8::is_ascii).count();
+        let non_ascii = text.chars().filter(|c| !c.is_ascii()).count();
+        #[allow(clippy::cast_possible_truncation)]
+        let non_ascii = non_ascii as u32;
+        chars_to_tokens(ascii) + non_ascii
+    }
+}
+
+/// Build the estimator selected by `context.tokenEstimator`.
+#[must_use]
+pub fn estimator_for(kind: TokenEstimatorKind) -> Arc<dyn TokenEstimator> {
+    match kind {
+        TokenEstimatorKind::Chars => Arc::new(CharEstimator),
+        TokenEstimatorKind::ScriptAware => Arc::new(ScriptAwareEstimator),
+    }
+}
+
+/// Walk a message with an arbitrary estimator, pricing each text run separately.
+fn estimate_message_tokens_with<E: TokenEstimator + ?Sized>(
+    estimator: &E,
+    message: &Message,
+) -> u32 {
+    let role_str = match message {
+        Message::User { .. } => "user",
+        Message::Assistant { .. } => "assistant",
+        Message::CapabilityResult { .. } => "capabilityResult",
+    };
+    let mut tokens = chars_to_tokens(role_str.len() + 10);
+    let image = |data: &str| {
+        estimate_image_tokens(Some(&ImageSource::Base64 {
+            data: data.to_owned(),
+        }))
+    };
+
+    match message {
+        Message::User { content, .. } => match content {
+            UserMessageContent::Text(text) => tokens += estimator.estimate_text(text),
+            UserMessageContent::Blocks(blocks) => {
+                for block in blocks {
+                    tokens += match block {
+                        UserContent::Text { text } => estimator.estimate_text(text),
+                        UserContent::Image { data, .. } => image(data),
+                        UserContent::Document { data, .. } => chars_to_tokens(data.len()),
+                    };
+                }
+            }
+        },
+        Message::Assistant { content, .. } => {
+            for block in content {
+                tokens += match block {
+                    AssistantContent::Text { text } => estimator.estimate_text(text),
+                    AssistantContent::Thinking { thinking, .. } => {
+                        estimator.estimate_text(thinking)
+                    }
+                    AssistantContent::CapabilityInvocation {
+                        id,
+                        name,
+                        arguments,
+                        ..
+                    } => {
+                        let args_str = serde_json::to_string(arguments).unwrap_or_default();
+                        chars_to_tokens(id.len() + name.len()) + estimator.estimate_text(&args_str)
+                    }
+                };
+            }
+        }
+        Message::CapabilityResult {
+            invocation_id,
+            content,
+            ..
+        } => {
+            tokens += chars_to_tokens(invocation_id.len());
+            match content {
+                CapabilityResultMessageContent::Text(text) => {
+                    tokens += estimator.estimate_text(text);
+                }
+                CapabilityResultMessageContent::Blocks(blocks) => {
+                    for block in blocks {
+                        tokens += match block {
+                            CapabilityResultContent::Text { text } => estimator.estimate_text(text),
+                            CapabilityResultContent::Image { data, .. } => image(data),
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    tokens
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ASCII_PROSE: &str = "The quick brown fox jumps over the lazy dog.";
+    const CODE: &str = "fn main() { let x: Vec<u8> = vec![1, 2, 3]; println!(\"{x:?}\"); }";
+    const CJK: &str = "日本語のテキストを処理する";
+
+    #[test]
+    fn char_estimator_matches_free_functions() {
+        let msg = Message::user(CODE);
+        assert_eq!(
+            CharEstimator.estimate_message(&msg),
+            estimate_message_tokens(&msg)
+        );
+        assert_eq!(
+            CharEstimator.estimate_text(CODE),
+            chars_to_tokens(CODE.len())
+        );
+        let caps: Vec<ModelCapability> = serde_json::from_value(serde_json::json!([{
+            "name": "read",
+            "description": "Read a file",
+            "parameters": {"type": "object"}
+        }]))
+        .unwrap();
+        assert_eq!(
+            CharEstimator.estimate_capabilities(&caps),
+            estimate_capabilities_tokens(&caps)
+        );
+    }
+
+    #[test]
+    fn script_aware_agrees_with_char_estimator_on_ascii() {
+        for text in [ASCII_PROSE, CODE] {
+            assert_eq!(
+                ScriptAwareEstimator.estimate_text(text),
+                CharEstimator.estimate_text(text)
+            );
+        }
+    }
+
+    #[test]
+    fn script_aware_counts_one_token_per_cjk_character() {
+        // 13 ideographs/kana = 39 UTF-8 bytes; chars/4 prices them at 10 tokens.
+        assert_eq!(CharEstimator.estimate_text(CJK), 10);
+        assert_eq!(ScriptAwareEstimator.estimate_text(CJK), 13);
+        assert_eq!(ScriptAwareEstimator.estimate_text("naïve café"), 2 + 2);
+    }
+
+    #[test]
+    fn script_aware_message_estimate_uses_text_strategy() {
+        let msg = Message::user(CJK);
+        let overhead = chars_to_tokens("user".len() + 10);
+        assert_eq!(ScriptAwareEstimator.estimate_message(&msg), overhead + 13);
+        assert!(ScriptAwareEstimator.estimate_message(&msg) > CharEstimator.estimate_message(&msg));
+    }
+}
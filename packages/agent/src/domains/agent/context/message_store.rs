@@ -16,7 +16,7 @@ use std::sync::Arc;
 
 use crate::shared::protocol::messages::Message;
 
-use super::token_estimator::estimate_message_tokens;
+use super::estimation_strategy::{CharEstimator, TokenEstimator};
 
 /// Configuration for creating a [`MessageStore`].
 #[cfg(test)]
@@ -35,6 +35,8 @@ pub struct MessageStoreConfig {
 pub struct MessageStore {
     messages: Vec<Message>,
     token_cache: Vec<u32>,
+    /// Strategy used to fill `token_cache`.
+    estimator: Arc<dyn TokenEstimator>,
     /// Cached Arc snapshot. Invalidated on add/set/clear.
     arc_snapshot: Option<Arc<[Message]>>,
 }
@@ -46,10 +48,21 @@ impl MessageStore {
         Self {
             messages: Vec::new(),
             token_cache: Vec::new(),
+            estimator: Arc::new(CharEstimator),
             arc_snapshot: None,
         }
     }
 
+    /// Swap the token estimator and rebuild the token cache with it.
+    pub fn set_estimator(&mut self, estimator: Arc<dyn TokenEstimator>) {
+        self.token_cache = self
+            .messages
+            .iter()
+            .map(|m| estimator.estimate_message(m))
+            .collect();
+        self.estimator = estimator;
+    }
+
     /// Create a message store with an initial configuration.
     #[cfg(test)]
     #[must_use]
@@ -66,7 +79,7 @@ impl MessageStore {
     /// The token estimate is computed and cached immediately.
     pub fn add(&mut self, message: Message) {
         self.arc_snapshot = None;
-        let tokens = estimate_message_tokens(&message);
+        let tokens = self.estimator.estimate_message(&message);
         self.messages.push(message);
         self.token_cache.push(tokens);
     }
@@ -76,7 +89,10 @@ impl MessageStore {
     /// Token cache is rebuilt for the new messages.
     pub fn set(&mut self, messages: Vec<Message>) {
         self.arc_snapshot = None;
-        self.token_cache = messages.iter().map(estimate_message_tokens).collect();
+        self.token_cache = messages
+            .iter()
+            .map(|m| self.estimator.estimate_message(m))
+            .collect();
         self.messages = messages;
     }
 
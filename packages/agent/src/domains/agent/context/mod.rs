@@ -6,11 +6,12 @@
 //! |--------|---------|
 //! | `context_manager` | Entry point — owns context lifecycle, compaction triggers, and manager dependency projections |
 //! | `context_snapshot_builder` | Builds context snapshots (stable + volatile breakdown) via `SnapshotDeps` |
-//! | `compaction_engine` | Executes compaction: summarize older eligible messages, trim context |
+//! | `compaction_engine` | Executes compaction: summarize older eligible messages, trim context; `estimate` previews savings without summarizing |
 //! | `summarizer` | Summarizer trait and recovery implementations |
 //! | `message_store` | In-memory message buffer with compaction boundary tracking |
 //! | `soul` | Static seed instruction for the primitive loop |
 //! | `token_estimator` | Token counting and context budget calculations |
+//! | `estimation_strategy` | `TokenEstimator` strategies selected by `context.tokenEstimator`; `CharEstimator` (chars/4) is the default |
 //! | `constants` | Token limits, compaction thresholds |
 //! | `types` | Shared types for context subsystem |
 //!
@@ -25,7 +26,8 @@
 //! agent-owned state projection loaded through engine state primitives, and a
 //! provider-safe memory prompt-trace audit that carries only mode/count/ref
 //! evidence.
-//! Compaction uses token pressure to decide when to compact context, and only
+//! Compaction uses token pressure (or a single turn's oversized capability
+//! output) to decide when to compact context, and only
 //! commits when an older message window can be summarized and the result
 //! reduces the durable context.
 
@@ -34,6 +36,7 @@ pub mod compaction_trigger;
 pub mod constants;
 pub mod context_manager;
 pub mod context_snapshot_builder;
+pub mod estimation_strategy;
 pub mod message_store;
 pub mod soul;
 pub mod summarizer;
@@ -24,7 +24,7 @@ use super::constants::{CHARS_PER_TOKEN, DEFAULT_URL_IMAGE_TOKENS, MIN_IMAGE_TOKE
 
 /// Shorthand for chars → tokens conversion.
 #[allow(clippy::cast_possible_truncation)]
-fn chars_to_tokens(chars: usize) -> u32 {
+pub(super) fn chars_to_tokens(chars: usize) -> u32 {
     chars.div_ceil(CHARS_PER_TOKEN as usize) as u32
 }
 
@@ -260,6 +260,7 @@ pub fn estimate_messages_tokens(messages: &[Message]) -> u32 {
 ///
 /// Optionally includes a capability clarification message (for Codex providers).
 #[must_use]
+#[cfg(test)]
 pub fn estimate_system_prompt_tokens(
     system_prompt: &str,
     capability_clarification: Option<&str>,
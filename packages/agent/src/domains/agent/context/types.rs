@@ -5,6 +5,8 @@
 //! state. Behavior instructions learned by the agent live in agent-owned state,
 //! not in separate context planes.
 
+use crate::domains::model::tokens::pressure::DEFAULT_CONTEXT_PRESSURE_THRESHOLDS;
+use crate::domains::settings::TokenEstimatorKind;
 use crate::shared::protocol::messages::Message;
 use crate::shared::protocol::model_capabilities::ModelCapability;
 use serde::{Deserialize, Serialize};
@@ -52,6 +54,14 @@ pub struct CompactionConfig {
     pub threshold: f64,
     pub preserve_recent_turns: usize,
     pub context_limit: u64,
+    /// Token ceiling for the composed system context sent to providers.
+    pub system_context_token_ceiling: u64,
+    /// Ids of system context blocks pinned ahead of the stable group.
+    pub pinned_context_blocks: Vec<String>,
+    /// Usage ratios that emit a context warning when first reached.
+    pub pressure_warning_thresholds: Vec<f64>,
+    /// Strategy used to price messages before provider usage arrives.
+    pub token_estimator: TokenEstimatorKind,
 }
 
 impl Default for CompactionConfig {
@@ -60,6 +70,10 @@ impl Default for CompactionConfig {
             threshold: 0.70,
             preserve_recent_turns: 5,
             context_limit: 200_000,
+            system_context_token_ceiling: 32_000,
+            pinned_context_blocks: Vec::new(),
+            pressure_warning_thresholds: DEFAULT_CONTEXT_PRESSURE_THRESHOLDS.to_vec(),
+            token_estimator: TokenEstimatorKind::default(),
         }
     }
 }
@@ -163,6 +177,21 @@ pub struct CompactionPreview {
     pub extracted_data: Option<ExtractedData>,
 }
 
+/// Summarizer-free estimate of what compaction would do to a message list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactionEstimate {
+    pub tokens_before: u64,
+    pub estimated_tokens_after: u64,
+    pub tokens_saved: u64,
+    pub preserved_messages: usize,
+    pub summarized_messages: usize,
+    pub preserved_turns: usize,
+    pub summarized_turns: usize,
+    /// Index of the first preserved message; `[0..split_index)` would be summarized.
+    pub split_index: usize,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CompactionResult {
@@ -234,12 +263,20 @@ pub struct SummaryResult {
 #[serde(rename_all = "camelCase")]
 pub struct CompactionTriggerConfig {
     pub trigger_token_threshold: f64,
+    /// Capability output tokens in one turn that force compaction (0 disables).
+    #[serde(default = "default_large_capability_output_tokens")]
+    pub large_capability_output_tokens: u64,
+}
+
+fn default_large_capability_output_tokens() -> u64 {
+    super::constants::LARGE_CAPABILITY_OUTPUT_TOKENS
 }
 
 impl Default for CompactionTriggerConfig {
     fn default() -> Self {
         Self {
             trigger_token_threshold: 0.70,
+            large_capability_output_tokens: default_large_capability_output_tokens(),
         }
     }
 }
@@ -251,6 +288,7 @@ impl From<&crate::domains::settings::CompactorSettings> for CompactionTriggerCon
             trigger_token_threshold: cs
                 .trigger_token_threshold
                 .unwrap_or(defaults.trigger_token_threshold),
+            ..defaults
         }
     }
 }
@@ -261,6 +299,9 @@ pub struct CompactionTriggerInput {
     pub current_token_ratio: f64,
     pub recent_event_types: Vec<String>,
     pub recent_capability_invocations: Vec<String>,
+    /// Estimated tokens of capability results since the latest user turn.
+    #[serde(default)]
+    pub last_turn_capability_output_tokens: u64,
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 //! Capability contracts owned by the agent domain worker.
 
+use std::time::Duration;
+
 use serde_json::json;
 
 use crate::domains::registration::catalog::CapabilitySpec;
@@ -11,14 +13,19 @@ use crate::engine::{
 
 pub(crate) const STREAM_TOPICS: &[&str] = &["agent.runtime"];
 
+/// Bound on accepting a prompt and starting its run, so a stuck session
+/// guard or persistence call cannot hold the caller's worker indefinitely.
+pub(crate) const PROMPT_HANDLER_TIMEOUT: Duration = Duration::from_secs(120);
+
 /// Canonical capability contracts exposed by this domain worker.
 pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
     let mut specs = vec![
         CapabilityContract::new("agent::prompt", "agent", EffectClass::ExternalSideEffect, RiskLevel::High, Some("agent.write"))
-            .request_schema(json!({"additionalProperties":false,"properties":{"attachments":{"items":{"additionalProperties":true,"type":"object"},"type":"array"},"prompt":{"type":"string"},"reasoningLevel":{"type":"string"},"sessionId":{"type":"string"},"source":{"type":"string"},"workspaceId":{"type":"string"}},"required":["sessionId","prompt"],"type":"object"}))
+            .request_schema(json!({"additionalProperties":false,"properties":{"attachments":{"items":{"additionalProperties":true,"type":"object"},"type":"array"},"approvePlan":{"type":"boolean"},"planOnly":{"type":"boolean"},"prompt":{"type":"string"},"reasoningLevel":{"type":"string"},"sessionId":{"type":"string"},"source":{"type":"string"},"workspaceId":{"type":"string"}},"required":["sessionId"],"type":"object"}))
             .response_schema(json!({"additionalProperties":false,"properties":{"acknowledged":{"type":"boolean"},"runId":{"type":"string"}},"required":["acknowledged","runId"],"type":"object"}))
             .idempotency(IdempotencyContract::caller_session_engine_ledger())
             .compensation(CompensationContract::new(CompensationKind::ManualOnly, "domain-specific tests preserve current rollback, no-op, or replay behavior"))
+            .handler_timeout(PROMPT_HANDLER_TIMEOUT)
             .stream_topics(STREAM_TOPICS.to_vec())
             .build()?,
         CapabilityContract::new("agent::abort", "agent", EffectClass::ReversibleSideEffect, RiskLevel::High, Some("agent.write"))
@@ -54,6 +61,7 @@ fn hidden_capabilities() -> EngineResult<Vec<CapabilitySpec>> {
                 CompensationKind::ExternalIrreversible,
                 "hidden prompt apply starts live runtime work; event-store history remains authoritative and replay is ledger/idempotency controlled",
             ))
+            .handler_timeout(PROMPT_HANDLER_TIMEOUT)
             .stream_topics(STREAM_TOPICS.to_vec())
             .build()?,
         CapabilityContract::new("agent::run_turn", "agent", EffectClass::ExternalSideEffect, RiskLevel::High, Some("agent.write"))
@@ -73,12 +81,14 @@ fn hidden_capabilities() -> EngineResult<Vec<CapabilitySpec>> {
 fn agent_prompt_apply_request_schema() -> serde_json::Value {
     json!({
         "type": "object",
-        "required": ["runId", "sessionId", "prompt"],
+        "required": ["runId", "sessionId"],
         "additionalProperties": false,
         "properties": {
             "runId": {"type": "string"},
             "sessionId": {"type": "string"},
             "prompt": {"type": "string"},
+            "planOnly": {"type": "boolean"},
+            "approvePlan": {"type": "boolean"},
             "reasoningLevel": {"type": "string"},
             "attachments": {"type": "array", "items": {"type": "object", "additionalProperties": true}},
             "source": {"type": "string"},
@@ -122,4 +132,27 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn prompt_path_declares_a_handler_timeout() {
+        let specs = capabilities().expect("agent contracts");
+        for spec in &specs {
+            let expected = matches!(
+                spec.function_id.as_str(),
+                "agent::prompt" | "agent::prompt_apply"
+            )
+            .then_some(PROMPT_HANDLER_TIMEOUT);
+            assert_eq!(spec.handler_timeout, expected, "{}", spec.function_id);
+            let definition =
+                crate::domains::registration::contract::function_definition_for_capability(spec);
+            let declared_ms = definition
+                .metadata
+                .pointer("/delivery/timeoutMs")
+                .and_then(serde_json::Value::as_u64);
+            assert_eq!(
+                declared_ms,
+                expected.map(|timeout| u64::try_from(timeout.as_millis()).unwrap())
+            );
+        }
+    }
 }
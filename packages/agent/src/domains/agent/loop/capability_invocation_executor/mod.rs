@@ -8,6 +8,9 @@
 //! placed in the engine causal context for `capability::execute` so downstream
 //! domains can enforce goal, prompt-artifact, program-execution, resource,
 //! filesystem, and Git contracts without wildcard selectors.
+//!
+//! Before any of that, the run's [`CapabilityPolicy`] is consulted; a call it
+//! does not admit fails with `CAPABILITY_NOT_PERMITTED` without dispatching.
 
 use std::sync::Arc;
 use std::sync::atomic::AtomicI64;
@@ -33,7 +36,7 @@ use crate::shared::protocol::messages::CapabilityInvocationDraft;
 use crate::shared::protocol::model_capabilities::{CapabilityResult, failure_result};
 use crate::shared::server::error_mapping::engine_error_to_failure;
 use crate::shared::server::failure::{
-    CAPABILITY_ENGINE_HOST_UNAVAILABLE, CAPABILITY_ENGINE_RESULT_MISSING,
+    CAPABILITY_ENGINE_HOST_UNAVAILABLE, CAPABILITY_ENGINE_RESULT_MISSING, CAPABILITY_NOT_PERMITTED,
     CAPABILITY_PRIMITIVE_NOT_FOUND, CAPABILITY_RESULT_INVALID, ENGINE_POLICY_VIOLATION,
     FailureCategory, FailureEnvelope, FailureOrigin, RUNTIME_CANCELLED,
 };
@@ -42,9 +45,11 @@ use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, instrument};
 
 mod grant;
+mod policy;
 use grant::{derive_capability_runtime_grant, model_capability_invocation_idempotency_key};
 #[cfg(test)]
 use grant::{sha256_hex, stable_capability_invocation_material};
+pub use policy::{CAPABILITY_POLICY_METADATA_KEY, CapabilityPolicy};
 
 fn duration_ceil_ms(d: Duration) -> u64 {
     let micros = d.as_micros();
@@ -200,6 +205,7 @@ pub struct CapabilityInvocationExecutionContext<'a> {
     pub provider_type: &'a str,
     pub trace_id: Option<&'a TraceId>,
     pub parent_invocation_id: Option<&'a InvocationId>,
+    pub capability_policy: &'a CapabilityPolicy,
 }
 
 #[allow(clippy::too_many_lines, clippy::cast_possible_truncation)]
@@ -243,6 +249,34 @@ pub async fn execute_capability_invocation(
         };
     };
 
+    if let Some(refused) = ctx
+        .capability_policy
+        .refusal(&model_primitive_name, &capability_invocation.arguments)
+    {
+        debug!(model_primitive_name, refused, "capability not permitted");
+        let failure = FailureEnvelope::new(
+            CAPABILITY_NOT_PERMITTED,
+            FailureCategory::Auth,
+            format!("capability {refused} not permitted in this session"),
+            false,
+            true,
+            FailureOrigin::Capability,
+        );
+        return CapabilityInvocationExecutionResult {
+            result: capability_failure_result(
+                failure,
+                &model_primitive_name,
+                &invocation_id,
+                session_id,
+                ctx.trace_id,
+                ctx.parent_invocation_id,
+                Some(json!({ "refused": refused })),
+            ),
+            duration_ms: duration_ceil_ms(start.elapsed()),
+            stops_turn: false,
+        };
+    }
+
     let stops_turn = engine_target.stops_turn;
     let effective_args = Value::Object(capability_invocation.arguments.clone());
     let primitive_identity = primitive_capability_identity(
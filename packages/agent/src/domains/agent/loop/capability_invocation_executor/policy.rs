@@ -0,0 +1,111 @@
+//! Per-session capability policy consulted before primitive dispatch.
+//!
+//! A policy names model primitives (`execute`) or `execute` operations
+//! (`file_write`, `git_commit`, ...). Denials always win. An allow-list, when
+//! present, admits a call whose primitive or requested operation it names.
+//! Policies from different sources combine with [`CapabilityPolicy::compose`],
+//! which never widens either input.
+//!
+//! A policy given at session creation is persisted as a `metadata.update`
+//! event under [`CAPABILITY_POLICY_METADATA_KEY`] so it survives eviction and
+//! restarts; the session reconstructor reads it back on resume.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// `metadata.update` key under which a session's capability policy is stored.
+pub const CAPABILITY_POLICY_METADATA_KEY: &str = "capabilityPolicy";
+
+/// Capability allow/deny policy carried on `RunContext`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CapabilityPolicy {
+    /// Names the session may call; `None` admits everything not denied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<BTreeSet<String>>,
+    /// Names the session may never call.
+    pub denied: BTreeSet<String>,
+}
+
+impl CapabilityPolicy {
+    /// Policy that admits every capability.
+    #[must_use]
+    pub const fn unrestricted() -> Self {
+        Self {
+            allowed: None,
+            denied: BTreeSet::new(),
+        }
+    }
+
+    /// Policy that admits only `names`.
+    #[must_use]
+    pub fn allow_only<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allowed: Some(names.into_iter().map(Into::into).collect()),
+            denied: BTreeSet::new(),
+        }
+    }
+
+    /// Policy that denies `names` and admits everything else.
+    #[must_use]
+    pub fn deny<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allowed: None,
+            denied: names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Combine two policies: denials are unioned and allow-lists intersected,
+    /// so the result admits a call only when both inputs admit it.
+    #[must_use]
+    pub fn compose(&self, other: &Self) -> Self {
+        let allowed = match (&self.allowed, &other.allowed) {
+            (None, None) => None,
+            (Some(allowed), None) | (None, Some(allowed)) => Some(allowed.clone()),
+            (Some(left), Some(right)) => Some(left.intersection(right).cloned().collect()),
+        };
+        Self {
+            allowed,
+            denied: self.denied.union(&other.denied).cloned().collect(),
+        }
+    }
+
+    /// The refused name when this policy does not admit the call, else `None`.
+    ///
+    /// The refused name is the requested operation when there is one and the
+    /// primitive otherwise.
+    #[must_use]
+    pub fn refusal(&self, primitive: &str, arguments: &Map<String, Value>) -> Option<String> {
+        let operation = arguments
+            .get("operation")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|operation| !operation.is_empty());
+        let refused = || operation.unwrap_or(primitive).to_owned();
+        if self.denied.contains(primitive) {
+            return Some(primitive.to_owned());
+        }
+        if operation.is_some_and(|operation| self.denied.contains(operation)) {
+            return Some(refused());
+        }
+        match &self.allowed {
+            Some(allowed)
+                if !allowed.contains(primitive)
+                    && !operation.is_some_and(|operation| allowed.contains(operation)) =>
+            {
+                Some(refused())
+            }
+            _ => None,
+        }
+    }
+}
@@ -35,6 +35,9 @@ mod grant_repository_tree_tests;
 mod grant_tests;
 mod grant_update_diagnostics_tests;
 mod grant_web_research_tests;
+mod policy_tests;
+
+static UNRESTRICTED: CapabilityPolicy = CapabilityPolicy::unrestricted();
 
 fn empty_surface() -> ResolvedPrimitiveSurface {
     ResolvedPrimitiveSurface {
@@ -106,6 +109,7 @@ fn capability_exec_ctx<'a>(
         provider_type: "openai",
         trace_id: None,
         parent_invocation_id: None,
+        capability_policy: &UNRESTRICTED,
     }
 }
 
@@ -0,0 +1,121 @@
+use super::*;
+use crate::shared::server::failure::CAPABILITY_NOT_PERMITTED;
+
+fn operation_call(operation: &str) -> CapabilityInvocationDraft {
+    CapabilityInvocationDraft::new(
+        "tc1",
+        "execute",
+        payload_object(&json!({"operation": operation})),
+    )
+}
+
+async fn execute_under(policy: &CapabilityPolicy, operation: &str) -> (CapabilityResult, usize) {
+    let surface = surface_with_echo();
+    let emitter = Arc::new(EventEmitter::new());
+    let cancel = CancellationToken::new();
+    let ctx = CapabilityInvocationExecutionContext {
+        capability_policy: policy,
+        ..capability_exec_ctx(&surface, &emitter, &cancel)
+    };
+    let result =
+        execute_capability_invocation(&operation_call(operation), "s1", "/tmp", &ctx).await;
+    (
+        result.result,
+        usize::try_from(emitter.emit_count()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn allowed_operation_passes_policy_and_reaches_dispatch() {
+    let policy = CapabilityPolicy::allow_only(["file_read"]);
+
+    let (result, emitted) = execute_under(&policy, "file_read").await;
+
+    assert_failure_code(&result, CAPABILITY_ENGINE_HOST_UNAVAILABLE);
+    assert_eq!(emitted, 1, "dispatch emits the started event");
+}
+
+#[tokio::test]
+async fn denied_operation_fails_without_dispatch() {
+    let policy = CapabilityPolicy::deny(["file_write"]);
+
+    let (result, emitted) = execute_under(&policy, "file_write").await;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert_failure_code(&result, CAPABILITY_NOT_PERMITTED);
+    let details = result.details.as_ref().unwrap();
+    assert_eq!(details["refused"], "file_write");
+    assert_eq!(
+        details["failure"]["message"],
+        "capability file_write not permitted in this session"
+    );
+    assert_eq!(emitted, 0, "refused calls never start");
+}
+
+#[tokio::test]
+async fn allow_list_refuses_unlisted_operations() {
+    let policy = CapabilityPolicy::allow_only(["file_read"]);
+
+    let (result, _) = execute_under(&policy, "program_execute").await;
+
+    assert_failure_code(&result, CAPABILITY_NOT_PERMITTED);
+    assert_eq!(
+        result.details.as_ref().unwrap()["refused"],
+        "program_execute"
+    );
+}
+
+#[tokio::test]
+async fn composed_denials_override_session_allow_list() {
+    let session = CapabilityPolicy::allow_only(["file_read", "file_write"]);
+    let read_only = CapabilityPolicy::deny(["file_write"]);
+    let policy = session.compose(&read_only);
+
+    let (read, _) = execute_under(&policy, "file_read").await;
+    let (write, _) = execute_under(&policy, "file_write").await;
+
+    assert_failure_code(&read, CAPABILITY_ENGINE_HOST_UNAVAILABLE);
+    assert_failure_code(&write, CAPABILITY_NOT_PERMITTED);
+}
+
+#[test]
+fn compose_intersects_allow_lists_and_unions_denials() {
+    let left = CapabilityPolicy {
+        allowed: Some(["file_read", "git_status"].map(str::to_owned).into()),
+        denied: ["program_execute"].map(str::to_owned).into(),
+    };
+    let right = CapabilityPolicy {
+        allowed: Some(["git_status", "file_write"].map(str::to_owned).into()),
+        denied: ["git_commit"].map(str::to_owned).into(),
+    };
+
+    let composed = left.compose(&right);
+
+    assert_eq!(
+        composed.allowed,
+        Some(["git_status"].map(str::to_owned).into())
+    );
+    assert_eq!(
+        composed.denied,
+        ["git_commit", "program_execute"].map(str::to_owned).into()
+    );
+    assert_eq!(
+        CapabilityPolicy::unrestricted().compose(&right),
+        right.compose(&CapabilityPolicy::unrestricted())
+    );
+}
+
+#[test]
+fn denying_the_primitive_refuses_every_operation() {
+    let policy = CapabilityPolicy::deny(["execute"]);
+    let arguments = payload_object(&json!({"operation": "file_read"}));
+
+    assert_eq!(
+        policy.refusal("execute", &arguments).as_deref(),
+        Some("execute")
+    );
+    assert_eq!(
+        CapabilityPolicy::default().refusal("execute", &arguments),
+        None
+    );
+}
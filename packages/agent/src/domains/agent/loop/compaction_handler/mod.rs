@@ -96,6 +96,8 @@ impl CompactionHandler {
                 current_token_ratio: token_ratio,
                 recent_event_types: Vec::new(),
                 recent_capability_invocations: Vec::new(),
+                last_turn_capability_output_tokens: context_manager
+                    .last_turn_capability_output_tokens(),
             });
         if !trigger_result.compact {
             return Ok(false);
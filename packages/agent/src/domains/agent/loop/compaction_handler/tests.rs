@@ -40,3 +40,53 @@ async fn skipped_event_reports_no_durable_reduction() {
     assert!(!success);
     assert!(!handler.is_compacting());
 }
+
+#[tokio::test]
+async fn large_capability_output_compacts_once_across_inner_turns() {
+    use crate::domains::agent::context::types::{CompactionConfig, ContextManagerConfig};
+    use crate::shared::protocol::messages::{CapabilityResultMessageContent, Message};
+
+    let result = |id: &str, chars: usize| Message::CapabilityResult {
+        invocation_id: id.into(),
+        content: CapabilityResultMessageContent::Text("x".repeat(chars)),
+        is_error: None,
+    };
+    let mut context_manager = ContextManager::new(ContextManagerConfig {
+        model: "test-model".into(),
+        system_prompt: Some("soul".into()),
+        working_directory: Some("/tmp".into()),
+        capabilities: vec![],
+        compaction: CompactionConfig {
+            preserve_recent_turns: 1,
+            context_limit: 10_000_000,
+            ..CompactionConfig::default()
+        },
+    });
+    for turn in 0..4 {
+        context_manager.add_message(Message::user(format!("earlier question {turn}")));
+        context_manager.add_message(Message::assistant("earlier answer ".repeat(400)));
+    }
+    context_manager.add_message(Message::user("read the huge file"));
+    context_manager.add_message(Message::assistant("reading"));
+    context_manager.add_message(result("huge", 200_000));
+
+    let handler = CompactionHandler::new(CompactionTriggerConfig::default());
+    let emitter = Arc::new(EventEmitter::new());
+    let mut events = emitter.subscribe();
+    for inner_turn in 0..4 {
+        let _ = handler
+            .check_and_compact(&mut context_manager, "s1", &emitter, None)
+            .await
+            .unwrap();
+        context_manager.add_message(Message::assistant(format!("inner turn {inner_turn}")));
+        context_manager.add_message(result(&format!("small-{inner_turn}"), 400));
+    }
+
+    let mut compaction_starts = 0;
+    while let Ok(event) = events.try_recv() {
+        if matches!(event, TronEvent::CompactionStart { .. }) {
+            compaction_starts += 1;
+        }
+    }
+    assert_eq!(compaction_starts, 1);
+}
@@ -97,6 +97,23 @@ impl RuntimeError {
         }
     }
 
+    /// Stop reason a run ends with when this error terminates it.
+    pub fn stop_reason(&self) -> StopReason {
+        match self {
+            Self::ModelResponse(error) if error.is_cancelled() => StopReason::Interrupted,
+            Self::ModelResponse(_) => StopReason::ModelResponseFailed,
+            Self::Cancelled => StopReason::Interrupted,
+            Self::MaxTurns(_) => StopReason::MaxTurns,
+            Self::ModelCapability { .. }
+            | Self::Context(_)
+            | Self::SessionNotFound(_)
+            | Self::SessionBusy(_)
+            | Self::ServerBusy { .. }
+            | Self::Persistence(_)
+            | Self::Internal(_) => StopReason::Error,
+        }
+    }
+
     /// Convert this runtime error to the canonical failure envelope.
     pub fn to_failure(&self) -> FailureEnvelope {
         match self {
@@ -197,13 +214,22 @@ pub enum StopReason {
     MaxTurns,
     /// A turn-stopping primitive call requested the next loop iteration.
     CapabilityStop,
-    /// Unrecoverable error.
+    /// Unrecoverable runtime error not attributable to the provider.
     Error,
     /// User abort.
     Interrupted,
+    /// The model declined to continue (safety refusal or content filter).
+    GuardrailBlocked,
+    /// The model provider failed the request or its response stream.
+    #[serde(rename = "provider_error")]
+    ModelResponseFailed,
+    /// The session's accumulated cost reached the configured ceiling.
+    CostLimitExceeded,
     /// Pure text response (no capabilities to execute).
     #[serde(rename = "no_capability_invocations")]
     NoCapabilityInvocationDrafts,
+    /// A `plan_only` run stopped before executing requested capabilities.
+    AwaitingApproval,
 }
 
 impl fmt::Display for StopReason {
@@ -214,7 +240,11 @@ impl fmt::Display for StopReason {
             Self::CapabilityStop => write!(f, "capability_stop"),
             Self::Error => write!(f, "error"),
             Self::Interrupted => write!(f, "interrupted"),
+            Self::GuardrailBlocked => write!(f, "guardrail_blocked"),
+            Self::ModelResponseFailed => write!(f, "provider_error"),
+            Self::CostLimitExceeded => write!(f, "cost_limit_exceeded"),
             Self::NoCapabilityInvocationDrafts => write!(f, "no_capability_invocations"),
+            Self::AwaitingApproval => write!(f, "awaiting_approval"),
         }
     }
 }
@@ -332,7 +362,11 @@ mod tests {
             StopReason::CapabilityStop,
             StopReason::Error,
             StopReason::Interrupted,
+            StopReason::GuardrailBlocked,
+            StopReason::ModelResponseFailed,
+            StopReason::CostLimitExceeded,
             StopReason::NoCapabilityInvocationDrafts,
+            StopReason::AwaitingApproval,
         ];
         for r in &reasons {
             let json = serde_json::to_string(r).unwrap();
@@ -359,11 +393,57 @@ mod tests {
             serde_json::to_string(&StopReason::NoCapabilityInvocationDrafts).unwrap(),
             "\"no_capability_invocations\""
         );
+        assert_eq!(
+            serde_json::to_string(&StopReason::GuardrailBlocked).unwrap(),
+            "\"guardrail_blocked\""
+        );
+        assert_eq!(
+            serde_json::to_string(&StopReason::ModelResponseFailed).unwrap(),
+            "\"provider_error\""
+        );
+        assert_eq!(
+            serde_json::to_string(&StopReason::CostLimitExceeded).unwrap(),
+            "\"cost_limit_exceeded\""
+        );
+        assert_eq!(
+            serde_json::to_string(&StopReason::AwaitingApproval).unwrap(),
+            "\"awaiting_approval\""
+        );
+    }
+
+    #[test]
+    fn runtime_error_stop_reason_names_the_cause() {
+        use crate::domains::model::responder::ModelResponseError;
+
+        assert_eq!(
+            RuntimeError::ModelResponse(ModelResponseError::other("boom")).stop_reason(),
+            StopReason::ModelResponseFailed
+        );
+        assert_eq!(
+            RuntimeError::Cancelled.stop_reason(),
+            StopReason::Interrupted
+        );
+        assert_eq!(
+            RuntimeError::MaxTurns(3).stop_reason(),
+            StopReason::MaxTurns
+        );
+        assert_eq!(
+            RuntimeError::Internal("x".into()).stop_reason(),
+            StopReason::Error
+        );
     }
 
     #[test]
     fn stop_reason_display() {
         assert_eq!(StopReason::EndTurn.to_string(), "end_turn");
         assert_eq!(StopReason::Interrupted.to_string(), "interrupted");
+        assert_eq!(
+            StopReason::GuardrailBlocked.to_string(),
+            "guardrail_blocked"
+        );
+        assert_eq!(
+            StopReason::ModelResponseFailed.to_string(),
+            "provider_error"
+        );
     }
 }
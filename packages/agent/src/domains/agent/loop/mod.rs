@@ -32,6 +32,7 @@ pub mod profile_runtime;
 mod stream_message;
 pub mod stream_processor;
 mod stream_state;
+mod stream_truncation;
 pub mod tron_agent;
 pub mod turn_runner;
 pub(crate) mod types;
@@ -0,0 +1,123 @@
+//! Agent replay — rerun a recorded provider transcript through the turn loop.
+//!
+//! [`run_agent_replay`] builds a fresh `TronAgent` whose responder replays a
+//! recorded provider transcript, then drives it through [`run_agent`] exactly as a live
+//! prompt would run. Model output comes only from the transcript, so the same
+//! transcript always yields the same events and capability invocations.
+//!
+//! Replays run against an isolated in-memory engine host with no capabilities
+//! registered: recorded `execute` calls are dispatched and reported, but they
+//! fail closed instead of repeating their original side effects. Streaming
+//! journals go to a temporary directory, so server crash recovery never sees
+//! a replayed turn.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::broadcast;
+use tracing::instrument;
+
+use crate::domains::agent::context::types::CompactionTriggerConfig;
+use crate::domains::agent::r#loop::event_emitter::EventEmitter;
+use crate::domains::agent::r#loop::orchestrator::agent_factory::{AgentFactory, CreateAgentOpts};
+use crate::domains::agent::r#loop::orchestrator::agent_runner::run_agent;
+use crate::domains::agent::r#loop::types::{AgentConfig, RunContext, RunResult};
+use crate::domains::model::responder::{ModelResponseError, open_replay_responder};
+use crate::engine::EngineHostHandle;
+use crate::shared::protocol::events::TronEvent;
+
+/// Errors that prevent a replay from running to completion.
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    /// The transcript could not be read or is not a supported transcript.
+    #[error("invalid replay transcript: {0}")]
+    Transcript(#[from] ModelResponseError),
+
+    /// The isolated replay engine host could not be created.
+    #[error("failed to create replay engine host: {0}")]
+    EngineHost(String),
+
+    /// The temporary streaming journal directory could not be created.
+    #[error("failed to create replay journal directory: {0}")]
+    JournalDir(#[from] std::io::Error),
+
+    /// The event collector fell behind and dropped events.
+    #[error("replay dropped {0} events; the recorded event sequence is incomplete")]
+    EventsLagged(u64),
+}
+
+/// Outcome of one replayed run.
+#[derive(Debug)]
+pub struct ReplayRun {
+    /// Run result reported by the turn loop.
+    pub result: RunResult,
+    /// Every event the run emitted, ending with `agent.ready`.
+    pub events: Vec<TronEvent>,
+}
+
+/// Replay the transcript at `transcript_path` as session `session_id`.
+#[instrument(skip_all, fields(session_id, transcript = %transcript_path.display()))]
+pub async fn run_agent_replay(
+    session_id: &str,
+    transcript_path: &Path,
+) -> Result<ReplayRun, ReplayError> {
+    let replay = open_replay_responder(transcript_path)?;
+    let engine_host = EngineHostHandle::new_in_memory()
+        .map_err(|error| ReplayError::EngineHost(error.to_string()))?;
+    let journal_dir = tempfile::tempdir()?;
+    let info = replay.responder.info();
+    let mut agent = AgentFactory::create_agent(
+        AgentConfig {
+            provider_type: Some(info.provider_type),
+            model: info.model,
+            system_prompt: replay.system_prompt,
+            max_turns: u32::try_from(replay.recorded_turns).unwrap_or(u32::MAX),
+            ..AgentConfig::default()
+        },
+        session_id.to_owned(),
+        CreateAgentOpts::primitive(
+            replay.responder,
+            Vec::new(),
+            0,
+            CompactionTriggerConfig::default(),
+            Some(engine_host),
+        ),
+    );
+    agent.set_journal_dir(journal_dir.path().to_path_buf());
+
+    let broadcast = Arc::new(EventEmitter::new());
+    let mut events_rx = broadcast.subscribe();
+    let ctx = RunContext {
+        run_id: Some(format!("replay-{session_id}")),
+        ..RunContext::default()
+    };
+    let collect = async {
+        let mut events = Vec::new();
+        loop {
+            match events_rx.recv().await {
+                Ok(event) => {
+                    let ready = matches!(event, TronEvent::AgentReady { .. });
+                    events.push(event);
+                    if ready {
+                        return Ok(events);
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    return Err(ReplayError::EventsLagged(skipped));
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(events),
+            }
+        }
+    };
+    let (result, events) = tokio::join!(
+        run_agent(&mut agent, replay.prompt.as_str(), ctx, &broadcast, None),
+        collect
+    );
+    Ok(ReplayRun {
+        result,
+        events: events?,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+use super::*;
+use crate::domains::agent::r#loop::errors::StopReason;
+use crate::domains::model::providers::replay::{REPLAY_TRANSCRIPT_VERSION, ReplayTranscript};
+use crate::shared::protocol::content::AssistantContent;
+use crate::shared::protocol::events::{AssistantMessage, StreamEvent};
+use crate::shared::protocol::messages::{CapabilityInvocationDraft, Provider};
+use serde_json::{Value, json};
+
+fn test_session_id(name: &str) -> String {
+    format!(
+        "agent-replay-test-{name}-{}",
+        crate::shared::foundation::ids::SessionId::new()
+    )
+}
+
+fn execute_turn() -> Vec<StreamEvent> {
+    let mut arguments = serde_json::Map::new();
+    let _ = arguments.insert("operation".into(), json!("observe"));
+    let _ = arguments.insert("input".into(), json!("replayed call"));
+    vec![
+        StreamEvent::Start,
+        StreamEvent::CapabilityInvocationDraftStart {
+            invocation_id: "tc-replay-1".into(),
+            name: "execute".into(),
+        },
+        StreamEvent::CapabilityInvocationDraftDelta {
+            invocation_id: "tc-replay-1".into(),
+            arguments_delta: serde_json::to_string(&arguments).expect("arguments json"),
+        },
+        StreamEvent::CapabilityInvocationDraftEnd {
+            capability_invocation: CapabilityInvocationDraft::new(
+                "tc-replay-1",
+                "execute",
+                arguments,
+            ),
+        },
+        StreamEvent::Done {
+            message: AssistantMessage {
+                content: vec![],
+                token_usage: None,
+            },
+            stop_reason: "capability_invocation".into(),
+        },
+    ]
+}
+
+fn text_turn(text: &str) -> Vec<StreamEvent> {
+    vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta { delta: text.into() },
+        StreamEvent::Done {
+            message: AssistantMessage {
+                content: vec![AssistantContent::text(text)],
+                token_usage: None,
+            },
+            stop_reason: "end_turn".into(),
+        },
+    ]
+}
+
+/// Record `turns` as a transcript file, the way a bug report would ship it.
+fn record_transcript(dir: &Path, turns: Vec<Vec<StreamEvent>>) -> std::path::PathBuf {
+    let transcript = ReplayTranscript {
+        version: REPLAY_TRANSCRIPT_VERSION,
+        provider: Provider::Anthropic,
+        model: "claude-opus-4-6".into(),
+        system_prompt: Some("You are a test agent.".into()),
+        prompt: "reproduce the bad turn".into(),
+        turns,
+    };
+    let path = dir.join("transcript.json");
+    std::fs::write(
+        &path,
+        serde_json::to_string_pretty(&transcript).expect("transcript json"),
+    )
+    .expect("write transcript");
+    path
+}
+
+/// Event JSON with wall-clock fields removed; everything else must match.
+fn stable_events(events: &[TronEvent]) -> Vec<Value> {
+    fn strip(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                map.retain(|key, _| !matches!(key.as_str(), "timestamp" | "duration"));
+                map.values_mut().for_each(strip);
+            }
+            Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+    events
+        .iter()
+        .map(|event| {
+            let mut value = serde_json::to_value(event).expect("event json");
+            strip(&mut value);
+            value
+        })
+        .collect()
+}
+
+fn capability_calls(events: &[TronEvent]) -> Vec<(String, String, Value)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            TronEvent::CapabilityInvocationBatch {
+                capability_invocations,
+                ..
+            } => Some(capability_invocations),
+            _ => None,
+        })
+        .flatten()
+        .map(|call| {
+            (
+                call.id.clone(),
+                call.name.clone(),
+                Value::Object(call.arguments.clone()),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn replay_reproduces_identical_events_and_capability_calls() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = record_transcript(dir.path(), vec![execute_turn(), text_turn("replayed")]);
+    let session_id = test_session_id("identical");
+
+    let first = run_agent_replay(&session_id, &path).await.expect("replay");
+    let second = run_agent_replay(&session_id, &path).await.expect("replay");
+
+    assert!(first.result.error.is_none(), "{:?}", first.result.error);
+    assert_eq!(first.result.turns_executed, 2);
+    assert_eq!(first.result.stop_reason, StopReason::EndTurn);
+    assert_eq!(
+        capability_calls(&first.events),
+        [(
+            "tc-replay-1".to_owned(),
+            "execute".to_owned(),
+            json!({"operation": "observe", "input": "replayed call"})
+        )]
+    );
+    assert_eq!(
+        capability_calls(&first.events),
+        capability_calls(&second.events)
+    );
+    assert_eq!(stable_events(&first.events), stable_events(&second.events));
+    assert!(matches!(
+        first.events.last(),
+        Some(TronEvent::AgentReady { .. })
+    ));
+}
+
+#[tokio::test]
+async fn replay_fails_the_run_when_the_loop_outlives_the_transcript() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = record_transcript(dir.path(), vec![execute_turn()]);
+    let session_id = test_session_id("exhausted");
+
+    let replay = run_agent_replay(&session_id, &path).await.expect("replay");
+
+    assert_eq!(replay.result.turns_executed, 1);
+    assert_eq!(replay.result.stop_reason, StopReason::MaxTurns);
+}
+
+#[tokio::test]
+async fn replay_rejects_transcripts_without_turns() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = record_transcript(dir.path(), Vec::new());
+
+    let error = run_agent_replay("agent-replay-empty", &path)
+        .await
+        .expect_err("empty transcript");
+
+    assert!(matches!(error, ReplayError::Transcript(_)), "{error}");
+}
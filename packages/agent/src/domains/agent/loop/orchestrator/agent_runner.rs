@@ -14,19 +14,19 @@ use tracing::{debug, instrument, warn};
 
 use crate::domains::agent::r#loop::event_emitter::EventEmitter;
 use crate::domains::agent::r#loop::tron_agent::TronAgent;
-use crate::domains::agent::r#loop::types::{RunContext, RunResult};
+use crate::domains::agent::r#loop::types::{RunContext, RunInput, RunResult};
 
 /// Run an agent with orchestrator integration.
 ///
-/// This wraps `TronAgent::run` with:
+/// This wraps `TronAgent::run` and `TronAgent::approve` with:
 /// 1. Build and inject the primitive `RunContext`
-/// 2. Execute `agent.run(content, ctx)`
+/// 2. Execute `agent.run(content, ctx)` or `agent.approve(plan, ctx)`
 /// 3. Forward streamed agent events
 /// 4. Emit `agent.ready` after the forwarded `agent.complete`
 #[instrument(skip_all, fields(session_id = agent.session_id()))]
 pub async fn run_agent(
     agent: &mut TronAgent,
-    content: &str,
+    input: impl Into<RunInput<'_>>,
     ctx: RunContext,
     broadcast: &Arc<EventEmitter>,
     sequence_counter: Option<Arc<AtomicI64>>,
@@ -68,7 +68,10 @@ pub async fn run_agent(
     });
 
     // Run the agent.
-    let result = agent.run(content, ctx).await;
+    let result = match input.into() {
+        RunInput::Prompt(content) => agent.run(content, ctx).await,
+        RunInput::ApprovedPlan(plan) => agent.approve(plan, ctx).await,
+    };
 
     // Signal the forward task to drain remaining buffered events and exit
     forward_cancel.cancel();
@@ -340,7 +343,7 @@ mod tests {
         let mut rx = broadcast.subscribe();
 
         let result = run_agent(&mut agent, "Hi", run_context(), &broadcast, None).await;
-        assert_eq!(result.stop_reason, StopReason::Error);
+        assert_eq!(result.stop_reason, StopReason::ModelResponseFailed);
 
         // Should still emit agent_ready after error
         let mut saw_ready = false;
@@ -0,0 +1,43 @@
+//! Idle-session sweep — frees cached session slots held by idle sessions.
+//!
+//! A session counts against [`MAX_CONCURRENT_SESSIONS`] while it is cached in
+//! the `SessionManager`. The sweep stops sessions with no activity for the
+//! configured idle timeout: their persister is flushed, they leave the cache,
+//! and a `session.idle_timeout` event tells clients why. Sessions with an
+//! active run, a processing prompt, or a retain in flight are never swept.
+//! Swept sessions stay durable and resume normally on the next access.
+//!
+//! [`MAX_CONCURRENT_SESSIONS`]: super::MAX_CONCURRENT_SESSIONS
+
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+use crate::shared::protocol::events::{BaseEvent, TronEvent};
+
+use super::Orchestrator;
+
+impl Orchestrator {
+    /// Stop sessions idle longer than `idle_timeout` as of `now`.
+    ///
+    /// Returns the swept session ids.
+    pub async fn sweep_idle_sessions(&self, idle_timeout: Duration, now: Instant) -> Vec<String> {
+        let idle = self
+            .session_manager
+            .take_idle_sessions(idle_timeout, now, |session_id| {
+                self.has_active_run(session_id) || self.retain_in_flight.contains_key(session_id)
+            });
+        let mut swept = Vec::with_capacity(idle.len());
+        for (session_id, session) in idle {
+            if let Err(error) = session.context.persister.flush().await {
+                warn!(session_id, error = %error, "failed to flush idle session before sweep");
+            }
+            let _ = self.broadcast.emit(TronEvent::SessionIdleTimeout {
+                base: BaseEvent::now(&session_id),
+                idle_timeout_secs: idle_timeout.as_secs(),
+            });
+            swept.push(session_id);
+        }
+        swept
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! | Module | Purpose |
 //! |--------|---------|
+//! | `idle_sweep` | Idle-session sweep that frees cached session slots |
 //! | `tests` | Coordinator capacity, sequencing, cancellation, and broadcast tests |
 //!
 //! ## Entry Points
@@ -12,6 +13,7 @@
 //! - [`Orchestrator::run_agent`] starts a primitive agent turn for a session.
 //! - [`Orchestrator::try_begin_retain`] guards active-run retention and
 //!   concurrency permits.
+//! - [`Orchestrator::sweep_idle_sessions`] stops idle sessions to free slots.
 //!
 //! ## Dependency Direction
 //!
@@ -50,13 +52,17 @@ use tokio_util::sync::CancellationToken;
 use metrics::gauge;
 use tracing::{debug, info, instrument, trace, warn};
 
+use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
 use crate::domains::agent::r#loop::compaction_handler::CompactionHandler;
 use crate::domains::agent::r#loop::errors::RuntimeError;
 use crate::domains::agent::r#loop::event_emitter::EventEmitter;
 use crate::domains::agent::r#loop::orchestrator::capability_invocation_tracker::CapabilityInvocationTracker;
 use crate::domains::agent::r#loop::orchestrator::invocation_abort_registry::InvocationAbortRegistry;
 use crate::domains::agent::r#loop::orchestrator::session_manager::{SessionFilter, SessionManager};
+use crate::domains::agent::r#loop::orchestrator::session_reconstructor::ReconstructedState;
 use crate::domains::agent::r#loop::orchestrator::turn_accumulator::TurnAccumulatorMap;
+use crate::domains::agent::r#loop::types::PlannedInvocations;
+use crate::domains::model::tokens::pressure::ContextPressureTracker;
 
 /// Tracks an active agent run within a session.
 struct ActiveRun {
@@ -157,6 +163,18 @@ pub struct Orchestrator {
     /// Per-session compaction handlers for active agent sessions.
     /// Registered when an agent starts, removed when it ends.
     compaction_handlers: Arc<DashMap<String, Arc<CompactionHandler>>>,
+    /// Per-session capability policies copied into each run's `RunContext`.
+    /// Sessions without an entry are unrestricted.
+    capability_policies: DashMap<String, CapabilityPolicy>,
+    /// Per-session plans from `plan_only` runs awaiting approval. Agents are
+    /// rebuilt for every prompt, so the plan and the policy it was made under
+    /// live here until the next prompt approves or declines it. Plans do not
+    /// survive a restart; the prompt runtime records a durable marker so a
+    /// late approval is reported as expired.
+    pending_plans: DashMap<String, PlannedInvocations>,
+    /// Per-session context-pressure trackers, carried between prompts so a
+    /// warning threshold fires once per crossing rather than once per prompt.
+    context_pressure: DashMap<String, ContextPressureTracker>,
     /// Set of session IDs with a retain pipeline currently running.
     ///
     /// Prevents two concurrent retains on the same session (manual + auto,
@@ -180,6 +198,9 @@ impl Orchestrator {
             turn_accumulators: Arc::new(TurnAccumulatorMap::new()),
             sequence_counters: Arc::new(DashMap::new()),
             compaction_handlers: Arc::new(DashMap::new()),
+            capability_policies: DashMap::new(),
+            pending_plans: DashMap::new(),
+            context_pressure: DashMap::new(),
             retain_in_flight: Arc::new(DashMap::new()),
             invocation_abort_registry: Arc::new(InvocationAbortRegistry::new()),
         }
@@ -356,6 +377,85 @@ impl Orchestrator {
         }
     }
 
+    // ── Capability policy ──
+
+    /// Narrow a session's capability policy by composing `policy` onto it.
+    ///
+    /// Restrictions from independent sources accumulate; none can widen what
+    /// an earlier one refused. Applies from the session's next run.
+    pub fn restrict_capability_policy(&self, session_id: &str, policy: &CapabilityPolicy) {
+        let mut entry = self
+            .capability_policies
+            .entry(session_id.to_string())
+            .or_default();
+        *entry = entry.compose(policy);
+        trace!(session_id, "capability policy restricted");
+    }
+
+    /// The capability policy for a session's next run.
+    pub fn capability_policy(&self, session_id: &str) -> CapabilityPolicy {
+        self.capability_policies
+            .get(session_id)
+            .map(|entry| entry.value().clone())
+            .unwrap_or_default()
+    }
+
+    /// Re-apply the capability policy persisted with a resumed session.
+    ///
+    /// Composition is idempotent, so restoring onto a session already
+    /// restricted in memory changes nothing.
+    pub fn restore_capability_policy(&self, session_id: &str, state: &ReconstructedState) {
+        if let Some(policy) = state.capability_policy.as_ref() {
+            self.restrict_capability_policy(session_id, policy);
+        }
+    }
+
+    /// Drop a session's capability policy, making it unrestricted again.
+    pub fn clear_capability_policy(&self, session_id: &str) {
+        if self.capability_policies.remove(session_id).is_some() {
+            trace!(session_id, "capability policy cleared");
+        }
+    }
+
+    // ── Pending plans ──
+
+    /// Hold a session's plan until its next prompt approves or declines it.
+    pub fn store_pending_plan(&self, session_id: &str, plan: PlannedInvocations) {
+        let _ = self.pending_plans.insert(session_id.to_string(), plan);
+        trace!(session_id, "pending plan stored");
+    }
+
+    /// Remove and return a session's plan awaiting approval.
+    pub fn take_pending_plan(&self, session_id: &str) -> Option<PlannedInvocations> {
+        self.pending_plans.remove(session_id).map(|(_, plan)| plan)
+    }
+
+    /// Whether a session has a plan awaiting approval.
+    pub fn has_pending_plan(&self, session_id: &str) -> bool {
+        self.pending_plans.contains_key(session_id)
+    }
+
+    // ── Context pressure ──
+
+    /// Keep a session's context-pressure tracker for its next prompt.
+    pub fn store_context_pressure(&self, session_id: &str, tracker: ContextPressureTracker) {
+        let _ = self
+            .context_pressure
+            .insert(session_id.to_string(), tracker);
+    }
+
+    /// A session's context-pressure tracker from its last prompt.
+    pub fn context_pressure(&self, session_id: &str) -> Option<ContextPressureTracker> {
+        self.context_pressure
+            .get(session_id)
+            .map(|tracker| tracker.clone())
+    }
+
+    /// Forget a session's context-pressure tracker.
+    pub fn clear_context_pressure(&self, session_id: &str) {
+        let _ = self.context_pressure.remove(session_id);
+    }
+
     /// Start tracking a run for a session.
     ///
     /// Errors if:
@@ -506,9 +606,13 @@ impl Orchestrator {
         // Cancel all pending capability invocations
         self.capability_invocation_tracker.lock().cancel_all();
 
-        // Clear all sequence counters and compaction handlers
+        // Clear all sequence counters, compaction handlers, policies, plans,
+        // and pressure trackers
         self.sequence_counters.clear();
         self.compaction_handlers.clear();
+        self.capability_policies.clear();
+        self.pending_plans.clear();
+        self.context_pressure.clear();
 
         // List all active sessions and end them
         let sessions = self
@@ -526,5 +630,7 @@ impl Orchestrator {
     }
 }
 
+mod idle_sweep;
+
 #[cfg(test)]
 mod tests;
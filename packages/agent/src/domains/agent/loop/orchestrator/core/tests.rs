@@ -522,3 +522,146 @@ async fn shutdown_clears_orphaned_runs() {
         "active_runs must be cleared after shutdown"
     );
 }
+
+#[tokio::test]
+async fn idle_sweep_stops_idle_sessions_and_emits_idle_timeout() {
+    let orch = make_orchestrator();
+    let mut rx = orch.subscribe();
+    let sid = orch
+        .session_manager()
+        .create_session("model", "/tmp", None)
+        .unwrap();
+    let timeout = std::time::Duration::from_secs(3600);
+
+    let early = std::time::Instant::now() + std::time::Duration::from_secs(60);
+    assert!(orch.sweep_idle_sessions(timeout, early).await.is_empty());
+    assert_eq!(orch.active_session_count(), 1);
+
+    let later = std::time::Instant::now() + std::time::Duration::from_secs(7200);
+    assert_eq!(
+        orch.sweep_idle_sessions(timeout, later).await,
+        vec![sid.clone()]
+    );
+    assert_eq!(orch.active_session_count(), 0);
+
+    let event = rx.try_recv().unwrap();
+    assert_eq!(event.event_type(), "session.idle_timeout");
+    assert_eq!(event.session_id(), sid);
+    match event {
+        TronEvent::SessionIdleTimeout {
+            idle_timeout_secs, ..
+        } => assert_eq!(idle_timeout_secs, 3600),
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    let _ = orch.session_manager().resume_session(&sid).unwrap();
+    assert_eq!(orch.active_session_count(), 1);
+}
+
+#[tokio::test]
+async fn idle_sweep_retains_sessions_with_active_runs() {
+    let orch = make_orchestrator();
+    let mut rx = orch.subscribe();
+    let running = orch
+        .session_manager()
+        .create_session("model", "/tmp/a", None)
+        .unwrap();
+    let processing = orch
+        .session_manager()
+        .create_session("model", "/tmp/b", None)
+        .unwrap();
+    let _run = orch.begin_run(&running, "run-1").unwrap();
+    assert!(orch.session_manager().mark_processing(&processing));
+
+    let later = std::time::Instant::now() + std::time::Duration::from_secs(7200);
+    let swept = orch
+        .sweep_idle_sessions(std::time::Duration::from_secs(60), later)
+        .await;
+
+    assert!(swept.is_empty());
+    assert_eq!(orch.active_session_count(), 2);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn capability_policy_restrictions_compose_per_session() {
+    use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
+
+    let orch = make_orchestrator();
+    assert_eq!(orch.capability_policy("s1"), CapabilityPolicy::default());
+
+    orch.restrict_capability_policy(
+        "s1",
+        &CapabilityPolicy::allow_only(["file_read", "file_write"]),
+    );
+    orch.restrict_capability_policy("s1", &CapabilityPolicy::deny(["file_write"]));
+    let policy = orch.capability_policy("s1");
+    let write = serde_json::Map::from_iter([("operation".to_owned(), json!("file_write"))]);
+    assert_eq!(
+        policy.refusal("execute", &write).as_deref(),
+        Some("file_write")
+    );
+    assert_eq!(orch.capability_policy("s2"), CapabilityPolicy::default());
+
+    orch.clear_capability_policy("s1");
+    assert_eq!(orch.capability_policy("s1"), CapabilityPolicy::default());
+}
+
+#[test]
+fn pending_plans_outlive_the_run_and_are_taken_once() {
+    use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
+    use crate::domains::agent::r#loop::primitive_surface::ResolvedPrimitiveSurface;
+    use crate::domains::agent::r#loop::types::PlannedInvocations;
+    use crate::shared::protocol::messages::CapabilityInvocationDraft;
+
+    let orch = make_orchestrator();
+    assert!(!orch.has_pending_plan("s1"));
+
+    orch.store_pending_plan(
+        "s1",
+        PlannedInvocations {
+            turn: 3,
+            invocations: vec![CapabilityInvocationDraft::new(
+                "tc-1",
+                "execute",
+                serde_json::Map::new(),
+            )],
+            primitive_surface: ResolvedPrimitiveSurface {
+                capabilities: Vec::new(),
+                targets_by_name: std::collections::BTreeMap::new(),
+                turn_stopping_capabilities: std::collections::HashSet::new(),
+            },
+            provider_type: "anthropic",
+            capability_policy: CapabilityPolicy::deny(["file_write"]),
+        },
+    );
+    assert!(orch.has_pending_plan("s1"));
+    assert!(!orch.has_pending_plan("s2"));
+
+    let plan = orch.take_pending_plan("s1").expect("stored plan");
+    assert_eq!(plan.turn, 3);
+    assert_eq!(
+        plan.capability_policy,
+        CapabilityPolicy::deny(["file_write"])
+    );
+    assert!(orch.take_pending_plan("s1").is_none());
+}
+
+#[test]
+fn context_pressure_is_kept_between_prompts_until_cleared() {
+    use crate::domains::model::tokens::pressure::ContextPressureTracker;
+
+    let orch = make_orchestrator();
+    assert!(orch.context_pressure("s1").is_none());
+
+    let mut tracker = ContextPressureTracker::default();
+    assert_eq!(tracker.record(75_000, 100_000).len(), 1);
+    orch.store_context_pressure("s1", tracker);
+
+    let mut next_prompt = ContextPressureTracker::default();
+    next_prompt.resume_from(&orch.context_pressure("s1").expect("stored tracker"));
+    assert!(next_prompt.record(76_000, 100_000).is_empty());
+
+    orch.clear_context_pressure("s1");
+    assert!(orch.context_pressure("s1").is_none());
+}
@@ -9,6 +9,7 @@
 //! | `session_reconstructor` | Rebuild session state from persisted events |
 //! | `session_context` | Per-session context and workspace path |
 //! | `agent_runner` | High-level primitive run and event ordering |
+//! | `agent_replay` | Reruns a recorded provider transcript through the turn loop for debugging |
 //! | `agent_factory` | Creates `TronAgent` instances with provider and `execute` capability |
 //! | `event_persister` | Persists agent events to the event store (supports pre-assigned sequences) |
 //! | `turn_accumulator` | In-memory per-session scratchpad of in-flight turn content for `session.reconstruct` |
@@ -68,6 +69,7 @@
 //! [`core::Orchestrator`] boundary.
 //!
 pub(crate) mod agent_factory;
+pub(crate) mod agent_replay;
 pub(crate) mod agent_runner;
 pub(crate) mod capability_invocation_tracker;
 pub(crate) mod core;
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
-use crate::domains::session::event_store::{AppendOptions, EventStore, EventType};
+use crate::domains::session::event_store::{AppendOptions, EventStore, EventType, SessionArchive};
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use serde_json::json;
@@ -211,6 +211,27 @@ impl SessionManager {
         })
     }
 
+    /// Export a session with its fork ancestry and referenced blobs.
+    pub fn export_session(&self, session_id: &str) -> Result<SessionArchive, RuntimeError> {
+        self.event_store
+            .export_session(session_id)
+            .map_err(|e| RuntimeError::Persistence(e.to_string()))
+    }
+
+    /// Import a session archive under fresh IDs, returning the new session ID.
+    pub fn import_session(&self, archive: &SessionArchive) -> Result<String, RuntimeError> {
+        let session = self
+            .event_store
+            .import_session(archive)
+            .map_err(|e| RuntimeError::Persistence(e.to_string()))?;
+        info!(
+            session_id = session.id,
+            archived_session_id = archive.session_id,
+            "session imported"
+        );
+        Ok(session.id)
+    }
+
     /// Archive a session.
     pub fn archive_session(&self, session_id: &str) -> Result<(), RuntimeError> {
         let _ = self.active_sessions.remove(session_id);
@@ -302,27 +323,40 @@ impl SessionManager {
     /// Evicted sessions are seamlessly reconstructed via `resume_session()`.
     /// Returns the number of sessions evicted.
     pub fn evict_idle_sessions(&self, ttl: Duration) -> usize {
-        let now = Instant::now();
-        let mut evicted = 0usize;
+        self.take_idle_sessions(ttl, Instant::now(), |_| false)
+            .len()
+    }
+
+    /// Remove and return sessions idle longer than `ttl` as of `now`.
+    ///
+    /// Processing sessions and sessions for which `keep` returns true stay
+    /// cached. Callers own the returned sessions and decide how to stop them.
+    pub fn take_idle_sessions(
+        &self,
+        ttl: Duration,
+        now: Instant,
+        keep: impl Fn(&str) -> bool,
+    ) -> Vec<(String, Arc<ActiveSession>)> {
+        let mut taken = Vec::new();
         self.active_sessions.retain(|session_id, cached| {
-            if cached.is_processing.load(Ordering::Relaxed) {
+            if cached.is_processing.load(Ordering::Relaxed) || keep(session_id) {
                 return true;
             }
             let last = *cached.last_accessed.lock();
-            let age = now.duration_since(last);
+            let age = now.saturating_duration_since(last);
             if age > ttl {
-                evicted += 1;
                 info!(
                     session_id,
                     age_secs = age.as_secs(),
                     "evicting idle session from cache"
                 );
+                taken.push((session_id.clone(), cached.session.clone()));
                 false
             } else {
                 true
             }
         });
-        evicted
+        taken
     }
 
     /// Mark a session as currently processing (prevents eviction).
@@ -179,6 +179,49 @@ async fn fork_session_from_nonexistent_event_fails() {
     );
 }
 
+#[tokio::test]
+async fn export_and_import_forked_session_across_managers() {
+    let source_mgr = make_manager();
+    let sid = source_mgr
+        .create_session("test-model", "/tmp", Some("test"))
+        .unwrap();
+    let _ = source_mgr
+        .event_store
+        .append(&crate::domains::session::event_store::AppendOptions {
+            session_id: &sid,
+            event_type: crate::domains::session::event_store::EventType::MessageUser,
+            payload: serde_json::json!({"content": "hello"}),
+            parent_id: None,
+            sequence: None,
+        })
+        .unwrap();
+    let fork = source_mgr
+        .fork_session(&sid, None, None, Some("forked"))
+        .unwrap();
+    let archive = source_mgr.export_session(&fork.new_session_id).unwrap();
+
+    let target_mgr = make_manager();
+    let imported = target_mgr.import_session(&archive).unwrap();
+    assert_ne!(imported, fork.new_session_id);
+    let row = target_mgr.get_session(&imported).unwrap().unwrap();
+    assert_eq!(row.title.as_deref(), Some("forked"));
+    assert!(row.parent_session_id.is_some());
+
+    let original = source_mgr.resume_session(&fork.new_session_id).unwrap();
+    let restored = target_mgr.resume_session(&imported).unwrap();
+    assert_eq!(restored.state.messages.len(), 1);
+    assert_eq!(
+        serde_json::to_value(&restored.state.messages).unwrap(),
+        serde_json::to_value(&original.state.messages).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn export_unknown_session_fails() {
+    let mgr = make_manager();
+    assert!(mgr.export_session("sess_missing").is_err());
+}
+
 #[tokio::test]
 async fn archive_and_unarchive() {
     let mgr = make_manager();
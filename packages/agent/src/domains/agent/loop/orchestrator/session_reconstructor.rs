@@ -1,8 +1,11 @@
 //! Session reconstructor — rebuild runtime state from event history.
 
-use crate::domains::session::event_store::{EventStore, SessionState};
+use crate::domains::session::event_store::{EventStore, EventType, SessionState};
 use crate::shared::protocol::messages::{Message, TokenUsage};
 
+use crate::domains::agent::r#loop::capability_invocation_executor::{
+    CAPABILITY_POLICY_METADATA_KEY, CapabilityPolicy,
+};
 use crate::domains::agent::r#loop::errors::RuntimeError;
 
 /// Reconstructed session state for resuming.
@@ -24,6 +27,9 @@ pub struct ReconstructedState {
     pub is_ended: bool,
     /// Last-seen reasoning level from `config.reasoning_level` events.
     pub reasoning_level: Option<String>,
+    /// Capability policy persisted for the session, composed across every
+    /// recorded restriction.
+    pub capability_policy: Option<CapabilityPolicy>,
 }
 
 /// Reconstruct session state from the event store.
@@ -35,7 +41,47 @@ pub fn reconstruct(
         .get_state_at_head(session_id)
         .map_err(|e| RuntimeError::Persistence(e.to_string()))?;
 
-    Ok(from_session_state(&state))
+    let mut reconstructed = from_session_state(&state);
+    reconstructed.capability_policy = persisted_capability_policy(event_store, session_id)?;
+    Ok(reconstructed)
+}
+
+/// Compose every capability policy recorded in the session's
+/// `metadata.update` events. A record that does not parse admits nothing
+/// rather than leaving the session unrestricted.
+fn persisted_capability_policy(
+    event_store: &EventStore,
+    session_id: &str,
+) -> Result<Option<CapabilityPolicy>, RuntimeError> {
+    let rows = event_store
+        .get_events_by_type(session_id, &[EventType::MetadataUpdate.as_str()], None)
+        .map_err(|e| RuntimeError::Persistence(e.to_string()))?;
+    let mut policy: Option<CapabilityPolicy> = None;
+    for row in rows {
+        let Ok(payload) = serde_json::from_str::<serde_json::Value>(&row.payload) else {
+            continue;
+        };
+        if payload.get("key").and_then(serde_json::Value::as_str)
+            != Some(CAPABILITY_POLICY_METADATA_KEY)
+        {
+            continue;
+        }
+        let value = payload.get("newValue").cloned().unwrap_or_default();
+        let recorded = serde_json::from_value::<CapabilityPolicy>(value).unwrap_or_else(|e| {
+            tracing::warn!(
+                session_id,
+                event_id = %row.id,
+                error = %e,
+                "session reconstructor: unreadable capability policy; denying all capabilities"
+            );
+            CapabilityPolicy::allow_only(Vec::<String>::new())
+        });
+        policy = Some(match policy {
+            Some(current) => current.compose(&recorded),
+            None => recorded,
+        });
+    }
+    Ok(policy)
 }
 
 /// Convert `SessionState` to `ReconstructedState`.
@@ -119,6 +165,7 @@ fn from_session_state(state: &SessionState) -> ReconstructedState {
             .total_tokens
             .filter(|&v| v > 0)
             .map(|v| v as u64),
+        cache_hit_ratio: None,
         provider_type: None,
     };
 
@@ -131,6 +178,7 @@ fn from_session_state(state: &SessionState) -> ReconstructedState {
         system_prompt: state.system_prompt.clone(),
         is_ended: state.is_ended.unwrap_or(false),
         reasoning_level: state.reasoning_level.clone(),
+        capability_policy: None,
     }
 }
 
@@ -163,6 +211,70 @@ mod tests {
         assert!(!state.is_ended);
     }
 
+    #[test]
+    fn reconstruct_composes_persisted_capability_policies() {
+        let store = make_store();
+        let session = store
+            .create_session("test-model", "/tmp", Some("test"), None)
+            .unwrap();
+        let sid = &session.session.id;
+        for value in [
+            serde_json::json!({"allowed": ["file_read", "file_write"]}),
+            serde_json::json!({"denied": ["file_write"]}),
+        ] {
+            let _ = store
+                .append(&AppendOptions {
+                    session_id: sid,
+                    event_type: EventType::MetadataUpdate,
+                    payload: serde_json::json!({
+                        "key": CAPABILITY_POLICY_METADATA_KEY,
+                        "newValue": value
+                    }),
+                    parent_id: None,
+                    sequence: None,
+                })
+                .unwrap();
+        }
+
+        let state = reconstruct(&store, sid).unwrap();
+        assert_eq!(
+            state.capability_policy,
+            Some(
+                CapabilityPolicy::allow_only(["file_read", "file_write"])
+                    .compose(&CapabilityPolicy::deny(["file_write"]))
+            )
+        );
+    }
+
+    #[test]
+    fn reconstruct_denies_everything_for_an_unreadable_capability_policy() {
+        let store = make_store();
+        let session = store
+            .create_session("test-model", "/tmp", Some("test"), None)
+            .unwrap();
+        let sid = &session.session.id;
+        let _ = store
+            .append(&AppendOptions {
+                session_id: sid,
+                event_type: EventType::MetadataUpdate,
+                payload: serde_json::json!({
+                    "key": CAPABILITY_POLICY_METADATA_KEY,
+                    "newValue": {"denied": "file_write"}
+                }),
+                parent_id: None,
+                sequence: None,
+            })
+            .unwrap();
+
+        let policy = reconstruct(&store, sid).unwrap().capability_policy.unwrap();
+        assert_eq!(
+            policy
+                .refusal("execute", &serde_json::Map::new())
+                .as_deref(),
+            Some("execute")
+        );
+    }
+
     #[test]
     fn reconstruct_with_messages() {
         let store = make_store();
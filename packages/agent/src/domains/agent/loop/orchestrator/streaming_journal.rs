@@ -24,7 +24,7 @@
 
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 use tracing::{debug, trace, warn};
@@ -63,7 +63,13 @@ impl StreamingJournal {
     /// Create a new journal for the given session/turn.
     /// Creates parent directories as needed.
     pub fn create(session_id: &str, turn: u32) -> io::Result<Self> {
-        let path = Self::journal_path(session_id, turn);
+        Self::create_in(&paths::journals_dir(), session_id, turn)
+    }
+
+    /// Create a new journal under `journals_dir` instead of the server's
+    /// journals directory. Journals written elsewhere are never recovered.
+    pub fn create_in(journals_dir: &Path, session_id: &str, turn: u32) -> io::Result<Self> {
+        let path = Self::journal_path_in(journals_dir, session_id, turn);
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -268,7 +274,11 @@ impl StreamingJournal {
 
     /// Path for a specific journal: `~/.tron/internal/database/journals/{session_id}/turn_{n}.wal`
     pub fn journal_path(session_id: &str, turn: u32) -> PathBuf {
-        paths::journals_dir()
+        Self::journal_path_in(&paths::journals_dir(), session_id, turn)
+    }
+
+    fn journal_path_in(journals_dir: &Path, session_id: &str, turn: u32) -> PathBuf {
+        journals_dir
             .join(session_id)
             .join(format!("turn_{turn}.wal"))
     }
@@ -300,24 +310,13 @@ mod tests {
         let session_id = "test-session-create";
         let turn = 1;
         let path = test_journal_path(tmp.path(), session_id, turn);
-        fs::create_dir_all(path.parent().unwrap()).unwrap();
 
-        // Create journal using the real constructor (it will create at the canonical path)
-        // For isolation, we test the path logic separately and verify file operations
-        let journal = StreamingJournal {
-            file: OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(&path)
-                .unwrap(),
-            path: path.clone(),
-            session_id: session_id.to_string(),
-            turn,
-        };
+        let journal = StreamingJournal::create_in(tmp.path(), session_id, turn).unwrap();
 
         assert!(path.exists());
-        drop(journal);
+        assert_eq!(journal.path, path);
+        journal.finalize_and_delete().unwrap();
+        assert!(!path.parent().unwrap().exists());
     }
 
     // ── Test 2: append_delta_writes_jsonl ─────────────────────────────────
@@ -350,6 +350,23 @@ mod tests {
         assert_eq!(record["pricing"]["cost"]["cacheWriteTokens"], 100_000);
     }
 
+    #[test]
+    fn build_token_record_maps_openai_cached_input_to_raw_cache_read() {
+        let usage = TokenUsage {
+            input_tokens: 8_000,
+            output_tokens: 500,
+            cache_read_tokens: Some(6_000),
+            cached_input_tokens: Some(6_000),
+            provider_type: Some(Provider::OpenAi),
+            ..Default::default()
+        };
+        let record = build_token_record(&usage, Provider::OpenAi, "s1", 1, 0, "gpt-4.1");
+        assert_eq!(record["source"]["rawCacheReadTokens"], 6_000);
+        assert_eq!(record["source"]["rawCachedInputTokens"], 6_000);
+        assert_eq!(record["pricing"]["cost"]["baseInputTokens"], 2_000);
+        assert_eq!(record["pricing"]["cost"]["cacheReadTokens"], 6_000);
+    }
+
     #[test]
     fn build_token_record_first_turn_all_new() {
         let usage = TokenUsage {
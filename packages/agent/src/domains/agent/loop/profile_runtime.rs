@@ -223,7 +223,9 @@ fn load_harness_spec(home: &Path) -> std::io::Result<ResolvedHarnessSpec> {
 
 fn effective_settings(settings: &TronSettings) -> std::io::Result<TronSettings> {
     let mut settings = settings.clone();
-    crate::domains::settings::profile::apply_env_overrides(&mut settings);
+    for error in crate::domains::settings::profile::apply_env_overrides(&mut settings) {
+        tracing::warn!(%error, "skipping invalid settings env override");
+    }
     settings.validate();
     settings.validate_strict().map_err(|error| {
         std::io::Error::new(
@@ -1,43 +1,63 @@
-use serde_json::Map;
+use serde_json::{Map, Value};
 
 use crate::shared::protocol::content::AssistantContent;
 use crate::shared::protocol::events::AssistantMessage;
 use crate::shared::protocol::messages::CapabilityInvocationDraft;
 
+/// How [`finalize_capability_invocation`] closed the in-flight invocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum FinalizedInvocation {
+    /// Nothing was in flight, or no argument deltas arrived.
+    Skipped,
+    /// Arguments parsed as a complete JSON object.
+    Complete,
+    /// Arguments were unclosed or malformed; the invocation was dropped.
+    Discarded(String),
+}
+
 /// Finalize an in-progress capability invocation from accumulated deltas.
+///
+/// A provider stream that stops mid-invocation (a dropped connection or a
+/// `max_tokens` stop) leaves unclosed argument JSON. Such an invocation is
+/// discarded, never executed with a subset of its members: a dropped
+/// `dryRun` or `workingDirectory` would change what the call does.
 pub(super) fn finalize_capability_invocation(
     capability_invocations: &mut Vec<CapabilityInvocationDraft>,
     current_id: &mut Option<String>,
     current_name: &mut Option<String>,
     current_args: &mut String,
-) {
-    if let (Some(id), Some(name)) = (current_id.take(), current_name.take()) {
-        if current_args.trim().is_empty() {
+) -> FinalizedInvocation {
+    let (Some(id), Some(name)) = (current_id.take(), current_name.take()) else {
+        return FinalizedInvocation::Skipped;
+    };
+    if current_args.trim().is_empty() {
+        current_args.clear();
+        return FinalizedInvocation::Skipped;
+    }
+    let arguments = match serde_json::from_str::<Map<String, Value>>(current_args) {
+        Ok(map) => map,
+        Err(e) => {
+            tracing::warn!(
+                component = "agent.stream",
+                agent_event = "stream_capability_invocation_arguments_malformed",
+                model_primitive_name = %name,
+                invocation_id = %id,
+                error = %e,
+                args_len = current_args.len(),
+                "malformed capability invocation arguments, discarding invocation"
+            );
             current_args.clear();
-            return;
-        }
-        let arguments: Map<String, serde_json::Value> = match serde_json::from_str(current_args) {
-            Ok(map) => map,
-            Err(e) => {
-                tracing::warn!(
-                    component = "agent.stream",
-                    agent_event = "stream_capability_invocation_arguments_malformed",
-                    model_primitive_name = %name,
-                    invocation_id = %id,
-                    error = %e,
-                    args_len = current_args.len(),
-                    "malformed capability invocation arguments, using empty map"
-                );
-                Map::new()
-            }
-        };
-        if let Some(pos) = capability_invocations.iter().position(|tc| tc.id == id) {
-            capability_invocations[pos] = CapabilityInvocationDraft::new(id, name, arguments);
-        } else {
-            capability_invocations.push(CapabilityInvocationDraft::new(id, name, arguments));
+            capability_invocations.retain(|tc| tc.id != id);
+            return FinalizedInvocation::Discarded(id);
         }
-        current_args.clear();
+    };
+    if let Some(pos) = capability_invocations.iter().position(|tc| tc.id == id) {
+        capability_invocations[pos] = CapabilityInvocationDraft::new(id, name, arguments);
+    } else {
+        capability_invocations.push(CapabilityInvocationDraft::new(id, name, arguments));
     }
+    current_args.clear();
+    FinalizedInvocation::Complete
 }
 
 /// Build an `AssistantMessage` from accumulated parts.
@@ -86,9 +86,9 @@ pub async fn process_stream_with_trace(
 
         match event {
             None => {
-                return Err(RuntimeError::Internal(
-                    "Stream ended without Done event".into(),
-                ));
+                return Err(state.truncated_stream_error().unwrap_or_else(|| {
+                    RuntimeError::Internal("Stream ended without Done event".into())
+                }));
             }
             Some(Err(error)) if error.is_cancelled() => {
                 return Ok(state.build_interrupted_result());
@@ -132,7 +132,7 @@ pub async fn process_stream_with_trace(
         }
     }
 
-    Ok(state.finalize_stream_result(final_message, stop_reason))
+    state.finalize_stream_result(final_message, stop_reason)
 }
 
 #[cfg(test)]
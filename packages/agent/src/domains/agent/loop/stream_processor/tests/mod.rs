@@ -1,11 +1,14 @@
 use super::*;
 mod drain;
 mod stream_state;
+mod truncation;
 use async_stream::stream;
 use std::collections::HashSet;
 use std::pin::Pin;
 
-use super::super::stream_state::{build_message, finalize_capability_invocation};
+use super::super::stream_message::{
+    FinalizedInvocation, build_message, finalize_capability_invocation,
+};
 use crate::domains::model::responder::{ModelResponseError, ModelResponseStream};
 use crate::shared::protocol::content::AssistantContent;
 use crate::shared::protocol::events::{AssistantMessage, RetryErrorInfo, StreamEvent, TronEvent};
@@ -135,12 +135,15 @@ fn finalize_capability_invocation_with_malformed_json() {
     let mut name = Some("inspect".to_string());
     let mut args = "{ not valid".to_string();
 
-    finalize_capability_invocation(&mut capability_invocations, &mut id, &mut name, &mut args);
+    let outcome =
+        finalize_capability_invocation(&mut capability_invocations, &mut id, &mut name, &mut args);
 
-    assert_eq!(capability_invocations.len(), 1);
-    assert_eq!(capability_invocations[0].name, "inspect");
-    assert_eq!(capability_invocations[0].id, "tc-2");
-    assert!(capability_invocations[0].arguments.is_empty());
+    assert_eq!(outcome, FinalizedInvocation::Discarded("tc-2".into()));
+    assert!(
+        capability_invocations.is_empty(),
+        "malformed arguments must not be recorded as an empty map"
+    );
+    assert!(args.is_empty());
 }
 
 #[test]
@@ -274,7 +277,7 @@ fn malformed_capability_arguments_log_length_not_preview() {
     let mut capability_invocations = Vec::new();
     let mut id = Some("tc-sensitive".to_string());
     let mut name = Some("execute".to_string());
-    let secret_args = r#"{"content":"secret-file-content""#.to_string();
+    let secret_args = r#"{"content":"secret-file-content"#.to_string();
     let mut malformed_args = secret_args.clone();
 
     finalize_capability_invocation(
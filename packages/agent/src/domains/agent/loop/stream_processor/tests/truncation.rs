@@ -0,0 +1,93 @@
+use super::*;
+
+/// A capability invocation whose argument deltas stop at `args`, followed by
+/// `Done` (provider closed the message) or nothing (connection dropped).
+fn truncated_invocation_stream(args: &'static str, send_done: bool) -> ModelResponseStream {
+    let s = stream! {
+        yield Ok(StreamEvent::Start);
+        yield Ok(StreamEvent::CapabilityInvocationDraftStart {
+            invocation_id: "tc-cut".into(),
+            name: "execute".into(),
+        });
+        yield Ok(StreamEvent::CapabilityInvocationDraftDelta {
+            invocation_id: "tc-cut".into(),
+            arguments_delta: args.into(),
+        });
+        if send_done {
+            yield Ok(StreamEvent::Done {
+                message: AssistantMessage {
+                    content: vec![AssistantContent::CapabilityInvocation {
+                        id: "tc-cut".into(),
+                        name: "execute".into(),
+                        arguments: serde_json::Map::new(),
+                        thought_signature: None,
+                    }],
+                    token_usage: None,
+                },
+                stop_reason: "max_tokens".into(),
+            });
+        }
+    };
+    Box::pin(s)
+}
+
+async fn run(stream: ModelResponseStream) -> Result<StreamResult, RuntimeError> {
+    process_stream(
+        stream,
+        "s-truncated",
+        &make_emitter(),
+        &CancellationToken::new(),
+        &no_stopping_capabilities(),
+        None,
+        None,
+    )
+    .await
+}
+
+fn assert_retryable_truncation(result: Result<StreamResult, RuntimeError>) {
+    match result {
+        Err(RuntimeError::ModelResponse(error)) => {
+            assert!(error.is_retryable(), "{error}");
+            assert_eq!(error.failure().code, "MODEL_STREAM_TRUNCATED");
+        }
+        other => panic!("expected retryable truncation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn truncated_invocation_is_never_executed_with_leading_members() {
+    // The complete `operation` member must not be salvaged: the dropped
+    // trailing members could have been `dryRun` or `workingDirectory`.
+    assert_retryable_truncation(
+        run(truncated_invocation_stream(
+            r#"{"operation":"process_run","command":"rm -rf build","dryRun":tr"#,
+            true,
+        ))
+        .await,
+    );
+    assert_retryable_truncation(
+        run(truncated_invocation_stream(
+            r#"{"operation":"observe","input":"a.txt""#,
+            true,
+        ))
+        .await,
+    );
+}
+
+#[tokio::test]
+async fn truncated_invocation_with_open_string_is_retryable_error() {
+    assert_retryable_truncation(
+        run(truncated_invocation_stream(
+            r#"{"command":"rm -rf /tm"#,
+            true,
+        ))
+        .await,
+    );
+}
+
+#[tokio::test]
+async fn stream_dropped_mid_invocation_is_retryable_error() {
+    assert_retryable_truncation(
+        run(truncated_invocation_stream(r#"{"command":"ls""#, false)).await,
+    );
+}
@@ -6,7 +6,8 @@
 //! that the caller (`process_stream`) uses to drive the select loop.
 //!
 //! Pure message/finalization helpers live in the sibling `stream_message`
-//! module and are re-exported here for focused stream tests.
+//! module; handling for invocations the provider never closed lives in
+//! `stream_truncation`.
 
 use std::collections::HashSet;
 use std::sync::atomic::AtomicI64;
@@ -19,9 +20,7 @@ use crate::shared::protocol::messages::{CapabilityInvocationDraft, TokenUsage};
 use crate::domains::agent::r#loop::errors::RuntimeError;
 use crate::domains::agent::r#loop::event_emitter::EventEmitter;
 use crate::domains::agent::r#loop::orchestrator::streaming_journal::StreamingJournal;
-pub(super) use crate::domains::agent::r#loop::stream_message::{
-    build_message, finalize_capability_invocation,
-};
+use crate::domains::agent::r#loop::stream_message::build_message;
 
 /// What `process_stream` should do after handling one event.
 pub(super) enum StreamAction {
@@ -75,6 +74,8 @@ pub(super) struct StreamState {
     /// When true, skip all content events (text, thinking, capability invocations) but keep
     /// reading the stream to capture token usage from the Done event.
     pub(super) draining: bool,
+    /// Invocation ids dropped because their arguments were unclosed or malformed.
+    pub(super) discarded_invocations: Vec<String>,
 }
 
 impl StreamState {
@@ -91,6 +92,7 @@ impl StreamState {
             stream_start: Instant::now(),
             ttft_ms: None,
             draining: false,
+            discarded_invocations: Vec::new(),
         }
     }
 
@@ -219,12 +221,7 @@ impl StreamState {
         counter: Option<&AtomicI64>,
         trace_context: StreamTraceContext<'_>,
     ) {
-        finalize_capability_invocation(
-            &mut self.capability_invocations,
-            &mut self.current_invocation_id,
-            &mut self.current_model_primitive_name,
-            &mut self.current_capability_args,
-        );
+        self.finalize_current_invocation();
 
         self.current_invocation_id = Some(invocation_id.clone());
         self.current_model_primitive_name = Some(name.clone());
@@ -348,17 +345,19 @@ impl StreamState {
         }
     }
 
+    /// Build the final stream result.
+    ///
+    /// Fails with a retryable error when any invocation's arguments were
+    /// unclosed or malformed, so no partial call is recorded or executed.
     pub(super) fn finalize_stream_result(
         mut self,
         final_message: Option<AssistantMessage>,
         stop_reason: String,
-    ) -> crate::domains::agent::r#loop::types::StreamResult {
-        finalize_capability_invocation(
-            &mut self.capability_invocations,
-            &mut self.current_invocation_id,
-            &mut self.current_model_primitive_name,
-            &mut self.current_capability_args,
-        );
+    ) -> Result<crate::domains::agent::r#loop::types::StreamResult, RuntimeError> {
+        self.finalize_current_invocation();
+        if let Some(error) = self.discarded_invocations_error() {
+            return Err(error);
+        }
 
         let message = final_message.unwrap_or_else(|| {
             build_message(
@@ -369,7 +368,7 @@ impl StreamState {
             )
         });
 
-        crate::domains::agent::r#loop::types::StreamResult {
+        Ok(crate::domains::agent::r#loop::types::StreamResult {
             message,
             capability_invocations: self.capability_invocations,
             stop_reason,
@@ -377,7 +376,7 @@ impl StreamState {
             interrupted: false,
             partial_content: None,
             ttft_ms: self.ttft_ms,
-        }
+        })
     }
 
     /// Handle a stream event while in drain mode (after a stopping capability completed).
@@ -0,0 +1,53 @@
+//! Truncated capability invocation handling for [`StreamState`].
+//!
+//! A provider stream can stop while an invocation is still streaming its
+//! arguments. The in-flight invocation is closed through
+//! `finalize_capability_invocation`; unclosed or malformed arguments are
+//! discarded and surface as a retryable `truncated_stream` error rather than
+//! being recorded or executed with a subset of their members.
+
+use crate::domains::agent::r#loop::errors::RuntimeError;
+use crate::domains::agent::r#loop::stream_message::{
+    FinalizedInvocation, finalize_capability_invocation,
+};
+use crate::domains::agent::r#loop::stream_state::StreamState;
+use crate::domains::model::responder::ModelResponseError;
+
+impl StreamState {
+    /// Close the in-flight capability invocation, recording a discard.
+    pub(super) fn finalize_current_invocation(&mut self) {
+        if let FinalizedInvocation::Discarded(id) = finalize_capability_invocation(
+            &mut self.capability_invocations,
+            &mut self.current_invocation_id,
+            &mut self.current_model_primitive_name,
+            &mut self.current_capability_args,
+        ) {
+            self.discarded_invocations.push(id);
+        }
+    }
+
+    /// Retryable error for a stream that ended while an invocation was still
+    /// streaming its arguments, if one was.
+    pub(super) fn truncated_stream_error(&self) -> Option<RuntimeError> {
+        let id = self.current_invocation_id.as_deref()?;
+        Some(RuntimeError::ModelResponse(
+            ModelResponseError::truncated_stream(format!(
+                "model stream ended before capability invocation {id} completed"
+            )),
+        ))
+    }
+
+    /// Retryable error naming every invocation discarded during the stream,
+    /// if any were.
+    pub(super) fn discarded_invocations_error(&self) -> Option<RuntimeError> {
+        if self.discarded_invocations.is_empty() {
+            return None;
+        }
+        Some(RuntimeError::ModelResponse(
+            ModelResponseError::truncated_stream(format!(
+                "model stream truncated capability invocation arguments: {}",
+                self.discarded_invocations.join(", ")
+            )),
+        ))
+    }
+}
@@ -1,5 +1,6 @@
 //! `TronAgent` multi-turn primitive loop.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
 
@@ -10,8 +11,11 @@ use crate::domains::agent::r#loop::event_emitter::EventEmitter;
 use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister;
 use crate::domains::agent::r#loop::orchestrator::invocation_abort_registry::InvocationAbortRegistry;
 use crate::domains::agent::r#loop::turn_runner;
-use crate::domains::agent::r#loop::types::{AgentConfig, RunContext, RunResult};
+use crate::domains::agent::r#loop::types::{
+    AgentConfig, PlannedInvocations, RunContext, RunInput, RunResult,
+};
 use crate::domains::model::responder::ModelResponder;
+use crate::domains::model::tokens::pressure::ContextPressureTracker;
 use crate::shared::protocol::events::{BaseEvent, TronEvent};
 use crate::shared::protocol::messages::{Message, TokenUsage, UserMessageContent};
 use tokio::sync::broadcast;
@@ -51,6 +55,7 @@ pub struct TronAgent {
     compaction: Arc<CompactionHandler>,
     session_id: String,
     completed_turn_offset: AtomicU32,
+    session_cost_usd: f64,
     current_turn: AtomicU32,
     is_running: AtomicBool,
     abort_token: CancellationToken,
@@ -59,6 +64,7 @@ pub struct TronAgent {
     sequence_counter: Option<Arc<AtomicI64>>,
     invocation_abort_registry: Option<Arc<InvocationAbortRegistry>>,
     engine_host: Option<crate::engine::EngineHostHandle>,
+    journal_dir: Option<PathBuf>,
 }
 
 impl TronAgent {
@@ -71,6 +77,7 @@ impl TronAgent {
             compaction: Arc::new(CompactionHandler::new(deps.compaction_trigger_config)),
             session_id,
             completed_turn_offset: AtomicU32::new(0),
+            session_cost_usd: 0.0,
             current_turn: AtomicU32::new(0),
             is_running: AtomicBool::new(false),
             abort_token: CancellationToken::new(),
@@ -79,12 +86,43 @@ impl TronAgent {
             sequence_counter: None,
             invocation_abort_registry: None,
             engine_host: deps.engine_host,
+            journal_dir: None,
         }
     }
 
+    /// Run the loop for a new user message.
+    pub async fn run(&mut self, content: &str, ctx: RunContext) -> RunResult {
+        self.drive(RunInput::Prompt(content), ctx).await
+    }
+
+    /// Execute the invocations a `plan_only` run stopped on, then continue
+    /// the loop from the following turn.
+    ///
+    /// The plan runs under the capability policy captured when it was made,
+    /// composed with `ctx`'s, so approving it can only narrow what the
+    /// session allowed. The session cost ceiling is checked before anything
+    /// runs; a plan refused for cost is handed back in
+    /// [`RunResult::pending_plan`].
+    pub async fn approve(&mut self, plan: PlannedInvocations, ctx: RunContext) -> RunResult {
+        self.drive(RunInput::ApprovedPlan(plan), ctx).await
+    }
+
+    /// Close out a plan that will not run, recording an error result for
+    /// each of its invocations.
+    pub async fn decline_plan(&mut self, plan: &PlannedInvocations) {
+        turn_runner::decline_plan(
+            &mut self.context_manager,
+            self.persister.as_deref(),
+            &self.session_id,
+            self.sequence_counter.as_ref().map(|c| c.as_ref()),
+            plan,
+        )
+        .await;
+    }
+
     #[allow(clippy::too_many_lines)]
-    #[instrument(skip(self, ctx), fields(session_id = %self.session_id, model = %self.config.model))]
-    pub async fn run(&mut self, content: &str, mut ctx: RunContext) -> RunResult {
+    #[instrument(skip(self, input, ctx), fields(session_id = %self.session_id, model = %self.config.model))]
+    async fn drive(&mut self, input: RunInput<'_>, mut ctx: RunContext) -> RunResult {
         let Some(_guard) = RunGuard::new(&self.is_running) else {
             warn!(
                 component = "agent.loop",
@@ -100,6 +138,15 @@ impl TronAgent {
             };
         };
 
+        let (content, approved_plan) = match input {
+            RunInput::Prompt(content) => (Some(content), None),
+            RunInput::ApprovedPlan(mut plan) => {
+                plan.capability_policy = plan.capability_policy.compose(&ctx.capability_policy);
+                ctx.capability_policy = plan.capability_policy.clone();
+                (None, Some(plan))
+            }
+        };
+
         if !self.external_abort_token {
             self.abort_token = CancellationToken::new();
         }
@@ -109,19 +156,26 @@ impl TronAgent {
         let mut final_stop_reason = StopReason::EndTurn;
         let mut interrupted = false;
         let mut error: Option<String> = None;
-
-        let user_content = ctx
-            .user_content_override
-            .take()
-            .unwrap_or_else(|| UserMessageContent::Text(content.to_owned()));
-        let user_content_kind = match &user_content {
-            UserMessageContent::Text(_) => "text",
-            UserMessageContent::Blocks(_) => "blocks",
+        let mut planned_invocations = Vec::new();
+        let mut pending_plan = None;
+
+        let user_content_kind = if let Some(content) = content {
+            let user_content = ctx
+                .user_content_override
+                .take()
+                .unwrap_or_else(|| UserMessageContent::Text(content.to_owned()));
+            let user_content_kind = match &user_content {
+                UserMessageContent::Text(_) => "text",
+                UserMessageContent::Blocks(_) => "blocks",
+            };
+            self.context_manager.add_message(Message::User {
+                content: user_content,
+                timestamp: None,
+            });
+            user_content_kind
+        } else {
+            "approved_plan"
         };
-        self.context_manager.add_message(Message::User {
-            content: user_content,
-            timestamp: None,
-        });
 
         let run_base = |session_id: &str| {
             BaseEvent::now(session_id).with_trace_context(
@@ -163,7 +217,49 @@ impl TronAgent {
         let mut previous_context_baseline =
             self.context_manager.get_api_context_tokens().unwrap_or(0);
 
-        while run_turn < max_turns {
+        if let Some(plan) = approved_plan {
+            if self.cost_ceiling_reached() {
+                final_stop_reason = StopReason::CostLimitExceeded;
+                exited_via_break = true;
+                planned_invocations.clone_from(&plan.invocations);
+                pending_plan = Some(plan);
+            } else {
+                let result = turn_runner::execute_approved_plan(
+                    turn_runner::TurnParams {
+                        turn: plan.turn,
+                        context_manager: &mut self.context_manager,
+                        responder: &self.responder,
+                        compaction: &self.compaction,
+                        session_id: &self.session_id,
+                        emitter: &self.emitter,
+                        cancel: &self.abort_token,
+                        run_context: &ctx,
+                        persister: self.persister.as_deref(),
+                        previous_context_baseline,
+                        retry_config: self.config.retry.as_ref(),
+                        workspace_id: self.config.workspace_id.as_deref(),
+                        server_origin: self.config.server_origin.as_deref(),
+                        sequence_counter: self.sequence_counter.as_ref().map(|c| c.as_ref()),
+                        invocation_abort_registry: self.invocation_abort_registry.as_ref(),
+                        engine_host: self.engine_host.as_ref(),
+                        journal_dir: self.journal_dir.as_deref(),
+                    },
+                    &plan,
+                )
+                .await;
+                if result.stop_turn_requested {
+                    final_stop_reason = StopReason::CapabilityStop;
+                    exited_via_break = true;
+                }
+            }
+        }
+
+        while !exited_via_break && run_turn < max_turns {
+            if self.cost_ceiling_reached() {
+                final_stop_reason = StopReason::CostLimitExceeded;
+                exited_via_break = true;
+                break;
+            }
             run_turn += 1;
             let session_turn = turn_offset.saturating_add(run_turn);
             self.current_turn.store(session_turn, Ordering::Relaxed);
@@ -194,12 +290,16 @@ impl TronAgent {
                 sequence_counter: self.sequence_counter.as_ref().map(|c| c.as_ref()),
                 invocation_abort_registry: self.invocation_abort_registry.as_ref(),
                 engine_host: self.engine_host.as_ref(),
+                journal_dir: self.journal_dir.as_deref(),
             })
             .await;
 
             if let Some(cw) = result.context_window_tokens {
                 previous_context_baseline = cw;
             }
+            if let Some(cost) = result.cost {
+                self.session_cost_usd += cost;
+            }
 
             if let Some(ref usage) = result.token_usage {
                 total_usage.input_tokens += usage.input_tokens;
@@ -219,7 +319,7 @@ impl TronAgent {
                     error = ?result.error,
                     "turn failed"
                 );
-                final_stop_reason = StopReason::Error;
+                final_stop_reason = result.stop_reason.unwrap_or(StopReason::Error);
                 error = result.error;
                 exited_via_break = true;
                 break;
@@ -233,14 +333,25 @@ impl TronAgent {
                 break;
             }
 
+            if let Some(plan) = result.planned {
+                planned_invocations.clone_from(&plan.invocations);
+                pending_plan = Some(plan);
+                final_stop_reason = StopReason::AwaitingApproval;
+                exited_via_break = true;
+                break;
+            }
+
             if result.stop_turn_requested {
                 final_stop_reason = StopReason::CapabilityStop;
                 exited_via_break = true;
                 break;
             }
 
-            if let Some(StopReason::EndTurn | StopReason::NoCapabilityInvocationDrafts) =
-                result.stop_reason
+            if let Some(
+                StopReason::EndTurn
+                | StopReason::GuardrailBlocked
+                | StopReason::NoCapabilityInvocationDrafts,
+            ) = result.stop_reason
             {
                 final_stop_reason = result.stop_reason.unwrap_or(StopReason::EndTurn);
                 exited_via_break = true;
@@ -289,9 +400,30 @@ impl TronAgent {
             } else {
                 None
             },
+            planned_invocations,
+            pending_plan,
         }
     }
 
+    /// Whether the session has spent up to its cost ceiling.
+    fn cost_ceiling_reached(&self) -> bool {
+        let Some(max_cost_usd) = self.config.max_cost_usd else {
+            return false;
+        };
+        if self.session_cost_usd < max_cost_usd {
+            return false;
+        }
+        warn!(
+            component = "agent.loop",
+            agent_event = "agent_cost_limit_reached",
+            session_id = %self.session_id,
+            session_cost_usd = self.session_cost_usd,
+            max_cost_usd,
+            "session cost ceiling reached; refusing next turn"
+        );
+        true
+    }
+
     fn emit_run_event(&self, event: TronEvent) {
         if let Some(ref counter) = self.sequence_counter {
             let _ = self.emitter.emit_sequenced(event, counter);
@@ -316,6 +448,12 @@ impl TronAgent {
         self.compaction.set_context_control(deps);
     }
 
+    /// Write streaming journals under `dir` instead of the server's journals
+    /// directory, so crash recovery never picks them up.
+    pub fn set_journal_dir(&mut self, dir: PathBuf) {
+        self.journal_dir = Some(dir);
+    }
+
     pub fn set_sequence_counter(&mut self, counter: Arc<AtomicI64>) {
         self.sequence_counter = Some(counter);
     }
@@ -324,6 +462,21 @@ impl TronAgent {
         self.completed_turn_offset.store(offset, Ordering::Relaxed);
     }
 
+    /// Seed the session's accumulated cost from before this agent was built.
+    pub fn set_session_cost(&mut self, cost_usd: f64) {
+        self.session_cost_usd = cost_usd;
+    }
+
+    /// Continue context-pressure tracking from the session's earlier prompts.
+    pub fn resume_context_pressure(&mut self, previous: &ContextPressureTracker) {
+        self.context_manager.resume_context_pressure(previous);
+    }
+
+    /// Context-pressure tracking state to keep for the session's next prompt.
+    pub fn context_pressure(&self) -> &ContextPressureTracker {
+        self.context_manager.context_pressure()
+    }
+
     pub fn set_invocation_abort_registry(&mut self, registry: Arc<InvocationAbortRegistry>) {
         self.invocation_abort_registry = Some(registry);
     }
@@ -346,5 +499,7 @@ impl TronAgent {
     }
 }
 
+#[cfg(test)]
+mod plan_tests;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,206 @@
+//! Plan-only runs: approval under the planning-time policy, the cost
+//! ceiling, and declined plans.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+
+use super::tests::{PrimitiveExecuteLoopResponder, make_primitive_loop_deps};
+use super::*;
+use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
+use crate::shared::protocol::messages::Message;
+
+fn plan_only_agent(
+    session_id: &str,
+    calls: &Arc<AtomicUsize>,
+    observed_result: &Arc<Mutex<Option<String>>>,
+    config: AgentConfig,
+) -> TronAgent {
+    let ctx = crate::shared::server::test_support::make_test_context();
+    TronAgent::new(
+        config,
+        make_primitive_loop_deps(
+            PrimitiveExecuteLoopResponder {
+                calls: calls.clone(),
+                observed_result: observed_result.clone(),
+            },
+            ctx.engine_host.clone(),
+        ),
+        session_id.into(),
+    )
+}
+
+async fn plan(agent: &mut TronAgent, capability_policy: CapabilityPolicy) -> RunResult {
+    agent
+        .run(
+            "call execute and continue",
+            crate::domains::agent::r#loop::types::RunContext {
+                plan_only: true,
+                capability_policy,
+                ..Default::default()
+            },
+        )
+        .await
+}
+
+fn capability_results(agent: &TronAgent) -> Vec<(String, Option<bool>)> {
+    agent
+        .context_manager()
+        .get_messages()
+        .iter()
+        .filter_map(|message| match message {
+            Message::CapabilityResult {
+                invocation_id,
+                is_error,
+                ..
+            } => Some((invocation_id.clone(), *is_error)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn plan_only_run_stops_before_execute_and_approve_runs_the_plan() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let observed_result = Arc::new(Mutex::new(None));
+    let mut agent = plan_only_agent(
+        "plan-only-session",
+        &calls,
+        &observed_result,
+        AgentConfig {
+            max_turns: 2,
+            ..AgentConfig::default()
+        },
+    );
+
+    let mut planned = plan(&mut agent, CapabilityPolicy::default()).await;
+
+    assert_eq!(planned.stop_reason, StopReason::AwaitingApproval);
+    assert_eq!(planned.turns_executed, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let planned_ids: Vec<_> = planned
+        .planned_invocations
+        .iter()
+        .map(|invocation| invocation.id.as_str())
+        .collect();
+    assert_eq!(planned_ids, ["tc-primitive-observe"]);
+    assert!(
+        capability_results(&agent).is_empty(),
+        "plan mode must not execute capabilities"
+    );
+
+    let pending = planned.pending_plan.take().expect("plan handed back");
+    let approved = agent
+        .approve(
+            pending,
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert!(
+        approved.error.is_none(),
+        "approval should succeed: {:?}",
+        approved.error
+    );
+    assert_eq!(approved.stop_reason, StopReason::EndTurn);
+    assert!(approved.planned_invocations.is_empty());
+    assert!(approved.pending_plan.is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(
+        observed_result
+            .lock()
+            .as_ref()
+            .is_some_and(|text| text.contains("primitive loop observed through execute"))
+    );
+}
+
+#[tokio::test]
+async fn approved_plan_runs_under_the_policy_it_was_planned_with() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let observed_result = Arc::new(Mutex::new(None));
+    let mut agent = plan_only_agent(
+        "plan-policy-session",
+        &calls,
+        &observed_result,
+        AgentConfig {
+            max_turns: 2,
+            ..AgentConfig::default()
+        },
+    );
+
+    let planned = plan(&mut agent, CapabilityPolicy::deny(["observe"])).await;
+    let approved = agent
+        .approve(
+            planned.pending_plan.expect("plan handed back"),
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(approved.stop_reason, StopReason::EndTurn);
+    assert_eq!(
+        capability_results(&agent),
+        [("tc-primitive-observe".to_owned(), Some(true))]
+    );
+    assert!(
+        observed_result
+            .lock()
+            .as_ref()
+            .is_some_and(|text| text.contains("capability observe not permitted")),
+        "the planning-time denial must survive an unrestricted approval context"
+    );
+}
+
+#[tokio::test]
+async fn approval_past_the_cost_ceiling_hands_the_plan_back_unexecuted() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let observed_result = Arc::new(Mutex::new(None));
+    let mut agent = plan_only_agent(
+        "plan-cost-session",
+        &calls,
+        &observed_result,
+        AgentConfig {
+            max_turns: 2,
+            max_cost_usd: Some(1.0),
+            ..AgentConfig::default()
+        },
+    );
+
+    let planned = plan(&mut agent, CapabilityPolicy::default()).await;
+    agent.set_session_cost(1.0);
+    let refused = agent
+        .approve(
+            planned.pending_plan.expect("plan handed back"),
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(refused.stop_reason, StopReason::CostLimitExceeded);
+    assert_eq!(refused.turns_executed, 0);
+    assert!(refused.pending_plan.is_some());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(capability_results(&agent).is_empty());
+}
+
+#[tokio::test]
+async fn declined_plan_closes_out_every_requested_invocation() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let observed_result = Arc::new(Mutex::new(None));
+    let mut agent = plan_only_agent(
+        "plan-decline-session",
+        &calls,
+        &observed_result,
+        AgentConfig::default(),
+    );
+
+    let planned = plan(&mut agent, CapabilityPolicy::default()).await;
+    agent
+        .decline_plan(planned.pending_plan.as_ref().expect("plan handed back"))
+        .await;
+
+    assert_eq!(
+        capability_results(&agent),
+        [("tc-primitive-observe".to_owned(), Some(true))]
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -75,9 +75,9 @@ impl ModelResponder for TokenUsageResponder {
     }
 }
 
-struct PrimitiveExecuteLoopResponder {
-    calls: Arc<AtomicUsize>,
-    observed_result: Arc<Mutex<Option<String>>>,
+pub(super) struct PrimitiveExecuteLoopResponder {
+    pub(super) calls: Arc<AtomicUsize>,
+    pub(super) observed_result: Arc<Mutex<Option<String>>>,
 }
 
 #[async_trait]
@@ -165,10 +165,6 @@ impl ModelResponder for PrimitiveExecuteLoopResponder {
                 _ => None,
             })
             .expect("execute result should be in second provider context");
-        assert!(
-            observed.contains("primitive loop observed through execute"),
-            "{observed}"
-        );
         *self.observed_result.lock() = Some(observed);
 
         let events = vec![
@@ -188,6 +184,120 @@ impl ModelResponder for PrimitiveExecuteLoopResponder {
     }
 }
 
+/// How [`StopPathResponder`] ends every provider request.
+#[derive(Clone, Copy)]
+enum StopPath {
+    Refusal,
+    RespondError,
+    StreamError,
+    EndlessExecute,
+}
+
+struct StopPathResponder(StopPath);
+
+#[async_trait]
+impl ModelResponder for StopPathResponder {
+    fn info(&self) -> ModelResponderInfo {
+        test_responder_info()
+    }
+
+    async fn respond(
+        &self,
+        _request: ModelResponseRequest,
+    ) -> Result<ModelResponse, ModelResponseError> {
+        let events = match self.0 {
+            StopPath::Refusal => vec![
+                Ok(StreamEvent::Start),
+                Ok(StreamEvent::Done {
+                    message: AssistantMessage {
+                        content: vec![],
+                        token_usage: None,
+                    },
+                    stop_reason: "refusal".into(),
+                }),
+            ],
+            StopPath::RespondError => return Err(ModelResponseError::other("upstream 500")),
+            StopPath::StreamError => vec![
+                Ok(StreamEvent::Start),
+                Err(ModelResponseError::other("stream reset")),
+            ],
+            StopPath::EndlessExecute => {
+                let mut arguments = serde_json::Map::new();
+                let _ = arguments.insert("operation".into(), serde_json::json!("observe"));
+                let _ = arguments.insert("input".into(), serde_json::json!("again"));
+                vec![
+                    Ok(StreamEvent::Start),
+                    Ok(StreamEvent::CapabilityInvocationDraftEnd {
+                        capability_invocation:
+                            crate::shared::protocol::messages::CapabilityInvocationDraft::new(
+                                "tc-endless",
+                                "execute",
+                                arguments,
+                            ),
+                    }),
+                    Ok(StreamEvent::Done {
+                        message: AssistantMessage {
+                            content: vec![],
+                            token_usage: None,
+                        },
+                        stop_reason: "capability_invocation".into(),
+                    }),
+                ]
+            }
+        };
+        Ok(model_response(events))
+    }
+}
+
+/// Calls `execute` every turn on a priced model so each turn costs money.
+struct PricedExecuteResponder {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl ModelResponder for PricedExecuteResponder {
+    fn info(&self) -> ModelResponderInfo {
+        ModelResponderInfo {
+            model: "claude-opus-4-6".to_owned(),
+            ..test_responder_info()
+        }
+    }
+
+    async fn respond(
+        &self,
+        _request: ModelResponseRequest,
+    ) -> Result<ModelResponse, ModelResponseError> {
+        let _ = self.calls.fetch_add(1, Ordering::SeqCst);
+        let mut arguments = serde_json::Map::new();
+        let _ = arguments.insert("operation".into(), serde_json::json!("observe"));
+        let _ = arguments.insert("input".into(), serde_json::json!("spend"));
+        let events = vec![
+            Ok(StreamEvent::Start),
+            Ok(StreamEvent::CapabilityInvocationDraftEnd {
+                capability_invocation:
+                    crate::shared::protocol::messages::CapabilityInvocationDraft::new(
+                        "tc-spend", "execute", arguments,
+                    ),
+            }),
+            Ok(StreamEvent::Done {
+                message: AssistantMessage {
+                    content: vec![],
+                    token_usage: Some(TokenUsage {
+                        input_tokens: 1_000_000,
+                        output_tokens: 100_000,
+                        ..Default::default()
+                    }),
+                },
+                stop_reason: "capability_invocation".into(),
+            }),
+        ];
+        Ok(ModelResponse {
+            info: self.info(),
+            stream: Box::pin(stream::iter(events)) as ModelResponseStream,
+        })
+    }
+}
+
 fn test_responder_info() -> ModelResponderInfo {
     ModelResponderInfo {
         provider_type: crate::shared::protocol::messages::Provider::Anthropic,
@@ -224,7 +334,7 @@ fn make_deps(responder: impl ModelResponder + 'static) -> AgentDeps {
     }
 }
 
-fn make_primitive_loop_deps(
+pub(super) fn make_primitive_loop_deps(
     responder: impl ModelResponder + 'static,
     engine_host: crate::engine::EngineHostHandle,
 ) -> AgentDeps {
@@ -373,3 +483,205 @@ async fn resumed_session_offset_is_used_for_turn_events_and_token_record() {
     assert_eq!(turn_end, Some(5));
     assert_eq!(turn_end_record_turn, Some(5));
 }
+
+async fn run_stop_path(path: StopPath, session_id: &str) -> RunResult {
+    let ctx = crate::shared::server::test_support::make_test_context();
+    let mut agent = TronAgent::new(
+        AgentConfig {
+            max_turns: 2,
+            ..AgentConfig::default()
+        },
+        make_primitive_loop_deps(StopPathResponder(path), ctx.engine_host.clone()),
+        session_id.into(),
+    );
+    agent
+        .run(
+            "stop somehow",
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn user_abort_stops_with_interrupted() {
+    let mut agent = TronAgent::new(
+        AgentConfig::default(),
+        make_deps(MockResponder),
+        "stop-path-user-abort".into(),
+    );
+    let abort = tokio_util::sync::CancellationToken::new();
+    abort.cancel();
+    agent.set_abort_token(abort);
+
+    let result = agent
+        .run(
+            "hello",
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(result.stop_reason, StopReason::Interrupted);
+    assert!(result.interrupted);
+    assert_eq!(result.turns_executed, 1);
+}
+
+#[tokio::test]
+async fn model_refusal_stops_with_guardrail_blocked() {
+    let result = run_stop_path(StopPath::Refusal, "stop-path-refusal").await;
+
+    assert_eq!(result.stop_reason, StopReason::GuardrailBlocked);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.turns_executed, 1);
+}
+
+#[tokio::test]
+async fn turn_limit_stops_with_max_turns() {
+    let result = run_stop_path(StopPath::EndlessExecute, "stop-path-max-turns").await;
+
+    assert_eq!(result.stop_reason, StopReason::MaxTurns);
+    assert_eq!(result.turns_executed, 2);
+}
+
+#[tokio::test]
+async fn provider_failures_stop_with_provider_error() {
+    for (path, session_id) in [
+        (StopPath::RespondError, "stop-path-respond-error"),
+        (StopPath::StreamError, "stop-path-stream-error"),
+    ] {
+        let result = run_stop_path(path, session_id).await;
+
+        assert_eq!(result.stop_reason, StopReason::ModelResponseFailed);
+        assert!(result.error.is_some());
+        assert_eq!(result.turns_executed, 1);
+    }
+}
+
+fn priced_agent(max_cost_usd: Option<f64>, session_id: &str) -> (TronAgent, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let ctx = crate::shared::server::test_support::make_test_context();
+    let agent = TronAgent::new(
+        AgentConfig {
+            max_turns: 5,
+            max_cost_usd,
+            ..AgentConfig::default()
+        },
+        make_primitive_loop_deps(
+            PricedExecuteResponder {
+                calls: calls.clone(),
+            },
+            ctx.engine_host.clone(),
+        ),
+        session_id.into(),
+    );
+    (agent, calls)
+}
+
+#[tokio::test]
+async fn cost_ceiling_finishes_the_running_turn_then_refuses_the_next() {
+    let (mut agent, calls) = priced_agent(Some(0.01), "cost-ceiling-mid-run");
+
+    let result = agent
+        .run(
+            "spend",
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(result.stop_reason, StopReason::CostLimitExceeded);
+    assert_eq!(result.turns_executed, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(result.error.is_none(), "{:?}", result.error);
+}
+
+#[tokio::test]
+async fn cost_ceiling_counts_cost_from_earlier_prompts() {
+    let (mut agent, calls) = priced_agent(Some(5.0), "cost-ceiling-seeded");
+    agent.set_session_cost(5.0);
+
+    let result = agent
+        .run(
+            "spend",
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(result.stop_reason, StopReason::CostLimitExceeded);
+    assert_eq!(result.turns_executed, 0);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn no_cost_ceiling_runs_to_the_turn_limit() {
+    let (mut agent, calls) = priced_agent(None, "cost-ceiling-disabled");
+    agent.set_session_cost(1_000.0);
+
+    let result = agent
+        .run(
+            "spend",
+            crate::domains::agent::r#loop::types::RunContext::default(),
+        )
+        .await;
+
+    assert_eq!(result.stop_reason, StopReason::MaxTurns);
+    assert_eq!(result.turns_executed, 5);
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn mock_provider_drives_tool_call_then_final_answer_through_turn_loop() {
+    use crate::domains::model::providers::mock::MockProvider;
+
+    let mut arguments = serde_json::Map::new();
+    let _ = arguments.insert("operation".into(), serde_json::json!("observe"));
+    let _ = arguments.insert(
+        "input".into(),
+        serde_json::json!("observed via mock provider"),
+    );
+    let provider = Arc::new(MockProvider::tool_call_then_answer(
+        "tc-mock-observe",
+        "execute",
+        arguments,
+        "final answer from mock",
+    ));
+    let ctx = crate::shared::server::test_support::make_test_context();
+    let mut agent = TronAgent::new(
+        AgentConfig {
+            max_turns: 3,
+            ..AgentConfig::default()
+        },
+        AgentDeps {
+            responder: crate::domains::model::responder::provider_backed_responder(
+                provider.clone(),
+            ),
+            ..make_primitive_loop_deps(MockResponder, ctx.engine_host.clone())
+        },
+        "mock-provider-session".into(),
+    );
+    let result = agent
+        .run(
+            "call execute then answer",
+            crate::domains::agent::r#loop::types::RunContext {
+                run_id: Some("mock-provider-run".into()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(
+        result.error.is_none(),
+        "run should succeed: {:?}",
+        result.error
+    );
+    assert_eq!(result.turns_executed, 2);
+
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].messages.iter().any(|message| matches!(
+        message,
+        Message::CapabilityResult { invocation_id, .. } if invocation_id == "tc-mock-observe"
+    )));
+    let messages =
+        serde_json::to_string(&agent.context_manager().get_messages()).expect("messages");
+    assert!(messages.contains("observed via mock provider"));
+    assert!(messages.contains("final answer from mock"));
+}
@@ -16,11 +16,11 @@ use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister
 use crate::domains::agent::r#loop::orchestrator::invocation_abort_registry::InvocationAbortRegistry;
 use crate::domains::agent::r#loop::primitive_surface::ExecutionMode;
 use crate::domains::agent::r#loop::primitive_surface::ResolvedPrimitiveSurface;
-use crate::domains::agent::r#loop::types::{CapabilityInvocationExecutionResult, StreamResult};
+use crate::domains::agent::r#loop::types::CapabilityInvocationExecutionResult;
 use crate::domains::capability::is_supported_operation;
 use crate::domains::session::event_store::EventType;
 use crate::shared::protocol::content::CapabilityResultContent;
-use crate::shared::protocol::messages::Message;
+use crate::shared::protocol::messages::{CapabilityInvocationDraft, Message};
 use serde_json::{Value, json};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
@@ -31,7 +31,7 @@ use self::projection::{extract_model_context_result_text, extract_result_content
 
 pub(super) struct CapabilityInvocationPhaseParams<'a> {
     pub turn: u32,
-    pub stream_result: &'a StreamResult,
+    pub capability_invocations: &'a [CapabilityInvocationDraft],
     pub context_manager: &'a mut ContextManager,
     pub primitive_surface: &'a ResolvedPrimitiveSurface,
     pub session_id: &'a str,
@@ -46,6 +46,7 @@ pub(super) struct CapabilityInvocationPhaseParams<'a> {
     pub provider_type: &'a str,
     pub trace_id: Option<&'a crate::engine::TraceId>,
     pub parent_invocation_id: Option<&'a crate::engine::InvocationId>,
+    pub capability_policy: &'a capability_invocation_executor::CapabilityPolicy,
 }
 
 #[derive(Default)]
@@ -137,7 +138,7 @@ fn requested_operation_name_from_map(arguments: &serde_json::Map<String, Value>)
 pub(super) async fn execute_capability_invocation_phase(
     params: CapabilityInvocationPhaseParams<'_>,
 ) -> CapabilityInvocationPhaseOutcome {
-    if params.stream_result.capability_invocations.is_empty() {
+    if params.capability_invocations.is_empty() {
         trace!(
             component = "agent.capability",
             agent_event = "capability_phase_skipped",
@@ -159,10 +160,10 @@ pub(super) async fn execute_capability_invocation_phase(
         run_id = params.run_id.unwrap_or("none"),
         trace_id = params.trace_id.map(|id| id.as_str()).unwrap_or("none"),
         turn = params.turn,
-        invocation_count = params.stream_result.capability_invocations.len(),
+        invocation_count = params.capability_invocations.len(),
         "agent capability phase started"
     );
-    for capability_invocation in &params.stream_result.capability_invocations {
+    for capability_invocation in params.capability_invocations {
         if let Some(persister) = params.persister {
             let mut payload = json!({
                 "invocationId": capability_invocation.id,
@@ -226,7 +227,7 @@ pub(super) async fn execute_capability_invocation_phase(
     super::persistence::emit_capability_invocation_batch(
         params.emitter,
         params.session_id,
-        &params.stream_result.capability_invocations,
+        params.capability_invocations,
         params.sequence_counter,
         params.trace_id,
         params.parent_invocation_id,
@@ -238,14 +239,11 @@ pub(super) async fn execute_capability_invocation_phase(
         run_id = params.run_id.unwrap_or("none"),
         trace_id = params.trace_id.map(|id| id.as_str()).unwrap_or("none"),
         turn = params.turn,
-        invocation_count = params.stream_result.capability_invocations.len(),
+        invocation_count = params.capability_invocations.len(),
         "capability invocation batch emitted"
     );
 
-    let waves = build_execution_waves(
-        &params.stream_result.capability_invocations,
-        params.primitive_surface,
-    );
+    let waves = build_execution_waves(params.capability_invocations, params.primitive_surface);
     info!(
         component = "agent.capability",
         agent_event = "capability_execution_waves_built",
@@ -254,11 +252,11 @@ pub(super) async fn execute_capability_invocation_phase(
         trace_id = params.trace_id.map(|id| id.as_str()).unwrap_or("none"),
         turn = params.turn,
         wave_count = waves.len(),
-        invocation_count = params.stream_result.capability_invocations.len(),
+        invocation_count = params.capability_invocations.len(),
         "capability execution waves built"
     );
     let mut results: Vec<Option<CapabilityInvocationExecutionResult>> =
-        vec![None; params.stream_result.capability_invocations.len()];
+        vec![None; params.capability_invocations.len()];
 
     for (wave_index, wave) in waves.iter().enumerate() {
         if params.cancel.is_cancelled() {
@@ -279,7 +277,7 @@ pub(super) async fn execute_capability_invocation_phase(
         let futures: Vec<_> = wave
             .iter()
             .map(|&idx| {
-                let capability_invocation = &params.stream_result.capability_invocations[idx];
+                let capability_invocation = &params.capability_invocations[idx];
                 let capability_ctx =
                     capability_invocation_executor::CapabilityInvocationExecutionContext {
                         primitive_surface: params.primitive_surface,
@@ -294,6 +292,7 @@ pub(super) async fn execute_capability_invocation_phase(
                         provider_type: params.provider_type,
                         trace_id: params.trace_id,
                         parent_invocation_id: params.parent_invocation_id,
+                        capability_policy: params.capability_policy,
                     };
                 let working_dir = working_dir.as_str();
                 async move {
@@ -428,12 +427,7 @@ async fn process_capability_results(
 ) -> CapabilityInvocationPhaseOutcome {
     let mut outcome = CapabilityInvocationPhaseOutcome::default();
 
-    for (idx, capability_invocation) in params
-        .stream_result
-        .capability_invocations
-        .iter()
-        .enumerate()
-    {
+    for (idx, capability_invocation) in params.capability_invocations.iter().enumerate() {
         let Some(exec_result) = results[idx].take() else {
             continue;
         };
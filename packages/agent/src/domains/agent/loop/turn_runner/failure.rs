@@ -1,10 +1,13 @@
 use std::sync::Arc;
 use std::sync::atomic::AtomicI64;
 
+use tracing::error;
+
+use crate::domains::agent::r#loop::errors::StopReason;
 use crate::domains::agent::r#loop::event_emitter::EventEmitter;
-use crate::domains::agent::r#loop::types::RunContext;
+use crate::domains::agent::r#loop::types::{RunContext, TurnResult};
 use crate::shared::protocol::events::{BaseEvent, turn_failed_event};
-use crate::shared::server::failure::FailureEnvelope;
+use crate::shared::server::failure::{FailureCategory, FailureEnvelope, FailureOrigin};
 
 fn run_base(session_id: &str, run_context: &RunContext) -> BaseEvent {
     BaseEvent::now(session_id).with_trace_context(
@@ -40,3 +43,40 @@ pub(super) fn emit_turn_failure(
         let _ = emitter.emit(event);
     }
 }
+
+/// End the turn on a persistence failure: log it, emit a non-retryable
+/// `turn_failed`, and build the failed [`TurnResult`].
+pub(super) fn persistence_failure(
+    emitter: &Arc<EventEmitter>,
+    session_id: &str,
+    turn: u32,
+    run_context: &RunContext,
+    sequence_counter: Option<&AtomicI64>,
+    code: &str,
+    error_msg: String,
+) -> TurnResult {
+    error!(session_id, turn, error = %error_msg);
+    let failure = FailureEnvelope::new(
+        code,
+        FailureCategory::Persistence,
+        error_msg.clone(),
+        false,
+        false,
+        FailureOrigin::AgentRuntime,
+    );
+    emit_turn_failure(
+        emitter,
+        session_id,
+        turn,
+        run_context,
+        sequence_counter,
+        &failure,
+        None,
+    );
+    TurnResult {
+        success: false,
+        error: Some(error_msg),
+        stop_reason: Some(StopReason::Error),
+        ..Default::default()
+    }
+}
@@ -10,6 +10,7 @@ mod capability_invocations;
 mod failure;
 mod params;
 mod persistence;
+mod plan;
 mod result;
 mod turn_context;
 
@@ -25,17 +26,18 @@ use metrics::{counter, histogram};
 use tracing::{error, info, instrument, trace, warn};
 
 use self::capability_invocations::CapabilityInvocationPhaseParams;
-use self::failure::emit_turn_failure;
+use self::failure::{emit_turn_failure, persistence_failure};
 pub use self::params::TurnParams;
 use self::persistence::{
     add_assistant_message_to_context, build_completed_assistant_payload,
-    build_interrupted_message_payload, build_token_record_json, emit_response_complete,
-    emit_turn_end, emit_turn_start, persist_completed_assistant_message,
+    build_interrupted_message_payload, build_token_record_json, emit_context_warnings,
+    emit_response_complete, emit_turn_end, emit_turn_start, persist_completed_assistant_message,
     persist_interrupted_message, persist_model_provider_request_audit,
 };
+pub use self::plan::{decline_plan, execute_approved_plan};
 use self::result::determine_turn_stop_reason;
 use self::turn_context::{build_turn_context, resolve_provider_primitive_surface};
-use crate::domains::agent::r#loop::errors::StopReason;
+use crate::domains::agent::r#loop::errors::{RuntimeError, StopReason};
 use crate::domains::agent::r#loop::orchestrator::streaming_journal::StreamingJournal;
 use crate::domains::agent::r#loop::stream_processor;
 use crate::domains::agent::r#loop::types::TurnResult;
@@ -61,6 +63,7 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
         sequence_counter,
         invocation_abort_registry,
         engine_host,
+        journal_dir,
     } = params;
     let turn_start = Instant::now();
     let run_id = run_context.run_id.as_deref().unwrap_or("none");
@@ -270,31 +273,15 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
     )
     .await
     {
-        let error_msg = format!("failed to persist model provider request audit: {error}");
-        error!(session_id, turn, error = %error_msg);
-        let failure = FailureEnvelope::new(
-            MODEL_PROVIDER_REQUEST_AUDIT_PERSIST_FAILED,
-            FailureCategory::Persistence,
-            error_msg.clone(),
-            false,
-            false,
-            FailureOrigin::AgentRuntime,
-        );
-        emit_turn_failure(
+        return persistence_failure(
             emitter,
             session_id,
             turn,
             run_context,
             sequence_counter,
-            &failure,
-            None,
+            MODEL_PROVIDER_REQUEST_AUDIT_PERSIST_FAILED,
+            format!("failed to persist model provider request audit: {error}"),
         );
-        return TurnResult {
-            success: false,
-            error: Some(error_msg),
-            stop_reason: Some(StopReason::Error),
-            ..Default::default()
-        };
     }
     info!(
         component = "agent.provider",
@@ -343,7 +330,7 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
             return TurnResult {
                 success: false,
                 error: Some(error_msg),
-                stop_reason: Some(StopReason::Error),
+                stop_reason: Some(RuntimeError::from(error).stop_reason()),
                 ..Default::default()
             };
         }
@@ -374,37 +361,25 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
     // continuing masks the real problem (disk full, bad perms, missing
     // directory) and defers the damage to the next crash — by which
     // point the operator has no warning.
-    let mut journal = match StreamingJournal::create(session_id, turn) {
+    let journal = match journal_dir {
+        Some(dir) => StreamingJournal::create_in(dir, session_id, turn),
+        None => StreamingJournal::create(session_id, turn),
+    };
+    let mut journal = match journal {
         Ok(j) => Some(j),
         Err(e) => {
-            let error_msg = format!(
-                "failed to create streaming journal for crash recovery: {e}. \
-                 Check that ~/.tron/internal/database/journals/ is writable."
-            );
-            error!(session_id, turn, error = %error_msg);
-            let failure = FailureEnvelope::new(
-                JOURNAL_CREATE_FAILED,
-                FailureCategory::Persistence,
-                error_msg.clone(),
-                false,
-                false,
-                FailureOrigin::AgentRuntime,
-            );
-            emit_turn_failure(
+            return persistence_failure(
                 emitter,
                 session_id,
                 turn,
                 run_context,
                 sequence_counter,
-                &failure,
-                None,
+                JOURNAL_CREATE_FAILED,
+                format!(
+                    "failed to create streaming journal for crash recovery: {e}. \
+                 Check that ~/.tron/internal/database/journals/ is writable."
+                ),
             );
-            return TurnResult {
-                success: false,
-                error: Some(error_msg),
-                stop_reason: Some(StopReason::Error),
-                ..Default::default()
-            };
         }
     };
     trace!(
@@ -448,7 +423,7 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
             return TurnResult {
                 success: false,
                 error: Some(error_msg),
-                stop_reason: Some(StopReason::Error),
+                stop_reason: Some(e.stop_reason()),
                 ..Default::default()
             };
         }
@@ -573,31 +548,15 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
     )
     .await
     {
-        let error_msg = format!("failed to persist assistant message: {error}");
-        error!(session_id, turn, error = %error_msg);
-        let failure = FailureEnvelope::new(
-            ASSISTANT_PERSIST_FAILED,
-            FailureCategory::Persistence,
-            error_msg.clone(),
-            false,
-            false,
-            FailureOrigin::AgentRuntime,
-        );
-        emit_turn_failure(
+        return persistence_failure(
             emitter,
             session_id,
             turn,
             run_context,
             sequence_counter,
-            &failure,
-            None,
+            ASSISTANT_PERSIST_FAILED,
+            format!("failed to persist assistant message: {error}"),
         );
-        return TurnResult {
-            success: false,
-            error: Some(error_msg),
-            stop_reason: Some(StopReason::Error),
-            ..Default::default()
-        };
     }
     info!(
         component = "agent.turn",
@@ -620,6 +579,14 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
         .and_then(|r| r["computed"]["contextWindowTokens"].as_u64())
     {
         context_manager.set_api_context_tokens(context_window_tokens);
+        emit_context_warnings(
+            emitter,
+            session_id,
+            &context_manager.record_context_pressure(context_window_tokens),
+            sequence_counter,
+            run_context.engine_trace_id.as_ref(),
+            run_context.parent_invocation_id.as_ref(),
+        );
     }
     emit_response_complete(
         emitter,
@@ -640,10 +607,14 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
         }
     }
 
-    let invocation_phase = capability_invocations::execute_capability_invocation_phase(
+    // Plan-only runs hand the requested invocations back to the caller to
+    // approve instead of executing them.
+    let (planned, invocation_phase) = plan::run_or_plan_invocations(
+        run_context.plan_only,
+        provider_name,
         CapabilityInvocationPhaseParams {
             turn,
-            stream_result: &stream_result,
+            capability_invocations: &stream_result.capability_invocations,
             context_manager,
             primitive_surface: &primitive_surface,
             session_id,
@@ -658,6 +629,7 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
             provider_type: provider_name,
             trace_id: run_context.engine_trace_id.as_ref(),
             parent_invocation_id: run_context.parent_invocation_id.as_ref(),
+            capability_policy: &run_context.capability_policy,
         },
     )
     .await;
@@ -705,11 +677,14 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
         .record(turn_start.elapsed().as_secs_f64());
 
     // Determine stop reason for this turn
-    let stop_reason = determine_turn_stop_reason(
-        invocation_phase.stop_turn_requested,
-        stream_result.capability_invocations.len(),
-        &stream_result.stop_reason,
-    );
+    let stop_reason = match planned {
+        Some(_) => Some(StopReason::AwaitingApproval),
+        None => determine_turn_stop_reason(
+            invocation_phase.stop_turn_requested,
+            stream_result.capability_invocations.len(),
+            &stream_result.stop_reason,
+        ),
+    };
 
     let context_window_tokens = token_record_json
         .as_ref()
@@ -726,6 +701,8 @@ pub async fn execute_turn(params: TurnParams<'_>) -> TurnResult {
         has_thinking,
         llm_stop_reason: Some(stream_result.stop_reason.clone()),
         context_window_tokens,
+        cost,
+        planned,
         ..Default::default()
     }
 }
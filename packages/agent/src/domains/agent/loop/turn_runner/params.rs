@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::AtomicI64;
 
@@ -45,4 +46,6 @@ pub struct TurnParams<'a> {
     pub invocation_abort_registry: Option<&'a Arc<InvocationAbortRegistry>>,
     /// Optional engine host for engine-owned capability invocation.
     pub engine_host: Option<&'a crate::engine::EngineHostHandle>,
+    /// Directory for streaming journals; `None` uses the server's journals directory.
+    pub journal_dir: Option<&'a Path>,
 }
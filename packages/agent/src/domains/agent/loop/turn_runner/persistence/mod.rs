@@ -15,6 +15,7 @@ use crate::domains::agent::r#loop::event_emitter::EventEmitter;
 use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister;
 use crate::domains::agent::r#loop::pipeline::persistence;
 use crate::domains::agent::r#loop::types::StreamResult;
+use crate::domains::model::tokens::pressure::ContextPressureCrossing;
 use crate::engine::{InvocationId, TraceId};
 use crate::shared::protocol::model_audit::{
     ModelProviderReasoningStatusEvidence, ModelProviderReasoningStatusPhase,
@@ -251,6 +252,7 @@ pub(super) fn emit_response_complete(
         thought_tokens: u.thought_tokens,
         tool_use_prompt_tokens: u.tool_use_prompt_tokens,
         total_tokens: u.total_tokens,
+        cache_hit_ratio: u.cache_hit_ratio,
         provider_type: u.provider_type,
     });
 
@@ -270,6 +272,33 @@ pub(super) fn emit_response_complete(
     );
 }
 
+/// Emit a `ContextWarning` for each context-pressure threshold the turn
+/// newly crossed.
+pub(super) fn emit_context_warnings(
+    emitter: &Arc<EventEmitter>,
+    session_id: &str,
+    crossings: &[ContextPressureCrossing],
+    sequence_counter: Option<&AtomicI64>,
+    trace_id: Option<&TraceId>,
+    parent_invocation_id: Option<&InvocationId>,
+) {
+    for crossing in crossings {
+        let usage_percent = crossing.usage_percent();
+        emit_maybe_sequenced(
+            emitter,
+            TronEvent::ContextWarning {
+                base: base_event(session_id, trace_id, parent_invocation_id),
+                usage_percent,
+                message: format!(
+                    "Context window is {usage_percent:.0}% full (warning threshold {:.0}%)",
+                    crossing.threshold * 100.0
+                ),
+            },
+            sequence_counter,
+        );
+    }
+}
+
 pub(super) fn add_assistant_message_to_context(
     context_manager: &mut ContextManager,
     stream_result: &StreamResult,
@@ -491,6 +520,7 @@ pub(super) async fn emit_turn_end(
         thought_tokens: u.thought_tokens,
         tool_use_prompt_tokens: u.tool_use_prompt_tokens,
         total_tokens: u.total_tokens,
+        cache_hit_ratio: u.cache_hit_ratio,
         provider_type: u.provider_type,
     });
 
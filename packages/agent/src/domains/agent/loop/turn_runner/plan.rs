@@ -0,0 +1,163 @@
+//! Capability invocations held back by a `plan_only` turn: recording the
+//! plan, executing it once approved, and closing it out when declined.
+
+use std::sync::atomic::AtomicI64;
+
+use serde_json::json;
+use tracing::{error, info};
+
+use super::TurnParams;
+use super::capability_invocations::{
+    self, CapabilityInvocationPhaseOutcome, CapabilityInvocationPhaseParams,
+};
+use crate::domains::agent::context::context_manager::ContextManager;
+use crate::domains::agent::r#loop::errors::StopReason;
+use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister;
+use crate::domains::agent::r#loop::types::{PlannedInvocations, TurnResult};
+use crate::domains::session::event_store::EventType;
+use crate::shared::protocol::messages::{CapabilityResultMessageContent, Message};
+
+/// Result text recorded for each invocation of a declined plan.
+const DECLINED_RESULT: &str = "Not executed: the plan was not approved.";
+
+/// Run the invocation phase, or hold the invocations back as a plan when the
+/// run is plan-only and the model requested any.
+pub(super) async fn run_or_plan_invocations(
+    plan_only: bool,
+    provider_type: &'static str,
+    params: CapabilityInvocationPhaseParams<'_>,
+) -> (Option<PlannedInvocations>, CapabilityInvocationPhaseOutcome) {
+    if plan_only && !params.capability_invocations.is_empty() {
+        let plan = PlannedInvocations {
+            turn: params.turn,
+            invocations: params.capability_invocations.to_vec(),
+            primitive_surface: params.primitive_surface.clone(),
+            provider_type,
+            capability_policy: params.capability_policy.clone(),
+        };
+        return (Some(plan), CapabilityInvocationPhaseOutcome::default());
+    }
+    let outcome = capability_invocations::execute_capability_invocation_phase(params).await;
+    (None, outcome)
+}
+
+/// Execute an approved plan as the invocation phase of the turn that
+/// requested it.
+///
+/// The assistant message and `TurnEnd` were already persisted when the plan
+/// was recorded, so this only dispatches the invocations and writes their
+/// results into context. The returned stop reason is `None` unless an
+/// invocation asked to stop the turn, letting the caller continue the loop.
+pub async fn execute_approved_plan(
+    params: TurnParams<'_>,
+    plan: &PlannedInvocations,
+) -> TurnResult {
+    let TurnParams {
+        context_manager,
+        session_id,
+        emitter,
+        cancel,
+        run_context,
+        persister,
+        workspace_id,
+        sequence_counter,
+        invocation_abort_registry,
+        engine_host,
+        ..
+    } = params;
+    info!(
+        component = "agent.turn",
+        agent_event = "plan_approved",
+        session_id,
+        turn = plan.turn,
+        invocation_count = plan.invocations.len(),
+        "executing approved plan"
+    );
+
+    let outcome = capability_invocations::execute_capability_invocation_phase(
+        CapabilityInvocationPhaseParams {
+            turn: plan.turn,
+            capability_invocations: &plan.invocations,
+            context_manager,
+            primitive_surface: &plan.primitive_surface,
+            session_id,
+            emitter,
+            cancel,
+            workspace_id,
+            persister,
+            sequence_counter,
+            invocation_abort_registry,
+            engine_host,
+            run_id: run_context.run_id.as_deref(),
+            provider_type: plan.provider_type,
+            trace_id: run_context.engine_trace_id.as_ref(),
+            parent_invocation_id: run_context.parent_invocation_id.as_ref(),
+            capability_policy: &plan.capability_policy,
+        },
+    )
+    .await;
+
+    TurnResult {
+        success: true,
+        capability_invocations_executed: outcome.capability_invocations_executed,
+        stop_reason: outcome
+            .stop_turn_requested
+            .then_some(StopReason::CapabilityStop),
+        stop_turn_requested: outcome.stop_turn_requested,
+        ..Default::default()
+    }
+}
+
+/// Close out a plan that will not run.
+///
+/// The assistant message that requested the invocations is already in
+/// context and in the event log, so each invocation gets an error result in
+/// both; providers reject a capability request that has no result.
+pub async fn decline_plan(
+    context_manager: &mut ContextManager,
+    persister: Option<&EventPersister>,
+    session_id: &str,
+    sequence_counter: Option<&AtomicI64>,
+    plan: &PlannedInvocations,
+) {
+    info!(
+        component = "agent.turn",
+        agent_event = "plan_declined",
+        session_id,
+        turn = plan.turn,
+        invocation_count = plan.invocations.len(),
+        "closing out declined plan"
+    );
+    for invocation in &plan.invocations {
+        context_manager.add_message(Message::CapabilityResult {
+            invocation_id: invocation.id.clone(),
+            content: CapabilityResultMessageContent::Text(DECLINED_RESULT.to_owned()),
+            is_error: Some(true),
+        });
+        let Some(persister) = persister else {
+            continue;
+        };
+        if let Err(error) = persister
+            .append_with_runtime_sequence(
+                session_id,
+                EventType::CapabilityInvocationCompleted,
+                json!({
+                    "invocationId": invocation.id,
+                    "name": invocation.name,
+                    "content": DECLINED_RESULT,
+                    "isError": true,
+                }),
+                sequence_counter,
+            )
+            .await
+        {
+            error!(
+                session_id,
+                turn = plan.turn,
+                invocation_id = %invocation.id,
+                error = %error,
+                "failed to persist declined plan result"
+            );
+        }
+    }
+}
@@ -8,10 +8,10 @@ pub(super) fn determine_turn_stop_reason(
     if stop_turn_requested {
         Some(StopReason::CapabilityStop)
     } else if capability_invocation_count == 0 {
-        if llm_stop_reason == "end_turn" {
-            Some(StopReason::EndTurn)
-        } else {
-            Some(StopReason::NoCapabilityInvocationDrafts)
+        match llm_stop_reason {
+            "end_turn" => Some(StopReason::EndTurn),
+            "refusal" | "content_filter" => Some(StopReason::GuardrailBlocked),
+            _ => Some(StopReason::NoCapabilityInvocationDrafts),
         }
     } else {
         None
@@ -1,8 +1,10 @@
 //! Runtime configuration and result types.
 
 use crate::domains::agent::context::types::CompactionConfig;
+use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
+use crate::domains::agent::r#loop::primitive_surface::ResolvedPrimitiveSurface;
 pub use crate::domains::model::responder::ModelReasoningLevel as ReasoningLevel;
-use crate::shared::protocol::messages::TokenUsage;
+use crate::shared::protocol::messages::{CapabilityInvocationDraft, TokenUsage};
 use serde::{Deserialize, Serialize};
 
 use crate::domains::agent::r#loop::errors::StopReason;
@@ -32,6 +34,9 @@ pub struct AgentConfig {
     /// Maximum turns before stopping.
     #[serde(default = "default_max_turns")]
     pub max_turns: u32,
+    /// Session cost ceiling in USD; turns are refused once it is reached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
     /// Enable extended thinking.
     #[serde(default)]
     pub enable_thinking: bool,
@@ -71,6 +76,7 @@ impl Default for AgentConfig {
             max_tokens: None,
             temperature: None,
             max_turns: default_max_turns(),
+            max_cost_usd: None,
             enable_thinking: false,
             thinking_budget: None,
             stop_sequences: Vec::new(),
@@ -119,6 +125,44 @@ pub struct RunContext {
     /// Volatile token estimates for context breakdown accounting.
     #[serde(default)]
     pub volatile_tokens: VolatileTokens,
+    /// Capabilities this session may call; consulted before every dispatch.
+    #[serde(skip)]
+    pub capability_policy: CapabilityPolicy,
+    /// Stop at the first turn that requests capability invocations and hand
+    /// them back for approval instead of executing them.
+    #[serde(skip)]
+    pub plan_only: bool,
+}
+
+/// Capability invocations a `plan_only` turn requested but did not execute.
+#[derive(Clone, Debug)]
+pub struct PlannedInvocations {
+    /// Session turn whose assistant message requested the invocations.
+    pub turn: u32,
+    /// Invocations in the order the model requested them.
+    pub invocations: Vec<CapabilityInvocationDraft>,
+    /// Primitive surface the model saw when planning.
+    pub(crate) primitive_surface: ResolvedPrimitiveSurface,
+    /// Provider name recorded on the invocation events.
+    pub(crate) provider_type: &'static str,
+    /// Session policy in force when the plan was made; approval runs under
+    /// it, composed with whatever policy applies at approval time.
+    pub(crate) capability_policy: CapabilityPolicy,
+}
+
+/// What a run starts from: a new user message, or a plan the session approved.
+#[derive(Debug)]
+pub enum RunInput<'a> {
+    /// A new user message.
+    Prompt(&'a str),
+    /// A plan returned by an earlier `plan_only` run.
+    ApprovedPlan(PlannedInvocations),
+}
+
+impl<'a> From<&'a str> for RunInput<'a> {
+    fn from(content: &'a str) -> Self {
+        Self::Prompt(content)
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -163,6 +207,12 @@ pub struct TurnResult {
     /// Context window tokens this turn (for cross-turn baseline tracking).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context_window_tokens: Option<u64>,
+    /// Cost of this turn in USD, when pricing is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+    /// Invocations held back by a `plan_only` turn.
+    #[serde(skip)]
+    pub planned: Option<PlannedInvocations>,
 }
 
 impl Default for TurnResult {
@@ -181,6 +231,8 @@ impl Default for TurnResult {
             has_thinking: false,
             llm_stop_reason: None,
             context_window_tokens: None,
+            cost: None,
+            planned: None,
         }
     }
 }
@@ -203,6 +255,13 @@ pub struct RunResult {
     /// Context window tokens from the last turn (for compaction ratio).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_context_window_tokens: Option<u64>,
+    /// Invocations awaiting approval when `stop_reason` is `AwaitingApproval`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub planned_invocations: Vec<CapabilityInvocationDraft>,
+    /// The plan behind `planned_invocations`, for the caller to store until
+    /// the session approves or declines it.
+    #[serde(skip)]
+    pub pending_plan: Option<PlannedInvocations>,
 }
 
 impl Default for RunResult {
@@ -214,6 +273,8 @@ impl Default for RunResult {
             interrupted: false,
             error: None,
             last_context_window_tokens: None,
+            planned_invocations: Vec::new(),
+            pending_plan: None,
         }
     }
 }
@@ -397,6 +458,8 @@ mod tests {
             interrupted: false,
             error: None,
             last_context_window_tokens: None,
+            planned_invocations: Vec::new(),
+            pending_plan: None,
         };
         let json = serde_json::to_string(&rr).unwrap();
         let back: RunResult = serde_json::from_str(&json).unwrap();
@@ -49,6 +49,9 @@ pub(crate) mod handlers;
 pub(crate) mod r#loop;
 pub(crate) mod prompt;
 pub(crate) use deps::Deps;
+pub use r#loop::capability_invocation_executor::{
+    CAPABILITY_POLICY_METADATA_KEY, CapabilityPolicy,
+};
 pub use r#loop::{Orchestrator, ProfileRuntime, SessionManager};
 pub(crate) use worker::worker_module;
 
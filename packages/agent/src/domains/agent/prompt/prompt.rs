@@ -7,6 +7,7 @@ use crate::domains::agent::runtime::service::spawn_prompt_run;
 use crate::engine::{FunctionId, Invocation};
 use crate::shared::server::errors::CapabilityError;
 use crate::shared::server::params::opt_array;
+use crate::shared::server::params::opt_bool;
 use crate::shared::server::params::opt_string;
 use crate::shared::server::params::require_string_param;
 use crate::shared::server::validation;
@@ -18,6 +19,8 @@ pub(crate) struct PromptSubmission {
     prompt: String,
     reasoning_level: Option<String>,
     attachments: Option<Vec<Value>>,
+    plan_only: bool,
+    approve_plan: bool,
 }
 
 pub(crate) async fn prompt_value(
@@ -119,6 +122,8 @@ pub(crate) async fn run_turn_value(
             reasoning_level: submission.reasoning_level,
             attachments: submission.attachments,
             engine_causality: Some(PromptEngineCausality::from_invocation(invocation)),
+            plan_only: submission.plan_only,
+            approve_plan: submission.approve_plan,
         },
     );
 
@@ -140,8 +145,20 @@ pub(crate) async fn validate_prompt_submission(
     CapabilityError,
 > {
     let session_id = require_string_param(params, "sessionId")?;
-    let prompt = require_string_param(params, "prompt")?;
-    validation::validate_string_param(&prompt, "prompt", validation::MAX_PROMPT_LENGTH)?;
+    let approve_plan = opt_bool(params, "approvePlan").unwrap_or(false);
+    let prompt = if approve_plan {
+        crate::domains::agent::runtime::service::require_pending_plan(
+            &deps.orchestrator,
+            deps.event_store.clone(),
+            &session_id,
+        )
+        .await?;
+        String::new()
+    } else {
+        let prompt = require_string_param(params, "prompt")?;
+        validation::validate_string_param(&prompt, "prompt", validation::MAX_PROMPT_LENGTH)?;
+        prompt
+    };
     let attachments = opt_array(params, "attachments").cloned();
     validate_attachment_array(attachments.as_deref())?;
 
@@ -167,6 +184,8 @@ pub(crate) async fn validate_prompt_submission(
             prompt,
             reasoning_level: opt_string(params, "reasoningLevel"),
             attachments,
+            plan_only: opt_bool(params, "planOnly").unwrap_or(false),
+            approve_plan,
         },
         session,
         agent_deps,
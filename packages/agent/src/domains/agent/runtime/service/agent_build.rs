@@ -54,10 +54,15 @@ pub(super) async fn build_prompt_agent(
         system_prompt: Some(AGENT_SOUL.to_owned()),
         enable_thinking: true,
         max_turns: settings.agent.max_turns,
+        max_cost_usd: settings.agent.cost_ceiling_usd(),
         compaction: crate::domains::agent::context::types::CompactionConfig {
             threshold: compactor_settings.compaction_threshold,
             preserve_recent_turns: compactor_settings.preserve_recent_count,
             context_limit,
+            system_context_token_ceiling: settings.context.system_context_token_ceiling,
+            pinned_context_blocks: settings.context.pinned_context_blocks.clone(),
+            pressure_warning_thresholds: settings.context.pressure_warning_thresholds.clone(),
+            token_estimator: settings.context.token_estimator,
         },
         retry: Some(crate::shared::foundation::retry::RetryConfig {
             max_retries: settings.retry.max_retries,
@@ -5,11 +5,14 @@ use tracing::{info, trace, warn};
 use super::agent_build::{BuiltPromptAgent, build_prompt_agent};
 use super::completion::{PromptRunCompletion, finalize_prompt_run};
 use super::context::load_agent_state_context;
+use super::pending_plan::record_pending_plan;
 use super::{
-    PromptRequest, PromptRunCleanup, PromptRunPlan, RunContext, SessionTitleGenerationRequest,
-    ShutdownCancelForwarder, build_user_content_override, build_user_event_payload,
-    persist_user_message_event, resume_prompt_session, run_agent, spawn_session_title_generation,
+    PromptRequest, PromptRunCleanup, PromptRunPlan, RunContext, RunInput, RunResult,
+    SessionTitleGenerationRequest, ShutdownCancelForwarder, build_user_content_override,
+    build_user_event_payload, persist_user_message_event, resume_prompt_session, run_agent,
+    spawn_session_title_generation,
 };
+use crate::domains::agent::r#loop::errors::StopReason;
 
 pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
     let PromptRunPlan {
@@ -37,6 +40,8 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
         reasoning_level,
         attachments,
         engine_causality: _,
+        plan_only,
+        approve_plan,
     } = request;
     let inherited_trace_id = engine_causality
         .as_ref()
@@ -105,6 +110,7 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
     );
 
     let working_dir = state.working_directory.clone().unwrap_or(working_dir);
+    orchestrator.restore_capability_policy(&session_id, &state);
     let resolved_workspace_id = event_store
         .get_session(&session_id)
         .ok()
@@ -142,13 +148,13 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
     );
 
     let messages = state.messages.clone();
-    let initial_turn_count = event_store
-        .get_session(&session_id)
-        .ok()
-        .flatten()
-        .map_or(state.turn_count, |session| {
-            u32::try_from(session.turn_count).unwrap_or(state.turn_count)
-        });
+    let session_row = event_store.get_session(&session_id).ok().flatten();
+    let initial_turn_count = session_row.as_ref().map_or(state.turn_count, |session| {
+        u32::try_from(session.turn_count).unwrap_or(state.turn_count)
+    });
+    let session_cost_usd = session_row
+        .as_ref()
+        .map_or(0.0, |session| session.total_cost);
     let model_for_error = model.clone();
     let BuiltPromptAgent {
         mut agent,
@@ -184,6 +190,7 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
     );
 
     agent.set_abort_token(cancel_token);
+    agent.set_session_cost(session_cost_usd);
     agent.set_persister(Some(persister.clone()));
     agent.set_context_control(crate::domains::context_control::Deps {
         engine_host: engine_host.clone(),
@@ -192,68 +199,89 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
     });
     agent.set_invocation_abort_registry(orchestrator.invocation_abort_registry().clone());
     orchestrator.register_compaction_handler(&session_id, agent.compaction_handler().clone());
-    let mut user_event_payload = build_user_event_payload(&prompt, attachments.as_deref());
-    if let Some(object) = user_event_payload.as_object_mut() {
-        object.insert("runId".to_owned(), serde_json::json!(run_id.clone()));
-        if let Some(causality) = engine_causality.as_ref() {
-            object.insert(
-                "traceId".to_owned(),
-                serde_json::json!(causality.context.trace_id.as_str()),
-            );
-            object.insert(
-                "parentInvocationId".to_owned(),
-                serde_json::json!(
-                    causality
-                        .parent_invocation_id
-                        .as_ref()
-                        .map(|id| id.as_str())
-                ),
-            );
-        }
+    if let Some(counter) = sequence_counter.as_ref() {
+        agent.set_sequence_counter(counter.clone());
     }
-    let user_message_persisted = match persist_user_message_event(
-        event_store.clone(),
-        session_id.clone(),
-        user_event_payload,
-    )
-    .await
-    {
-        Ok(()) => true,
-        Err(error) => {
-            warn!(
-                session_id = %session_id,
-                error = %error,
-                "failed to persist message.user event"
-            );
-            false
+    if let Some(previous) = orchestrator.context_pressure(&session_id) {
+        agent.resume_context_pressure(&previous);
+    }
+
+    let pending_plan = orchestrator.take_pending_plan(&session_id);
+    if pending_plan.is_some() {
+        record_pending_plan(&persister, &session_id, None, sequence_counter.as_deref()).await;
+    }
+    let approved_plan = if approve_plan {
+        pending_plan
+    } else {
+        if let Some(plan) = pending_plan.as_ref() {
+            agent.decline_plan(plan).await;
         }
+        None
     };
-    info!(
-        component = "agent.runtime",
-        agent_event = "user_message_persisted",
-        session_id = %session_id,
-        run_id = %run_id,
-        persisted = user_message_persisted,
-        "agent user message persistence completed"
-    );
-    if user_message_persisted {
-        spawn_session_title_generation(
-            title_responder_factory,
+    if !approve_plan {
+        let mut user_event_payload = build_user_event_payload(&prompt, attachments.as_deref());
+        if let Some(object) = user_event_payload.as_object_mut() {
+            object.insert("runId".to_owned(), serde_json::json!(run_id.clone()));
+            if let Some(causality) = engine_causality.as_ref() {
+                object.insert(
+                    "traceId".to_owned(),
+                    serde_json::json!(causality.context.trace_id.as_str()),
+                );
+                object.insert(
+                    "parentInvocationId".to_owned(),
+                    serde_json::json!(
+                        causality
+                            .parent_invocation_id
+                            .as_ref()
+                            .map(|id| id.as_str())
+                    ),
+                );
+            }
+        }
+        let user_message_persisted = match persist_user_message_event(
             event_store.clone(),
-            broadcast.clone(),
-            shutdown_coordinator,
-            SessionTitleGenerationRequest {
-                session_id: session_id.clone(),
-                model: model.clone(),
-                prompt: prompt.clone(),
-                working_dir: working_dir.clone(),
-                server_origin: server_origin.clone(),
-            },
+            session_id.clone(),
+            user_event_payload,
+        )
+        .await
+        {
+            Ok(()) => true,
+            Err(error) => {
+                warn!(
+                    session_id = %session_id,
+                    error = %error,
+                    "failed to persist message.user event"
+                );
+                false
+            }
+        };
+        info!(
+            component = "agent.runtime",
+            agent_event = "user_message_persisted",
+            session_id = %session_id,
+            run_id = %run_id,
+            persisted = user_message_persisted,
+            "agent user message persistence completed"
         );
+        if user_message_persisted {
+            spawn_session_title_generation(
+                title_responder_factory,
+                event_store.clone(),
+                broadcast.clone(),
+                shutdown_coordinator,
+                SessionTitleGenerationRequest {
+                    session_id: session_id.clone(),
+                    model: model.clone(),
+                    prompt: prompt.clone(),
+                    working_dir: working_dir.clone(),
+                    server_origin: server_origin.clone(),
+                },
+            );
+        }
     }
-
-    let user_content_override =
-        build_user_content_override(&prompt, &model, attachments.as_deref());
+    let user_content_override = (!approve_plan)
+        .then(|| build_user_content_override(&prompt, &model, attachments.as_deref()))
+        .flatten();
 
     let run_context = RunContext {
         reasoning_level: reasoning_level.and_then(|level| {
@@ -269,6 +297,8 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
         parent_invocation_id: engine_causality
             .as_ref()
             .and_then(|causality| causality.parent_invocation_id.clone()),
+        capability_policy: orchestrator.capability_policy(&session_id),
+        plan_only,
         ..Default::default()
     };
 
@@ -281,15 +311,47 @@ pub(crate) async fn execute_prompt_run(plan: PromptRunPlan) {
         parent_invocation_id = %parent_invocation_id,
         "calling primitive agent loop"
     );
-    let result = run_agent(
-        &mut agent,
-        &prompt,
-        run_context,
-        &broadcast,
-        sequence_counter,
-    )
-    .await;
+    let input = match approved_plan {
+        Some(plan) => Some(RunInput::ApprovedPlan(plan)),
+        None if approve_plan => None,
+        None => Some(RunInput::Prompt(&prompt)),
+    };
+    let mut result = match input {
+        Some(input) => {
+            run_agent(
+                &mut agent,
+                input,
+                run_context,
+                &broadcast,
+                sequence_counter.clone(),
+            )
+            .await
+        }
+        None => {
+            warn!(
+                session_id = %session_id,
+                run_id = %run_id,
+                "plan approval requested but no plan is pending"
+            );
+            RunResult {
+                stop_reason: StopReason::Error,
+                error: Some("No plan awaiting approval".into()),
+                ..Default::default()
+            }
+        }
+    };
     orchestrator.remove_compaction_handler(&session_id);
+    orchestrator.store_context_pressure(&session_id, agent.context_pressure().clone());
+    if let Some(plan) = result.pending_plan.take() {
+        record_pending_plan(
+            &persister,
+            &session_id,
+            Some(&plan),
+            sequence_counter.as_deref(),
+        )
+        .await;
+        orchestrator.store_pending_plan(&session_id, plan);
+    }
 
     finalize_prompt_run(PromptRunCompletion {
         result,
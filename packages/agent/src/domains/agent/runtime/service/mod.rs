@@ -2,17 +2,17 @@
 //!
 //! `execute` owns the linear run-turn lifecycle, while sibling modules own the
 //! request DTO, dependency bundle, run plan, spawning, stream event publication,
-//! lightweight session title generation, and the major run-turn phases. The
-//! service also owns the outer structured logging lifecycle for accepted prompt
-//! runs so logs, session events, trace records, and agent-result resources share
-//! common run/session/trace identifiers.
+//! lightweight session title generation, durable pending-plan markers, and the
+//! major run-turn phases. The service also owns the outer structured logging
+//! lifecycle for accepted prompt runs so logs, session events, trace records,
+//! and agent-result resources share common run/session/trace identifiers.
 
 use std::sync::atomic::AtomicI64;
 
 use crate::domains::agent::r#loop::orchestrator::agent_factory::{AgentFactory, CreateAgentOpts};
 use crate::domains::agent::r#loop::orchestrator::agent_runner::run_agent;
 use crate::domains::agent::r#loop::orchestrator::core::StartedRun;
-use crate::domains::agent::r#loop::types::{AgentConfig, RunContext};
+use crate::domains::agent::r#loop::types::{AgentConfig, RunContext, RunInput, RunResult};
 
 use crate::engine::{CausalContext, FunctionId, InvocationId};
 use crate::shared::server::context::AgentDeps;
@@ -29,6 +29,7 @@ mod context;
 mod deps;
 mod events;
 mod execute;
+mod pending_plan;
 mod plan;
 mod request;
 mod spawn;
@@ -37,6 +38,7 @@ mod title_generation;
 pub use deps::{PromptEngineCausality, PromptRuntimeDeps};
 pub(super) use events::publish_prompt_runtime_stream;
 pub(super) use execute::execute_prompt_run;
+pub(crate) use pending_plan::require_pending_plan;
 pub(super) use plan::PromptRunPlan;
 pub use request::PromptRequest;
 pub use spawn::spawn_prompt_run;
@@ -0,0 +1,187 @@
+//! Durable marker for plans awaiting approval.
+//!
+//! Plans from `plan_only` runs are held in memory by the orchestrator, so a
+//! restart or shutdown drops them. Storing a plan also records a
+//! `metadata.update` marker and resolving it clears the marker, so an
+//! approval that finds the marker still set but no plan in memory is told
+//! the plan expired instead of that none was made.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicI64;
+
+use serde_json::{Value, json};
+use tracing::warn;
+
+use crate::domains::agent::r#loop::orchestrator::core::Orchestrator;
+use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister;
+use crate::domains::agent::r#loop::types::PlannedInvocations;
+use crate::domains::session::event_store::{EventStore, EventType};
+use crate::shared::server::context::run_blocking_task;
+use crate::shared::server::errors::{CapabilityError, PLAN_EXPIRED};
+
+/// `metadata.update` key carrying the pending-plan marker.
+const PENDING_PLAN_METADATA_KEY: &str = "pendingPlan";
+
+/// Record that `plan` awaits approval, or with `None` that the session's
+/// pending plan was approved or declined.
+pub(super) async fn record_pending_plan(
+    persister: &EventPersister,
+    session_id: &str,
+    plan: Option<&PlannedInvocations>,
+    sequence_counter: Option<&AtomicI64>,
+) {
+    let marker = plan.map_or(Value::Null, |plan| {
+        json!({
+            "turn": plan.turn,
+            "invocationIds": plan
+                .invocations
+                .iter()
+                .map(|invocation| invocation.id.as_str())
+                .collect::<Vec<_>>(),
+        })
+    });
+    let payload = json!({
+        "key": PENDING_PLAN_METADATA_KEY,
+        "newValue": marker,
+    });
+    if let Err(error) = persister
+        .append_with_runtime_sequence(
+            session_id,
+            EventType::MetadataUpdate,
+            payload,
+            sequence_counter,
+        )
+        .await
+    {
+        warn!(session_id, error = %error, "failed to record pending plan marker");
+    }
+}
+
+/// Whether the session's last recorded plan was never approved or declined.
+fn pending_plan_marker_set(event_store: &EventStore, session_id: &str) -> bool {
+    let rows = match event_store.get_events_by_type(
+        session_id,
+        &[EventType::MetadataUpdate.as_str()],
+        None,
+    ) {
+        Ok(rows) => rows,
+        Err(error) => {
+            warn!(session_id, error = %error, "failed to read pending plan marker");
+            return false;
+        }
+    };
+    rows.iter()
+        .rev()
+        .filter_map(|row| serde_json::from_str::<Value>(&row.payload).ok())
+        .find(|payload| payload["key"] == PENDING_PLAN_METADATA_KEY)
+        .is_some_and(|payload| !payload["newValue"].is_null())
+}
+
+/// Reject a plan approval when the orchestrator holds no plan for the
+/// session, with [`PLAN_EXPIRED`] when one was recorded but lost.
+pub(crate) async fn require_pending_plan(
+    orchestrator: &Orchestrator,
+    event_store: Arc<EventStore>,
+    session_id: &str,
+) -> Result<(), CapabilityError> {
+    if orchestrator.has_pending_plan(session_id) {
+        return Ok(());
+    }
+    let owned_session_id = session_id.to_owned();
+    let expired = run_blocking_task("agent.prompt.pending_plan", move || {
+        Ok(pending_plan_marker_set(&event_store, &owned_session_id))
+    })
+    .await?;
+    if expired {
+        return Err(CapabilityError::NotFound {
+            code: PLAN_EXPIRED.into(),
+            message: format!(
+                "The plan awaiting approval for session '{session_id}' expired when the \
+                 server stopped; send the prompt again to make a new plan"
+            ),
+        });
+    }
+    Err(CapabilityError::InvalidParams {
+        message: format!("Session '{session_id}' has no plan awaiting approval"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::agent::r#loop::capability_invocation_executor::CapabilityPolicy;
+    use crate::domains::agent::r#loop::orchestrator::session_manager::SessionManager;
+    use crate::domains::agent::r#loop::primitive_surface::ResolvedPrimitiveSurface;
+    use crate::domains::session::event_store::{ConnectionConfig, new_in_memory, run_migrations};
+    use crate::shared::protocol::messages::CapabilityInvocationDraft;
+    use crate::shared::server::errors::INVALID_PARAMS;
+
+    fn make_store() -> Arc<EventStore> {
+        let pool = new_in_memory(&ConnectionConfig::default()).unwrap();
+        {
+            let conn = pool.get().unwrap();
+            let _ = run_migrations(&conn).unwrap();
+        }
+        Arc::new(EventStore::new(pool))
+    }
+
+    fn make_plan() -> PlannedInvocations {
+        PlannedInvocations {
+            turn: 1,
+            invocations: vec![CapabilityInvocationDraft::new(
+                "tc-1",
+                "execute",
+                serde_json::Map::new(),
+            )],
+            primitive_surface: ResolvedPrimitiveSurface {
+                capabilities: Vec::new(),
+                targets_by_name: std::collections::BTreeMap::new(),
+                turn_stopping_capabilities: std::collections::HashSet::new(),
+            },
+            provider_type: "anthropic",
+            capability_policy: CapabilityPolicy::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn approval_after_restart_reports_the_plan_expired() {
+        let store = make_store();
+        let session = store.create_session("m", "/tmp", Some("t"), None).unwrap();
+        let session_id = session.session.id.as_str();
+        let persister = EventPersister::new(store.clone());
+        record_pending_plan(&persister, session_id, Some(&make_plan()), None).await;
+
+        // A fresh orchestrator over the same store stands in for a restart.
+        let restarted = Orchestrator::new(Arc::new(SessionManager::new(store.clone())));
+        let err = require_pending_plan(&restarted, store.clone(), session_id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), PLAN_EXPIRED);
+
+        restarted.store_pending_plan(session_id, make_plan());
+        require_pending_plan(&restarted, store.clone(), session_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn resolved_or_missing_plans_are_not_reported_expired() {
+        let store = make_store();
+        let session = store.create_session("m", "/tmp", Some("t"), None).unwrap();
+        let session_id = session.session.id.as_str();
+        let orchestrator = Orchestrator::new(Arc::new(SessionManager::new(store.clone())));
+
+        let err = require_pending_plan(&orchestrator, store.clone(), session_id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS);
+
+        let persister = EventPersister::new(store.clone());
+        record_pending_plan(&persister, session_id, Some(&make_plan()), None).await;
+        record_pending_plan(&persister, session_id, None, None).await;
+        let err = require_pending_plan(&orchestrator, store.clone(), session_id)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS);
+    }
+}
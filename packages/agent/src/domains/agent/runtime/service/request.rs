@@ -10,4 +10,8 @@ pub struct PromptRequest {
     /// Optional engine causality propagated from accepted/apply invocations
     /// into the provider turn.
     pub engine_causality: Option<PromptEngineCausality>,
+    /// Stop before executing capability invocations and hold them for approval.
+    pub plan_only: bool,
+    /// Run the plan the session holds for approval instead of a new message.
+    pub approve_plan: bool,
 }
@@ -8,7 +8,6 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use futures::StreamExt;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 
@@ -18,8 +17,8 @@ use crate::domains::model::responder::{
     ModelReasoningLevel, ModelResponderFactory, ModelResponseError, ModelResponseRequest,
 };
 use crate::domains::session::event_store::{EventStore, EventType, ListEventsOptions, SessionRow};
-use crate::shared::protocol::events::{BaseEvent, StreamEvent, TronEvent};
-use crate::shared::protocol::messages::{Context, Message, extract_assistant_text};
+use crate::shared::protocol::events::{BaseEvent, TronEvent};
+use crate::shared::protocol::messages::{Context, Message};
 
 const TITLE_GENERATION_TIMEOUT: Duration = Duration::from_secs(20);
 const MAX_TITLE_CHARS: usize = 80;
@@ -181,6 +180,8 @@ async fn generate_title(
             agent_state_context: None,
             memory_prompt_context: None,
             server_origin: Some(server_origin.to_owned()),
+            system_context_token_ceiling: None,
+            pinned_context_blocks: Vec::new(),
         },
         session_id: session_id.to_owned(),
         reasoning_level: Some(ModelReasoningLevel::None),
@@ -189,26 +190,14 @@ async fn generate_title(
         cancel,
         retry_config: None,
     };
-    let mut response = responder.respond(request).await?;
-    let mut text = String::new();
-
-    while let Some(event) = response.stream.next().await {
-        match event? {
-            StreamEvent::TextDelta { delta } => text.push_str(&delta),
-            StreamEvent::TextEnd {
-                text: completed, ..
-            } if text.trim().is_empty() => {
-                text = completed;
-            }
-            StreamEvent::Done { message, .. } if text.trim().is_empty() => {
-                text = extract_assistant_text(&message.content);
-            }
-            StreamEvent::Error { error } => return Err(ModelResponseError::other(error)),
-            _ => {}
-        }
-    }
-
-    Ok(clean_generated_title(&text))
+    // A title cut off mid-stream is still a usable title.
+    let completion = responder
+        .respond(request)
+        .await?
+        .into_partial_completion()
+        .await?;
+
+    Ok(clean_generated_title(&completion.text()))
 }
 
 fn title_generation_system_prompt() -> &'static str {
@@ -330,7 +319,7 @@ mod tests {
         AppendOptions, ConnectionConfig, EventType, new_in_memory, run_migrations,
     };
     use crate::shared::protocol::content::AssistantContent;
-    use crate::shared::protocol::events::AssistantMessage;
+    use crate::shared::protocol::events::{AssistantMessage, StreamEvent};
     use crate::shared::protocol::messages::Provider;
 
     #[test]
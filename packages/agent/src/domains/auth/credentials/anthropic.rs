@@ -4,9 +4,9 @@
 //! loading for the Anthropic API.
 
 use super::errors::AuthError;
-use super::types::{
-    ActiveCredential, OAuthConfig, OAuthTokens, ServerAuth, calculate_expires_at, now_ms,
-};
+#[cfg(test)]
+use super::types::now_ms;
+use super::types::{ActiveCredential, OAuthConfig, OAuthTokens, ServerAuth, calculate_expires_at};
 
 /// Default Anthropic OAuth settings (matching `tron login` CLI).
 pub fn default_config() -> OAuthConfig {
@@ -22,6 +22,7 @@ pub fn default_config() -> OAuthConfig {
             "user:inference".to_string(),
         ],
         token_expiry_buffer_seconds: 300,
+        token_refresh_skew_seconds: 0,
     }
 }
 
@@ -217,7 +218,10 @@ pub async fn load_server_auth_with_client(
 /// as "other process didn't update", so masking a parse error is OK in
 /// this narrow branch. Top-level load paths use `get_provider_auth`
 /// directly and propagate the error.
-fn read_tokens_from_disk(auth_path: &std::path::Path, account_label: &str) -> Option<OAuthTokens> {
+pub(super) fn read_tokens_from_disk(
+    auth_path: &std::path::Path,
+    account_label: &str,
+) -> Option<OAuthTokens> {
     let pa = super::storage::get_provider_auth(auth_path, "anthropic")
         .ok()
         .flatten()?;
@@ -244,7 +248,7 @@ fn persist_tokens(
 ///
 /// HTTP 400 with `invalid_grant` means the single-use refresh token was used
 /// by another process/server between our read and our refresh attempt.
-fn is_stale_token_error(e: &AuthError) -> bool {
+pub(super) fn is_stale_token_error(e: &AuthError) -> bool {
     matches!(e, AuthError::OAuth { status: 400, message } if message.contains("invalid_grant"))
 }
 
@@ -254,8 +258,9 @@ fn is_stale_token_error(e: &AuthError) -> bool {
 /// (for async tasks) and a file-level advisory lock (for multiple processes).
 /// Re-reads from disk after acquiring the file lock in case another process
 /// refreshed while we waited. On stale-token errors (HTTP 400 `invalid_grant`),
-/// retries once with tokens re-read from disk.
-async fn maybe_refresh_tokens(
+/// retries once with tokens re-read from disk; if that cannot help, surfaces
+/// [`AuthError::ReloginRequired`].
+pub(super) async fn maybe_refresh_tokens(
     auth_path: &std::path::Path,
     account_label: &str,
     tokens: &OAuthTokens,
@@ -267,8 +272,8 @@ async fn maybe_refresh_tokens(
 
     static REFRESH_LOCK: OnceLock<TokioMutex<()>> = OnceLock::new();
 
-    let buffer_ms = config.token_expiry_buffer_seconds * 1000;
-    if now_ms() + buffer_ms < tokens.expires_at {
+    let policy = config.refresh_policy();
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -277,7 +282,7 @@ async fn maybe_refresh_tokens(
     let _guard = lock.lock().await;
 
     // Re-check expiry after acquiring process lock
-    if now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -288,7 +293,7 @@ async fn maybe_refresh_tokens(
     // Also prefer disk tokens for refresh (may have a newer refresh_token).
     let disk_tokens = read_tokens_from_disk(auth_path, account_label);
     if let Some(ref dt) = disk_tokens
-        && now_ms() + buffer_ms < dt.expires_at
+        && policy.is_fresh(dt.expires_at)
     {
         return Ok((dt.clone(), true));
     }
@@ -304,17 +309,12 @@ async fn maybe_refresh_tokens(
         let config = config.clone();
         let tok = tok.clone();
         async move {
-            super::refresh::maybe_refresh(
-                &tok,
-                config.token_expiry_buffer_seconds,
-                "anthropic",
-                |refresh_tok| {
-                    let client = client.clone();
-                    let config = config.clone();
-                    let refresh_tok = refresh_tok.to_owned();
-                    async move { refresh_token_with_client(&config, &refresh_tok, &client).await }
-                },
-            )
+            super::refresh::maybe_refresh(&tok, policy, "anthropic", |refresh_tok| {
+                let client = client.clone();
+                let config = config.clone();
+                let refresh_tok = refresh_tok.to_owned();
+                async move { refresh_token_with_client(&config, &refresh_tok, &client).await }
+            })
             .await
         }
     };
@@ -330,7 +330,7 @@ async fn maybe_refresh_tokens(
 
             let retry_tokens = read_tokens_from_disk(&auth_path, &account_label_owned);
             match retry_tokens {
-                Some(rt) if now_ms() + buffer_ms < rt.expires_at => Ok((rt, true)),
+                Some(rt) if policy.is_fresh(rt.expires_at) => Ok((rt, true)),
                 Some(rt) => {
                     tracing::info!("retrying refresh with updated token from disk");
                     match do_refresh(&rt).await {
@@ -339,10 +339,12 @@ async fn maybe_refresh_tokens(
                             Ok((new_tokens, true))
                         }
                         Ok(not_refreshed) => Ok(not_refreshed),
-                        Err(retry_err) => Err(retry_err),
+                        Err(retry_err) => {
+                            Err(super::refresh::relogin_if_rejected("anthropic", retry_err))
+                        }
                     }
                 }
-                None => Err(e),
+                None => Err(super::refresh::relogin_if_rejected("anthropic", e)),
             }
         }
         Err(e) => Err(e),
@@ -560,122 +562,56 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn load_server_auth_single_account() {
+    async fn load_server_auth_respects_default_account() {
         let dir = tempfile::TempDir::new().unwrap();
         let path = dir.path().join("auth.json");
 
-        let tokens = OAuthTokens {
-            access_token: "tok-alice".to_string(),
-            refresh_token: "ref-alice".to_string(),
-            expires_at: now_ms() + 3_600_000,
-        };
-        crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+        for (label, token) in [("work", "work-tok"), ("personal", "personal-tok")] {
+            crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+                &path,
+                "anthropic",
+                label,
+                &OAuthTokens {
+                    access_token: token.to_string(),
+                    refresh_token: format!("ref-{label}"),
+                    expires_at: now_ms() + 3_600_000,
+                },
+            )
+            .unwrap();
+        }
+        crate::domains::auth::credentials::storage::set_default_account(
             &path,
             "anthropic",
-            "alice",
-            &tokens,
+            "personal",
         )
         .unwrap();
 
         let cfg = default_config();
-        let result = load_server_auth(&path, &cfg).await.unwrap();
-        let auth = result.unwrap();
-        assert_eq!(auth.token(), "tok-alice");
+        let auth = load_server_auth(&path, &cfg).await.unwrap().unwrap();
+        assert_eq!(auth.token(), "personal-tok");
     }
 
-    #[test]
-    fn stale_token_error_detected() {
-        let err = AuthError::OAuth {
-            status: 400,
-            message: r#"{"error":"invalid_grant"}"#.to_string(),
-        };
-        assert!(is_stale_token_error(&err));
-    }
-
-    #[test]
-    fn non_stale_errors_not_detected() {
-        assert!(!is_stale_token_error(&AuthError::OAuth {
-            status: 400,
-            message: "bad_request".to_string(),
-        }));
-        assert!(!is_stale_token_error(&AuthError::OAuth {
-            status: 401,
-            message: "invalid_grant".to_string(),
-        }));
-        assert!(!is_stale_token_error(&AuthError::OAuth {
-            status: 503,
-            message: "server_error".to_string(),
-        }));
-        assert!(!is_stale_token_error(&AuthError::Io(
-            std::io::Error::other("test",)
-        )));
-    }
-
-    #[test]
-    fn read_tokens_from_disk_account() {
+    #[tokio::test]
+    async fn load_server_auth_single_account() {
         let dir = tempfile::TempDir::new().unwrap();
         let path = dir.path().join("auth.json");
 
         let tokens = OAuthTokens {
-            access_token: "disk-tok".to_string(),
-            refresh_token: "disk-ref".to_string(),
+            access_token: "tok-alice".to_string(),
+            refresh_token: "ref-alice".to_string(),
             expires_at: now_ms() + 3_600_000,
         };
         crate::domains::auth::credentials::storage::save_account_oauth_tokens(
             &path,
             "anthropic",
-            "user@host",
+            "alice",
             &tokens,
         )
         .unwrap();
 
-        let loaded = read_tokens_from_disk(&path, "user@host").unwrap();
-        assert_eq!(loaded.access_token, "disk-tok");
-
-        assert!(read_tokens_from_disk(&path, "nonexistent").is_none());
-    }
-
-    #[tokio::test]
-    async fn maybe_refresh_uses_disk_tokens_after_lock() {
-        let dir = tempfile::TempDir::new().unwrap();
-        let path = dir.path().join("auth.json");
-
-        // Write expired tokens initially
-        let expired = OAuthTokens {
-            access_token: "expired-tok".to_string(),
-            refresh_token: "old-ref".to_string(),
-            expires_at: 0,
-        };
-        crate::domains::auth::credentials::storage::save_account_oauth_tokens(
-            &path,
-            "anthropic",
-            "user@host",
-            &expired,
-        )
-        .unwrap();
-
-        // Simulate another process having refreshed: write fresh tokens to disk
-        let fresh = OAuthTokens {
-            access_token: "fresh-tok".to_string(),
-            refresh_token: "new-ref".to_string(),
-            expires_at: now_ms() + 3_600_000,
-        };
-        crate::domains::auth::credentials::storage::save_account_oauth_tokens(
-            &path,
-            "anthropic",
-            "user@host",
-            &fresh,
-        )
-        .unwrap();
-
         let cfg = default_config();
-        let client = reqwest::Client::new();
-        let (tokens, refreshed) = maybe_refresh_tokens(&path, "user@host", &expired, &cfg, &client)
-            .await
-            .unwrap();
-
-        // Should return the fresh tokens from disk without making HTTP call
-        assert!(refreshed);
-        assert_eq!(tokens.access_token, "fresh-tok");
+        let result = load_server_auth(&path, &cfg).await.unwrap();
+        let auth = result.unwrap();
+        assert_eq!(auth.token(), "tok-alice");
     }
 }
@@ -0,0 +1,205 @@
+//! Anthropic account token refresh tests.
+//!
+//! Cover the lock-and-refresh path in [`super::anthropic`]: disk re-reads
+//! after the file lock, retrying transient token endpoint failures, and the
+//! relogin surface for a consumed refresh token.
+
+use super::anthropic::{
+    default_config, is_stale_token_error, maybe_refresh_tokens, read_tokens_from_disk,
+};
+use super::errors::AuthError;
+use super::types::{OAuthConfig, OAuthTokens, now_ms};
+
+#[test]
+fn stale_token_error_detected() {
+    let err = AuthError::OAuth {
+        status: 400,
+        message: r#"{"error":"invalid_grant"}"#.to_string(),
+    };
+    assert!(is_stale_token_error(&err));
+}
+
+#[test]
+fn non_stale_errors_not_detected() {
+    assert!(!is_stale_token_error(&AuthError::OAuth {
+        status: 400,
+        message: "bad_request".to_string(),
+    }));
+    assert!(!is_stale_token_error(&AuthError::OAuth {
+        status: 401,
+        message: "invalid_grant".to_string(),
+    }));
+    assert!(!is_stale_token_error(&AuthError::OAuth {
+        status: 503,
+        message: "server_error".to_string(),
+    }));
+    assert!(!is_stale_token_error(&AuthError::Io(
+        std::io::Error::other("test",)
+    )));
+}
+
+#[test]
+fn read_tokens_from_disk_account() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("auth.json");
+
+    let tokens = OAuthTokens {
+        access_token: "disk-tok".to_string(),
+        refresh_token: "disk-ref".to_string(),
+        expires_at: now_ms() + 3_600_000,
+    };
+    crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+        &path,
+        "anthropic",
+        "user@host",
+        &tokens,
+    )
+    .unwrap();
+
+    let loaded = read_tokens_from_disk(&path, "user@host").unwrap();
+    assert_eq!(loaded.access_token, "disk-tok");
+
+    assert!(read_tokens_from_disk(&path, "nonexistent").is_none());
+}
+
+#[tokio::test]
+async fn maybe_refresh_uses_disk_tokens_after_lock() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("auth.json");
+
+    // Write expired tokens initially
+    let expired = OAuthTokens {
+        access_token: "expired-tok".to_string(),
+        refresh_token: "old-ref".to_string(),
+        expires_at: 0,
+    };
+    crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+        &path,
+        "anthropic",
+        "user@host",
+        &expired,
+    )
+    .unwrap();
+
+    // Simulate another process having refreshed: write fresh tokens to disk
+    let fresh = OAuthTokens {
+        access_token: "fresh-tok".to_string(),
+        refresh_token: "new-ref".to_string(),
+        expires_at: now_ms() + 3_600_000,
+    };
+    crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+        &path,
+        "anthropic",
+        "user@host",
+        &fresh,
+    )
+    .unwrap();
+
+    let cfg = default_config();
+    let client = reqwest::Client::new();
+    let (tokens, refreshed) = maybe_refresh_tokens(&path, "user@host", &expired, &cfg, &client)
+        .await
+        .unwrap();
+
+    // Should return the fresh tokens from disk without making HTTP call
+    assert!(refreshed);
+    assert_eq!(tokens.access_token, "fresh-tok");
+}
+
+fn mock_token_config(server: &wiremock::MockServer) -> OAuthConfig {
+    OAuthConfig {
+        token_url: format!("{}/v1/oauth/token", server.uri()),
+        ..default_config()
+    }
+}
+
+fn save_expired_account(path: &std::path::Path) -> OAuthTokens {
+    let expired = OAuthTokens {
+        access_token: "expired-tok".to_string(),
+        refresh_token: "old-ref".to_string(),
+        expires_at: 0,
+    };
+    crate::domains::auth::credentials::storage::save_account_oauth_tokens(
+        path,
+        "anthropic",
+        "user@host",
+        &expired,
+    )
+    .unwrap();
+    expired
+}
+
+#[tokio::test]
+async fn refresh_retries_token_endpoint_server_error() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth/token"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("upstream error"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "new-tok",
+            "refresh_token": "new-ref",
+            "expires_in": 3600,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("auth.json");
+    let expired = save_expired_account(&path);
+    let cfg = mock_token_config(&server);
+
+    let (tokens, refreshed) =
+        maybe_refresh_tokens(&path, "user@host", &expired, &cfg, &reqwest::Client::new())
+            .await
+            .unwrap();
+
+    assert!(refreshed);
+    assert_eq!(tokens.access_token, "new-tok");
+    assert_eq!(
+        read_tokens_from_disk(&path, "user@host")
+            .unwrap()
+            .access_token,
+        "new-tok"
+    );
+}
+
+#[tokio::test]
+async fn refresh_invalid_grant_requires_relogin() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_string(r#"{"error":"invalid_grant"}"#))
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("auth.json");
+    let expired = save_expired_account(&path);
+    let cfg = mock_token_config(&server);
+
+    let err = maybe_refresh_tokens(&path, "user@host", &expired, &cfg, &reqwest::Client::new())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, AuthError::ReloginRequired { ref provider, .. } if provider == "anthropic"),
+        "unexpected error: {err}"
+    );
+    assert!(!err.is_transient());
+    // One attempt with the in-memory token, one with the disk re-read;
+    // neither is retried with backoff.
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
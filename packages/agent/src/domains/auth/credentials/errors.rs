@@ -33,6 +33,18 @@ pub enum AuthError {
     #[error("token expired and refresh failed: {0}")]
     TokenExpired(String),
 
+    /// The provider rejected the refresh token (`invalid_grant`). Retrying
+    /// cannot help; the user must sign in again.
+    #[error(
+        "{provider} refresh token was rejected: {message}. Re-authenticate via `tron auth {provider}`."
+    )]
+    ReloginRequired {
+        /// Provider identifier (e.g. "anthropic").
+        provider: String,
+        /// Token endpoint error body.
+        message: String,
+    },
+
     /// No authentication configured for the given provider.
     #[error("no auth configured for provider: {0}")]
     NotConfigured(String),
@@ -79,8 +91,9 @@ impl AuthError {
                         s.is_server_error() || s == reqwest::StatusCode::TOO_MANY_REQUESTS
                     })
             }
-            Self::OAuth { status, .. } => matches!(status, 408 | 429 | 502 | 503 | 504),
+            Self::OAuth { status, .. } => matches!(status, 408 | 429 | 500 | 502 | 503 | 504),
             Self::TokenExpired(_)
+            | Self::ReloginRequired { .. }
             | Self::NotConfigured(_)
             | Self::MalformedProviderAuth { .. }
             | Self::MalformedAuthFile { .. }
@@ -184,6 +197,25 @@ mod tests {
         assert!(err.is_transient());
     }
 
+    #[test]
+    fn oauth_500_is_transient() {
+        let err = AuthError::OAuth {
+            status: 500,
+            message: "internal error".into(),
+        };
+        assert!(err.is_transient());
+    }
+
+    #[test]
+    fn relogin_required_is_not_transient() {
+        let err = AuthError::ReloginRequired {
+            provider: "anthropic".into(),
+            message: "invalid_grant".into(),
+        };
+        assert!(!err.is_transient());
+        assert!(err.to_string().contains("tron auth anthropic"));
+    }
+
     #[test]
     fn oauth_401_is_not_transient() {
         let err = AuthError::OAuth {
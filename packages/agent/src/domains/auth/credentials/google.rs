@@ -5,7 +5,9 @@
 use super::errors::AuthError;
 #[cfg(test)]
 use super::types::now_ms;
-use super::types::{GoogleAuth, OAuthConfig, OAuthTokens, ServerAuth, calculate_expires_at};
+use super::types::{
+    GoogleAuth, OAuthConfig, OAuthTokens, RefreshPolicy, ServerAuth, calculate_expires_at,
+};
 
 /// Default Google OAuth configuration for the standard Gemini API.
 ///
@@ -21,6 +23,7 @@ pub fn cloud_code_assist_config() -> GoogleOAuthConfig {
             client_secret: None,
             scopes: vec!["https://www.googleapis.com/auth/generative-language".to_string()],
             token_expiry_buffer_seconds: 300,
+            token_refresh_skew_seconds: 0,
         },
         api_endpoint: "https://generativelanguage.googleapis.com".to_string(),
         api_version: "v1beta".to_string(),
@@ -192,6 +195,26 @@ pub async fn load_server_auth_with_client(
     auth_path: &std::path::Path,
     credential_override: Option<&super::types::ActiveCredential>,
     client: &reqwest::Client,
+) -> Result<Option<GoogleAuth>, AuthError> {
+    load_server_auth_with_policy(
+        auth_path,
+        credential_override,
+        cloud_code_assist_config().oauth.refresh_policy(),
+        client,
+    )
+    .await
+}
+
+/// Load server auth, refreshing OAuth tokens according to `policy`.
+///
+/// Use this to refresh earlier than the default buffer, e.g. to absorb
+/// clock skew against the token server.
+#[tracing::instrument(skip_all, fields(provider = "google"))]
+pub async fn load_server_auth_with_policy(
+    auth_path: &std::path::Path,
+    credential_override: Option<&super::types::ActiveCredential>,
+    policy: RefreshPolicy,
+    client: &reqwest::Client,
 ) -> Result<Option<GoogleAuth>, AuthError> {
     // Strict parse: a retired `endpoint` field or any other unknown key
     // surfaces as `AuthError::MalformedProviderAuth` with re-auth guidance.
@@ -211,7 +234,7 @@ pub async fn load_server_auth_with_client(
                 "Google OAuth requires a client_id — configure one in Settings > Providers > Google".into(),
             ))?;
 
-            let cfg_with_creds = GoogleOAuthConfig {
+            let cfg = GoogleOAuthConfig {
                 oauth: OAuthConfig {
                     client_id,
                     client_secret: gpa.client_secret.clone().or(cfg.oauth.client_secret),
@@ -220,7 +243,7 @@ pub async fn load_server_auth_with_client(
                 ..cfg
             };
 
-            match maybe_refresh_tokens(auth_path, &acct.label, &acct.oauth, &cfg_with_creds, client)
+            match maybe_refresh_tokens(auth_path, &acct.label, &acct.oauth, &cfg, policy, client)
                 .await
             {
                 Ok((tokens, _refreshed)) => Ok(Some(GoogleAuth {
@@ -287,12 +310,14 @@ fn is_stale_token_error(e: &AuthError) -> bool {
 /// (for async tasks) and a file-level advisory lock (for multiple processes).
 /// Re-reads from disk after acquiring the file lock in case another process
 /// refreshed while we waited. On stale-token errors (HTTP 400 `invalid_grant`),
-/// retries once with tokens re-read from disk.
+/// retries once with tokens re-read from disk; if that cannot help, surfaces
+/// [`AuthError::ReloginRequired`].
 async fn maybe_refresh_tokens(
     auth_path: &std::path::Path,
     account_label: &str,
     tokens: &OAuthTokens,
     config: &GoogleOAuthConfig,
+    policy: RefreshPolicy,
     client: &reqwest::Client,
 ) -> Result<(OAuthTokens, bool), AuthError> {
     use std::sync::OnceLock;
@@ -300,15 +325,14 @@ async fn maybe_refresh_tokens(
 
     static REFRESH_LOCK: OnceLock<TokioMutex<()>> = OnceLock::new();
 
-    let buffer_ms = config.oauth.token_expiry_buffer_seconds * 1000;
-    if super::types::now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
     let lock = REFRESH_LOCK.get_or_init(|| TokioMutex::new(()));
     let _guard = lock.lock().await;
 
-    if super::types::now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -316,7 +340,7 @@ async fn maybe_refresh_tokens(
 
     let disk_tokens = read_tokens_from_disk(auth_path, account_label);
     if let Some(ref dt) = disk_tokens
-        && super::types::now_ms() + buffer_ms < dt.expires_at
+        && policy.is_fresh(dt.expires_at)
     {
         return Ok((dt.clone(), true));
     }
@@ -332,17 +356,12 @@ async fn maybe_refresh_tokens(
         let config = config.clone();
         let tok = tok.clone();
         async move {
-            super::refresh::maybe_refresh(
-                &tok,
-                config.oauth.token_expiry_buffer_seconds,
-                "google",
-                |refresh_tok| {
-                    let client = client.clone();
-                    let config = config.clone();
-                    let refresh_tok = refresh_tok.to_owned();
-                    async move { refresh_token_with_client(&config, &refresh_tok, &client).await }
-                },
-            )
+            super::refresh::maybe_refresh(&tok, policy, "google", |refresh_tok| {
+                let client = client.clone();
+                let config = config.clone();
+                let refresh_tok = refresh_tok.to_owned();
+                async move { refresh_token_with_client(&config, &refresh_tok, &client).await }
+            })
             .await
         }
     };
@@ -360,7 +379,7 @@ async fn maybe_refresh_tokens(
 
             let retry_tokens = read_tokens_from_disk(&auth_path, &account_label_owned);
             match retry_tokens {
-                Some(rt) if super::types::now_ms() + buffer_ms < rt.expires_at => Ok((rt, true)),
+                Some(rt) if policy.is_fresh(rt.expires_at) => Ok((rt, true)),
                 Some(rt) => {
                     tracing::info!("retrying Google refresh with updated token from disk");
                     match do_refresh(&rt).await {
@@ -369,10 +388,12 @@ async fn maybe_refresh_tokens(
                             Ok((new_tokens, true))
                         }
                         Ok(not_refreshed) => Ok(not_refreshed),
-                        Err(retry_err) => Err(retry_err),
+                        Err(retry_err) => {
+                            Err(super::refresh::relogin_if_rejected("google", retry_err))
+                        }
                     }
                 }
-                None => Err(e),
+                None => Err(super::refresh::relogin_if_rejected("google", e)),
             }
         }
         Err(e) => Err(e),
@@ -689,10 +710,16 @@ mod tests {
         let mut cfg = cloud_code_assist_config();
         cfg.oauth.client_id = "client-id".to_string();
         let client = reqwest::Client::new();
-        let (tokens, refreshed) =
-            maybe_refresh_tokens(&path, "user@example.com", &expired, &cfg, &client)
-                .await
-                .unwrap();
+        let (tokens, refreshed) = maybe_refresh_tokens(
+            &path,
+            "user@example.com",
+            &expired,
+            &cfg,
+            cfg.oauth.refresh_policy(),
+            &client,
+        )
+        .await
+        .unwrap();
 
         assert!(refreshed);
         assert_eq!(tokens.access_token, "fresh-tok");
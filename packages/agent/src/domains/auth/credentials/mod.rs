@@ -21,6 +21,8 @@
 
 mod accounts;
 pub mod anthropic;
+#[cfg(test)]
+mod anthropic_refresh_tests;
 pub mod errors;
 pub mod google;
 pub mod openai;
@@ -54,7 +56,10 @@ pub(crate) fn shared_auth_client() -> &'static reqwest::Client {
 pub(crate) use crate::shared::server::error_mapping::map_auth_error;
 pub(crate) use accounts::*;
 pub use errors::AuthError;
-pub use pkce::{PkcePair, generate_pkce};
+pub use pkce::{
+    PkceChallengeMethod, PkcePair, generate_oauth_state, generate_pkce, generate_pkce_with,
+    pkce_challenge, verify_oauth_state,
+};
 pub(crate) use provider_state::*;
 pub(crate) use storage::{
     acquire_auth_file_lock, clear_provider_auth, get_google_provider_auth, get_provider_auth,
@@ -63,8 +68,8 @@ pub(crate) use storage::{
 pub use storage::{auth_file_path, load_auth_storage, save_auth_storage};
 pub use types::{
     AccountEntry, ActiveCredential, ApiKeyEntry, AuthStorage, GoogleAuth, GoogleProviderAuth,
-    OAuthConfig, OAuthTokenRefreshResponse, OAuthTokens, OpenAIAuthPath, ProviderAuth, ServerAuth,
-    ServiceAuth, calculate_expires_at, now_ms, should_refresh,
+    OAuthConfig, OAuthTokenRefreshResponse, OAuthTokens, OpenAIAuthPath, ProviderAuth,
+    RefreshPolicy, ServerAuth, ServiceAuth, calculate_expires_at, now_ms, should_refresh,
 };
 
 pub(crate) const DEFAULT_API_KEY_LABEL: &str = "Default";
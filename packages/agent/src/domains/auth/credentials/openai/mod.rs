@@ -7,9 +7,11 @@
 //! (`http://localhost:1455/auth/callback`) for the callback.
 
 use super::errors::AuthError;
+#[cfg(test)]
+use super::types::now_ms;
 use super::types::{
-    ActiveCredential, OAuthConfig, OAuthTokens, OpenAIAuthPath, ProviderAuth, ServerAuth,
-    calculate_expires_at, now_ms,
+    ActiveCredential, OAuthConfig, OAuthTokens, OpenAIAuthPath, ProviderAuth, RefreshPolicy,
+    ServerAuth, calculate_expires_at,
 };
 
 /// `OpenAI` token endpoint URL.
@@ -45,6 +47,7 @@ pub fn default_config() -> OAuthConfig {
         client_secret: None,
         scopes: SCOPES.iter().map(|s| (*s).to_string()).collect(),
         token_expiry_buffer_seconds: TOKEN_EXPIRY_BUFFER_SECONDS,
+        token_refresh_skew_seconds: 0,
     }
 }
 
@@ -188,6 +191,26 @@ pub async fn load_server_auth_with_client(
     auth_path: &std::path::Path,
     credential_override: Option<&ActiveCredential>,
     client: &reqwest::Client,
+) -> Result<Option<ServerAuth>, AuthError> {
+    load_server_auth_with_policy(
+        auth_path,
+        credential_override,
+        default_config().refresh_policy(),
+        client,
+    )
+    .await
+}
+
+/// Load server auth, refreshing OAuth tokens according to `policy`.
+///
+/// Use this to refresh earlier than the default buffer, e.g. to absorb
+/// clock skew against the token server.
+#[tracing::instrument(skip_all, fields(provider = "openai"))]
+pub async fn load_server_auth_with_policy(
+    auth_path: &std::path::Path,
+    credential_override: Option<&ActiveCredential>,
+    policy: RefreshPolicy,
+    client: &reqwest::Client,
 ) -> Result<Option<ServerAuth>, AuthError> {
     let Some(pa) = super::storage::get_provider_auth(auth_path, PROVIDER_KEY)? else {
         return Ok(None);
@@ -200,7 +223,7 @@ pub async fn load_server_auth_with_client(
     match resolved {
         super::ResolvedCredential::OAuthAccount(acct) => {
             let (tokens, _refreshed) =
-                maybe_refresh_tokens(auth_path, &acct.label, &acct.oauth, client).await?;
+                maybe_refresh_tokens(auth_path, &acct.label, &acct.oauth, policy, client).await?;
             Ok(Some(ServerAuth::from_oauth(&tokens)))
         }
         super::ResolvedCredential::ApiKey(key) => Ok(Some(ServerAuth::from_api_key(&key.key))),
@@ -281,11 +304,13 @@ fn is_stale_token_error(e: &AuthError) -> bool {
 /// (for async tasks) and a file-level advisory lock (for multiple processes).
 /// Re-reads from disk after acquiring the file lock in case another process
 /// refreshed while we waited. On stale-token errors (HTTP 400 `invalid_grant`),
-/// retries once with tokens re-read from disk.
+/// retries once with tokens re-read from disk; if that cannot help, surfaces
+/// [`AuthError::ReloginRequired`].
 async fn maybe_refresh_tokens(
     auth_path: &std::path::Path,
     account_label: &str,
     tokens: &OAuthTokens,
+    policy: RefreshPolicy,
     client: &reqwest::Client,
 ) -> Result<(OAuthTokens, bool), AuthError> {
     use std::sync::OnceLock;
@@ -293,8 +318,7 @@ async fn maybe_refresh_tokens(
 
     static REFRESH_LOCK: OnceLock<TokioMutex<()>> = OnceLock::new();
 
-    let buffer_ms = TOKEN_EXPIRY_BUFFER_SECONDS * 1000;
-    if now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -303,7 +327,7 @@ async fn maybe_refresh_tokens(
     let _guard = lock.lock().await;
 
     // Re-check expiry after acquiring process lock
-    if now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -313,7 +337,7 @@ async fn maybe_refresh_tokens(
     // Re-read from disk — another process may have refreshed while we waited.
     let disk_tokens = read_tokens_from_disk(auth_path, account_label);
     if let Some(ref dt) = disk_tokens
-        && now_ms() + buffer_ms < dt.expires_at
+        && policy.is_fresh(dt.expires_at)
     {
         return Ok((dt.clone(), true));
     }
@@ -327,16 +351,11 @@ async fn maybe_refresh_tokens(
         let client = client.clone();
         let tok = tok.clone();
         async move {
-            super::refresh::maybe_refresh(
-                &tok,
-                TOKEN_EXPIRY_BUFFER_SECONDS,
-                "openai",
-                |refresh_tok| {
-                    let client = client.clone();
-                    let refresh_tok = refresh_tok.to_owned();
-                    async move { refresh_token_with_client(&refresh_tok, &client).await }
-                },
-            )
+            super::refresh::maybe_refresh(&tok, policy, "openai", |refresh_tok| {
+                let client = client.clone();
+                let refresh_tok = refresh_tok.to_owned();
+                async move { refresh_token_with_client(&refresh_tok, &client).await }
+            })
             .await
         }
     };
@@ -354,7 +373,7 @@ async fn maybe_refresh_tokens(
 
             let retry_tokens = read_tokens_from_disk(&auth_path, &account_label_owned);
             match retry_tokens {
-                Some(rt) if now_ms() + buffer_ms < rt.expires_at => Ok((rt, true)),
+                Some(rt) if policy.is_fresh(rt.expires_at) => Ok((rt, true)),
                 Some(rt) => {
                     tracing::info!("retrying OpenAI refresh with updated token from disk");
                     match do_refresh(&rt).await {
@@ -363,10 +382,12 @@ async fn maybe_refresh_tokens(
                             Ok((new_tokens, true))
                         }
                         Ok(not_refreshed) => Ok(not_refreshed),
-                        Err(retry_err) => Err(retry_err),
+                        Err(retry_err) => {
+                            Err(super::refresh::relogin_if_rejected(PROVIDER_KEY, retry_err))
+                        }
                     }
                 }
-                None => Err(e),
+                None => Err(super::refresh::relogin_if_rejected(PROVIDER_KEY, e)),
             }
         }
         Err(e) => Err(e),
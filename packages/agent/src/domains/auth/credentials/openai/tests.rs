@@ -373,9 +373,15 @@ async fn maybe_refresh_uses_disk_tokens_after_lock() {
     .unwrap();
 
     let client = reqwest::Client::new();
-    let (tokens, refreshed) = maybe_refresh_tokens(&path, "user@host", &expired, &client)
-        .await
-        .unwrap();
+    let (tokens, refreshed) = maybe_refresh_tokens(
+        &path,
+        "user@host",
+        &expired,
+        default_config().refresh_policy(),
+        &client,
+    )
+    .await
+    .unwrap();
 
     // Should return the fresh tokens from disk without making HTTP call
     assert!(refreshed);
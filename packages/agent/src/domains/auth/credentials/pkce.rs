@@ -1,40 +1,103 @@
-//! PKCE (Proof Key for Code Exchange) generation.
+//! PKCE (Proof Key for Code Exchange) generation and OAuth `state` checks.
 //!
-//! Used by all OAuth providers for secure authorization code flows.
+//! Used by all OAuth providers for secure authorization code flows. The
+//! `state` helpers guard the redirect callback against CSRF.
 
 use base64::Engine;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use sha2::{Digest, Sha256};
 
+/// How a PKCE challenge is derived from its verifier (RFC 7636 §4.2).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PkceChallengeMethod {
+    /// Challenge is the base64url SHA-256 of the verifier.
+    #[default]
+    S256,
+    /// Challenge is the verifier itself. Only for servers without S256.
+    Plain,
+}
+
+impl PkceChallengeMethod {
+    /// Value for the `code_challenge_method` query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::S256 => "S256",
+            Self::Plain => "plain",
+        }
+    }
+}
+
 /// A PKCE verifier/challenge pair.
 #[derive(Clone, Debug)]
 pub struct PkcePair {
     /// Random verifier string (base64url, no padding).
     pub verifier: String,
-    /// SHA-256 challenge of the verifier (base64url, no padding).
+    /// Challenge derived from the verifier with `method`.
     pub challenge: String,
+    /// Method used to derive `challenge`.
+    pub method: PkceChallengeMethod,
 }
 
-/// Generate a new PKCE verifier/challenge pair.
+/// Generate a new PKCE verifier/challenge pair using S256.
 ///
 /// The verifier is 32 cryptographically-secure random bytes encoded as
 /// base64url (no padding). The challenge is the SHA-256 hash of the
 /// verifier, also base64url-encoded.
 pub fn generate_pkce() -> PkcePair {
+    generate_pkce_with(PkceChallengeMethod::default())
+}
+
+/// Generate a new PKCE verifier/challenge pair using `method`.
+pub fn generate_pkce_with(method: PkceChallengeMethod) -> PkcePair {
     let random_bytes: [u8; 32] = rand::random();
     let verifier = URL_SAFE_NO_PAD.encode(random_bytes);
-
-    let mut hasher = Sha256::new();
-    hasher.update(verifier.as_bytes());
-    let hash = hasher.finalize();
-    let challenge = URL_SAFE_NO_PAD.encode(hash);
+    let challenge = pkce_challenge(&verifier, method);
 
     PkcePair {
         verifier,
         challenge,
+        method,
+    }
+}
+
+/// Derive the challenge for `verifier` using `method`.
+pub fn pkce_challenge(verifier: &str, method: PkceChallengeMethod) -> String {
+    match method {
+        PkceChallengeMethod::S256 => {
+            let mut hasher = Sha256::new();
+            hasher.update(verifier.as_bytes());
+            URL_SAFE_NO_PAD.encode(hasher.finalize())
+        }
+        PkceChallengeMethod::Plain => verifier.to_string(),
     }
 }
 
+/// Generate a random OAuth `state` value for an authorization request.
+///
+/// 32 cryptographically-secure random bytes encoded as base64url (no
+/// padding), so it is safe to place in a query string unescaped.
+pub fn generate_oauth_state() -> String {
+    let random_bytes: [u8; 32] = rand::random();
+    URL_SAFE_NO_PAD.encode(random_bytes)
+}
+
+/// Check the `state` returned on the OAuth callback against the one sent.
+///
+/// Compares in constant time so the callback does not leak how much of the
+/// expected value an attacker guessed. An empty `expected` never matches.
+pub fn verify_oauth_state(expected: &str, received: &str) -> bool {
+    if expected.is_empty() {
+        return false;
+    }
+    let (a, b) = (expected.as_bytes(), received.as_bytes());
+    let len = a.len().max(b.len());
+    let mut diff = u8::from(a.len() != b.len());
+    for i in 0..len {
+        diff |= a.get(i).copied().unwrap_or(0) ^ b.get(i).copied().unwrap_or(0);
+    }
+    diff == 0
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -94,6 +157,58 @@ mod tests {
         assert_eq!(pair.challenge, expected);
     }
 
+    #[test]
+    fn default_method_is_s256() {
+        let pair = generate_pkce();
+        assert_eq!(pair.method, PkceChallengeMethod::S256);
+        assert_eq!(pair.method.as_str(), "S256");
+    }
+
+    #[test]
+    fn s256_challenge_matches_rfc7636_example() {
+        // RFC 7636 Appendix B.
+        let verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        assert_eq!(
+            pkce_challenge(verifier, PkceChallengeMethod::S256),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn plain_challenge_is_verifier() {
+        let pair = generate_pkce_with(PkceChallengeMethod::Plain);
+        assert_eq!(pair.challenge, pair.verifier);
+        assert_eq!(pair.method.as_str(), "plain");
+    }
+
+    #[test]
+    fn oauth_state_is_unique_base64url() {
+        let a = generate_oauth_state();
+        let b = generate_oauth_state();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 43);
+        assert!(
+            a.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn oauth_state_matching_is_accepted() {
+        let state = generate_oauth_state();
+        assert!(verify_oauth_state(&state, &state));
+    }
+
+    #[test]
+    fn oauth_state_mismatch_is_rejected() {
+        let state = generate_oauth_state();
+        assert!(!verify_oauth_state(&state, &generate_oauth_state()));
+        assert!(!verify_oauth_state(&state, &state[..42]));
+        assert!(!verify_oauth_state(&state, &format!("{state}x")));
+        assert!(!verify_oauth_state(&state, ""));
+        assert!(!verify_oauth_state("", ""));
+    }
+
     #[test]
     fn each_call_produces_unique_pair() {
         let a = generate_pkce();
@@ -1,26 +1,40 @@
 //! Shared OAuth token refresh logic.
 //!
 //! Eliminates duplication across Anthropic, Google, and `OpenAI` auth modules.
+//! Transient token endpoint failures (network errors, 5xx, 429) are retried
+//! with exponential backoff; a rejected refresh token (`invalid_grant`) is
+//! never retried.
+
+use std::time::Duration;
 
 use super::errors::AuthError;
-use super::types::{OAuthTokens, now_ms};
+use super::types::{OAuthTokens, RefreshPolicy};
+use crate::shared::foundation::retry::calculate_backoff_delay;
+
+/// Maximum refresh attempts, including the first.
+const REFRESH_MAX_ATTEMPTS: u32 = 3;
+/// Backoff before the first retry, doubling per retry.
+const REFRESH_BASE_DELAY_MS: u64 = 250;
+/// Cap on a single backoff delay.
+const REFRESH_MAX_DELAY_MS: u64 = 2_000;
 
 /// Check if tokens need refreshing, and refresh if expired.
 ///
 /// Returns `(tokens, was_refreshed)`. The `refresh_fn` is only called if the
-/// token is expired (accounting for `buffer_seconds`).
+/// token is expired (accounting for the buffer and skew margins in `policy`),
+/// and is called again after a backoff while it fails transiently, up to
+/// [`REFRESH_MAX_ATTEMPTS`] times.
 pub(crate) async fn maybe_refresh<F, Fut>(
     tokens: &OAuthTokens,
-    buffer_seconds: i64,
+    policy: RefreshPolicy,
     provider_name: &str,
-    refresh_fn: F,
+    mut refresh_fn: F,
 ) -> Result<(OAuthTokens, bool), AuthError>
 where
-    F: FnOnce(&str) -> Fut,
+    F: FnMut(&str) -> Fut,
     Fut: std::future::Future<Output = Result<OAuthTokens, AuthError>>,
 {
-    let buffer_ms = buffer_seconds * 1000;
-    if now_ms() + buffer_ms < tokens.expires_at {
+    if policy.is_fresh(tokens.expires_at) {
         return Ok((tokens.clone(), false));
     }
 
@@ -28,21 +42,60 @@ where
         provider = provider_name,
         "OAuth token expired, refreshing..."
     );
-    match refresh_fn(&tokens.refresh_token).await {
-        Ok(new_tokens) => {
-            metrics::counter!("auth_refresh_total", "provider" => provider_name.to_owned(), "status" => "success").increment(1);
-            Ok((new_tokens, true))
-        }
-        Err(e) => {
-            metrics::counter!("auth_refresh_total", "provider" => provider_name.to_owned(), "status" => "failure").increment(1);
-            Err(e)
+    let mut attempt = 0;
+    loop {
+        match refresh_fn(&tokens.refresh_token).await {
+            Ok(new_tokens) => {
+                metrics::counter!("auth_refresh_total", "provider" => provider_name.to_owned(), "status" => "success").increment(1);
+                return Ok((new_tokens, true));
+            }
+            Err(e) if e.is_transient() && attempt + 1 < REFRESH_MAX_ATTEMPTS => {
+                let delay_ms = calculate_backoff_delay(
+                    attempt,
+                    REFRESH_BASE_DELAY_MS,
+                    REFRESH_MAX_DELAY_MS,
+                    0.0,
+                );
+                tracing::warn!(
+                    provider = provider_name,
+                    attempt = attempt + 1,
+                    delay_ms,
+                    "OAuth token refresh failed transiently, retrying: {e}"
+                );
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                metrics::counter!("auth_refresh_total", "provider" => provider_name.to_owned(), "status" => "failure").increment(1);
+                return Err(e);
+            }
         }
     }
 }
 
+/// Whether the token endpoint rejected the refresh token itself.
+fn is_invalid_grant(e: &AuthError) -> bool {
+    matches!(e, AuthError::OAuth { status: 400, message } if message.contains("invalid_grant"))
+}
+
+/// Turn an `invalid_grant` failure into [`AuthError::ReloginRequired`];
+/// other errors pass through unchanged.
+pub(crate) fn relogin_if_rejected(provider: &str, e: AuthError) -> AuthError {
+    if !is_invalid_grant(&e) {
+        return e;
+    }
+    AuthError::ReloginRequired {
+        provider: provider.to_string(),
+        message: e.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domains::auth::credentials::types::now_ms;
+
+    const POLICY: RefreshPolicy = RefreshPolicy::new(300_000);
 
     fn make_tokens(expires_at: i64) -> OAuthTokens {
         OAuthTokens {
@@ -55,7 +108,7 @@ mod tests {
     #[tokio::test]
     async fn fresh_token_no_refresh() {
         let tokens = make_tokens(now_ms() + 3_600_000); // 1 hour from now
-        let (result, refreshed) = maybe_refresh(&tokens, 300, "test", |_| async {
+        let (result, refreshed) = maybe_refresh(&tokens, POLICY, "test", |_| async {
             panic!("refresh_fn should not be called for fresh tokens")
         })
         .await
@@ -68,7 +121,7 @@ mod tests {
     #[tokio::test]
     async fn expired_token_triggers_refresh() {
         let tokens = make_tokens(now_ms() - 1000); // already expired
-        let (result, refreshed) = maybe_refresh(&tokens, 300, "test", |refresh_tok| {
+        let (result, refreshed) = maybe_refresh(&tokens, POLICY, "test", |refresh_tok| {
             let refresh_tok = refresh_tok.to_owned();
             async move {
                 assert_eq!(refresh_tok, "ref-tok");
@@ -89,7 +142,7 @@ mod tests {
     #[tokio::test]
     async fn refresh_failure_propagates_error() {
         let tokens = make_tokens(now_ms() - 1000);
-        let result = maybe_refresh(&tokens, 300, "test", |_| async {
+        let result = maybe_refresh(&tokens, POLICY, "test", |_| async {
             Err(AuthError::OAuth {
                 status: 401,
                 message: "invalid refresh token".to_string(),
@@ -104,16 +157,106 @@ mod tests {
     async fn buffer_seconds_applied() {
         // Token expires in 30s, but buffer is 60s → should trigger refresh
         let tokens = make_tokens(now_ms() + 30_000);
-        let (_, refreshed) = maybe_refresh(&tokens, 60, "test", |_| async {
-            Ok(OAuthTokens {
-                access_token: "new".to_string(),
-                refresh_token: "ref".to_string(),
-                expires_at: now_ms() + 3_600_000,
+        let (_, refreshed) =
+            maybe_refresh(&tokens, RefreshPolicy::new(60_000), "test", |_| async {
+                Ok(OAuthTokens {
+                    access_token: "new".to_string(),
+                    refresh_token: "ref".to_string(),
+                    expires_at: now_ms() + 3_600_000,
+                })
             })
+            .await
+            .unwrap();
+
+        assert!(refreshed, "should refresh when within buffer window");
+    }
+
+    #[tokio::test]
+    async fn transient_failure_is_retried() {
+        let tokens = make_tokens(now_ms() - 1000);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let (result, refreshed) = maybe_refresh(&tokens, POLICY, "test", |_| {
+            let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                if call == 0 {
+                    return Err(AuthError::OAuth {
+                        status: 503,
+                        message: "unavailable".to_string(),
+                    });
+                }
+                Ok(OAuthTokens {
+                    access_token: "new-tok".to_string(),
+                    refresh_token: "new-ref".to_string(),
+                    expires_at: now_ms() + 3_600_000,
+                })
+            }
         })
         .await
         .unwrap();
 
-        assert!(refreshed, "should refresh when within buffer window");
+        assert!(refreshed);
+        assert_eq!(result.access_token, "new-tok");
+        assert_eq!(calls.into_inner(), 2);
+    }
+
+    #[tokio::test]
+    async fn transient_retries_are_bounded() {
+        let tokens = make_tokens(now_ms() - 1000);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result = maybe_refresh(&tokens, POLICY, "test", |_| {
+            let _ = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async {
+                Err(AuthError::OAuth {
+                    status: 502,
+                    message: "bad gateway".to_string(),
+                })
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(AuthError::OAuth { status: 502, .. })));
+        assert_eq!(calls.into_inner(), REFRESH_MAX_ATTEMPTS);
+    }
+
+    #[tokio::test]
+    async fn invalid_grant_is_not_retried() {
+        let tokens = make_tokens(now_ms() - 1000);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result = maybe_refresh(&tokens, POLICY, "test", |_| {
+            let _ = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async {
+                Err(AuthError::OAuth {
+                    status: 400,
+                    message: r#"{"error":"invalid_grant"}"#.to_string(),
+                })
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.into_inner(), 1);
+    }
+
+    #[test]
+    fn relogin_if_rejected_maps_invalid_grant_only() {
+        let rejected = relogin_if_rejected(
+            "anthropic",
+            AuthError::OAuth {
+                status: 400,
+                message: r#"{"error":"invalid_grant"}"#.to_string(),
+            },
+        );
+        assert!(
+            matches!(rejected, AuthError::ReloginRequired { ref provider, .. } if provider == "anthropic")
+        );
+
+        let other = relogin_if_rejected(
+            "anthropic",
+            AuthError::OAuth {
+                status: 503,
+                message: "unavailable".to_string(),
+            },
+        );
+        assert!(matches!(other, AuthError::OAuth { status: 503, .. }));
     }
 }
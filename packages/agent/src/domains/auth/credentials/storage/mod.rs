@@ -4,6 +4,9 @@
 //! (`0o600`). Fresh Mac installs intentionally seed this file as `{}`; the
 //! loader treats only that exact empty object as a pristine install sentinel and
 //! materializes the normal schema on the first write.
+//!
+//! Loading warns when an existing file is group/other-accessible; every save
+//! re-asserts `0o600` through [`ensure_secure_permissions`].
 
 use std::path::{Path, PathBuf};
 
@@ -45,6 +48,7 @@ pub fn load_auth_storage(path: &Path) -> Result<Option<AuthStorage>, AuthError>
         Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
         Err(e) => return Err(AuthError::Io(e)),
     };
+    warn_if_insecure_permissions(path);
 
     let value = serde_json::from_str::<serde_json::Value>(&data).map_err(|e| {
         AuthError::MalformedAuthFile {
@@ -107,7 +111,50 @@ pub fn save_auth_storage(path: &Path, storage: &mut AuthStorage) -> Result<(), A
 
     let json = serde_json::to_vec_pretty(storage)
         .map_err(|error| AuthError::json("encode auth storage", error))?;
-    atomic_write_0600(parent, path, &json)
+    atomic_write_0600(parent, path, &json)?;
+    ensure_secure_permissions(path)?;
+    Ok(())
+}
+
+/// Narrow `path` to owner-only (`0o600`) permissions.
+///
+/// Repairs an auth file that was created or chmod-ed with wider permissions
+/// outside of [`save_auth_storage`]. No-op on non-Unix platforms and when the
+/// mode is already `0o600`.
+pub fn ensure_secure_permissions(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(path)?.permissions().mode() & 0o777;
+        if mode != 0o600 {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// Log a warning when the auth file is readable by group or other.
+///
+/// Loading never rewrites the file; the next [`save_auth_storage`] narrows it.
+fn warn_if_insecure_permissions(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(path) {
+            let mode = metadata.permissions().mode() & 0o777;
+            if mode & 0o077 != 0 {
+                tracing::warn!(
+                    path = %path.display(),
+                    mode = format!("{mode:o}"),
+                    "auth file is accessible to group/other; it will be narrowed to 0600 on next save"
+                );
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
 }
 
 /// Atomically write `contents` to `final_path`. The temp file is created in
@@ -234,8 +281,11 @@ pub fn rename_account(
     save_auth_storage(path, &mut storage)
 }
 
-/// Get account labels for a provider.
-pub fn get_account_labels(path: &Path, provider: &str) -> Result<Vec<String>, AuthError> {
+/// List the OAuth account labels configured for a provider, in storage order.
+///
+/// Returns an empty vec when the auth file is missing or the provider has no
+/// accounts.
+pub fn list_accounts(path: &Path, provider: &str) -> Result<Vec<String>, AuthError> {
     let Some(pa) = get_provider_auth(path, provider)? else {
         return Ok(Vec::new());
     };
@@ -404,6 +454,34 @@ pub fn get_active_credential(
     Ok(get_provider_auth(path, provider)?.and_then(|pa| pa.active_credential))
 }
 
+/// Get the default OAuth account label for a provider.
+///
+/// The default account is the provider's `active_credential` when it names an
+/// OAuth account. Returns `None` when nothing is selected or the selection is
+/// an API key.
+pub fn get_default_account(path: &Path, provider: &str) -> Result<Option<String>, AuthError> {
+    Ok(match get_active_credential(path, provider)? {
+        Some(ActiveCredential::OAuth { label }) => Some(label),
+        Some(ActiveCredential::ApiKey { .. }) | None => None,
+    })
+}
+
+/// Mark an OAuth account as the provider's default.
+///
+/// Persisted as the provider's `active_credential`, so `load_server_auth`
+/// without a credential override resolves to this account before falling
+/// back to the first configured one. Returns an error if the account does not
+/// exist.
+pub fn set_default_account(path: &Path, provider: &str, label: &str) -> Result<(), AuthError> {
+    set_active_credential(
+        path,
+        provider,
+        &ActiveCredential::OAuth {
+            label: label.to_string(),
+        },
+    )
+}
+
 /// Save Google-specific provider auth.
 pub fn save_google_provider_auth(path: &Path, auth: &GoogleProviderAuth) -> Result<(), AuthError> {
     let mut storage = load_or_init_for_write(path)?;
@@ -126,6 +126,85 @@ fn remove_named_api_key_preserves_active_if_pointing_elsewhere() {
     );
 }
 
+// ── Account listing and default selection ──
+
+#[test]
+fn list_accounts_returns_labels_in_order() {
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    save_account_oauth_tokens(&path, "anthropic", "work", &make_tokens()).unwrap();
+    save_account_oauth_tokens(&path, "anthropic", "personal", &make_tokens()).unwrap();
+    save_named_api_key(&path, "anthropic", "ci", "sk-ci").unwrap();
+
+    assert_eq!(
+        list_accounts(&path, "anthropic").unwrap(),
+        vec!["work", "personal"]
+    );
+    assert!(list_accounts(&path, "openai-codex").unwrap().is_empty());
+}
+
+#[test]
+fn list_accounts_missing_file_is_empty() {
+    let dir = TempDir::new().unwrap();
+    assert!(
+        list_accounts(&test_path(&dir), "anthropic")
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn default_account_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    save_account_oauth_tokens(&path, "anthropic", "work", &make_tokens()).unwrap();
+    save_account_oauth_tokens(&path, "anthropic", "personal", &make_tokens()).unwrap();
+    assert_eq!(get_default_account(&path, "anthropic").unwrap(), None);
+
+    set_default_account(&path, "anthropic", "personal").unwrap();
+    assert_eq!(
+        get_default_account(&path, "anthropic").unwrap().as_deref(),
+        Some("personal")
+    );
+    assert_eq!(
+        get_active_credential(&path, "anthropic").unwrap(),
+        Some(ActiveCredential::OAuth {
+            label: "personal".to_string()
+        })
+    );
+}
+
+#[test]
+fn set_default_account_rejects_unknown_label() {
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    save_account_oauth_tokens(&path, "anthropic", "work", &make_tokens()).unwrap();
+    assert!(set_default_account(&path, "anthropic", "missing").is_err());
+    assert_eq!(get_default_account(&path, "anthropic").unwrap(), None);
+}
+
+#[test]
+fn default_account_ignores_active_api_key() {
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    save_account_oauth_tokens(&path, "anthropic", "work", &make_tokens()).unwrap();
+    save_named_api_key(&path, "anthropic", "ci", "sk-ci").unwrap();
+    set_active_credential(
+        &path,
+        "anthropic",
+        &ActiveCredential::ApiKey {
+            label: "ci".to_string(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(get_default_account(&path, "anthropic").unwrap(), None);
+}
+
 // ── Remove account ──
 
 #[test]
@@ -138,7 +217,7 @@ fn remove_account_removes() {
 
     remove_account(&path, "anthropic", "acct1").unwrap();
 
-    let labels = get_account_labels(&path, "anthropic").unwrap();
+    let labels = list_accounts(&path, "anthropic").unwrap();
     assert_eq!(labels, vec!["acct2"]);
 }
 
@@ -252,10 +331,7 @@ fn remove_account_nonexistent_noop() {
     save_account_oauth_tokens(&path, "anthropic", "main", &make_tokens()).unwrap();
     remove_account(&path, "anthropic", "nonexistent").unwrap();
 
-    assert_eq!(
-        get_account_labels(&path, "anthropic").unwrap(),
-        vec!["main"]
-    );
+    assert_eq!(list_accounts(&path, "anthropic").unwrap(), vec!["main"]);
 }
 
 // ── Active credential ──
@@ -320,6 +320,64 @@ fn save_over_existing_wider_permissions_narrows_to_0600() {
     );
 }
 
+#[cfg(unix)]
+#[test]
+fn ensure_secure_permissions_narrows_wide_file() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    std::fs::write(&path, "{}").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    ensure_secure_permissions(&path).unwrap();
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+
+    // Already-secure files are left untouched.
+    ensure_secure_permissions(&path).unwrap();
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn ensure_secure_permissions_missing_file_errors() {
+    let dir = TempDir::new().unwrap();
+    let err = ensure_secure_permissions(&test_path(&dir)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[cfg(unix)]
+#[test]
+fn load_then_save_repairs_world_readable_auth_file() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = TempDir::new().unwrap();
+    let path = test_path(&dir);
+
+    save_named_api_key(&path, "anthropic", "work", "sk-123").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    // Loading only warns; it never rewrites the file.
+    let mut storage = load_auth_storage(&path).unwrap().unwrap();
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o644
+    );
+
+    save_auth_storage(&path, &mut storage).unwrap();
+    assert_eq!(
+        std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+    let pa = get_provider_auth(&path, "anthropic").unwrap().unwrap();
+    assert_eq!(pa.api_keys.unwrap()[0].key, "sk-123");
+}
+
 #[test]
 fn save_account_and_api_key_roundtrip() {
     let dir = TempDir::new().unwrap();
@@ -346,7 +404,7 @@ fn save_account_oauth_tokens_creates_new() {
     let tokens = make_tokens();
     save_account_oauth_tokens(&path, "anthropic", "work", &tokens).unwrap();
 
-    let labels = get_account_labels(&path, "anthropic").unwrap();
+    let labels = list_accounts(&path, "anthropic").unwrap();
     assert_eq!(labels, vec!["work"]);
 }
 
@@ -427,6 +427,19 @@ pub struct OAuthConfig {
     pub scopes: Vec<String>,
     /// Buffer in seconds before expiry to trigger refresh.
     pub token_expiry_buffer_seconds: i64,
+    /// Extra margin in seconds for clock skew against the token server.
+    ///
+    /// Added on top of `token_expiry_buffer_seconds` when deciding whether a
+    /// stored token is due for refresh. `0` keeps the plain buffer behavior.
+    pub token_refresh_skew_seconds: i64,
+}
+
+impl OAuthConfig {
+    /// Refresh policy derived from this config's buffer and skew margins.
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        RefreshPolicy::new(self.token_expiry_buffer_seconds * 1000)
+            .with_skew_ms(self.token_refresh_skew_seconds * 1000)
+    }
 }
 
 /// OAuth token refresh response from any provider's token endpoint.
@@ -448,9 +461,50 @@ pub fn now_ms() -> i64 {
     chrono::Utc::now().timestamp_millis()
 }
 
-/// Check if OAuth tokens need refreshing.
-pub fn should_refresh(tokens: &OAuthTokens, buffer_ms: i64) -> bool {
-    now_ms() + buffer_ms >= tokens.expires_at
+/// When an OAuth access token is considered due for refresh.
+///
+/// A token is refreshed once its remaining lifetime drops to
+/// `min_remaining_ms + skew_ms` or below. `skew_ms` exists so callers can
+/// refresh earlier when the token server's clock runs ahead of ours; the
+/// default (both zero) refreshes only once the token has actually expired.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RefreshPolicy {
+    /// Minimum remaining token lifetime in milliseconds.
+    pub min_remaining_ms: i64,
+    /// Additional clock-skew margin in milliseconds.
+    pub skew_ms: i64,
+}
+
+impl RefreshPolicy {
+    /// Policy with the given minimum remaining lifetime and no skew margin.
+    pub const fn new(min_remaining_ms: i64) -> Self {
+        Self {
+            min_remaining_ms,
+            skew_ms: 0,
+        }
+    }
+
+    /// Set the clock-skew margin.
+    #[must_use]
+    pub const fn with_skew_ms(mut self, skew_ms: i64) -> Self {
+        self.skew_ms = skew_ms;
+        self
+    }
+
+    /// Total margin before expiry at which a refresh becomes due.
+    pub const fn margin_ms(&self) -> i64 {
+        self.min_remaining_ms.saturating_add(self.skew_ms)
+    }
+
+    /// Whether a token expiring at `expires_at` (ms) is still usable as-is.
+    pub fn is_fresh(&self, expires_at: i64) -> bool {
+        now_ms().saturating_add(self.margin_ms()) < expires_at
+    }
+}
+
+/// Check if OAuth tokens need refreshing under `policy`.
+pub fn should_refresh(tokens: &OAuthTokens, policy: RefreshPolicy) -> bool {
+    !policy.is_fresh(tokens.expires_at)
 }
 
 /// Calculate expiration timestamp from `expires_in` seconds.
@@ -205,7 +205,7 @@ fn should_refresh_expired() {
         refresh_token: "ref".to_string(),
         expires_at: 0,
     };
-    assert!(should_refresh(&tokens, 0));
+    assert!(should_refresh(&tokens, RefreshPolicy::default()));
 }
 
 #[test]
@@ -216,9 +216,50 @@ fn should_refresh_with_buffer() {
         expires_at: now_ms() + 60_000, // 60s from now
     };
     // With 120s buffer (120_000ms), should need refresh
-    assert!(should_refresh(&tokens, 120_000));
+    assert!(should_refresh(&tokens, RefreshPolicy::new(120_000)));
     // With 0 buffer, should NOT need refresh
-    assert!(!should_refresh(&tokens, 0));
+    assert!(!should_refresh(&tokens, RefreshPolicy::default()));
+}
+
+#[test]
+fn should_refresh_honors_skew_margin() {
+    let tokens = OAuthTokens {
+        access_token: "tok".to_string(),
+        refresh_token: "ref".to_string(),
+        expires_at: now_ms() + 30_000, // 30s from now
+    };
+    // 60s skew margin covers the remaining 30s → refresh early
+    assert!(should_refresh(
+        &tokens,
+        RefreshPolicy::default().with_skew_ms(60_000)
+    ));
+    // 10s skew margin leaves 20s of headroom → keep the token
+    assert!(!should_refresh(
+        &tokens,
+        RefreshPolicy::default().with_skew_ms(10_000)
+    ));
+}
+
+#[test]
+fn refresh_policy_margin_combines_buffer_and_skew() {
+    let policy = RefreshPolicy::new(20_000).with_skew_ms(15_000);
+    assert_eq!(policy.margin_ms(), 35_000);
+    assert!(!policy.is_fresh(now_ms() + 30_000));
+    assert!(policy.is_fresh(now_ms() + 60_000));
+}
+
+#[test]
+fn oauth_config_refresh_policy_uses_seconds_fields() {
+    let mut config = crate::domains::auth::credentials::anthropic::default_config();
+    assert_eq!(config.refresh_policy(), RefreshPolicy::new(300_000));
+    config.token_refresh_skew_seconds = 45;
+    assert_eq!(
+        config.refresh_policy(),
+        RefreshPolicy {
+            min_remaining_ms: 300_000,
+            skew_ms: 45_000,
+        }
+    );
 }
 
 #[test]
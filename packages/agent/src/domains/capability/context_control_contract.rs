@@ -4,6 +4,7 @@ use serde_json::{Map, json};
 pub(super) const CONTEXT_CONTROL_SCHEMA_FIELDS: &[&str] = &[
     "contextControlActionResourceId",
     "contextControlSnapshotResourceId",
+    "sinceContextControlSnapshotResourceId",
     "expectedContextControlActionVersionId",
     "contextActionReason",
 ];
@@ -17,6 +18,10 @@ pub(super) fn insert_context_control_fields(properties: &mut Map<String, serde_j
         "contextControlSnapshotResourceId".to_owned(),
         json!({"type": "string", "description": "Returned context_control_snapshot resource id from context_control_snapshot or a compact/clear preflight; inspect raw snapshot payloads only through provider-safe projections."}),
     );
+    properties.insert(
+        "sinceContextControlSnapshotResourceId".to_owned(),
+        json!({"type": "string", "description": "Optional earlier context_control_snapshot resource id for context_control_snapshot; the result adds a diff of added, removed, and changed parts since that snapshot."}),
+    );
     properties.insert(
         "expectedContextControlActionVersionId".to_owned(),
         json!({"type": "string", "description": "Optional current context_control_action version id for UI freshness checks."}),
@@ -163,6 +163,10 @@ fn execute_model_request_schema() -> serde_json::Value {
         "commit".to_owned(),
         json!({"type": "boolean", "description": "When true, commit filesystem_write/edit/apply_patch. Default is preview only."}),
     );
+    properties.insert(
+        "dryRun".to_owned(),
+        json!({"type": "boolean", "description": "When true, filesystem_edit/apply_patch validate the match and return the diff as a proposal; commit is rejected and the file is never written."}),
+    );
     insert_string(
         &mut properties,
         "glob",
@@ -172,12 +176,32 @@ fn execute_model_request_schema() -> serde_json::Value {
         "showHidden".to_owned(),
         json!({"type": "boolean", "description": "Include hidden filesystem entries."}),
     );
+    insert_string(
+        &mut properties,
+        "symbolKind",
+        "filesystem_search_text definition filter: function, struct, enum, trait, impl, type, const, module, or macro. Rust files return only matching definitions; other files fall back to text matching.",
+    );
+    properties.insert(
+        "respectGitignore".to_owned(),
+        json!({"type": "boolean", "description": "Skip .gitignore'd paths in filesystem_find/glob. Default true."}),
+    );
     insert_integer(&mut properties, "maxBytes", 1, Some(262_144), None);
+    insert_integer(
+        &mut properties,
+        "offset",
+        1,
+        None,
+        Some("1-based first line for a filesystem_read window; pair with limit."),
+    );
     insert_integer(&mut properties, "maxFileBytes", 1, Some(262_144), None);
     insert_integer(&mut properties, "maxDiffBytes", 1, Some(131_072), None);
     insert_integer(&mut properties, "maxStatusBytes", 1, Some(200_000), None);
     insert_integer(&mut properties, "maxBranches", 1, Some(500), None);
     insert_integer(&mut properties, "maxBranchBytes", 1, Some(200_000), None);
+    properties.insert(
+        "includeWorktrees".to_owned(),
+        json!({"type": "boolean", "description": "When true, git_branch_inventory also lists the repository's worktrees with branch, dirty, and ahead/behind facts."}),
+    );
     insert_string(
         &mut properties,
         "command",
@@ -233,6 +257,20 @@ fn execute_model_request_schema() -> serde_json::Value {
         "expiresAt",
         "Optional RFC3339 expiry timestamp for question_create.",
     );
+    insert_integer(
+        &mut properties,
+        "answerTimeoutMs",
+        1,
+        Some(604_800_000),
+        Some(
+            "Relative question_create expiry; unanswered questions then resolve to defaultChoice or no_response.",
+        ),
+    );
+    insert_string(
+        &mut properties,
+        "defaultChoice",
+        "Answer question_inspect reports once a question_create deadline passes unanswered.",
+    );
     scheduler_contract::insert_scheduler_request_fields(&mut properties);
     properties.insert(
         "allowFreeForm".to_owned(),
@@ -279,6 +317,11 @@ fn execute_model_request_schema() -> serde_json::Value {
         "traceId",
         "Optional trace id filter for trace_list and log_recent.",
     );
+    insert_string(
+        &mut properties,
+        "continuationToken",
+        "Continuation token from a truncated execute result, for result_continue.",
+    );
     insert_string(
         &mut properties,
         "traceRecordId",
@@ -627,6 +670,20 @@ fn execute_model_request_schema() -> serde_json::Value {
         json!({"type": "boolean", "description": "Include aggregate protected omission counts without protected ids."}),
     );
     insert_integer(&mut properties, "limit", 1, Some(500), None);
+    insert_string(
+        &mut properties,
+        "category",
+        "Memory record category filter for memory_list.",
+    );
+    insert_integer(
+        &mut properties,
+        "minImportance",
+        0,
+        Some(10),
+        Some(
+            "Minimum memory record importance for memory_list; filtered lists are ordered by importance.",
+        ),
+    );
     insert_integer(
         &mut properties,
         "maxAgeDays",
@@ -683,6 +740,10 @@ fn execute_model_request_schema() -> serde_json::Value {
         Some(1_048_576),
         Some("Maximum captured response bytes for web_fetch source evidence."),
     );
+    properties.insert(
+        "allowedContentTypes".to_owned(),
+        json!({"type": "array", "description": "web_fetch media type allowlist such as text/html or text/*; defaults to HTML, plain text, markdown, and JSON. Other types are rejected before the body is read."}),
+    );
     insert_integer(
         &mut properties,
         "maxRobotsBytes",
@@ -161,6 +161,7 @@ pub(crate) use operations::{is_supported_operation, operation_list_text};
 mod update_diagnostics_contract;
 mod web_research_contract;
 pub(crate) use operations::execute_value;
+pub(crate) use operations::{ContinuationStore, OperationInterceptor};
 
 use std::sync::Arc;
 
@@ -184,11 +185,13 @@ pub(crate) struct Deps {
     pub(crate) shutdown_coordinator:
         Option<Arc<crate::app::lifecycle::shutdown::ShutdownCoordinator>>,
     pub(crate) jobs_reconcile: jobs::service::ReconcileContext,
+    pub(crate) interceptors: Vec<Arc<dyn OperationInterceptor>>,
+    pub(crate) continuations: Arc<ContinuationStore>,
 }
 
 impl Deps {
     pub(crate) fn from_engine(deps: &DomainRegistrationContext) -> Self {
-        Self {
+        let base = Self {
             engine_host: deps.engine_host.clone(),
             event_store: Arc::clone(&deps.event_store),
             session_manager: Arc::clone(&deps.session_manager),
@@ -196,7 +199,23 @@ impl Deps {
             jobs_reconcile: jobs::service::ReconcileContext {
                 startup_cutoff: Utc::now(),
             },
-        }
+            interceptors: Vec::new(),
+            continuations: Arc::default(),
+        };
+        deps.capability_interceptors
+            .iter()
+            .cloned()
+            .fold(base, Self::with_interceptor)
+    }
+
+    /// Append an interceptor to the execute operation chain.
+    ///
+    /// Interceptors run in the order they were appended. `from_engine` seeds
+    /// the chain from the registration context, which supplies none by
+    /// default; slow handlers are already logged by the engine.
+    pub(crate) fn with_interceptor(mut self, interceptor: Arc<dyn OperationInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
     }
 }
 
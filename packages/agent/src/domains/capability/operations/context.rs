@@ -118,6 +118,7 @@ pub(super) fn validate_execute_context(
         "trace_list"
         | "trace_get"
         | "log_recent"
+        | "result_continue"
         | "replay_manifest"
         | "memory_status"
         | "memory_list"
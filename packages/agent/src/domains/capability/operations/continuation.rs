@@ -0,0 +1,253 @@
+//! Oversized execute result truncation with continuation tokens.
+//!
+//! Results whose text exceeds [`RESULT_BUDGET_BYTES`] are cut at a UTF-8
+//! boundary, the remainder is parked in the [`ContinuationStore`] on `Deps`,
+//! and the result ends with a note naming the token. `result_continue` hands
+//! back the next chunk and, while text remains, a fresh token. Tokens are
+//! bound to the session that produced them; the store keeps at most
+//! [`MAX_PENDING_CONTINUATIONS`] remainders and [`MAX_PENDING_BYTES`] of
+//! remainder text, dropping the oldest first. Results that already say they
+//! were cut short (the `filesystem_read` paging notice) pass through as is.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use serde_json::json;
+
+use super::{Deps, invalid, ok_result, required_str};
+use crate::engine::Invocation;
+use crate::shared::foundation::text::truncate_str;
+use crate::shared::protocol::content::CapabilityResultContent;
+use crate::shared::protocol::model_capabilities::{CapabilityResult, CapabilityResultBody};
+use crate::shared::server::errors::CapabilityError;
+
+/// Text budget for one execute result or continuation chunk.
+pub(super) const RESULT_BUDGET_BYTES: usize = 100_000;
+/// Remainders kept before the oldest is evicted.
+pub(super) const MAX_PENDING_CONTINUATIONS: usize = 64;
+/// Remainder bytes kept before the oldest is evicted. The newest remainder
+/// is always kept so its token stays usable.
+pub(super) const MAX_PENDING_BYTES: usize = 8 * 1024 * 1024;
+
+struct Continuation {
+    session_id: Option<String>,
+    invocation_id: String,
+    offset: usize,
+    remainder: String,
+}
+
+#[derive(Default)]
+struct Pending {
+    entries: HashMap<String, Continuation>,
+    order: VecDeque<String>,
+    bytes: usize,
+}
+
+impl Pending {
+    fn remove(&mut self, token: &str) -> Option<Continuation> {
+        let continuation = self.entries.remove(token)?;
+        self.bytes -= continuation.remainder.len();
+        Some(continuation)
+    }
+}
+
+/// Truncated result remainders keyed by continuation token.
+#[derive(Default)]
+pub(crate) struct ContinuationStore {
+    pending: Mutex<Pending>,
+}
+
+/// One chunk fetched with a continuation token.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Chunk {
+    pub(super) text: String,
+    pub(super) remaining_chars: usize,
+    pub(super) next_token: Option<String>,
+}
+
+impl ContinuationStore {
+    /// Truncate `result` to `budget` bytes of text, parking the remainder.
+    ///
+    /// Text blocks are joined with newlines before cutting and image blocks
+    /// are kept after the text. Results within budget, or already truncated
+    /// by their operation, are returned unchanged.
+    pub(super) fn truncate(
+        &self,
+        invocation: &Invocation,
+        result: CapabilityResult,
+        budget: usize,
+    ) -> CapabilityResult {
+        if text_len(&result.content) <= budget || already_truncated(&result) {
+            return result;
+        }
+        let CapabilityResult {
+            content,
+            details,
+            is_error,
+            stop_turn,
+        } = result;
+        let (text, images) = match content {
+            CapabilityResultBody::Text(text) => (text, None),
+            CapabilityResultBody::Blocks(blocks) => {
+                let mut texts = Vec::new();
+                let mut images = Vec::new();
+                for block in blocks {
+                    match block {
+                        CapabilityResultContent::Text { text } => texts.push(text),
+                        image @ CapabilityResultContent::Image { .. } => images.push(image),
+                    }
+                }
+                (texts.join("\n"), Some(images))
+            }
+        };
+        let head = truncate_str(&text, budget);
+        let rest = text[head.len()..].to_owned();
+        let remaining_chars = rest.chars().count();
+        let token = self.park(Continuation {
+            session_id: invocation.causal_context.session_id.clone(),
+            invocation_id: invocation.id.as_str().to_owned(),
+            offset: head.len(),
+            remainder: rest,
+        });
+        let text = format!("{head}{}", truncation_note(remaining_chars, &token));
+        let content = match images {
+            None => CapabilityResultBody::Text(text),
+            Some(images) => {
+                let mut blocks = vec![CapabilityResultContent::text(text)];
+                blocks.extend(images);
+                CapabilityResultBody::Blocks(blocks)
+            }
+        };
+        CapabilityResult {
+            content,
+            details,
+            is_error,
+            stop_turn,
+        }
+    }
+
+    /// Fetch up to `budget` bytes after `token` for the session in `invocation`.
+    pub(super) fn next_chunk(
+        &self,
+        invocation: &Invocation,
+        token: &str,
+        budget: usize,
+    ) -> Result<Chunk, CapabilityError> {
+        let continuation = {
+            let mut pending = self.lock();
+            let owned = pending.entries.get(token).is_some_and(|entry| {
+                entry.session_id.as_deref() == invocation.causal_context.session_id.as_deref()
+            });
+            if owned {
+                pending.order.retain(|queued| queued != token);
+            }
+            owned.then(|| pending.remove(token)).flatten()
+        }
+        .ok_or_else(|| invalid(format!("continuation token {token} is unknown or expired")))?;
+
+        let head = truncate_str(&continuation.remainder, budget).to_owned();
+        let rest = continuation.remainder[head.len()..].to_owned();
+        if rest.is_empty() {
+            return Ok(Chunk {
+                text: head,
+                remaining_chars: 0,
+                next_token: None,
+            });
+        }
+        let remaining_chars = rest.chars().count();
+        let next_token = self.park(Continuation {
+            session_id: continuation.session_id,
+            offset: continuation.offset + head.len(),
+            invocation_id: continuation.invocation_id,
+            remainder: rest,
+        });
+        Ok(Chunk {
+            text: format!("{head}{}", truncation_note(remaining_chars, &next_token)),
+            remaining_chars,
+            next_token: Some(next_token),
+        })
+    }
+
+    fn park(&self, continuation: Continuation) -> String {
+        let token = format!(
+            "cont_{}_{}",
+            continuation.invocation_id, continuation.offset
+        );
+        let mut pending = self.lock();
+        let _ = pending.remove(&token);
+        pending.order.retain(|queued| queued != &token);
+        pending.bytes += continuation.remainder.len();
+        let _ = pending.entries.insert(token.clone(), continuation);
+        pending.order.push_back(token.clone());
+        while pending.order.len() > MAX_PENDING_CONTINUATIONS
+            || (pending.bytes > MAX_PENDING_BYTES && pending.order.len() > 1)
+        {
+            if let Some(oldest) = pending.order.pop_front() {
+                let _ = pending.remove(&oldest);
+            }
+        }
+        token
+    }
+
+    #[cfg(test)]
+    pub(super) fn pending_len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Pending> {
+        self.pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Byte length of the result text as [`ContinuationStore::truncate`] joins it.
+fn text_len(content: &CapabilityResultBody) -> usize {
+    match content {
+        CapabilityResultBody::Text(text) => text.len(),
+        CapabilityResultBody::Blocks(blocks) => {
+            let texts = blocks.iter().filter_map(|block| match block {
+                CapabilityResultContent::Text { text } => Some(text.len()),
+                CapabilityResultContent::Image { .. } => None,
+            });
+            let (count, bytes) =
+                texts.fold((0_usize, 0), |(count, bytes), len| (count + 1, bytes + len));
+            bytes + count.saturating_sub(1)
+        }
+    }
+}
+
+/// Whether the operation already cut its output and told the agent how to
+/// page through the rest.
+fn already_truncated(result: &CapabilityResult) -> bool {
+    result
+        .details
+        .as_ref()
+        .and_then(|details| details.pointer("/filesystem/notice"))
+        .is_some_and(serde_json::Value::is_string)
+}
+
+fn truncation_note(remaining_chars: usize, token: &str) -> String {
+    format!(
+        "\n\n[truncated — {remaining_chars} more chars, use continuation token {token} with operation result_continue]"
+    )
+}
+
+pub(super) fn result_continue(
+    invocation: &Invocation,
+    deps: &Deps,
+) -> Result<CapabilityResult, CapabilityError> {
+    let token = required_str(&invocation.payload, "continuationToken")?;
+    let chunk = deps
+        .continuations
+        .next_chunk(invocation, token, RESULT_BUDGET_BYTES)?;
+    Ok(ok_result(
+        chunk.text,
+        json!({
+            "primitiveOperation": "result_continue",
+            "status": "ok",
+            "remainingChars": chunk.remaining_chars,
+            "continuationToken": chunk.next_token
+        }),
+    ))
+}
@@ -0,0 +1,210 @@
+use serde_json::json;
+
+use super::common::ok_result;
+use super::continuation::{Chunk, ContinuationStore, MAX_PENDING_BYTES, MAX_PENDING_CONTINUATIONS};
+use crate::engine::{
+    ActorId, ActorKind, AuthorityGrantId, CausalContext, DeliveryMode, FunctionId, Invocation,
+    InvocationId, TraceId,
+};
+use crate::shared::protocol::content::CapabilityResultContent;
+use crate::shared::protocol::model_capabilities::{CapabilityResult, CapabilityResultBody};
+
+fn invocation(id: &str, session_id: &str) -> Invocation {
+    let context = CausalContext::new(
+        ActorId::new("system:continuation-test").unwrap(),
+        ActorKind::System,
+        AuthorityGrantId::new("grant-continuation-test").unwrap(),
+        TraceId::new("trace-continuation-test").unwrap(),
+    )
+    .with_scope("capability.execute")
+    .with_session_id(session_id);
+    Invocation {
+        id: InvocationId::new(id).unwrap(),
+        function_id: FunctionId::new("capability::execute").unwrap(),
+        delivery_mode: DeliveryMode::Sync,
+        payload: json!({}),
+        causal_context: context,
+    }
+}
+
+fn text_result(text: &str) -> CapabilityResult {
+    ok_result(text.to_owned(), json!({"status": "ok"}))
+}
+
+fn first_text(result: &CapabilityResult) -> &str {
+    match &result.content {
+        CapabilityResultBody::Text(text) => text,
+        CapabilityResultBody::Blocks(blocks) => match &blocks[0] {
+            CapabilityResultContent::Text { text } => text,
+            CapabilityResultContent::Image { .. } => panic!("expected text block"),
+        },
+    }
+}
+
+fn token_in(text: &str) -> String {
+    let start = text.find("token ").expect("token in note") + "token ".len();
+    text[start..].split_whitespace().next().unwrap().to_owned()
+}
+
+#[test]
+fn result_within_budget_is_unchanged() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-fit", "session-a");
+    let exact = text_result(&"a".repeat(10));
+    assert_eq!(store.truncate(&call, exact.clone(), 10), exact);
+    assert_eq!(store.pending_len(), 0);
+}
+
+#[test]
+fn one_byte_over_budget_parks_remainder() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-over", "session-a");
+    let result = store.truncate(&call, text_result(&"a".repeat(11)), 10);
+    let text = first_text(&result);
+    assert!(text.starts_with(&"a".repeat(10)));
+    assert!(text.contains("truncated — 1 more chars, use continuation token cont_inv-over_10"));
+    assert_eq!(result.details, Some(json!({"status": "ok"})));
+
+    let chunk = store.next_chunk(&call, &token_in(text), 10).unwrap();
+    assert_eq!(
+        chunk,
+        Chunk {
+            text: "a".to_owned(),
+            remaining_chars: 0,
+            next_token: None
+        }
+    );
+}
+
+#[test]
+fn truncation_respects_utf8_boundaries() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-utf8", "session-a");
+    // Each "é" is two bytes, so a 5-byte budget keeps two of them.
+    let result = store.truncate(&call, text_result("ééééé"), 5);
+    let text = first_text(&result);
+    assert!(text.starts_with("éé\n"));
+    assert!(text.contains("3 more chars"));
+    let chunk = store.next_chunk(&call, &token_in(text), 100).unwrap();
+    assert_eq!(chunk.text, "ééé");
+}
+
+#[test]
+fn subsequent_chunks_walk_the_remainder() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-walk", "session-a");
+    let body: String = ('a'..='z').collect();
+    let result = store.truncate(&call, text_result(&body), 10);
+    let mut token = token_in(first_text(&result));
+
+    let second = store.next_chunk(&call, &token, 10).unwrap();
+    assert!(second.text.starts_with("klmnopqrst"));
+    assert_eq!(second.remaining_chars, 6);
+    assert_eq!(second.next_token.as_deref(), Some("cont_inv-walk_20"));
+    token = second.next_token.unwrap();
+
+    let third = store.next_chunk(&call, &token, 10).unwrap();
+    assert_eq!(third.text, "uvwxyz");
+    assert_eq!(third.next_token, None);
+
+    // Tokens are single-use.
+    assert!(store.next_chunk(&call, &token, 10).is_err());
+}
+
+#[test]
+fn blocks_are_joined_and_images_kept() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-blocks", "session-a");
+    let result = CapabilityResult {
+        content: CapabilityResultBody::Blocks(vec![
+            CapabilityResultContent::text("12345"),
+            CapabilityResultContent::Image {
+                data: "aW1n".to_owned(),
+                mime_type: "image/png".to_owned(),
+            },
+            CapabilityResultContent::text("67890"),
+        ]),
+        details: None,
+        is_error: Some(false),
+        stop_turn: None,
+    };
+    let truncated = store.truncate(&call, result, 8);
+    let CapabilityResultBody::Blocks(blocks) = &truncated.content else {
+        panic!("expected blocks");
+    };
+    assert_eq!(blocks.len(), 2);
+    assert!(matches!(blocks[1], CapabilityResultContent::Image { .. }));
+    let chunk = store
+        .next_chunk(&call, &token_in(first_text(&truncated)), 8)
+        .unwrap();
+    assert_eq!(chunk.text, "890");
+}
+
+#[test]
+fn tokens_are_bound_to_their_session() {
+    let store = ContinuationStore::default();
+    let owner = invocation("inv-owner", "session-a");
+    let result = store.truncate(&owner, text_result(&"x".repeat(20)), 10);
+    let token = token_in(first_text(&result));
+
+    let other = invocation("inv-other", "session-b");
+    assert!(store.next_chunk(&other, &token, 10).is_err());
+    assert!(store.next_chunk(&owner, &token, 10).is_ok());
+}
+
+#[test]
+fn oldest_remainders_are_evicted() {
+    let store = ContinuationStore::default();
+    let mut tokens = Vec::new();
+    for n in 0..=MAX_PENDING_CONTINUATIONS {
+        let call = invocation(&format!("inv-{n}"), "session-a");
+        let result = store.truncate(&call, text_result("overflowing"), 4);
+        tokens.push(token_in(first_text(&result)));
+    }
+    let call = invocation("inv-fetch", "session-a");
+    assert!(store.next_chunk(&call, &tokens[0], 100).is_err());
+    assert!(
+        store
+            .next_chunk(&call, &tokens[MAX_PENDING_CONTINUATIONS], 100)
+            .is_ok()
+    );
+}
+
+#[test]
+fn remainders_beyond_the_byte_cap_evict_the_oldest() {
+    let store = ContinuationStore::default();
+    let half = MAX_PENDING_BYTES / 2 + 1;
+    let first = invocation("inv-big-1", "session-a");
+    let first_token = token_in(first_text(&store.truncate(
+        &first,
+        text_result(&"a".repeat(half + 4)),
+        4,
+    )));
+    let second = invocation("inv-big-2", "session-a");
+    let second_token = token_in(first_text(&store.truncate(
+        &second,
+        text_result(&"b".repeat(half + 4)),
+        4,
+    )));
+
+    assert_eq!(store.pending_len(), 1);
+    assert!(store.next_chunk(&second, &first_token, 4).is_err());
+    assert!(store.next_chunk(&second, &second_token, 4).is_ok());
+}
+
+#[test]
+fn results_already_truncated_by_their_operation_pass_through() {
+    let store = ContinuationStore::default();
+    let call = invocation("inv-read", "session-a");
+    let paged = ok_result(
+        "x".repeat(20),
+        json!({
+            "primitiveOperation": "filesystem_read",
+            "filesystem": {
+                "notice": "content truncated at 10 bytes; page with offset and limit to read the rest"
+            }
+        }),
+    );
+    assert_eq!(store.truncate(&call, paged.clone(), 10), paged);
+    assert_eq!(store.pending_len(), 0);
+}
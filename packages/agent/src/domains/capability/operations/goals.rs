@@ -46,8 +46,13 @@ pub(super) async fn question_create(
     invocation: &Invocation,
     deps: &Deps,
 ) -> Result<CapabilityResult, CapabilityError> {
-    let details =
-        service::create_question_value(&deps.engine_host, invocation, &invocation.payload).await?;
+    let details = service::create_question_value(
+        &deps.engine_host,
+        invocation,
+        deps.shutdown_coordinator.as_ref(),
+        &invocation.payload,
+    )
+    .await?;
     Ok(result("Question recorded.", details))
 }
 
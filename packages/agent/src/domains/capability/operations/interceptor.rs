@@ -0,0 +1,84 @@
+//! Cross-cutting hooks around primitive execute operations.
+//!
+//! Interceptors on capability [`Deps`](crate::domains::capability::Deps) run
+//! in registration order before each operation and may short-circuit it with
+//! their own result. Those that let it proceed then observe the outcome and
+//! elapsed time in reverse order. Context validation and trace recording stay
+//! in `execute_value`, outside the chain, so a short-circuited operation is
+//! still traced like any other. Slow operations need no interceptor: the
+//! engine already warns when any handler call outlives its slow threshold.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+use crate::engine::Invocation;
+use crate::shared::protocol::model_capabilities::CapabilityResult;
+use crate::shared::server::errors::CapabilityError;
+
+/// The operation an interceptor is asked about.
+pub(crate) struct OperationContext<'a> {
+    pub(crate) operation: &'a str,
+    pub(crate) invocation: &'a Invocation,
+}
+
+/// Hooks applied around every `capability::execute` operation.
+pub(crate) trait OperationInterceptor: Send + Sync {
+    /// Return `Some` to skip the operation and every later interceptor.
+    fn before(
+        &self,
+        _context: &OperationContext<'_>,
+    ) -> Option<Result<CapabilityResult, CapabilityError>> {
+        None
+    }
+
+    /// Observe the result the caller will receive.
+    fn after(
+        &self,
+        _context: &OperationContext<'_>,
+        _result: &Result<CapabilityResult, CapabilityError>,
+        _elapsed: Duration,
+    ) {
+    }
+}
+
+pub(super) async fn intercepted<F>(
+    interceptors: &[Arc<dyn OperationInterceptor>],
+    context: &OperationContext<'_>,
+    operation: F,
+) -> Result<CapabilityResult, CapabilityError>
+where
+    F: Future<Output = Result<CapabilityResult, CapabilityError>>,
+{
+    let start = Instant::now();
+    let mut entered = 0;
+    let mut short_circuit = None;
+    for interceptor in interceptors {
+        short_circuit = interceptor.before(context);
+        if short_circuit.is_some() {
+            break;
+        }
+        entered += 1;
+    }
+    let result = match short_circuit {
+        Some(result) => {
+            debug!(
+                component = "agent.execute",
+                agent_event = "execute_operation_intercepted",
+                operation = %context.operation,
+                invocation_id = %context.invocation.id.as_str(),
+                succeeded = result.is_ok(),
+                "primitive execute operation answered by interceptor"
+            );
+            result
+        }
+        None => operation.await,
+    };
+    let elapsed = start.elapsed();
+    for interceptor in interceptors[..entered].iter().rev() {
+        interceptor.after(context, &result, elapsed);
+    }
+    result
+}
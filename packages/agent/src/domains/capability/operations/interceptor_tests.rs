@@ -0,0 +1,166 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use super::{OperationContext, OperationInterceptor, execute_value, ok_result};
+use crate::domains::capability::Deps;
+use crate::domains::registration::worker::DomainRegistrationContext;
+use crate::engine::{
+    ActorId, ActorKind, AuthorityGrantId, CausalContext, DeliveryMode, FunctionId, Invocation,
+    InvocationId, TraceId,
+};
+use crate::shared::protocol::model_capabilities::CapabilityResult;
+use crate::shared::server::errors::CapabilityError;
+use crate::shared::server::test_support::make_test_context;
+
+struct BlockOperation(&'static str);
+
+impl OperationInterceptor for BlockOperation {
+    fn before(
+        &self,
+        context: &OperationContext<'_>,
+    ) -> Option<Result<CapabilityResult, CapabilityError>> {
+        (context.operation == self.0).then(|| {
+            Err(CapabilityError::InvalidParams {
+                message: format!("{} is blocked by guardrail", self.0),
+            })
+        })
+    }
+}
+
+struct AnswerOperation(&'static str);
+
+impl OperationInterceptor for AnswerOperation {
+    fn before(
+        &self,
+        context: &OperationContext<'_>,
+    ) -> Option<Result<CapabilityResult, CapabilityError>> {
+        (context.operation == self.0)
+            .then(|| Ok(ok_result("answered".to_owned(), json!({"answered": true}))))
+    }
+}
+
+#[derive(Default)]
+struct RecordTimings {
+    seen: Mutex<Vec<(String, bool, Duration)>>,
+}
+
+impl OperationInterceptor for RecordTimings {
+    fn after(
+        &self,
+        context: &OperationContext<'_>,
+        result: &Result<CapabilityResult, CapabilityError>,
+        elapsed: Duration,
+    ) {
+        self.seen
+            .lock()
+            .unwrap()
+            .push((context.operation.to_owned(), result.is_ok(), elapsed));
+    }
+}
+
+fn system_invocation(payload: Value) -> Invocation {
+    let context = CausalContext::new(
+        ActorId::new("system:interceptor-test").unwrap(),
+        ActorKind::System,
+        AuthorityGrantId::new("grant-interceptor-test").unwrap(),
+        TraceId::new("trace-interceptor-test").unwrap(),
+    )
+    .with_scope("capability.execute")
+    .with_session_id("session-interceptor-test");
+    Invocation {
+        id: InvocationId::new("invocation-interceptor-test").unwrap(),
+        function_id: FunctionId::new("capability::execute").unwrap(),
+        delivery_mode: DeliveryMode::Sync,
+        payload,
+        causal_context: context,
+    }
+}
+
+#[tokio::test]
+async fn interceptor_blocks_operation_before_it_runs() {
+    let ctx = make_test_context();
+    let timings = Arc::new(RecordTimings::default());
+    let deps = Deps::from_engine(&DomainRegistrationContext::from_context(&ctx))
+        .with_interceptor(timings.clone())
+        .with_interceptor(Arc::new(BlockOperation("process_run")));
+
+    let error = execute_value(
+        &system_invocation(json!({"operation": "process_run", "command": "true"})),
+        &deps,
+    )
+    .await
+    .expect_err("blocked");
+    assert!(
+        error.to_string().contains("blocked by guardrail"),
+        "{error}"
+    );
+
+    let error = execute_value(
+        &system_invocation(json!({"operation": "no_such_operation"})),
+        &deps,
+    )
+    .await
+    .expect_err("unsupported");
+    assert!(
+        error
+            .to_string()
+            .contains("Unsupported primitive execute operation")
+    );
+
+    let seen = timings.seen.lock().unwrap();
+    let operations = seen
+        .iter()
+        .map(|(operation, ok, _)| (operation.as_str(), *ok))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        operations,
+        vec![("process_run", false), ("no_such_operation", false)]
+    );
+}
+
+#[tokio::test]
+async fn short_circuit_skips_later_interceptors_and_reaches_earlier_ones() {
+    let ctx = make_test_context();
+    let outer = Arc::new(RecordTimings::default());
+    let inner = Arc::new(RecordTimings::default());
+    let deps = Deps::from_engine(&DomainRegistrationContext::from_context(&ctx))
+        .with_interceptor(outer.clone())
+        .with_interceptor(Arc::new(AnswerOperation("catalog_search")))
+        .with_interceptor(inner.clone());
+
+    let value = execute_value(
+        &system_invocation(json!({"operation": "catalog_search"})),
+        &deps,
+    )
+    .await
+    .expect("answered");
+    assert_eq!(value["details"]["answered"], true);
+
+    assert_eq!(outer.seen.lock().unwrap().len(), 1);
+    assert!(outer.seen.lock().unwrap()[0].1);
+    assert!(inner.seen.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn registration_context_interceptors_wrap_execute() {
+    let ctx = make_test_context();
+    let timings = Arc::new(RecordTimings::default());
+    let mut registration = DomainRegistrationContext::from_context(&ctx);
+    registration.capability_interceptors =
+        vec![timings.clone(), Arc::new(BlockOperation("process_run"))];
+    let deps = Deps::from_engine(&registration);
+
+    let error = execute_value(
+        &system_invocation(json!({"operation": "process_run", "command": "true"})),
+        &deps,
+    )
+    .await
+    .expect_err("blocked");
+    assert!(
+        error.to_string().contains("blocked by guardrail"),
+        "{error}"
+    );
+    assert_eq!(timings.seen.lock().unwrap().len(), 1);
+}
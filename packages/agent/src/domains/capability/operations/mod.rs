@@ -9,6 +9,17 @@
 //! can read or mutate job state; procedural module-pack operations similarly
 //! require exact procedural resource selectors and remain metadata-only review
 //! records rather than activation, prompt injection, or code execution.
+//! `process_run` is jailed to the grant's file roots whenever they are
+//! explicit: the working directory and every absolute, `~`, or `..` path in
+//! the command must stay inside them. Its stdout/stderr lines are published on
+//! the capability runtime topic as `capability.invocation.output` while the
+//! command runs; the final result still carries the collected output.
+//! Results whose text exceeds the `continuation` budget are truncated with a
+//! continuation token that `result_continue` exchanges for the next chunk.
+//! Every operation runs inside the `interceptor` chain registered on `Deps`,
+//! which can observe or short-circuit it without touching individual adapters.
+//! The chain holds only the interceptors passed in at registration and is
+//! empty by default; slow handlers are already logged by the engine.
 
 use std::time::Instant;
 
@@ -25,12 +36,16 @@ mod catalog;
 mod common;
 mod context;
 mod context_control;
+mod continuation;
 mod device;
 mod filesystem;
 mod git;
 mod goals;
 mod import_history;
 mod import_preview;
+mod interceptor;
+#[cfg(test)]
+mod interceptor_tests;
 mod jobs;
 mod logs;
 mod media;
@@ -46,6 +61,8 @@ mod module_validation;
 mod notifications;
 mod procedural;
 mod process;
+mod process_jail;
+mod process_stream;
 mod program_execution;
 mod prompt_artifacts;
 mod registry;
@@ -61,8 +78,14 @@ mod web;
 mod web_research;
 mod worker_packages;
 
+#[cfg(test)]
+mod continuation_tests;
 #[cfg(test)]
 mod module_program_execution_tests;
+#[cfg(test)]
+mod process_jail_tests;
+#[cfg(test)]
+mod process_stream_tests;
 
 use catalog::{catalog_conformance, catalog_inspect, catalog_search};
 use common::{
@@ -74,6 +97,7 @@ use context_control::{
     context_control_action_inspect, context_control_action_list, context_control_clear,
     context_control_compact, context_control_snapshot,
 };
+use continuation::{RESULT_BUDGET_BYTES, result_continue};
 use device::{device_inspect, device_list, device_register, device_unregister};
 use filesystem::{
     filesystem_apply_patch, filesystem_diff, filesystem_edit, filesystem_find, filesystem_glob,
@@ -161,6 +185,8 @@ use web_research::{
 };
 use worker_packages::{worker_package_inspect, worker_package_list};
 
+pub(crate) use continuation::ContinuationStore;
+pub(crate) use interceptor::{OperationContext, OperationInterceptor};
 #[cfg(test)]
 pub(crate) use registry::supported_operation_names;
 pub(crate) use registry::{is_supported_operation, operation_list_text};
@@ -222,7 +248,23 @@ pub(crate) async fn execute_value(
         "primitive execute trace record started"
     );
 
-    let result = execute_operation(&operation, invocation, deps, operation_at).await;
+    let result = interceptor::intercepted(
+        &deps.interceptors,
+        &OperationContext {
+            operation: &operation,
+            invocation,
+        },
+        execute_operation(&operation, invocation, deps, operation_at),
+    )
+    .await
+    .map(|result| {
+        if operation == "result_continue" {
+            result
+        } else {
+            deps.continuations
+                .truncate(invocation, result, RESULT_BUDGET_BYTES)
+        }
+    });
     match result {
         Ok(result) => {
             complete_trace_record(
@@ -324,6 +366,7 @@ async fn execute_operation(
         "trace_list" => trace_list(invocation, deps)?,
         "trace_get" => trace_get(invocation, deps)?,
         "log_recent" => log_recent(invocation, deps).await?,
+        "result_continue" => result_continue(invocation, deps)?,
         "replay_manifest" => replay_manifest(invocation, deps).await?,
         "catalog_search" => catalog_search(invocation, deps).await?,
         "catalog_inspect" => catalog_inspect(invocation, deps).await?,
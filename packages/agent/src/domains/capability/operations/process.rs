@@ -6,6 +6,8 @@ use serde_json::json;
 use tokio::process::Command;
 
 use super::filesystem::working_directory;
+use super::process_jail::ProcessJail;
+use super::process_stream::{OUTPUT_CHUNK_CAPACITY, publish_chunks, pump_output};
 use super::{Deps, error_capability_result, internal, invalid, optional_u64, required_str};
 use crate::engine::Invocation;
 use crate::shared::protocol::content::CapabilityResultContent;
@@ -23,7 +25,12 @@ pub(super) async fn process_run(
 ) -> Result<CapabilityResult, CapabilityError> {
     let command = required_str(&invocation.payload, "command")?;
     let root = working_directory(invocation)?;
-    ensure_no_network_process_grant(invocation, deps).await?;
+    let file_roots = ensure_no_network_process_grant(invocation, deps).await?;
+    let settings = crate::domains::settings::get_settings();
+    if let Some(jail) = ProcessJail::configured(&settings.agent, &file_roots) {
+        jail.check(command, &root)
+            .map_err(|reason| invalid(format!("process_run {reason}")))?;
+    }
     let timeout_ms = optional_u64(&invocation.payload, "timeoutMs")?
         .unwrap_or(DEFAULT_COMMAND_TIMEOUT_MS)
         .clamp(1, MAX_COMMAND_TIMEOUT_MS);
@@ -31,32 +38,48 @@ pub(super) async fn process_run(
         .map(|value| value as usize)
         .unwrap_or(DEFAULT_OUTPUT_BYTES)
         .clamp(1, MAX_OUTPUT_BYTES);
-    let child = network_denied_shell_command(command, root)?
+    let mut child = network_denied_shell_command(command, root)?
         .kill_on_drop(true)
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .spawn()
         .map_err(|error| internal(format!("spawn process: {error}")))?;
-    let output =
-        match tokio::time::timeout(Duration::from_millis(timeout_ms), child.wait_with_output())
-            .await
-        {
-            Ok(result) => result.map_err(|error| internal(format!("wait for process: {error}")))?,
-            Err(_) => {
-                return Ok(error_capability_result(
-                    format!("process_run timed out after {timeout_ms}ms"),
-                    json!({
-                        "primitiveOperation": "process_run",
-                        "status": "timeout",
-                        "timeoutMs": timeout_ms
-                    }),
-                ));
-            }
-        };
+    let (child_stdout, child_stderr) = child
+        .stdout
+        .take()
+        .zip(child.stderr.take())
+        .ok_or_else(|| internal("spawn process: output pipes unavailable"))?;
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::channel(OUTPUT_CHUNK_CAPACITY);
+    let publisher = tokio::spawn(publish_chunks(
+        deps.engine_host.clone(),
+        invocation.clone(),
+        chunk_rx,
+    ));
+    let run = async {
+        let output = pump_output(child_stdout, child_stderr, &chunk_tx, max_output_bytes).await?;
+        let status = child.wait().await?;
+        Ok::<_, std::io::Error>((output, status))
+    };
+    let outcome = tokio::time::timeout(Duration::from_millis(timeout_ms), run).await;
+    drop(chunk_tx);
+    let _ = publisher.await;
+    let (output, status) = match outcome {
+        Ok(result) => result.map_err(|error| internal(format!("wait for process: {error}")))?,
+        Err(_) => {
+            return Ok(error_capability_result(
+                format!("process_run timed out after {timeout_ms}ms"),
+                json!({
+                    "primitiveOperation": "process_run",
+                    "status": "timeout",
+                    "timeoutMs": timeout_ms
+                }),
+            ));
+        }
+    };
     let stdout = truncate_utf8(&output.stdout, max_output_bytes);
     let stderr = truncate_utf8(&output.stderr, max_output_bytes);
-    let exit_code = output.status.code();
-    let is_error = !output.status.success();
+    let exit_code = status.code();
+    let is_error = !status.success();
     Ok(CapabilityResult {
         content: CapabilityResultBody::Blocks(vec![CapabilityResultContent::text(format!(
             "exitCode: {}\nstdout:\n{}\nstderr:\n{}",
@@ -78,10 +101,11 @@ pub(super) async fn process_run(
     })
 }
 
+/// Require a `networkPolicy none` grant and return its file roots.
 async fn ensure_no_network_process_grant(
     invocation: &Invocation,
     deps: &Deps,
-) -> Result<(), CapabilityError> {
+) -> Result<Vec<String>, CapabilityError> {
     let grant = deps
         .engine_host
         .inspect_authority_grant(&invocation.causal_context.authority_grant_id)
@@ -93,7 +117,7 @@ async fn ensure_no_network_process_grant(
             "process_run requires an authority grant with networkPolicy none",
         ));
     }
-    Ok(())
+    Ok(grant.file_roots)
 }
 
 #[cfg(target_os = "macos")]
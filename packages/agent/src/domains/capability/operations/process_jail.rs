@@ -0,0 +1,365 @@
+//! File-root jail for `process_run` shell commands.
+//!
+//! Opt-in through `agent.processFileRootJail`. When enabled and the
+//! invocation's authority grant names explicit file roots (no `*`), the
+//! command must start inside one of them and may only reference paths inside
+//! them. Containment uses the grant authorizer's own
+//! [`file_roots_allow_path`], so a path the jail admits is one the grant
+//! admits.
+//!
+//! The scan is lexical over shell words: absolute paths, `~` paths, and
+//! `..`-bearing relative paths are checked, including `NAME=value` and
+//! `:`-separated values. The quoted pattern or script handed to a pattern tool
+//! (`grep '/api/'`, `sed '/^$/d'`, `awk '/x/'`) is not scanned as paths, but a
+//! `sed` or `awk` script that writes files, reads other files, or runs commands
+//! is rejected outright. `perl` scripts are scanned like any other argument.
+//! Standard streams, the temp directory, and system executable directories
+//! are always allowed. Shell expansion (`$VAR`, globs,
+//! `cd` inside the script) is not interpreted, so the jail narrows the common
+//! escape forms without claiming to be a full sandbox.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::domains::settings::AgentRuntimeSettings;
+use crate::engine::file_roots_allow_path;
+
+/// Path prefixes commands may reference even when jailed.
+const ALWAYS_ALLOWED_PREFIXES: &[&str] = &[
+    "/dev/null",
+    "/dev/stdin",
+    "/dev/stdout",
+    "/dev/stderr",
+    "/dev/fd",
+    "/tmp",
+    "/bin",
+    "/sbin",
+    "/usr/bin",
+    "/usr/sbin",
+    "/usr/libexec",
+    "/usr/local/bin",
+    "/opt/homebrew/bin",
+];
+
+/// Programs whose quoted pattern or script argument is not a path.
+const PATTERN_PROGRAMS: &[&str] = &["awk", "egrep", "fgrep", "gawk", "grep", "jq", "rg", "sed"];
+
+/// Shell metacharacters that end a command.
+const COMMAND_SEPARATORS: &[char] = &[';', '|', '&', '(', ')', '`', '\n'];
+
+/// Shell metacharacters that end a word without ending the command.
+const REDIRECTIONS: &[char] = &['<', '>'];
+
+#[derive(Debug)]
+pub(super) struct ProcessJail {
+    roots: Vec<String>,
+    allowed: Vec<PathBuf>,
+}
+
+impl ProcessJail {
+    /// Build the jail for a grant when `agent.processFileRootJail` is on.
+    pub(super) fn configured(
+        settings: &AgentRuntimeSettings,
+        file_roots: &[String],
+    ) -> Option<Self> {
+        if !settings.process_file_root_jail {
+            return None;
+        }
+        Self::from_file_roots(file_roots)
+    }
+
+    /// Build a jail from grant file roots; `None` when any root is `*`.
+    pub(super) fn from_file_roots(file_roots: &[String]) -> Option<Self> {
+        if file_roots.is_empty() || file_roots.iter().any(|root| root == "*") {
+            return None;
+        }
+        let mut allowed = Vec::new();
+        let prefixes = ALWAYS_ALLOWED_PREFIXES
+            .iter()
+            .map(PathBuf::from)
+            .chain([std::env::temp_dir()]);
+        for prefix in prefixes {
+            if let Ok(canonical) = prefix.canonicalize()
+                && canonical != prefix
+            {
+                allowed.push(canonical);
+            }
+            allowed.push(prefix);
+        }
+        Some(Self {
+            roots: file_roots.to_vec(),
+            allowed,
+        })
+    }
+
+    /// Reject a working directory or command path that escapes the roots.
+    pub(super) fn check(&self, command: &str, working_directory: &Path) -> Result<(), String> {
+        if !self.within_roots(working_directory) {
+            return Err(format!(
+                "working directory {} is outside the grant file roots",
+                working_directory.display()
+            ));
+        }
+        for words in commands(command) {
+            let scripts = script_word_indices(&words);
+            if let Some(program) = script_escape(&words, &scripts) {
+                return Err(format!(
+                    "{program} script reads or writes files or runs commands the file-root \
+                     jail cannot check"
+                ));
+            }
+            for (index, word) in words.iter().enumerate() {
+                if scripts.contains(&index) {
+                    continue;
+                }
+                for candidate in word.text.split(|c: char| {
+                    c.is_whitespace()
+                        || COMMAND_SEPARATORS.contains(&c)
+                        || REDIRECTIONS.contains(&c)
+                        || matches!(c, '=' | ':' | ',' | '"' | '\'')
+                }) {
+                    if let Some(path) = path_reference(candidate, working_directory)
+                        && !self.allows(&path)
+                    {
+                        return Err(format!(
+                            "command path {candidate} escapes the grant file roots"
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        self.allowed.iter().any(|prefix| path.starts_with(prefix)) || self.within_roots(path)
+    }
+
+    fn within_roots(&self, path: &Path) -> bool {
+        file_roots_allow_path(&self.roots, path).unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Word {
+    text: String,
+    quoted: bool,
+}
+
+/// Split a shell command into commands of words, honoring quotes.
+fn commands(command: &str) -> Vec<Vec<Word>> {
+    let mut commands = vec![Vec::new()];
+    let mut word = Word::default();
+    let mut quote = None;
+    for c in command.chars() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => word.text.push(c),
+            None if matches!(c, '\'' | '"') => {
+                quote = Some(c);
+                word.quoted = true;
+            }
+            None if COMMAND_SEPARATORS.contains(&c) => {
+                push_word(&mut commands, &mut word);
+                commands.push(Vec::new());
+            }
+            None if c.is_whitespace() || REDIRECTIONS.contains(&c) => {
+                push_word(&mut commands, &mut word);
+            }
+            None => word.text.push(c),
+        }
+    }
+    push_word(&mut commands, &mut word);
+    commands
+}
+
+fn push_word(commands: &mut [Vec<Word>], word: &mut Word) {
+    let word = std::mem::take(word);
+    if !word.text.is_empty()
+        && let Some(current) = commands.last_mut()
+    {
+        current.push(word);
+    }
+}
+
+/// `NAME=value` prefix assignments precede the program word.
+fn is_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric())
+    })
+}
+
+fn program_name(word: &str) -> &str {
+    word.rsplit('/').next().unwrap_or(word)
+}
+
+/// Indices of the quoted pattern or script words a pattern program takes: the
+/// first quoted argument and any quoted argument directly after `-e`. Later
+/// quoted arguments are file operands and are scanned like any other word.
+fn script_word_indices(words: &[Word]) -> Vec<usize> {
+    let Some(program) = words.iter().position(|word| !is_assignment(&word.text)) else {
+        return Vec::new();
+    };
+    if !PATTERN_PROGRAMS.contains(&program_name(&words[program].text)) {
+        return Vec::new();
+    }
+    let mut scripts = Vec::new();
+    let mut seen_quoted = false;
+    for index in program + 1..words.len() {
+        let word = &words[index];
+        if !word.quoted {
+            continue;
+        }
+        let previous = &words[index - 1];
+        if !seen_quoted || (!previous.quoted && previous.text == "-e") {
+            scripts.push(index);
+        }
+        seen_quoted = true;
+    }
+    scripts
+}
+
+/// The program whose script touches files or processes the jail cannot see.
+/// An unquoted script is checked too, though its paths are still scanned.
+fn script_escape<'a>(words: &'a [Word], scripts: &[usize]) -> Option<&'a str> {
+    let program = words.iter().position(|word| !is_assignment(&word.text))?;
+    let name = program_name(&words[program].text);
+    let unquoted_script = || {
+        words[program + 1..]
+            .iter()
+            .find(|word| !word.text.starts_with('-'))
+    };
+    let escapes = |script: &Word| match name {
+        "sed" => sed_script_escapes(&script.text),
+        "awk" | "gawk" => awk_script_escapes(&script.text),
+        _ => false,
+    };
+    let escaped = if scripts.is_empty() {
+        unquoted_script().is_some_and(escapes)
+    } else {
+        scripts.iter().any(|index| escapes(&words[*index]))
+    };
+    escaped.then_some(name)
+}
+
+/// `sed` commands `r`, `R`, `w`, `W`, `e` and the `s///w` and `s///e` flags.
+fn sed_script_escapes(script: &str) -> bool {
+    let chars: Vec<char> = script.chars().collect();
+    let mut index = 0;
+    while let Some(&c) = chars.get(index) {
+        match c {
+            'r' | 'R' | 'w' | 'W' | 'e' => return true,
+            '/' => index = skip_address_flags(&chars, skip_delimited(&chars, index + 1, '/')),
+            '\\' => {
+                let delimiter = chars.get(index + 1).copied().unwrap_or('\\');
+                index = skip_address_flags(&chars, skip_delimited(&chars, index + 2, delimiter));
+            }
+            's' | 'y' => {
+                let Some(&delimiter) = chars.get(index + 1) else {
+                    return false;
+                };
+                index = skip_delimited(&chars, index + 2, delimiter);
+                index = skip_delimited(&chars, index, delimiter);
+                let flags_end = command_end(&chars, index, &[';', '\n', '}']);
+                if c == 's'
+                    && chars[index..flags_end]
+                        .iter()
+                        .any(|f| matches!(f, 'w' | 'e'))
+                {
+                    return true;
+                }
+                index = flags_end;
+            }
+            // Text, comments, and labels run to the end of the line or command.
+            'a' | 'i' | 'c' | '#' => index = command_end(&chars, index, &['\n']),
+            ':' | 'b' | 't' | 'T' => index = command_end(&chars, index, &[';', '\n']),
+            _ => index += 1,
+        }
+    }
+    false
+}
+
+fn skip_delimited(chars: &[char], mut index: usize, delimiter: char) -> usize {
+    while let Some(&c) = chars.get(index) {
+        index += 1;
+        if c == '\\' {
+            index += 1;
+        } else if c == delimiter {
+            break;
+        }
+    }
+    index
+}
+
+fn skip_address_flags(chars: &[char], mut index: usize) -> usize {
+    while chars.get(index).is_some_and(|c| matches!(c, 'I' | 'M')) {
+        index += 1;
+    }
+    index
+}
+
+fn command_end(chars: &[char], index: usize, terminators: &[char]) -> usize {
+    chars
+        .iter()
+        .skip(index)
+        .position(|c| terminators.contains(c))
+        .map_or(chars.len(), |offset| index + offset)
+}
+
+/// `awk` statements calling `system`, reading with `getline <` or
+/// `cmd | getline`, or redirecting `print` output to a file or pipe.
+fn awk_script_escapes(script: &str) -> bool {
+    script.split([';', '{', '}', '\n']).any(|statement| {
+        let piped = statement.replace("||", "").contains('|');
+        let mut names = statement
+            .split(|c: char| c != '_' && !c.is_ascii_alphanumeric())
+            .filter(|name| !name.is_empty());
+        names.any(|name| match name {
+            "system" => true,
+            "getline" => piped || statement.contains('<'),
+            "print" | "printf" => piped || statement.contains('>'),
+            _ => false,
+        })
+    })
+}
+
+/// Resolve a shell word to a path when it names one outside plain relative form.
+fn path_reference(word: &str, working_directory: &Path) -> Option<PathBuf> {
+    if word.starts_with('/') {
+        return Some(normalize(Path::new(word)));
+    }
+    if word == "~" || word.starts_with("~/") {
+        return Some(normalize(&expand_home(word)));
+    }
+    let relative = Path::new(word);
+    if relative
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        return Some(normalize(&working_directory.join(relative)));
+    }
+    None
+}
+
+fn expand_home(raw: &str) -> PathBuf {
+    let home = crate::shared::foundation::paths::home_dir();
+    match raw.strip_prefix('~') {
+        Some("") => PathBuf::from(home),
+        Some(rest) if rest.starts_with('/') => PathBuf::from(home).join(&rest[1..]),
+        _ => PathBuf::from(raw),
+    }
+}
+
+/// Normalize `.` and `..` lexically; symlinks are resolved by the root check.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+
+use super::process_jail::ProcessJail;
+use crate::domains::settings::AgentRuntimeSettings;
+
+fn workspace() -> (tempfile::TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    std::fs::create_dir(root.join("src")).unwrap();
+    (dir, root)
+}
+
+fn jail(root: &Path) -> ProcessJail {
+    ProcessJail::from_file_roots(&[root.display().to_string()]).unwrap()
+}
+
+#[test]
+fn jail_is_off_unless_configured() {
+    let roots = ["/srv/project".to_owned()];
+    assert!(ProcessJail::configured(&AgentRuntimeSettings::default(), &roots).is_none());
+    let enabled = AgentRuntimeSettings {
+        process_file_root_jail: true,
+        ..AgentRuntimeSettings::default()
+    };
+    assert!(ProcessJail::configured(&enabled, &roots).is_some());
+    assert!(ProcessJail::configured(&enabled, &["*".to_owned()]).is_none());
+}
+
+#[test]
+fn wildcard_or_empty_roots_disable_the_jail() {
+    assert!(ProcessJail::from_file_roots(&["*".to_owned()]).is_none());
+    assert!(ProcessJail::from_file_roots(&[]).is_none());
+}
+
+#[test]
+fn commands_without_path_arguments_run_normally() {
+    let (_dir, root) = workspace();
+    let jail = jail(&root);
+    for command in [
+        "ls -la",
+        "cargo test --workspace",
+        "echo hi | wc -c",
+        "git status",
+    ] {
+        assert!(jail.check(command, &root).is_ok(), "{command}");
+    }
+}
+
+#[test]
+fn paths_inside_the_root_are_allowed() {
+    let (_dir, root) = workspace();
+    let jail = jail(&root);
+    let inside = format!("cat {}/src/lib.rs", root.display());
+    assert!(jail.check(&inside, &root).is_ok());
+    assert!(jail.check("cat src/../Cargo.toml", &root).is_ok());
+    assert!(jail.check("cd src && ls ..", &root.join("src")).is_ok());
+    assert!(jail.check("make 2>/dev/null", &root).is_ok());
+}
+
+#[test]
+fn patterns_system_tools_and_scratch_paths_are_not_escapes() {
+    let (_dir, root) = workspace();
+    let jail = jail(&root);
+    for command in [
+        "sed '/^$/d' notes.txt",
+        "awk '/x/' data.csv",
+        "awk -F, '$3 > 100 { n++ } END { print n }' data.csv",
+        "sed -e 's/a/b/g' -e '/^#/d' notes.txt",
+        "sed -n s/a/b/p notes.txt",
+        "grep -E \"/api/v[0-9]+/\" src/routes.rs",
+        "PATH=/usr/bin:$PATH make",
+        "/usr/bin/env python3 tool.py",
+        "sort data.txt > /tmp/sorted.txt",
+        "echo done >/dev/stdout 2>/dev/stderr",
+    ] {
+        assert!(jail.check(command, &root).is_ok(), "{command}");
+    }
+}
+
+#[test]
+fn absolute_and_parent_escapes_are_rejected() {
+    let (_dir, root) = workspace();
+    let jail = jail(&root);
+    for command in [
+        "cat /etc/passwd",
+        "ls ../../etc",
+        "cp secrets.txt '../../out'",
+        "tool --output=/opt/elsewhere",
+        "echo $(cat /etc/hosts)",
+        "sed -i 's/a/b/' /etc/hosts",
+        "sed -i 's/a/b/' '/etc/hosts'",
+        "perl -ne 'open(F, \">/etc/cron.d/job\")' notes.txt",
+        "PATH=/opt/tools:$PATH make",
+        "ls ~",
+    ] {
+        let error = jail.check(command, &root).unwrap_err();
+        assert!(
+            error.contains("escapes the grant file roots"),
+            "{command}: {error}"
+        );
+    }
+}
+
+#[test]
+fn sed_and_awk_scripts_that_touch_files_or_processes_are_rejected() {
+    let (_dir, root) = workspace();
+    let jail = jail(&root);
+    for command in [
+        "sed 'w /etc/cron.d/job' notes.txt",
+        "sed -n '/x/Iw out' notes.txt",
+        "sed 's/a/b/w /etc/x' notes.txt",
+        "sed s/a/b/w/etc/x notes.txt",
+        "sed -e 's/a/b/' -e '1r /etc/passwd' notes.txt",
+        "sed '1e id' notes.txt",
+        "awk '{ system(\"id\") }' data.csv",
+        "awk '{ print > \"/etc/x\" }' data.csv",
+        "awk 'BEGIN { \"id\" | getline user }'",
+        "gawk 'BEGIN { while ((getline line < \"/etc/passwd\") > 0) n++ }'",
+    ] {
+        let error = jail.check(command, &root).unwrap_err();
+        assert!(
+            error.contains("script reads or writes files or runs commands"),
+            "{command}: {error}"
+        );
+    }
+}
+
+#[test]
+fn working_directory_outside_roots_is_rejected() {
+    let (_dir, root) = workspace();
+    let (_other_dir, other) = workspace();
+    let error = jail(&root).check("ls", &other).unwrap_err();
+    assert!(error.contains("working directory"));
+}
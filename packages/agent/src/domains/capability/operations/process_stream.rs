@@ -0,0 +1,235 @@
+//! Incremental stdout/stderr delivery for `process_run`.
+//!
+//! Output is read line by line while the child runs. Each line is appended to
+//! the buffer that backs the final result and forwarded as a chunk, which
+//! `process_run` publishes on the capability runtime topic as
+//! `capability.invocation.output` so clients can render long commands live.
+//!
+//! Both the buffer and the forwarded chunks stop at `maxOutputBytes` per
+//! stream; the pipes keep draining past that point so the child never stalls
+//! on a full pipe. Lines longer than [`MAX_CHUNK_BYTES`] are split, and when
+//! the publisher falls behind, new text is merged into the chunk still
+//! waiting for a slot instead of blocking the read.
+
+use serde_json::json;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::mpsc::{Receiver, Sender};
+
+use crate::domains::capability::contract;
+use crate::engine::{
+    EngineHostHandle, Invocation, PublishStreamEvent, RUNTIME_METADATA_PROVIDER_INVOCATION_ID,
+    VisibilityScope,
+};
+use crate::shared::server::events::ServerEventPayload;
+
+/// Chunks buffered ahead of the publisher before new text is merged.
+pub(super) const OUTPUT_CHUNK_CAPACITY: usize = 256;
+
+/// Longest piece of a single line forwarded as one chunk.
+pub(super) const MAX_CHUNK_BYTES: usize = 8 * 1024;
+
+/// Which pipe a chunk came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        }
+    }
+}
+
+/// One incremental piece of process output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct OutputChunk {
+    pub(super) stream: OutputStream,
+    pub(super) text: String,
+}
+
+/// Output collected once both pipes reach EOF, capped per stream.
+#[derive(Debug, Default)]
+pub(super) struct CollectedOutput {
+    pub(super) stdout: Vec<u8>,
+    pub(super) stderr: Vec<u8>,
+}
+
+/// Drain both pipes concurrently, forwarding each line as it arrives and
+/// keeping at most `max_output_bytes` of each stream.
+pub(super) async fn pump_output<O, E>(
+    stdout: O,
+    stderr: E,
+    chunks: &Sender<OutputChunk>,
+    max_output_bytes: usize,
+) -> std::io::Result<CollectedOutput>
+where
+    O: AsyncRead + Unpin,
+    E: AsyncRead + Unpin,
+{
+    let (stdout, stderr) = tokio::try_join!(
+        pump_stream(stdout, OutputStream::Stdout, chunks, max_output_bytes),
+        pump_stream(stderr, OutputStream::Stderr, chunks, max_output_bytes),
+    )?;
+    Ok(CollectedOutput { stdout, stderr })
+}
+
+async fn pump_stream<R>(
+    reader: R,
+    stream: OutputStream,
+    chunks: &Sender<OutputChunk>,
+    max_output_bytes: usize,
+) -> std::io::Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut reader = BufReader::new(reader);
+    let mut forwarder = ChunkForwarder::new(stream, chunks);
+    let mut collected = Vec::new();
+    let mut piece = Vec::new();
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            forwarder.forward(&piece, true);
+            forwarder.finish().await;
+            return Ok(collected);
+        }
+        let room = max_output_bytes.saturating_sub(collected.len());
+        if room == 0 {
+            // Past the budget: keep draining so the child is not blocked.
+            let consumed = available.len();
+            reader.consume(consumed);
+            continue;
+        }
+        let want = room.min(MAX_CHUNK_BYTES - piece.len());
+        let take = match available.iter().take(want).position(|byte| *byte == b'\n') {
+            Some(newline) => newline + 1,
+            None => available.len().min(want),
+        };
+        piece.extend_from_slice(&available[..take]);
+        collected.extend_from_slice(&available[..take]);
+        reader.consume(take);
+        if piece.ends_with(b"\n")
+            || piece.len() >= MAX_CHUNK_BYTES
+            || collected.len() >= max_output_bytes
+        {
+            let carry = forwarder.forward(&piece, false);
+            piece.drain(..piece.len() - carry);
+        }
+    }
+}
+
+/// Hands chunks to the publisher without ever waiting for a free slot.
+struct ChunkForwarder<'a> {
+    stream: OutputStream,
+    chunks: &'a Sender<OutputChunk>,
+    /// Text that found the channel full, merged with later text until a
+    /// slot opens.
+    held: String,
+    closed: bool,
+}
+
+impl<'a> ChunkForwarder<'a> {
+    fn new(stream: OutputStream, chunks: &'a Sender<OutputChunk>) -> Self {
+        Self {
+            stream,
+            chunks,
+            held: String::new(),
+            closed: false,
+        }
+    }
+
+    /// Forward the UTF-8 prefix of `bytes` and return how many trailing
+    /// bytes of an incomplete character were left for the next piece. The
+    /// last piece of a stream is forwarded whole.
+    fn forward(&mut self, bytes: &[u8], last: bool) -> usize {
+        let carry = if last {
+            0
+        } else {
+            incomplete_utf8_suffix(bytes)
+        };
+        let text = String::from_utf8_lossy(&bytes[..bytes.len() - carry]);
+        if self.closed || text.is_empty() {
+            return carry;
+        }
+        self.held.push_str(&text);
+        let chunk = OutputChunk {
+            stream: self.stream,
+            text: std::mem::take(&mut self.held),
+        };
+        match self.chunks.try_send(chunk) {
+            Ok(()) => {}
+            Err(TrySendError::Full(chunk)) => self.held = chunk.text,
+            // A closed receiver only means nobody is watching; keep collecting.
+            Err(TrySendError::Closed(_)) => self.closed = true,
+        }
+        carry
+    }
+
+    /// The pipe has closed, so waiting for a slot no longer holds up the
+    /// child's writes.
+    async fn finish(self) {
+        if !self.closed && !self.held.is_empty() {
+            let _ = self
+                .chunks
+                .send(OutputChunk {
+                    stream: self.stream,
+                    text: self.held,
+                })
+                .await;
+        }
+    }
+}
+
+/// Length of a trailing, still incomplete UTF-8 sequence in `bytes`.
+fn incomplete_utf8_suffix(bytes: &[u8]) -> usize {
+    match std::str::from_utf8(bytes) {
+        Err(error) if error.error_len().is_none() => bytes.len() - error.valid_up_to(),
+        _ => 0,
+    }
+}
+
+/// Publish chunks until the sender side is dropped.
+pub(super) async fn publish_chunks(
+    engine_host: EngineHostHandle,
+    invocation: Invocation,
+    mut chunks: Receiver<OutputChunk>,
+) {
+    let invocation_id = invocation
+        .causal_context
+        .runtime_metadata(RUNTIME_METADATA_PROVIDER_INVOCATION_ID)
+        .map_or_else(|| invocation.id.to_string(), ToOwned::to_owned);
+    while let Some(chunk) = chunks.recv().await {
+        let event = ServerEventPayload::new(
+            "capability.invocation.output",
+            invocation.causal_context.session_id.clone(),
+            Some(json!({
+                "invocationId": invocation_id,
+                "output": chunk.text,
+                "stream": chunk.stream.as_str(),
+            })),
+        );
+        if let Err(error) = engine_host
+            .publish_stream_event(PublishStreamEvent {
+                topic: contract::STREAM_TOPICS[0].to_owned(),
+                payload: json!({
+                    "serverEvent": event,
+                    "sourceEventType": "capability.invocation.output",
+                }),
+                visibility: VisibilityScope::Session,
+                session_id: invocation.causal_context.session_id.clone(),
+                workspace_id: invocation.causal_context.workspace_id.clone(),
+                producer: "capability".to_owned(),
+                trace_id: Some(invocation.causal_context.trace_id.clone()),
+                parent_invocation_id: Some(invocation.id.clone()),
+            })
+            .await
+        {
+            tracing::warn!(error = %error, "failed to publish process output chunk");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use tokio::io::AsyncWriteExt;
+
+use super::process_stream::{
+    MAX_CHUNK_BYTES, OUTPUT_CHUNK_CAPACITY, OutputChunk, OutputStream, pump_output,
+};
+
+const BUDGET: usize = 20_000;
+
+fn chunk(stream: OutputStream, text: &str) -> OutputChunk {
+    OutputChunk {
+        stream,
+        text: text.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn lines_are_forwarded_before_the_process_finishes() {
+    let (mut stdout_writer, stdout) = tokio::io::duplex(64);
+    let (mut stderr_writer, stderr) = tokio::io::duplex(64);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(OUTPUT_CHUNK_CAPACITY);
+
+    // The runner side only closes its pipes after each earlier line has
+    // already been observed as a chunk.
+    let runner = async {
+        stdout_writer.write_all(b"building\n").await.unwrap();
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            chunk(OutputStream::Stdout, "building\n")
+        );
+        stderr_writer.write_all(b"warning: slow\n").await.unwrap();
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            chunk(OutputStream::Stderr, "warning: slow\n")
+        );
+        stdout_writer.write_all(b"done").await.unwrap();
+        drop(stdout_writer);
+        drop(stderr_writer);
+    };
+    let (output, ()) = tokio::join!(pump_output(stdout, stderr, &tx, BUDGET), runner);
+    let output = output.unwrap();
+    drop(tx);
+
+    assert_eq!(
+        rx.recv().await.unwrap(),
+        chunk(OutputStream::Stdout, "done")
+    );
+    assert!(rx.recv().await.is_none());
+    assert_eq!(output.stdout, b"building\ndone");
+    assert_eq!(output.stderr, b"warning: slow\n");
+}
+
+#[tokio::test]
+async fn full_output_is_kept_when_nobody_listens() {
+    let (tx, rx) = tokio::sync::mpsc::channel(OUTPUT_CHUNK_CAPACITY);
+    drop(rx);
+    let output = pump_output(&b"a\nb\n"[..], &b""[..], &tx, BUDGET)
+        .await
+        .unwrap();
+    assert_eq!(output.stdout, b"a\nb\n");
+    assert!(output.stderr.is_empty());
+}
+
+#[tokio::test]
+async fn output_past_the_budget_is_neither_kept_nor_forwarded() {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(OUTPUT_CHUNK_CAPACITY);
+    let output = pump_output(&b"first\nsecond\nthird\n"[..], &b"oops\n"[..], &tx, 9)
+        .await
+        .unwrap();
+    drop(tx);
+
+    assert_eq!(output.stdout, b"first\nsec");
+    assert_eq!(output.stderr, b"oops\n");
+    let mut forwarded = String::new();
+    while let Some(chunk) = rx.recv().await {
+        if chunk.stream == OutputStream::Stdout {
+            forwarded.push_str(&chunk.text);
+        }
+    }
+    assert_eq!(forwarded, "first\nsec");
+}
+
+#[tokio::test]
+async fn oversized_lines_are_split_on_character_boundaries() {
+    let line = "é".repeat(MAX_CHUNK_BYTES);
+    let (tx, mut rx) = tokio::sync::mpsc::channel(OUTPUT_CHUNK_CAPACITY);
+    let output = pump_output(line.as_bytes(), &b""[..], &tx, line.len())
+        .await
+        .unwrap();
+    drop(tx);
+
+    assert_eq!(output.stdout, line.as_bytes());
+    let mut pieces = Vec::new();
+    while let Some(chunk) = rx.recv().await {
+        assert!(chunk.text.len() <= MAX_CHUNK_BYTES);
+        pieces.push(chunk.text);
+    }
+    assert!(pieces.len() >= 2);
+    assert_eq!(pieces.concat(), line);
+}
+
+#[tokio::test]
+async fn a_stalled_publisher_does_not_block_the_child() {
+    let (mut writer, stdout) = tokio::io::duplex(64);
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<OutputChunk>(1);
+    let input = "line\n".repeat(100);
+
+    // The child writes far more than the pipe holds while nothing drains the
+    // channel; only after every write lands does the publisher catch up.
+    let child_then_publisher = async {
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            writer.write_all(input.as_bytes()).await.unwrap();
+            drop(writer);
+        })
+        .await
+        .expect("child writes stalled behind a full channel");
+        let mut forwarded = String::new();
+        while let Some(chunk) = rx.recv().await {
+            forwarded.push_str(&chunk.text);
+        }
+        forwarded
+    };
+    let pump = async {
+        let output = pump_output(stdout, &b""[..], &tx, BUDGET).await;
+        drop(tx);
+        output
+    };
+    let (output, forwarded) = tokio::join!(pump, child_then_publisher);
+
+    assert_eq!(output.unwrap().stdout, input.as_bytes());
+    assert_eq!(forwarded, input);
+}
@@ -42,6 +42,7 @@ pub(crate) const SUPPORTED_OPERATION_NAMES: &[&str] = &[
     "trace_list",
     "trace_get",
     "log_recent",
+    "result_continue",
     "replay_manifest",
     "catalog_search",
     "catalog_inspect",
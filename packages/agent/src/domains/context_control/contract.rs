@@ -1,5 +1,7 @@
 //! Context-control domain contracts.
 
+use std::time::Duration;
+
 use serde_json::json;
 
 use crate::domains::registration::catalog::CapabilitySpec;
@@ -22,6 +24,9 @@ pub(crate) const ACTION_SCHEMA_VERSION: &str =
     crate::engine::CONTEXT_CONTROL_ACTION_PAYLOAD_SCHEMA_VERSION;
 pub(crate) const EPOCH_SCHEMA_VERSION: &str =
     crate::engine::CONTEXT_CONTROL_EPOCH_PAYLOAD_SCHEMA_VERSION;
+/// Compaction summarizes through the model, so it gets a generous bound
+/// rather than none.
+pub(crate) const COMPACT_HANDLER_TIMEOUT: Duration = Duration::from_secs(300);
 
 pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
     Ok(vec![
@@ -36,7 +41,7 @@ pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
         .idempotency(IdempotencyContract::caller_session_engine_ledger())
         .idempotency_mode(TransportIdempotencyMode::ExplicitRequired)
         .request_schema(session_request_schema())
-        .response_schema(common_response_schema("context_control_snapshot"))
+        .response_schema(snapshot_response_schema())
         .build()?,
         contract(
             "context_control::compact",
@@ -50,6 +55,7 @@ pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
         .idempotency_mode(TransportIdempotencyMode::ExplicitRequired)
         .request_schema(action_request_schema())
         .response_schema(common_response_schema("context_control_compact"))
+        .handler_timeout(COMPACT_HANDLER_TIMEOUT)
         .stream_topics(vec![CONTEXT_CONTROL_TOPIC])
         .build()?,
         contract(
@@ -105,7 +111,7 @@ pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
         .idempotency(IdempotencyContract::caller_session_engine_ledger())
         .idempotency_mode(TransportIdempotencyMode::ExplicitRequired)
         .request_schema(session_request_schema())
-        .response_schema(common_response_schema("context_control_snapshot"))
+        .response_schema(snapshot_response_schema())
         .build()?,
         contract(
             "context_control::ui_compact",
@@ -119,6 +125,7 @@ pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
         .idempotency_mode(TransportIdempotencyMode::ExplicitRequired)
         .request_schema(action_request_schema())
         .response_schema(common_response_schema("context_control_compact"))
+        .handler_timeout(COMPACT_HANDLER_TIMEOUT)
         .stream_topics(vec![CONTEXT_CONTROL_TOPIC])
         .build()?,
         contract(
@@ -186,6 +193,7 @@ fn session_request_schema() -> serde_json::Value {
         "properties": {
             "sessionId": {"type": "string", "minLength": 1},
             "reason": {"type": "string", "minLength": 1, "maxLength": 200},
+            "sinceContextControlSnapshotResourceId": {"type": "string", "minLength": 1},
             "idempotencyKey": {"type": "string", "minLength": 1, "maxLength": 256}
         }
     })
@@ -229,6 +237,23 @@ fn inspect_request_schema() -> serde_json::Value {
     })
 }
 
+/// Snapshots answer with either the full `projection` or, for a diff
+/// request, only the `diff`.
+fn snapshot_response_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "additionalProperties": true,
+        "required": ["schemaVersion", "operation", "status"],
+        "properties": {
+            "schemaVersion": {"type": "string"},
+            "operation": {"const": "context_control_snapshot"},
+            "status": {"type": "string"},
+            "projection": {"type": "object"},
+            "diff": {"type": "object"}
+        }
+    })
+}
+
 fn common_response_schema(operation: &'static str) -> serde_json::Value {
     json!({
         "type": "object",
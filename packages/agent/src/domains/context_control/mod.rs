@@ -17,6 +17,7 @@
 //! | `resource_store` | Resource creation, inspection, scope, and lifecycle events |
 //! | `service` | Snapshot, compact, clear, list, and inspect behavior |
 //! | `snapshot` | Session composition snapshot builder and bounded ref counters |
+//! | `snapshot_diff` | Structural diff against an earlier snapshot of the same session |
 //! | `tests` | Redaction, replay, epoch, selector, and provider-safety tests |
 //! | `validation` | Bounded input, idempotency, and error mapping helpers |
 //!
@@ -44,6 +45,7 @@ mod records;
 mod resource_store;
 pub(crate) mod service;
 mod snapshot;
+mod snapshot_diff;
 mod validation;
 
 #[derive(Clone)]
@@ -80,5 +82,7 @@ pub(crate) fn worker_module(
     )
 }
 
+#[cfg(test)]
+mod snapshot_diff_tests;
 #[cfg(test)]
 mod tests;
@@ -7,35 +7,30 @@ use serde_json::{Value, json};
 use crate::domains::agent::r#loop::orchestrator::event_persister::EventPersister;
 use crate::domains::registration::bindings::operation_bindings;
 use crate::domains::session::event_store::{AppendOptions, EventType};
-use crate::engine::{
-    CreateResource, EngineResource, EngineResourceLocation, EngineResourceScope,
-    EngineResourceVersion, Invocation, ListResources,
-};
+use crate::engine::{EngineResourceScope, Invocation, ListResources};
 use crate::shared::server::errors::CapabilityError;
 
 use super::authority::{AccessMode, ensure_authority, session_scope_for_invocation};
-use super::contract::{ACTION_SCHEMA_VERSION, SNAPSHOT_SCHEMA_VERSION, WORKER};
+use super::contract::ACTION_SCHEMA_VERSION;
 use super::projection::{
     action_projection, action_response, action_summary, event_ref, safe_compacted_token_estimate,
-    safe_compaction_summary, snapshot_projection,
+    safe_compaction_summary,
 };
 use super::records::{
     ActionInput, EpochInput, action_record, action_resource_id, epoch_record, epoch_resource_id,
-    resource_policy, snapshot_resource_id, version_ref,
+    version_ref,
 };
 use super::resource_store::{
     create_action_resource, create_epoch_resource, current_payload, ensure_context_action,
-    ensure_context_snapshot, ensure_scope, inspect_resource_required, publish_lifecycle_event,
+    ensure_scope, inspect_resource_required, publish_lifecycle_event,
 };
-use super::snapshot::build_snapshot_record;
+use super::snapshot::record_snapshot;
+pub(crate) use super::snapshot::snapshot_value_at;
 use super::validation::{
-    actor_kind, bounded_text, engine_error, id_error, idempotency_key, optional_str, optional_u64,
-    reason, required_str, runtime_error, store_error, system_invocation, ui_system_invocation,
-};
-use super::{
-    CONTEXT_CONTROL_ACTION_KIND, CONTEXT_CONTROL_SNAPSHOT_KIND, CONTEXT_CONTROL_SNAPSHOT_SCHEMA_ID,
-    Deps,
+    actor_kind, bounded_text, engine_error, idempotency_key, optional_str, optional_u64, reason,
+    required_str, runtime_error, store_error, system_invocation, ui_system_invocation,
 };
+use super::{CONTEXT_CONTROL_ACTION_KIND, Deps};
 
 const DEFAULT_LIST_LIMIT: usize = 20;
 const MAX_LIST_LIMIT: usize = 50;
@@ -323,48 +318,6 @@ pub(crate) async fn record_runtime_compaction_action(
     Ok(())
 }
 
-pub(crate) async fn snapshot_value_at(
-    deps: &Deps,
-    invocation: &Invocation,
-    payload: &Value,
-    operation_at: DateTime<Utc>,
-) -> Result<Value, CapabilityError> {
-    let (session_id, scope) = session_scope_for_invocation(
-        invocation,
-        optional_str(payload, "sessionId")?,
-        "context_control_snapshot",
-    )?;
-    ensure_authority(
-        deps,
-        invocation,
-        "context_control_snapshot",
-        AccessMode::Write,
-        &session_id,
-        None,
-    )
-    .await?;
-    let idempotency_key = idempotency_key(invocation, payload, "context_control_snapshot")?;
-    let snapshot_id = format!("snapshot-{idempotency_key}");
-    let (resource, version, record, replay) = record_snapshot(
-        deps,
-        invocation,
-        &session_id,
-        &scope,
-        &snapshot_id,
-        operation_at,
-    )
-    .await?;
-    Ok(json!({
-        "schemaVersion": SNAPSHOT_SCHEMA_VERSION,
-        "operation": "context_control_snapshot",
-        "status": resource.lifecycle,
-        "idempotentReplay": replay,
-        "contextControlSnapshotResourceId": resource.resource_id,
-        "contextControlSnapshotVersionId": version.version_id,
-        "projection": snapshot_projection(&resource, &version, &record)
-    }))
-}
-
 pub(crate) async fn compact_value_at(
     deps: &Deps,
     invocation: &Invocation,
@@ -780,66 +733,3 @@ pub(crate) async fn action_inspect_value(
         "projection": action_projection(&inspection.resource, version, record)
     }))
 }
-
-async fn record_snapshot(
-    deps: &Deps,
-    invocation: &Invocation,
-    session_id: &str,
-    scope: &EngineResourceScope,
-    snapshot_id: &str,
-    operation_at: DateTime<Utc>,
-) -> Result<(EngineResource, EngineResourceVersion, Value, bool), CapabilityError> {
-    let resource_id = snapshot_resource_id(session_id, snapshot_id);
-    if let Some(existing) = deps
-        .engine_host
-        .inspect_resource(&resource_id)
-        .await
-        .map_err(engine_error)?
-    {
-        ensure_context_snapshot(&existing, "context_control_snapshot replay")?;
-        ensure_scope(&existing, scope, "context_control_snapshot replay")?;
-        let (version, payload) = current_payload(&existing, "context_control_snapshot replay")?;
-        return Ok((
-            existing.resource.clone(),
-            version.clone(),
-            payload.clone(),
-            true,
-        ));
-    }
-    let record = build_snapshot_record(deps, session_id, scope, snapshot_id, operation_at).await?;
-    let resource = deps
-        .engine_host
-        .create_resource(CreateResource {
-            resource_id: Some(resource_id),
-            kind: CONTEXT_CONTROL_SNAPSHOT_KIND.to_owned(),
-            schema_id: Some(CONTEXT_CONTROL_SNAPSHOT_SCHEMA_ID.to_owned()),
-            scope: scope.clone(),
-            owner_worker_id: crate::engine::WorkerId::new(WORKER).map_err(id_error)?,
-            owner_actor_id: invocation.causal_context.actor_id.clone(),
-            lifecycle: Some("available".to_owned()),
-            policy: resource_policy(CONTEXT_CONTROL_SNAPSHOT_KIND),
-            initial_payload: Some(record),
-            locations: vec![EngineResourceLocation {
-                kind: "context_control_snapshot".to_owned(),
-                uri: format!("context-control-snapshot:{snapshot_id}"),
-                mime_type: Some("application/json".to_owned()),
-                size_bytes: None,
-            }],
-            trace_id: invocation.causal_context.trace_id.clone(),
-            invocation_id: Some(invocation.id.clone()),
-        })
-        .await
-        .map_err(engine_error)?;
-    publish_lifecycle_event(
-        deps,
-        invocation,
-        "context_control.snapshot_recorded",
-        &resource,
-        json!({"metadataOnly": true, "networkPolicy": "none"}),
-    )
-    .await?;
-    let inspection =
-        inspect_resource_required(deps, &resource.resource_id, "context control snapshot").await?;
-    let (version, payload) = current_payload(&inspection, "context_control_snapshot created")?;
-    Ok((resource, version.clone(), payload.clone(), false))
-}
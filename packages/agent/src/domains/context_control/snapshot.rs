@@ -2,16 +2,134 @@ use chrono::{DateTime, Utc};
 use serde_json::{Value, json};
 
 use crate::domains::agent::context::token_estimator::estimate_message_tokens;
-use crate::engine::{EngineResourceScope, ListResources};
+use crate::engine::{
+    CreateResource, EngineResource, EngineResourceLocation, EngineResourceScope,
+    EngineResourceVersion, Invocation, ListResources,
+};
 use crate::shared::server::errors::CapabilityError;
 
-use super::projection::event_ref;
-use super::records::{SnapshotInput, snapshot_record};
-use super::validation::{engine_error, store_error};
+use super::authority::{AccessMode, ensure_authority, session_scope_for_invocation};
+use super::contract::{SNAPSHOT_SCHEMA_VERSION, WORKER};
+use super::projection::{event_ref, snapshot_projection};
+use super::records::{SnapshotInput, resource_policy, snapshot_record, snapshot_resource_id};
+use super::resource_store::{
+    current_payload, ensure_context_snapshot, ensure_scope, inspect_resource_required,
+    publish_lifecycle_event,
+};
+use super::snapshot_diff::attach_snapshot_diff;
+use super::validation::{engine_error, id_error, idempotency_key, optional_str, store_error};
 use super::{
-    CONTEXT_CONTROL_ACTION_KIND, CONTEXT_CONTROL_EPOCH_KIND, CONTEXT_CONTROL_SNAPSHOT_KIND, Deps,
+    CONTEXT_CONTROL_ACTION_KIND, CONTEXT_CONTROL_EPOCH_KIND, CONTEXT_CONTROL_SNAPSHOT_KIND,
+    CONTEXT_CONTROL_SNAPSHOT_SCHEMA_ID, Deps,
 };
 
+pub(crate) async fn snapshot_value_at(
+    deps: &Deps,
+    invocation: &Invocation,
+    payload: &Value,
+    operation_at: DateTime<Utc>,
+) -> Result<Value, CapabilityError> {
+    let (session_id, scope) = session_scope_for_invocation(
+        invocation,
+        optional_str(payload, "sessionId")?,
+        "context_control_snapshot",
+    )?;
+    ensure_authority(
+        deps,
+        invocation,
+        "context_control_snapshot",
+        AccessMode::Write,
+        &session_id,
+        None,
+    )
+    .await?;
+    let idempotency_key = idempotency_key(invocation, payload, "context_control_snapshot")?;
+    let snapshot_id = format!("snapshot-{idempotency_key}");
+    let (resource, version, record, replay) = record_snapshot(
+        deps,
+        invocation,
+        &session_id,
+        &scope,
+        &snapshot_id,
+        operation_at,
+    )
+    .await?;
+    let mut response = json!({
+        "schemaVersion": SNAPSHOT_SCHEMA_VERSION,
+        "operation": "context_control_snapshot",
+        "status": resource.lifecycle,
+        "idempotentReplay": replay,
+        "contextControlSnapshotResourceId": resource.resource_id,
+        "contextControlSnapshotVersionId": version.version_id,
+        "projection": snapshot_projection(&resource, &version, &record)
+    });
+    attach_snapshot_diff(deps, &scope, payload, &record, &mut response).await?;
+    Ok(response)
+}
+
+pub(super) async fn record_snapshot(
+    deps: &Deps,
+    invocation: &Invocation,
+    session_id: &str,
+    scope: &EngineResourceScope,
+    snapshot_id: &str,
+    operation_at: DateTime<Utc>,
+) -> Result<(EngineResource, EngineResourceVersion, Value, bool), CapabilityError> {
+    let resource_id = snapshot_resource_id(session_id, snapshot_id);
+    if let Some(existing) = deps
+        .engine_host
+        .inspect_resource(&resource_id)
+        .await
+        .map_err(engine_error)?
+    {
+        ensure_context_snapshot(&existing, "context_control_snapshot replay")?;
+        ensure_scope(&existing, scope, "context_control_snapshot replay")?;
+        let (version, payload) = current_payload(&existing, "context_control_snapshot replay")?;
+        return Ok((
+            existing.resource.clone(),
+            version.clone(),
+            payload.clone(),
+            true,
+        ));
+    }
+    let record = build_snapshot_record(deps, session_id, scope, snapshot_id, operation_at).await?;
+    let resource = deps
+        .engine_host
+        .create_resource(CreateResource {
+            resource_id: Some(resource_id),
+            kind: CONTEXT_CONTROL_SNAPSHOT_KIND.to_owned(),
+            schema_id: Some(CONTEXT_CONTROL_SNAPSHOT_SCHEMA_ID.to_owned()),
+            scope: scope.clone(),
+            owner_worker_id: crate::engine::WorkerId::new(WORKER).map_err(id_error)?,
+            owner_actor_id: invocation.causal_context.actor_id.clone(),
+            lifecycle: Some("available".to_owned()),
+            policy: resource_policy(CONTEXT_CONTROL_SNAPSHOT_KIND),
+            initial_payload: Some(record),
+            locations: vec![EngineResourceLocation {
+                kind: "context_control_snapshot".to_owned(),
+                uri: format!("context-control-snapshot:{snapshot_id}"),
+                mime_type: Some("application/json".to_owned()),
+                size_bytes: None,
+            }],
+            trace_id: invocation.causal_context.trace_id.clone(),
+            invocation_id: Some(invocation.id.clone()),
+        })
+        .await
+        .map_err(engine_error)?;
+    publish_lifecycle_event(
+        deps,
+        invocation,
+        "context_control.snapshot_recorded",
+        &resource,
+        json!({"metadataOnly": true, "networkPolicy": "none"}),
+    )
+    .await?;
+    let inspection =
+        inspect_resource_required(deps, &resource.resource_id, "context control snapshot").await?;
+    let (version, payload) = current_payload(&inspection, "context_control_snapshot created")?;
+    Ok((resource, version.clone(), payload.clone(), false))
+}
+
 pub(super) async fn build_snapshot_record(
     deps: &Deps,
     session_id: &str,
@@ -0,0 +1,122 @@
+//! Structural diff between two context-control snapshots of one session.
+//!
+//! With `sinceContextControlSnapshotResourceId`, `context_control_snapshot`
+//! also reports which parts of the provider-safe composition changed since
+//! that earlier snapshot, so live views can apply a delta instead of
+//! re-rendering the whole projection; the response then carries the `diff` in
+//! place of the full `projection`. Parts are session fields, prompt blocks and
+//! resource refs keyed by `kind`, execution refs keyed by event id, and the
+//! memory block as a whole. The new snapshot's resource id is the cursor for
+//! the next diff.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+
+use crate::engine::EngineResourceScope;
+use crate::shared::server::errors::CapabilityError;
+
+use super::Deps;
+use super::resource_store::{
+    current_payload, ensure_context_snapshot, ensure_scope, inspect_resource_required,
+};
+use super::validation::optional_str;
+
+const SINCE_FIELD: &str = "sinceContextControlSnapshotResourceId";
+
+/// Replace the `projection` in `response` with a `diff` against the requested
+/// earlier snapshot.
+pub(super) async fn attach_snapshot_diff(
+    deps: &Deps,
+    scope: &EngineResourceScope,
+    payload: &Value,
+    record: &Value,
+    response: &mut Value,
+) -> Result<(), CapabilityError> {
+    let Some(since_id) = optional_str(payload, SINCE_FIELD)? else {
+        return Ok(());
+    };
+    let operation = "context_control_snapshot diff";
+    let since = inspect_resource_required(deps, since_id, "context control snapshot").await?;
+    ensure_context_snapshot(&since, operation)?;
+    ensure_scope(&since, scope, operation)?;
+    let (since_version, since_record) = current_payload(&since, operation)?;
+    let mut diff = snapshot_diff(since_record, record);
+    diff["since"] = json!({
+        "contextControlSnapshotResourceId": since_id,
+        "contextControlSnapshotVersionId": since_version.version_id,
+        "snapshotId": since_record["snapshotId"]
+    });
+    if let Some(response) = response.as_object_mut() {
+        response.remove("projection");
+        response.insert("diff".to_owned(), diff);
+    }
+    Ok(())
+}
+
+/// Added, removed, and changed composition parts from `before` to `after`.
+fn snapshot_diff(before: &Value, after: &Value) -> Value {
+    let before = snapshot_parts(before);
+    let mut after = snapshot_parts(after);
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    let mut unchanged = 0_usize;
+    for (part, old) in before {
+        match after.remove(&part) {
+            None => removed.push(json!({"part": part, "value": old})),
+            Some(new) if new == old => unchanged += 1,
+            Some(new) => changed.push(json!({"part": part, "before": old, "after": new})),
+        }
+    }
+    let added = after
+        .into_iter()
+        .map(|(part, value)| json!({"part": part, "value": value}))
+        .collect::<Vec<_>>();
+    json!({
+        "added": added,
+        "removed": removed,
+        "changed": changed,
+        "unchangedParts": unchanged
+    })
+}
+
+fn snapshot_parts(record: &Value) -> BTreeMap<String, Value> {
+    let mut parts = BTreeMap::new();
+    if let Some(session) = record["session"].as_object() {
+        for (field, value) in session {
+            parts.insert(format!("session.{field}"), value.clone());
+        }
+    }
+    let composition = &record["composition"];
+    insert_keyed(
+        &mut parts,
+        "promptBlocks",
+        &composition["promptBlocks"],
+        "kind",
+    );
+    insert_keyed(
+        &mut parts,
+        "resourceRefs",
+        &composition["resourceRefs"],
+        "kind",
+    );
+    insert_keyed(
+        &mut parts,
+        "executionRefs",
+        &composition["executionRefs"],
+        "eventId",
+    );
+    if !record["memory"].is_null() {
+        parts.insert("memory".to_owned(), record["memory"].clone());
+    }
+    parts
+}
+
+fn insert_keyed(parts: &mut BTreeMap<String, Value>, group: &str, items: &Value, key: &str) {
+    for (index, item) in items.as_array().into_iter().flatten().enumerate() {
+        let id = item[key]
+            .as_str()
+            .map_or_else(|| index.to_string(), ToOwned::to_owned);
+        parts.insert(format!("{group}.{id}"), item.clone());
+    }
+}
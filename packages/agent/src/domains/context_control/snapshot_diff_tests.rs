@@ -0,0 +1,104 @@
+use serde_json::{Value, json};
+
+use super::service::snapshot_value_at;
+use super::tests::{Fixture, operation_at};
+use crate::domains::session::event_store::{AppendOptions, EventType};
+
+async fn snapshot(fixture: &Fixture, key: &str, since: Option<&Value>) -> Value {
+    let mut payload = json!({
+        "operation": "context_control_snapshot",
+        "sessionId": fixture.session_id,
+        "idempotencyKey": key
+    });
+    if let Some(since) = since {
+        payload["sinceContextControlSnapshotResourceId"] = since.clone();
+    }
+    let invocation = fixture.write_invocation(key, "context_control_snapshot", payload.clone());
+    snapshot_value_at(&fixture.deps, &invocation, &payload, operation_at())
+        .await
+        .expect("snapshot")
+}
+
+fn part_names(entries: &Value) -> Vec<&str> {
+    entries
+        .as_array()
+        .expect("diff entries")
+        .iter()
+        .map(|entry| entry["part"].as_str().expect("part name"))
+        .collect()
+}
+
+#[tokio::test]
+async fn snapshot_diff_reports_only_parts_changed_since_the_earlier_snapshot() {
+    let fixture = Fixture::new("context-control-snapshot-diff").await;
+    let first = snapshot(&fixture, "diff-1", None).await;
+    assert!(first.get("diff").is_none());
+    assert!(first["projection"].is_object());
+
+    fixture
+        .deps
+        .event_store
+        .append(&AppendOptions {
+            session_id: &fixture.session_id,
+            event_type: EventType::MessageUser,
+            payload: json!({"content": "A follow-up that grows the reconstructed history."}),
+            parent_id: None,
+            sequence: None,
+        })
+        .expect("append follow-up message");
+
+    let since = &first["contextControlSnapshotResourceId"];
+    let second = snapshot(&fixture, "diff-2", Some(since)).await;
+    assert_ne!(second["contextControlSnapshotResourceId"], *since);
+    assert!(second.get("projection").is_none());
+    let diff = &second["diff"];
+    assert_eq!(diff["since"]["contextControlSnapshotResourceId"], *since);
+    assert_eq!(
+        diff["since"]["contextControlSnapshotVersionId"],
+        first["contextControlSnapshotVersionId"]
+    );
+
+    let changed = part_names(&diff["changed"]);
+    for expected in [
+        "session.estimatedTokens",
+        "promptBlocks.session_history",
+        "resourceRefs.context_control_snapshot",
+    ] {
+        assert!(changed.contains(&expected), "{expected} in {changed:?}");
+    }
+    for stable in ["session.model", "session.contextWindowTokens", "memory"] {
+        assert!(!changed.contains(&stable), "{stable} in {changed:?}");
+    }
+    let estimated = diff["changed"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["part"] == "session.estimatedTokens")
+        .unwrap();
+    assert!(estimated["after"].as_u64() > estimated["before"].as_u64());
+    assert!(part_names(&diff["added"]).is_empty());
+    assert!(part_names(&diff["removed"]).is_empty());
+    assert!(diff["unchangedParts"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn snapshot_diff_rejects_unknown_since_snapshot() {
+    let fixture = Fixture::new("context-control-snapshot-diff-missing").await;
+    let payload = json!({
+        "operation": "context_control_snapshot",
+        "sessionId": fixture.session_id,
+        "idempotencyKey": "diff-missing",
+        "sinceContextControlSnapshotResourceId": "context_control_snapshot:missing"
+    });
+    let invocation =
+        fixture.write_invocation("diff-missing", "context_control_snapshot", payload.clone());
+    let error = snapshot_value_at(&fixture.deps, &invocation, &payload, operation_at())
+        .await
+        .expect_err("missing since snapshot");
+    assert!(
+        error
+            .to_string()
+            .contains("missing context control snapshot"),
+        "{error}"
+    );
+}
@@ -23,15 +23,15 @@ use crate::shared::server::test_support::make_test_context;
 
 const DEFAULT_OPERATION_AT: &str = "2026-06-30T12:00:00Z";
 
-struct Fixture {
-    deps: Deps,
-    session_id: String,
+pub(super) struct Fixture {
+    pub(super) deps: Deps,
+    pub(super) session_id: String,
     write_grant_id: AuthorityGrantId,
     read_grant_id: AuthorityGrantId,
 }
 
 impl Fixture {
-    async fn new(label: &str) -> Self {
+    pub(super) async fn new(label: &str) -> Self {
         let ctx = make_test_context();
         let deps = Deps {
             engine_host: ctx.engine_host.clone(),
@@ -100,7 +100,12 @@ impl Fixture {
         }
     }
 
-    fn write_invocation(&self, key: &str, operation: &str, payload: Value) -> Invocation {
+    pub(super) fn write_invocation(
+        &self,
+        key: &str,
+        operation: &str,
+        payload: Value,
+    ) -> Invocation {
         invocation(
             key,
             operation,
@@ -584,7 +589,7 @@ fn client_invocation(key: &str, function_id: &str, payload: Value, session_id: &
     }
 }
 
-fn operation_at() -> DateTime<Utc> {
+pub(super) fn operation_at() -> DateTime<Utc> {
     DateTime::parse_from_rfc3339(DEFAULT_OPERATION_AT)
         .unwrap()
         .with_timezone(&Utc)
@@ -1,7 +1,7 @@
 //! Shared helpers for the filesystem agent toolbox.
 
 use std::fs;
-use std::io::Read;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Component, Path, PathBuf};
 
 use serde_json::{Value, json};
@@ -20,6 +20,7 @@ pub(super) const MATERIALIZED_FILE_KIND: &str = "materialized_file";
 pub(super) const MATERIALIZED_FILE_SCHEMA_ID: &str = "tron.resource.materialized_file.v1";
 pub(super) const DEFAULT_READ_BYTES: usize = 64 * 1024;
 pub(super) const MAX_READ_BYTES: usize = 256 * 1024;
+pub(super) const MAX_READ_LINES: usize = 500;
 pub(super) const MAX_WRITE_BYTES: usize = 512 * 1024;
 pub(super) const DEFAULT_DIFF_BYTES: usize = 64 * 1024;
 pub(super) const MAX_DIFF_BYTES: usize = 128 * 1024;
@@ -45,6 +46,16 @@ pub(super) struct FileSnapshot {
     pub(super) truncated: bool,
 }
 
+/// A 1-based line window read from a text file.
+pub(super) struct LineWindow {
+    pub(super) is_binary: bool,
+    pub(super) size_bytes: u64,
+    pub(super) text: Option<String>,
+    pub(super) returned_lines: usize,
+    pub(super) total_lines: usize,
+    pub(super) truncated: bool,
+}
+
 #[derive(Clone)]
 pub(super) struct MutationPlan {
     pub(super) path: ResolvedPath,
@@ -188,6 +199,81 @@ pub(super) fn read_snapshot(
     })
 }
 
+/// Read lines `offset..offset + limit` while counting every line in the file.
+///
+/// The window stops early at `max_bytes`; a first line longer than the cap is
+/// cut at a char boundary so the caller always makes progress.
+pub(super) fn read_line_window(
+    path: &Path,
+    offset: usize,
+    limit: usize,
+    max_bytes: usize,
+) -> Result<LineWindow, CapabilityError> {
+    let metadata = fs::symlink_metadata(path).map_err(|error| map_io_error(error, path))?;
+    if !metadata.is_file() {
+        return Err(invalid(format!("path is not a file: {}", path.display())));
+    }
+    let file = fs::File::open(path).map_err(|error| map_io_error(error, path))?;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+    let mut text = String::new();
+    let mut total_lines = 0;
+    let mut returned_lines = 0;
+    let mut truncated = false;
+    loop {
+        line.clear();
+        if reader
+            .read_until(b'\n', &mut line)
+            .map_err(|error| map_io_error(error, path))?
+            == 0
+        {
+            break;
+        }
+        total_lines += 1;
+        if total_lines < offset || returned_lines >= limit || truncated {
+            continue;
+        }
+        let decoded = match std::str::from_utf8(&line) {
+            Ok(decoded) if !decoded.contains('\0') => decoded,
+            _ => return Ok(binary_window(metadata.len(), total_lines, &mut reader)),
+        };
+        if text.len() + decoded.len() > max_bytes {
+            truncated = true;
+            if returned_lines > 0 {
+                continue;
+            }
+            text.push_str(&truncate_chars(decoded, max_bytes));
+        } else {
+            text.push_str(decoded);
+        }
+        returned_lines += 1;
+    }
+    Ok(LineWindow {
+        is_binary: false,
+        size_bytes: metadata.len(),
+        text: Some(text),
+        returned_lines,
+        total_lines,
+        truncated,
+    })
+}
+
+fn binary_window(
+    size_bytes: u64,
+    lines_so_far: usize,
+    reader: &mut BufReader<fs::File>,
+) -> LineWindow {
+    let remaining = reader.split(b'\n').count();
+    LineWindow {
+        is_binary: true,
+        size_bytes,
+        text: None,
+        returned_lines: 0,
+        total_lines: lines_so_far + remaining,
+        truncated: false,
+    }
+}
+
 pub(super) fn snapshot_value(snapshot: &FileSnapshot, include_content: bool) -> Value {
     json!({
         "exists": snapshot.exists,
@@ -19,6 +19,8 @@ use crate::shared::server::error_mapping::engine_error_to_capability_error;
 use crate::shared::server::errors::CapabilityError;
 
 use super::agent_support::*;
+use super::gitignore::GitignoreMatcher;
+use super::symbols::{self, SymbolKind};
 use super::{FILESYSTEM_LIFECYCLE_TOPIC, WORKER, WRITE_SCOPE};
 
 pub(crate) async fn read_value(
@@ -32,6 +34,34 @@ pub(crate) async fn read_value(
         let max_bytes = optional_usize(&request, "maxBytes")?
             .unwrap_or(DEFAULT_READ_BYTES)
             .min(MAX_READ_BYTES);
+        let offset = optional_usize(&request, "offset")?;
+        let limit = optional_usize(&request, "limit")?;
+        if offset.is_some() || limit.is_some() {
+            let offset = offset.unwrap_or(1);
+            let limit = limit.unwrap_or(MAX_READ_LINES).min(MAX_READ_LINES);
+            let window = read_line_window(&path.canonical, offset, limit, max_bytes)?;
+            return Ok(json!({
+                "schemaVersion": SCHEMA_VERSION,
+                "status": "ok",
+                "operation": "read",
+                "path": path_value(&path),
+                "file": {
+                    "exists": true,
+                    "isBinary": window.is_binary,
+                    "sizeBytes": window.size_bytes,
+                    "contentHash": Value::Null,
+                    "truncated": window.truncated,
+                    "content": window.text,
+                },
+                "range": {
+                    "offset": offset,
+                    "limit": limit,
+                    "returnedLines": window.returned_lines,
+                    "totalLines": window.total_lines,
+                },
+                "notice": window.truncated.then(|| truncation_notice(max_bytes)),
+            }));
+        }
         let snapshot = read_snapshot(&path.canonical, max_bytes)?;
         if !snapshot.exists {
             return Err(not_found(&path.canonical));
@@ -42,11 +72,16 @@ pub(crate) async fn read_value(
             "operation": "read",
             "path": path_value(&path),
             "file": snapshot_value(&snapshot, true),
+            "notice": snapshot.truncated.then(|| truncation_notice(max_bytes)),
         }))
     })
     .await
 }
 
+fn truncation_notice(max_bytes: usize) -> String {
+    format!("content truncated at {max_bytes} bytes; page with offset and limit to read the rest")
+}
+
 pub(crate) async fn list_value(
     invocation: &Invocation,
     payload: &Value,
@@ -124,12 +159,23 @@ pub(crate) async fn find_value(
             return Err(invalid("query or glob is required"));
         }
         let show_hidden = optional_bool(&request, "showHidden")?.unwrap_or(false);
+        let respect_gitignore = optional_bool(&request, "respectGitignore")?.unwrap_or(true);
         let max_results = optional_usize(&request, "maxResults")?
             .unwrap_or(DEFAULT_RESULTS)
             .min(MAX_RESULTS);
         let mut visited = 0usize;
         let mut matches = Vec::new();
-        for entry in WalkDir::new(&base.canonical).follow_links(false) {
+        let mut gitignore = respect_gitignore.then(|| GitignoreMatcher::new(&base.canonical));
+        let walker = WalkDir::new(&base.canonical)
+            .follow_links(false)
+            .into_iter()
+            .filter_entry(|entry| {
+                entry.depth() == 0
+                    || gitignore.as_mut().is_none_or(|matcher| {
+                        !matcher.is_ignored(entry.path(), entry.file_type().is_dir())
+                    })
+            });
+        for entry in walker {
             let entry = match entry {
                 Ok(entry) => entry,
                 Err(_) => continue,
@@ -154,6 +200,7 @@ pub(crate) async fn find_value(
             "status": "ok",
             "operation": if glob_only { "glob" } else { "find" },
             "path": path_value(&base),
+            "respectGitignore": respect_gitignore,
             "matches": matches,
             "truncated": visited > MAX_WALK_ENTRIES,
             "limit": max_results
@@ -181,9 +228,13 @@ pub(crate) async fn search_text_value(
         let max_file_bytes = optional_usize(&request, "maxFileBytes")?
             .unwrap_or(DEFAULT_READ_BYTES)
             .min(MAX_READ_BYTES);
+        let symbol_kind = optional_str(&request, "symbolKind")?
+            .map(SymbolKind::parse)
+            .transpose()?;
         let mut visited = 0usize;
         let mut results = Vec::new();
         let mut skipped_binary = 0usize;
+        let mut text_fallback_files = 0usize;
         for entry in WalkDir::new(&base.canonical).follow_links(false) {
             let entry = match entry {
                 Ok(entry) => entry,
@@ -211,21 +262,33 @@ pub(crate) async fn search_text_value(
             let Some(text) = snapshot.text.as_deref() else {
                 continue;
             };
+            let file_kind = symbol_kind.filter(|_| symbols::supports_path(entry.path()));
+            if symbol_kind.is_some() && file_kind.is_none() {
+                text_fallback_files += 1;
+            }
             for (index, line) in text.lines().enumerate() {
-                if line.to_lowercase().contains(&query_lower) {
-                    results.push(json!({
-                        "relativePath": rel,
-                        "lineNumber": index + 1,
-                        "preview": truncate_chars(line, MAX_LINE_PREVIEW),
-                        "contentHash": snapshot.content_hash,
-                    }));
-                    if results.len() >= max_results {
-                        break;
-                    }
+                let mut result = json!({
+                    "relativePath": rel,
+                    "lineNumber": index + 1,
+                    "preview": truncate_chars(line, MAX_LINE_PREVIEW),
+                    "contentHash": snapshot.content_hash,
+                });
+                if let Some(kind) = file_kind {
+                    let Some(symbol) = symbols::definition_match(line, kind, &query_lower) else {
+                        continue;
+                    };
+                    result["symbolKind"] = json!(kind.as_str());
+                    result["symbol"] = json!(symbol);
+                } else if !line.to_lowercase().contains(&query_lower) {
+                    continue;
+                }
+                results.push(result);
+                if results.len() >= max_results {
+                    break;
                 }
             }
         }
-        Ok(json!({
+        let mut value = json!({
             "schemaVersion": SCHEMA_VERSION,
             "status": "ok",
             "operation": "search_text",
@@ -235,7 +298,18 @@ pub(crate) async fn search_text_value(
             "skippedBinaryFiles": skipped_binary,
             "truncated": visited > MAX_WALK_ENTRIES,
             "limit": max_results
-        }))
+        });
+        if let Some(kind) = symbol_kind {
+            value["symbolKind"] = json!(kind.as_str());
+            value["textFallbackFiles"] = json!(text_fallback_files);
+            if text_fallback_files > 0 {
+                value["notice"] = json!(format!(
+                    "symbolKind filtering supports Rust sources only; {text_fallback_files} other \
+                     file(s) were searched as plain text."
+                ));
+            }
+        }
+        Ok(value)
     })
     .await
 }
@@ -272,13 +346,23 @@ pub(crate) async fn edit_value(
     invocation: &Invocation,
     payload: &Value,
 ) -> Result<Value, CapabilityError> {
+    let dry_run = optional_bool(payload, "dryRun")?.unwrap_or(false);
+    if dry_run && optional_bool(payload, "commit")?.unwrap_or(false) {
+        return Err(invalid("dryRun cannot be combined with commit=true"));
+    }
     let request = payload.clone();
     let invocation_clone = invocation.clone();
     let mut plan = run_blocking_task("filesystem::edit", move || {
-        exact_replace_plan(&invocation_clone, &request, true)
+        exact_replace_plan(&invocation_clone, &request, !dry_run)
     })
     .await?;
-    persist_plan(engine_host, invocation, &mut plan, "apply_patch").await
+    let mut value = persist_plan(engine_host, invocation, &mut plan, "apply_patch").await?;
+    if dry_run {
+        // The patch proposal is still recorded as resource-backed evidence;
+        // only the file write is ruled out up front.
+        value["status"] = json!("dry_run");
+    }
+    Ok(value)
 }
 
 fn full_write_plan(
@@ -9,6 +9,7 @@ use crate::engine::{
     IdempotencyContract, ResourceLeaseRequirement, Result as EngineResult, RiskLevel,
 };
 
+use super::symbols;
 use super::{FILESYSTEM_LIFECYCLE_TOPIC, READ_SCOPE, WORKER, WRITE_SCOPE};
 
 pub(super) const GET_HOME_FUNCTION: &str = "filesystem::get_home";
@@ -268,7 +269,9 @@ fn read_schema() -> serde_json::Value {
         "additionalProperties": false,
         "properties": {
             "path": {"type": "string"},
-            "maxBytes": {"type": "integer", "minimum": 1, "maximum": 262144}
+            "maxBytes": {"type": "integer", "minimum": 1, "maximum": 262144},
+            "offset": {"type": "integer", "minimum": 1},
+            "limit": {"type": "integer", "minimum": 1, "maximum": 500}
         }
     })
 }
@@ -299,6 +302,7 @@ fn find_schema(glob_required: bool) -> serde_json::Value {
             "query": {"type": "string"},
             "glob": {"type": "string"},
             "showHidden": {"type": "boolean"},
+            "respectGitignore": {"type": "boolean"},
             "maxResults": {"type": "integer", "minimum": 1, "maximum": 1000}
         }
     })
@@ -314,6 +318,7 @@ fn search_schema() -> serde_json::Value {
             "query": {"type": "string"},
             "glob": {"type": "string"},
             "showHidden": {"type": "boolean"},
+            "symbolKind": {"type": "string", "enum": symbols::SYMBOL_KINDS},
             "maxResults": {"type": "integer", "minimum": 1, "maximum": 1000},
             "maxFileBytes": {"type": "integer", "minimum": 1, "maximum": 262144}
         }
@@ -361,6 +366,7 @@ fn edit_schema() -> serde_json::Value {
             "newText": {"type": "string"},
             "expectedHash": {"type": "string"},
             "commit": {"type": "boolean"},
+            "dryRun": {"type": "boolean"},
             "reason": {"type": "string"},
             "maxDiffBytes": {"type": "integer", "minimum": 1, "maximum": 131072}
         }
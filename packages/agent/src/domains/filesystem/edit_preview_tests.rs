@@ -0,0 +1,101 @@
+use std::fs;
+
+use serde_json::json;
+use tempfile::tempdir;
+
+use crate::shared::server::test_support::make_test_context;
+
+use super::contract;
+use super::tests::{client_context, invoke_error, invoke_ok};
+
+#[tokio::test]
+async fn edit_dry_run_returns_diff_without_touching_the_file() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    let target = root.path().join("note.txt");
+    fs::write(&target, "alpha\nbeta\n").expect("initial");
+
+    let preview = invoke_ok(
+        &ctx,
+        contract::EDIT_FUNCTION,
+        json!({
+            "path": "note.txt",
+            "oldText": "beta",
+            "newText": "gamma",
+            "dryRun": true
+        }),
+        client_context(root.path(), "edit-dry-run", true),
+    )
+    .await;
+    assert_eq!(preview["status"], "dry_run");
+    assert_eq!(preview["commit"], false);
+    let diff = preview["diff"].as_str().unwrap();
+    assert!(diff.contains("-beta"), "{diff}");
+    assert!(diff.contains("+gamma"), "{diff}");
+    assert_eq!(preview["resourceRefs"][0]["kind"], "patch_proposal");
+    assert_eq!(fs::read_to_string(&target).unwrap(), "alpha\nbeta\n");
+}
+
+#[tokio::test]
+async fn edit_dry_run_rejects_missing_old_text() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("note.txt"), "alpha\n").expect("initial");
+
+    let error = invoke_error(
+        &ctx,
+        contract::EDIT_FUNCTION,
+        json!({
+            "path": "note.txt",
+            "oldText": "omega",
+            "newText": "gamma",
+            "dryRun": true
+        }),
+        client_context(root.path(), "edit-dry-run-missing", true),
+    )
+    .await;
+    assert!(error.contains("found 0 matches"), "{error}");
+}
+
+#[tokio::test]
+async fn edit_dry_run_rejects_ambiguous_old_text() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("note.txt"), "beta\nbeta\n").expect("initial");
+
+    let error = invoke_error(
+        &ctx,
+        contract::EDIT_FUNCTION,
+        json!({
+            "path": "note.txt",
+            "oldText": "beta",
+            "newText": "gamma",
+            "dryRun": true
+        }),
+        client_context(root.path(), "edit-dry-run-ambiguous", true),
+    )
+    .await;
+    assert!(error.contains("found 2 matches"), "{error}");
+}
+
+#[tokio::test]
+async fn edit_dry_run_cannot_commit() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("note.txt"), "alpha\n").expect("initial");
+
+    let error = invoke_error(
+        &ctx,
+        contract::EDIT_FUNCTION,
+        json!({
+            "path": "note.txt",
+            "oldText": "alpha",
+            "newText": "gamma",
+            "dryRun": true,
+            "commit": true
+        }),
+        client_context(root.path(), "edit-dry-run-commit", true),
+    )
+    .await;
+    assert!(error.contains("dryRun cannot be combined"), "{error}");
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Value, json};
+use tempfile::tempdir;
+
+use crate::shared::server::test_support::make_test_context;
+
+use super::contract;
+use super::tests::{client_context, invoke_ok};
+
+fn write(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).expect("parent");
+    fs::write(path, content).expect("write");
+}
+
+fn ignored_tree(root: &Path) {
+    fs::create_dir(root.join(".git")).expect("git dir");
+    write(
+        root,
+        ".gitignore",
+        "target/\nnode_modules\n*.log\n!keep.log\n",
+    );
+    write(root, "src/main.rs", "fn main() {}\n");
+    write(root, "target/debug/app", "bin");
+    write(root, "node_modules/pkg/index.js", "js");
+    write(root, "debug.log", "log");
+    write(root, "keep.log", "log");
+    write(root, ".env", "SECRET=1");
+    write(root, "sub/.gitignore", "/generated/\n");
+    write(root, "sub/lib.rs", "pub fn lib() {}\n");
+    write(root, "sub/generated/out.rs", "// generated\n");
+}
+
+fn relative_paths(value: &Value) -> Vec<String> {
+    let mut paths = value["matches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["relativePath"].as_str().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+#[tokio::test]
+async fn find_skips_gitignored_paths_and_nested_rules() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    ignored_tree(root.path());
+
+    let value = invoke_ok(
+        &ctx,
+        contract::GLOB_FUNCTION,
+        json!({"path": ".", "glob": "*"}),
+        client_context(root.path(), "find-gitignore", false),
+    )
+    .await;
+    assert_eq!(value["respectGitignore"], true);
+    assert_eq!(
+        relative_paths(&value),
+        vec!["keep.log", "src", "src/main.rs", "sub", "sub/lib.rs"]
+    );
+}
+
+#[tokio::test]
+async fn find_can_include_ignored_and_hidden_paths() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    ignored_tree(root.path());
+
+    let value = invoke_ok(
+        &ctx,
+        contract::FIND_FUNCTION,
+        json!({
+            "path": ".",
+            "query": "generated",
+            "respectGitignore": false,
+            "showHidden": true
+        }),
+        client_context(root.path(), "find-no-gitignore", false),
+    )
+    .await;
+    assert_eq!(
+        relative_paths(&value),
+        vec!["sub/generated", "sub/generated/out.rs"]
+    );
+
+    let hidden = invoke_ok(
+        &ctx,
+        contract::FIND_FUNCTION,
+        json!({"path": ".", "query": "env", "respectGitignore": false}),
+        client_context(root.path(), "find-no-hidden", false),
+    )
+    .await;
+    assert!(relative_paths(&hidden).is_empty());
+}
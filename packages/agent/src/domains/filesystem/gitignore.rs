@@ -0,0 +1,145 @@
+//! `.gitignore` matching for filesystem toolbox walks.
+//!
+//! Rules are loaded lazily per directory, from the enclosing Git repository
+//! root (when there is one) down to the walked path, and applied shallow to
+//! deep so nested files override their parents and the last matching rule
+//! wins. Supported syntax: comments, `!` negation, trailing `/` for
+//! directory-only rules, leading or inner `/` anchoring, `*`, `?`, and `**`
+//! segments. Ignored directories are pruned, so (as in Git) files beneath them
+//! cannot be re-included.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::agent_support::wildcard_match;
+
+const GITIGNORE_FILE: &str = ".gitignore";
+const MAX_GITIGNORE_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug)]
+struct IgnoreRule {
+    segments: Vec<String>,
+    negated: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl IgnoreRule {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let segments = line
+            .trim_start_matches('/')
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        (!segments.is_empty()).then_some(Self {
+            segments,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, relative: &[&str], is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            return segments_match(&self.segments, relative);
+        }
+        relative
+            .last()
+            .is_some_and(|name| wildcard_match(&self.segments[0], name))
+    }
+}
+
+fn segments_match(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| segments_match(rest, &path[skip..]))
+        }
+        Some((first, rest)) => path
+            .split_first()
+            .is_some_and(|(name, tail)| wildcard_match(first, name) && segments_match(rest, tail)),
+    }
+}
+
+/// Lazily loaded `.gitignore` rules for one walk.
+#[derive(Debug)]
+pub(super) struct GitignoreMatcher {
+    top: PathBuf,
+    rules: HashMap<PathBuf, Vec<IgnoreRule>>,
+}
+
+impl GitignoreMatcher {
+    /// Start rule lookup at the Git repository enclosing `base`, or at `base`.
+    pub(super) fn new(base: &Path) -> Self {
+        let top = base
+            .ancestors()
+            .find(|dir| dir.join(".git").exists())
+            .unwrap_or(base)
+            .to_path_buf();
+        Self {
+            top,
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Whether `path` is ignored by the rules of its ancestor directories.
+    pub(super) fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.top) else {
+            return false;
+        };
+        let components = relative
+            .iter()
+            .filter_map(|part| part.to_str())
+            .collect::<Vec<_>>();
+        if components.first() == Some(&".git") {
+            return true;
+        }
+        let mut ignored = false;
+        let mut dir = self.top.clone();
+        for depth in 0..components.len() {
+            let rules = self.rules_for(&dir);
+            for rule in rules {
+                if rule.matches(&components[depth..], is_dir) {
+                    ignored = !rule.negated;
+                }
+            }
+            dir.push(components[depth]);
+        }
+        ignored
+    }
+
+    fn rules_for(&mut self, dir: &Path) -> &[IgnoreRule] {
+        self.rules
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| load_rules(&dir.join(GITIGNORE_FILE)))
+    }
+}
+
+fn load_rules(path: &Path) -> Vec<IgnoreRule> {
+    let readable = fs::symlink_metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.len() <= MAX_GITIGNORE_BYTES);
+    if !readable {
+        return Vec::new();
+    }
+    fs::read_to_string(path)
+        .map(|text| text.lines().filter_map(IgnoreRule::parse).collect())
+        .unwrap_or_default()
+}
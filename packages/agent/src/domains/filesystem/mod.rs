@@ -6,7 +6,12 @@
 //!   directory browsing, hidden entry visibility, and folder creation;
 //! - the Phase 2 filesystem agent toolbox: bounded read/list/find/glob/search,
 //!   diff, write preview/commit, and exact-text patch application under the
-//!   trusted working-directory root.
+//!   trusted working-directory root. Reads accept a 1-based `offset`/`limit`
+//!   line window that reports the file's total line count, and any read cut
+//!   at `maxBytes` carries a `notice` telling the model how to page. Edits
+//!   accept `dryRun`, which returns the proposal diff and refuses `commit`.
+//!   Find and glob skip `.gitignore`d paths unless `respectGitignore` is false.
+//!   Text search takes `symbolKind` to return only Rust definitions.
 //!
 //! The toolbox is not a retired-surface resurrection. It consumes existing engine
 //! primitives for authority roots, resources, idempotency, leases,
@@ -21,8 +26,13 @@
 //! |--------|---------|
 //! | `agent_tools` | Agent filesystem toolbox with path authority and evidence |
 //! | `contract` | Narrow `filesystem::*` workspace-browser contracts |
+//! | `edit_preview_tests` | Test-only `dryRun` edit diff coverage |
+//! | `find_gitignore_tests` | Test-only gitignore-aware find/glob coverage |
+//! | `gitignore` | Nested `.gitignore` rule matching for find/glob walks |
 //! | `handlers` | Operation-key binding table |
 //! | `service` | Hardened local filesystem reads/writes for selector UX |
+//! | `symbol_search_tests` | Test-only `symbolKind` search coverage |
+//! | `symbols` | Line-level Rust symbol-definition matching for search |
 //!
 //! # INVARIANT: picker and toolbox stay separated
 //!
@@ -40,8 +50,10 @@ use crate::shared::foundation::paths;
 mod agent_support;
 pub(crate) mod agent_tools;
 pub(crate) mod contract;
+mod gitignore;
 mod handlers;
 mod service;
+mod symbols;
 
 pub(crate) const WORKER: &str = "filesystem";
 pub(crate) const FILESYSTEM_LIFECYCLE_TOPIC: &str = "filesystem.lifecycle";
@@ -83,5 +95,11 @@ pub(crate) fn worker_module(
     )
 }
 
+#[cfg(test)]
+mod edit_preview_tests;
+#[cfg(test)]
+mod find_gitignore_tests;
+#[cfg(test)]
+mod symbol_search_tests;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,108 @@
+use std::fs;
+
+use serde_json::{Value, json};
+use tempfile::tempdir;
+
+use crate::shared::server::test_support::make_test_context;
+
+use super::contract;
+use super::tests::{client_context, invoke_error, invoke_ok};
+
+const FIXTURE: &str = "\
+pub trait Render {
+    fn render(&self) -> String;
+}
+
+pub(crate) struct Widget;
+
+impl Render for Widget {
+    fn render(&self) -> String {
+        render_label(\"widget\")
+    }
+}
+
+pub(crate) async fn render_label(label: &str) -> String {
+    // render_label is called from Widget::render.
+    label.to_owned()
+}
+";
+
+fn line_numbers(value: &Value) -> Vec<u64> {
+    value["matches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["lineNumber"].as_u64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn search_symbol_kind_returns_definitions_not_call_sites() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("widget.rs"), FIXTURE).expect("fixture");
+
+    let text = invoke_ok(
+        &ctx,
+        contract::SEARCH_TEXT_FUNCTION,
+        json!({"path": ".", "query": "render_label"}),
+        client_context(root.path(), "symbol-text", false),
+    )
+    .await;
+    assert_eq!(line_numbers(&text), vec![9, 13, 14]);
+
+    let function = invoke_ok(
+        &ctx,
+        contract::SEARCH_TEXT_FUNCTION,
+        json!({"path": ".", "query": "render_label", "symbolKind": "function"}),
+        client_context(root.path(), "symbol-function", false),
+    )
+    .await;
+    assert_eq!(line_numbers(&function), vec![13]);
+    assert_eq!(function["matches"][0]["symbol"], "render_label");
+    assert_eq!(function["matches"][0]["symbolKind"], "function");
+    assert_eq!(function["textFallbackFiles"], 0);
+    assert!(function.get("notice").is_none());
+
+    let impls = invoke_ok(
+        &ctx,
+        contract::SEARCH_TEXT_FUNCTION,
+        json!({"path": ".", "query": "Render", "symbolKind": "impl"}),
+        client_context(root.path(), "symbol-impl", false),
+    )
+    .await;
+    assert_eq!(line_numbers(&impls), vec![7]);
+    assert_eq!(impls["matches"][0]["symbol"], "Render for Widget");
+}
+
+#[tokio::test]
+async fn search_symbol_kind_falls_back_to_text_for_other_languages() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(
+        root.path().join("widget.py"),
+        "def render_label(label):\n    return render_label(label)\n",
+    )
+    .expect("fixture");
+
+    let value = invoke_ok(
+        &ctx,
+        contract::SEARCH_TEXT_FUNCTION,
+        json!({"path": ".", "query": "render_label", "symbolKind": "function"}),
+        client_context(root.path(), "symbol-fallback", false),
+    )
+    .await;
+    assert_eq!(line_numbers(&value), vec![1, 2]);
+    assert!(value["matches"][0].get("symbol").is_none());
+    assert_eq!(value["textFallbackFiles"], 1);
+    assert!(value["notice"].as_str().unwrap().contains("plain text"));
+
+    let error = invoke_error(
+        &ctx,
+        contract::SEARCH_TEXT_FUNCTION,
+        json!({"path": ".", "query": "x", "symbolKind": "variable"}),
+        client_context(root.path(), "symbol-invalid", false),
+    )
+    .await;
+    assert!(error.contains("symbolKind"), "{error}");
+}
@@ -0,0 +1,151 @@
+//! Symbol-definition filtering for `filesystem::search_text`.
+//!
+//! With `symbolKind`, search matches only lines that define a symbol of that
+//! kind whose name contains the query, so "function foo" finds `fn foo` and
+//! not its call sites. Definitions are recognised per line from the item
+//! header (visibility and qualifiers such as `pub(crate) async unsafe` are
+//! skipped); this covers rustfmt-shaped Rust sources without a parser
+//! dependency. Files in other languages fall back to plain text matching and
+//! the result says so.
+
+use std::path::Path;
+
+use super::agent_support::invalid;
+use crate::shared::server::errors::CapabilityError;
+
+/// Item kinds accepted by `symbolKind`.
+pub(super) const SYMBOL_KINDS: &[&str] = &[
+    "function", "struct", "enum", "trait", "impl", "type", "const", "module", "macro",
+];
+
+const RUST_QUALIFIERS: &[&str] = &["async", "const", "unsafe", "default", "extern"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum SymbolKind {
+    Function,
+    Struct,
+    Enum,
+    Trait,
+    Impl,
+    Type,
+    Const,
+    Module,
+    Macro,
+}
+
+impl SymbolKind {
+    pub(super) fn parse(raw: &str) -> Result<Self, CapabilityError> {
+        Ok(match raw {
+            "function" => Self::Function,
+            "struct" => Self::Struct,
+            "enum" => Self::Enum,
+            "trait" => Self::Trait,
+            "impl" => Self::Impl,
+            "type" => Self::Type,
+            "const" => Self::Const,
+            "module" => Self::Module,
+            "macro" => Self::Macro,
+            _ => {
+                return Err(invalid(format!(
+                    "symbolKind must be one of: {}",
+                    SYMBOL_KINDS.join(", ")
+                )));
+            }
+        })
+    }
+
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Trait => "trait",
+            Self::Impl => "impl",
+            Self::Type => "type",
+            Self::Const => "const",
+            Self::Module => "module",
+            Self::Macro => "macro",
+        }
+    }
+}
+
+/// Whether symbol filtering understands this file's language.
+pub(super) fn supports_path(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "rs")
+}
+
+/// The defined symbol when `line` is a `kind` definition matching `query_lower`.
+///
+/// For `impl`, the query may name either the trait or the implementing type.
+pub(super) fn definition_match(line: &str, kind: SymbolKind, query_lower: &str) -> Option<String> {
+    let (keyword, rest) = item_header(line)?;
+    let matches_kind = match kind {
+        SymbolKind::Function => keyword == "fn",
+        SymbolKind::Struct => keyword == "struct",
+        SymbolKind::Enum => keyword == "enum",
+        SymbolKind::Trait => keyword == "trait",
+        SymbolKind::Impl => keyword == "impl",
+        SymbolKind::Type => keyword == "type",
+        SymbolKind::Const => keyword == "const" || keyword == "static",
+        SymbolKind::Module => keyword == "mod",
+        SymbolKind::Macro => keyword == "macro_rules!",
+    };
+    if !matches_kind {
+        return None;
+    }
+    if kind == SymbolKind::Impl {
+        let header = rest.split('{').next().unwrap_or(rest).trim();
+        return identifiers(header)
+            .any(|ident| ident.to_lowercase().contains(query_lower))
+            .then(|| header.to_owned());
+    }
+    let rest = rest.strip_prefix("mut ").unwrap_or(rest);
+    let name = identifiers(rest).next()?;
+    name.to_lowercase()
+        .contains(query_lower)
+        .then(|| name.to_owned())
+}
+
+/// Split a Rust item line into its keyword and the text after it.
+fn item_header(line: &str) -> Option<(&str, &str)> {
+    let mut rest = strip_visibility(line.trim_start());
+    loop {
+        let (word, tail) = rest.split_once(|c: char| c.is_whitespace() || c == '<')?;
+        let tail = tail.trim_start();
+        if word == "const" && !tail.starts_with("fn ") && !tail.starts_with("unsafe ") {
+            return Some((word, tail));
+        }
+        if RUST_QUALIFIERS.contains(&word) {
+            rest = skip_abi(tail);
+            continue;
+        }
+        return Some((word, tail));
+    }
+}
+
+fn strip_visibility(line: &str) -> &str {
+    let Some(rest) = line.strip_prefix("pub") else {
+        return line;
+    };
+    let rest = match rest.strip_prefix('(') {
+        Some(scoped) => scoped.split_once(')').map_or(scoped, |(_, tail)| tail),
+        None if rest.starts_with(char::is_whitespace) => rest,
+        None => return line,
+    };
+    rest.trim_start()
+}
+
+fn skip_abi(rest: &str) -> &str {
+    match rest.strip_prefix('"') {
+        Some(abi) => abi
+            .split_once('"')
+            .map_or(abi, |(_, tail)| tail)
+            .trim_start(),
+        None => rest,
+    }
+}
+
+fn identifiers(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| word.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+}
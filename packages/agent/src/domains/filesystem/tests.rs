@@ -227,6 +227,87 @@ async fn agent_read_bounds_binary_preview() {
     assert!(!value.to_string().contains(root.path().to_str().unwrap()));
 }
 
+fn numbered_lines(count: usize) -> String {
+    (1..=count).map(|line| format!("line {line}\n")).collect()
+}
+
+#[tokio::test]
+async fn agent_read_returns_requested_line_window_with_total() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("app.log"), numbered_lines(10)).expect("log");
+
+    let value = invoke_ok(
+        &ctx,
+        contract::READ_FUNCTION,
+        json!({"path": "app.log", "offset": 4, "limit": 3}),
+        client_context(root.path(), "window-read", false),
+    )
+    .await;
+    assert_eq!(value["file"]["content"], "line 4\nline 5\nline 6\n");
+    assert_eq!(value["file"]["truncated"], false);
+    assert_eq!(value["range"]["returnedLines"], 3);
+    assert_eq!(value["range"]["totalLines"], 10);
+    assert!(value["notice"].is_null());
+}
+
+#[tokio::test]
+async fn agent_read_offset_beyond_eof_returns_empty_slice() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("short.txt"), "one\ntwo\nthree").expect("file");
+
+    let value = invoke_ok(
+        &ctx,
+        contract::READ_FUNCTION,
+        json!({"path": "short.txt", "offset": 50}),
+        client_context(root.path(), "window-eof", false),
+    )
+    .await;
+    assert_eq!(value["file"]["content"], "");
+    assert_eq!(value["range"]["returnedLines"], 0);
+    assert_eq!(value["range"]["totalLines"], 3);
+}
+
+#[tokio::test]
+async fn agent_read_byte_cap_truncates_with_notice() {
+    let ctx = make_test_context();
+    let root = tempdir().expect("root");
+    fs::write(root.path().join("big.log"), numbered_lines(100)).expect("log");
+
+    let window = invoke_ok(
+        &ctx,
+        contract::READ_FUNCTION,
+        json!({"path": "big.log", "offset": 1, "limit": 100, "maxBytes": 20}),
+        client_context(root.path(), "window-cap", false),
+    )
+    .await;
+    assert_eq!(window["file"]["content"], "line 1\nline 2\n");
+    assert_eq!(window["file"]["truncated"], true);
+    assert_eq!(window["range"]["totalLines"], 100);
+    assert!(
+        window["notice"]
+            .as_str()
+            .unwrap()
+            .contains("truncated at 20 bytes")
+    );
+
+    let whole = invoke_ok(
+        &ctx,
+        contract::READ_FUNCTION,
+        json!({"path": "big.log", "maxBytes": 20}),
+        client_context(root.path(), "whole-cap", false),
+    )
+    .await;
+    assert_eq!(whole["file"]["truncated"], true);
+    assert!(
+        whole["notice"]
+            .as_str()
+            .unwrap()
+            .contains("offset and limit")
+    );
+}
+
 #[tokio::test]
 async fn agent_search_text_is_bounded_and_skips_binary() {
     let ctx = make_test_context();
@@ -565,7 +646,7 @@ async fn execute_filesystem_apply_patch_refuses_truncated_preview() {
     assert_eq!(fs::read_to_string(&target).unwrap(), large);
 }
 
-async fn invoke_ok(
+pub(super) async fn invoke_ok(
     ctx: &ServerRuntimeContext,
     function_id: &str,
     payload: Value,
@@ -576,7 +657,7 @@ async fn invoke_ok(
     result.value.expect("value")
 }
 
-async fn invoke_error(
+pub(super) async fn invoke_error(
     ctx: &ServerRuntimeContext,
     function_id: &str,
     payload: Value,
@@ -601,7 +682,7 @@ async fn invoke_result(
         .await
 }
 
-fn client_context(root: &Path, key: &str, write: bool) -> CausalContext {
+pub(super) fn client_context(root: &Path, key: &str, write: bool) -> CausalContext {
     let mut context = CausalContext::new(
         ActorId::new("engine-client").unwrap(),
         ActorKind::Client,
@@ -11,6 +11,7 @@ use super::types::{
     BRANCH_INVENTORY_SCHEMA_VERSION, DEFAULT_BRANCH_BYTES, DEFAULT_BRANCH_COUNT, MAX_BRANCH_BYTES,
     MAX_BRANCH_COUNT, RepositoryFacts,
 };
+use super::worktree_inventory::{self, MAX_WORKTREES};
 
 const BRANCH_SCAN_BYTES: usize = 1024 * 1024;
 const BRANCH_METADATA_BYTES: usize = 16 * 1024;
@@ -62,8 +63,16 @@ fn branch_inventory_sync(
         max_branch_bytes,
     )?;
     let current_branch = current_branch_value(&repository, current_ref.as_deref(), &retained.rows);
+    let worktrees = if optional_bool(payload, "includeWorktrees")?.unwrap_or(false) {
+        Some(worktree_inventory::worktree_rows(
+            &repository,
+            &trusted_root.working_root,
+        )?)
+    } else {
+        None
+    };
 
-    Ok(json!({
+    let mut value = json!({
         "schemaVersion": BRANCH_INVENTORY_SCHEMA_VERSION,
         "status": "ok",
         "operation": "branch_inventory",
@@ -88,7 +97,14 @@ fn branch_inventory_sync(
             "networkPolicy": "local git refs only",
             "resourceRefs": []
         }
-    }))
+    });
+    if let Some(worktrees) = worktrees {
+        value["worktrees"] = json!(worktrees.rows);
+        value["evidence"]["totalWorktrees"] = json!(worktrees.total);
+        value["evidence"]["maxWorktrees"] = json!(MAX_WORKTREES);
+        value["evidence"]["worktreesTruncated"] = json!(worktrees.truncated);
+    }
+    Ok(value)
 }
 
 struct BranchScan {
@@ -325,7 +341,7 @@ fn ahead_behind_value(no_upstream: bool, ahead: Option<u64>, behind: Option<u64>
     }
 }
 
-fn ahead_behind(
+pub(super) fn ahead_behind(
     worktree_root: &std::path::Path,
     branch_ref: &str,
     upstream_ref: &str,
@@ -410,6 +426,14 @@ fn optional_usize(payload: &Value, field: &str) -> Result<Option<usize>, Capabil
     }
 }
 
+fn optional_bool(payload: &Value, field: &str) -> Result<Option<bool>, CapabilityError> {
+    match payload.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Bool(value)) => Ok(Some(*value)),
+        Some(_) => Err(invalid(format!("{field} must be a boolean"))),
+    }
+}
+
 fn invalid(message: impl Into<String>) -> CapabilityError {
     CapabilityError::InvalidParams {
         message: message.into(),
@@ -24,6 +24,8 @@
 //! | `mutation` | Index-only stage/unstage implementation and evidence |
 //! | `service` | Trusted path resolution, Git command execution, and truncation |
 //! | `types` | Small request/result helper types |
+//! | `worktree_inventory` | Read-only worktree rows for branch inventory |
+//! | `worktree_inventory_tests` | Test-only worktree inventory coverage |
 //!
 //! # INVARIANT: Git mutation is staged-state only
 //!
@@ -50,7 +52,9 @@
 //! enumerates local `refs/heads/*`, computes ahead/behind only against
 //! already-present local upstream refs, reports oversized last-commit metadata
 //! as truncated row evidence, and never fetches, switches, creates, deletes,
-//! renames, or contacts remotes.
+//! renames, or contacts remotes. With `includeWorktrees` it also lists the
+//! repository's worktrees; dirty state is read only for worktrees inside the
+//! trusted working directory.
 
 use crate::domains::registration::worker::{DomainRegistrationContext, DomainWorkerModule};
 
@@ -62,6 +66,7 @@ mod handlers;
 pub(crate) mod mutation;
 pub(crate) mod service;
 mod types;
+mod worktree_inventory;
 
 pub(crate) const WORKER: &str = "git";
 pub(crate) const GIT_LIFECYCLE_TOPIC: &str = "git.lifecycle";
@@ -99,3 +104,5 @@ pub(crate) fn worker_module(
 
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod worktree_inventory_tests;
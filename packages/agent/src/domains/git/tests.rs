@@ -2547,7 +2547,7 @@ async fn status_error(root: &Path, payload: Value) -> String {
         .to_string()
 }
 
-async fn branch_inventory(root: &Path, payload: Value) -> Value {
+pub(super) async fn branch_inventory(root: &Path, payload: Value) -> Value {
     branch_inventory_value(&invocation(root, "git-branch-inventory"), &payload)
         .await
         .expect("branch inventory")
@@ -2973,7 +2973,7 @@ fn expected_guarded_head_move(
     }
 }
 
-fn init_repo(path: &Path) {
+pub(super) fn init_repo(path: &Path) {
     git(path, ["init", "-b", "main"]);
     configure_repo(path);
 }
@@ -2983,7 +2983,7 @@ fn configure_repo(path: &Path) {
     git(path, ["config", "user.email", "tron-test@invalid.local"]);
 }
 
-fn write_file(root: &Path, relative: &str, content: &str) {
+pub(super) fn write_file(root: &Path, relative: &str, content: &str) {
     let path = root.join(relative);
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).expect("parent dir");
@@ -2991,11 +2991,11 @@ fn write_file(root: &Path, relative: &str, content: &str) {
     fs::write(path, content).expect("write file");
 }
 
-fn commit(path: &Path, message: &str) {
+pub(super) fn commit(path: &Path, message: &str) {
     git(path, ["commit", "-m", message]);
 }
 
-fn git<const N: usize>(path: &Path, args: [&str; N]) {
+pub(super) fn git<const N: usize>(path: &Path, args: [&str; N]) {
     let output = Command::new("git")
         .arg("-C")
         .arg(path)
@@ -0,0 +1,184 @@
+//! Read-only linked-worktree rows for `git_branch_inventory`.
+//!
+//! `git worktree list --porcelain` enumerates the repository's worktrees.
+//! Worktrees inside the trusted working directory report a relative path,
+//! dirty state, and ahead/behind against the branch's local upstream ref.
+//! Worktrees elsewhere report only their directory name and branch facts:
+//! their files lie outside the grant, so dirty state is not read.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Value, json};
+
+use crate::shared::server::errors::CapabilityError;
+
+use super::branch_inventory::ahead_behind;
+use super::service;
+use super::types::RepositoryFacts;
+
+/// Worktrees listed before the inventory reports truncation.
+pub(super) const MAX_WORKTREES: usize = 64;
+const WORKTREE_SCAN_BYTES: usize = 256 * 1024;
+const DIRTY_PROBE_BYTES: usize = 4 * 1024;
+
+pub(super) struct WorktreeScan {
+    pub(super) rows: Vec<Value>,
+    pub(super) total: usize,
+    pub(super) truncated: bool,
+}
+
+#[derive(Default)]
+struct WorktreeEntry {
+    path: PathBuf,
+    head: Option<String>,
+    branch_ref: Option<String>,
+    bare: bool,
+    locked: bool,
+    prunable: bool,
+}
+
+pub(super) fn worktree_rows(
+    repository: &RepositoryFacts,
+    working_root: &Path,
+) -> Result<WorktreeScan, CapabilityError> {
+    let output = service::git_output_bounded(
+        &repository.worktree_root,
+        ["--no-pager", "worktree", "list", "--porcelain"],
+        WORKTREE_SCAN_BYTES,
+    )?;
+    let entries = parse_worktree_list(&String::from_utf8_lossy(&output.stdout));
+    let total = entries.len();
+    let rows = entries
+        .iter()
+        .filter(|entry| !entry.bare)
+        .take(MAX_WORKTREES)
+        .map(|entry| worktree_row(repository, working_root, entry))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(WorktreeScan {
+        rows,
+        total,
+        truncated: output.stdout_truncated || total > MAX_WORKTREES,
+    })
+}
+
+fn parse_worktree_list(text: &str) -> Vec<WorktreeEntry> {
+    let mut entries = Vec::new();
+    let mut current: Option<WorktreeEntry> = None;
+    for line in text.lines() {
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "worktree" => {
+                entries.extend(current.take());
+                current = Some(WorktreeEntry {
+                    path: PathBuf::from(value),
+                    ..WorktreeEntry::default()
+                });
+            }
+            "HEAD" => set(&mut current, |entry| entry.head = Some(value.to_owned())),
+            "branch" => set(&mut current, |entry| {
+                entry.branch_ref = Some(value.to_owned())
+            }),
+            "bare" => set(&mut current, |entry| entry.bare = true),
+            "locked" => set(&mut current, |entry| entry.locked = true),
+            "prunable" => set(&mut current, |entry| entry.prunable = true),
+            _ => {}
+        }
+    }
+    entries.extend(current);
+    entries
+}
+
+fn set(current: &mut Option<WorktreeEntry>, update: impl FnOnce(&mut WorktreeEntry)) {
+    if let Some(entry) = current.as_mut() {
+        update(entry);
+    }
+}
+
+fn worktree_row(
+    repository: &RepositoryFacts,
+    working_root: &Path,
+    entry: &WorktreeEntry,
+) -> Result<Value, CapabilityError> {
+    let canonical = entry.path.canonicalize().ok();
+    let inside = canonical
+        .as_ref()
+        .is_some_and(|path| path.starts_with(working_root));
+    let path = match canonical.as_ref().filter(|_| inside) {
+        Some(path) => json!({
+            "root": "working_directory",
+            "relativePath": relative_path(working_root, path)
+        }),
+        None => json!({
+            "root": "outside_working_directory",
+            "name": entry.path.file_name().map(|name| name.to_string_lossy().into_owned())
+        }),
+    };
+    let dirty = match canonical.as_ref() {
+        _ if entry.prunable => json!({"available": false, "reason": "prunable"}),
+        Some(path) if inside => json!({"available": true, "dirty": is_dirty(path)?}),
+        _ => json!({"available": false, "reason": "outside_working_directory"}),
+    };
+    let (ahead, behind) = match entry.branch_ref.as_deref() {
+        Some(branch_ref) => {
+            let upstream = upstream_ref(&repository.worktree_root, branch_ref)?;
+            ahead_behind(&repository.worktree_root, branch_ref, &upstream)?
+        }
+        None => (None, None),
+    };
+    Ok(json!({
+        "path": path,
+        "current": canonical.as_deref() == Some(repository.worktree_root.as_path()),
+        "branch": entry
+            .branch_ref
+            .as_deref()
+            .map(|branch_ref| branch_ref.trim_start_matches("refs/heads/")),
+        "detachedHead": entry.branch_ref.is_none(),
+        "headOid": entry.head,
+        "dirty": dirty,
+        "ahead": ahead,
+        "behind": behind,
+        "locked": entry.locked,
+        "prunable": entry.prunable
+    }))
+}
+
+fn is_dirty(worktree: &Path) -> Result<bool, CapabilityError> {
+    let output = service::git_output_bounded(
+        worktree,
+        [
+            "--no-pager",
+            "status",
+            "--porcelain=v1",
+            "--untracked-files=normal",
+        ],
+        DIRTY_PROBE_BYTES,
+    )?;
+    Ok(!output.stdout.is_empty())
+}
+
+fn upstream_ref(worktree_root: &Path, branch_ref: &str) -> Result<String, CapabilityError> {
+    let output = service::git_output_bounded(
+        worktree_root,
+        [
+            "--no-pager",
+            "for-each-ref",
+            "--format=%(upstream)",
+            branch_ref,
+        ],
+        1024,
+    )?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    let relative = path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned();
+    if relative.is_empty() {
+        ".".to_owned()
+    } else {
+        relative
+    }
+}
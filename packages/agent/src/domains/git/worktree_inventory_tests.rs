@@ -0,0 +1,98 @@
+use serde_json::{Value, json};
+use tempfile::tempdir;
+
+use super::tests::{branch_inventory, commit, git, init_repo, write_file};
+
+fn worktree_by_branch<'a>(value: &'a Value, branch: &str) -> &'a Value {
+    value["worktrees"]
+        .as_array()
+        .expect("worktrees")
+        .iter()
+        .find(|row| row["branch"] == json!(branch))
+        .unwrap_or_else(|| panic!("missing worktree for {branch}: {value}"))
+}
+
+#[tokio::test]
+async fn git_branch_inventory_lists_worktrees_with_dirty_state() {
+    let repo = tempdir().expect("repo");
+    let outside = tempdir().expect("outside");
+    init_repo(repo.path());
+    write_file(repo.path(), ".git/info/exclude", ".worktrees/\n");
+    write_file(repo.path(), "tracked.txt", "base\n");
+    git(repo.path(), ["add", "tracked.txt"]);
+    commit(repo.path(), "base");
+    git(
+        repo.path(),
+        ["worktree", "add", "-b", "clean", ".worktrees/clean"],
+    );
+    git(
+        repo.path(),
+        ["worktree", "add", "-b", "dirty", ".worktrees/dirty"],
+    );
+    let sibling = outside.path().join("sibling");
+    git(
+        repo.path(),
+        [
+            "worktree",
+            "add",
+            "-b",
+            "sibling",
+            sibling.to_str().unwrap(),
+        ],
+    );
+    write_file(repo.path(), ".worktrees/dirty/tracked.txt", "changed\n");
+
+    let without = branch_inventory(repo.path(), json!({})).await;
+    assert!(without.get("worktrees").is_none());
+
+    let value = branch_inventory(repo.path(), json!({"includeWorktrees": true})).await;
+    assert_eq!(value["evidence"]["totalWorktrees"], 4);
+    assert_eq!(value["evidence"]["worktreesTruncated"], false);
+
+    let main = worktree_by_branch(&value, "main");
+    assert_eq!(main["current"], true);
+    assert_eq!(main["path"]["relativePath"], ".");
+    assert_eq!(main["dirty"], json!({"available": true, "dirty": false}));
+
+    let clean = worktree_by_branch(&value, "clean");
+    assert_eq!(clean["current"], false);
+    assert_eq!(clean["path"]["relativePath"], ".worktrees/clean");
+    assert_eq!(clean["dirty"]["dirty"], false);
+    assert_eq!(clean["detachedHead"], false);
+
+    assert_eq!(worktree_by_branch(&value, "dirty")["dirty"]["dirty"], true);
+
+    let sibling = worktree_by_branch(&value, "sibling");
+    assert_eq!(
+        sibling["path"],
+        json!({"root": "outside_working_directory", "name": "sibling"})
+    );
+    assert_eq!(
+        sibling["dirty"],
+        json!({"available": false, "reason": "outside_working_directory"})
+    );
+}
+
+#[tokio::test]
+async fn git_branch_inventory_reports_worktree_ahead_behind_local_upstream() {
+    let repo = tempdir().expect("repo");
+    init_repo(repo.path());
+    write_file(repo.path(), ".git/info/exclude", ".worktrees/\n");
+    write_file(repo.path(), "tracked.txt", "base\n");
+    git(repo.path(), ["add", "tracked.txt"]);
+    commit(repo.path(), "base");
+    git(
+        repo.path(),
+        ["worktree", "add", "-b", "feature", ".worktrees/feature"],
+    );
+    git(repo.path(), ["branch", "--set-upstream-to=main", "feature"]);
+    let feature = repo.path().join(".worktrees/feature");
+    write_file(&feature, "tracked.txt", "feature\n");
+    git(&feature, ["commit", "-am", "feature work"]);
+
+    let value = branch_inventory(repo.path(), json!({"includeWorktrees": true})).await;
+    let row = worktree_by_branch(&value, "feature");
+    assert_eq!(row["ahead"], 1);
+    assert_eq!(row["behind"], 0);
+    assert_eq!(row["dirty"]["dirty"], false);
+}
@@ -0,0 +1,162 @@
+//! Deadline expiry for unanswered questions.
+//!
+//! Creating a question with a deadline schedules a task that, once the
+//! deadline passes, persists the `expired` lifecycle and publishes
+//! `question.expired`, so askers and clients learn of the timeout without
+//! waiting for a late answer attempt. The answer path expires through the same
+//! helper; both write against the version they read, so whichever lands second
+//! fails its version check instead of overwriting the first.
+//!
+//! Each timer is owned: it is tracked by question id so resolving the question
+//! aborts it, registered with the [`ShutdownCoordinator`], and stops when the
+//! shutdown token is cancelled. Timers live in memory. After a restart an
+//! overdue question still inspects as timed out from its durable `expiresAt`
+//! and expires on the next answer attempt.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde_json::json;
+use tokio::task::AbortHandle;
+
+use crate::app::lifecycle::shutdown::ShutdownCoordinator;
+use crate::engine::{EngineHostHandle, EngineResourceInspection, Invocation, UpdateResource};
+use crate::shared::server::errors::CapabilityError;
+
+use super::errors::engine_error;
+use super::service::question_record;
+use super::support::{publish_lifecycle_event, to_value, version_ref};
+use super::types::{QuestionRecord, QuestionState};
+
+/// Pending deadline timers by question resource id.
+static QUESTION_TIMERS: LazyLock<Mutex<HashMap<String, AbortHandle>>> =
+    LazyLock::new(Mutex::default);
+
+/// Expire the question at `expires_at` unless it is answered first.
+///
+/// `now` is the creation time the deadline was resolved against, so the
+/// timer waits exactly the remaining span without reading the wall clock itself.
+pub(super) fn schedule_question_expiry(
+    engine_host: &EngineHostHandle,
+    invocation: &Invocation,
+    shutdown: Option<&Arc<ShutdownCoordinator>>,
+    question_resource_id: &str,
+    expires_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) {
+    let deadline = tokio::time::Instant::now() + (expires_at - now).to_std().unwrap_or_default();
+    let token = shutdown.map(|shutdown| shutdown.token());
+    let engine_host = engine_host.clone();
+    let invocation = invocation.clone();
+    let question_resource_id = question_resource_id.to_owned();
+    // Held across the spawn so the timer cannot clear its slot before it is set.
+    let mut timers = QUESTION_TIMERS.lock();
+    let task = tokio::spawn({
+        let question_resource_id = question_resource_id.clone();
+        async move {
+            let deadline = tokio::time::sleep_until(deadline);
+            match &token {
+                Some(token) => tokio::select! {
+                    () = deadline => {}
+                    () = token.cancelled() => return,
+                },
+                None => deadline.await,
+            }
+            // Leave the slot before writing so a racing answer cannot abort
+            // the write midway; the version check settles that race instead.
+            let _ = QUESTION_TIMERS.lock().remove(&question_resource_id);
+            if let Err(error) =
+                expire_pending_question(&engine_host, &invocation, &question_resource_id).await
+            {
+                tracing::warn!(
+                    question_resource_id,
+                    error = %error,
+                    "failed to expire question at its deadline"
+                );
+            }
+        }
+    });
+    if let Some(previous) = timers.insert(question_resource_id, task.abort_handle()) {
+        previous.abort();
+    }
+    drop(timers);
+    if let Some(shutdown) = shutdown {
+        shutdown.register_task(task);
+    } else {
+        drop(task);
+    }
+}
+
+/// Abort the deadline timer of a question that has resolved.
+pub(super) fn cancel_question_expiry(question_resource_id: &str) {
+    if let Some(timer) = QUESTION_TIMERS.lock().remove(question_resource_id) {
+        timer.abort();
+    }
+}
+
+#[cfg(test)]
+pub(super) fn has_question_expiry(question_resource_id: &str) -> bool {
+    QUESTION_TIMERS.lock().contains_key(question_resource_id)
+}
+
+async fn expire_pending_question(
+    engine_host: &EngineHostHandle,
+    invocation: &Invocation,
+    question_resource_id: &str,
+) -> Result<(), CapabilityError> {
+    let Some(inspection) = engine_host
+        .inspect_resource(question_resource_id)
+        .await
+        .map_err(engine_error)?
+    else {
+        return Ok(());
+    };
+    let (version_id, record) = question_record(&inspection)?;
+    if record.state != QuestionState::Pending {
+        return Ok(());
+    }
+    mark_question_expired(engine_host, invocation, &inspection, version_id, record).await
+}
+
+/// Persist the `expired` lifecycle and publish `question.expired`.
+pub(super) async fn mark_question_expired(
+    engine_host: &EngineHostHandle,
+    invocation: &Invocation,
+    inspection: &EngineResourceInspection,
+    current_version_id: String,
+    mut record: QuestionRecord,
+) -> Result<(), CapabilityError> {
+    record.state = QuestionState::Expired;
+    record.revision = record.revision.saturating_add(1);
+    let version = engine_host
+        .update_resource(UpdateResource {
+            resource_id: inspection.resource.resource_id.clone(),
+            expected_current_version_id: Some(current_version_id),
+            lifecycle: Some(QuestionState::Expired.as_str().to_owned()),
+            payload: to_value(&record, "expired question")?,
+            state: None,
+            locations: Vec::new(),
+            trace_id: invocation.causal_context.trace_id.clone(),
+            invocation_id: Some(invocation.id.clone()),
+        })
+        .await
+        .map_err(engine_error)?;
+    cancel_question_expiry(&inspection.resource.resource_id);
+    publish_lifecycle_event(
+        engine_host,
+        invocation,
+        "question.expired",
+        json!({
+            "questionResourceId": inspection.resource.resource_id,
+            "questionVersionId": version.version_id,
+            "goalRef": record.goal_ref,
+            "state": QuestionState::Expired.as_str(),
+            "resolution": record.timeout_resolution(),
+            "resourceRefs": [version_ref(&inspection.resource, &version, "user_question")]
+        }),
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use serde_json::{Value, json};
+
+use crate::engine::{StreamActorScope, StreamCursor, VisibilityScope};
+use crate::shared::server::context::ServerRuntimeContext;
+
+use super::GOALS_LIFECYCLE_TOPIC;
+use super::tests::{
+    QUESTION_ANSWER_FUNCTION, create_question, inspect_question, invocation, test_context,
+};
+
+async fn question_expired_events(ctx: &ServerRuntimeContext, subscription_id: &str) -> Vec<Value> {
+    ctx.engine_host
+        .subscribe_stream(
+            subscription_id.to_owned(),
+            GOALS_LIFECYCLE_TOPIC.to_owned(),
+            StreamCursor(0),
+            VisibilityScope::System,
+            None,
+            None,
+        )
+        .await
+        .expect("subscribe goals lifecycle stream");
+    let page = ctx
+        .engine_host
+        .poll_stream(subscription_id, None, 50, &StreamActorScope::admin())
+        .await
+        .expect("poll goals lifecycle stream");
+    page.events
+        .into_iter()
+        .map(|event| event.payload)
+        .filter(|payload| payload["type"] == "question.expired")
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn question_expires_and_publishes_at_its_deadline_without_an_answer() {
+    let ctx = test_context().await;
+    let question = create_question(
+        &ctx,
+        "question-deadline",
+        json!({
+            "prompt": "Deploy now?",
+            "options": ["yes", "no"],
+            "answerTimeoutMs": 60_000,
+            "defaultChoice": "no"
+        }),
+    )
+    .await;
+
+    tokio::time::advance(std::time::Duration::from_secs(59)).await;
+    tokio::task::yield_now().await;
+    let pending = inspect_question(&ctx, "inspect-before-deadline", &question).await;
+    assert_eq!(pending["question"]["state"], "pending");
+    assert!(
+        question_expired_events(&ctx, "before-deadline")
+            .await
+            .is_empty()
+    );
+
+    tokio::time::advance(std::time::Duration::from_secs(2)).await;
+    let mut expired = Vec::new();
+    for _ in 0..20 {
+        tokio::task::yield_now().await;
+        expired = question_expired_events(&ctx, "after-deadline").await;
+        if !expired.is_empty() {
+            break;
+        }
+    }
+    assert_eq!(expired.len(), 1, "{expired:?}");
+    let payload = &expired[0]["payload"];
+    assert_eq!(
+        payload["questionResourceId"],
+        question["questionResourceId"]
+    );
+    assert_eq!(
+        payload["resolution"],
+        json!({"kind": "default_choice", "answerText": "no"})
+    );
+    let persisted = inspect_question(&ctx, "inspect-after-deadline", &question).await;
+    assert_eq!(persisted["question"]["state"], "expired");
+
+    let late = super::service::answer_question_value(
+        &ctx.engine_host,
+        &invocation(
+            "answer-after-deadline",
+            QUESTION_ANSWER_FUNCTION,
+            Some("answer-after-deadline"),
+        ),
+        &json!({
+            "questionResourceId": question["questionResourceId"],
+            "expectedQuestionVersionId": question["questionVersionId"],
+            "answerText": "yes",
+            "reason": "too late"
+        }),
+    )
+    .await
+    .expect_err("answer after the deadline must fail");
+    assert!(late.to_string().contains("expired"), "{late}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn answering_a_question_aborts_its_deadline_timer() {
+    let ctx = test_context().await;
+    let question = create_question(
+        &ctx,
+        "question-answered-before-deadline",
+        json!({
+            "prompt": "Ship it?",
+            "options": ["yes", "no"],
+            "answerTimeoutMs": 60_000,
+            "defaultChoice": "no"
+        }),
+    )
+    .await;
+    let question_id = question["questionResourceId"].as_str().unwrap();
+    assert!(super::expiry::has_question_expiry(question_id));
+
+    super::service::answer_question_value(
+        &ctx.engine_host,
+        &invocation(
+            "answer-before-deadline",
+            QUESTION_ANSWER_FUNCTION,
+            Some("answer-before-deadline"),
+        ),
+        &json!({
+            "questionResourceId": question_id,
+            "expectedQuestionVersionId": question["questionVersionId"],
+            "answerText": "yes",
+            "reason": "answered in time"
+        }),
+    )
+    .await
+    .expect("answer before the deadline");
+    assert!(!super::expiry::has_question_expiry(question_id));
+
+    tokio::time::advance(std::time::Duration::from_secs(61)).await;
+    tokio::task::yield_now().await;
+    assert!(
+        question_expired_events(&ctx, "after-aborted-deadline")
+            .await
+            .is_empty()
+    );
+    let persisted = inspect_question(&ctx, "inspect-after-aborted-deadline", &question).await;
+    assert_eq!(persisted["question"]["state"], "answered");
+}
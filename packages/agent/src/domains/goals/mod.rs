@@ -10,6 +10,8 @@
 //! | Module | Purpose |
 //! |--------|---------|
 //! | `errors` | Domain-local error helpers |
+//! | `expiry` | Deadline expiry of unanswered questions |
+//! | `expiry_tests` | Paused-clock deadline expiry coverage |
 //! | `schema_tests` | Resource/schema drift guards |
 //! | `service` | Durable goal/question lifecycle behavior |
 //! | `support` | Scope, validation, resource refs, and stream helpers |
@@ -24,8 +26,15 @@
 //! remain serialized. Queue refs are evidence refs only in this slice; no
 //! hidden prompt queue, autonomous runner, planner, scheduler, notification
 //! path, or subagent dispatch is restored here.
+//!
+//! Questions never block: an unanswered question past its `expiresAt` (or
+//! `answerTimeoutMs`) deadline inspects as timed out and resolves to its
+//! `defaultChoice`, or to `no_response` so the asker can proceed. A timer
+//! started with the question persists the `expired` lifecycle and publishes
+//! `question.expired` at the deadline; it touches nothing but that question.
 
 mod errors;
+mod expiry;
 pub(crate) mod service;
 mod support;
 mod types;
@@ -38,6 +47,8 @@ pub(crate) const USER_QUESTION_SCHEMA_ID: &str = "tron.resource.user_question.v1
 pub(crate) const GOAL_ANSWER_KIND: &str = "goal_answer";
 pub(crate) const GOAL_ANSWER_SCHEMA_ID: &str = "tron.resource.goal_answer.v1";
 
+#[cfg(test)]
+mod expiry_tests;
 #[cfg(test)]
 mod schema_tests;
 #[cfg(test)]
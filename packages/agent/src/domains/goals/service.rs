@@ -1,6 +1,9 @@
+use std::sync::Arc;
+
 use chrono::Utc;
 use serde_json::{Value, json};
 
+use crate::app::lifecycle::shutdown::ShutdownCoordinator;
 use crate::engine::{
     AcquireResourceLease, CreateResource, EngineHostHandle, EngineResourceInspection,
     ListResources, UpdateResource, WorkerId,
@@ -8,6 +11,7 @@ use crate::engine::{
 use crate::shared::server::errors::CapabilityError;
 
 use super::errors::{engine_error, invalid_params};
+use super::expiry::{cancel_question_expiry, mark_question_expired, schedule_question_expiry};
 use super::support::*;
 use super::types::{
     ANSWER_SCHEMA_VERSION, AnswerRecord, GOAL_KIND, GOAL_SCHEMA_ID, GOAL_SCHEMA_VERSION,
@@ -218,6 +222,7 @@ pub(crate) async fn cancel_goal_value(
 pub(crate) async fn create_question_value(
     engine_host: &EngineHostHandle,
     invocation: &crate::engine::Invocation,
+    shutdown: Option<&Arc<ShutdownCoordinator>>,
     payload: &Value,
 ) -> Result<Value, CapabilityError> {
     let now = Utc::now();
@@ -228,6 +233,10 @@ pub(crate) async fn create_question_value(
         PROMPT_MAX_CHARS,
     )?;
     let goal_ref = optional_goal_ref(engine_host, invocation, payload).await?;
+    let expires_at = question_expiry(payload, now)?;
+    let default_choice = optional_string(payload, "defaultChoice")?
+        .map(|choice| bounded_text("defaultChoice", &choice, 1, ANSWER_MAX_CHARS))
+        .transpose()?;
     let record = QuestionRecord {
         schema_version: QUESTION_SCHEMA_VERSION.to_owned(),
         state: QuestionState::Pending,
@@ -237,7 +246,8 @@ pub(crate) async fn create_question_value(
         goal_ref,
         options: optional_string_array(payload, "options", 20, 500)?,
         allow_free_form: optional_bool(payload, "allowFreeForm")?.unwrap_or(true),
-        expires_at: optional_datetime(payload, "expiresAt")?,
+        expires_at,
+        default_choice,
         created_at: now,
         answered_at: None,
         cancelled_at: None,
@@ -253,6 +263,18 @@ pub(crate) async fn create_question_value(
             "question requires options when allowFreeForm is false",
         ));
     }
+    if let Some(choice) = record.default_choice.as_deref() {
+        if record.expires_at.is_none() {
+            return Err(invalid_params(
+                "defaultChoice requires answerTimeoutMs or expiresAt",
+            ));
+        }
+        if !record.allow_free_form && !record.options.iter().any(|option| option == choice) {
+            return Err(invalid_params(
+                "defaultChoice must match one of the question options",
+            ));
+        }
+    }
     let resource = engine_host
         .create_resource(CreateResource {
             resource_id: Some(format!("{USER_QUESTION_KIND}:{}", invocation.id.as_str())),
@@ -270,6 +292,16 @@ pub(crate) async fn create_question_value(
         })
         .await
         .map_err(engine_error)?;
+    if let Some(expires_at) = record.expires_at {
+        schedule_question_expiry(
+            engine_host,
+            invocation,
+            shutdown,
+            &resource.resource_id,
+            expires_at,
+            now,
+        );
+    }
     let cursor = publish_lifecycle_event(
         engine_host,
         invocation,
@@ -336,10 +368,13 @@ pub(crate) async fn inspect_question_value(
 ) -> Result<Value, CapabilityError> {
     let inspection = require_question(engine_host, invocation, payload).await?;
     let (version_id, record) = question_record(&inspection)?;
+    let timed_out = record.timed_out(Utc::now());
     Ok(json!({
         "schemaVersion": QUESTION_SCHEMA_VERSION,
-        "status": record.state.as_str(),
+        "status": if timed_out { QuestionState::Expired.as_str() } else { record.state.as_str() },
         "question": question_detail(&inspection, &version_id, &record),
+        "timedOut": timed_out,
+        "resolution": timed_out.then(|| record.timeout_resolution()),
         "resourceRefs": [resource_ref(&inspection.resource, "user_question")]
     }))
 }
@@ -390,34 +425,28 @@ async fn answer_question_value_locked(
     let mut inspection = require_question(engine_host, invocation, payload).await?;
     let expected = required_string(payload, "expectedQuestionVersionId")?;
     let (current_version_id, mut record) = question_record(&inspection)?;
+    // A question the deadline already closed reports that, not a stale version.
+    if record.state.is_terminal() {
+        return Err(question_terminal_error(&record.state));
+    }
     if current_version_id != expected {
         return Err(invalid_params(format!(
             "question revision conflict: expected {expected}, actual {current_version_id}"
         )));
     }
-    if record.state.is_terminal() {
-        return Err(question_terminal_error(&record.state));
-    }
     let now = Utc::now();
     if record
         .expires_at
         .is_some_and(|expires_at| expires_at <= now)
     {
-        record.state = QuestionState::Expired;
-        record.revision = record.revision.saturating_add(1);
-        let _ = engine_host
-            .update_resource(UpdateResource {
-                resource_id: inspection.resource.resource_id.clone(),
-                expected_current_version_id: Some(current_version_id),
-                lifecycle: Some(QuestionState::Expired.as_str().to_owned()),
-                payload: to_value(&record, "expired question")?,
-                state: None,
-                locations: Vec::new(),
-                trace_id: invocation.causal_context.trace_id.clone(),
-                invocation_id: Some(invocation.id.clone()),
-            })
-            .await
-            .map_err(engine_error)?;
+        mark_question_expired(
+            engine_host,
+            invocation,
+            &inspection,
+            current_version_id,
+            record,
+        )
+        .await?;
         return Err(invalid_params("question is expired and cannot be answered"));
     }
     let answer_text = bounded_text(
@@ -508,6 +537,7 @@ async fn answer_question_value_locked(
         })
         .await
         .map_err(engine_error)?;
+    cancel_question_expiry(&inspection.resource.resource_id);
     inspection.resource.lifecycle = QuestionState::Answered.as_str().to_owned();
     inspection.resource.current_version_id = Some(question_version.version_id.clone());
     let cursor = publish_lifecycle_event(
@@ -608,6 +638,29 @@ async fn require_question(
     Ok(inspection)
 }
 
+/// Resolve `answerTimeoutMs` (relative) or `expiresAt` (absolute) into a deadline.
+fn question_expiry(
+    payload: &Value,
+    now: chrono::DateTime<Utc>,
+) -> Result<Option<chrono::DateTime<Utc>>, CapabilityError> {
+    let expires_at = optional_datetime(payload, "expiresAt")?;
+    let Some(timeout_ms) = optional_u64(payload, "answerTimeoutMs")? else {
+        return Ok(expires_at);
+    };
+    if expires_at.is_some() {
+        return Err(invalid_params(
+            "question accepts answerTimeoutMs or expiresAt, not both",
+        ));
+    }
+    if timeout_ms == 0 || timeout_ms > QUESTION_TIMEOUT_MAX_MS {
+        return Err(invalid_params(format!(
+            "answerTimeoutMs must be between 1 and {QUESTION_TIMEOUT_MAX_MS}"
+        )));
+    }
+    let timeout = chrono::Duration::milliseconds(i64::try_from(timeout_ms).unwrap_or(i64::MAX));
+    Ok(Some(now + timeout))
+}
+
 fn goal_record(
     inspection: &EngineResourceInspection,
 ) -> Result<(String, GoalRecord), CapabilityError> {
@@ -618,7 +671,7 @@ fn goal_record(
     Ok((version_id, record))
 }
 
-fn question_record(
+pub(super) fn question_record(
     inspection: &EngineResourceInspection,
 ) -> Result<(String, QuestionRecord), CapabilityError> {
     let (version_id, payload) = current_payload(inspection)
@@ -666,6 +719,7 @@ fn question_summary(inspection: &EngineResourceInspection, record: &QuestionReco
         "summaryTruncated": summary_truncated,
         "goalRef": record.goal_ref,
         "expiresAt": record.expires_at,
+        "timedOut": record.timed_out(Utc::now()),
         "answer": record.answer,
         "revision": record.revision,
         "resourceRefs": [resource_ref(&inspection.resource, "user_question")]
@@ -19,6 +19,7 @@ pub(super) const PROMPT_MAX_CHARS: usize = 4_000;
 pub(super) const ANSWER_MAX_CHARS: usize = 8_000;
 pub(super) const REASON_MAX_CHARS: usize = 1_000;
 pub(super) const SUMMARY_MAX_CHARS: usize = 240;
+pub(super) const QUESTION_TIMEOUT_MAX_MS: u64 = 7 * 24 * 60 * 60 * 1_000;
 
 pub(super) fn required_string(payload: &Value, field: &str) -> Result<String, CapabilityError> {
     let value = optional_string(payload, field)?
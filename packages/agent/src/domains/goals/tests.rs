@@ -15,7 +15,7 @@ const GOAL_CANCEL_FUNCTION: &str = "goals::cancel";
 const QUESTION_CREATE_FUNCTION: &str = "goals::question_create";
 const QUESTION_LIST_FUNCTION: &str = "goals::question_list";
 const QUESTION_INSPECT_FUNCTION: &str = "goals::question_inspect";
-const QUESTION_ANSWER_FUNCTION: &str = "goals::question_answer";
+pub(super) const QUESTION_ANSWER_FUNCTION: &str = "goals::question_answer";
 
 #[tokio::test]
 async fn create_list_inspect_and_cancel_goal_records_resource_evidence() {
@@ -118,6 +118,7 @@ async fn create_question_and_answer_once_with_expected_version_guard() {
     let question = super::service::create_question_value(
         &ctx.engine_host,
         &question_invocation,
+        None,
         &json!({
             "goalResourceId": goal_id,
             "prompt": "Which direction should the implementation take?",
@@ -188,6 +189,7 @@ async fn active_question_answer_lease_blocks_answer_without_orphan_resource() {
     let question = super::service::create_question_value(
         &ctx.engine_host,
         &question_invocation,
+        None,
         &json!({"prompt": "Lease protected?"}),
     )
     .await
@@ -293,6 +295,7 @@ async fn execute_question_answer_replays_same_idempotency_key_without_double_ans
     let question = super::service::create_question_value(
         &ctx.engine_host,
         &question_invocation,
+        None,
         &json!({"prompt": "Which answer should be recorded?"}),
     )
     .await
@@ -367,6 +370,7 @@ async fn bounded_question_list_reports_truncation() {
         super::service::create_question_value(
             &ctx.engine_host,
             &invocation,
+            None,
             &json!({"prompt": format!("Question {index}?")}),
         )
         .await
@@ -395,6 +399,7 @@ async fn invalid_scope_expired_question_and_oversized_text_fail_closed() {
     let question = super::service::create_question_value(
         &ctx.engine_host,
         &create,
+        None,
         &json!({"prompt": "Expired?", "expiresAt": past_time()}),
     )
     .await
@@ -427,6 +432,7 @@ async fn invalid_scope_expired_question_and_oversized_text_fail_closed() {
     let option_question = super::service::create_question_value(
         &ctx.engine_host,
         &option_invocation,
+        None,
         &json!({
             "prompt": "Proceed?",
             "options": ["yes"],
@@ -485,7 +491,143 @@ async fn invalid_scope_expired_question_and_oversized_text_fail_closed() {
     assert!(too_large.to_string().contains("too large"));
 }
 
-async fn test_context() -> ServerRuntimeContext {
+#[tokio::test]
+async fn timely_answer_ignores_default_choice() {
+    let ctx = test_context().await;
+    let question = create_question(
+        &ctx,
+        "question-timely",
+        json!({
+            "prompt": "Ship now?",
+            "options": ["yes", "no"],
+            "allowFreeForm": false,
+            "answerTimeoutMs": 60_000,
+            "defaultChoice": "no"
+        }),
+    )
+    .await;
+    let inspected = inspect_question(&ctx, "inspect-timely", &question).await;
+    assert_eq!(inspected["timedOut"], false);
+    assert!(inspected["resolution"].is_null());
+    assert!(inspected["question"]["expiresAt"].is_string());
+
+    let answered = super::service::answer_question_value(
+        &ctx.engine_host,
+        &invocation(
+            "answer-timely",
+            QUESTION_ANSWER_FUNCTION,
+            Some("answer-timely"),
+        ),
+        &json!({
+            "questionResourceId": question["questionResourceId"],
+            "expectedQuestionVersionId": question["questionVersionId"],
+            "answerText": "yes",
+            "reason": "answered in time"
+        }),
+    )
+    .await
+    .expect("answer before timeout");
+    assert_eq!(answered["status"], "answered");
+}
+
+#[tokio::test]
+async fn timed_out_question_resolves_to_default_choice() {
+    let ctx = test_context().await;
+    let question = create_question(
+        &ctx,
+        "question-default",
+        json!({
+            "prompt": "Which branch?",
+            "options": ["main", "release"],
+            "expiresAt": past_time(),
+            "defaultChoice": "main"
+        }),
+    )
+    .await;
+    let inspected = inspect_question(&ctx, "inspect-default", &question).await;
+    assert_eq!(inspected["status"], "expired");
+    assert_eq!(inspected["timedOut"], true);
+    assert_eq!(
+        inspected["resolution"],
+        json!({"kind": "default_choice", "answerText": "main"})
+    );
+
+    let late = super::service::answer_question_value(
+        &ctx.engine_host,
+        &invocation("answer-late", QUESTION_ANSWER_FUNCTION, Some("answer-late")),
+        &json!({
+            "questionResourceId": question["questionResourceId"],
+            "expectedQuestionVersionId": question["questionVersionId"],
+            "answerText": "release",
+            "reason": "too late"
+        }),
+    )
+    .await
+    .expect_err("late answer must fail");
+    assert!(late.to_string().contains("expired"));
+    let persisted = inspect_question(&ctx, "inspect-default-after", &question).await;
+    assert_eq!(persisted["question"]["state"], "expired");
+    assert_eq!(persisted["resolution"]["answerText"], "main");
+}
+
+#[tokio::test]
+async fn timed_out_question_without_default_resolves_to_no_response() {
+    let ctx = test_context().await;
+    let question = create_question(
+        &ctx,
+        "question-no-default",
+        json!({"prompt": "Anything else?", "expiresAt": past_time()}),
+    )
+    .await;
+    let inspected = inspect_question(&ctx, "inspect-no-default", &question).await;
+    assert_eq!(inspected["timedOut"], true);
+    assert_eq!(inspected["resolution"], json!({"kind": "no_response"}));
+
+    let error = super::service::create_question_value(
+        &ctx.engine_host,
+        &invocation(
+            "question-default-no-deadline",
+            QUESTION_CREATE_FUNCTION,
+            Some("question-default-no-deadline"),
+        ),
+        None,
+        &json!({"prompt": "Pick one", "defaultChoice": "a"}),
+    )
+    .await
+    .expect_err("default without deadline must fail");
+    assert!(error.to_string().contains("defaultChoice requires"));
+}
+
+pub(super) async fn create_question(
+    ctx: &ServerRuntimeContext,
+    key: &str,
+    payload: serde_json::Value,
+) -> serde_json::Value {
+    super::service::create_question_value(
+        &ctx.engine_host,
+        &invocation(key, QUESTION_CREATE_FUNCTION, Some(key)),
+        None,
+        &payload,
+    )
+    .await
+    .expect("create question")
+}
+
+pub(super) async fn inspect_question(
+    ctx: &ServerRuntimeContext,
+    key: &str,
+    question: &serde_json::Value,
+) -> serde_json::Value {
+    super::service::inspect_question_value(
+        &ctx.engine_host,
+        &invocation(key, QUESTION_INSPECT_FUNCTION, None),
+        &json!({"questionResourceId": question["questionResourceId"]}),
+    )
+    .await
+    .expect("inspect question")
+}
+
+pub(super) async fn test_context() -> ServerRuntimeContext {
     let ctx = make_test_context();
     register_goal_question_types(&ctx).await;
     ctx
@@ -535,7 +677,7 @@ fn test_type(kind: &str, schema_id: &str, lifecycle_states: &[&str]) -> Register
     }
 }
 
-fn invocation(trace_id: &str, function_id: &str, key: Option<&str>) -> Invocation {
+pub(super) fn invocation(trace_id: &str, function_id: &str, key: Option<&str>) -> Invocation {
     let mut context = CausalContext::new(
         ActorId::new("agent:goals-session").unwrap(),
         ActorKind::Agent,
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub(super) const GOAL_SCHEMA_VERSION: &str = "tron.goals.goal.v1";
 pub(super) const QUESTION_SCHEMA_VERSION: &str = "tron.goals.user_question.v1";
@@ -95,6 +95,9 @@ pub(super) struct QuestionRecord {
     pub(super) options: Vec<String>,
     pub(super) allow_free_form: bool,
     pub(super) expires_at: Option<DateTime<Utc>>,
+    /// Answer the asker proceeds with once `expires_at` passes unanswered.
+    #[serde(default)]
+    pub(super) default_choice: Option<String>,
     pub(super) created_at: DateTime<Utc>,
     pub(super) answered_at: Option<DateTime<Utc>>,
     pub(super) cancelled_at: Option<DateTime<Utc>>,
@@ -106,6 +109,25 @@ pub(super) struct QuestionRecord {
     pub(super) revision: u64,
 }
 
+impl QuestionRecord {
+    /// Whether the question went unanswered past its deadline.
+    pub(super) fn timed_out(&self, now: DateTime<Utc>) -> bool {
+        match self.state {
+            QuestionState::Expired => true,
+            QuestionState::Pending => self.expires_at.is_some_and(|expires_at| expires_at <= now),
+            QuestionState::Answered | QuestionState::Cancelled => false,
+        }
+    }
+
+    /// How a timed-out question resolves: its default choice, or no response.
+    pub(super) fn timeout_resolution(&self) -> Value {
+        match &self.default_choice {
+            Some(choice) => json!({"kind": "default_choice", "answerText": choice}),
+            None => json!({"kind": "no_response"}),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct QuestionAnswerSummary {
@@ -222,6 +222,8 @@ fn retain_schema() -> serde_json::Value {
             "provenance": {"type": "object"},
             "confidence": {"type": "object"},
             "sensitivity": {"type": "string"},
+            "category": {"type": "string"},
+            "importance": {"type": "integer", "minimum": 0, "maximum": 10},
             "retention": {"type": "object"},
             "expiresAt": {"type": "string"},
             "sourceRefs": {"type": "array"},
@@ -246,6 +248,8 @@ fn edit_schema() -> serde_json::Value {
             "bodyRef": {"type": "object"},
             "confidence": {"type": "object"},
             "sensitivity": {"type": "string"},
+            "category": {"type": "string"},
+            "importance": {"type": "integer", "minimum": 0, "maximum": 10},
             "retention": {"type": "object"},
             "expiresAt": {"type": "string"},
             "reason": {"type": "string"}
@@ -272,6 +276,8 @@ fn list_schema() -> serde_json::Value {
         "additionalProperties": false,
         "properties": {
             "lifecycle": {"type": "string"},
+            "category": {"type": "string"},
+            "minImportance": {"type": "integer", "minimum": 0, "maximum": 10},
             "limit": {"type": "integer", "minimum": 1, "maximum": 500}
         }
     })
@@ -18,6 +18,7 @@
 //! | `prompt_trace` | Provider-safe memory prompt trace assembly |
 //! | `query_decision` | Query/result and decision evidence records plus provider-safe list/inspect projections |
 //! | `query_decision_validation` | Bounds and authority/grant leakage guards for query/decision evidence |
+//! | `record_list` | Record listing narrowed by category and ranked by importance |
 //! | `retrieval` | Deterministic resource-backed retrieval over record refs/previews |
 //! | `retention` | Retention/edit/delete policy support evidence |
 //! | `schema_tests` | Test-only resource schema drift guards |
@@ -59,6 +60,7 @@ mod migration;
 mod prompt_trace;
 mod query_decision;
 mod query_decision_validation;
+mod record_list;
 mod retention;
 mod retrieval;
 pub(crate) mod service;
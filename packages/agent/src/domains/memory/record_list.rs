@@ -0,0 +1,92 @@
+//! Memory record listing with category and importance filters.
+//!
+//! Category and importance live in each record payload rather than on the
+//! engine resource, so a filtered list scans every record in scope, keeps the
+//! matches, and ranks them by importance before applying the limit.
+
+use serde_json::{Value, json};
+
+use crate::engine::{EngineHostHandle, Invocation, ListResources};
+use crate::shared::protocol::memory::MEMORY_SCHEMA_VERSION;
+use crate::shared::server::errors::CapabilityError;
+
+use super::MEMORY_RECORD_KIND;
+use super::errors::engine_error;
+use super::support::{
+    current_payload, optional_category, optional_importance, optional_string, record_importance,
+    redacted_record_payload, redacted_resource_projection, resource_scope,
+};
+
+/// List redacted memory records in the current scope.
+///
+/// `category` and `minImportance` narrow the list; when either is given the
+/// records come back most important first, with unrated records last.
+pub(crate) async fn list_memory_value(
+    engine_host: &EngineHostHandle,
+    invocation: &Invocation,
+    payload: &Value,
+) -> Result<Value, CapabilityError> {
+    let limit = payload
+        .get("limit")
+        .and_then(Value::as_u64)
+        .unwrap_or(50)
+        .clamp(1, 500) as usize;
+    let category = optional_category(payload)?;
+    let min_importance = optional_importance(payload, "minImportance")?;
+    let filtered = category.is_some() || min_importance.is_some();
+    let filter = ListResources {
+        kind: Some(MEMORY_RECORD_KIND.to_owned()),
+        scope: Some(resource_scope(invocation)),
+        lifecycle: optional_string(payload, "lifecycle")?,
+        limit,
+    };
+    // Category and importance live in the record payload, so a filtered list
+    // has to see every record in scope before it can rank the matches.
+    let resources = if filtered {
+        engine_host
+            .scan_resources_internal(ListResources {
+                limit: usize::MAX,
+                ..filter
+            })
+            .await
+    } else {
+        engine_host.list_resources(filter).await
+    }
+    .map_err(engine_error)?;
+    let mut records = Vec::new();
+    for resource in resources {
+        if let Some(inspection) = engine_host
+            .inspect_resource(&resource.resource_id)
+            .await
+            .map_err(engine_error)?
+            && let Some((version_id, payload)) = current_payload(&inspection)
+        {
+            let importance = record_importance(&payload);
+            if category
+                .as_deref()
+                .is_some_and(|category| payload["category"].as_str() != Some(category))
+                || min_importance.is_some_and(|min| importance.is_none_or(|value| value < min))
+            {
+                continue;
+            }
+            records.push((
+                importance,
+                json!({
+                    "resource": redacted_resource_projection(&inspection.resource),
+                    "currentVersionId": version_id,
+                    "record": redacted_record_payload(&payload)
+                }),
+            ));
+        }
+    }
+    if filtered {
+        records.sort_by_key(|(importance, _)| std::cmp::Reverse(*importance));
+        records.truncate(limit);
+    }
+    let records: Vec<Value> = records.into_iter().map(|(_, record)| record).collect();
+    Ok(json!({
+        "schemaVersion": MEMORY_SCHEMA_VERSION,
+        "records": records,
+        "redacted": true
+    }))
+}
@@ -345,6 +345,8 @@ fn record_value() -> Value {
         provenance: json!({"source": "test"}),
         confidence: json!({"score": 0.9}),
         sensitivity: "private".to_owned(),
+        category: Some("preference".to_owned()),
+        importance: Some(7),
         retention: json!({"policy": "explicit"}),
         expires_at: Some(timestamp()),
         source_refs: vec![json!({"kind": "message", "id": "msg"})],
@@ -3,7 +3,7 @@ use serde_json::{Value, json};
 
 use crate::engine::{
     CreateResource, EngineHostHandle, EngineResourceInspection, EngineResourceScope, Invocation,
-    ListResources, UpdateResource, WorkerId,
+    UpdateResource, WorkerId,
 };
 use crate::shared::protocol::memory::{
     MEMORY_SCHEMA_VERSION, MemoryEngineDescriptor, MemoryMode, MemoryPolicyRecord, MemoryRecord,
@@ -18,6 +18,7 @@ pub(crate) use super::query_decision::{
     inspect_memory_decision_value, inspect_memory_query_value, list_memory_decisions_value,
     list_memory_queries_value, record_memory_decision_value, record_memory_query_value,
 };
+pub(crate) use super::record_list::list_memory_value;
 use super::retention::{ensure_retention_policy_supported, retention_policy_evidence};
 use super::retrieval::prompt_snippet_policy;
 use super::support::*;
@@ -245,6 +246,8 @@ pub(crate) async fn retain_memory_value(
         provenance: required_object(payload, "provenance")?,
         confidence: required_object(payload, "confidence")?,
         sensitivity: required_string(payload, "sensitivity")?,
+        category: optional_category(payload)?,
+        importance: optional_importance(payload, "importance")?,
         retention,
         expires_at: optional_datetime(payload, "expiresAt")?,
         source_refs: optional_array(payload, "sourceRefs")?,
@@ -288,6 +291,8 @@ pub(crate) async fn retain_memory_value(
             "policyResourceId": policy.resource_id.clone(),
             "mode": policy.record.mode.as_str(),
             "sensitivity": record.sensitivity.clone(),
+            "category": record.category.clone(),
+            "importance": record.importance,
             "retentionEvidence": retention_evidence.clone(),
             "traceRefs": record.trace_refs.clone(),
             "replayRefs": record.replay_refs.clone()
@@ -347,6 +352,12 @@ pub(crate) async fn edit_memory_value(
     if let Some(sensitivity) = optional_string(payload, "sensitivity")? {
         record.sensitivity = sensitivity;
     }
+    if let Some(category) = optional_category(payload)? {
+        record.category = Some(category);
+    }
+    if let Some(importance) = optional_importance(payload, "importance")? {
+        record.importance = Some(importance);
+    }
     record.expires_at = optional_datetime(payload, "expiresAt")?.or(record.expires_at);
     record.revision = record.revision.saturating_add(1);
     let retention_evidence = retention_policy_evidence(&policy, "edit");
@@ -450,48 +461,6 @@ pub(crate) async fn tombstone_memory_value(
     }))
 }
 
-/// List redacted memory records in the current scope.
-pub(crate) async fn list_memory_value(
-    engine_host: &EngineHostHandle,
-    invocation: &Invocation,
-    payload: &Value,
-) -> Result<Value, CapabilityError> {
-    let limit = payload
-        .get("limit")
-        .and_then(Value::as_u64)
-        .unwrap_or(50)
-        .clamp(1, 500) as usize;
-    let resources = engine_host
-        .list_resources(ListResources {
-            kind: Some(MEMORY_RECORD_KIND.to_owned()),
-            scope: Some(resource_scope(invocation)),
-            lifecycle: optional_string(payload, "lifecycle")?,
-            limit,
-        })
-        .await
-        .map_err(engine_error)?;
-    let mut records = Vec::new();
-    for resource in resources {
-        if let Some(inspection) = engine_host
-            .inspect_resource(&resource.resource_id)
-            .await
-            .map_err(engine_error)?
-            && let Some((version_id, payload)) = current_payload(&inspection)
-        {
-            records.push(json!({
-                "resource": redacted_resource_projection(&inspection.resource),
-                "currentVersionId": version_id,
-                "record": redacted_record_payload(&payload)
-            }));
-        }
-    }
-    Ok(json!({
-        "schemaVersion": MEMORY_SCHEMA_VERSION,
-        "records": records,
-        "redacted": true
-    }))
-}
-
 /// Inspect one redacted memory record.
 pub(crate) async fn inspect_memory_value(
     engine_host: &EngineHostHandle,
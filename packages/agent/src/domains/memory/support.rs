@@ -7,12 +7,15 @@ use crate::engine::{
     EngineResourceScope, EngineResourceVersion, Invocation, PublishStreamEvent, StreamCursor,
     VisibilityScope,
 };
-use crate::shared::protocol::memory::{MemoryMode, MemoryResourceRef};
+use crate::shared::protocol::memory::{MAX_MEMORY_IMPORTANCE, MemoryMode, MemoryResourceRef};
 use crate::shared::server::errors::CapabilityError;
 
 use super::errors::{engine_error, invalid_params};
 use super::{MEMORY_LIFECYCLE_TOPIC, WORKER, WRITE_SCOPE};
 
+/// Longest accepted memory record category.
+const MAX_CATEGORY_BYTES: usize = 64;
+
 pub(super) fn resource_scope(invocation: &Invocation) -> EngineResourceScope {
     if let Some(session_id) = &invocation.causal_context.session_id {
         EngineResourceScope::Session(session_id.clone())
@@ -181,6 +184,48 @@ pub(super) fn parse_datetime(value: &str) -> Result<DateTime<Utc>, CapabilityErr
         .map_err(|err| invalid_params(format!("invalid datetime {value}: {err}")))
 }
 
+/// Parse an optional record category: short, provider-safe, and trimmed.
+pub(super) fn optional_category(payload: &Value) -> Result<Option<String>, CapabilityError> {
+    let Some(category) = optional_string(payload, "category")? else {
+        return Ok(None);
+    };
+    let category = category.trim().to_owned();
+    if category.len() > MAX_CATEGORY_BYTES {
+        return Err(invalid_params(format!(
+            "category must be at most {MAX_CATEGORY_BYTES} bytes"
+        )));
+    }
+    ensure_provider_safe_text(&category, "category")?;
+    Ok(Some(category))
+}
+
+/// Parse an optional importance in `0..=MAX_MEMORY_IMPORTANCE`.
+pub(super) fn optional_importance(
+    payload: &Value,
+    field: &str,
+) -> Result<Option<u8>, CapabilityError> {
+    match payload.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .filter(|value| *value <= u64::from(MAX_MEMORY_IMPORTANCE))
+            .map(|value| Some(value as u8))
+            .ok_or_else(|| {
+                invalid_params(format!(
+                    "{field} must be an integer from 0 to {MAX_MEMORY_IMPORTANCE}"
+                ))
+            }),
+    }
+}
+
+/// Importance stored on a record payload, if any.
+pub(super) fn record_importance(payload: &Value) -> Option<u8> {
+    payload
+        .get("importance")
+        .and_then(Value::as_u64)
+        .and_then(|value| u8::try_from(value).ok())
+}
+
 pub(super) fn mode_from_payload(payload: &Value) -> Result<MemoryMode, CapabilityError> {
     let mode = required_string(payload, "mode")?;
     mode.parse::<MemoryMode>().map_err(invalid_params)
@@ -305,6 +350,8 @@ pub(super) fn redacted_record_payload(payload: &Value) -> Value {
         "provenance": provider_safe_projection(payload.get("provenance").unwrap_or(&Value::Null), 160, 3),
         "confidence": provider_safe_projection(payload.get("confidence").unwrap_or(&Value::Null), 80, 2),
         "sensitivity": redacted_text_field(payload, "sensitivity", 48),
+        "category": redacted_text_field(payload, "category", MAX_CATEGORY_BYTES),
+        "importance": payload.get("importance").cloned().unwrap_or(Value::Null),
         "retention": provider_safe_projection(payload.get("retention").unwrap_or(&Value::Null), 160, 3),
         "expiresAt": payload.get("expiresAt").cloned().unwrap_or(Value::Null),
         "sourceRefs": provider_safe_projection(payload.get("sourceRefs").unwrap_or(&json!([])), 160, 3),
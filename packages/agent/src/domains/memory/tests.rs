@@ -187,6 +187,136 @@ async fn record_lifecycle_is_versioned_resource_backed_and_redacted() {
     assert_memory_lifecycle_stream_redacts_authority_grants(&ctx).await;
 }
 
+#[tokio::test]
+async fn filtered_list_finds_matches_behind_the_list_page_cap() {
+    let ctx = make_test_context();
+    configure_active(&ctx, "memory-deep-configure").await;
+
+    let mut oldest = retain_payload("buried-preference");
+    oldest["category"] = json!("preference");
+    invoke_write(
+        &ctx,
+        super::RETAIN_FUNCTION,
+        oldest,
+        "memory-deep-retain-buried",
+    )
+    .await;
+    for index in 0..500 {
+        invoke_write(
+            &ctx,
+            super::RETAIN_FUNCTION,
+            retain_payload(&format!("filler-{index}")),
+            &format!("memory-deep-retain-{index}"),
+        )
+        .await;
+    }
+
+    let preferences = invoke_read(
+        &ctx,
+        super::LIST_FUNCTION,
+        json!({"category": "preference", "limit": 1}),
+        "memory-deep-list",
+    )
+    .await
+    .expect("list");
+    let records = preferences["records"].as_array().expect("records");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["record"]["subject"], "buried-preference");
+}
+
+#[tokio::test]
+async fn list_filters_by_category_and_orders_by_importance() {
+    let ctx = make_test_context();
+    configure_active(&ctx, "memory-category-configure").await;
+
+    for (record_id, category, importance) in [
+        ("pref-low", Some("preference"), Some(2)),
+        ("project-note", Some("project"), Some(9)),
+        ("pref-unrated", Some("preference"), None),
+        ("pref-high", Some("preference"), Some(8)),
+        ("uncategorized", None, Some(10)),
+    ] {
+        let mut payload = retain_payload(record_id);
+        if let Some(category) = category {
+            payload["category"] = json!(category);
+        }
+        if let Some(importance) = importance {
+            payload["importance"] = json!(importance);
+        }
+        invoke_write(
+            &ctx,
+            super::RETAIN_FUNCTION,
+            payload,
+            &format!("memory-category-retain-{record_id}"),
+        )
+        .await;
+    }
+
+    let subjects = |list: &Value| -> Vec<String> {
+        list["records"]
+            .as_array()
+            .expect("records")
+            .iter()
+            .map(|record| record["record"]["subject"].as_str().unwrap().to_owned())
+            .collect()
+    };
+
+    let preferences = invoke_read(
+        &ctx,
+        super::LIST_FUNCTION,
+        json!({"category": "preference"}),
+        "memory-category-list",
+    )
+    .await
+    .expect("list");
+    assert_eq!(
+        subjects(&preferences),
+        ["pref-high", "pref-low", "pref-unrated"]
+    );
+    assert_eq!(
+        preferences["records"][0]["record"]["category"],
+        "preference"
+    );
+    assert_eq!(preferences["records"][0]["record"]["importance"], 8);
+
+    let important = invoke_read(
+        &ctx,
+        super::LIST_FUNCTION,
+        json!({"minImportance": 8, "limit": 2}),
+        "memory-importance-list",
+    )
+    .await
+    .expect("list");
+    assert_eq!(subjects(&important), ["uncategorized", "project-note"]);
+
+    let important_preferences = invoke_read(
+        &ctx,
+        super::LIST_FUNCTION,
+        json!({"category": "preference", "minImportance": 5}),
+        "memory-category-importance-list",
+    )
+    .await
+    .expect("list");
+    assert_eq!(subjects(&important_preferences), ["pref-high"]);
+
+    let mut out_of_range = retain_payload("too-important");
+    out_of_range["importance"] = json!(11);
+    let rejected = invoke_write_result(
+        &ctx,
+        super::RETAIN_FUNCTION,
+        out_of_range,
+        "memory-importance-out-of-range",
+    )
+    .await;
+    assert!(
+        rejected.error.as_ref().is_some_and(|error| error
+            .to_string()
+            .contains("importance must be an integer from 0 to 10")),
+        "out-of-range importance must be rejected: {:?}",
+        rejected.error
+    );
+}
+
 #[tokio::test]
 async fn record_id_operations_reject_cross_session_scope() {
     let ctx = make_test_context();
@@ -3,6 +3,10 @@
 //! When the Anthropic prompt cache goes cold (>5 minutes since last API call),
 //! re-caching the entire conversation is expensive. This module prunes large
 //! tool-result blocks from old turns to reduce cache write tokens.
+//!
+//! [`PruneConfig`] bounds how aggressive pruning is: the most recent
+//! `recent_turns` assistant turns are left intact, and the newest
+//! `preserve_recent_n` tool results survive even when they fall in an old turn.
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -16,9 +20,31 @@ pub const DEFAULT_TTL_MS: u64 = 5 * 60 * 1000;
 /// Number of recent assistant turns to preserve without pruning.
 pub const DEFAULT_RECENT_TURNS: usize = 3;
 
+/// Number of most recent tool results that are never pruned.
+pub const DEFAULT_PRESERVE_RECENT_N: usize = 1;
+
 /// Minimum content size (bytes) to be considered for pruning.
 pub const PRUNE_THRESHOLD_BYTES: usize = 2048;
 
+/// Backpressure limits for cold-cache tool-result pruning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PruneConfig {
+    /// Number of recent assistant turns to preserve without pruning.
+    pub recent_turns: usize,
+    /// Number of most recent `tool_result` blocks that are never pruned,
+    /// regardless of which turn they belong to.
+    pub preserve_recent_n: usize,
+}
+
+impl Default for PruneConfig {
+    fn default() -> Self {
+        Self {
+            recent_turns: DEFAULT_RECENT_TURNS,
+            preserve_recent_n: DEFAULT_PRESERVE_RECENT_N,
+        }
+    }
+}
+
 /// Check whether the prompt cache has expired.
 ///
 /// Returns `false` if `last_api_call_ms` is 0 (first request — no cache to expire)
@@ -40,22 +66,23 @@ pub fn is_cache_cold(last_api_call_ms: u64, ttl_ms: u64) -> bool {
 ///
 /// When the cache goes cold, re-caching the entire conversation is expensive.
 /// This function replaces large `tool_result` content (>2KB) in old turns with
-/// a placeholder, keeping recent turns intact.
+/// a placeholder, keeping the last `config.recent_turns` turns and the last
+/// `config.preserve_recent_n` tool results intact.
 ///
 /// Returns a new Vec — never mutates the input.
 #[must_use]
 pub fn prune_tool_results_for_recache(
     messages: &[AnthropicMessageParam],
-    recent_turns: usize,
+    config: PruneConfig,
 ) -> Vec<AnthropicMessageParam> {
     // Count assistant messages to determine turn boundaries
     let assistant_count = messages.iter().filter(|m| m.role == "assistant").count();
 
-    if assistant_count <= recent_turns {
+    if assistant_count <= config.recent_turns {
         return messages.to_vec(); // Not enough turns to prune
     }
 
-    let preserve_after_turn = assistant_count - recent_turns;
+    let preserve_after_turn = assistant_count - config.recent_turns;
 
     // Find cutoff index: walk messages, count assistant messages seen
     let mut turns_seen = 0usize;
@@ -71,26 +98,72 @@ pub fn prune_tool_results_for_recache(
         }
     }
 
+    // Tool results at or after this global ordinal are always preserved.
+    let tool_result_count: usize = messages
+        .iter()
+        .filter(|m| m.role == "user")
+        .map(|m| m.content.iter().filter(|b| is_tool_result(b)).count())
+        .sum();
+    let protected_from = tool_result_count.saturating_sub(config.preserve_recent_n);
+
     // Clone messages, pruning large tool_results before the cutoff.
+    let mut tool_results_seen = 0usize;
     messages
         .iter()
         .enumerate()
         .map(|(i, msg)| {
-            if i >= cutoff_index || msg.role != "user" {
+            if msg.role != "user" {
                 return msg.clone();
             }
-            prune_user_message_tool_results(msg)
+            let first_ordinal = tool_results_seen;
+            tool_results_seen += msg.content.iter().filter(|b| is_tool_result(b)).count();
+            if i >= cutoff_index {
+                return msg.clone();
+            }
+            prune_user_message_tool_results(msg, first_ordinal, protected_from)
         })
         .collect()
 }
 
+/// Prune tool results only when the cache went cold since `last_api_call_ms`.
+///
+/// Returns `None` while the cache is warm (or before the first call), so the
+/// request resends the cached prefix unchanged and keeps hitting the cache.
+#[must_use]
+pub fn prune_if_cache_cold(
+    messages: &[AnthropicMessageParam],
+    last_api_call_ms: u64,
+    ttl_ms: u64,
+    config: PruneConfig,
+) -> Option<Vec<AnthropicMessageParam>> {
+    is_cache_cold(last_api_call_ms, ttl_ms)
+        .then(|| prune_tool_results_for_recache(messages, config))
+}
+
+fn is_tool_result(block: &Value) -> bool {
+    block.get("type").and_then(Value::as_str) == Some("tool_result")
+}
+
 /// Prune large `tool_result` content blocks in a user message.
-fn prune_user_message_tool_results(msg: &AnthropicMessageParam) -> AnthropicMessageParam {
+///
+/// `first_ordinal` is the global index of this message's first tool result;
+/// tool results whose ordinal reaches `protected_from` are left untouched.
+fn prune_user_message_tool_results(
+    msg: &AnthropicMessageParam,
+    first_ordinal: usize,
+    protected_from: usize,
+) -> AnthropicMessageParam {
+    let mut ordinal = first_ordinal;
     let pruned_content: Vec<Value> = msg
         .content
         .iter()
         .map(|block| {
-            if block.get("type").and_then(Value::as_str) != Some("tool_result") {
+            if !is_tool_result(block) {
+                return block.clone();
+            }
+            let protected = ordinal >= protected_from;
+            ordinal += 1;
+            if protected {
                 return block.clone();
             }
             let content = &block["content"];
@@ -137,6 +210,13 @@ mod tests {
         }
     }
 
+    fn turns(recent_turns: usize) -> PruneConfig {
+        PruneConfig {
+            recent_turns,
+            ..PruneConfig::default()
+        }
+    }
+
     fn tool_result_block(id: &str, content: &str) -> Value {
         json!({
             "type": "tool_result",
@@ -201,7 +281,7 @@ mod tests {
             user_msg(vec![json!("hello")]),
             assistant_msg(vec![json!({"type": "text", "text": "hi"})]),
         ];
-        let result = prune_tool_results_for_recache(&messages, 3);
+        let result = prune_tool_results_for_recache(&messages, turns(3));
         assert_eq!(result.len(), 2);
     }
 
@@ -224,7 +304,7 @@ mod tests {
             user_msg(vec![large_tool_result_block("t5")]),
             assistant_msg(vec![json!({"type": "text", "text": "r5"})]),
         ];
-        let result = prune_tool_results_for_recache(&messages, 3);
+        let result = prune_tool_results_for_recache(&messages, turns(3));
         assert_eq!(result.len(), 10);
 
         // Turn 1 user message (index 0) should be pruned
@@ -261,7 +341,7 @@ mod tests {
             user_msg(vec![json!("user4")]),
             assistant_msg(vec![json!({"type": "text", "text": "r4"})]),
         ];
-        let result = prune_tool_results_for_recache(&messages, 3);
+        let result = prune_tool_results_for_recache(&messages, turns(3));
 
         // Turn 1 capability result is small — NOT pruned
         let content = result[0].content[0]["content"].as_str().unwrap();
@@ -280,7 +360,7 @@ mod tests {
             user_msg(vec![json!("u4")]),
             assistant_msg(vec![json!({"type": "text", "text": "r4"})]),
         ];
-        let result = prune_tool_results_for_recache(&messages, 3);
+        let result = prune_tool_results_for_recache(&messages, turns(3));
 
         // First user message has large text block, not tool_result — NOT pruned
         assert_eq!(result[0].content[0]["type"], "text");
@@ -288,10 +368,97 @@ mod tests {
 
     #[test]
     fn prune_empty_messages() {
-        let result = prune_tool_results_for_recache(&[], 3);
+        let result = prune_tool_results_for_recache(&[], turns(3));
         assert!(result.is_empty());
     }
 
+    fn old_tool_results_then_chat() -> Vec<AnthropicMessageParam> {
+        vec![
+            // Turns 1-3 (old) each carry a large tool result
+            user_msg(vec![large_tool_result_block("t1")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r1"})]),
+            user_msg(vec![large_tool_result_block("t2")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r2"})]),
+            user_msg(vec![large_tool_result_block("t3")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r3"})]),
+            // Turns 4-6 (recent) are plain chat
+            user_msg(vec![json!("u4")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r4"})]),
+            user_msg(vec![json!("u5")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r5"})]),
+            user_msg(vec![json!("u6")]),
+            assistant_msg(vec![json!({"type": "text", "text": "r6"})]),
+        ]
+    }
+
+    fn is_pruned(msg: &AnthropicMessageParam) -> bool {
+        msg.content[0]["content"].to_string().contains("pruned")
+    }
+
+    #[test]
+    fn prune_default_config_preserves_latest_tool_result() {
+        let result =
+            prune_tool_results_for_recache(&old_tool_results_then_chat(), PruneConfig::default());
+        assert!(is_pruned(&result[0]));
+        assert!(is_pruned(&result[2]));
+        assert!(!is_pruned(&result[4]), "latest tool result must survive");
+    }
+
+    #[test]
+    fn prune_preserves_recent_n_tool_results_when_cold() {
+        let config = PruneConfig {
+            recent_turns: 3,
+            preserve_recent_n: 2,
+        };
+        let result = prune_tool_results_for_recache(&old_tool_results_then_chat(), config);
+        assert!(is_pruned(&result[0]));
+        assert!(!is_pruned(&result[2]));
+        assert!(!is_pruned(&result[4]));
+    }
+
+    #[test]
+    fn prune_preserve_recent_zero_prunes_all_old_tool_results() {
+        let config = PruneConfig {
+            recent_turns: 3,
+            preserve_recent_n: 0,
+        };
+        let result = prune_tool_results_for_recache(&old_tool_results_then_chat(), config);
+        assert!(is_pruned(&result[0]));
+        assert!(is_pruned(&result[2]));
+        assert!(is_pruned(&result[4]));
+    }
+
+    #[test]
+    fn prune_nothing_when_cache_warm() {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let messages = old_tool_results_then_chat();
+        let warm = prune_if_cache_cold(
+            &messages,
+            now_ms - 1000,
+            DEFAULT_TTL_MS,
+            PruneConfig::default(),
+        );
+        assert!(
+            warm.is_none(),
+            "a warm cache must resend the prefix unchanged"
+        );
+        let first_request =
+            prune_if_cache_cold(&messages, 0, DEFAULT_TTL_MS, PruneConfig::default());
+        assert!(first_request.is_none());
+
+        let cold = prune_if_cache_cold(
+            &messages,
+            now_ms - 6 * 60 * 1000,
+            DEFAULT_TTL_MS,
+            PruneConfig::default(),
+        )
+        .expect("a cold cache prunes");
+        assert!(is_pruned(&cold[0]));
+    }
+
     #[test]
     fn prune_exactly_at_boundary() {
         // 3 turns, preserve 3 → nothing pruned
@@ -303,7 +470,7 @@ mod tests {
             user_msg(vec![large_tool_result_block("t3")]),
             assistant_msg(vec![json!({"type": "text", "text": "r3"})]),
         ];
-        let result = prune_tool_results_for_recache(&messages, 3);
+        let result = prune_tool_results_for_recache(&messages, turns(3));
 
         // All capability results preserved (exactly 3 turns, preserve 3)
         let content = result[0].content[0]["content"].to_string();
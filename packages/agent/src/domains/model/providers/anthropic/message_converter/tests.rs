@@ -13,6 +13,8 @@ fn simple_context() -> Context {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     }
 }
 
@@ -11,16 +11,17 @@
 //! |------------------------|---------|
 //! | [`provider`]           | [`AnthropicProvider`] — implements the shared `Provider` trait ([`crate::domains::model::providers::shared::provider`]); stream orchestration, retry, caching |
 //! | [`message_converter`]  | `Vec<Message>` → `messages`+`system` blocks; Anthropic `tool_use` / `tool_result` provider blocks, thinking blocks, content-block ordering |
-//! | [`stream_handler`]     | Anthropic SSE (`message_start`, `content_block_*`, `message_delta`, `message_stop`) → `StreamEvent` ([`crate::shared::protocol::events`]) |
-//! | [`cache_pruning`]      | Remove the oldest `cache_control` marker(s) when the 4-breakpoint cap is hit; preserves the system prompt marker |
+//! | [`stream_handler`]     | Anthropic SSE (`message_start`, `content_block_*`, `message_delta`, `message_stop`) → `StreamEvent` ([`crate::shared::protocol::events`]); reports prompt-cache hit ratio on `message_stop` |
+//! | [`cache_pruning`]      | Cold-cache detection; prunes large old `tool_result` content before re-caching, bounded by `PruneConfig` (recent turns and the newest `preserve_recent_n` tool results survive) |
 //! | [`message_sanitizer`]  | Drop empty assistant messages and normalise internal capability-result ordering before provider conversion |
 //! | [`types`]              | [`AnthropicAuth`] (ApiKey / Oauth / ClaudeAgentSdk), [`AnthropicConfig`], [`AnthropicProviderSettings`] |
 //!
 //! ## Invariants
 //!
 //! - Cache breakpoints are capped at 4 per request
-//!   (Anthropic API limit); [`cache_pruning`] strips the oldest when the
-//!   cap would be exceeded. The system-prompt marker is permanent.
+//!   (Anthropic API limit). The system-prompt marker is permanent.
+//! - Cold-cache pruning in [`cache_pruning`] never touches the most recent
+//!   `preserve_recent_n` tool results (default 1).
 //! - Provider-wire tool blocks use Anthropic's canonical `tool_use` and
 //!   `tool_result` shape; internal messages keep Tron capability-invocation names.
 //! - v1beta (ApiKey) rejects unknown fields; v1internal (OAuth / SDK)
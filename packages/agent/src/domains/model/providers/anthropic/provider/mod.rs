@@ -13,12 +13,12 @@ use tracing::{debug, error, info, instrument};
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
+use crate::domains::model::routing::models::output_budget::clamp_output_tokens;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
-use super::cache_pruning::{
-    DEFAULT_RECENT_TURNS, DEFAULT_TTL_MS, is_cache_cold, prune_tool_results_for_recache,
-};
+use super::cache_pruning::{DEFAULT_TTL_MS, PruneConfig, prune_if_cache_cold};
 use super::message_converter::convert_messages;
 use super::message_sanitizer::sanitize_messages;
 use super::stream_handler::{create_stream_state, process_sse_event};
@@ -234,14 +234,17 @@ impl AnthropicProvider {
         Some(json!({ "effort": effort.as_str() }))
     }
 
-    /// Calculate `max_tokens` for the request.
+    /// Calculate `max_tokens` for the request: options → config → model
+    /// registry default, clamped to the model's registry maximum.
     fn calculate_max_tokens(&self, options: &ProviderStreamOptions) -> u32 {
-        options.max_tokens.unwrap_or_else(|| {
-            self.config.max_tokens.unwrap_or_else(|| {
+        let requested = options
+            .requested_output_tokens()
+            .or(self.config.max_tokens)
+            .unwrap_or_else(|| {
                 get_claude_model(&self.config.model)
                     .map_or(DEFAULT_MAX_OUTPUT_TOKENS, |m| m.max_output)
-            })
-        })
+            });
+        clamp_output_tokens(&self.config.model, requested)
     }
 
     /// Apply cache control to the last user message (Breakpoint 4: 5m TTL).
@@ -290,9 +293,14 @@ impl AnthropicProvider {
 
         // Cache cold detection and pruning
         if self.last_api_call_ms > 0 {
-            if is_cache_cold(self.last_api_call_ms, DEFAULT_TTL_MS) {
+            if let Some(pruned) = prune_if_cache_cold(
+                &messages,
+                self.last_api_call_ms,
+                DEFAULT_TTL_MS,
+                PruneConfig::default(),
+            ) {
                 let msg_count = messages.len();
-                messages = prune_tool_results_for_recache(&messages, DEFAULT_RECENT_TURNS);
+                messages = pruned;
                 info!(
                     elapsed_ms = %now_ms().saturating_sub(self.last_api_call_ms),
                     message_count = msg_count,
@@ -326,6 +334,8 @@ impl AnthropicProvider {
             "Sending Anthropic request"
         );
 
+        wire_log::log_request("anthropic", &url, &headers, &body);
+
         let response = self
             .client
             .post(&url)
@@ -336,6 +346,7 @@ impl AnthropicProvider {
             .map_err(ProviderError::Http)?;
 
         let status = response.status();
+        wire_log::log_response("anthropic", status.as_u16(), response.headers());
         if !status.is_success() {
             let retry_after = response
                 .headers()
@@ -343,6 +354,7 @@ impl AnthropicProvider {
                 .and_then(|v| v.to_str().ok())
                 .and_then(crate::shared::foundation::retry::parse_retry_after_header);
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("anthropic", &body_text);
             let err_info = crate::domains::model::providers::shared::error_parsing::parse_api_error(
                 &body_text,
                 status.as_u16(),
@@ -405,8 +417,13 @@ impl Provider for AnthropicProvider {
     ) -> ProviderResult<ProviderAuditPayload> {
         let sanitized = sanitize_messages(context.messages.to_vec());
         let mut messages = convert_messages(&sanitized);
-        if self.last_api_call_ms > 0 && is_cache_cold(self.last_api_call_ms, DEFAULT_TTL_MS) {
-            messages = prune_tool_results_for_recache(&messages, DEFAULT_RECENT_TURNS);
+        if let Some(pruned) = prune_if_cache_cold(
+            &messages,
+            self.last_api_call_ms,
+            DEFAULT_TTL_MS,
+            PruneConfig::default(),
+        ) {
+            messages = pruned;
         }
         Self::apply_cache_to_last_user_message(&mut messages);
         serde_json::to_value(self.build_request(context, options, messages))
@@ -543,6 +543,31 @@ fn max_tokens_from_model() {
     assert_eq!(provider.calculate_max_tokens(&options), 128_000); // Opus 4.6
 }
 
+#[test]
+fn max_output_tokens_sets_request_max_tokens() {
+    let provider = AnthropicProvider::new(api_key_config());
+    let options = ProviderStreamOptions {
+        max_tokens: Some(8000),
+        max_output_tokens: Some(2048),
+        ..Default::default()
+    };
+    let request = provider.build_request(&Context::default(), &options, Vec::new());
+    let body = serde_json::to_value(&request).expect("request serializes");
+    assert_eq!(body["max_tokens"], 2048);
+}
+
+#[test]
+fn max_output_tokens_clamped_to_model_max() {
+    let provider = AnthropicProvider::new(api_key_config());
+    let options = ProviderStreamOptions {
+        max_output_tokens: Some(1_000_000),
+        ..Default::default()
+    };
+    let request = provider.build_request(&Context::default(), &options, Vec::new());
+    let body = serde_json::to_value(&request).expect("request serializes");
+    assert_eq!(body["max_tokens"], 128_000); // Opus 4.6
+}
+
 // ── Request building ────────────────────────────────────────────────
 
 #[test]
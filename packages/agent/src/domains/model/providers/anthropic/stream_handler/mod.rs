@@ -63,6 +63,24 @@ impl Default for StreamState {
     }
 }
 
+impl StreamState {
+    /// Fraction of the prompt served from the prompt cache.
+    ///
+    /// Computed as `cache_read / (cache_read + cache_creation + input)`, where
+    /// `input` is Anthropic's uncached input bucket. Returns `None` before any
+    /// prompt tokens have been reported.
+    #[must_use]
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        let prompt_tokens =
+            self.cache_read_tokens + self.cache_creation_tokens + self.acc.input_tokens;
+        if prompt_tokens == 0 {
+            return None;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        Some(self.cache_read_tokens as f64 / prompt_tokens as f64)
+    }
+}
+
 /// Type of content block being accumulated.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BlockType {
@@ -220,11 +238,31 @@ pub fn process_sse_event(event: &AnthropicSseEvent, state: &mut StreamState) ->
             state.stop_reason.clone_from(&delta.stop_reason);
             if let Some(u) = usage {
                 state.acc.output_tokens = u.output_tokens;
+                if let Some(created) = u.cache_creation_input_tokens {
+                    state.cache_creation_tokens = created;
+                }
+                if let Some(read) = u.cache_read_input_tokens {
+                    state.cache_read_tokens = read;
+                }
             }
             vec![]
         }
 
         AnthropicSseEvent::MessageStop => {
+            if let Some(ratio) = state.cache_hit_ratio() {
+                debug!(
+                    cache_read = state.cache_read_tokens,
+                    cache_write = state.cache_creation_tokens,
+                    input_tokens = state.acc.input_tokens,
+                    cache_hit_ratio = ratio,
+                    "[CACHE] message_stop"
+                );
+                metrics::histogram!(
+                    "provider_cache_hit_ratio",
+                    "provider" => state.provider_type.as_str()
+                )
+                .record(ratio);
+            }
             let done_event = build_done_event(state);
             vec![done_event]
         }
@@ -349,6 +387,7 @@ fn build_done_event(state: &mut StreamState) -> StreamEvent {
                     + state.cache_read_tokens
                     + state.cache_creation_tokens,
             ),
+            cache_hit_ratio: state.cache_hit_ratio(),
             provider_type: Some(state.provider_type),
             ..Default::default()
         })
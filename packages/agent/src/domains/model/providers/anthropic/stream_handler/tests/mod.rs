@@ -5,6 +5,7 @@ use crate::domains::model::providers::anthropic::types::{
     SseCacheCreation, SseError, SseMessage, SseMessageDelta, SseUsage, SseUsageDelta,
 };
 
+mod stream_handler_cache_hit_ratio_tests;
 mod stream_handler_content_block_start_tests;
 
 fn usage(input: u64, output: u64, cache_create: u64, cache_read: u64) -> SseUsage {
@@ -346,7 +347,10 @@ fn message_delta_stop_reason() {
         delta: SseMessageDelta {
             stop_reason: Some("end_turn".into()),
         },
-        usage: Some(SseUsageDelta { output_tokens: 42 }),
+        usage: Some(SseUsageDelta {
+            output_tokens: 42,
+            ..Default::default()
+        }),
     };
     let events = process_sse_event(&event, &mut state);
     assert!(events.is_empty());
@@ -548,7 +552,10 @@ fn full_text_stream() {
             delta: SseMessageDelta {
                 stop_reason: Some("end_turn".into()),
             },
-            usage: Some(SseUsageDelta { output_tokens: 10 }),
+            usage: Some(SseUsageDelta {
+                output_tokens: 10,
+                ..Default::default()
+            }),
         },
         &mut state,
     );
@@ -661,7 +668,10 @@ fn full_thinking_then_text_stream() {
             delta: SseMessageDelta {
                 stop_reason: Some("end_turn".into()),
             },
-            usage: Some(SseUsageDelta { output_tokens: 20 }),
+            usage: Some(SseUsageDelta {
+                output_tokens: 20,
+                ..Default::default()
+            }),
         },
         &mut state,
     );
@@ -745,7 +755,10 @@ fn full_capability_invocation_stream() {
             delta: SseMessageDelta {
                 stop_reason: Some("tool_use".into()),
             },
-            usage: Some(SseUsageDelta { output_tokens: 30 }),
+            usage: Some(SseUsageDelta {
+                output_tokens: 30,
+                ..Default::default()
+            }),
         },
         &mut state,
     );
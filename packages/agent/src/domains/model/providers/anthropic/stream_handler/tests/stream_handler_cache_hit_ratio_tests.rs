@@ -0,0 +1,94 @@
+use super::super::*;
+use crate::domains::model::providers::anthropic::types::{
+    AnthropicSseEvent, SseMessage, SseMessageDelta, SseUsageDelta,
+};
+use crate::shared::protocol::events::StreamEvent;
+
+use super::usage;
+
+#[test]
+fn message_delta_cache_usage_overrides_message_start() {
+    let mut state = create_stream_state();
+    let _ = process_sse_event(
+        &AnthropicSseEvent::MessageStart {
+            message: SseMessage {
+                id: None,
+                model: None,
+                stop_reason: None,
+                usage: usage(10, 0, 0, 0),
+            },
+        },
+        &mut state,
+    );
+    let _ = process_sse_event(
+        &AnthropicSseEvent::MessageDelta {
+            delta: SseMessageDelta { stop_reason: None },
+            usage: Some(SseUsageDelta {
+                output_tokens: 5,
+                cache_creation_input_tokens: Some(30),
+                cache_read_input_tokens: Some(60),
+            }),
+        },
+        &mut state,
+    );
+    assert_eq!(state.cache_creation_tokens, 30);
+    assert_eq!(state.cache_read_tokens, 60);
+}
+
+#[test]
+fn cache_hit_ratio_none_without_prompt_tokens() {
+    let state = create_stream_state();
+    assert_eq!(state.cache_hit_ratio(), None);
+}
+
+#[test]
+fn cache_hit_ratio_from_synthetic_sse_sequence() {
+    let mut state = create_stream_state();
+    let events = [
+        AnthropicSseEvent::MessageStart {
+            message: SseMessage {
+                id: Some("msg_cache".into()),
+                model: Some("claude-opus-4-6".into()),
+                stop_reason: None,
+                usage: usage(100, 1, 100, 800),
+            },
+        },
+        AnthropicSseEvent::MessageDelta {
+            delta: SseMessageDelta {
+                stop_reason: Some("end_turn".into()),
+            },
+            usage: Some(SseUsageDelta {
+                output_tokens: 12,
+                ..Default::default()
+            }),
+        },
+        AnthropicSseEvent::MessageStop,
+    ];
+    let mut done = None;
+    for event in &events {
+        done = process_sse_event(event, &mut state)
+            .into_iter()
+            .last()
+            .or(done);
+    }
+
+    let ratio = state.cache_hit_ratio().unwrap();
+    assert!((ratio - 0.8).abs() < f64::EPSILON);
+    match done {
+        Some(StreamEvent::Done { message, .. }) => {
+            let usage = message.token_usage.unwrap();
+            assert_eq!(usage.cache_read_tokens, Some(800));
+            assert_eq!(usage.cache_creation_tokens, Some(100));
+            assert_eq!(usage.cache_hit_ratio, Some(ratio));
+        }
+        _ => panic!("expected Done"),
+    }
+}
+
+#[test]
+fn cache_hit_ratio_zero_on_cold_cache() {
+    let mut state = create_stream_state();
+    state.acc.input_tokens = 50;
+    state.cache_creation_tokens = 950;
+    assert_eq!(state.cache_hit_ratio(), Some(0.0));
+}
@@ -174,11 +174,20 @@ pub struct SseMessageDelta {
 }
 
 /// Usage delta in `message_delta`.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// Anthropic may repeat the cumulative cache buckets here; when present they
+/// supersede the values reported in `message_start`.
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct SseUsageDelta {
     /// Output tokens.
     #[serde(default)]
     pub output_tokens: u64,
+    /// Cumulative cache creation tokens, when reported.
+    #[serde(default)]
+    pub cache_creation_input_tokens: Option<u64>,
+    /// Cumulative cache read tokens, when reported.
+    #[serde(default)]
+    pub cache_read_input_tokens: Option<u64>,
 }
 
 /// Error in SSE `error` event.
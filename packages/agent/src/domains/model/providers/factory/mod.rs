@@ -39,6 +39,8 @@ pub struct DefaultProviderFactory {
     kimi_base_url: Option<String>,
     /// Ollama base URL override from settings.
     ollama_base_url: Option<String>,
+    /// `OpenAI` reasoning summary floor from settings.
+    openai_min_reasoning_summary_chars: Option<usize>,
     /// Shared HTTP client — connection pool reused across all providers.
     http_client: reqwest::Client,
 }
@@ -64,6 +66,11 @@ impl DefaultProviderFactory {
             minimax_base_url: settings.api.minimax.as_ref().map(|m| m.base_url.clone()),
             kimi_base_url: settings.api.kimi.as_ref().map(|k| k.base_url.clone()),
             ollama_base_url: settings.api.ollama.as_ref().map(|o| o.base_url.clone()),
+            openai_min_reasoning_summary_chars: settings
+                .api
+                .openai_codex
+                .as_ref()
+                .map(|o| o.min_reasoning_summary_chars),
             http_client,
         }
     }
@@ -244,8 +251,10 @@ impl DefaultProviderFactory {
             temperature: None,
             base_url: None,
             reasoning_effort: None,
-            provider_settings:
-                crate::domains::model::providers::openai::types::OpenAIApiSettings::default(),
+            provider_settings: crate::domains::model::providers::openai::types::OpenAIApiSettings {
+                min_reasoning_summary_chars: self.openai_min_reasoning_summary_chars,
+                ..Default::default()
+            },
         };
         Ok(Arc::new(
             crate::domains::model::providers::openai::provider::OpenAIProvider::with_client(
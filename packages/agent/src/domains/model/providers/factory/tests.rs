@@ -290,6 +290,22 @@ fn factory_captures_ollama_base_url() {
     );
 }
 
+#[test]
+fn factory_captures_openai_min_reasoning_summary_chars() {
+    let mut settings = crate::domains::settings::TronSettings::default();
+    assert!(
+        DefaultProviderFactory::new(&settings)
+            .openai_min_reasoning_summary_chars
+            .is_none()
+    );
+    settings.api.openai_codex = Some(crate::domains::settings::OpenAiCodexApiSettings {
+        min_reasoning_summary_chars: 0,
+        ..Default::default()
+    });
+    let factory = DefaultProviderFactory::new(&settings);
+    assert_eq!(factory.openai_min_reasoning_summary_chars, Some(0));
+}
+
 #[test]
 fn factory_ollama_base_url_none_by_default() {
     let settings = crate::domains::settings::TronSettings::default();
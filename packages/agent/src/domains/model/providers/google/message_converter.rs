@@ -302,6 +302,8 @@ mod tests {
             agent_state_context: None,
             memory_prompt_context: None,
             server_origin: None,
+            system_context_token_ceiling: None,
+            pinned_context_blocks: Vec::new(),
         }
     }
 
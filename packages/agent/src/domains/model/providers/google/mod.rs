@@ -12,7 +12,7 @@
 //! |-----------------------|---------|
 //! | [`provider`]          | [`GoogleProvider`] — implements the shared `Provider` trait ([`crate::domains::model::providers::shared::provider`]); stream orchestration, retry, and tool translation |
 //! | [`message_converter`] | `Vec<Message>` → Gemini `contents` array; handles capability invocations, capability results, and multimodal parts |
-//! | [`stream_handler`]    | Gemini SSE `v1beta/{model}:streamGenerateContent` → `StreamEvent` sequence ([`crate::shared::protocol::events`]) |
+//! | [`stream_handler`]    | Gemini SSE `v1beta/{model}:streamGenerateContent` → `StreamEvent` sequence ([`crate::shared::protocol::events`]); prompt and response safety blocks surface as `StreamEvent::SafetyBlock` |
 //! | [`types`]             | [`GoogleAuth`] (API key), [`GoogleConfig`] (model + generation parameters) |
 //!
 //! ## Invariants
@@ -19,11 +19,15 @@ use async_trait::async_trait;
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
 use tracing::{debug, error, info, instrument, warn};
 
-use crate::domains::auth::credentials::{OAuthTokens, calculate_expires_at, should_refresh};
+use crate::domains::auth::credentials::{
+    OAuthTokens, RefreshPolicy, calculate_expires_at, should_refresh,
+};
 use crate::domains::model::providers::shared::compose_context_parts;
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
+use crate::domains::model::routing::models::output_budget::clamp_output_tokens;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
@@ -181,7 +185,7 @@ impl GoogleProvider {
         };
 
         let mut tokens = token_mutex.lock().await;
-        if should_refresh(&tokens, TOKEN_REFRESH_BUFFER_MS) {
+        if should_refresh(&tokens, RefreshPolicy::new(TOKEN_REFRESH_BUFFER_MS)) {
             let new_tokens =
                 refresh_tokens(&tokens, &self.config.provider_settings, &self.client).await?;
             *tokens = new_tokens;
@@ -250,12 +254,13 @@ impl GoogleProvider {
         let is_gemini3 = is_gemini_3_model(model);
 
         let max_tokens = options
-            .max_tokens
+            .requested_output_tokens()
             .or(self.config.max_tokens)
             .unwrap_or_else(|| {
                 #[allow(clippy::cast_possible_truncation)]
                 get_gemini_model(model).map_or(DEFAULT_MAX_OUTPUT_TOKENS, |m| m.max_output as u32)
             });
+        let max_tokens = clamp_output_tokens(model, max_tokens);
 
         let temperature = if is_gemini3 {
             let temp = options.temperature.or(self.config.temperature);
@@ -404,6 +409,8 @@ impl GoogleProvider {
 
         let url = self.get_api_url("streamGenerateContent");
 
+        wire_log::log_request("google", &url, &headers, &body);
+
         let response = self
             .client
             .post(&url)
@@ -414,6 +421,7 @@ impl GoogleProvider {
             .map_err(ProviderError::Http)?;
 
         let status = response.status();
+        wire_log::log_response("google", status.as_u16(), response.headers());
         if !status.is_success() {
             let retry_after = response
                 .headers()
@@ -421,6 +429,7 @@ impl GoogleProvider {
                 .and_then(|v| v.to_str().ok())
                 .and_then(crate::shared::foundation::retry::parse_retry_after_header);
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("google", &body_text);
             let err_info = crate::domains::model::providers::shared::error_parsing::parse_api_error(
                 &body_text,
                 status.as_u16(),
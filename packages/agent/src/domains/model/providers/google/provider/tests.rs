@@ -153,6 +153,29 @@ fn gen_config_max_tokens_from_model_default() {
     assert_eq!(gc.max_output_tokens, Some(65_536)); // gemini-3-pro-preview default
 }
 
+#[test]
+fn gen_config_max_output_tokens_sets_body_field() {
+    let provider = GoogleProvider::new(oauth_config());
+    let options = ProviderStreamOptions {
+        max_tokens: Some(8192),
+        max_output_tokens: Some(2048),
+        ..Default::default()
+    };
+    let body = serde_json::to_value(provider.build_generation_config(&options)).unwrap();
+    assert_eq!(body["maxOutputTokens"], 2048);
+}
+
+#[test]
+fn gen_config_max_output_tokens_clamped_to_model_max() {
+    let provider = GoogleProvider::new(oauth_config());
+    let options = ProviderStreamOptions {
+        max_output_tokens: Some(1_000_000),
+        ..Default::default()
+    };
+    let body = serde_json::to_value(provider.build_generation_config(&options)).unwrap();
+    assert_eq!(body["maxOutputTokens"], 65_536); // gemini-3-pro-preview max
+}
+
 // ── Thinking config ───────────────────────────────────────────────
 
 #[test]
@@ -258,6 +281,8 @@ fn system_instruction_empty_when_no_context() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let si = GoogleProvider::build_system_instruction(&context);
     assert!(si.is_none());
@@ -273,6 +298,8 @@ fn system_instruction_from_prompt() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let si = GoogleProvider::build_system_instruction(&context).unwrap();
     assert_eq!(si.parts.len(), 1);
@@ -297,6 +324,8 @@ fn oauth_request_body_standard_gemini() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let opts = ProviderStreamOptions::default();
     let gc = provider.build_generation_config(&opts);
@@ -323,6 +352,8 @@ fn request_body_same_format_for_oauth_and_api_key() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
 
     let oauth_gc = oauth_provider.build_generation_config(&ProviderStreamOptions::default());
@@ -351,6 +382,8 @@ fn api_key_request_body() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let opts = ProviderStreamOptions::default();
     let gc = provider.build_generation_config(&opts);
@@ -415,6 +448,8 @@ fn api_key_body_thinking_nested_not_top_level() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let gc = provider.build_generation_config(&ProviderStreamOptions::default());
     let body = provider.build_request_body(&context, &gc);
@@ -448,6 +483,8 @@ fn oauth_body_thinking_nested_not_top_level() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let gc = provider.build_generation_config(&ProviderStreamOptions::default());
     let body = provider.build_request_body(&context, &gc);
@@ -477,6 +514,8 @@ fn api_key_body_no_thinking_for_flash() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let gc = provider.build_generation_config(&ProviderStreamOptions::default());
     let body = provider.build_request_body(&context, &gc);
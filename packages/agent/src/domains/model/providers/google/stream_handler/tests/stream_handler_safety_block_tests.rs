@@ -0,0 +1,124 @@
+use super::super::*;
+use crate::domains::model::providers::google::types::{GeminiCandidate, HarmCategory};
+
+use super::empty_chunk;
+
+#[test]
+fn finish_safety_emits_safety_block() {
+    let chunk = GeminiStreamChunk {
+        candidates: Some(vec![GeminiCandidate {
+            content: None,
+            finish_reason: Some("SAFETY".into()),
+            safety_ratings: Some(vec![SafetyRating {
+                category: HarmCategory::Harassment,
+                probability: HarmProbability::High,
+            }]),
+        }]),
+        ..empty_chunk()
+    };
+    let mut state = create_stream_state();
+    let events = process_stream_chunk(&chunk, &mut state);
+    let block_event = events
+        .iter()
+        .find(|e| matches!(e, StreamEvent::SafetyBlock { .. }));
+    assert!(block_event.is_some());
+}
+
+#[test]
+fn finish_safety_without_high_ratings_still_surfaces_block() {
+    let chunk: GeminiStreamChunk = serde_json::from_value(serde_json::json!({
+        "candidates": [{
+            "finishReason": "SAFETY",
+            "safetyRatings": [
+                {"category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "LOW"}
+            ]
+        }]
+    }))
+    .unwrap();
+    let mut state = create_stream_state();
+    let events = process_stream_chunk(&chunk, &mut state);
+
+    match &events[0] {
+        StreamEvent::SafetyBlock {
+            blocked_categories,
+            error,
+        } => {
+            assert_eq!(blocked_categories, &vec!["DangerousContent".to_string()]);
+            assert!(error.contains("Response blocked by safety filter (SAFETY)"));
+        }
+        other => panic!("expected SafetyBlock, got {other:?}"),
+    }
+    match events.last() {
+        Some(StreamEvent::Done { stop_reason, .. }) => assert_eq!(stop_reason, "refusal"),
+        other => panic!("expected Done, got {other:?}"),
+    }
+}
+
+#[test]
+fn prompt_feedback_block_reason_emits_safety_block() {
+    let chunk: GeminiStreamChunk = serde_json::from_value(serde_json::json!({
+        "promptFeedback": {
+            "blockReason": "SAFETY",
+            "safetyRatings": [
+                {"category": "HARM_CATEGORY_HARASSMENT", "probability": "HIGH"},
+                {"category": "HARM_CATEGORY_HATE_SPEECH", "probability": "NEGLIGIBLE"}
+            ]
+        },
+        "usageMetadata": {"promptTokenCount": 12, "totalTokenCount": 12}
+    }))
+    .unwrap();
+    let mut state = create_stream_state();
+    let events = process_stream_chunk(&chunk, &mut state);
+
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        StreamEvent::SafetyBlock {
+            blocked_categories,
+            error,
+        } => {
+            assert_eq!(blocked_categories, &vec!["Harassment".to_string()]);
+            assert_eq!(
+                error,
+                "Prompt blocked by safety filter (SAFETY): Harassment"
+            );
+        }
+        other => panic!("expected SafetyBlock, got {other:?}"),
+    }
+}
+
+#[test]
+fn prompt_feedback_without_ratings_reports_reason() {
+    let chunk: GeminiStreamChunk = serde_json::from_value(serde_json::json!({
+        "promptFeedback": {"blockReason": "BLOCKLIST"}
+    }))
+    .unwrap();
+    let mut state = create_stream_state();
+    let events = process_stream_chunk(&chunk, &mut state);
+
+    match &events[0] {
+        StreamEvent::SafetyBlock {
+            blocked_categories,
+            error,
+        } => {
+            assert!(blocked_categories.is_empty());
+            assert_eq!(error, "Prompt blocked by safety filter (BLOCKLIST)");
+        }
+        other => panic!("expected SafetyBlock, got {other:?}"),
+    }
+}
+
+#[test]
+fn prompt_feedback_without_block_reason_is_ignored() {
+    let chunk: GeminiStreamChunk = serde_json::from_value(serde_json::json!({
+        "promptFeedback": {"safetyRatings": []},
+        "candidates": [{"content": {"parts": [{"text": "ok"}]}}]
+    }))
+    .unwrap();
+    let mut state = create_stream_state();
+    let events = process_stream_chunk(&chunk, &mut state);
+    assert!(
+        !events
+            .iter()
+            .any(|e| matches!(e, StreamEvent::SafetyBlock { .. }))
+    );
+}
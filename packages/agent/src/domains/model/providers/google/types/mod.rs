@@ -370,6 +370,21 @@ pub struct GeminiStreamChunk {
     /// Error (if any).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<GeminiApiError>,
+    /// Prompt-level feedback, set when the prompt itself was blocked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_feedback: Option<PromptFeedback>,
+}
+
+/// Prompt-level safety feedback.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptFeedback {
+    /// Why the prompt was blocked (e.g., `SAFETY`, `BLOCKLIST`), if it was.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_reason: Option<String>,
+    /// Safety ratings for the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_ratings: Option<Vec<SafetyRating>>,
 }
 
 /// A response candidate.
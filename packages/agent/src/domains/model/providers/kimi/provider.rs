@@ -12,6 +12,8 @@ use crate::domains::model::providers::shared::compose_context_parts;
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
+use crate::domains::model::routing::models::output_budget::clamp_output_tokens;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
@@ -79,14 +81,17 @@ impl KimiProvider {
         Some(parts.join("\n\n"))
     }
 
-    /// Calculate `max_tokens`: options → config → model registry default.
+    /// Calculate `max_tokens`: options → config → model registry default,
+    /// clamped to the model's registry maximum.
     fn calculate_max_tokens(&self, options: &ProviderStreamOptions) -> u32 {
-        options.max_tokens.unwrap_or_else(|| {
-            self.config.max_tokens.unwrap_or_else(|| {
+        let requested = options
+            .requested_output_tokens()
+            .or(self.config.max_tokens)
+            .unwrap_or_else(|| {
                 get_kimi_model(&self.config.model)
                     .map_or(DEFAULT_MAX_OUTPUT_TOKENS, |m| m.max_output)
-            })
-        })
+            });
+        clamp_output_tokens(&self.config.model, requested)
     }
 
     /// Check if the current model supports thinking.
@@ -173,6 +178,8 @@ impl KimiProvider {
             "Sending Kimi request"
         );
 
+        wire_log::log_request("kimi", &url, &headers, &body);
+
         let response = self
             .client
             .post(&url)
@@ -183,6 +190,7 @@ impl KimiProvider {
             .map_err(ProviderError::Http)?;
 
         let status = response.status();
+        wire_log::log_response("kimi", status.as_u16(), response.headers());
         if !status.is_success() {
             let retry_after = response
                 .headers()
@@ -190,6 +198,7 @@ impl KimiProvider {
                 .and_then(|v| v.to_str().ok())
                 .and_then(crate::shared::foundation::retry::parse_retry_after_header);
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("kimi", &body_text);
             let err_info = crate::domains::model::providers::shared::error_parsing::parse_api_error(
                 &body_text,
                 status.as_u16(),
@@ -20,6 +20,8 @@ use crate::domains::model::providers::anthropic::types::{
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
+use crate::domains::model::routing::models::output_budget::clamp_output_tokens;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
@@ -149,14 +151,17 @@ impl MiniMaxProvider {
         }))
     }
 
-    /// Calculate `max_tokens`: options → config → model registry default.
+    /// Calculate `max_tokens`: options → config → model registry default,
+    /// clamped to the model's registry maximum.
     fn calculate_max_tokens(&self, options: &ProviderStreamOptions) -> u32 {
-        options.max_tokens.unwrap_or_else(|| {
-            self.config.max_tokens.unwrap_or_else(|| {
+        let requested = options
+            .requested_output_tokens()
+            .or(self.config.max_tokens)
+            .unwrap_or_else(|| {
                 get_minimax_model(&self.config.model)
                     .map_or(DEFAULT_MAX_OUTPUT_TOKENS, |m| m.max_output)
-            })
-        })
+            });
+        clamp_output_tokens(&self.config.model, requested)
     }
 
     /// Strip image content blocks from messages (`MiniMax` doesn't support images).
@@ -242,6 +247,8 @@ impl MiniMaxProvider {
             "Sending MiniMax request"
         );
 
+        wire_log::log_request("minimax", &url, &headers, &body);
+
         let response = self
             .client
             .post(&url)
@@ -252,6 +259,7 @@ impl MiniMaxProvider {
             .map_err(ProviderError::Http)?;
 
         let status = response.status();
+        wire_log::log_response("minimax", status.as_u16(), response.headers());
         if !status.is_success() {
             let retry_after = response
                 .headers()
@@ -259,6 +267,7 @@ impl MiniMaxProvider {
                 .and_then(|v| v.to_str().ok())
                 .and_then(crate::shared::foundation::retry::parse_retry_after_header);
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("minimax", &body_text);
             let err_info = crate::domains::model::providers::shared::error_parsing::parse_api_error(
                 &body_text,
                 status.as_u16(),
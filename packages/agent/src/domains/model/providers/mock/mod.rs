@@ -0,0 +1,170 @@
+//! Mock provider — scripted model output for end-to-end runtime tests.
+//!
+//! [`MockProvider`] serves a fixed list of turns, one per [`Provider::stream`]
+//! call, and records the [`Context`] of every request so tests can assert what
+//! the agent loop sent back to the model (for example, a capability result
+//! after a tool call). Unlike [`super::replay`], the script is built in code
+//! with the turn helpers below instead of loaded from a transcript file.
+//! Requests beyond the scripted turns fail instead of inventing output.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use futures::stream;
+use serde_json::{Map, Value};
+
+use super::shared::provider::{
+    Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
+};
+use crate::shared::protocol::content::AssistantContent;
+use crate::shared::protocol::events::{AssistantMessage, StreamEvent};
+use crate::shared::protocol::messages::{
+    CapabilityInvocationDraft, Context, Provider as ProviderType, TokenUsage,
+};
+
+/// Model id reported by [`MockProvider`] unless overridden.
+pub const MOCK_MODEL: &str = "mock-model";
+
+/// [`Provider`] that serves scripted turns in order.
+pub struct MockProvider {
+    model: String,
+    turns: Vec<Vec<StreamEvent>>,
+    next_turn: AtomicUsize,
+    requests: Mutex<Vec<Context>>,
+}
+
+impl MockProvider {
+    /// Serve `turns` in order, one per provider request.
+    #[must_use]
+    pub fn new(turns: Vec<Vec<StreamEvent>>) -> Self {
+        Self {
+            model: MOCK_MODEL.to_owned(),
+            turns,
+            next_turn: AtomicUsize::new(0),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Script one capability invocation followed by a final text answer.
+    #[must_use]
+    pub fn tool_call_then_answer(
+        invocation_id: &str,
+        name: &str,
+        arguments: Map<String, Value>,
+        answer: &str,
+    ) -> Self {
+        Self::new(vec![
+            tool_call_turn(invocation_id, name, arguments),
+            text_turn(answer),
+        ])
+    }
+
+    /// Report `model` instead of [`MOCK_MODEL`].
+    #[must_use]
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Contexts received so far, in request order.
+    pub fn requests(&self) -> Vec<Context> {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl Provider for MockProvider {
+    fn provider_type(&self) -> ProviderType {
+        ProviderType::Anthropic
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn stream(
+        &self,
+        context: &Context,
+        _options: &ProviderStreamOptions,
+    ) -> ProviderResult<StreamEventStream> {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(context.clone());
+        let turn = self.next_turn.fetch_add(1, Ordering::SeqCst);
+        let Some(events) = self.turns.get(turn) else {
+            return Err(ProviderError::Other {
+                message: format!(
+                    "mock provider script exhausted: request {} but only {} turns scripted",
+                    turn + 1,
+                    self.turns.len()
+                ),
+            });
+        };
+        Ok(Box::pin(stream::iter(events.clone().into_iter().map(Ok))))
+    }
+}
+
+/// A turn that streams `text` as one delta and ends the run.
+#[must_use]
+pub fn text_turn(text: &str) -> Vec<StreamEvent> {
+    vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta {
+            delta: text.to_owned(),
+        },
+        done(vec![AssistantContent::text(text)], "end_turn"),
+    ]
+}
+
+/// A turn that streams one capability invocation and stops for its result.
+#[must_use]
+pub fn tool_call_turn(
+    invocation_id: &str,
+    name: &str,
+    arguments: Map<String, Value>,
+) -> Vec<StreamEvent> {
+    let arguments_delta = Value::Object(arguments.clone()).to_string();
+    vec![
+        StreamEvent::Start,
+        StreamEvent::CapabilityInvocationDraftStart {
+            invocation_id: invocation_id.to_owned(),
+            name: name.to_owned(),
+        },
+        StreamEvent::CapabilityInvocationDraftDelta {
+            invocation_id: invocation_id.to_owned(),
+            arguments_delta,
+        },
+        StreamEvent::CapabilityInvocationDraftEnd {
+            capability_invocation: CapabilityInvocationDraft::new(invocation_id, name, arguments),
+        },
+        done(Vec::new(), "capability_invocation"),
+    ]
+}
+
+/// Usage reported on every scripted `Done` event.
+#[must_use]
+pub fn mock_usage() -> TokenUsage {
+    TokenUsage {
+        input_tokens: 10,
+        output_tokens: 5,
+        ..TokenUsage::default()
+    }
+}
+
+fn done(content: Vec<AssistantContent>, stop_reason: &str) -> StreamEvent {
+    StreamEvent::Done {
+        message: AssistantMessage {
+            content,
+            token_usage: Some(mock_usage()),
+        },
+        stop_reason: stop_reason.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use futures::StreamExt;
+use serde_json::json;
+
+use super::*;
+
+async fn collect(provider: &MockProvider) -> ProviderResult<Vec<StreamEvent>> {
+    let stream = provider
+        .stream(&Context::default(), &ProviderStreamOptions::default())
+        .await?;
+    stream.collect::<Vec<_>>().await.into_iter().collect()
+}
+
+#[tokio::test]
+async fn serves_scripted_turns_in_order_then_fails() {
+    let arguments = json!({"operation": "observe"}).as_object().unwrap().clone();
+    let provider = MockProvider::tool_call_then_answer("tc-1", "execute", arguments, "done");
+
+    let first = collect(&provider).await.unwrap();
+    assert!(matches!(
+        &first[1],
+        StreamEvent::CapabilityInvocationDraftStart { invocation_id, name }
+            if invocation_id == "tc-1" && name == "execute"
+    ));
+    assert!(matches!(
+        first.last(),
+        Some(StreamEvent::Done { stop_reason, message })
+            if stop_reason == "capability_invocation"
+                && message.token_usage == Some(mock_usage())
+    ));
+
+    let second = collect(&provider).await.unwrap();
+    assert!(matches!(&second[1], StreamEvent::TextDelta { delta } if delta == "done"));
+
+    let error = collect(&provider).await.unwrap_err();
+    assert!(error.to_string().contains("script exhausted"), "{error}");
+    assert_eq!(provider.requests().len(), 3);
+}
+
+#[test]
+fn with_model_overrides_reported_model() {
+    let provider = MockProvider::new(vec![text_turn("hi")]).with_model("claude-test");
+    assert_eq!(provider.model(), "claude-test");
+    assert_eq!(MockProvider::new(Vec::new()).model(), MOCK_MODEL);
+}
@@ -15,7 +15,7 @@
 //! - [`shared::sse`] — Shared SSE line parser for HTTP streaming responses
 //! - [`shared::retry`] — Stream retry with exponential backoff + jitter
 //! - [`crate::domains::model::protocol::capability_parsing`] — Fail-closed JSON parsing for provider capability invocation arguments
-//! - [`shared::context_composition`] — Context part ordering and stable/volatile grouping
+//! - [`shared::context_composition`] — Context part ordering, stable/volatile grouping, and the system-context token ceiling
 //! - [`crate::domains::model::protocol::id_remapping`] — Capability invocation ID format conversion between providers
 //! - [`shared::stream_common`] — Shared [`shared::stream_common::StreamAccumulator`] for delta processing
 //!
@@ -26,6 +26,8 @@
 //! | [`shared`] | Provider-neutral traits, retry, SSE, context composition, stream helpers, and health tracking |
 //! | [`factory`] | Default provider factory and settings/auth capture |
 //! | [`anthropic`], [`google`], [`kimi`], [`minimax`], [`ollama`], [`openai`] | Provider-specific wire protocol implementations |
+//! | `mock` | Test-only scripted provider for deterministic end-to-end turn-loop tests |
+//! | [`replay`] | Deterministic provider that serves recorded transcript turns for debugging replays |
 //! | [`tokens`] | Provider-neutral usage normalization and pricing helpers |
 //!
 //! ## Entry Points
@@ -68,8 +70,11 @@ pub mod factory;
 pub mod google;
 pub mod kimi;
 pub mod minimax;
+#[cfg(test)]
+pub mod mock;
 pub mod ollama;
 pub mod openai;
+pub mod replay;
 pub mod shared;
 
 pub use crate::domains::model::protocol::remap_invocation_id;
@@ -27,6 +27,8 @@ use crate::domains::model::providers::shared::compose_context_parts;
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
+use crate::domains::model::routing::models::output_budget::clamp_output_tokens;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
@@ -89,14 +91,17 @@ impl OllamaProvider {
         })
     }
 
-    /// Calculate `max_tokens`: options → config → model registry default.
+    /// Calculate `max_tokens`: options → config → model registry default,
+    /// clamped to the model's registry maximum.
     fn calculate_max_tokens(&self, options: &ProviderStreamOptions) -> u32 {
-        options.max_tokens.unwrap_or_else(|| {
-            self.config.max_tokens.unwrap_or_else(|| {
+        let requested = options
+            .requested_output_tokens()
+            .or(self.config.max_tokens)
+            .unwrap_or_else(|| {
                 get_ollama_model(&self.config.model)
                     .map_or(DEFAULT_MAX_OUTPUT_TOKENS, |m| m.max_output)
-            })
-        })
+            });
+        clamp_output_tokens(&self.config.model, requested)
     }
 
     /// Check if the current model supports images.
@@ -224,6 +229,8 @@ impl OllamaProvider {
             "Sending Ollama native API request"
         );
 
+        wire_log::log_request("ollama", &url, &headers, &body);
+
         let response = self
             .client
             .post(&url)
@@ -234,8 +241,10 @@ impl OllamaProvider {
             .map_err(|e| Self::map_connection_error(e, &self.config.model))?;
 
         let status = response.status();
+        wire_log::log_response("ollama", status.as_u16(), response.headers());
         if !status.is_success() {
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("ollama", &body_text);
             error!(
                 status = status.as_u16(),
                 body = %body_text,
@@ -324,6 +333,7 @@ fn ndjson_to_event_stream(response: reqwest::Response) -> StreamEventStream {
                     if line.is_empty() {
                         continue;
                     }
+                    wire_log::log_stream_frame(line);
 
                     let chunk: OllamaChatChunk = match serde_json::from_str(line) {
                         Ok(c) => c,
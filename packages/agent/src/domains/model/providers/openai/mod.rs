@@ -11,7 +11,7 @@
 //! |-----------------------|---------|
 //! | [`provider`]          | [`OpenAIProvider`] — implements the shared `Provider` trait ([`crate::domains::model::providers::shared::provider`]); stream, retry, capability invocation parsing |
 //! | [`message_converter`] | `Vec<Message>` → Responses `input` items, tool schema conversion, and single-primitive `execute` instruction text |
-//! | [`stream_handler`]    | OpenAI SSE → `StreamEvent` ([`crate::shared::protocol::events`]); handles output deltas, capability invocations, and terminal events |
+//! | [`stream_handler`]    | OpenAI SSE → `StreamEvent` ([`crate::shared::protocol::events`]); handles output deltas, capability invocations, and terminal events; buffers reasoning summaries per part behind a `min_summary_chars` floor |
 //! | [`types`]             | [`OpenAIAuth`], [`OpenAIConfig`], [`ApiEndpoint`], endpoint-aware model profiles, and Responses wire DTOs split by owned surface |
 //!
 //! ## Invariants
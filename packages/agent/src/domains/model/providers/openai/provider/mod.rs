@@ -29,13 +29,16 @@ use crate::domains::model::providers::shared::provider::ReasoningEffort;
 use crate::domains::model::providers::shared::provider::{
     Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
 };
+use crate::domains::model::providers::shared::wire_log;
 use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::ProviderAuditPayload;
 
 use super::message_converter::{
     convert_to_responses_input, convert_tools_v2, generate_capability_instruction_text,
 };
-use super::stream_handler::{create_stream_state, process_stream_event};
+use super::stream_handler::{
+    create_stream_state, create_stream_state_with_min_summary_chars, process_stream_event,
+};
 use super::types::{
     ApiEndpoint, OpenAIApiSettings, OpenAIAuth, OpenAIConfig, OpenAIModelProfile, ReasoningConfig,
     ResponseTextConfig, ResponsesInputItem, ResponsesRequest, ResponsesSseEvent,
@@ -320,7 +323,10 @@ impl OpenAIProvider {
     #[instrument(skip_all)]
     async fn ensure_valid_tokens(&self) -> ProviderResult<()> {
         let mut tokens = self.tokens.lock().await;
-        if crate::domains::auth::credentials::should_refresh(&tokens, TOKEN_EXPIRY_BUFFER_MS) {
+        if crate::domains::auth::credentials::should_refresh(
+            &tokens,
+            crate::domains::auth::credentials::RefreshPolicy::new(TOKEN_EXPIRY_BUFFER_MS),
+        ) {
             let new_tokens =
                 refresh_tokens(&tokens.refresh_token, &self.provider_settings, &self.client)
                     .await?;
@@ -443,7 +449,9 @@ impl OpenAIProvider {
 
     /// Resolve and clamp max output tokens for the active profile.
     fn resolve_max_output_tokens(&self, options: &ProviderStreamOptions) -> Option<u32> {
-        let requested = options.max_tokens.or(self.config.max_tokens)?;
+        let requested = options
+            .requested_output_tokens()
+            .or(self.config.max_tokens)?;
         let Some(profile) = self.active_profile() else {
             return Some(requested);
         };
@@ -534,6 +542,8 @@ impl OpenAIProvider {
         let request = self.build_request(context, options);
         let url = format!("{}{}", self.base_url, self.api_endpoint.path());
 
+        wire_log::log_request("openai", &url, &headers, &request);
+
         let response = self
             .client
             .post(&url)
@@ -544,6 +554,7 @@ impl OpenAIProvider {
             .map_err(ProviderError::Http)?;
 
         let status = response.status();
+        wire_log::log_response("openai", status.as_u16(), response.headers());
         if !status.is_success() {
             let retry_after = response
                 .headers()
@@ -551,6 +562,7 @@ impl OpenAIProvider {
                 .and_then(|v| v.to_str().ok())
                 .and_then(crate::shared::foundation::retry::parse_retry_after_header);
             let body_text = response.text().await.unwrap_or_default();
+            wire_log::log_response_body("openai", &body_text);
             let err_info = crate::domains::model::providers::shared::error_parsing::parse_api_error(
                 &body_text,
                 status.as_u16(),
@@ -584,7 +596,12 @@ impl OpenAIProvider {
             >(
                 response,
                 &SSE_OPTIONS,
-                create_stream_state(),
+                self.provider_settings
+                    .min_reasoning_summary_chars
+                    .map_or_else(
+                        create_stream_state,
+                        create_stream_state_with_min_summary_chars,
+                    ),
                 process_stream_event,
             ),
         )
@@ -175,7 +175,7 @@ fn should_refresh_when_expired() {
     };
     assert!(crate::domains::auth::credentials::should_refresh(
         &tokens,
-        TOKEN_EXPIRY_BUFFER_MS
+        crate::domains::auth::credentials::RefreshPolicy::new(TOKEN_EXPIRY_BUFFER_MS)
     ));
 }
 
@@ -188,7 +188,7 @@ fn should_refresh_within_buffer() {
     };
     assert!(crate::domains::auth::credentials::should_refresh(
         &tokens,
-        TOKEN_EXPIRY_BUFFER_MS
+        crate::domains::auth::credentials::RefreshPolicy::new(TOKEN_EXPIRY_BUFFER_MS)
     ));
 }
 
@@ -201,7 +201,7 @@ fn should_not_refresh_when_valid() {
     };
     assert!(!crate::domains::auth::credentials::should_refresh(
         &tokens,
-        TOKEN_EXPIRY_BUFFER_MS
+        crate::domains::auth::credentials::RefreshPolicy::new(TOKEN_EXPIRY_BUFFER_MS)
     ));
 }
 
@@ -215,7 +215,7 @@ fn should_refresh_at_exact_boundary() {
     // Shared version uses >=, so at exact boundary it refreshes (safer)
     assert!(crate::domains::auth::credentials::should_refresh(
         &tokens,
-        TOKEN_EXPIRY_BUFFER_MS
+        crate::domains::auth::credentials::RefreshPolicy::new(TOKEN_EXPIRY_BUFFER_MS)
     ));
 }
 
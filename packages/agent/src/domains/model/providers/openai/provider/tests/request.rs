@@ -38,6 +38,37 @@ fn build_request_gpt55_platform_preserves_none_and_platform_verbosity() {
     assert_eq!(request.text.unwrap().verbosity, "medium");
 }
 
+#[test]
+fn build_request_maps_max_output_tokens_into_body() {
+    let provider = OpenAIProvider::new(api_key_config("gpt-5.5"));
+    let request = provider.build_request(
+        &Context::default(),
+        &ProviderStreamOptions {
+            max_tokens: Some(8_000),
+            max_output_tokens: Some(4_096),
+            ..Default::default()
+        },
+    );
+    let body = serde_json::to_value(&request).expect("request serializes");
+
+    assert_eq!(body["max_output_tokens"], 4_096);
+}
+
+#[test]
+fn build_request_clamps_max_output_tokens_to_profile_max() {
+    let provider = OpenAIProvider::new(api_key_config("gpt-5.5"));
+    let request = provider.build_request(
+        &Context::default(),
+        &ProviderStreamOptions {
+            max_output_tokens: Some(1_000_000),
+            ..Default::default()
+        },
+    );
+    let body = serde_json::to_value(&request).expect("request serializes");
+
+    assert_eq!(body["max_output_tokens"], 128_000);
+}
+
 #[test]
 fn build_request_configured_openai_model_preserves_exact_id() {
     let provider = OpenAIProvider::new(api_key_config("gpt-5.2-codex"));
@@ -104,6 +135,8 @@ fn build_request_compiles_primitive_context_into_instructions() {
         agent_state_context: Some("Remember the teardown scorecard.".into()),
         memory_prompt_context: None,
         server_origin: Some("localhost:9847".into()),
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
 
     let request = provider.build_request(&context, &ProviderStreamOptions::default());
@@ -7,12 +7,20 @@
 //! - `response.output_item.added` (`function_call`) → `CapabilityInvocationDraftStart`
 //! - `response.function_call_arguments.delta` → `CapabilityInvocationDraftDelta`
 //! - `response.reasoning_text.delta` → `ThinkingStart` + `ThinkingDelta` (full reasoning)
-//! - `response.reasoning_summary_text.delta` → `ThinkingStart` + `ThinkingDelta` (summary delta)
+//! - `response.reasoning_summary_text.delta` → buffered until the summary part completes
+//! - `response.reasoning_summary_part.done` → `ThinkingStart` + `ThinkingDelta` (consolidated summary)
 //! - `response.completed` → `ThinkingEnd`, `TextEnd`, `CapabilityInvocationDraftEnd`, `Done`
 //!
 //! Delegates text/thinking delta accumulation to [`StreamAccumulator`] from the
 //! shared `stream_common` module. OpenAI-specific reasoning dedup and capability invocation
 //! handling (HashMap-based, with fail-closed provider argument parsing) stays here.
+//!
+//! Reasoning summaries are buffered per summary part to avoid UI flicker from
+//! fragmentary deltas. A completed part shorter than
+//! [`StreamState::min_summary_chars`] is dropped; a part still buffered when
+//! the reasoning item or response completes is flushed as-is. The provider
+//! takes the floor from `api.openaiCodex.minReasoningSummaryChars`, falling
+//! back to [`DEFAULT_MIN_SUMMARY_CHARS`].
 
 use std::collections::{HashMap, HashSet};
 
@@ -25,6 +33,9 @@ use crate::shared::protocol::content::AssistantContent;
 use crate::shared::protocol::events::{AssistantMessage, StreamEvent};
 use crate::shared::protocol::messages::{CapabilityInvocationDraft, TokenUsage};
 
+/// Default minimum length (in chars) of a completed reasoning summary part.
+pub const DEFAULT_MIN_SUMMARY_CHARS: usize = 24;
+
 /// State for tracking accumulated stream content.
 #[derive(Clone, Debug)]
 pub struct StreamState {
@@ -38,6 +49,12 @@ pub struct StreamState {
     pub seen_thinking_texts: HashSet<String>,
     /// Whether we received full reasoning text (vs only summary).
     pub has_reasoning_text: bool,
+    /// Reasoning summary text buffered until its summary part completes.
+    pub summary_buffer: String,
+    /// Whether any reasoning summary deltas were streamed.
+    pub summary_streamed: bool,
+    /// Completed summary parts shorter than this (in chars) are dropped.
+    pub min_summary_chars: usize,
 }
 
 /// State for an individual capability invocation being accumulated.
@@ -54,12 +71,21 @@ pub struct CapabilityInvocationDraftState {
 /// Create a fresh stream state.
 #[must_use]
 pub fn create_stream_state() -> StreamState {
+    create_stream_state_with_min_summary_chars(DEFAULT_MIN_SUMMARY_CHARS)
+}
+
+/// Create a fresh stream state with a custom reasoning-summary verbosity floor.
+#[must_use]
+pub fn create_stream_state_with_min_summary_chars(min_summary_chars: usize) -> StreamState {
     StreamState {
         acc: StreamAccumulator::new(),
         capability_invocations: HashMap::new(),
         capability_argument_failed: false,
         seen_thinking_texts: HashSet::new(),
         has_reasoning_text: false,
+        summary_buffer: String::new(),
+        summary_streamed: false,
+        min_summary_chars,
     }
 }
 
@@ -74,6 +100,7 @@ pub fn process_stream_event(
         SseEventType::OutputItemAdded => handle_output_item_added(event, state),
         SseEventType::OutputItemDone => handle_output_item_done(event, state),
         SseEventType::ReasoningSummaryPartAdded => handle_reasoning_summary_part_added(state),
+        SseEventType::ReasoningSummaryPartDone => flush_reasoning_summary(state, true),
         SseEventType::ReasoningTextDelta => handle_reasoning_text_delta(event, state),
         SseEventType::ReasoningSummaryTextDelta => {
             handle_reasoning_summary_text_delta(event, state)
@@ -154,6 +181,7 @@ fn handle_reasoning_text_delta(
     if let Some(delta) = &event.delta {
         if !state.has_reasoning_text {
             state.has_reasoning_text = true;
+            state.summary_buffer.clear();
             if !state.acc.accumulated_thinking.is_empty() {
                 state.acc.accumulated_thinking.clear();
             }
@@ -164,6 +192,8 @@ fn handle_reasoning_text_delta(
 }
 
 /// Handle `response.reasoning_summary_text.delta` when full reasoning is unavailable.
+///
+/// Deltas are buffered; [`flush_reasoning_summary`] emits them once the part completes.
 fn handle_reasoning_summary_text_delta(
     event: &ResponsesSseEvent,
     state: &mut StreamState,
@@ -171,14 +201,34 @@ fn handle_reasoning_summary_text_delta(
     if state.has_reasoning_text {
         return Vec::new();
     }
-    if let Some(delta) = &event.delta
-        && !state.seen_thinking_texts.contains(delta.as_str())
-    {
-        let _ = state.seen_thinking_texts.insert(delta.clone());
-        state.acc.process_thinking_delta(delta)
-    } else {
-        Vec::new()
+    if let Some(delta) = &event.delta {
+        state.summary_streamed = true;
+        state.summary_buffer.push_str(delta);
     }
+    Vec::new()
+}
+
+/// Emit the buffered reasoning summary as one consolidated thinking delta.
+///
+/// With `apply_floor`, summaries shorter than `min_summary_chars` are dropped.
+/// Without it (the stream ended mid-summary), whatever was buffered is flushed.
+fn flush_reasoning_summary(state: &mut StreamState, apply_floor: bool) -> Vec<StreamEvent> {
+    let summary = std::mem::take(&mut state.summary_buffer);
+    if summary.is_empty() || state.has_reasoning_text {
+        return Vec::new();
+    }
+    if apply_floor && summary.chars().count() < state.min_summary_chars {
+        tracing::debug!(
+            chars = summary.chars().count(),
+            min_summary_chars = state.min_summary_chars,
+            "dropping short OpenAI reasoning summary"
+        );
+        return Vec::new();
+    }
+    if !state.seen_thinking_texts.insert(summary.clone()) {
+        return Vec::new();
+    }
+    state.acc.process_thinking_delta(&summary)
 }
 
 /// Handle `response.function_call_arguments.delta` — accumulate capability invocation arguments.
@@ -238,11 +288,16 @@ fn handle_output_item_done(event: &ResponsesSseEvent, state: &mut StreamState) -
         return events;
     }
 
+    if item.item_type == OutputItemType::Reasoning {
+        events.extend(flush_reasoning_summary(state, false));
+    }
+
     // Only process reasoning items with summary content not already streamed.
     if item.item_type != OutputItemType::Reasoning
         || item.summary.is_none()
         || !state.acc.accumulated_thinking.is_empty()
         || state.has_reasoning_text
+        || state.summary_streamed
     {
         return events;
     }
@@ -306,6 +361,9 @@ fn process_completed_response(
         state.acc.total_tokens = usage.total_tokens;
     }
 
+    // Flush a summary part the stream never closed
+    events.extend(flush_reasoning_summary(state, false));
+
     // Process output items from completed response
     merge_completed_output_items(response, state, &mut events);
 
@@ -383,7 +441,10 @@ fn merge_reasoning_item(
     state: &mut StreamState,
     events: &mut Vec<StreamEvent>,
 ) {
-    if !state.acc.accumulated_thinking.is_empty() || state.has_reasoning_text {
+    if !state.acc.accumulated_thinking.is_empty()
+        || state.has_reasoning_text
+        || state.summary_streamed
+    {
         return;
     }
     if let Some(summary) = &item.summary {
@@ -499,5 +560,9 @@ fn parse_openai_capability_arguments(
 // Tests
 // =============================================================================
 
+#[cfg(test)]
+mod reasoning_summary_tests;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod usage_tests;
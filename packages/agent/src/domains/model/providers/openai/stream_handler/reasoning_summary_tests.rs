@@ -0,0 +1,158 @@
+use super::*;
+use crate::domains::model::providers::openai::types::{
+    OutputContent, OutputItemType, ResponsesOutputItem, ResponsesResponse,
+};
+
+fn reasoning_summary_delta_event(delta: &str) -> ResponsesSseEvent {
+    ResponsesSseEvent {
+        event_type: SseEventType::ReasoningSummaryTextDelta,
+        delta: Some(delta.into()),
+        ..Default::default()
+    }
+}
+
+fn summary_part_done_event() -> ResponsesSseEvent {
+    ResponsesSseEvent {
+        event_type: SseEventType::ReasoningSummaryPartDone,
+        ..Default::default()
+    }
+}
+
+fn completed_event(output: Vec<ResponsesOutputItem>) -> ResponsesSseEvent {
+    ResponsesSseEvent {
+        event_type: SseEventType::Completed,
+        response: Some(ResponsesResponse {
+            id: Some("resp-123".into()),
+            output,
+            usage: None,
+        }),
+        ..Default::default()
+    }
+}
+
+fn reasoning_done_event(summary: &str) -> ResponsesSseEvent {
+    ResponsesSseEvent {
+        event_type: SseEventType::OutputItemDone,
+        item: Some(ResponsesOutputItem {
+            item_type: OutputItemType::Reasoning,
+            summary: Some(vec![OutputContent {
+                content_type: "summary_text".into(),
+                text: Some(summary.into()),
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn thinking_deltas(events: &[StreamEvent]) -> Vec<&str> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            StreamEvent::ThinkingDelta { delta } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn completed_summary_part_emits_one_consolidated_delta() {
+    let mut state = create_stream_state();
+    for delta in ["**Inspecting** ", "the parser ", "for edge cases"] {
+        let events = process_stream_event(&reasoning_summary_delta_event(delta), &mut state);
+        assert!(events.is_empty());
+    }
+
+    let events = process_stream_event(&summary_part_done_event(), &mut state);
+    assert_eq!(events[0], StreamEvent::ThinkingStart);
+    assert_eq!(
+        thinking_deltas(&events),
+        vec!["**Inspecting** the parser for edge cases"]
+    );
+    assert!(state.summary_buffer.is_empty());
+}
+
+#[test]
+fn completed_summary_part_below_floor_is_dropped() {
+    let mut state = create_stream_state_with_min_summary_chars(20);
+    let _ = process_stream_event(&reasoning_summary_delta_event("**Plan"), &mut state);
+    let _ = process_stream_event(&reasoning_summary_delta_event("ning**"), &mut state);
+
+    let events = process_stream_event(&summary_part_done_event(), &mut state);
+    assert!(events.is_empty());
+    assert!(state.acc.accumulated_thinking.is_empty());
+    assert!(state.summary_buffer.is_empty());
+}
+
+#[test]
+fn floor_applies_per_summary_part() {
+    let mut state = create_stream_state_with_min_summary_chars(20);
+    let _ = process_stream_event(&reasoning_summary_delta_event("Hmm"), &mut state);
+    let dropped = process_stream_event(&summary_part_done_event(), &mut state);
+    let _ = process_stream_event(
+        &reasoning_summary_delta_event("Comparing both retry strategies"),
+        &mut state,
+    );
+    let kept = process_stream_event(&summary_part_done_event(), &mut state);
+
+    assert!(thinking_deltas(&dropped).is_empty());
+    assert_eq!(
+        thinking_deltas(&kept),
+        vec!["Comparing both retry strategies"]
+    );
+    assert_eq!(
+        state.acc.accumulated_thinking,
+        "Comparing both retry strategies"
+    );
+}
+
+#[test]
+fn stream_end_mid_summary_flushes_buffer() {
+    let mut state = create_stream_state_with_min_summary_chars(100);
+    let _ = process_stream_event(&reasoning_summary_delta_event("Partial th"), &mut state);
+
+    let events = process_stream_event(&completed_event(vec![]), &mut state);
+    assert_eq!(thinking_deltas(&events), vec!["Partial th"]);
+    assert!(events.iter().any(
+        |e| matches!(e, StreamEvent::ThinkingEnd { thinking, .. } if thinking == "Partial th")
+    ));
+    match events.last() {
+        Some(StreamEvent::Done { message, .. }) => assert_eq!(
+            message.content[0],
+            AssistantContent::Thinking {
+                thinking: "Partial th".into(),
+                signature: None,
+            }
+        ),
+        other => panic!("expected Done, got {other:?}"),
+    }
+}
+
+#[test]
+fn reasoning_item_done_flushes_open_part_without_duplicating_summary() {
+    let mut state = create_stream_state();
+    let _ = process_stream_event(&reasoning_summary_delta_event("Checking"), &mut state);
+
+    let events = process_stream_event(&reasoning_done_event("Checking the config"), &mut state);
+    assert_eq!(thinking_deltas(&events), vec!["Checking"]);
+    assert_eq!(state.acc.accumulated_thinking, "Checking");
+}
+
+#[test]
+fn dropped_summary_is_not_restored_from_completed_output() {
+    let mut state = create_stream_state_with_min_summary_chars(20);
+    let _ = process_stream_event(&reasoning_summary_delta_event("Ok"), &mut state);
+    let _ = process_stream_event(&summary_part_done_event(), &mut state);
+
+    let item = ResponsesOutputItem {
+        item_type: OutputItemType::Reasoning,
+        summary: Some(vec![OutputContent {
+            content_type: "summary_text".into(),
+            text: Some("Ok".into()),
+        }]),
+        ..Default::default()
+    };
+    let events = process_stream_event(&completed_event(vec![item]), &mut state);
+    assert!(thinking_deltas(&events).is_empty());
+    assert!(state.acc.accumulated_thinking.is_empty());
+}
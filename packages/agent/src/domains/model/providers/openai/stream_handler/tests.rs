@@ -53,6 +53,13 @@ fn reasoning_summary_delta_event(delta: &str) -> ResponsesSseEvent {
     }
 }
 
+fn summary_part_done_event() -> ResponsesSseEvent {
+    ResponsesSseEvent {
+        event_type: SseEventType::ReasoningSummaryPartDone,
+        ..Default::default()
+    }
+}
+
 fn completed_event(
     output: Vec<ResponsesOutputItem>,
     usage: Option<ResponsesUsage>,
@@ -225,11 +232,13 @@ fn emits_thinking_start_on_reasoning_item() {
 
 #[test]
 fn emits_thinking_delta_for_reasoning_summary() {
-    let mut state = create_stream_state();
+    let mut state = create_stream_state_with_min_summary_chars(0);
     state.acc.thinking_started = true;
 
     let events = process_stream_event(&reasoning_summary_delta_event("Analyzing..."), &mut state);
+    assert!(events.is_empty(), "summary deltas are buffered");
 
+    let events = process_stream_event(&summary_part_done_event(), &mut state);
     assert_eq!(events.len(), 1);
     assert_eq!(
         events[0],
@@ -242,11 +251,12 @@ fn emits_thinking_delta_for_reasoning_summary() {
 
 #[test]
 fn deduplicates_reasoning_text() {
-    let mut state = create_stream_state();
+    let mut state = create_stream_state_with_min_summary_chars(0);
     state.acc.thinking_started = true;
     state.seen_thinking_texts.insert("Already seen".into());
 
-    let events = process_stream_event(&reasoning_summary_delta_event("Already seen"), &mut state);
+    let _ = process_stream_event(&reasoning_summary_delta_event("Already seen"), &mut state);
+    let events = process_stream_event(&summary_part_done_event(), &mut state);
     assert!(events.is_empty());
 }
 
@@ -733,12 +743,13 @@ fn reasoning_text_delta_emits_thinking_events() {
 
 #[test]
 fn reasoning_text_replaces_prior_summary() {
-    let mut state = create_stream_state();
-    // First, receive a summary delta
+    let mut state = create_stream_state_with_min_summary_chars(0);
+    // First, receive a completed summary part
     let _ = process_stream_event(
         &reasoning_summary_delta_event("**Short summary**"),
         &mut state,
     );
+    let _ = process_stream_event(&summary_part_done_event(), &mut state);
     assert_eq!(state.acc.accumulated_thinking, "**Short summary**");
 
     // Then receive full reasoning text — should replace summary
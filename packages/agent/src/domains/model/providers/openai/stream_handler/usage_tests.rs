@@ -0,0 +1,77 @@
+use serde_json::json;
+
+use super::*;
+use crate::domains::model::tokens::pricing::calculate_pricing;
+
+/// `response.completed` as the Responses API sends it, with 6k of the 8k
+/// input tokens served from the prompt cache.
+fn completed_with_cached_input() -> ResponsesSseEvent {
+    serde_json::from_value(json!({
+        "type": "response.completed",
+        "response": {
+            "id": "resp-cached",
+            "output": [{
+                "type": "message",
+                "content": [{"type": "output_text", "text": "ok"}]
+            }],
+            "usage": {
+                "input_tokens": 8_000,
+                "input_tokens_details": {"cached_tokens": 6_000},
+                "output_tokens": 500,
+                "output_tokens_details": {"reasoning_tokens": 200},
+                "total_tokens": 8_500
+            }
+        }
+    }))
+    .unwrap()
+}
+
+fn done_usage(events: &[StreamEvent]) -> TokenUsage {
+    match events.last() {
+        Some(StreamEvent::Done { message, .. }) => message.token_usage.clone().unwrap(),
+        other => panic!("expected Done, got {other:?}"),
+    }
+}
+
+#[test]
+fn cached_tokens_land_in_cache_read_usage() {
+    let mut state = create_stream_state();
+    let usage = done_usage(&process_stream_event(
+        &completed_with_cached_input(),
+        &mut state,
+    ));
+
+    assert_eq!(usage.input_tokens, 8_000);
+    assert_eq!(usage.cache_read_tokens, Some(6_000));
+    assert_eq!(usage.cached_input_tokens, Some(6_000));
+    assert_eq!(usage.reasoning_output_tokens, Some(200));
+    assert_eq!(
+        usage.provider_type,
+        Some(crate::shared::protocol::messages::Provider::OpenAi)
+    );
+}
+
+#[test]
+fn cached_tokens_are_priced_at_the_cached_input_rate() {
+    let mut state = create_stream_state();
+    let usage = done_usage(&process_stream_event(
+        &completed_with_cached_input(),
+        &mut state,
+    ));
+    let uncached = TokenUsage {
+        cache_read_tokens: None,
+        cached_input_tokens: None,
+        ..usage.clone()
+    };
+
+    // gpt-4.1: $2.00 input, $0.50 cached input, $8.00 output per million.
+    let cost = calculate_pricing("gpt-4.1", &usage).cost.unwrap();
+    assert_eq!(cost.base_input_tokens, 2_000);
+    assert_eq!(cost.cache_read_tokens, 6_000);
+    assert!((cost.base_input_cost - 0.004).abs() < 1e-9);
+    assert!((cost.cache_read_cost - 0.003).abs() < 1e-9);
+    assert!((cost.total_cost - 0.011).abs() < 1e-9);
+
+    let full_price = calculate_pricing("gpt-4.1", &uncached).cost.unwrap();
+    assert!((full_price.total_cost - 0.020).abs() < 1e-9);
+}
@@ -111,6 +111,9 @@ pub struct OpenAIApiSettings {
     /// Default reasoning effort.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_reasoning_effort: Option<String>,
+    /// Minimum length of a streamed reasoning summary part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_reasoning_summary_chars: Option<usize>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
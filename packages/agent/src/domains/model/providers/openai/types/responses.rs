@@ -282,6 +282,9 @@ pub enum SseEventType {
     /// New reasoning summary part added.
     #[serde(rename = "response.reasoning_summary_part.added")]
     ReasoningSummaryPartAdded,
+    /// Reasoning summary part completed.
+    #[serde(rename = "response.reasoning_summary_part.done")]
+    ReasoningSummaryPartDone,
     /// Full reasoning text delta.
     #[serde(rename = "response.reasoning_text.delta")]
     ReasoningTextDelta,
@@ -687,6 +687,16 @@ fn sse_reasoning_summary_delta() {
     assert_eq!(event.delta.as_deref(), Some("Thinking about..."));
 }
 
+#[test]
+fn sse_reasoning_summary_part_done() {
+    let json = json!({
+        "type": "response.reasoning_summary_part.done",
+        "summary_index": 0,
+    });
+    let event: ResponsesSseEvent = serde_json::from_value(json).unwrap();
+    assert_eq!(event.event_type, SseEventType::ReasoningSummaryPartDone);
+}
+
 #[test]
 fn sse_function_call_args_delta() {
     let json = json!({
@@ -0,0 +1,135 @@
+//! Replay provider — deterministic model output from a recorded transcript.
+//!
+//! A [`ReplayTranscript`] records the prompt a run started from and, for every
+//! provider request the run made, the exact [`StreamEvent`] sequence the model
+//! produced. [`ReplayProvider`] serves those sequences back in order, one turn
+//! per [`Provider::stream`] call, so a bad session can be reproduced without
+//! network access or model nondeterminism. Requests beyond the recorded turns
+//! fail instead of inventing output.
+//!
+//! The transcript file is JSON:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "provider": "anthropic",
+//!   "model": "claude-opus-4-6",
+//!   "prompt": "summarize the repo",
+//!   "turns": [[{"type": "start"}, {"type": "text_delta", "delta": "…"}, {"type": "done", …}]]
+//! }
+//! ```
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use futures::stream;
+use serde::{Deserialize, Serialize};
+
+use super::shared::provider::{
+    Provider, ProviderError, ProviderResult, ProviderStreamOptions, StreamEventStream,
+};
+use crate::shared::protocol::events::StreamEvent;
+use crate::shared::protocol::messages::{Context, Provider as ProviderType};
+
+/// Transcript format version accepted by [`ReplayTranscript::load`].
+pub const REPLAY_TRANSCRIPT_VERSION: u32 = 1;
+
+/// Recorded provider output for one agent run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayTranscript {
+    /// Transcript format version.
+    pub version: u32,
+    /// Provider that produced the recorded output.
+    pub provider: ProviderType,
+    /// Model that produced the recorded output.
+    pub model: String,
+    /// System prompt the run used, when one was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    /// User prompt the run started from.
+    pub prompt: String,
+    /// Stream events for each provider request, in request order.
+    pub turns: Vec<Vec<StreamEvent>>,
+}
+
+impl ReplayTranscript {
+    /// Read and validate a transcript file.
+    pub fn load(path: &Path) -> ProviderResult<Self> {
+        let raw = std::fs::read_to_string(path).map_err(|error| ProviderError::Other {
+            message: format!(
+                "failed to read replay transcript {}: {error}",
+                path.display()
+            ),
+        })?;
+        let transcript: Self = serde_json::from_str(&raw)?;
+        if transcript.version != REPLAY_TRANSCRIPT_VERSION {
+            return Err(ProviderError::Other {
+                message: format!(
+                    "unsupported replay transcript version {} (expected {REPLAY_TRANSCRIPT_VERSION})",
+                    transcript.version
+                ),
+            });
+        }
+        if transcript.turns.is_empty() {
+            return Err(ProviderError::Other {
+                message: "replay transcript records no turns".to_owned(),
+            });
+        }
+        Ok(transcript)
+    }
+}
+
+/// [`Provider`] that serves recorded turns from a [`ReplayTranscript`].
+pub struct ReplayProvider {
+    provider_type: ProviderType,
+    model: String,
+    turns: Vec<Vec<StreamEvent>>,
+    next_turn: AtomicUsize,
+}
+
+impl ReplayProvider {
+    /// Serve the turns recorded in `transcript`.
+    #[must_use]
+    pub fn new(transcript: &ReplayTranscript) -> Self {
+        Self {
+            provider_type: transcript.provider,
+            model: transcript.model.clone(),
+            turns: transcript.turns.clone(),
+            next_turn: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl Provider for ReplayProvider {
+    fn provider_type(&self) -> ProviderType {
+        self.provider_type
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn stream(
+        &self,
+        _context: &Context,
+        _options: &ProviderStreamOptions,
+    ) -> ProviderResult<StreamEventStream> {
+        let turn = self.next_turn.fetch_add(1, Ordering::SeqCst);
+        let Some(events) = self.turns.get(turn) else {
+            return Err(ProviderError::Other {
+                message: format!(
+                    "replay transcript exhausted: request {} but only {} turns recorded",
+                    turn + 1,
+                    self.turns.len()
+                ),
+            });
+        };
+        Ok(Box::pin(stream::iter(events.clone().into_iter().map(Ok))))
+    }
+}
+
+#[cfg(test)]
+mod tests;
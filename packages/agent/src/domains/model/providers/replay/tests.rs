@@ -0,0 +1,80 @@
+use futures::StreamExt;
+
+use super::*;
+
+fn transcript(turns: Vec<Vec<StreamEvent>>) -> ReplayTranscript {
+    ReplayTranscript {
+        version: REPLAY_TRANSCRIPT_VERSION,
+        provider: ProviderType::Anthropic,
+        model: "claude-opus-4-6".into(),
+        system_prompt: None,
+        prompt: "hello".into(),
+        turns,
+    }
+}
+
+fn write(dir: &Path, value: &serde_json::Value) -> std::path::PathBuf {
+    let path = dir.join("transcript.json");
+    std::fs::write(&path, value.to_string()).expect("write transcript");
+    path
+}
+
+async fn collect(provider: &ReplayProvider) -> ProviderResult<Vec<StreamEvent>> {
+    let stream = provider
+        .stream(&Context::default(), &ProviderStreamOptions::default())
+        .await?;
+    stream.collect::<Vec<_>>().await.into_iter().collect()
+}
+
+#[test]
+fn load_round_trips_a_recorded_transcript() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let recorded = transcript(vec![vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta { delta: "hi".into() },
+    ]]);
+    let path = write(
+        dir.path(),
+        &serde_json::to_value(&recorded).expect("transcript json"),
+    );
+
+    assert_eq!(ReplayTranscript::load(&path).expect("load"), recorded);
+}
+
+#[test]
+fn load_rejects_unknown_versions_and_empty_transcripts() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let mut future = serde_json::to_value(transcript(vec![vec![StreamEvent::Start]])).unwrap();
+    future["version"] = serde_json::json!(REPLAY_TRANSCRIPT_VERSION + 1);
+    let error = ReplayTranscript::load(&write(dir.path(), &future)).expect_err("version");
+    assert!(
+        error
+            .to_string()
+            .contains("unsupported replay transcript version")
+    );
+
+    let empty = serde_json::to_value(transcript(Vec::new())).unwrap();
+    let error = ReplayTranscript::load(&write(dir.path(), &empty)).expect_err("empty");
+    assert!(error.to_string().contains("no turns"));
+}
+
+#[tokio::test]
+async fn stream_serves_recorded_turns_in_order_then_fails() {
+    let provider = ReplayProvider::new(&transcript(vec![
+        vec![StreamEvent::Start],
+        vec![StreamEvent::TextDelta {
+            delta: "second".into(),
+        }],
+    ]));
+
+    assert_eq!(provider.model(), "claude-opus-4-6");
+    assert_eq!(collect(&provider).await.unwrap(), [StreamEvent::Start]);
+    assert_eq!(
+        collect(&provider).await.unwrap(),
+        [StreamEvent::TextDelta {
+            delta: "second".into()
+        }]
+    );
+    let error = collect(&provider).await.expect_err("exhausted");
+    assert!(error.to_string().contains("replay transcript exhausted"));
+}
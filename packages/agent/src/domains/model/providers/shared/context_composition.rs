@@ -1,19 +1,113 @@
 //! Provider-independent composition for the primitive agent context.
+//!
+//! Composed parts keep precedence order, except that blocks named in
+//! [`Context::pinned_context_blocks`] are marked [`ContextBlock::pinned`] and
+//! lead the output (relative order preserved). In grouped composition pinned
+//! parts sit ahead of the stable group and share its cache region, so pinning
+//! a turn-local block moves the stable cache boundary past it and invalidates
+//! that region whenever the block changes.
+//!
+//! Each block carries its own [`ContextBlock::priority`]; the token ceiling
+//! drops the lowest priorities first and never drops a pinned block.
 
 use crate::shared::foundation::constitution::{
-    ContextBlock, ContextCacheClass, ContextSensitivity, ProviderSurface, TronHome,
-    context_block_for_text,
+    ContextBlock, ContextCacheClass, ContextSensitivity, ProviderSurface, SectionPriority,
+    TronHome, context_block_for_text,
 };
 use crate::shared::protocol::messages::Context;
+use tracing::warn;
+
+/// Ceiling used when the context does not carry
+/// `context.systemContextTokenCeiling` from settings.
+pub const DEFAULT_SYSTEM_CONTEXT_TOKEN_CEILING: u64 = 32_000;
+
+/// Blocks omitted by [`compose_context_blocks_within`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DroppedSections {
+    /// Ids of dropped blocks, highest priority first.
+    pub ids: Vec<String>,
+    /// Sum of the dropped blocks' token estimates.
+    pub tokens: u64,
+}
+
+impl DroppedSections {
+    /// Whether every block fit.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
 
 /// Compose provider prompt text from the primitive context.
 pub fn compose_context_parts(context: &Context) -> Vec<String> {
-    compose_context_blocks(context)
+    pinned_first(compose_ceiling_blocks(context))
         .into_iter()
         .map(|block| block.text)
         .collect()
 }
 
+/// Move pinned blocks to the front, preserving relative order in both halves.
+fn pinned_first(blocks: Vec<ContextBlock>) -> Vec<ContextBlock> {
+    let (mut pinned, rest): (Vec<_>, Vec<_>) = blocks.into_iter().partition(|block| block.pinned);
+    pinned.extend(rest);
+    pinned
+}
+
+/// Compose primitive blocks that fit within `budget_tokens`.
+///
+/// Pinned blocks are always kept and count against the budget first. The
+/// rest are admitted greedily from highest [`ContextBlock::priority`] down (ties
+/// in precedence order). The first block that does not fit ends admission:
+/// it and every block after it are dropped, so a lower-priority block never
+/// survives a higher-priority one. Kept blocks stay in precedence order.
+pub fn compose_context_blocks_within(
+    context: &Context,
+    budget_tokens: u64,
+) -> (Vec<ContextBlock>, DroppedSections) {
+    let mut blocks = compose_context_blocks(context);
+    let mut admission: Vec<usize> = (0..blocks.len()).collect();
+    admission.sort_by_key(|&i| std::cmp::Reverse((blocks[i].pinned, blocks[i].priority)));
+
+    let mut keep = vec![false; blocks.len()];
+    let mut used = 0_u64;
+    let mut dropped = DroppedSections::default();
+    for i in admission {
+        let tokens = blocks[i].token_estimate;
+        if blocks[i].pinned || (dropped.is_empty() && used + tokens <= budget_tokens) {
+            used += tokens;
+            keep[i] = true;
+        } else {
+            dropped.ids.push(blocks[i].id.clone());
+            dropped.tokens += tokens;
+        }
+    }
+
+    let mut keep = keep.into_iter();
+    blocks.retain(|_| keep.next().unwrap_or(false));
+    (blocks, dropped)
+}
+
+/// The system context ceiling configured for this context.
+fn system_context_token_ceiling(context: &Context) -> u64 {
+    context
+        .system_context_token_ceiling
+        .unwrap_or(DEFAULT_SYSTEM_CONTEXT_TOKEN_CEILING)
+}
+
+/// Compose blocks under the configured ceiling, logging any drops.
+fn compose_ceiling_blocks(context: &Context) -> Vec<ContextBlock> {
+    let ceiling = system_context_token_ceiling(context);
+    let (blocks, dropped) = compose_context_blocks_within(context, ceiling);
+    if !dropped.is_empty() {
+        warn!(
+            ceiling,
+            dropped = ?dropped.ids,
+            dropped_tokens = dropped.tokens,
+            "system context over budget; dropped low-priority sections"
+        );
+    }
+    blocks
+}
+
 /// Compile primitive prompt blocks into the Constitution audit shape.
 pub fn compose_context_blocks(context: &Context) -> Vec<ContextBlock> {
     let mut blocks = Vec::new();
@@ -21,27 +115,31 @@ pub fn compose_context_blocks(context: &Context) -> Vec<ContextBlock> {
     if let Some(ref soul) = context.system_prompt
         && !soul.is_empty()
     {
-        blocks.push(context_block_for_text(
+        let mut block = context_block_for_text(
             "agent.soul",
             "Agent Soul",
             TronHome::Profiles,
             soul.clone(),
             ContextCacheClass::Foundation,
             10,
-        ));
+        );
+        block.priority = SectionPriority::Core;
+        blocks.push(block);
     }
 
     if let Some(ref state) = context.agent_state_context
         && !state.is_empty()
     {
-        blocks.push(context_block_for_text(
+        let mut block = context_block_for_text(
             "agent.state",
             "Agent State",
             TronHome::Workspace,
             state.clone(),
             ContextCacheClass::Turn,
             20,
-        ));
+        );
+        block.priority = SectionPriority::High;
+        blocks.push(block);
     }
 
     if let Some(ref memory) = context.memory_prompt_context
@@ -56,6 +154,7 @@ pub fn compose_context_blocks(context: &Context) -> Vec<ContextBlock> {
             25,
         );
         block.sensitivity = ContextSensitivity::Private;
+        block.priority = SectionPriority::Normal;
         block.inclusion_reason =
             "memory prompt inclusion trace attached without retained body content".into();
         blocks.push(block);
@@ -87,12 +186,19 @@ pub fn compose_context_blocks(context: &Context) -> Vec<ContextBlock> {
         ));
     }
 
+    for block in &mut blocks {
+        block.pinned = context.pinned_context_blocks.contains(&block.id);
+    }
     blocks
 }
 
 /// Compile the full provider-independent audit view of an LLM request.
+///
+/// System blocks go through the same ceiling as the provider request, so the
+/// audit lists only what was actually sent.
 pub fn compose_context_audit_blocks(context: &Context) -> Vec<ContextBlock> {
-    let mut blocks = compose_context_blocks(context);
+    let (mut blocks, _) =
+        compose_context_blocks_within(context, system_context_token_ceiling(context));
 
     if let Some(ref capabilities) = context.capabilities
         && !capabilities.is_empty()
@@ -143,9 +249,20 @@ pub struct GroupedContextParts {
 
 /// Compose primitive context parts into stable and turn-local groups.
 pub fn compose_context_parts_grouped(context: &Context) -> GroupedContextParts {
+    group_context_blocks(compose_ceiling_blocks(context))
+}
+
+/// Split composed blocks into stable and turn-local groups.
+///
+/// Pinned blocks lead the stable group regardless of cache class.
+pub fn group_context_blocks(blocks: Vec<ContextBlock>) -> GroupedContextParts {
     let mut stable = Vec::new();
     let mut volatile = Vec::new();
-    for block in compose_context_blocks(context) {
+    for block in pinned_first(blocks) {
+        if block.pinned {
+            stable.push(block.text);
+            continue;
+        }
         match block.cache_class {
             ContextCacheClass::Foundation
             | ContextCacheClass::Profile
@@ -170,6 +287,8 @@ mod tests {
             agent_state_context: Some("state summary".into()),
             memory_prompt_context: Some("memory status".into()),
             server_origin: Some("localhost:9847".into()),
+            system_context_token_ceiling: None,
+            pinned_context_blocks: Vec::new(),
         }
     }
 
@@ -196,6 +315,73 @@ mod tests {
         );
     }
 
+    fn pinned(ids: &[&str]) -> Context {
+        let mut ctx = make_context();
+        ctx.pinned_context_blocks = ids.iter().map(|&id| id.to_owned()).collect();
+        ctx
+    }
+
+    #[test]
+    fn pinned_parts_lead_grouped_output_in_relative_order() {
+        let ctx = pinned(&["environment.workingDirectory", "environment.server"]);
+
+        let grouped = compose_context_parts_grouped(&ctx);
+
+        assert_eq!(
+            grouped.stable,
+            vec![
+                "Server: localhost:9847".to_owned(),
+                "Current working directory: /Users/test/project".to_owned(),
+                "Soul seed".to_owned(),
+            ]
+        );
+        assert_eq!(grouped.volatile.len(), 2);
+        assert_eq!(compose_context_parts(&ctx)[0], "Server: localhost:9847");
+    }
+
+    #[test]
+    fn pinned_turn_part_moves_stable_cache_boundary() {
+        let unpinned = compose_context_parts_grouped(&make_context());
+
+        let grouped = compose_context_parts_grouped(&pinned(&["agent.memoryPromptTrace"]));
+
+        // The pinned turn-local part joins the stable cache region up front.
+        assert_eq!(grouped.stable.len(), unpinned.stable.len() + 1);
+        assert_eq!(grouped.stable[0], "memory status");
+        assert_eq!(grouped.volatile, vec!["state summary".to_owned()]);
+    }
+
+    #[test]
+    fn ceiling_never_drops_pinned_blocks() {
+        let mut ctx = pinned(&["environment.workingDirectory"]);
+        ctx.agent_state_context = Some("state ".repeat(200));
+        let tokens = block_tokens(&ctx);
+        // Too small for the state once the pinned block is charged first.
+        let budget = tokens["agent.soul"] + tokens["agent.state"];
+
+        let (blocks, dropped) = compose_context_blocks_within(&ctx, budget);
+
+        let kept = blocks.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(kept, ["agent.soul", "environment.workingDirectory"]);
+        assert_eq!(dropped.ids[0], "agent.state");
+
+        // A pinned block survives even a ceiling it alone exceeds.
+        let (blocks, _) = compose_context_blocks_within(&ctx, 0);
+        let kept = blocks.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(kept, ["environment.workingDirectory"]);
+    }
+
+    #[test]
+    fn unpinned_blocks_keep_precedence_order() {
+        let blocks = compose_context_blocks(&make_context());
+        assert!(blocks.iter().all(|block| !block.pinned));
+        let texts = pinned_first(blocks)
+            .into_iter()
+            .map(|block| block.text)
+            .collect::<Vec<_>>();
+        assert_eq!(texts, compose_context_parts(&make_context()));
+    }
+
     #[test]
     fn audit_blocks_include_capabilities_and_messages() {
         let mut ctx = make_context();
@@ -229,4 +415,97 @@ mod tests {
         assert!(ids.contains(&"capabilities.schemas".to_owned()));
         assert!(ids.contains(&"conversation.messages".to_owned()));
     }
+
+    #[test]
+    fn audit_blocks_follow_the_configured_ceiling() {
+        let mut ctx = make_context();
+        ctx.agent_state_context = Some("state ".repeat(200));
+        let tokens = block_tokens(&ctx);
+        ctx.system_context_token_ceiling = Some(tokens["agent.soul"] + tokens["agent.state"]);
+
+        let audit = compose_context_audit_blocks(&ctx)
+            .into_iter()
+            .map(|block| block.text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(audit, compose_context_parts(&ctx));
+        assert_eq!(audit.len(), 2);
+    }
+
+    fn block_tokens(ctx: &Context) -> std::collections::HashMap<String, u64> {
+        compose_context_blocks(ctx)
+            .into_iter()
+            .map(|block| (block.id, block.token_estimate))
+            .collect()
+    }
+
+    #[test]
+    fn budget_drops_low_priority_sections_first() {
+        let mut ctx = make_context();
+        ctx.agent_state_context = Some("state ".repeat(200));
+        let tokens = block_tokens(&ctx);
+        let budget =
+            tokens["agent.soul"] + tokens["agent.state"] + tokens["agent.memoryPromptTrace"];
+
+        let (blocks, dropped) = compose_context_blocks_within(&ctx, budget);
+
+        let kept = blocks.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            kept,
+            ["agent.soul", "agent.state", "agent.memoryPromptTrace"]
+        );
+        assert_eq!(
+            dropped.ids,
+            ["environment.server", "environment.workingDirectory"]
+        );
+        assert_eq!(
+            dropped.tokens,
+            tokens["environment.server"] + tokens["environment.workingDirectory"]
+        );
+    }
+
+    #[test]
+    fn budget_never_keeps_lower_priority_over_dropped_section() {
+        let mut ctx = make_context();
+        ctx.agent_state_context = Some("state ".repeat(200));
+        let tokens = block_tokens(&ctx);
+        // Room for the soul plus the small environment blocks, but not the state.
+        let budget = tokens["agent.soul"] + tokens["agent.state"] - 1;
+
+        let (blocks, dropped) = compose_context_blocks_within(&ctx, budget);
+
+        let kept = blocks.iter().map(|b| b.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(kept, ["agent.soul"]);
+        assert_eq!(dropped.ids[0], "agent.state");
+        assert_eq!(dropped.ids.len(), 4);
+    }
+
+    #[test]
+    fn budget_within_limit_drops_nothing() {
+        let ctx = make_context();
+        let (blocks, dropped) =
+            compose_context_blocks_within(&ctx, DEFAULT_SYSTEM_CONTEXT_TOKEN_CEILING);
+        assert!(dropped.is_empty());
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(compose_context_parts(&ctx).len(), 5);
+    }
+
+    #[test]
+    fn section_priorities_rank_soul_over_environment() {
+        let blocks = compose_context_blocks(&make_context());
+        let priorities = blocks
+            .iter()
+            .map(|block| block.priority)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            priorities,
+            [
+                SectionPriority::Core,
+                SectionPriority::High,
+                SectionPriority::Normal,
+                SectionPriority::Low,
+                SectionPriority::Low,
+            ]
+        );
+    }
 }
@@ -2,14 +2,28 @@
 //!
 //! Tracks per-provider request outcomes in a fixed-size ring buffer.
 //! When the error rate exceeds a threshold, logs a warning and sets a
-//! `provider_degraded` gauge metric. This is observability-only — it never
-//! blocks requests (single-provider mode).
+//! `provider_degraded` gauge metric.
+//!
+//! The degraded signal is observability-only. Request blocking is opt-in via
+//! [`ProviderHealthTracker::with_circuit_breaker`]: after `failure_threshold`
+//! consecutive provider failures (5xx, network, overload) the provider's
+//! circuit opens and [`ProviderHealthTracker::check_circuit`] fast-fails until
+//! `cooldown` elapses. The circuit then half-opens and admits exactly one
+//! probe; its success closes the circuit and its failure reopens it.
+//!
+//! Request-specific errors (bad request, auth, context overflow) are recorded
+//! with [`ProviderHealthTracker::record_client_error`]. They count toward the
+//! degraded error rate but never toward the circuit, so one session's bad
+//! request cannot lock every session out of a shared provider.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
 
-use tracing::debug;
+use tracing::{debug, warn};
+
+use super::provider::ProviderError;
 
 /// Default window size for tracking recent requests.
 const DEFAULT_WINDOW_SIZE: usize = 10;
@@ -17,6 +31,28 @@ const DEFAULT_WINDOW_SIZE: usize = 10;
 /// Default error rate threshold (50%) to mark a provider as degraded.
 const DEFAULT_DEGRADED_THRESHOLD: f64 = 0.5;
 
+/// Circuit-breaker state for a single provider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fast-fail until the cooldown elapses.
+    Open,
+    /// Cooldown elapsed; a single probe decides whether the circuit closes.
+    HalfOpen,
+}
+
+/// Circuit-breaker tuning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive provider failures that open the circuit.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before half-opening. Also
+    /// bounds how long a half-open probe may stay unresolved before another
+    /// caller is admitted in its place.
+    pub cooldown: Duration,
+}
+
 /// Per-provider health tracker.
 ///
 /// Thread-safe (interior `Mutex`). Create one at server startup and share
@@ -25,6 +61,7 @@ pub struct ProviderHealthTracker {
     inner: Mutex<Inner>,
     window_size: usize,
     threshold: f64,
+    circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 struct Inner {
@@ -36,6 +73,11 @@ struct ProviderWindow {
     outcomes: Vec<bool>,
     cursor: usize,
     total: usize,
+    consecutive_failures: u32,
+    circuit: CircuitState,
+    opened_at: Option<Instant>,
+    /// When the half-open probe was admitted, while it is unresolved.
+    probe_started_at: Option<Instant>,
 }
 
 impl ProviderWindow {
@@ -44,15 +86,80 @@ impl ProviderWindow {
             outcomes: vec![true; size],
             cursor: 0,
             total: 0,
+            consecutive_failures: 0,
+            circuit: CircuitState::Closed,
+            opened_at: None,
+            probe_started_at: None,
         }
     }
 
-    fn record(&mut self, success: bool) {
+    fn push_outcome(&mut self, success: bool) {
         self.outcomes[self.cursor] = success;
         self.cursor = (self.cursor + 1) % self.outcomes.len();
         self.total += 1;
     }
 
+    fn record(&mut self, success: bool) {
+        self.push_outcome(success);
+        if success {
+            self.consecutive_failures = 0;
+        } else {
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        }
+    }
+
+    /// Advance the circuit after an outcome. Returns `true` if it just opened.
+    fn transition(&mut self, success: bool, config: &CircuitBreakerConfig) -> bool {
+        self.refresh_circuit(config);
+        self.probe_started_at = None;
+        if success {
+            self.circuit = CircuitState::Closed;
+            self.opened_at = None;
+            return false;
+        }
+        let open = match self.circuit {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => self.consecutive_failures >= config.failure_threshold.max(1),
+            CircuitState::Open => false,
+        };
+        if open {
+            self.circuit = CircuitState::Open;
+            self.opened_at = Some(Instant::now());
+        }
+        open
+    }
+
+    /// Half-open an open circuit whose cooldown has elapsed.
+    fn refresh_circuit(&mut self, config: &CircuitBreakerConfig) {
+        if self.circuit == CircuitState::Open
+            && self
+                .opened_at
+                .is_none_or(|opened| opened.elapsed() >= config.cooldown)
+        {
+            self.circuit = CircuitState::HalfOpen;
+        }
+    }
+
+    /// Admit the half-open probe unless one is already in flight. A probe
+    /// that never reported an outcome is replaced once `cooldown` elapses.
+    fn admit_probe(&mut self, config: &CircuitBreakerConfig) -> bool {
+        let in_flight = self
+            .probe_started_at
+            .is_some_and(|started| started.elapsed() < config.cooldown);
+        if in_flight {
+            return false;
+        }
+        self.probe_started_at = Some(Instant::now());
+        true
+    }
+
+    /// Time left before an open circuit half-opens.
+    fn cooldown_remaining(&self, config: &CircuitBreakerConfig) -> Duration {
+        self.opened_at.map_or(Duration::ZERO, |opened| {
+            config.cooldown.saturating_sub(opened.elapsed())
+        })
+    }
+
     #[allow(clippy::cast_precision_loss)] // window_size is tiny (≤100), no precision loss
     fn error_rate(&self) -> f64 {
         if self.total == 0 {
@@ -73,9 +180,18 @@ impl ProviderHealthTracker {
             }),
             window_size: DEFAULT_WINDOW_SIZE,
             threshold: DEFAULT_DEGRADED_THRESHOLD,
+            circuit_breaker: None,
         }
     }
 
+    /// Enable circuit-breaker mode, which fast-fails requests to a provider
+    /// after consecutive failures.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
     /// Create a tracker with custom window size and threshold.
     #[cfg(test)]
     pub fn with_config(window_size: usize, threshold: f64) -> Self {
@@ -85,6 +201,7 @@ impl ProviderHealthTracker {
             }),
             window_size: window_size.max(1),
             threshold: threshold.clamp(0.0, 1.0),
+            circuit_breaker: None,
         }
     }
 
@@ -93,11 +210,102 @@ impl ProviderHealthTracker {
         self.record(provider, true);
     }
 
-    /// Record a failed request for a provider.
+    /// Record a provider failure (5xx, network, overload) for a provider.
+    ///
+    /// Counts toward both the degraded error rate and the circuit.
     pub fn record_failure(&self, provider: &str) {
         self.record(provider, false);
     }
 
+    /// Record a request-specific error (bad request, auth, context overflow).
+    ///
+    /// Counts toward the degraded error rate only. The circuit neither moves
+    /// nor resets, but an in-flight half-open probe is released so the next
+    /// request can probe the provider.
+    pub fn record_client_error(&self, provider: &str) {
+        let mut inner = self.inner.lock();
+        let window = inner
+            .providers
+            .entry(provider.to_string())
+            .or_insert_with(|| ProviderWindow::new(self.window_size));
+        window.push_outcome(false);
+        window.probe_started_at = None;
+        self.update_degraded(provider, window, false);
+    }
+
+    /// Whether a request to `provider` may proceed.
+    ///
+    /// Always `true` unless circuit-breaker mode is enabled and the provider's
+    /// circuit is open, or half-open with its single probe already in flight.
+    /// A `true` answer for a half-open circuit admits the caller as the probe,
+    /// so the caller must send the request and record its outcome.
+    pub fn should_allow_request(&self, provider: &str) -> bool {
+        let Some(config) = self.circuit_breaker else {
+            return true;
+        };
+        let mut inner = self.inner.lock();
+        let Some(window) = inner.providers.get_mut(provider) else {
+            return true;
+        };
+        window.refresh_circuit(&config);
+        match window.circuit {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen => window.admit_probe(&config),
+            CircuitState::Open => false,
+        }
+    }
+
+    /// Whether a request to `provider` may proceed, as a fast-fail error.
+    ///
+    /// Same admission rule as [`Self::should_allow_request`]. The error is a
+    /// retryable [`ProviderError::RateLimited`] whose `retry_after_ms` is the
+    /// time left in the cooldown or in the in-flight probe's window.
+    pub fn check_circuit(&self, provider: &str) -> Result<(), ProviderError> {
+        if self.should_allow_request(provider) {
+            return Ok(());
+        }
+        let Some(config) = self.circuit_breaker else {
+            return Ok(());
+        };
+        let inner = self.inner.lock();
+        let Some(window) = inner.providers.get(provider) else {
+            return Ok(());
+        };
+        let (remaining, message) = match window.circuit {
+            CircuitState::Closed => return Ok(()),
+            CircuitState::HalfOpen => (
+                window.probe_started_at.map_or(Duration::ZERO, |started| {
+                    config.cooldown.saturating_sub(started.elapsed())
+                }),
+                format!("{provider} circuit half-open; probe request in flight"),
+            ),
+            CircuitState::Open => (
+                window.cooldown_remaining(&config),
+                format!(
+                    "{provider} circuit open after {} consecutive failures",
+                    window.consecutive_failures
+                ),
+            ),
+        };
+        Err(ProviderError::RateLimited {
+            retry_after_ms: u64::try_from(remaining.as_millis()).unwrap_or(u64::MAX),
+            message,
+        })
+    }
+
+    /// Current circuit state for a provider.
+    #[cfg(test)]
+    pub fn circuit_state(&self, provider: &str) -> CircuitState {
+        let mut inner = self.inner.lock();
+        match (self.circuit_breaker, inner.providers.get_mut(provider)) {
+            (Some(config), Some(window)) => {
+                window.refresh_circuit(&config);
+                window.circuit
+            }
+            _ => CircuitState::Closed,
+        }
+    }
+
     /// Check if a provider is currently degraded (error rate above threshold).
     #[cfg(test)]
     pub fn is_degraded(&self, provider: &str) -> bool {
@@ -126,6 +334,29 @@ impl ProviderHealthTracker {
             .or_insert_with(|| ProviderWindow::new(self.window_size));
         window.record(success);
 
+        if let Some(config) = self.circuit_breaker {
+            let opened = window.transition(success, &config);
+            let circuit_open = if window.circuit == CircuitState::Open {
+                1.0
+            } else {
+                0.0
+            };
+            metrics::gauge!("provider_circuit_open", "provider" => provider.to_string())
+                .set(circuit_open);
+            if opened {
+                warn!(
+                    provider,
+                    consecutive_failures = window.consecutive_failures,
+                    cooldown_ms = u64::try_from(config.cooldown.as_millis()).unwrap_or(u64::MAX),
+                    "provider circuit opened"
+                );
+            }
+        }
+
+        self.update_degraded(provider, window, success);
+    }
+
+    fn update_degraded(&self, provider: &str, window: &ProviderWindow, success: bool) {
         let rate = window.error_rate();
         let degraded = window.total >= 2 && rate > self.threshold;
 
@@ -149,6 +380,7 @@ impl std::fmt::Debug for ProviderHealthTracker {
         f.debug_struct("ProviderHealthTracker")
             .field("window_size", &self.window_size)
             .field("threshold", &self.threshold)
+            .field("circuit_breaker", &self.circuit_breaker)
             .finish_non_exhaustive()
     }
 }
@@ -268,6 +500,162 @@ mod tests {
         assert!((tracker.error_rate("p") - 0.5).abs() < f64::EPSILON);
     }
 
+    fn breaker(failure_threshold: u32, cooldown: Duration) -> ProviderHealthTracker {
+        ProviderHealthTracker::new().with_circuit_breaker(CircuitBreakerConfig {
+            failure_threshold,
+            cooldown,
+        })
+    }
+
+    /// Backdate the provider's open timestamp so its cooldown has elapsed.
+    fn expire_cooldown(tracker: &ProviderHealthTracker, provider: &str, cooldown: Duration) {
+        let mut inner = tracker.inner.lock();
+        let window = inner.providers.get_mut(provider).unwrap();
+        window.opened_at = Instant::now().checked_sub(cooldown + Duration::from_millis(1));
+    }
+
+    #[test]
+    fn circuit_breaker_disabled_by_default() {
+        let tracker = ProviderHealthTracker::new();
+        for _ in 0..20 {
+            tracker.record_failure("anthropic");
+        }
+        assert!(tracker.should_allow_request("anthropic"));
+        assert_eq!(tracker.circuit_state("anthropic"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures() {
+        let tracker = breaker(3, Duration::from_secs(60));
+        tracker.record_failure("anthropic");
+        tracker.record_failure("anthropic");
+        assert!(tracker.should_allow_request("anthropic"));
+
+        tracker.record_failure("anthropic");
+        assert_eq!(tracker.circuit_state("anthropic"), CircuitState::Open);
+        assert!(!tracker.should_allow_request("anthropic"));
+
+        let err = tracker.check_circuit("anthropic").unwrap_err();
+        assert!(err.is_retryable());
+        let retry_after = err.retry_after_ms().unwrap();
+        assert!(retry_after > 0 && retry_after <= 60_000);
+    }
+
+    #[test]
+    fn success_resets_consecutive_failure_count() {
+        let tracker = breaker(3, Duration::from_secs(60));
+        tracker.record_failure("openai");
+        tracker.record_failure("openai");
+        tracker.record_success("openai");
+        tracker.record_failure("openai");
+        tracker.record_failure("openai");
+        assert_eq!(tracker.circuit_state("openai"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn circuit_open_half_open_closed() {
+        let tracker = breaker(2, Duration::ZERO);
+        tracker.record_failure("google");
+        tracker.record_failure("google");
+
+        // Zero cooldown: the open circuit half-opens on the next check.
+        assert_eq!(tracker.circuit_state("google"), CircuitState::HalfOpen);
+        assert!(tracker.should_allow_request("google"));
+
+        tracker.record_success("google");
+        assert_eq!(tracker.circuit_state("google"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn circuit_open_half_open_reopens_on_failure() {
+        let tracker = breaker(2, Duration::from_secs(60));
+        tracker.record_failure("google");
+        tracker.record_failure("google");
+        assert!(!tracker.should_allow_request("google"));
+
+        expire_cooldown(&tracker, "google", Duration::from_secs(60));
+        assert!(tracker.should_allow_request("google"));
+        assert_eq!(tracker.circuit_state("google"), CircuitState::HalfOpen);
+
+        // A single failed probe reopens the circuit.
+        tracker.record_failure("google");
+        assert_eq!(tracker.circuit_state("google"), CircuitState::Open);
+        assert!(!tracker.should_allow_request("google"));
+    }
+
+    #[test]
+    fn half_open_admits_exactly_one_probe() {
+        let tracker = breaker(2, Duration::from_secs(60));
+        tracker.record_failure("google");
+        tracker.record_failure("google");
+        expire_cooldown(&tracker, "google", Duration::from_secs(60));
+
+        assert!(tracker.should_allow_request("google"));
+        let err = tracker.check_circuit("google").unwrap_err();
+        assert!(err.is_retryable());
+        assert!(!tracker.should_allow_request("google"));
+
+        tracker.record_success("google");
+        assert_eq!(tracker.circuit_state("google"), CircuitState::Closed);
+        assert!(tracker.should_allow_request("google"));
+        assert!(tracker.should_allow_request("google"));
+    }
+
+    #[test]
+    fn unresolved_probe_is_replaced_after_cooldown() {
+        let cooldown = Duration::from_secs(60);
+        let tracker = breaker(1, cooldown);
+        tracker.record_failure("google");
+        expire_cooldown(&tracker, "google", cooldown);
+        assert!(tracker.should_allow_request("google"));
+        assert!(!tracker.should_allow_request("google"));
+
+        {
+            let mut inner = tracker.inner.lock();
+            let window = inner.providers.get_mut("google").unwrap();
+            window.probe_started_at = Instant::now().checked_sub(cooldown);
+        }
+        assert!(tracker.should_allow_request("google"));
+    }
+
+    #[test]
+    fn client_errors_never_open_the_circuit() {
+        let tracker = breaker(2, Duration::from_secs(60));
+        for _ in 0..10 {
+            tracker.record_client_error("anthropic");
+        }
+        assert_eq!(tracker.circuit_state("anthropic"), CircuitState::Closed);
+        assert!(tracker.should_allow_request("anthropic"));
+        assert!(tracker.is_degraded("anthropic"));
+
+        // Client errors do not reset the provider-failure streak either.
+        tracker.record_failure("anthropic");
+        tracker.record_client_error("anthropic");
+        tracker.record_failure("anthropic");
+        assert_eq!(tracker.circuit_state("anthropic"), CircuitState::Open);
+    }
+
+    #[test]
+    fn client_error_releases_half_open_probe() {
+        let tracker = breaker(1, Duration::from_secs(60));
+        tracker.record_failure("openai");
+        expire_cooldown(&tracker, "openai", Duration::from_secs(60));
+        assert!(tracker.should_allow_request("openai"));
+        assert!(!tracker.should_allow_request("openai"));
+
+        tracker.record_client_error("openai");
+        assert_eq!(tracker.circuit_state("openai"), CircuitState::HalfOpen);
+        assert!(tracker.should_allow_request("openai"));
+    }
+
+    #[test]
+    fn circuits_are_per_provider() {
+        let tracker = breaker(1, Duration::from_secs(60));
+        tracker.record_failure("anthropic");
+        assert!(!tracker.should_allow_request("anthropic"));
+        assert!(tracker.should_allow_request("openai"));
+    }
+
     #[test]
     fn is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
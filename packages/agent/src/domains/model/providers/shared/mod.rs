@@ -9,14 +9,15 @@
 //!
 //! | Module | Purpose |
 //! |--------|---------|
-//! | [`context_composition`] | Stable/volatile context grouping and provider prompt assembly |
+//! | [`context_composition`] | Stable/volatile context grouping with pinned-first ordering and provider prompt assembly under a priority-ordered token ceiling |
 //! | [`error_parsing`] | Provider API error body classification |
-//! | [`health`] | Provider health tracker used by routing and runtime context |
+//! | [`health`] | Provider health tracker used by routing and runtime context; optional circuit breaker that fast-fails requests to a failing provider |
 //! | [`provider`] | Core provider trait, stream options, and error types |
 //! | [`retry`] | Provider stream retry with exponential backoff and jitter |
 //! | [`sse`] | Shared SSE line parser |
 //! | [`stream_common`] | Delta accumulator shared by streaming providers |
 //! | [`stream_pipeline`] | SSE-to-event stream adapters and provider stream wrappers |
+//! | [`wire_log`] | Opt-in redacted request/response/stream-frame debug logging |
 //!
 //! ## Entry Points
 //!
@@ -44,6 +45,8 @@
 //!   tool invocations are capped before provider-specific handlers can retain
 //!   unbounded buffers.
 //! - Health tracking is advisory routing state, not an authority source.
+//! - Provider wire logging is off by default and redacts credential headers,
+//!   bearer tokens, and secret-shaped body text before anything is logged.
 //!
 //! ## Test Ownership
 //!
@@ -59,8 +62,9 @@ pub mod retry;
 pub mod sse;
 pub mod stream_common;
 pub mod stream_pipeline;
+pub mod wire_log;
 
 pub use context_composition::{compose_context_parts, compose_context_parts_grouped};
-pub use health::ProviderHealthTracker;
+pub use health::{CircuitBreakerConfig, ProviderHealthTracker};
 pub use retry::{StreamFactory, StreamRetryConfig, with_provider_retry};
 pub use sse::SseParserOptions;
@@ -430,6 +430,12 @@ pub struct ProviderStreamOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
 
+    /// Provider-agnostic output-token budget. Each provider maps it to its own
+    /// request field and clamps it to the model's registry maximum. Takes
+    /// precedence over `max_tokens`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+
     /// Sampling temperature (0.0 - 2.0).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f64>,
@@ -634,6 +640,7 @@ mod tests {
     fn provider_stream_options_defaults() {
         let opts = ProviderStreamOptions::default();
         assert!(opts.max_tokens.is_none());
+        assert!(opts.max_output_tokens.is_none());
         assert!(opts.temperature.is_none());
         assert!(opts.stop_sequences.is_none());
         assert!(opts.enable_thinking.is_none());
@@ -12,6 +12,7 @@ use crate::domains::model::providers::shared::provider::{
     ProviderError, ProviderResult, StreamEventStream,
 };
 use crate::domains::model::providers::shared::sse::{SseParserOptions, parse_sse_lines};
+use crate::domains::model::providers::shared::wire_log;
 use crate::shared::protocol::events::StreamEvent;
 
 /// Convert an HTTP response's SSE byte stream into a typed [`StreamEventStream`].
@@ -38,6 +39,7 @@ where
                 Ok(line) => line,
                 Err(error) => return std::future::ready(Some(vec![Err(error)])),
             };
+            wire_log::log_stream_frame(&line);
             let event: E = match serde_json::from_str(&line) {
                 Ok(e) => e,
                 Err(e) => {
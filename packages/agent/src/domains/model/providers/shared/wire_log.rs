@@ -0,0 +1,212 @@
+//! Opt-in provider request/response wire logging.
+//!
+//! When `logging.providerWireDebug` is enabled, providers log each outgoing
+//! request (URL, headers, JSON body), the response status and headers, error
+//! bodies, and every raw streamed frame at `debug` level under
+//! [`WIRE_LOG_TARGET`]. Everything passes through redaction first: credential
+//! headers are replaced wholesale, bearer tokens become `Bearer [REDACTED]`,
+//! and body text goes through the shared secret-shape redactor.
+//!
+//! The switch is process-global so provider construction stays unchanged;
+//! bootstrap applies it from settings on startup and on every reload.
+
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use regex::Regex;
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use tracing::debug;
+
+use crate::shared::foundation::redaction::redact_sensitive_content;
+
+/// Tracing target for provider wire logs, usable in `moduleOverrides`.
+pub const WIRE_LOG_TARGET: &str = "tron::provider_wire";
+
+const REDACTED: &str = "[REDACTED]";
+
+static WIRE_LOGGING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable provider wire logging for the whole process.
+pub fn set_provider_wire_logging(enabled: bool) {
+    WIRE_LOGGING_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether provider wire logging is currently enabled.
+#[must_use]
+pub fn provider_wire_logging_enabled() -> bool {
+    WIRE_LOGGING_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Log an outgoing provider request when wire logging is enabled.
+pub fn log_request(provider: &str, url: &str, headers: &HeaderMap, body: &impl Serialize) {
+    if provider_wire_logging_enabled() {
+        emit_request(provider, url, headers, body);
+    }
+}
+
+/// Log a provider response status and headers when wire logging is enabled.
+pub fn log_response(provider: &str, status: u16, headers: &HeaderMap) {
+    if provider_wire_logging_enabled() {
+        debug!(
+            target: WIRE_LOG_TARGET,
+            provider,
+            status,
+            headers = %redact_headers(headers),
+            "provider response"
+        );
+    }
+}
+
+/// Log a buffered provider response body when wire logging is enabled.
+pub fn log_response_body(provider: &str, body: &str) {
+    if provider_wire_logging_enabled() {
+        debug!(
+            target: WIRE_LOG_TARGET,
+            provider,
+            body = %redact_wire_text(body),
+            "provider response body"
+        );
+    }
+}
+
+/// Log one raw streamed response frame (SSE `data:` payload or NDJSON line)
+/// when wire logging is enabled.
+pub fn log_stream_frame(frame: &str) {
+    if provider_wire_logging_enabled() {
+        debug!(
+            target: WIRE_LOG_TARGET,
+            frame = %redact_wire_text(frame),
+            "provider stream frame"
+        );
+    }
+}
+
+fn emit_request(provider: &str, url: &str, headers: &HeaderMap, body: &impl Serialize) {
+    let body = serde_json::to_string(body).unwrap_or_else(|error| format!("<{error}>"));
+    debug!(
+        target: WIRE_LOG_TARGET,
+        provider,
+        url = %redact_wire_text(url),
+        headers = %redact_headers(headers),
+        body = %redact_wire_text(&body),
+        "provider request"
+    );
+}
+
+/// Render headers as `name: value` lines with credentials replaced.
+fn redact_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = value.to_str().unwrap_or("<binary>");
+            let value = if is_credential_header(name.as_str()) {
+                redact_credential_value(value)
+            } else {
+                redact_wire_text(value)
+            };
+            format!("{name}: {value}")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn is_credential_header(name: &str) -> bool {
+    matches!(
+        name,
+        "authorization" | "proxy-authorization" | "x-api-key" | "api-key" | "x-goog-api-key"
+    ) || name.contains("token")
+        || name.contains("secret")
+        || name.contains("cookie")
+}
+
+/// Keep the auth scheme so logs show which credential kind was sent.
+fn redact_credential_value(value: &str) -> String {
+    match value.split_once(' ') {
+        Some((scheme, _)) if !scheme.is_empty() => format!("{scheme} {REDACTED}"),
+        _ => REDACTED.to_owned(),
+    }
+}
+
+fn redact_wire_text(text: &str) -> String {
+    static BEARER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)\b(bearer)\s+[A-Za-z0-9._~+/=-]+").unwrap());
+    static KEY_PARAM: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)([?&](?:key|api_?key|access_token)=)[^&#\s]+").unwrap());
+    let text = BEARER.replace_all(text, format!("${{1}} {REDACTED}"));
+    let text = KEY_PARAM.replace_all(&text, format!("${{1}}{REDACTED}"));
+    redact_sensitive_content(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::observability::test_utils::{CapturedLogs, capture_logs};
+    use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue};
+    use serde_json::json;
+
+    const TOKEN: &str = "tok-live-0123456789abcdef";
+
+    fn field(logs: &CapturedLogs, name: &str) -> String {
+        logs.events_for_target(WIRE_LOG_TARGET)
+            .iter()
+            .flat_map(|event| event.fields.clone())
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn request_log_redacts_bearer_token() {
+        let (logs, _guard) = capture_logs();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {TOKEN}")).unwrap(),
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let body = json!({"model": "m", "note": format!("Authorization: Bearer {TOKEN}")});
+
+        emit_request("openai", "https://api.example.com/v1", &headers, &body);
+
+        let headers = field(&logs, "headers");
+        let body = field(&logs, "body");
+        assert!(
+            headers.contains("authorization: Bearer [REDACTED]"),
+            "{headers}"
+        );
+        assert!(headers.contains("content-type: application/json"));
+        assert!(body.contains("Bearer [REDACTED]"), "{body}");
+        for event in logs.events_for_target(WIRE_LOG_TARGET) {
+            assert!(
+                !format!("{event:?}").contains(TOKEN),
+                "token leaked: {event:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn api_key_headers_and_query_params_are_redacted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-api-key",
+            HeaderValue::from_static("sk-ant-api03-secretsecret"),
+        );
+        let rendered = redact_headers(&headers);
+        assert_eq!(rendered, "x-api-key: [REDACTED]");
+
+        let url = redact_wire_text("https://g.example.com/v1:stream?alt=sse&key=AIzaSecret123");
+        assert_eq!(
+            url,
+            "https://g.example.com/v1:stream?alt=sse&key=[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn response_body_frames_are_redacted() {
+        let text = redact_wire_text(r#"{"access_token":"oauth-access-1234567890","ok":true}"#);
+        assert!(!text.contains("oauth-access-1234567890"));
+        assert!(text.contains(r#""ok":true"#));
+    }
+}
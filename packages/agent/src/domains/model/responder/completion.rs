@@ -0,0 +1,94 @@
+//! Draining an open model response into one final assistant message.
+//!
+//! [`ModelResponse::into_completion`] requires the terminal `Done` event and
+//! reports a stream that closes early as truncated. Callers that can use a
+//! cut-off answer opt in with [`ModelResponse::into_partial_completion`].
+
+use futures::StreamExt;
+
+use super::{ModelResponderInfo, ModelResponse, ModelResponseError};
+use crate::shared::protocol::content::AssistantContent;
+use crate::shared::protocol::events::{AssistantMessage, StreamEvent};
+use crate::shared::protocol::messages::extract_assistant_text;
+
+/// Stop reason of a partial completion whose stream closed without `Done`.
+pub const STREAM_ENDED_STOP_REASON: &str = "stream_ended";
+
+impl ModelResponse {
+    /// Drain the stream into its final assistant message.
+    ///
+    /// Streamed text stands in for the `Done` content when a provider reports
+    /// an empty final message. A stream that ends without `Done` is treated as
+    /// truncated.
+    pub async fn into_completion(self) -> Result<ModelCompletion, ModelResponseError> {
+        self.drain(false).await
+    }
+
+    /// Like [`into_completion`](Self::into_completion), but a stream that ends
+    /// without `Done` after some text completes with that text and
+    /// [`STREAM_ENDED_STOP_REASON`]. A stream that ends without any text is
+    /// still treated as truncated.
+    pub async fn into_partial_completion(self) -> Result<ModelCompletion, ModelResponseError> {
+        self.drain(true).await
+    }
+
+    async fn drain(mut self, accept_partial: bool) -> Result<ModelCompletion, ModelResponseError> {
+        let mut text = String::new();
+        while let Some(event) = self.stream.next().await {
+            match event? {
+                StreamEvent::TextDelta { delta } => text.push_str(&delta),
+                StreamEvent::TextEnd {
+                    text: completed, ..
+                } if text.is_empty() => text = completed,
+                StreamEvent::Done {
+                    mut message,
+                    stop_reason,
+                } => {
+                    if message.content.is_empty() && !text.is_empty() {
+                        message.content.push(AssistantContent::text(text));
+                    }
+                    return Ok(ModelCompletion {
+                        info: self.info,
+                        message,
+                        stop_reason,
+                    });
+                }
+                StreamEvent::Error { error } => return Err(ModelResponseError::other(error)),
+                _ => {}
+            }
+        }
+        if !accept_partial || text.is_empty() {
+            return Err(ModelResponseError::truncated_stream(
+                "model stream ended before completion",
+            ));
+        }
+        Ok(ModelCompletion {
+            info: self.info,
+            message: AssistantMessage {
+                content: vec![AssistantContent::text(text)],
+                token_usage: None,
+            },
+            stop_reason: STREAM_ENDED_STOP_REASON.to_owned(),
+        })
+    }
+}
+
+/// Accumulated result of one model response.
+#[derive(Clone, Debug)]
+pub struct ModelCompletion {
+    /// Responder metadata.
+    pub info: ModelResponderInfo,
+    /// Final assistant message, with token usage when the provider reports it.
+    pub message: AssistantMessage,
+    /// Provider stop reason from the terminal `Done` event, or
+    /// [`STREAM_ENDED_STOP_REASON`] for a partial completion.
+    pub stop_reason: String,
+}
+
+impl ModelCompletion {
+    /// Text blocks of the final message, joined.
+    #[must_use]
+    pub fn text(&self) -> String {
+        extract_assistant_text(&self.message.content)
+    }
+}
@@ -5,7 +5,11 @@
 //! records provider health. It also builds provider request audit payloads from
 //! the same stream options used to open the provider stream, redacts and bounds
 //! those payloads before persistence, attaches metadata-only reasoning/status
-//! evidence, and redacts provider-derived failure text.
+//! evidence, and redacts provider-derived failure text. When
+//! `retry.circuitBreaker` is configured, provider health runs in
+//! circuit-breaker mode: once a provider's circuit opens, `respond` fast-fails
+//! with a retryable rate-limit failure instead of opening a stream. Only
+//! retryable provider failures count toward the circuit.
 //! Agent loop code depends on this boundary instead of provider factories,
 //! provider traits, stream options, retry wrappers, or provider-native errors.
 
@@ -25,7 +29,8 @@ use crate::domains::model::providers::shared::provider::{
     ReasoningEffort, StreamEventStream,
 };
 use crate::domains::model::providers::shared::{
-    ProviderHealthTracker, StreamFactory, StreamRetryConfig, with_provider_retry,
+    CircuitBreakerConfig, ProviderHealthTracker, StreamFactory, StreamRetryConfig,
+    with_provider_retry,
 };
 use crate::shared::foundation::redaction::redact_sensitive_content;
 use crate::shared::foundation::retry::RetryConfig;
@@ -34,9 +39,17 @@ use crate::shared::protocol::messages::Context;
 use crate::shared::protocol::model_audit::{ModelProviderRequestAudit, ProviderAuditPayload};
 use crate::shared::server::failure::{
     FailureCategory, FailureEnvelope, FailureOrigin, MODEL_AUTH_ERROR,
-    MODEL_PROVIDER_REQUEST_AUDIT_FAILED, MODEL_RESPONSE_ERROR, PROVIDER_SSE_PARSE_ERROR,
+    MODEL_PROVIDER_REQUEST_AUDIT_FAILED, MODEL_RESPONSE_ERROR, MODEL_STREAM_TRUNCATED,
+    PROVIDER_SSE_PARSE_ERROR,
 };
 
+mod completion;
+mod replay;
+
+pub use crate::domains::model::providers::shared::wire_log::set_provider_wire_logging;
+pub use completion::{ModelCompletion, STREAM_ENDED_STOP_REASON};
+pub use replay::{ReplayResponder, open_replay_responder};
+
 /// Boxed stream returned by the model responder boundary.
 pub type ModelResponseStream =
     Pin<Box<dyn Stream<Item = Result<StreamEvent, ModelResponseError>> + Send>>;
@@ -177,6 +190,23 @@ impl ModelResponseError {
         )
     }
 
+    /// Create a retryable error for a stream that ended mid capability
+    /// invocation, so the truncated call is retried instead of recorded.
+    pub fn truncated_stream(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::from_failure(
+            FailureEnvelope::new(
+                MODEL_STREAM_TRUNCATED,
+                FailureCategory::Parse,
+                message,
+                true,
+                true,
+                FailureOrigin::ModelResponder,
+            ),
+            false,
+        )
+    }
+
     fn from_failure(failure: FailureEnvelope, cancelled: bool) -> Self {
         Self {
             message: failure.message.clone(),
@@ -280,20 +310,55 @@ pub struct ModelResponderHealth {
 }
 
 impl ModelResponderHealth {
-    /// Create an empty health tracker.
+    /// Create an empty health tracker with the circuit breaker disabled.
     pub fn new() -> Self {
         Self {
             inner: ProviderHealthTracker::new(),
         }
     }
 
+    /// Create an empty health tracker with a circuit breaker.
+    pub fn with_circuit_breaker(config: CircuitBreakerConfig) -> Self {
+        Self {
+            inner: ProviderHealthTracker::new().with_circuit_breaker(config),
+        }
+    }
+
+    /// Create a health tracker from `retry.circuitBreaker`, which is disabled
+    /// unless a failure threshold is configured.
+    pub fn from_settings(settings: &crate::domains::settings::RetrySettings) -> Self {
+        let breaker = &settings.circuit_breaker;
+        if breaker.failure_threshold == 0 {
+            return Self::new();
+        }
+        Self::with_circuit_breaker(CircuitBreakerConfig {
+            failure_threshold: breaker.failure_threshold,
+            cooldown: std::time::Duration::from_millis(breaker.cooldown_ms),
+        })
+    }
+
+    fn check_circuit(&self, provider_name: &'static str) -> Result<(), ProviderError> {
+        self.inner.check_circuit(provider_name)
+    }
+
     fn record_success(&self, provider_name: &'static str) {
         self.inner.record_success(provider_name);
     }
 
+    /// Record a provider-side failure that counts toward the circuit.
     fn record_failure(&self, provider_name: &'static str) {
         self.inner.record_failure(provider_name);
     }
+
+    /// Record a failed request, counting it toward the circuit only when it is
+    /// retryable (5xx, network, overload) rather than request-specific.
+    fn record_error(&self, provider_name: &'static str, error: &ProviderError) {
+        if error.is_retryable() {
+            self.inner.record_failure(provider_name);
+        } else {
+            self.inner.record_client_error(provider_name);
+        }
+    }
 }
 
 impl Default for ModelResponderHealth {
@@ -343,6 +408,17 @@ pub trait ModelResponder: Send + Sync {
         &self,
         request: ModelResponseRequest,
     ) -> Result<ModelResponse, ModelResponseError>;
+
+    /// Produce one complete model response without incremental events.
+    ///
+    /// The default drains [`respond`](Self::respond); responders can override
+    /// it with a direct non-streaming request where that is cheaper.
+    async fn complete(
+        &self,
+        request: ModelResponseRequest,
+    ) -> Result<ModelCompletion, ModelResponseError> {
+        self.respond(request).await?.into_completion().await
+    }
 }
 
 /// Factory for model responders.
@@ -368,7 +444,7 @@ impl DefaultModelResponderFactory {
             providers: crate::domains::model::providers::factory::DefaultProviderFactory::new(
                 settings,
             ),
-            health: Arc::new(ModelResponderHealth::new()),
+            health: Arc::new(ModelResponderHealth::from_settings(&settings.retry)),
         }
     }
 
@@ -406,6 +482,16 @@ impl ModelResponderFactory for DefaultModelResponderFactory {
     }
 }
 
+/// Wrap a provider in the production responder so tests exercise the same
+/// stream, retry, and failure mapping path as real providers.
+#[cfg(test)]
+pub(crate) fn provider_backed_responder(provider: Arc<dyn Provider>) -> Arc<dyn ModelResponder> {
+    Arc::new(ProviderBackedModelResponder {
+        provider,
+        health: Arc::new(ModelResponderHealth::new()),
+    })
+}
+
 struct ProviderBackedModelResponder {
     provider: Arc<dyn Provider>,
     health: Arc<ModelResponderHealth>,
@@ -442,6 +528,17 @@ impl ModelResponder for ProviderBackedModelResponder {
         request: ModelResponseRequest,
     ) -> Result<ModelResponse, ModelResponseError> {
         let info = self.info();
+        if let Err(error) = self.health.check_circuit(info.provider_name) {
+            counter!("provider_circuit_rejections_total", "provider" => info.provider_name)
+                .increment(1);
+            warn!(
+                provider = %info.provider_name,
+                model = %info.model,
+                retry_after_ms = error.retry_after_ms().unwrap_or_default(),
+                "provider circuit open; failing fast"
+            );
+            return Err(ModelResponseError::from_provider_error(error, &info));
+        }
         let request_start = Instant::now();
         counter!("provider_requests_total", "provider" => info.provider_name).increment(1);
 
@@ -458,7 +555,7 @@ impl ModelResponder for ProviderBackedModelResponder {
         {
             Ok(stream) => stream,
             Err(error) => {
-                self.health.record_failure(info.provider_name);
+                self.health.record_error(info.provider_name, &error);
                 let category = error.category().to_owned();
                 counter!("provider_errors_total", "provider" => info.provider_name, "status" => category.clone()).increment(1);
                 histogram!("provider_request_duration_seconds", "provider" => info.provider_name)
@@ -599,7 +696,7 @@ fn wrap_provider_stream(
                     yield Ok(event);
                 }
                 Err(error) => {
-                    health.record_failure(provider_name);
+                    health.record_error(provider_name, &error);
                     histogram!("provider_request_duration_seconds", "provider" => provider_name)
                         .record(request_start.elapsed().as_secs_f64());
                     let info = ModelResponderInfo {
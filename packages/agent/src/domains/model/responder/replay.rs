@@ -0,0 +1,51 @@
+//! Replay responders opened from recorded provider transcripts.
+//!
+//! Keeps the transcript format and [`ReplayProvider`] behind the responder
+//! boundary: agent code receives an ordinary [`ModelResponder`] plus the
+//! prompt the recorded run started from.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{
+    ModelResponder, ModelResponderHealth, ModelResponderInfo, ModelResponseError,
+    ProviderBackedModelResponder,
+};
+use crate::domains::model::providers::replay::{ReplayProvider, ReplayTranscript};
+use crate::shared::protocol::messages::Provider as ProviderType;
+
+/// Responder that replays a recorded transcript, with the run inputs it needs.
+pub struct ReplayResponder {
+    /// Responder serving the recorded turns in order.
+    pub responder: Arc<dyn ModelResponder>,
+    /// System prompt the recorded run used, when one was set.
+    pub system_prompt: Option<String>,
+    /// User prompt the recorded run started from.
+    pub prompt: String,
+    /// Number of provider requests the transcript can answer.
+    pub recorded_turns: usize,
+}
+
+/// Open the transcript at `path` as a [`ReplayResponder`].
+pub fn open_replay_responder(path: &Path) -> Result<ReplayResponder, ModelResponseError> {
+    let transcript = ReplayTranscript::load(path).map_err(|error| {
+        ModelResponseError::from_provider_error(
+            error,
+            &ModelResponderInfo {
+                provider_type: ProviderType::Unknown,
+                provider_name: "replay",
+                model: path.display().to_string(),
+                context_window: 0,
+            },
+        )
+    })?;
+    Ok(ReplayResponder {
+        responder: Arc::new(ProviderBackedModelResponder {
+            provider: Arc::new(ReplayProvider::new(&transcript)),
+            health: Arc::new(ModelResponderHealth::new()),
+        }),
+        system_prompt: transcript.system_prompt,
+        prompt: transcript.prompt,
+        recorded_turns: transcript.turns.len(),
+    })
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::shared::protocol::events::AssistantMessage;
 use crate::shared::protocol::messages::{Message, UserMessageContent};
 use crate::shared::protocol::model_audit::ProviderAuditPayloadKind;
 use futures::{StreamExt, stream};
@@ -325,3 +326,271 @@ fn provider_backed_request_audit_uses_stream_options_and_exact_payload() {
         serde_json::json!("Bearer ****")
     );
 }
+
+#[tokio::test]
+async fn open_circuit_fails_fast_with_retryable_rate_limit() {
+    let health = Arc::new(ModelResponderHealth::with_circuit_breaker(
+        CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: std::time::Duration::from_secs(60),
+        },
+    ));
+    health.record_failure("openai");
+    let responder = ProviderBackedModelResponder {
+        provider: Arc::new(AuditProvider),
+        health,
+    };
+    let request = ModelResponseRequest {
+        context: Context::default(),
+        session_id: "sess-1".to_owned(),
+        reasoning_level: None,
+        trace_id: None,
+        parent_invocation_id: None,
+        cancel: CancellationToken::new(),
+        retry_config: None,
+    };
+
+    let Err(error) = responder.respond(request).await else {
+        panic!("open circuit should reject the request");
+    };
+    let failure = error.failure();
+
+    assert_eq!(failure.category, FailureCategory::RateLimit);
+    assert!(failure.retryable);
+    assert!(failure.message.contains("circuit open"));
+    assert!(
+        failure.details.as_ref().unwrap()["retryAfterMs"]
+            .as_u64()
+            .unwrap()
+            > 0
+    );
+}
+
+/// Provider whose stream fails to open with a fixed error.
+struct FailingProvider(fn() -> ProviderError);
+
+#[async_trait]
+impl Provider for FailingProvider {
+    fn provider_type(&self) -> crate::shared::protocol::messages::Provider {
+        crate::shared::protocol::messages::Provider::OpenAi
+    }
+
+    fn model(&self) -> &str {
+        "gpt-5.5-codex"
+    }
+
+    async fn stream(
+        &self,
+        _context: &Context,
+        _options: &ProviderStreamOptions,
+    ) -> crate::domains::model::providers::shared::provider::ProviderResult<StreamEventStream> {
+        Err((self.0)())
+    }
+}
+
+async fn fail_repeatedly(health: &Arc<ModelResponderHealth>, error: fn() -> ProviderError) {
+    let responder = ProviderBackedModelResponder {
+        provider: Arc::new(FailingProvider(error)),
+        health: health.clone(),
+    };
+    for _ in 0..3 {
+        let Err(error) = responder.respond(completion_request()).await else {
+            panic!("failing provider should fail");
+        };
+        assert!(!error.failure().message.contains("circuit"));
+    }
+}
+
+fn bad_request() -> ProviderError {
+    ProviderError::Api {
+        status: 400,
+        message: "prompt is too long".into(),
+        code: None,
+        retryable: false,
+    }
+}
+
+fn overloaded() -> ProviderError {
+    ProviderError::Api {
+        status: 529,
+        message: "overloaded".into(),
+        code: Some("overloaded_error".into()),
+        retryable: true,
+    }
+}
+
+#[tokio::test]
+async fn non_retryable_errors_never_open_the_circuit() {
+    let health = Arc::new(ModelResponderHealth::with_circuit_breaker(
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown: std::time::Duration::from_secs(60),
+        },
+    ));
+    fail_repeatedly(&health, bad_request).await;
+    fail_repeatedly(&health, || ProviderError::Auth {
+        message: "expired".into(),
+    })
+    .await;
+
+    assert!(health.check_circuit("openai").is_ok());
+}
+
+#[tokio::test]
+async fn retryable_errors_open_a_configured_circuit() {
+    let health = Arc::new(ModelResponderHealth::with_circuit_breaker(
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            cooldown: std::time::Duration::from_secs(60),
+        },
+    ));
+    let responder = ProviderBackedModelResponder {
+        provider: Arc::new(FailingProvider(overloaded)),
+        health: health.clone(),
+    };
+    for _ in 0..2 {
+        assert!(responder.respond(completion_request()).await.is_err());
+    }
+
+    assert!(health.check_circuit("openai").is_err());
+}
+
+#[tokio::test]
+async fn circuit_breaker_is_disabled_unless_configured() {
+    let defaults = crate::domains::settings::RetrySettings::default();
+    assert_eq!(defaults.circuit_breaker.failure_threshold, 0);
+
+    let health = Arc::new(ModelResponderHealth::from_settings(&defaults));
+    fail_repeatedly(&health, overloaded).await;
+    fail_repeatedly(&health, overloaded).await;
+    assert!(health.check_circuit("openai").is_ok());
+
+    let mut configured = defaults;
+    configured.circuit_breaker.failure_threshold = 1;
+    let health = Arc::new(ModelResponderHealth::from_settings(&configured));
+    health.record_failure("openai");
+    assert!(health.check_circuit("openai").is_err());
+}
+
+fn completion_request() -> ModelResponseRequest {
+    ModelResponseRequest {
+        context: Context::default(),
+        session_id: "sess-complete".to_owned(),
+        reasoning_level: None,
+        trace_id: None,
+        parent_invocation_id: None,
+        cancel: CancellationToken::new(),
+        retry_config: None,
+    }
+}
+
+#[tokio::test]
+async fn complete_returns_accumulated_text_and_usage_from_mock_provider() {
+    use crate::domains::model::providers::mock::{MockProvider, mock_usage, text_turn};
+
+    let responder =
+        provider_backed_responder(Arc::new(MockProvider::new(vec![text_turn("final answer")])));
+
+    let completion = responder
+        .complete(completion_request())
+        .await
+        .expect("completion");
+
+    assert_eq!(completion.text(), "final answer");
+    assert_eq!(completion.stop_reason, "end_turn");
+    assert_eq!(completion.message.token_usage, Some(mock_usage()));
+    assert_eq!(completion.info.model, "mock-model");
+}
+
+#[tokio::test]
+async fn complete_uses_streamed_text_when_done_content_is_empty() {
+    use crate::domains::model::providers::mock::{MockProvider, mock_usage};
+
+    let responder = provider_backed_responder(Arc::new(MockProvider::new(vec![vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta {
+            delta: "streamed ".into(),
+        },
+        StreamEvent::TextDelta {
+            delta: "only".into(),
+        },
+        StreamEvent::Done {
+            message: AssistantMessage {
+                content: Vec::new(),
+                token_usage: Some(mock_usage()),
+            },
+            stop_reason: "end_turn".into(),
+        },
+    ]])));
+
+    let completion = responder
+        .complete(completion_request())
+        .await
+        .expect("completion");
+
+    assert_eq!(completion.text(), "streamed only");
+    assert_eq!(completion.message.token_usage, Some(mock_usage()));
+}
+
+#[tokio::test]
+async fn complete_rejects_stream_that_ends_without_done() {
+    use crate::domains::model::providers::mock::MockProvider;
+
+    let responder = provider_backed_responder(Arc::new(MockProvider::new(vec![vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta {
+            delta: "cut off".into(),
+        },
+    ]])));
+
+    let error = responder
+        .complete(completion_request())
+        .await
+        .expect_err("truncated stream");
+
+    assert_eq!(error.failure().code, MODEL_STREAM_TRUNCATED);
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn partial_completion_keeps_streamed_text_when_done_never_arrives() {
+    use crate::domains::model::providers::mock::MockProvider;
+
+    let responder = provider_backed_responder(Arc::new(MockProvider::new(vec![vec![
+        StreamEvent::Start,
+        StreamEvent::TextDelta {
+            delta: "cut off".into(),
+        },
+    ]])));
+
+    let completion = responder
+        .respond(completion_request())
+        .await
+        .expect("response")
+        .into_partial_completion()
+        .await
+        .expect("partial completion");
+
+    assert_eq!(completion.text(), "cut off");
+    assert_eq!(completion.stop_reason, super::STREAM_ENDED_STOP_REASON);
+    assert_eq!(completion.message.token_usage, None);
+}
+
+#[tokio::test]
+async fn partial_completion_rejects_stream_that_ends_without_text() {
+    use crate::domains::model::providers::mock::MockProvider;
+
+    let responder =
+        provider_backed_responder(Arc::new(MockProvider::new(vec![vec![StreamEvent::Start]])));
+
+    let error = responder
+        .respond(completion_request())
+        .await
+        .expect("response")
+        .into_partial_completion()
+        .await
+        .expect_err("truncated stream");
+
+    assert_eq!(error.failure().code, MODEL_STREAM_TRUNCATED);
+    assert!(error.is_retryable());
+}
@@ -6,12 +6,19 @@
 //! and the model switch helper is a plain domain function rather than a
 //! transport dispatch branch.
 //!
+//! `model.switch` rejections are typed: `MODEL_UNKNOWN` for ids no registry
+//! knows, `AUTH_NOT_CONFIGURED` when the inferred provider has no stored
+//! credential, and `MODEL_NOT_AVAILABLE` for retired models or models the
+//! active auth plan does not offer.
+//!
 //! Model data is derived from the provider registries (single source of truth).
 //! See `anthropic/types.rs`, `openai/types.rs`, `google/types.rs`, `minimax/types.rs`.
 //!
-use serde_json::Value;
+use std::path::Path;
+
+use serde_json::{Value, json};
 
-use crate::domains::auth::credentials::OpenAIAuthPath;
+use crate::domains::auth::credentials::{AuthError, OpenAIAuthPath};
 use crate::domains::model::Deps;
 use crate::domains::model::providers::anthropic::types::{
     all_claude_models_api_json, get_claude_model,
@@ -26,8 +33,12 @@ use crate::domains::model::providers::openai::types::openai_model_available_for_
 use crate::domains::model::providers::openai::types::{
     all_openai_models_api_json_for_auth_path, get_openai_model,
 };
-use crate::domains::model::routing::models::registry::strip_provider_prefix;
+use crate::domains::model::routing::models::registry::{
+    detect_provider_from_model, provider_auth_key, strip_provider_prefix,
+};
+use crate::shared::protocol::messages::Provider;
 use crate::shared::server::errors::{self, CapabilityError};
+use crate::shared::server::failure::{FailureCategory, FailureEnvelope, FailureOrigin};
 use crate::shared::server::params::require_string_param;
 
 /// All known models, derived from provider registries (single source of truth).
@@ -69,44 +80,134 @@ pub(crate) fn is_model_retired(model_id: &str) -> bool {
     false
 }
 
-pub(crate) fn active_openai_auth_path(deps: &Deps) -> OpenAIAuthPath {
-    crate::domains::auth::credentials::openai::infer_auth_path(&deps.auth_path, None)
-        .unwrap_or(OpenAIAuthPath::ChatGptCodex)
+/// Whether `auth.json` holds a usable credential for `provider`.
+///
+/// Providers without stored credentials (local Ollama) always pass.
+fn provider_has_credential(provider: Provider, auth_path: &Path) -> Result<bool, AuthError> {
+    let Some(key) = provider_auth_key(provider) else {
+        return Ok(true);
+    };
+    let provider_auth = if provider == Provider::Google {
+        crate::domains::auth::credentials::get_google_provider_auth(auth_path)?
+            .map(|auth| auth.base)
+    } else {
+        crate::domains::auth::credentials::get_provider_auth(auth_path, key)?
+    };
+    Ok(provider_auth.is_some_and(|auth| {
+        crate::domains::auth::credentials::resolve_credential(&auth, None).is_some()
+    }))
 }
 
-/// Switch the model for a session.
-pub(crate) async fn switch_model(
-    params: Option<&Value>,
-    deps: &Deps,
-) -> Result<Value, CapabilityError> {
-    let session_id = require_string_param(params, "sessionId")?;
-    let requested_model = require_string_param(params, "model")?;
-    let model = strip_provider_prefix(&requested_model).to_string();
+fn model_not_available(
+    model: &str,
+    provider: Provider,
+    message: String,
+    details: Value,
+) -> CapabilityError {
+    CapabilityError::from_failure(
+        FailureEnvelope::new(
+            errors::MODEL_NOT_AVAILABLE,
+            FailureCategory::InvalidModel,
+            message,
+            false,
+            true,
+            FailureOrigin::Server,
+        )
+        .with_provider_model(provider.as_str(), model)
+        .with_details(Some(details)),
+    )
+}
 
-    if !is_model_supported(&model) {
-        return Err(CapabilityError::InvalidParams {
-            message: format!("Unknown model: {requested_model}"),
-        });
-    }
+/// Validate a `model.switch` target and return the bare model id to persist.
+///
+/// Checks run in order: registry membership, retirement, provider credential,
+/// then plan availability (`OpenAI` models differ per auth path).
+pub(crate) fn validate_switch_target(
+    requested_model: &str,
+    auth_path: &Path,
+) -> Result<String, CapabilityError> {
+    let model = strip_provider_prefix(requested_model).to_string();
+    let provider = is_model_supported(&model)
+        .then(|| detect_provider_from_model(&model))
+        .flatten();
+    let Some(provider) = provider else {
+        return Err(CapabilityError::from_failure(
+            FailureEnvelope::new(
+                errors::MODEL_UNKNOWN,
+                FailureCategory::InvalidModel,
+                format!("Unknown model: {requested_model}"),
+                false,
+                true,
+                FailureOrigin::Server,
+            )
+            .with_details(Some(json!({ "model": requested_model })))
+            .with_suggestion(Some("Choose a model returned by `model.list`.".to_owned())),
+        ));
+    };
 
     if is_model_retired(&model) {
-        return Err(CapabilityError::InvalidParams {
-            message: format!("Model '{model}' is retired and cannot be selected"),
-        });
+        return Err(model_not_available(
+            &model,
+            provider,
+            format!("Model '{model}' is retired and cannot be selected"),
+            json!({ "model": model, "provider": provider.as_str(), "reason": "retired" }),
+        ));
+    }
+
+    if !provider_has_credential(provider, auth_path)
+        .map_err(crate::domains::auth::credentials::map_auth_error)?
+    {
+        return Err(CapabilityError::from_failure(
+            FailureEnvelope::new(
+                errors::AUTH_NOT_CONFIGURED,
+                FailureCategory::Auth,
+                format!("No auth configured for provider {provider} (model '{model}')"),
+                false,
+                true,
+                FailureOrigin::Auth,
+            )
+            .with_provider_model(provider.as_str(), &model)
+            .with_details(Some(
+                json!({ "model": model, "provider": provider.as_str() }),
+            ))
+            .with_suggestion(Some("Add credentials in Settings > Providers.".to_owned())),
+        ));
     }
 
     if get_openai_model(&model).is_some() {
-        let auth_path = active_openai_auth_path(deps);
-        if !openai_model_available_for_auth_path(&model, auth_path) {
-            return Err(CapabilityError::InvalidParams {
-                message: format!(
+        let openai_auth_path =
+            crate::domains::auth::credentials::openai::infer_auth_path(auth_path, None)
+                .unwrap_or(OpenAIAuthPath::ChatGptCodex);
+        if !openai_model_available_for_auth_path(&model, openai_auth_path) {
+            return Err(model_not_available(
+                &model,
+                provider,
+                format!(
                     "OpenAI model '{model}' is not available for the active auth path ({})",
-                    auth_path.as_str()
+                    openai_auth_path.as_str()
                 ),
-            });
+                json!({
+                    "model": model,
+                    "provider": provider.as_str(),
+                    "reason": "auth_path",
+                    "authPath": openai_auth_path.as_str(),
+                }),
+            ));
         }
     }
 
+    Ok(model)
+}
+
+/// Switch the model for a session.
+pub(crate) async fn switch_model(
+    params: Option<&Value>,
+    deps: &Deps,
+) -> Result<Value, CapabilityError> {
+    let session_id = require_string_param(params, "sessionId")?;
+    let requested_model = require_string_param(params, "model")?;
+    let model = validate_switch_target(&requested_model, &deps.auth_path)?;
+
     let session = deps
         .event_store
         .get_session(&session_id)
@@ -166,3 +267,75 @@ pub(crate) async fn switch_model(
         "newModel": model,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::auth::credentials::OAuthTokens;
+    use crate::domains::auth::credentials::storage::{
+        save_account_oauth_tokens, save_named_api_key,
+    };
+    use crate::domains::model::routing::models::model_ids::{
+        CLAUDE_3_7_SONNET, CLAUDE_OPUS_4_6, GPT_5_4_NANO,
+    };
+
+    fn auth_file() -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("auth.json");
+        (dir, path)
+    }
+
+    #[test]
+    fn switch_target_rejects_unknown_model() {
+        let (_dir, path) = auth_file();
+        let err = validate_switch_target("not-a-model", &path).unwrap_err();
+        assert_eq!(err.code(), errors::MODEL_UNKNOWN);
+        assert!(err.to_string().contains("not-a-model"));
+    }
+
+    #[test]
+    fn switch_target_rejects_provider_without_auth() {
+        let (_dir, path) = auth_file();
+        let err = validate_switch_target(CLAUDE_OPUS_4_6, &path).unwrap_err();
+        assert_eq!(err.code(), errors::AUTH_NOT_CONFIGURED);
+        let failure = err.to_failure(FailureOrigin::Server);
+        assert_eq!(failure.provider.as_deref(), Some("anthropic"));
+        assert_eq!(failure.model.as_deref(), Some(CLAUDE_OPUS_4_6));
+    }
+
+    #[test]
+    fn switch_target_rejects_model_outside_active_plan() {
+        let (_dir, path) = auth_file();
+        save_account_oauth_tokens(
+            &path,
+            crate::domains::auth::credentials::openai::PROVIDER_KEY,
+            "work",
+            &OAuthTokens {
+                access_token: "access".into(),
+                refresh_token: "refresh".into(),
+                expires_at: i64::MAX,
+            },
+        )
+        .unwrap();
+        let err = validate_switch_target(GPT_5_4_NANO, &path).unwrap_err();
+        assert_eq!(err.code(), errors::MODEL_NOT_AVAILABLE);
+        assert_eq!(err.details().unwrap()["reason"], "auth_path");
+    }
+
+    #[test]
+    fn switch_target_rejects_retired_model_as_not_available() {
+        let (_dir, path) = auth_file();
+        save_named_api_key(&path, "anthropic", "Default", "sk-ant-test").unwrap();
+        let err = validate_switch_target(CLAUDE_3_7_SONNET, &path).unwrap_err();
+        assert_eq!(err.code(), errors::MODEL_NOT_AVAILABLE);
+        assert_eq!(err.details().unwrap()["reason"], "retired");
+    }
+
+    #[test]
+    fn switch_target_accepts_authenticated_model_and_strips_prefix() {
+        let (_dir, path) = auth_file();
+        save_named_api_key(&path, "anthropic", "Default", "sk-ant-test").unwrap();
+        let model = validate_switch_target(&format!("anthropic/{CLAUDE_OPUS_4_6}"), &path).unwrap();
+        assert_eq!(model, CLAUDE_OPUS_4_6);
+    }
+}
@@ -4,9 +4,11 @@
 //! Provider-specific registries remain authoritative. Cross-provider helpers
 //! in [`registry`] deliberately use conservative defaults when metadata depends
 //! on runtime credentials, such as OpenAI's Platform API key vs ChatGPT/Codex
-//! OAuth profiles.
+//! OAuth profiles. [`output_budget`] resolves the caller's requested output
+//! budget and clamps it to the registry maximum.
 
 pub mod model_ids;
+pub mod output_budget;
 pub mod registry;
 pub mod types;
 
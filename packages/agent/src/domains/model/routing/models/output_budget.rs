@@ -0,0 +1,99 @@
+//! Output-token budgets.
+//!
+//! Callers request an output budget through [`ProviderStreamOptions`]; each
+//! provider clamps it to the model's registry maximum before building its
+//! request so an oversized budget never reaches the API.
+
+use crate::domains::model::providers::anthropic::types::get_claude_model;
+use crate::domains::model::providers::google::types::get_gemini_model;
+use crate::domains::model::providers::kimi::types::get_kimi_model;
+use crate::domains::model::providers::minimax::types::get_minimax_model;
+use crate::domains::model::providers::ollama::types::get_ollama_model;
+use crate::domains::model::providers::openai::types::get_openai_model;
+use crate::domains::model::providers::shared::provider::ProviderStreamOptions;
+
+use super::registry::strip_provider_prefix;
+
+impl ProviderStreamOptions {
+    /// Caller-requested output budget: `max_output_tokens`, then `max_tokens`.
+    #[must_use]
+    pub fn requested_output_tokens(&self) -> Option<u32> {
+        self.max_output_tokens.or(self.max_tokens)
+    }
+}
+
+/// Maximum output tokens for a model, from its provider registry.
+///
+/// `OpenAI` uses the conservative default profile; provider instances with
+/// auth context clamp against the active profile instead. Unknown models
+/// return `None`.
+pub fn model_max_output_tokens(model_id: &str) -> Option<u32> {
+    let bare = strip_provider_prefix(model_id);
+    let max_output = if let Some(m) = get_claude_model(bare) {
+        u64::from(m.max_output)
+    } else if let Some(m) = get_openai_model(bare) {
+        m.default_profile().max_output
+    } else if let Some(m) = get_gemini_model(bare) {
+        m.max_output
+    } else if let Some(m) = get_minimax_model(bare) {
+        u64::from(m.max_output)
+    } else if let Some(m) = get_kimi_model(bare) {
+        u64::from(m.max_output)
+    } else if let Some(m) = get_ollama_model(bare) {
+        u64::from(m.max_output)
+    } else {
+        return None;
+    };
+    Some(u32::try_from(max_output).unwrap_or(u32::MAX))
+}
+
+/// Clamp a requested output budget to the model's registry maximum.
+///
+/// Unknown models pass the request through unchanged.
+pub fn clamp_output_tokens(model_id: &str, requested: u32) -> u32 {
+    model_max_output_tokens(model_id).map_or(requested, |max| requested.min(max))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::model::routing::models::model_ids::*;
+
+    #[test]
+    fn requested_output_tokens_prefers_provider_agnostic_budget() {
+        let opts = ProviderStreamOptions {
+            max_tokens: Some(4096),
+            max_output_tokens: Some(2048),
+            ..Default::default()
+        };
+        assert_eq!(opts.requested_output_tokens(), Some(2048));
+        let legacy = ProviderStreamOptions {
+            max_tokens: Some(4096),
+            ..Default::default()
+        };
+        assert_eq!(legacy.requested_output_tokens(), Some(4096));
+        let json = serde_json::to_value(&opts).unwrap();
+        assert_eq!(json["maxOutputTokens"], 2048);
+    }
+
+    #[test]
+    fn max_output_tokens_from_registry() {
+        assert_eq!(model_max_output_tokens(CLAUDE_OPUS_4_6), Some(128_000));
+        assert_eq!(
+            model_max_output_tokens(&format!("anthropic/{CLAUDE_OPUS_4_6}")),
+            Some(128_000)
+        );
+        assert_eq!(model_max_output_tokens("unknown-model"), None);
+    }
+
+    #[test]
+    fn clamp_output_tokens_caps_known_models_only() {
+        assert_eq!(clamp_output_tokens(CLAUDE_OPUS_4_6, 1_000_000), 128_000);
+        assert_eq!(clamp_output_tokens(CLAUDE_OPUS_4_6, 2048), 2048);
+        assert_eq!(clamp_output_tokens("unknown-model", 1_000_000), 1_000_000);
+    }
+}
@@ -84,6 +84,21 @@ pub fn is_model_supported(model_id: &str) -> bool {
         || get_ollama_model(bare).is_some()
 }
 
+/// `auth.json` provider entry whose credentials serve `provider`.
+///
+/// Returns `None` for providers that run without stored credentials (local
+/// Ollama) and for unrecognized providers.
+pub fn provider_auth_key(provider: Provider) -> Option<&'static str> {
+    match provider {
+        Provider::Anthropic => Some("anthropic"),
+        Provider::OpenAi | Provider::OpenAiCodex => Some("openai-codex"),
+        Provider::Google => Some("google"),
+        Provider::MiniMax => Some("minimax"),
+        Provider::Kimi => Some("kimi"),
+        Provider::Ollama | Provider::Unknown => None,
+    }
+}
+
 /// Check if a model supports image inputs.
 ///
 /// Looks up all three provider registries. Unknown models default to `true`.
@@ -225,6 +240,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_auth_key_matches_auth_storage_entries() {
+        assert_eq!(provider_auth_key(Provider::Anthropic), Some("anthropic"));
+        assert_eq!(
+            provider_auth_key(Provider::OpenAi),
+            Some(crate::domains::auth::credentials::openai::PROVIDER_KEY)
+        );
+        assert_eq!(provider_auth_key(Provider::Google), Some("google"));
+        assert_eq!(provider_auth_key(Provider::Ollama), None);
+    }
+
     #[test]
     fn detect_explicit_prefix_unknown() {
         assert_eq!(detect_provider_from_model("unknown/some-model"), None);
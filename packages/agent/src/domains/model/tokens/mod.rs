@@ -8,6 +8,9 @@
 //! consume the typed [`TokenRecord`] rather than recomputing provider-specific
 //! semantics locally. Reasoning/thought token counts are metadata-only audit
 //! facts; they do not imply raw hidden reasoning content is stored or displayed.
+//! Context-window pressure (`context_window_tokens / context_limit`) is tracked
+//! per session in [`pressure`] so the runtime can emit one `ContextWarning` per
+//! threshold crossing.
 //!
 //! INVARIANT: token accounting is server-authoritative. A missing provider,
 //! unknown model price, absent turn number, or partial provider usage must not
@@ -15,5 +18,7 @@
 
 pub mod errors;
 pub mod normalization;
+pub mod pressure;
 pub mod pricing;
+pub mod pricing_overrides;
 pub mod types;
@@ -0,0 +1,166 @@
+//! Context-window pressure tracking.
+//!
+//! Pressure is `context_window_tokens / context_limit` after each turn.
+//! [`ContextPressureTracker`] reports a threshold the first time pressure
+//! reaches it and stays quiet until pressure falls back below it (for example
+//! after compaction), so each crossing yields exactly one warning. Agents
+//! are rebuilt for every prompt, so the orchestrator keeps each session's
+//! tracker between prompts and [`ContextPressureTracker::resume_from`] hands
+//! it to the next one.
+
+/// Thresholds warned about when none are configured.
+pub const DEFAULT_CONTEXT_PRESSURE_THRESHOLDS: [f64; 2] = [0.7, 0.9];
+
+/// Context-window usage as a fraction of the model's limit.
+///
+/// Returns `None` when the limit is unknown (`0`).
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // Token counts never approach 2^52
+pub fn context_pressure_ratio(context_window_tokens: u64, context_limit: u64) -> Option<f64> {
+    (context_limit > 0).then(|| context_window_tokens as f64 / context_limit as f64)
+}
+
+/// A threshold newly reached by a recorded turn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextPressureCrossing {
+    /// Threshold that was crossed (fraction of the limit).
+    pub threshold: f64,
+    /// Pressure ratio of the turn that crossed it.
+    pub ratio: f64,
+}
+
+impl ContextPressureCrossing {
+    /// Usage as a percentage of the context limit.
+    #[must_use]
+    pub fn usage_percent(&self) -> f64 {
+        self.ratio * 100.0
+    }
+}
+
+/// Tracks which pressure thresholds a session is currently above.
+#[derive(Clone, Debug)]
+pub struct ContextPressureTracker {
+    thresholds: Vec<f64>,
+    above: Vec<bool>,
+}
+
+impl ContextPressureTracker {
+    /// Track `thresholds`; values outside `(0, 1]` or non-finite are ignored.
+    #[must_use]
+    pub fn new(thresholds: &[f64]) -> Self {
+        let mut thresholds: Vec<f64> = thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| threshold.is_finite() && *threshold > 0.0 && *threshold <= 1.0)
+            .collect();
+        thresholds.sort_by(f64::total_cmp);
+        thresholds.dedup();
+        let above = vec![false; thresholds.len()];
+        Self { thresholds, above }
+    }
+
+    /// Record one turn's context usage and return the thresholds it newly
+    /// crossed, lowest first.
+    pub fn record(
+        &mut self,
+        context_window_tokens: u64,
+        context_limit: u64,
+    ) -> Vec<ContextPressureCrossing> {
+        let Some(ratio) = context_pressure_ratio(context_window_tokens, context_limit) else {
+            return Vec::new();
+        };
+        let mut crossings = Vec::new();
+        for (threshold, above) in self.thresholds.iter().zip(self.above.iter_mut()) {
+            let now_above = ratio >= *threshold;
+            if now_above && !*above {
+                crossings.push(ContextPressureCrossing {
+                    threshold: *threshold,
+                    ratio,
+                });
+            }
+            *above = now_above;
+        }
+        crossings
+    }
+
+    /// Take over which thresholds `previous` was above, for thresholds both
+    /// trackers share. Thresholds new to this tracker start below.
+    pub fn resume_from(&mut self, previous: &Self) {
+        for (threshold, above) in self.thresholds.iter().zip(self.above.iter_mut()) {
+            *above = previous
+                .thresholds
+                .iter()
+                .position(|prior| prior == threshold)
+                .is_some_and(|index| previous.above[index]);
+        }
+    }
+}
+
+impl Default for ContextPressureTracker {
+    fn default() -> Self {
+        Self::new(&DEFAULT_CONTEXT_PRESSURE_THRESHOLDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholds(crossings: &[ContextPressureCrossing]) -> Vec<f64> {
+        crossings
+            .iter()
+            .map(|crossing| crossing.threshold)
+            .collect()
+    }
+
+    #[test]
+    fn ratio_is_none_without_a_limit() {
+        assert_eq!(context_pressure_ratio(500, 1_000), Some(0.5));
+        assert_eq!(context_pressure_ratio(500, 0), None);
+    }
+
+    #[test]
+    fn each_threshold_fires_once_per_crossing() {
+        let mut tracker = ContextPressureTracker::default();
+
+        assert!(tracker.record(50_000, 100_000).is_empty());
+        let first = tracker.record(72_000, 100_000);
+        assert_eq!(thresholds(&first), vec![0.7]);
+        assert!((first[0].usage_percent() - 72.0).abs() < 1e-9);
+        assert!(tracker.record(80_000, 100_000).is_empty());
+        assert_eq!(thresholds(&tracker.record(91_000, 100_000)), vec![0.9]);
+        assert!(tracker.record(95_000, 100_000).is_empty());
+    }
+
+    #[test]
+    fn jump_past_several_thresholds_reports_each_once() {
+        let mut tracker = ContextPressureTracker::default();
+        assert_eq!(thresholds(&tracker.record(95_000, 100_000)), vec![0.7, 0.9]);
+        assert!(tracker.record(99_000, 100_000).is_empty());
+    }
+
+    #[test]
+    fn dropping_below_a_threshold_rearms_it() {
+        let mut tracker = ContextPressureTracker::default();
+        assert_eq!(thresholds(&tracker.record(75_000, 100_000)), vec![0.7]);
+        assert!(tracker.record(30_000, 100_000).is_empty());
+        assert_eq!(thresholds(&tracker.record(70_000, 100_000)), vec![0.7]);
+    }
+
+    #[test]
+    fn resumed_tracker_stays_quiet_for_thresholds_already_reported() {
+        let mut earlier = ContextPressureTracker::default();
+        assert_eq!(thresholds(&earlier.record(75_000, 100_000)), vec![0.7]);
+
+        let mut resumed = ContextPressureTracker::new(&[0.7, 0.8]);
+        resumed.resume_from(&earlier);
+        assert_eq!(thresholds(&resumed.record(85_000, 100_000)), vec![0.8]);
+    }
+
+    #[test]
+    fn custom_thresholds_are_sorted_and_invalid_values_ignored() {
+        let mut tracker = ContextPressureTracker::new(&[0.9, 0.5, -1.0, f64::NAN, 1.5, 0.5]);
+        assert_eq!(thresholds(&tracker.record(95, 100)), vec![0.5, 0.9]);
+        assert!(tracker.record(1_000, 0).is_empty());
+    }
+}
@@ -4,24 +4,38 @@
 //! Anthropic's per-TTL cache pricing (5-minute and 1-hour tiers), prompt-cache
 //! hit/write buckets for cache-aware providers, and unavailable pricing for any
 //! model not listed here.
+//!
+//! Configured overrides from [`super::pricing_overrides`] win over this table.
 
 use crate::shared::protocol::messages::TokenUsage;
 
+use super::pricing_overrides::configured_tier;
 use super::types::{PricingRecord, PricingTier, TokenCostBreakdown};
 
 /// Look up the pricing tier for a model identifier.
 ///
-/// Returns `None` for unknown models (no implicit default pricing).
+/// Configured overrides win over the built-in table. Returns `None` for
+/// unknown models (no implicit default pricing).
 #[must_use]
 pub fn get_pricing_tier(model: &str) -> Option<PricingTier> {
-    exact_match(model)
+    configured_tier(model)
 }
 
 /// Calculate server-authoritative component pricing for a token record.
 #[must_use]
-#[allow(clippy::cast_precision_loss)] // Token counts never approach 2^52
 pub fn calculate_pricing(model: &str, usage: &TokenUsage) -> PricingRecord {
-    let Some(tier) = get_pricing_tier(model) else {
+    price_usage(model, get_pricing_tier(model), usage)
+}
+
+// ─── Internal helpers ────────────────────────────────────────────────────────
+
+#[allow(clippy::cast_precision_loss)] // Token counts never approach 2^52
+pub(super) fn price_usage(
+    model: &str,
+    tier: Option<PricingTier>,
+    usage: &TokenUsage,
+) -> PricingRecord {
+    let Some(tier) = tier else {
         return PricingRecord::unavailable(model, "unsupported_model_pricing");
     };
     let Some(provider) = usage.provider_type else {
@@ -94,8 +108,6 @@ pub fn calculate_pricing(model: &str, usage: &TokenUsage) -> PricingRecord {
     }
 }
 
-// ─── Internal helpers ────────────────────────────────────────────────────────
-
 /// Create an Anthropic pricing tier.
 fn anthropic_tier(input: f64, output: f64) -> PricingTier {
     PricingTier {
@@ -141,7 +153,7 @@ fn openai_uncached_tier(input: f64, output: f64) -> PricingTier {
 }
 
 /// Exact model name matching.
-fn exact_match(model: &str) -> Option<PricingTier> {
+pub(super) fn exact_match(model: &str) -> Option<PricingTier> {
     Some(match model {
         // Anthropic — Opus 4.5/4.6
         "claude-opus-4-6" | "claude-opus-4-5" => anthropic_tier(5.0, 25.0),
@@ -0,0 +1,172 @@
+//! Configured model pricing overrides.
+//!
+//! `agent.pricingOverrides` entries replace the built-in tier for the same
+//! model id, with or without a `provider/` prefix; cache multipliers an entry
+//! leaves out keep the built-in tier's values. Bootstrap installs them on
+//! startup and on every settings reload; settings validation has already
+//! rejected negative or non-finite prices.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+use crate::domains::model::routing::models::strip_provider_prefix;
+use crate::domains::settings::ModelPricingOverride;
+
+use super::pricing::exact_match;
+use super::types::PricingTier;
+
+static PRICING_OVERRIDES: LazyLock<RwLock<HashMap<String, PricingTier>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Replace the process-wide pricing overrides with `overrides`.
+pub fn set_pricing_overrides(overrides: &HashMap<String, ModelPricingOverride>) {
+    *PRICING_OVERRIDES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = override_tiers(overrides);
+}
+
+/// The tier for `model`: its configured override, else the built-in tier.
+pub(super) fn configured_tier(model: &str) -> Option<PricingTier> {
+    let overrides = PRICING_OVERRIDES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    resolve_tier(model, &overrides)
+}
+
+/// Overrides as tiers keyed by bare model id.
+fn override_tiers(
+    overrides: &HashMap<String, ModelPricingOverride>,
+) -> HashMap<String, PricingTier> {
+    overrides
+        .iter()
+        .map(|(model, pricing)| {
+            let model = strip_provider_prefix(model);
+            (model.to_owned(), override_tier(pricing, exact_match(model)))
+        })
+        .collect()
+}
+
+fn override_tier(pricing: &ModelPricingOverride, built_in: Option<PricingTier>) -> PricingTier {
+    let multiplier = |configured: Option<f64>, field: fn(&PricingTier) -> f64| {
+        configured
+            .or_else(|| built_in.as_ref().map(field))
+            .unwrap_or(1.0)
+    };
+    PricingTier {
+        input_per_million: pricing.input_per_million,
+        output_per_million: pricing.output_per_million,
+        cache_write_5m_multiplier: multiplier(pricing.cache_write_5m_multiplier, |tier| {
+            tier.cache_write_5m_multiplier
+        }),
+        cache_write_1h_multiplier: multiplier(pricing.cache_write_1h_multiplier, |tier| {
+            tier.cache_write_1h_multiplier
+        }),
+        cache_read_multiplier: multiplier(pricing.cache_read_multiplier, |tier| {
+            tier.cache_read_multiplier
+        }),
+    }
+}
+
+fn resolve_tier(model: &str, overrides: &HashMap<String, PricingTier>) -> Option<PricingTier> {
+    let model = strip_provider_prefix(model);
+    overrides.get(model).cloned().or_else(|| exact_match(model))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::model::tokens::pricing::price_usage;
+    use crate::shared::protocol::messages::{Provider, TokenUsage};
+
+    fn assert_float_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < f64::EPSILON,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    fn override_pricing(input: f64, output: f64) -> ModelPricingOverride {
+        ModelPricingOverride {
+            input_per_million: input,
+            output_per_million: output,
+            cache_write_5m_multiplier: None,
+            cache_write_1h_multiplier: None,
+            cache_read_multiplier: Some(0.5),
+        }
+    }
+
+    #[test]
+    fn override_replaces_built_in_tier_by_model_id() {
+        let overrides = override_tiers(&HashMap::from([(
+            "gpt-4.1".to_owned(),
+            override_pricing(1.0, 4.0),
+        )]));
+        let tier = resolve_tier("gpt-4.1", &overrides).unwrap();
+        assert_float_eq(tier.input_per_million, 1.0);
+        assert_float_eq(tier.output_per_million, 4.0);
+        assert_float_eq(tier.cache_read_multiplier, 0.5);
+    }
+
+    #[test]
+    fn override_keeps_built_in_cache_multipliers_it_leaves_out() {
+        let overrides = override_tiers(&HashMap::from([
+            ("claude-opus-4-6".to_owned(), override_pricing(4.0, 20.0)),
+            ("unlisted-model".to_owned(), override_pricing(1.0, 2.0)),
+        ]));
+        let tier = resolve_tier("claude-opus-4-6", &overrides).unwrap();
+        assert_float_eq(tier.cache_write_5m_multiplier, 1.25);
+        assert_float_eq(tier.cache_write_1h_multiplier, 2.0);
+        assert_float_eq(tier.cache_read_multiplier, 0.5);
+
+        let tier = resolve_tier("unlisted-model", &overrides).unwrap();
+        assert_float_eq(tier.cache_write_5m_multiplier, 1.0);
+        assert_float_eq(tier.cache_write_1h_multiplier, 1.0);
+    }
+
+    #[test]
+    fn override_matches_with_or_without_provider_prefix() {
+        let overrides = override_tiers(&HashMap::from([(
+            "openai/gpt-4.1".to_owned(),
+            override_pricing(1.0, 4.0),
+        )]));
+        for model in ["gpt-4.1", "openai/gpt-4.1"] {
+            let tier = resolve_tier(model, &overrides).unwrap();
+            assert_float_eq(tier.input_per_million, 1.0);
+        }
+        assert_eq!(resolve_tier("openai/o3", &overrides), exact_match("o3"));
+    }
+
+    #[test]
+    fn missing_override_falls_back_to_built_in_tier() {
+        let overrides = override_tiers(&HashMap::from([(
+            "gpt-4.1".to_owned(),
+            override_pricing(1.0, 4.0),
+        )]));
+        assert_eq!(resolve_tier("o3", &overrides), exact_match("o3"));
+        assert_eq!(resolve_tier("unlisted-model", &overrides), None);
+    }
+
+    #[test]
+    fn override_changes_computed_cost() {
+        let model = "o1-preview";
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+            provider_type: Some(Provider::OpenAi),
+            ..Default::default()
+        };
+        let built_in = price_usage(model, exact_match(model), &usage);
+        assert_float_eq(built_in.cost.unwrap().total_cost, 75.0);
+
+        let overrides = override_tiers(&HashMap::from([(
+            model.to_owned(),
+            override_pricing(10.0, 20.0),
+        )]));
+        let overridden = price_usage(model, resolve_tier(model, &overrides), &usage);
+        assert_float_eq(overridden.cost.unwrap().total_cost, 30.0);
+    }
+}
@@ -89,6 +89,8 @@ pub struct CapabilitySpec {
     pub lifecycle: Option<serde_json::Value>,
     /// Generated UI presentation hints supplied by the owning domain.
     pub presentation_hints: Option<serde_json::Value>,
+    /// Engine-enforced bound on one handler call, for long-running functions.
+    pub handler_timeout: Option<std::time::Duration>,
 }
 
 /// Agent-facing canonical function contract.
@@ -10,6 +10,8 @@
 //! module contains only method-agnostic construction helpers used to turn those
 //! local records into engine definitions.
 
+use std::time::Duration;
+
 use serde_json::{Map, Value, json};
 
 use super::catalog::{CapabilitySpec, TransportIdempotencyMode};
@@ -69,6 +71,8 @@ pub(crate) struct CapabilityContract {
     /// use hints such as `themeColor`, but capability identity always comes
     /// from the contract.
     pub(crate) presentation_hints: Option<Value>,
+    /// Engine-enforced bound on one handler call.
+    pub(crate) handler_timeout: Option<Duration>,
 }
 
 impl CapabilityContract {
@@ -108,6 +112,7 @@ impl CapabilityContract {
             examples: Vec::new(),
             lifecycle: None,
             presentation_hints: None,
+            handler_timeout: None,
         }
     }
 
@@ -207,6 +212,12 @@ impl CapabilityContract {
         self
     }
 
+    /// Bound each handler call; the engine host fails calls that run longer.
+    pub(crate) fn handler_timeout(mut self, timeout: Duration) -> Self {
+        self.handler_timeout = Some(timeout);
+        self
+    }
+
     /// Attach stream topics.
     pub(crate) fn stream_topics(mut self, topics: Vec<&'static str>) -> Self {
         self.stream_topics = topics;
@@ -238,6 +249,7 @@ impl CapabilityContract {
             examples: self.examples,
             lifecycle: self.lifecycle,
             presentation_hints: self.presentation_hints,
+            handler_timeout: self.handler_timeout,
         })
     }
 }
@@ -316,6 +328,9 @@ pub(crate) fn function_definition_for_capability(spec: &CapabilitySpec) -> Funct
         "stopsTurn": stops_turn,
         "presentationHints": presentation_hints,
     });
+    if let Some(timeout) = spec.handler_timeout {
+        definition = definition.with_handler_timeout(timeout);
+    }
     definition
 }
 
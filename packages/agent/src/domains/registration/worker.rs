@@ -40,6 +40,9 @@ pub(crate) struct DomainRegistrationContext {
     pub(crate) ws_port: Arc<AtomicU16>,
     pub(crate) onboarded_marker_path: PathBuf,
     pub(crate) engine_host: crate::engine::EngineHostHandle,
+    /// Extra hooks installed on every `capability::execute` operation.
+    pub(crate) capability_interceptors:
+        Vec<Arc<dyn crate::domains::capability::OperationInterceptor>>,
 }
 
 impl DomainRegistrationContext {
@@ -60,6 +63,7 @@ impl DomainRegistrationContext {
             ws_port: Arc::clone(&ctx.ws_port),
             onboarded_marker_path: ctx.onboarded_marker_path.clone(),
             engine_host: ctx.engine_host.clone(),
+            capability_interceptors: Vec::new(),
         }
     }
 }
@@ -7,6 +7,8 @@
 //! - **Event types**: branch-local [`EventType`] enum for retained loop events
 //! - **Session events**: [`SessionEvent`] flat struct with typed payload access
 //! - **Event store**: High-level API for session creation, event append, ancestor walk, fork
+//! - **Session archives**: Portable export/import of a session, its fork ancestry, and blobs
+//! - **Branch trees**: Nested fork graph of a session family with per-session event counts
 //! - **`SQLite` backend**: `rusqlite` facade with repository pattern
 //! - **Event factory**: Scoped event creation with auto-generated IDs and timestamps
 //! - **Replay identities**: Explicit IDs/timestamps for deterministic replay/import tests
@@ -26,6 +28,7 @@
 //! | `factory` | Event ID creation and chain append helpers. |
 //! | `identity` | Explicit event/session/workspace identities for replay-critical constructors. |
 //! | `reconstruction` | Provider-context reconstruction from persisted event history. |
+//! | `redaction` | Secret-shape and opt-in payload-key redaction applied before persistence. |
 //! | `sqlite` | Connection, migration, repository, lock, and row-type boundary. |
 //! | `store` | High-level transactional `EventStore` facade. |
 //! | `trace` | Agent trace record types and query options. |
@@ -36,7 +39,8 @@
 //! `EventStore` is the high-level transactional facade for session/event truth.
 //! `EventFactory` and `EventChainBuilder` build append-ready events, while
 //! `reconstruct_from_events` rebuilds provider-facing message context from the
-//! durable event stream.
+//! durable event stream; `reconstruct_from_events_with` takes
+//! `ReconstructOptions` for trimmed preview views.
 //!
 //! ## Dependency Direction
 //!
@@ -88,18 +92,22 @@ pub use identity::{
     EventIdentity, SessionCreationIdentity, SessionForkIdentity, SessionIdentity, WorkspaceIdentity,
 };
 pub use reconstruction::{
-    COMPACTION_ACK_TEXT, COMPACTION_SUMMARY_PREFIX, ReconstructionResult, reconstruct_from_events,
+    COMPACTION_ACK_TEXT, COMPACTION_SUMMARY_PREFIX, ConsistencyIssue, ReconstructOptions,
+    ReconstructionResult, TRUNCATION_MARKER, reconstruct_from_events, reconstruct_from_events_with,
+    validate_consistency,
 };
 pub use sqlite::repositories::event::ListEventsOptions;
 pub use sqlite::repositories::session::{ActivitySummaryLine, ListSessionsOptions, MessagePreview};
 pub use sqlite::row_types::{BlobRow, EventRow, SessionRow, WorkspaceRow};
 pub use sqlite::{
-    ConnectionConfig, ConnectionPool, DatabaseLock, LockError, MigrationResult, PooledConnection,
-    acquire_database_lock, check_integrity, new_file, new_in_memory, run_migrations,
+    CheckpointMode, CheckpointStats, ConnectionConfig, ConnectionPool, DatabaseLock, DatabaseSize,
+    LockError, MigrationResult, PoolHealth, PooledConnection, acquire_database_lock,
+    check_integrity, new_file, new_in_memory, new_in_memory_shared, run_migrations,
 };
 pub use store::{
-    AppendOptions, ClientLogEntry, ClientLogIngestResult, CreateSessionResult, EventStore,
-    ForkOptions, ForkResult, LogEntry, LogSessionFilter, RecentLogQuery,
+    AppendOptions, ArchivedBlob, ArchivedSession, BranchNode, ClientLogEntry,
+    ClientLogIngestResult, CreateSessionResult, EventStore, ForkOptions, ForkResult, LogEntry,
+    LogSessionFilter, RecentLogQuery, SESSION_ARCHIVE_VERSION, SessionArchive, VacuumStats,
     event_rows_to_session_events,
 };
 pub use trace::{AGENT_TRACE_VERSION, AgentTraceListOptions, AgentTraceRecord};
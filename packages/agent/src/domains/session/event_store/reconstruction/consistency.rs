@@ -0,0 +1,148 @@
+//! Diagnostic consistency check for reconstructed message lists.
+//!
+//! Reconstruction pairs every `capability_invocation` block with the
+//! `capabilityResult` messages that follow its assistant message, and repairs
+//! gaps with a synthetic interrupted result. Malformed event histories can
+//! still leave invocations without a genuine result, results without an
+//! invocation, or messages in an order providers reject.
+//! [`validate_consistency`] reports those cases for support tooling without
+//! failing or modifying the result.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::{INTERRUPTED_RESULT_TEXT, ReconstructionResult, extract_capability_invocation_ids};
+use crate::domains::session::event_store::types::state::MessageWithEventId;
+
+/// One problem found by [`validate_consistency`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConsistencyIssue {
+    /// An assistant `capability_invocation` with no genuine `capabilityResult`
+    /// after it. Synthetic interrupted results do not count as a match.
+    OrphanedInvocation {
+        /// Invocation ID from the `capability_invocation` block.
+        invocation_id: String,
+        /// Index of the assistant message holding the block.
+        message_index: usize,
+    },
+    /// A `capabilityResult` whose invocation ID is not in the preceding
+    /// assistant message.
+    OrphanedResult {
+        /// Invocation ID carried by the result, empty when missing.
+        invocation_id: String,
+        /// Index of the result message.
+        message_index: usize,
+    },
+    /// A message in a position providers reject.
+    OrderingViolation {
+        /// Index of the offending message.
+        message_index: usize,
+        /// What is out of order.
+        detail: String,
+    },
+}
+
+/// Report orphaned invocations, orphaned results, and ordering violations in
+/// a reconstructed message list, in message order.
+///
+/// Never fails; an empty list means the messages are consistent.
+#[must_use]
+pub fn validate_consistency(result: &ReconstructionResult) -> Vec<ConsistencyIssue> {
+    let messages = &result.messages_with_event_ids;
+    let mut issues = Vec::new();
+
+    let mut i = 0;
+    while i < messages.len() {
+        let message = &messages[i].message;
+        match message.role.as_str() {
+            "capabilityResult" => {
+                // Results reachable here have no assistant message before them.
+                issues.push(ConsistencyIssue::OrderingViolation {
+                    message_index: i,
+                    detail: format!(
+                        "capabilityResult {} does not follow an assistant message",
+                        message.invocation_id.as_deref().unwrap_or("")
+                    ),
+                });
+                i += 1;
+                continue;
+            }
+            "assistant" => {
+                i = check_invocation_run(messages, i, &mut issues);
+                continue;
+            }
+            _ => {}
+        }
+        if let Some(prev) = i.checked_sub(1).map(|p| &messages[p].message)
+            && prev.role == message.role
+        {
+            issues.push(ConsistencyIssue::OrderingViolation {
+                message_index: i,
+                detail: format!("consecutive {} messages were not merged", message.role),
+            });
+        }
+        i += 1;
+    }
+
+    issues
+}
+
+/// Check the assistant message at `index` against the `capabilityResult` run
+/// after it. Returns the index of the first message past the run.
+fn check_invocation_run(
+    messages: &[MessageWithEventId],
+    index: usize,
+    issues: &mut Vec<ConsistencyIssue>,
+) -> usize {
+    if let Some(prev) = index.checked_sub(1).map(|p| &messages[p].message)
+        && prev.role == "assistant"
+    {
+        issues.push(ConsistencyIssue::OrderingViolation {
+            message_index: index,
+            detail: "consecutive assistant messages were not merged".to_string(),
+        });
+    }
+
+    let invocation_ids = extract_capability_invocation_ids(&messages[index].message.content);
+    let expected: HashSet<&str> = invocation_ids.iter().map(String::as_str).collect();
+    let mut answered = HashSet::new();
+
+    let mut j = index + 1;
+    while j < messages.len() && messages[j].message.role == "capabilityResult" {
+        let message = &messages[j].message;
+        let id = message.invocation_id.as_deref().unwrap_or("");
+        if !expected.contains(id) {
+            issues.push(ConsistencyIssue::OrphanedResult {
+                invocation_id: id.to_string(),
+                message_index: j,
+            });
+        } else if !answered.insert(id) {
+            issues.push(ConsistencyIssue::OrderingViolation {
+                message_index: j,
+                detail: format!("duplicate capabilityResult for {id}"),
+            });
+        } else if is_interrupted_placeholder(&message.content, message.is_error) {
+            issues.push(ConsistencyIssue::OrphanedInvocation {
+                invocation_id: id.to_string(),
+                message_index: index,
+            });
+        }
+        j += 1;
+    }
+
+    for id in &invocation_ids {
+        if !answered.contains(id.as_str()) {
+            issues.push(ConsistencyIssue::OrphanedInvocation {
+                invocation_id: id.clone(),
+                message_index: index,
+            });
+        }
+    }
+
+    j
+}
+
+fn is_interrupted_placeholder(content: &Value, is_error: Option<bool>) -> bool {
+    is_error == Some(true) && content.as_str() == Some(INTERRUPTED_RESULT_TEXT)
+}
@@ -11,6 +11,14 @@
 //! The output is a [`ReconstructionResult`] containing messages with event IDs,
 //! aggregate token usage and turn count.
 //!
+//! [`reconstruct_from_events_with`] takes [`ReconstructOptions`] for cheaper,
+//! lossy views such as session-list previews: capability results can be
+//! skipped and long text truncated with [`TRUNCATION_MARKER`]. The default
+//! options are fully faithful.
+//!
+//! [`validate_consistency`] is a support diagnostic that reports orphaned
+//! capability invocations/results and ordering violations in a result.
+//!
 //! ## Size note
 //!
 //! Both passes share mutable state (deleted IDs, capability invocation maps, message
@@ -24,12 +32,43 @@ use crate::domains::session::event_store::types::base::SessionEvent;
 use crate::domains::session::event_store::types::payloads::TokenTotals;
 use crate::domains::session::event_store::types::state::{Message, MessageWithEventId};
 
+mod consistency;
+
+pub use consistency::{ConsistencyIssue, validate_consistency};
+
 /// Prefix for compaction boundary summary messages, matching TypeScript `context/constants.ts`.
 pub const COMPACTION_SUMMARY_PREFIX: &str = "[Context from earlier in this conversation]";
 /// Assistant acknowledgement text after compaction, matching TypeScript `context/constants.ts`.
 pub const COMPACTION_ACK_TEXT: &str =
     "I understand the previous context. Let me continue helping you.";
 
+/// Appended to text cut short by [`ReconstructOptions::max_message_chars`].
+pub const TRUNCATION_MARKER: &str = "…";
+
+/// Content of the synthetic error result injected for an invocation whose
+/// result never arrived.
+const INTERRUPTED_RESULT_TEXT: &str = "Capability invocation was interrupted.";
+
+/// How faithfully [`reconstruct_from_events_with`] rebuilds messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReconstructOptions {
+    /// Keep `capabilityResult` messages. Previews that only need the text
+    /// turns skip them.
+    pub include_capability_results: bool,
+    /// Truncate each text or thinking string longer than this many characters,
+    /// appending [`TRUNCATION_MARKER`].
+    pub max_message_chars: Option<usize>,
+}
+
+impl Default for ReconstructOptions {
+    fn default() -> Self {
+        Self {
+            include_capability_results: true,
+            max_message_chars: None,
+        }
+    }
+}
+
 /// Result of reconstructing messages from event ancestry.
 #[derive(Clone, Debug)]
 pub struct ReconstructionResult {
@@ -64,8 +103,22 @@ struct PendingCapabilityResult {
 ///
 /// * `ancestors` - Ordered events from `session.start` to target event.
 pub fn reconstruct_from_events(ancestors: &[SessionEvent]) -> ReconstructionResult {
+    reconstruct_from_events_with(ancestors, &ReconstructOptions::default())
+}
+
+/// [`reconstruct_from_events`] with explicit [`ReconstructOptions`].
+pub fn reconstruct_from_events_with(
+    ancestors: &[SessionEvent],
+    options: &ReconstructOptions,
+) -> ReconstructionResult {
     let metadata = collect_metadata(ancestors);
-    build_messages(ancestors, &metadata)
+    let mut result = build_messages(ancestors, &metadata, options);
+    if let Some(max_chars) = options.max_message_chars {
+        for entry in &mut result.messages_with_event_ids {
+            truncate_content(&mut entry.message.content, max_chars);
+        }
+    }
+    result
 }
 
 /// Pass 1 output: metadata collected from events.
@@ -122,7 +175,11 @@ struct BuildState {
 }
 
 /// Pass 2: Build messages from events using metadata from pass 1.
-fn build_messages(ancestors: &[SessionEvent], metadata: &Metadata) -> ReconstructionResult {
+fn build_messages(
+    ancestors: &[SessionEvent],
+    metadata: &Metadata,
+    options: &ReconstructOptions,
+) -> ReconstructionResult {
     let mut st = BuildState {
         combined: Vec::new(),
         tokens: TokenTotals::default(),
@@ -138,7 +195,9 @@ fn build_messages(ancestors: &[SessionEvent], metadata: &Metadata) -> Reconstruc
         match event.event_type {
             EventType::CompactBoundary => handle_compact_boundary(event, &mut st),
             EventType::ContextCleared => handle_context_cleared(&mut st),
-            EventType::CapabilityInvocationCompleted => handle_capability_result(event, &mut st),
+            EventType::CapabilityInvocationCompleted if options.include_capability_results => {
+                handle_capability_result(event, &mut st);
+            }
             EventType::MessageUser => handle_message_user(event, &mut st),
             EventType::MessageAssistant => handle_message_assistant(event, metadata, &mut st),
             _ => {}
@@ -159,7 +218,10 @@ fn build_messages(ancestors: &[SessionEvent], metadata: &Metadata) -> Reconstruc
     // or (b) the session ended mid-capability-execution before results arrived.
     // Without this, providers like OpenAI reject the history because every
     // function_call must have a corresponding function_call_output.
-    inject_missing_capability_results(&mut st.combined);
+    // Views without capability results are never sent to a provider.
+    if options.include_capability_results {
+        inject_missing_capability_results(&mut st.combined);
+    }
 
     ReconstructionResult {
         messages_with_event_ids: st.combined,
@@ -350,9 +412,7 @@ fn inject_missing_capability_results(combined: &mut Vec<MessageWithEventId>) {
                         synthetic.push(MessageWithEventId {
                             message: Message {
                                 role: "capabilityResult".to_string(),
-                                content: Value::String(
-                                    "Capability invocation was interrupted.".to_string(),
-                                ),
+                                content: Value::String(INTERRUPTED_RESULT_TEXT.to_string()),
                                 invocation_id: Some(tc_id.clone()),
                                 is_error: Some(true),
                             },
@@ -489,6 +549,30 @@ fn restore_truncated_inputs(
     }
 }
 
+/// Truncate text and thinking strings in message content to `max_chars`.
+fn truncate_content(content: &mut Value, max_chars: usize) {
+    match content {
+        Value::String(text) => truncate_text(text, max_chars),
+        Value::Array(blocks) => {
+            for block in blocks {
+                for key in ["text", "thinking"] {
+                    if let Some(Value::String(text)) = block.get_mut(key) {
+                        truncate_text(text, max_chars);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn truncate_text(text: &mut String, max_chars: usize) {
+    if let Some((cut, _)) = text.char_indices().nth(max_chars) {
+        text.truncate(cut);
+        text.push_str(TRUNCATION_MARKER);
+    }
+}
+
 /// Accumulate token usage from a payload's `tokenUsage` field.
 fn accumulate_tokens(payload: &Value, tokens: &mut TokenTotals) {
     if let Some(tu) = payload.get("tokenUsage") {
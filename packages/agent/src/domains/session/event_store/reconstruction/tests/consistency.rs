@@ -0,0 +1,151 @@
+use super::*;
+
+fn assistant_invoking(id: &str, invocation_ids: &[&str]) -> SessionEvent {
+    let mut content = vec![serde_json::json!({"type": "text", "text": "Working."})];
+    content.extend(invocation_ids.iter().map(|tc| {
+        serde_json::json!({"type": "capability_invocation", "id": tc, "name": "execute", "arguments": {}})
+    }));
+    ev_with_id(
+        id,
+        EventType::MessageAssistant,
+        serde_json::json!({"content": content, "turn": 1}),
+    )
+}
+
+fn completed(invocation_id: &str) -> SessionEvent {
+    ev(
+        EventType::CapabilityInvocationCompleted,
+        serde_json::json!({"invocationId": invocation_id, "content": "ok"}),
+    )
+}
+
+fn user(text: &str) -> SessionEvent {
+    ev(EventType::MessageUser, serde_json::json!({"content": text}))
+}
+
+fn msg(role: &str, content: Value, invocation_id: Option<&str>) -> MessageWithEventId {
+    MessageWithEventId {
+        message: Message {
+            role: role.to_string(),
+            content,
+            invocation_id: invocation_id.map(String::from),
+            is_error: invocation_id.map(|_| false),
+        },
+        event_ids: vec![None],
+    }
+}
+
+fn result_of(messages: Vec<MessageWithEventId>) -> ReconstructionResult {
+    ReconstructionResult {
+        messages_with_event_ids: messages,
+        token_usage: TokenTotals::default(),
+        turn_count: 0,
+        reasoning_level: None,
+        system_prompt: None,
+    }
+}
+
+#[test]
+fn matched_invocations_are_consistent() {
+    let events = vec![
+        session_start(),
+        user("run it"),
+        assistant_invoking("evt_a1", &["tc_1", "tc_2"]),
+        completed("tc_1"),
+        completed("tc_2"),
+        assistant_invoking("evt_a2", &[]),
+    ];
+    let result = reconstruct_from_events(&events);
+    assert!(validate_consistency(&result).is_empty());
+}
+
+#[test]
+fn orphaned_invocation_is_reported() {
+    let events = vec![
+        session_start(),
+        user("run both"),
+        assistant_invoking("evt_a1", &["tc_done", "tc_lost"]),
+        completed("tc_done"),
+        assistant_invoking("evt_a2", &[]),
+        user("where is the second result?"),
+    ];
+    let result = reconstruct_from_events(&events);
+
+    // Reconstruction repairs the gap with a synthetic result, which still
+    // counts as orphaned for diagnostics.
+    assert_eq!(result.messages_with_event_ids.len(), 6);
+    assert_eq!(
+        validate_consistency(&result),
+        vec![ConsistencyIssue::OrphanedInvocation {
+            invocation_id: "tc_lost".to_string(),
+            message_index: 1,
+        }]
+    );
+
+    let preview = reconstruct_from_events_with(
+        &events,
+        &ReconstructOptions {
+            include_capability_results: false,
+            max_message_chars: None,
+        },
+    );
+    let orphaned: Vec<_> = validate_consistency(&preview)
+        .into_iter()
+        .filter_map(|issue| match issue {
+            ConsistencyIssue::OrphanedInvocation { invocation_id, .. } => Some(invocation_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(orphaned, vec!["tc_done", "tc_lost"]);
+}
+
+#[test]
+fn result_without_matching_invocation_is_reported() {
+    let events = vec![
+        session_start(),
+        user("hello"),
+        assistant_invoking("evt_a1", &[]),
+        completed("tc_stray"),
+        assistant_invoking("evt_a2", &[]),
+    ];
+    let result = reconstruct_from_events(&events);
+    assert_eq!(
+        validate_consistency(&result),
+        vec![ConsistencyIssue::OrphanedResult {
+            invocation_id: "tc_stray".to_string(),
+            message_index: 2,
+        }]
+    );
+}
+
+#[test]
+fn ordering_violations_are_reported() {
+    let invoke = serde_json::json!([
+        {"type": "capability_invocation", "id": "tc_1", "name": "execute", "arguments": {}},
+    ]);
+    let result = result_of(vec![
+        msg(
+            "capabilityResult",
+            Value::String("early".into()),
+            Some("tc_0"),
+        ),
+        msg("user", Value::String("a".into()), None),
+        msg("user", Value::String("b".into()), None),
+        msg("assistant", invoke, None),
+        msg("capabilityResult", Value::String("ok".into()), Some("tc_1")),
+        msg(
+            "capabilityResult",
+            Value::String("again".into()),
+            Some("tc_1"),
+        ),
+    ]);
+
+    let indices: Vec<_> = validate_consistency(&result)
+        .into_iter()
+        .map(|issue| match issue {
+            ConsistencyIssue::OrderingViolation { message_index, .. } => message_index,
+            other => panic!("unexpected issue: {other:?}"),
+        })
+        .collect();
+    assert_eq!(indices, vec![0, 2, 5]);
+}
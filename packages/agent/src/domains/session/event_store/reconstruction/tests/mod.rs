@@ -49,4 +49,6 @@ fn session_start() -> SessionEvent {
 }
 
 mod basic_capability;
+mod consistency;
 mod multimodal_performance;
+mod trimmed;
@@ -0,0 +1,130 @@
+use super::*;
+
+/// A turn that lists a large directory through `execute`, then summarizes it.
+fn history_with_large_capability_result() -> Vec<SessionEvent> {
+    vec![
+        session_start(),
+        ev_with_id(
+            "evt_user",
+            EventType::MessageUser,
+            serde_json::json!({"content": "list every file in the repo"}),
+        ),
+        ev_with_id(
+            "evt_call",
+            EventType::MessageAssistant,
+            serde_json::json!({
+                "content": [
+                    {"type": "text", "text": "Listing files."},
+                    {"type": "capability_invocation", "id": "tc_ls", "name": "execute", "arguments": {"input": "ls -R"}},
+                ],
+                "turn": 1,
+                "tokenUsage": {"inputTokens": 100, "outputTokens": 10},
+            }),
+        ),
+        ev(
+            EventType::CapabilityInvocationCompleted,
+            serde_json::json!({"invocationId": "tc_ls", "content": "src/lib.rs\n".repeat(5_000)}),
+        ),
+        ev_with_id(
+            "evt_summary",
+            EventType::MessageAssistant,
+            serde_json::json!({
+                "content": [
+                    {"type": "thinking", "thinking": "t".repeat(400)},
+                    {"type": "text", "text": "ü".repeat(300)},
+                ],
+                "turn": 2,
+                "tokenUsage": {"inputTokens": 200, "outputTokens": 20},
+            }),
+        ),
+    ]
+}
+
+fn event_ids(result: &ReconstructionResult) -> Vec<Option<String>> {
+    result
+        .messages_with_event_ids
+        .iter()
+        .flat_map(|m| m.event_ids.clone())
+        .flatten()
+        .map(Some)
+        .collect()
+}
+
+#[test]
+fn default_options_are_fully_faithful() {
+    let events = history_with_large_capability_result();
+    let full = reconstruct_from_events(&events);
+    let explicit = reconstruct_from_events_with(&events, &ReconstructOptions::default());
+
+    let full_messages = get_messages(&full);
+    assert_eq!(full_messages, get_messages(&explicit));
+    assert_eq!(
+        full_messages
+            .iter()
+            .map(|m| m.role.as_str())
+            .collect::<Vec<_>>(),
+        ["user", "assistant", "capabilityResult", "assistant"]
+    );
+    assert_eq!(full_messages[2].content, "src/lib.rs\n".repeat(5_000));
+    assert_eq!(full_messages[3].content[1]["text"], "ü".repeat(300));
+}
+
+#[test]
+fn trimmed_view_drops_capability_results_and_truncates_text() {
+    let events = history_with_large_capability_result();
+    let full = reconstruct_from_events(&events);
+    let trimmed = reconstruct_from_events_with(
+        &events,
+        &ReconstructOptions {
+            include_capability_results: false,
+            max_message_chars: Some(50),
+        },
+    );
+
+    let messages = get_messages(&trimmed);
+    assert!(messages.iter().all(|m| m.role != "capabilityResult"));
+    assert_eq!(messages[0].content, "list every file in the repo");
+    let blocks = messages
+        .last()
+        .and_then(|m| m.content.as_array())
+        .expect("assistant blocks");
+    let thinking = blocks
+        .iter()
+        .find_map(|b| b["thinking"].as_str())
+        .expect("thinking");
+    let text = blocks
+        .iter()
+        .filter_map(|b| b["text"].as_str())
+        .next_back()
+        .expect("text");
+    assert_eq!(thinking, format!("{}{TRUNCATION_MARKER}", "t".repeat(50)));
+    assert_eq!(text, format!("{}{TRUNCATION_MARKER}", "ü".repeat(50)));
+
+    assert_eq!(event_ids(&trimmed), event_ids(&full));
+    assert_eq!(trimmed.turn_count, full.turn_count);
+    assert_eq!(
+        trimmed.token_usage.input_tokens,
+        full.token_usage.input_tokens
+    );
+    assert_eq!(
+        trimmed.token_usage.output_tokens,
+        full.token_usage.output_tokens
+    );
+}
+
+#[test]
+fn truncation_leaves_short_text_untouched() {
+    let events = history_with_large_capability_result();
+    let trimmed = reconstruct_from_events_with(
+        &events,
+        &ReconstructOptions {
+            include_capability_results: true,
+            max_message_chars: Some(100_000),
+        },
+    );
+
+    assert_eq!(
+        get_messages(&trimmed),
+        get_messages(&reconstruct_from_events(&events))
+    );
+}
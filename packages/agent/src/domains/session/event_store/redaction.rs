@@ -6,12 +6,155 @@
 //!
 //! Delegated auth-secret coverage remains: `access_?token`,
 //! `refresh_?token`, `client_?secret`, and `authorization_?code`.
+//!
+//! [`PayloadKeyRedaction`] is the opt-in, key-based complement: operators list
+//! payload keys (`storage.redactedPayloadKeys`) and every value stored under a
+//! matching key is replaced with [`REDACTED_VALUE`] before the event is
+//! persisted, regardless of whether the value looks like a known secret shape.
+
+use serde_json::Value;
 
 pub use crate::shared::foundation::redaction::redact_sensitive_content;
 
+/// Replacement written in place of values stored under a redacted key.
+pub const REDACTED_VALUE: &str = "[REDACTED]";
+
+/// Keys whose values are replaced before an event payload is persisted.
+///
+/// Matching is case-insensitive and ignores `_` / `-` separators, so the
+/// pattern `apiToken` covers `apiToken`, `api_token`, and `API-TOKEN`. A key
+/// matches only when it equals a normalized pattern: `token` redacts `token`
+/// but leaves runtime fields such as `tokenUsage` and `inputTokens` intact. An
+/// empty pattern list disables redaction entirely.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PayloadKeyRedaction {
+    patterns: Vec<String>,
+}
+
+impl PayloadKeyRedaction {
+    /// Build a redaction policy from raw key patterns. Blank patterns are ignored.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut patterns: Vec<String> = patterns
+            .into_iter()
+            .map(|pattern| normalize_key(pattern.as_ref()))
+            .filter(|pattern| !pattern.is_empty())
+            .collect();
+        patterns.sort();
+        patterns.dedup();
+        Self { patterns }
+    }
+
+    /// Whether any key pattern is configured.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.patterns.is_empty()
+    }
+
+    /// Whether values under `key` should be redacted.
+    #[must_use]
+    pub fn matches_key(&self, key: &str) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        self.patterns.binary_search(&normalize_key(key)).is_ok()
+    }
+
+    /// Return a copy of `value` with every matching key's value replaced,
+    /// descending through nested objects and arrays.
+    #[must_use]
+    pub fn redact(&self, value: &Value) -> Value {
+        if !self.is_enabled() {
+            return value.clone();
+        }
+        self.redact_value(value)
+    }
+
+    fn redact_value(&self, value: &Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        let value = if self.matches_key(key) {
+                            Value::String(REDACTED_VALUE.to_string())
+                        } else {
+                            self.redact_value(value)
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+            Value::Array(items) => {
+                Value::Array(items.iter().map(|item| self.redact_value(item)).collect())
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn payload_key_redaction_disabled_by_default() {
+        let policy = PayloadKeyRedaction::default();
+        let payload = json!({"password": "hunter2"});
+        assert!(!policy.is_enabled());
+        assert_eq!(policy.redact(&payload), payload);
+    }
+
+    #[test]
+    fn payload_key_redaction_replaces_nested_values() {
+        let policy = PayloadKeyRedaction::new(["password", "apiToken"]);
+        let payload = json!({
+            "name": "Bash",
+            "arguments": {
+                "command": "login",
+                "Password": "hunter2",
+                "env": [{"api_token": "tok-123"}, {"API-TOKEN": {"value": "tok-456"}}]
+            }
+        });
+        let redacted = policy.redact(&payload);
+        assert_eq!(redacted["arguments"]["Password"], REDACTED_VALUE);
+        assert_eq!(redacted["arguments"]["env"][0]["api_token"], REDACTED_VALUE);
+        assert_eq!(redacted["arguments"]["env"][1]["API-TOKEN"], REDACTED_VALUE);
+        assert_eq!(redacted["arguments"]["command"], "login");
+        assert_eq!(redacted["name"], "Bash");
+    }
+
+    #[test]
+    fn payload_key_redaction_leaves_non_matching_keys_untouched() {
+        let policy = PayloadKeyRedaction::new(["client_secret", "  "]);
+        let payload = json!({"path": "/tmp/a", "count": 3, "items": [{"ok": true}]});
+        assert_eq!(policy.redact(&payload), payload);
+        assert!(policy.matches_key("clientSecret"));
+        assert!(!policy.matches_key("path"));
+    }
+
+    #[test]
+    fn payload_key_redaction_matches_whole_keys_only() {
+        let policy = PayloadKeyRedaction::new(["token"]);
+        let payload = json!({
+            "token": "tok-123",
+            "tokenUsage": {"inputTokens": 10, "outputTokens": 5}
+        });
+        let redacted = policy.redact(&payload);
+        assert_eq!(redacted["token"], REDACTED_VALUE);
+        assert_eq!(redacted["tokenUsage"], payload["tokenUsage"]);
+        assert!(!policy.matches_key("inputTokens"));
+    }
 
     #[test]
     fn redacts_anthropic_key() {
@@ -0,0 +1,185 @@
+//! WAL checkpoint control and on-disk size reporting for a [`ConnectionPool`].
+//!
+//! `SQLite` auto-checkpoints the WAL in `PASSIVE` mode but never shrinks the
+//! `-wal` file, so write-heavy sessions can leave a large sidecar behind.
+//! [`ConnectionPool::checkpoint`] runs `PRAGMA wal_checkpoint` on a pooled
+//! connection; `TRUNCATE` also resets the WAL file to zero bytes.
+
+use std::path::Path;
+
+use crate::domains::session::event_store::errors::{EventStoreError, Result};
+use crate::domains::session::event_store::sqlite::connection::ConnectionPool;
+
+/// `PRAGMA wal_checkpoint` mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckpointMode {
+    /// Checkpoint as many frames as possible without waiting on readers or
+    /// writers.
+    Passive,
+    /// Wait for writers, then checkpoint every frame.
+    Full,
+    /// Like `Full`, then wait for readers so the next writer restarts the WAL.
+    Restart,
+    /// Like `Restart`, then truncate the WAL file to zero bytes.
+    Truncate,
+}
+
+impl CheckpointMode {
+    /// The mode keyword used in `PRAGMA wal_checkpoint(...)`.
+    #[must_use]
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Passive => "PASSIVE",
+            Self::Full => "FULL",
+            Self::Restart => "RESTART",
+            Self::Truncate => "TRUNCATE",
+        }
+    }
+}
+
+/// Outcome of one WAL checkpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CheckpointStats {
+    /// Whether a `Full`/`Restart`/`Truncate` checkpoint was blocked by a
+    /// concurrent reader or writer before completing.
+    pub busy: bool,
+    /// Frames in the WAL when the checkpoint ran (`-1` outside WAL mode).
+    pub log_frames: i64,
+    /// Frames copied back into the database file (`-1` outside WAL mode).
+    pub checkpointed_frames: i64,
+}
+
+/// On-disk size of the database file and its WAL sidecar.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DatabaseSize {
+    /// Main database file bytes.
+    pub main_bytes: u64,
+    /// `-wal` sidecar bytes (`0` when absent).
+    pub wal_bytes: u64,
+}
+
+impl DatabaseSize {
+    /// Main file plus WAL.
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.main_bytes.saturating_add(self.wal_bytes)
+    }
+}
+
+impl ConnectionPool {
+    /// Run `PRAGMA wal_checkpoint(<mode>)` on a pooled connection.
+    pub fn checkpoint(&self, mode: CheckpointMode) -> Result<CheckpointStats> {
+        let conn = self.get()?;
+        let (busy, log_frames, checkpointed_frames): (i64, i64, i64) = conn
+            .query_row(
+                &format!("PRAGMA wal_checkpoint({})", mode.as_sql()),
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .map_err(EventStoreError::Sqlite)?;
+        Ok(CheckpointStats {
+            busy: busy != 0,
+            log_frames,
+            checkpointed_frames,
+        })
+    }
+
+    /// Current size of the database file and its WAL, or zero for an
+    /// in-memory pool.
+    #[must_use]
+    pub fn db_size_bytes(&self) -> DatabaseSize {
+        let Some(path) = self.path() else {
+            return DatabaseSize::default();
+        };
+        DatabaseSize {
+            main_bytes: file_len(path),
+            wal_bytes: file_len(&wal_path(path)),
+        }
+    }
+}
+
+fn wal_path(path: &Path) -> std::path::PathBuf {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    wal.into()
+}
+
+fn file_len(path: &Path) -> u64 {
+    std::fs::metadata(path).map_or(0, |metadata| metadata.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::session::event_store::sqlite::connection::{
+        ConnectionConfig, new_file, new_in_memory,
+    };
+
+    fn file_pool(dir: &tempfile::TempDir) -> ConnectionPool {
+        let path = dir.path().join("checkpoint.db");
+        new_file(path.to_str().unwrap(), &ConnectionConfig::default()).unwrap()
+    }
+
+    fn write_rows(pool: &ConnectionPool, rows: usize) {
+        let conn = pool.get().unwrap();
+        conn.execute_batch("CREATE TABLE IF NOT EXISTS filler (body TEXT NOT NULL)")
+            .unwrap();
+        let body = "x".repeat(1024);
+        for _ in 0..rows {
+            conn.execute("INSERT INTO filler (body) VALUES (?1)", [&body])
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn truncate_checkpoint_shrinks_wal_to_zero() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = file_pool(&dir);
+        write_rows(&pool, 200);
+
+        let before = pool.db_size_bytes();
+        assert!(before.wal_bytes > 0, "writes should grow the WAL");
+
+        let stats = pool.checkpoint(CheckpointMode::Truncate).unwrap();
+        assert!(!stats.busy);
+        assert_eq!(stats.log_frames, 0, "truncate resets the WAL");
+
+        let after = pool.db_size_bytes();
+        assert_eq!(after.wal_bytes, 0);
+        assert!(after.wal_bytes < before.wal_bytes);
+        assert!(after.main_bytes >= 200 * 1024, "rows land in the main file");
+        assert_eq!(after.total_bytes(), after.main_bytes);
+    }
+
+    #[test]
+    fn passive_checkpoint_copies_frames_without_truncating() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = file_pool(&dir);
+        write_rows(&pool, 50);
+
+        let stats = pool.checkpoint(CheckpointMode::Passive).unwrap();
+        assert!(!stats.busy);
+        assert!(stats.log_frames > 0);
+        assert_eq!(stats.checkpointed_frames, stats.log_frames);
+        assert!(pool.db_size_bytes().wal_bytes > 0);
+    }
+
+    #[test]
+    fn in_memory_pool_reports_zero_size() {
+        let pool = new_in_memory(&ConnectionConfig::default()).unwrap();
+        assert_eq!(pool.db_size_bytes(), DatabaseSize::default());
+        assert!(pool.checkpoint(CheckpointMode::Passive).is_ok());
+    }
+
+    #[test]
+    fn mode_keywords_match_sqlite() {
+        let modes = [
+            CheckpointMode::Passive,
+            CheckpointMode::Full,
+            CheckpointMode::Restart,
+            CheckpointMode::Truncate,
+        ];
+        let sql: Vec<_> = modes.iter().map(|mode| mode.as_sql()).collect();
+        assert_eq!(sql, ["PASSIVE", "FULL", "RESTART", "TRUNCATE"]);
+    }
+}
@@ -3,19 +3,145 @@
 //! Uses `r2d2` connection pooling with `r2d2_sqlite` backend.
 //! The connection customizer runs on each new connection to ensure
 //! WAL mode, foreign keys, and performance pragmas are set.
-
-use r2d2::Pool;
+//!
+//! Connections can go bad under sustained load (for example after a disk
+//! error). [`ConnectionConfig::validate_on_checkout`] pings every connection
+//! with `SELECT 1` before handing it out, and
+//! [`ConnectionPool::check_health`] pings idle connections on demand. Either
+//! way a connection that fails the ping is dropped and the pool opens a
+//! replacement.
+
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use r2d2::{ManageConnection, Pool};
 use r2d2_sqlite::SqliteConnectionManager;
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 
 use crate::domains::session::event_store::errors::{EventStoreError, Result};
 use crate::domains::session::event_store::sqlite::contention::BusyRetryPolicy;
 
-/// Alias for the connection pool type.
-pub type ConnectionPool = Pool<SqliteConnectionManager>;
+/// Pooled `SQLite` connections, dereferencing to the underlying `r2d2` pool.
+#[derive(Clone)]
+pub struct ConnectionPool {
+    pool: Pool<ValidatingManager>,
+    validation: Arc<ValidationState>,
+    path: Option<PathBuf>,
+}
 
 /// Alias for a pooled connection.
-pub type PooledConnection = r2d2::PooledConnection<SqliteConnectionManager>;
+pub type PooledConnection = r2d2::PooledConnection<ValidatingManager>;
+
+/// Result of [`ConnectionPool::check_health`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolHealth {
+    /// Idle connections pinged.
+    pub checked: u64,
+    /// Connections that answered the ping and went back to the pool.
+    pub healthy: u64,
+    /// Connections that failed the ping and were dropped for replacement.
+    pub recycled: u64,
+}
+
+impl ConnectionPool {
+    /// Database file backing the pool, or `None` for an in-memory pool.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Ping every idle connection with `SELECT 1`, dropping any that fail so
+    /// the pool replaces them. Connections checked out by other callers are
+    /// not touched.
+    pub fn check_health(&self) -> PoolHealth {
+        let idle = self.pool.state().idle_connections;
+        let recycled_before = self.validation.recycled.load(Ordering::SeqCst);
+        self.validation.forced.fetch_add(1, Ordering::SeqCst);
+        let mut healthy = Vec::new();
+        while healthy.len() < idle as usize {
+            match self.pool.try_get() {
+                Some(conn) => healthy.push(conn),
+                None => break,
+            }
+        }
+        self.validation.forced.fetch_sub(1, Ordering::SeqCst);
+        let recycled = self.validation.recycled.load(Ordering::SeqCst) - recycled_before;
+        let healthy = healthy.len() as u64;
+        if recycled > 0 {
+            tracing::warn!(
+                healthy,
+                recycled,
+                "recycled sqlite connections that failed health check"
+            );
+        }
+        PoolHealth {
+            checked: healthy + recycled,
+            healthy,
+            recycled,
+        }
+    }
+}
+
+impl Deref for ConnectionPool {
+    type Target = Pool<ValidatingManager>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
+
+impl std::fmt::Debug for ConnectionPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pool.fmt(f)
+    }
+}
+
+/// [`SqliteConnectionManager`] that validates connections with a ping when
+/// checkout validation is enabled or a health check is running.
+#[derive(Debug)]
+pub struct ValidatingManager {
+    inner: SqliteConnectionManager,
+    validate_on_checkout: bool,
+    validation: Arc<ValidationState>,
+    ping: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+#[derive(Debug, Default)]
+struct ValidationState {
+    /// Health checks in progress; while non-zero every checkout is pinged.
+    forced: AtomicUsize,
+    /// Connections dropped after failing a ping.
+    recycled: AtomicU64,
+}
+
+impl ManageConnection for ValidatingManager {
+    type Connection = Connection;
+    type Error = rusqlite::Error;
+
+    fn connect(&self) -> std::result::Result<Connection, rusqlite::Error> {
+        self.inner.connect()
+    }
+
+    fn is_valid(&self, conn: &mut Connection) -> std::result::Result<(), rusqlite::Error> {
+        if !self.validate_on_checkout && self.validation.forced.load(Ordering::SeqCst) == 0 {
+            return Ok(());
+        }
+        (self.ping)(conn).inspect_err(|_| {
+            self.validation.recycled.fetch_add(1, Ordering::SeqCst);
+        })
+    }
+
+    fn has_broken(&self, conn: &mut Connection) -> bool {
+        self.inner.has_broken(conn)
+    }
+}
+
+fn ping(conn: &Connection) -> rusqlite::Result<()> {
+    conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+        .map(|_| ())
+}
 
 /// Configuration for the connection pool.
 #[derive(Clone, Debug)]
@@ -32,6 +158,9 @@ pub struct ConnectionConfig {
     pub cache_size_kib: i64,
     /// Memory-mapped I/O size in bytes (default: 256 MB). Set to 0 to disable.
     pub mmap_size: i64,
+    /// Ping each connection with `SELECT 1` on checkout and replace it if the
+    /// ping fails (default: off).
+    pub validate_on_checkout: bool,
 }
 
 impl Default for ConnectionConfig {
@@ -41,6 +170,7 @@ impl Default for ConnectionConfig {
             busy_timeout_ms: BusyRetryPolicy::sqlite_busy_timeout_ms(),
             cache_size_kib: 8192,
             mmap_size: 268_435_456,
+            validate_on_checkout: false,
         }
     }
 }
@@ -72,32 +202,82 @@ impl r2d2::CustomizeConnection<Connection, rusqlite::Error> for PragmaCustomizer
 
 /// Create an in-memory connection pool (for testing).
 pub fn new_in_memory(config: &ConnectionConfig) -> Result<ConnectionPool> {
-    let manager = SqliteConnectionManager::memory();
-    let pool = Pool::builder()
-        .max_size(config.pool_size)
-        .connection_timeout(std::time::Duration::from_secs(5))
-        .connection_customizer(Box::new(PragmaCustomizer {
-            busy_timeout_ms: config.busy_timeout_ms,
-            cache_size_kib: config.cache_size_kib,
-            mmap_size: config.mmap_size,
-        }))
-        .build(manager)?;
-    Ok(pool)
+    build_pool(SqliteConnectionManager::memory(), None, config, ping)
+}
+
+/// Create an in-memory connection pool whose connections share one database
+/// named `name` (for tests that need several pools over the same data).
+///
+/// Every pool opened with the same `name` in this process sees the same
+/// tables. `SQLite` frees a shared in-memory database when its last
+/// connection closes, so the data only lives while at least one pool (or a
+/// connection checked out from one) is alive; dropping every pool and
+/// reopening the name starts empty. Shared-cache connections use table-level
+/// locks and report contention as `SQLITE_LOCKED` rather than waiting on the
+/// busy timeout, and the journal stays in memory mode instead of WAL.
+///
+/// `name` must be non-empty ASCII alphanumerics, `-`, or `_`, since it is
+/// spliced into a `file:` URI.
+pub fn new_in_memory_shared(name: &str, config: &ConnectionConfig) -> Result<ConnectionPool> {
+    if name.is_empty()
+        || !name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    {
+        return Err(EventStoreError::InvalidOperation(format!(
+            "invalid shared in-memory database name: {name:?}"
+        )));
+    }
+    let uri = format!("file:{name}?mode=memory&cache=shared");
+    let flags = OpenFlags::SQLITE_OPEN_READ_WRITE
+        | OpenFlags::SQLITE_OPEN_CREATE
+        | OpenFlags::SQLITE_OPEN_URI
+        | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+    build_pool(
+        SqliteConnectionManager::file(uri).with_flags(flags),
+        None,
+        config,
+        ping,
+    )
 }
 
 /// Create a file-backed connection pool.
 pub fn new_file(path: &str, config: &ConnectionConfig) -> Result<ConnectionPool> {
-    let manager = SqliteConnectionManager::file(path);
+    build_pool(
+        SqliteConnectionManager::file(path),
+        Some(PathBuf::from(path)),
+        config,
+        ping,
+    )
+}
+
+fn build_pool(
+    manager: SqliteConnectionManager,
+    path: Option<PathBuf>,
+    config: &ConnectionConfig,
+    ping: fn(&Connection) -> rusqlite::Result<()>,
+) -> Result<ConnectionPool> {
+    let validation = Arc::new(ValidationState::default());
     let pool = Pool::builder()
         .max_size(config.pool_size)
         .connection_timeout(std::time::Duration::from_secs(5))
+        .test_on_check_out(true)
         .connection_customizer(Box::new(PragmaCustomizer {
             busy_timeout_ms: config.busy_timeout_ms,
             cache_size_kib: config.cache_size_kib,
             mmap_size: config.mmap_size,
         }))
-        .build(manager)?;
-    Ok(pool)
+        .build(ValidatingManager {
+            inner: manager,
+            validate_on_checkout: config.validate_on_checkout,
+            validation: Arc::clone(&validation),
+            ping,
+        })?;
+    Ok(ConnectionPool {
+        pool,
+        validation,
+        path,
+    })
 }
 
 /// Run `PRAGMA integrity_check` on the connection and fail if `SQLite`
@@ -226,6 +406,7 @@ mod tests {
         );
         assert_eq!(config.cache_size_kib, 8192);
         assert_eq!(config.mmap_size, 268_435_456);
+        assert!(!config.validate_on_checkout);
     }
 
     #[test]
@@ -298,6 +479,68 @@ mod tests {
         assert_eq!(temp_store, 2); // 2 = MEMORY
     }
 
+    // ── Shared in-memory pools ───────────────────────────────────────────────
+
+    #[test]
+    fn shared_in_memory_pools_see_each_others_writes() {
+        let config = ConnectionConfig {
+            pool_size: 2,
+            ..ConnectionConfig::default()
+        };
+        let writer = new_in_memory_shared("conn_shared_visibility", &config).unwrap();
+        let reader = new_in_memory_shared("conn_shared_visibility", &config).unwrap();
+        assert!(writer.path().is_none());
+
+        writer
+            .get()
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE shared_probe (value TEXT NOT NULL);
+                 INSERT INTO shared_probe (value) VALUES ('from writer');",
+            )
+            .unwrap();
+
+        let value: String = reader
+            .get()
+            .unwrap()
+            .query_row("SELECT value FROM shared_probe", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(value, "from writer");
+    }
+
+    #[test]
+    fn shared_in_memory_pools_with_different_names_are_isolated() {
+        let config = ConnectionConfig::default();
+        let a = new_in_memory_shared("conn_shared_isolated_a", &config).unwrap();
+        let b = new_in_memory_shared("conn_shared_isolated_b", &config).unwrap();
+        a.get()
+            .unwrap()
+            .execute_batch("CREATE TABLE only_in_a (x)")
+            .unwrap();
+
+        let tables: i64 = b
+            .get()
+            .unwrap()
+            .query_row(
+                "SELECT count(*) FROM sqlite_master WHERE name = 'only_in_a'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(tables, 0);
+    }
+
+    #[test]
+    fn shared_in_memory_rejects_uri_unsafe_names() {
+        let config = ConnectionConfig::default();
+        for name in ["", "a?mode=rwc", "a b", "a/b"] {
+            assert!(
+                new_in_memory_shared(name, &config).is_err(),
+                "{name:?} must be rejected"
+            );
+        }
+    }
+
     // ── check_integrity (M34) ────────────────────────────────────────────────
 
     #[test]
@@ -345,6 +588,117 @@ mod tests {
         check_integrity(&conn).expect("healthy DB round-trips the pragma");
     }
 
+    // ── Connection validation ────────────────────────────────────────────────
+
+    /// Ping that fails for connections holding a `poisoned` temp table, so a
+    /// test can poison one connection (temp tables are per connection).
+    fn marked_ping(conn: &Connection) -> rusqlite::Result<()> {
+        ping(conn)?;
+        let poisoned: i64 = conn.query_row(
+            "SELECT count(*) FROM temp.sqlite_master WHERE name = 'poisoned'",
+            [],
+            |r| r.get(0),
+        )?;
+        if poisoned > 0 {
+            return Err(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_IOERR),
+                Some("poisoned".into()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn marked_pool(validate_on_checkout: bool) -> ConnectionPool {
+        let config = ConnectionConfig {
+            pool_size: 2,
+            validate_on_checkout,
+            ..ConnectionConfig::default()
+        };
+        build_pool(
+            SqliteConnectionManager::memory(),
+            None,
+            &config,
+            marked_ping,
+        )
+        .unwrap()
+    }
+
+    fn poison_one(pool: &ConnectionPool) {
+        let conn = pool.get().unwrap();
+        conn.execute_batch("CREATE TEMP TABLE poisoned (x)")
+            .unwrap();
+    }
+
+    /// Check out every slot (blocking until replacements are open), then
+    /// return them all so the pool is full of idle connections.
+    fn wait_for_full_pool(pool: &ConnectionPool) {
+        let conns: Vec<_> = (0..pool.max_size()).map(|_| pool.get().unwrap()).collect();
+        drop(conns);
+    }
+
+    #[test]
+    fn health_check_passes_healthy_connections() {
+        let pool = new_in_memory(&ConnectionConfig {
+            pool_size: 3,
+            ..ConnectionConfig::default()
+        })
+        .unwrap();
+        wait_for_full_pool(&pool);
+
+        let health = pool.check_health();
+        assert_eq!(
+            health,
+            PoolHealth {
+                checked: 3,
+                healthy: 3,
+                recycled: 0
+            }
+        );
+        assert_eq!(pool.state().idle_connections, 3);
+    }
+
+    #[test]
+    fn health_check_replaces_connection_failing_validation() {
+        let pool = marked_pool(false);
+        wait_for_full_pool(&pool);
+        poison_one(&pool);
+
+        let health = pool.check_health();
+        assert_eq!(health.recycled, 1);
+        assert_eq!(health.healthy, 1);
+
+        wait_for_full_pool(&pool);
+        assert_eq!(pool.check_health().recycled, 0);
+    }
+
+    #[test]
+    fn checkout_validation_replaces_failing_connection_when_enabled() {
+        let pool = marked_pool(true);
+        wait_for_full_pool(&pool);
+        poison_one(&pool);
+
+        let conns: Vec<_> = (0..2).map(|_| pool.get().unwrap()).collect();
+        for conn in &conns {
+            marked_ping(conn).expect("checkout must never hand out a poisoned connection");
+        }
+    }
+
+    #[test]
+    fn checkout_without_validation_hands_out_connections_unchecked() {
+        let pool = marked_pool(false);
+        wait_for_full_pool(&pool);
+        poison_one(&pool);
+
+        let conns: Vec<_> = (0..2).map(|_| pool.get().unwrap()).collect();
+        assert_eq!(
+            conns
+                .iter()
+                .filter(|conn| marked_ping(conn).is_err())
+                .count(),
+            1
+        );
+    }
+
     // Corruption-injection negative tests are intentionally omitted: SQLite
     // refuses to open a file with damaged headers (the pool errors before
     // check_integrity runs), and writable_schema tricks do not propagate to
@@ -1,7 +1,8 @@
 //! Schema migration runner for the event store database.
 //!
-//! Tron ships a single `v001_schema.sql` for the primitive branch.
-//! The primitive branch ships only this fresh schema migration.
+//! Tron ships `v001_schema.sql` as the primitive branch's fresh schema.
+//! Later versions only add to it, so databases created at v001 upgrade in
+//! place; `v002_logs_trace_id_index.sql` adds the trace-id log index.
 //!
 //! The `schema_version` table tracks which migrations have been applied.
 //! Running the migrator is idempotent: already-applied versions are skipped.
@@ -13,7 +14,8 @@
 //! migration before they reach production.
 //!
 //! # INVARIANT
-//! The only supported path is empty DB to the primitive schema.
+//! Databases start empty or at a version this runner created; applied
+//! migrations are never edited, new schema goes into a new version.
 
 use rusqlite::Connection;
 use tracing::{debug, info};
@@ -30,11 +32,18 @@ struct Migration {
 /// All migrations in version order.
 ///
 /// Migrations in application order.
-const MIGRATIONS: &[Migration] = &[Migration {
-    version: 1,
-    description: "Consolidated schema — all core tables, indexes, and CHECK constraints",
-    sql: include_str!("v001_schema.sql"),
-}];
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Consolidated schema — all core tables, indexes, and CHECK constraints",
+        sql: include_str!("v001_schema.sql"),
+    },
+    Migration {
+        version: 2,
+        description: "Index logs by trace id",
+        sql: include_str!("v002_logs_trace_id_index.sql"),
+    },
+];
 
 /// Result of running migrations.
 #[derive(Debug)]
@@ -1,4 +1,4 @@
-use super::{open_memory, run_migrations};
+use super::{MIGRATIONS, apply_migration, ensure_version_table, open_memory, run_migrations};
 
 #[test]
 fn fresh_schema_contains_only_primitive_tables() {
@@ -76,7 +76,7 @@ fn trace_records_table_is_agent_trace_compatible_primitive_storage() {
 }
 
 #[test]
-fn schema_version_is_single_fresh_migration() {
+fn schema_version_records_every_migration() {
     let conn = open_memory();
     run_migrations(&conn).unwrap();
 
@@ -88,7 +88,30 @@ fn schema_version_is_single_fresh_migration() {
         .collect::<std::result::Result<Vec<_>, _>>()
         .unwrap();
 
-    assert_eq!(versions, vec![1]);
+    assert_eq!(versions, vec![1, 2]);
+}
+
+#[test]
+fn v001_database_upgrades_with_the_trace_id_log_index() {
+    let conn = open_memory();
+    ensure_version_table(&conn).unwrap();
+    apply_migration(&conn, &MIGRATIONS[0]).unwrap();
+    let index_exists = |conn: &rusqlite::Connection| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND name = 'idx_logs_trace_id'",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .unwrap()
+            == 1
+    };
+    assert!(!index_exists(&conn));
+
+    let result = run_migrations(&conn).unwrap();
+
+    assert_eq!(result.applied, 1);
+    assert_eq!(result.max_version_applied, 2);
+    assert!(index_exists(&conn));
 }
 
 #[test]
@@ -0,0 +1,4 @@
+-- Index log rows by trace id so trace-scoped log queries avoid a table scan.
+CREATE INDEX IF NOT EXISTS idx_logs_trace_id
+  ON logs(trace_id)
+  WHERE trace_id IS NOT NULL;
@@ -2,15 +2,19 @@
 //!
 //! Provides connection pooling, schema migrations, and repository implementations
 //! for primitive sessions, events, blobs, logs, and trace records. Fresh
-//! databases start from consolidated `migrations/v001_schema.sql`; that single
-//! schema is the clean-break branch storage surface. Constraints
+//! databases start from consolidated `migrations/v001_schema.sql`, the
+//! clean-break branch storage surface, and later versions add to it in place
+//! (`v002_logs_trace_id_index.sql` indexes logs by trace id). Constraints
 //! (`CHECK`, `UNIQUE`, `FOREIGN KEY`, `COALESCE`-nullable unique indexes) are
 //! declared inline on `CREATE TABLE` -- no triggers and no FTS virtual tables.
 //!
 //! # Architecture
 //!
 //! - **[`connection`]**: `r2d2` connection pool with WAL mode, foreign keys, and
-//!   performance pragmas applied to every connection.
+//!   performance pragmas applied to every connection, plus `SELECT 1` health
+//!   checks and optional checkout validation that recycle bad connections.
+//! - **[`checkpoint`]**: `PRAGMA wal_checkpoint` control and main/WAL file size
+//!   reporting on a pool.
 //! - **[`migrations`]**: Version-tracked schema evolution. Migrations are embedded
 //!   at compile time and run transactionally. Each applied migration is verified
 //!   with `PRAGMA foreign_key_check` before commit.
@@ -20,6 +24,7 @@
 //! - **[`repositories`]**: Stateless repository structs — each method takes
 //!   `&Connection` and executes SQL. No shared mutable state.
 
+pub mod checkpoint;
 pub mod connection;
 pub mod contention;
 pub mod migrations;
@@ -27,9 +32,10 @@ pub mod process_lock;
 pub mod repositories;
 pub mod row_types;
 
+pub use checkpoint::{CheckpointMode, CheckpointStats, DatabaseSize};
 pub use connection::{
-    ConnectionConfig, ConnectionPool, PooledConnection, PragmaState, check_integrity, new_file,
-    new_in_memory, verify_pragmas,
+    ConnectionConfig, ConnectionPool, PoolHealth, PooledConnection, PragmaState, check_integrity,
+    new_file, new_in_memory, new_in_memory_shared, verify_pragmas,
 };
 pub use migrations::{MigrationResult, current_version, latest_version, run_migrations};
 pub use process_lock::{DatabaseLock, LockError, acquire_database_lock};
@@ -154,6 +154,17 @@ impl SessionRepo {
         Ok(row)
     }
 
+    /// List sessions forked directly from `parent_session_id`, oldest first.
+    pub fn list_children(conn: &Connection, parent_session_id: &str) -> Result<Vec<SessionRow>> {
+        let mut stmt = conn.prepare(
+            "SELECT * FROM sessions WHERE parent_session_id = ?1 ORDER BY created_at ASC, id ASC",
+        )?;
+        let rows = stmt
+            .query_map(params![parent_session_id], Self::map_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
     /// List sessions with filtering.
     pub fn list(conn: &Connection, opts: &ListSessionsOptions<'_>) -> Result<Vec<SessionRow>> {
         use std::fmt::Write;
@@ -233,6 +244,15 @@ impl SessionRepo {
         Ok(changed > 0)
     }
 
+    /// Mark session as ended at an explicit timestamp, leaving last activity alone.
+    pub fn mark_ended_at(conn: &Connection, session_id: &str, ended_at: &str) -> Result<bool> {
+        let changed = conn.execute(
+            "UPDATE sessions SET ended_at = ?1 WHERE id = ?2",
+            params![ended_at, session_id],
+        )?;
+        Ok(changed > 0)
+    }
+
     /// Clear ended status (reactivate session).
     pub fn clear_ended(conn: &Connection, session_id: &str) -> Result<bool> {
         let now = chrono::Utc::now().to_rfc3339();
@@ -355,3 +355,35 @@ fn get_by_ids_missing_ids_omitted() {
     assert_eq!(map.len(), 1);
     assert!(map.contains_key(&s1.id));
 }
+
+#[test]
+fn list_children_returns_direct_forks_only() {
+    let (conn, ws_id) = setup();
+    let parent = create_default_session(&conn, &ws_id);
+    let fork = |parent_id: &str| {
+        SessionRepo::create(
+            &conn,
+            &CreateSessionOptions {
+                workspace_id: &ws_id,
+                model: "claude-opus-4-6",
+                working_directory: "/tmp/test",
+                title: None,
+                tags: None,
+                parent_session_id: Some(parent_id),
+                fork_from_event_id: None,
+            },
+        )
+        .unwrap()
+    };
+    let child = fork(&parent.id);
+    let _grandchild = fork(&child.id);
+
+    let children = SessionRepo::list_children(&conn, &parent.id).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id, child.id);
+    assert!(
+        SessionRepo::list_children(&conn, "sess_nonexistent")
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,423 @@
+//! Portable session archives for moving a session between machines.
+//!
+//! An archive carries the session row, its events in sequence order, and the
+//! blobs its payloads reference by `blobId`. A forked session's history runs
+//! through its source session, so the archive also carries every session the
+//! fork chain reaches, oldest first. Import validates the event chain, then
+//! writes everything in one transaction under fresh session, event, and blob
+//! IDs. Archived IDs under the payload keys in [`PAYLOAD_ID_KEYS`] are
+//! rewritten to their new IDs, and imported payloads pass through the same
+//! redaction as appended ones.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domains::session::event_store::SessionRow;
+use crate::domains::session::event_store::errors::{EventStoreError, Result};
+use crate::domains::session::event_store::identity::{EventIdentity, SessionIdentity};
+use crate::domains::session::event_store::sqlite::repositories::blob::BlobRepo;
+use crate::domains::session::event_store::sqlite::repositories::event::{
+    EventRepo, ListEventsOptions,
+};
+use crate::domains::session::event_store::sqlite::repositories::session::{
+    CreateSessionOptions, IncrementCounters, SessionRepo,
+};
+use crate::domains::session::event_store::sqlite::repositories::workspace::WorkspaceRepo;
+use crate::domains::session::event_store::types::EventType;
+use crate::domains::session::event_store::types::base::SessionEvent;
+
+use super::EventStore;
+use super::event_log::redact_event_payload;
+use super::state::event_rows_to_session_events_with_conn;
+use crate::domains::session::event_store::redaction::PayloadKeyRedaction;
+
+/// Archive format version written by [`EventStore::export_session`].
+pub const SESSION_ARCHIVE_VERSION: u32 = 1;
+
+/// Payload keys whose string values name an archived session, event, or blob.
+const PAYLOAD_ID_KEYS: &[&str] = &[
+    "blobId",
+    "eventId",
+    "sessionId",
+    "sourceEventId",
+    "sourceSessionId",
+    "targetEventId",
+];
+
+/// Portable copy of one session and the fork ancestry it depends on.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionArchive {
+    /// Archive format version.
+    pub version: u32,
+    /// The exported session; always the last entry in `sessions`.
+    pub session_id: String,
+    /// Archived sessions, fork sources before the sessions forked from them.
+    pub sessions: Vec<ArchivedSession>,
+    /// Blobs referenced by archived event payloads.
+    pub blobs: Vec<ArchivedBlob>,
+}
+
+/// One archived session row with its events in sequence order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedSession {
+    /// Session row as stored on the exporting machine.
+    pub session: SessionRow,
+    /// Session events with resolved payloads, ordered by sequence.
+    pub events: Vec<SessionEvent>,
+}
+
+/// One archived blob, keyed by its ID on the exporting machine.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedBlob {
+    /// Blob ID on the exporting machine.
+    pub id: String,
+    /// MIME type.
+    pub mime_type: String,
+    /// Uncompressed content, base64-encoded.
+    pub content_base64: String,
+}
+
+impl EventStore {
+    /// Export a session, its fork ancestry, and referenced blobs.
+    #[tracing::instrument(skip(self), fields(session_id))]
+    pub fn export_session(&self, session_id: &str) -> Result<SessionArchive> {
+        let conn = self.conn()?;
+        let mut sessions: Vec<ArchivedSession> = Vec::new();
+        let mut next = Some(session_id.to_owned());
+        while let Some(id) = next {
+            if sessions.iter().any(|archived| archived.session.id == id) {
+                return Err(EventStoreError::InvalidOperation(format!(
+                    "fork ancestry of {session_id} loops back to {id}"
+                )));
+            }
+            let session = SessionRepo::get_by_id(&conn, &id)?
+                .ok_or_else(|| EventStoreError::SessionNotFound(id.clone()))?;
+            let rows = EventRepo::get_by_session(&conn, &id, &ListEventsOptions::default())?;
+            let events = event_rows_to_session_events_with_conn(&conn, &rows);
+            if events.len() != rows.len() {
+                return Err(EventStoreError::InvalidOperation(format!(
+                    "session {id} has events of unknown type and cannot be exported"
+                )));
+            }
+            next = session.parent_session_id.clone();
+            sessions.push(ArchivedSession { session, events });
+        }
+        sessions.reverse();
+
+        let mut blob_ids = BTreeSet::new();
+        for event in sessions.iter().flat_map(|archived| &archived.events) {
+            collect_blob_ids(&event.payload, &mut blob_ids);
+        }
+        let mut blobs = Vec::with_capacity(blob_ids.len());
+        for id in blob_ids {
+            let row = BlobRepo::get_by_id(&conn, &id)?
+                .ok_or_else(|| EventStoreError::BlobNotFound(id.clone()))?;
+            let content = BlobRepo::get_content(&conn, &id)?
+                .ok_or_else(|| EventStoreError::BlobNotFound(id.clone()))?;
+            blobs.push(ArchivedBlob {
+                id,
+                mime_type: row.mime_type,
+                content_base64: general_purpose::STANDARD.encode(content),
+            });
+        }
+
+        Ok(SessionArchive {
+            version: SESSION_ARCHIVE_VERSION,
+            session_id: session_id.to_owned(),
+            sessions,
+            blobs,
+        })
+    }
+
+    /// Import an archive under fresh IDs and return the imported session.
+    ///
+    /// Atomic: either every archived session, event, and blob is written, or
+    /// nothing is.
+    #[tracing::instrument(skip(self, archive), fields(session_id = %archive.session_id))]
+    pub fn import_session(&self, archive: &SessionArchive) -> Result<SessionRow> {
+        validate_archive(archive)?;
+        let blobs = archive
+            .blobs
+            .iter()
+            .map(|blob| {
+                general_purpose::STANDARD
+                    .decode(&blob.content_base64)
+                    .map(|content| (blob, content))
+                    .map_err(|error| {
+                        EventStoreError::InvalidOperation(format!(
+                            "archived blob {} is not valid base64: {error}",
+                            blob.id
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.with_global_write_lock(|| {
+            let mut conn = self.conn()?;
+            let tx = conn.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+
+            let mut ids = HashMap::new();
+            for (blob, content) in &blobs {
+                let new_id = BlobRepo::store(&tx, content, &blob.mime_type)?;
+                let _ = ids.insert(blob.id.clone(), new_id);
+            }
+            for archived in &archive.sessions {
+                let _ = ids.insert(
+                    archived.session.id.clone(),
+                    SessionIdentity::generate_current().id,
+                );
+                for event in &archived.events {
+                    let _ = ids.insert(event.id.clone(), EventIdentity::generate_current().id);
+                }
+            }
+            for archived in &archive.sessions {
+                import_archived_session(&tx, archived, &ids, &self.payload_key_redaction)?;
+            }
+
+            let imported = SessionRepo::get_by_id(&tx, &ids[&archive.session_id])?
+                .ok_or_else(|| EventStoreError::SessionNotFound(archive.session_id.clone()))?;
+            tx.commit()?;
+            tracing::debug!(
+                session_id = %imported.id,
+                archived_sessions = archive.sessions.len(),
+                "session archive imported"
+            );
+            Ok(imported)
+        })
+    }
+}
+
+fn import_archived_session(
+    tx: &rusqlite::Transaction<'_>,
+    archived: &ArchivedSession,
+    ids: &HashMap<String, String>,
+    key_redaction: &PayloadKeyRedaction,
+) -> Result<()> {
+    let row = &archived.session;
+    let remap = |id: &Option<String>| id.as_ref().map(|id| ids[id].clone());
+    let session_id = ids[&row.id].clone();
+    let workspace = WorkspaceRepo::get_or_create(tx, &row.working_directory, None)?;
+    let tags: Vec<String> = serde_json::from_str(&row.tags).unwrap_or_default();
+    let parent_session_id = remap(&row.parent_session_id);
+    let fork_from_event_id = remap(&row.fork_from_event_id);
+    let _ = SessionRepo::create_with_identity(
+        tx,
+        &CreateSessionOptions {
+            workspace_id: &workspace.id,
+            model: &row.latest_model,
+            working_directory: &row.working_directory,
+            title: row.title.as_deref(),
+            tags: Some(&tags),
+            parent_session_id: parent_session_id.as_deref(),
+            fork_from_event_id: fork_from_event_id.as_deref(),
+        },
+        &SessionIdentity::new(session_id.clone(), row.created_at.clone()),
+    )?;
+
+    for event in &archived.events {
+        EventRepo::insert(
+            tx,
+            &SessionEvent {
+                id: ids[&event.id].clone(),
+                parent_id: remap(&event.parent_id),
+                session_id: session_id.clone(),
+                workspace_id: workspace.id.clone(),
+                timestamp: event.timestamp.clone(),
+                event_type: event.event_type,
+                sequence: event.sequence,
+                checksum: None,
+                payload: redact_event_payload(
+                    &remap_payload_ids(&event.payload, ids),
+                    key_redaction,
+                ),
+            },
+        )?;
+    }
+
+    let _ = SessionRepo::update_root(tx, &session_id, &ids[&archived.events[0].id])?;
+    if let Some(head) = remap(&row.head_event_id) {
+        let _ = SessionRepo::update_head_at(tx, &session_id, &head, &row.last_activity_at)?;
+    }
+    let _ = SessionRepo::increment_counters_at(
+        tx,
+        &session_id,
+        &IncrementCounters {
+            event_count: Some(row.event_count),
+            message_count: Some(row.message_count),
+            turn_count: Some(row.turn_count),
+            input_tokens: Some(row.total_input_tokens),
+            output_tokens: Some(row.total_output_tokens),
+            last_turn_input_tokens: Some(row.last_turn_input_tokens),
+            cost: Some(row.total_cost),
+            cache_read_tokens: Some(row.total_cache_read_tokens),
+            cache_creation_tokens: Some(row.total_cache_creation_tokens),
+        },
+        &row.last_activity_at,
+    )?;
+    if let Some(ended_at) = row.ended_at.as_deref() {
+        let _ = SessionRepo::mark_ended_at(tx, &session_id, ended_at)?;
+    }
+    Ok(())
+}
+
+/// Check that every archived event chain is complete before anything is written.
+fn validate_archive(archive: &SessionArchive) -> Result<()> {
+    let invalid = |message: String| Err(EventStoreError::InvalidOperation(message));
+    if archive.version != SESSION_ARCHIVE_VERSION {
+        return invalid(format!(
+            "unsupported session archive version {} (expected {SESSION_ARCHIVE_VERSION})",
+            archive.version
+        ));
+    }
+    if archive.sessions.last().map(|archived| &archived.session.id) != Some(&archive.session_id) {
+        return invalid(format!(
+            "session archive must end with the exported session {}",
+            archive.session_id
+        ));
+    }
+
+    // Event ID -> owning session ID, for every event already validated.
+    let mut owners: HashMap<&str, &str> = HashMap::new();
+    let mut session_ids = HashSet::new();
+    for ArchivedSession { session, events } in &archive.sessions {
+        let id = session.id.as_str();
+        if !session_ids.insert(id) {
+            return invalid(format!("session {id} appears twice in the archive"));
+        }
+        let Some(root) = events.first() else {
+            return invalid(format!("archived session {id} has no events"));
+        };
+        if session.root_event_id.as_deref() != Some(root.id.as_str()) {
+            return invalid(format!(
+                "archived session {id} does not start at its root event"
+            ));
+        }
+        match (&session.parent_session_id, root.event_type) {
+            (None, EventType::SessionStart) if root.parent_id.is_none() => {}
+            (Some(parent_session), EventType::SessionFork)
+                if root.parent_id.is_some()
+                    && root.parent_id == session.fork_from_event_id
+                    && root
+                        .parent_id
+                        .as_deref()
+                        .and_then(|parent| owners.get(parent))
+                        == Some(&parent_session.as_str()) => {}
+            _ => {
+                return invalid(format!(
+                    "archived session {id} root event does not match its fork link"
+                ));
+            }
+        }
+
+        let mut previous_sequence = None;
+        for (index, event) in events.iter().enumerate() {
+            if event.session_id != id {
+                return invalid(format!(
+                    "event {} does not belong to session {id}",
+                    event.id
+                ));
+            }
+            if previous_sequence.is_some_and(|previous| event.sequence <= previous) {
+                return invalid(format!("events of session {id} are not in sequence order"));
+            }
+            previous_sequence = Some(event.sequence);
+            let parent_in_session = event
+                .parent_id
+                .as_deref()
+                .and_then(|parent| owners.get(parent))
+                == Some(&id);
+            if index > 0 && !parent_in_session {
+                return invalid(format!(
+                    "event {} breaks the event chain of session {id}",
+                    event.id
+                ));
+            }
+            if owners.insert(event.id.as_str(), id).is_some() {
+                return invalid(format!("event {} appears twice in the archive", event.id));
+            }
+        }
+        if session
+            .head_event_id
+            .as_deref()
+            .and_then(|head| owners.get(head))
+            != Some(&id)
+        {
+            return invalid(format!(
+                "archived session {id} head is not one of its events"
+            ));
+        }
+    }
+
+    let archived_blobs = archive
+        .blobs
+        .iter()
+        .map(|blob| blob.id.clone())
+        .collect::<BTreeSet<_>>();
+    let mut referenced = BTreeSet::new();
+    for event in archive
+        .sessions
+        .iter()
+        .flat_map(|archived| &archived.events)
+    {
+        collect_blob_ids(&event.payload, &mut referenced);
+    }
+    if let Some(missing) = referenced.difference(&archived_blobs).next() {
+        return invalid(format!(
+            "archived payloads reference missing blob {missing}"
+        ));
+    }
+    Ok(())
+}
+
+fn collect_blob_ids(value: &Value, ids: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    Value::String(id) if key == "blobId" => {
+                        let _ = ids.insert(id.clone());
+                    }
+                    _ => collect_blob_ids(value, ids),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_blob_ids(item, ids)),
+        _ => {}
+    }
+}
+
+/// Rewrite archived IDs stored under [`PAYLOAD_ID_KEYS`] to their imported IDs.
+///
+/// Other strings are left alone even when they happen to equal an archived ID,
+/// so message text quoting an ID survives the import unchanged.
+fn remap_payload_ids(value: &Value, ids: &HashMap<String, String>) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| remap_payload_ids(item, ids))
+                .collect(),
+        ),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(id) if PAYLOAD_ID_KEYS.contains(&key.as_str()) => ids
+                            .get(id)
+                            .map_or_else(|| value.clone(), |id| Value::String(id.clone())),
+                        _ => remap_payload_ids(value, ids),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
@@ -0,0 +1,99 @@
+//! Fork graph queries for drawing a session's branch tree.
+//!
+//! A fork is a new session whose row records its source session and the event
+//! it branched from. [`EventStore::branch_tree`] climbs those links to the
+//! original session and returns every session reachable from it, so any
+//! member of a fork family yields the same tree.
+
+use std::collections::HashSet;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::domains::session::event_store::SessionRow;
+use crate::domains::session::event_store::errors::{EventStoreError, Result};
+use crate::domains::session::event_store::sqlite::repositories::session::SessionRepo;
+
+use super::EventStore;
+
+/// One session in a fork tree.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchNode {
+    /// Session ID.
+    pub session_id: String,
+    /// Session title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Event in the parent session this branch forked from; `None` at the root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fork_from_event_id: Option<String>,
+    /// Events owned by this session, excluding inherited parent history.
+    pub event_count: i64,
+    /// Creation timestamp.
+    pub created_at: String,
+    /// Sessions forked from this one, oldest first.
+    pub children: Vec<BranchNode>,
+}
+
+impl BranchNode {
+    /// Number of sessions in this subtree, including this one.
+    #[must_use]
+    pub fn session_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(BranchNode::session_count)
+            .sum::<usize>()
+    }
+}
+
+impl EventStore {
+    /// Return the fork tree containing `session_id`, rooted at the session
+    /// its fork chain started from.
+    pub fn branch_tree(&self, session_id: &str) -> Result<BranchNode> {
+        let conn = self.conn()?;
+        let mut root = SessionRepo::get_by_id(&conn, session_id)?
+            .ok_or_else(|| EventStoreError::SessionNotFound(session_id.to_string()))?;
+        let mut visited = HashSet::from([root.id.clone()]);
+        while let Some(parent_id) = root.parent_session_id.clone() {
+            if !visited.insert(parent_id.clone()) {
+                return Err(EventStoreError::InvalidOperation(format!(
+                    "fork ancestry of {session_id} loops back to {parent_id}"
+                )));
+            }
+            match SessionRepo::get_by_id(&conn, &parent_id)? {
+                Some(parent) => root = parent,
+                None => break,
+            }
+        }
+
+        let mut visited = HashSet::new();
+        build_node(&conn, root, &mut visited)
+    }
+}
+
+fn build_node(
+    conn: &Connection,
+    session: SessionRow,
+    visited: &mut HashSet<String>,
+) -> Result<BranchNode> {
+    if !visited.insert(session.id.clone()) {
+        return Err(EventStoreError::InvalidOperation(format!(
+            "fork tree revisits session {}",
+            session.id
+        )));
+    }
+    let children = SessionRepo::list_children(conn, &session.id)?
+        .into_iter()
+        .map(|child| build_node(conn, child, visited))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(BranchNode {
+        session_id: session.id,
+        title: session.title,
+        fork_from_event_id: session.fork_from_event_id,
+        event_count: session.event_count,
+        created_at: session.created_at,
+        children,
+    })
+}
@@ -17,7 +17,9 @@ use crate::domains::session::event_store::types::base::SessionEvent;
 use crate::domains::session::event_store::{EventRow, SessionRow};
 
 use super::{AppendOptions, EventStore};
-use crate::domains::session::event_store::redaction::redact_sensitive_content;
+use crate::domains::session::event_store::redaction::{
+    PayloadKeyRedaction, redact_sensitive_content,
+};
 
 fn resolve_payload_for_row(conn: &rusqlite::Connection, row: &EventRow) -> Result<Value> {
     crate::shared::storage::resolve_stored_json_value(conn, &row.payload).map_err(|error| {
@@ -28,6 +30,12 @@ fn resolve_payload_for_row(conn: &rusqlite::Connection, row: &EventRow) -> Resul
     })
 }
 
+/// Redact a payload the way every persisted event payload is redacted:
+/// value-pattern secrets first, then the configured payload keys.
+pub(super) fn redact_event_payload(payload: &Value, key_redaction: &PayloadKeyRedaction) -> Value {
+    key_redaction.redact(&redact_json_strings(payload))
+}
+
 /// Append a single event with an explicit durable identity.
 ///
 /// This is the core write primitive used by [`EventStore::append`] and
@@ -39,11 +47,15 @@ fn resolve_payload_for_row(conn: &rusqlite::Connection, row: &EventRow) -> Resul
 /// - committing or rolling back the transaction,
 /// - keeping `session.head_event_id` fresh if performing a loop of appends
 ///   (set it to the returned event's id after each call).
+///
+/// `key_redaction` is applied to the payload before it is written, after the
+/// value-pattern secret redaction; counters still read the caller's payload.
 pub(super) fn append_event_in_tx_with_identity(
     tx: &rusqlite::Transaction<'_>,
     session: &SessionRow,
     opts: &AppendOptions<'_>,
     identity: EventIdentity,
+    key_redaction: &PayloadKeyRedaction,
 ) -> Result<SessionEvent> {
     let parent_id = match opts.parent_id {
         Some(pid) => Some(pid.to_string()),
@@ -81,7 +93,7 @@ pub(super) fn append_event_in_tx_with_identity(
         }
     };
 
-    let payload = redact_json_strings(&opts.payload);
+    let payload = redact_event_payload(&opts.payload, key_redaction);
 
     let event = SessionEvent {
         id: identity.id,
@@ -197,7 +209,13 @@ impl EventStore {
         let session = SessionRepo::get_by_id(&tx, opts.session_id)?
             .ok_or_else(|| EventStoreError::SessionNotFound(opts.session_id.to_string()))?;
 
-        let event = append_event_in_tx_with_identity(&tx, &session, opts, identity)?;
+        let event = append_event_in_tx_with_identity(
+            &tx,
+            &session,
+            opts,
+            identity,
+            &self.payload_key_redaction,
+        )?;
         tx.commit()?;
 
         EventRepo::get_by_id(&conn, &event.id)?.ok_or(EventStoreError::EventNotFound(event.id))
@@ -99,6 +99,17 @@ impl RecentLogQuery<'_> {
     }
 }
 
+impl<'a> RecentLogQuery<'a> {
+    /// Build a query for every log line correlated to one trace (agent run),
+    /// across all components and sessions.
+    pub fn by_trace_id(trace_id: &'a str, limit: i64) -> Self {
+        Self {
+            trace_id: Some(trace_id),
+            ..RecentLogQuery::all(limit)
+        }
+    }
+}
+
 /// A durable log row projected through the event-store facade.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LogEntry {
@@ -541,6 +552,41 @@ mod tests {
         assert_eq!(global_messages, ["global"]);
     }
 
+    #[test]
+    fn list_recent_logs_by_trace_id_returns_only_that_run() {
+        let store = make_store();
+        let entries = [
+            (
+                "2026-03-03T14:30:05.100Z",
+                "Engine",
+                "run a start",
+                "trace_a",
+            ),
+            (
+                "2026-03-03T14:30:05.200Z",
+                "Tools",
+                "run b start",
+                "trace_b",
+            ),
+            ("2026-03-03T14:30:05.300Z", "Tools", "run a tool", "trace_a"),
+        ]
+        .map(|(timestamp, category, message, trace_id)| {
+            let mut entry = ClientLogEntry::new(timestamp, "info", category, message);
+            entry.trace_id = Some(trace_id.to_owned());
+            entry
+        });
+        store.ingest_client_logs(&entries).unwrap();
+
+        let logs = store
+            .list_recent_logs(RecentLogQuery::by_trace_id("trace_a", 10))
+            .unwrap();
+        let messages = logs
+            .iter()
+            .map(|entry| entry.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["run a start", "run a tool"]);
+    }
+
     #[test]
     fn list_recent_logs_applies_workspace_scope_and_keeps_correlation_ids() {
         let store = make_store();
@@ -0,0 +1,86 @@
+//! Database maintenance: `VACUUM` to reclaim dead pages and `ANALYZE` to
+//! refresh query-planner statistics.
+//!
+//! Both run under the global write lock with busy retries, so they are safe
+//! while the server is up: WAL readers keep reading the pre-vacuum snapshot,
+//! and session writers that collide with the vacuum retry on `SQLITE_BUSY`.
+
+use crate::domains::session::event_store::errors::{EventStoreError, Result};
+
+use super::EventStore;
+
+/// Result of [`EventStore::vacuum`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VacuumStats {
+    /// Database size (`page_count * page_size`) before the vacuum.
+    pub bytes_before: u64,
+    /// Database size after the vacuum.
+    pub bytes_after: u64,
+    /// Free-list pages (dead pages left by deletes) before the vacuum.
+    pub free_pages_before: u64,
+}
+
+impl VacuumStats {
+    /// Bytes returned to the filesystem.
+    #[must_use]
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
+impl EventStore {
+    /// Rebuild the database file without dead pages.
+    ///
+    /// Under WAL the rebuilt pages land in the `-wal` file first, so the
+    /// vacuum is followed by a `TRUNCATE` checkpoint that shrinks the main
+    /// file and resets the WAL. The copy is spilled to a temporary file
+    /// rather than the pool's in-memory temp store.
+    pub fn vacuum(&self) -> Result<VacuumStats> {
+        self.with_global_write_lock(|| {
+            let conn = self.conn()?;
+            let (bytes_before, free_pages_before) = database_pages(&conn)?;
+            conn.execute_batch("PRAGMA temp_store = FILE")?;
+            let vacuumed = conn.execute_batch("VACUUM");
+            conn.execute_batch("PRAGMA temp_store = MEMORY")?;
+            vacuumed?;
+            let _: (i64, i64, i64) =
+                conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+                })?;
+            let (bytes_after, _) = database_pages(&conn)?;
+            tracing::info!(
+                bytes_before,
+                bytes_after,
+                free_pages_before,
+                "event store vacuumed"
+            );
+            Ok(VacuumStats {
+                bytes_before,
+                bytes_after,
+                free_pages_before,
+            })
+        })
+    }
+
+    /// Refresh query-planner statistics with `ANALYZE`.
+    pub fn analyze(&self) -> Result<()> {
+        self.with_global_write_lock(|| {
+            let conn = self.conn()?;
+            conn.execute_batch("ANALYZE")?;
+            Ok(())
+        })
+    }
+}
+
+/// Database size in bytes and free-list page count.
+fn database_pages(conn: &rusqlite::Connection) -> Result<(u64, u64)> {
+    let pragma = |name: &str| -> Result<u64> {
+        let value: i64 = conn.query_row(&format!("PRAGMA {name}"), [], |row| row.get(0))?;
+        u64::try_from(value)
+            .map_err(|_| EventStoreError::Internal(format!("negative PRAGMA {name}: {value}")))
+    };
+    Ok((
+        pragma("page_count")? * pragma("page_size")?,
+        pragma("freelist_count")?,
+    ))
+}
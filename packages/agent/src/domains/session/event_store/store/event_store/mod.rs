@@ -12,18 +12,25 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::{Mutex, Weak};
 
+use crate::domains::session::event_store::redaction::PayloadKeyRedaction;
 use crate::domains::session::event_store::sqlite::connection::ConnectionPool;
 use crate::domains::session::event_store::types::EventType;
 use crate::domains::session::event_store::{EventRow, SessionRow};
 
+mod archive;
 mod auxiliary;
+mod branches;
 mod event_log;
 mod locking;
 mod logs;
+mod maintenance;
 mod session_lifecycle;
 mod state;
 mod trace_log;
 
+pub use self::archive::{ArchivedBlob, ArchivedSession, SESSION_ARCHIVE_VERSION, SessionArchive};
+pub use self::branches::BranchNode;
+pub use self::maintenance::VacuumStats;
 pub use self::state::event_rows_to_session_events;
 pub use logs::{ClientLogEntry, ClientLogIngestResult, LogEntry, LogSessionFilter, RecentLogQuery};
 
@@ -86,6 +93,7 @@ pub struct EventStore {
     pool: ConnectionPool,
     global_write_lock: Mutex<()>,
     session_write_locks: Mutex<HashMap<String, Weak<Mutex<()>>>>,
+    payload_key_redaction: PayloadKeyRedaction,
 }
 
 impl EventStore {
@@ -95,8 +103,17 @@ impl EventStore {
             pool,
             global_write_lock: Mutex::new(()),
             session_write_locks: Mutex::new(HashMap::new()),
+            payload_key_redaction: PayloadKeyRedaction::default(),
         }
     }
+
+    /// Redact values under matching payload keys before appended events are
+    /// persisted. Off unless at least one key pattern is configured.
+    #[must_use]
+    pub fn with_payload_key_redaction(mut self, redaction: PayloadKeyRedaction) -> Self {
+        self.payload_key_redaction = redaction;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,73 @@
+use serde_json::json;
+
+use super::*;
+use crate::domains::session::event_store::sqlite::checkpoint::CheckpointMode;
+
+/// File-backed store: in-memory databases have no file to shrink.
+fn file_store(dir: &tempfile::TempDir) -> (EventStore, ConnectionPool) {
+    let path = dir.path().join("vacuum.db");
+    let pool = connection::new_file(path.to_str().unwrap(), &ConnectionConfig::default()).unwrap();
+    run_migrations(&pool.get().unwrap()).unwrap();
+    (EventStore::new(pool.clone()), pool)
+}
+
+fn fill_session(store: &EventStore, events: usize) -> String {
+    let session_id = store
+        .create_session("claude-opus-4-6", "/tmp/vacuum", None, None)
+        .unwrap()
+        .session
+        .id;
+    let text = "x".repeat(4096);
+    for _ in 0..events {
+        store
+            .append(&AppendOptions {
+                session_id: &session_id,
+                event_type: EventType::MessageUser,
+                payload: json!({"content": text}),
+                parent_id: None,
+                sequence: None,
+            })
+            .unwrap();
+    }
+    session_id
+}
+
+#[test]
+fn vacuum_after_deleting_rows_shrinks_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let (store, pool) = file_store(&dir);
+    let session_id = fill_session(&store, 300);
+    pool.checkpoint(CheckpointMode::Truncate).unwrap();
+    let filled = pool.db_size_bytes().main_bytes;
+
+    assert!(store.delete_session(&session_id).unwrap());
+    pool.checkpoint(CheckpointMode::Truncate).unwrap();
+    assert_eq!(
+        pool.db_size_bytes().main_bytes,
+        filled,
+        "deletes leave dead pages"
+    );
+
+    let stats = store.vacuum().unwrap();
+    assert!(stats.free_pages_before > 0);
+    assert!(stats.reclaimed_bytes() > 300 * 4096 / 2, "{stats:?}");
+
+    let size = pool.db_size_bytes();
+    assert!(size.main_bytes < filled, "{size:?} vs {filled}");
+    assert_eq!(size.wal_bytes, 0);
+}
+
+#[test]
+fn vacuum_and_analyze_keep_data_readable() {
+    let dir = tempfile::tempdir().unwrap();
+    let (store, _pool) = file_store(&dir);
+    let session_id = fill_session(&store, 5);
+
+    store.vacuum().unwrap();
+    store.analyze().unwrap();
+
+    let events = store
+        .get_events_by_session(&session_id, &ListEventsOptions::default())
+        .unwrap();
+    assert_eq!(events.len(), 6);
+}
@@ -20,6 +20,9 @@ fn setup() -> EventStore {
 mod activity_summary;
 mod append_counters;
 mod auto_sequence;
+mod maintenance;
+mod payload_redaction;
 mod queries_state;
+mod session_archive;
 mod session_creation;
 mod tree_sessions;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::domains::session::event_store::redaction::{PayloadKeyRedaction, REDACTED_VALUE};
+
+fn append_tool_call(store: &EventStore, session_id: &str) -> Value {
+    let event = store
+        .append(&AppendOptions {
+            session_id,
+            event_type: EventType::CapabilityInvocationCompleted,
+            payload: serde_json::json!({
+                "name": "Bash",
+                "arguments": {
+                    "command": "deploy",
+                    "credentials": [{"user": "ops", "password": "hunter2"}],
+                    "apiToken": "tok-plain"
+                }
+            }),
+            parent_id: None,
+            sequence: None,
+        })
+        .unwrap();
+    let conn = store.conn().unwrap();
+    let fetched = EventRepo::get_by_id(&conn, &event.id).unwrap().unwrap();
+    serde_json::from_str(&fetched.payload).unwrap()
+}
+
+#[test]
+fn append_redacts_configured_keys_in_nested_payloads() {
+    let store =
+        setup().with_payload_key_redaction(PayloadKeyRedaction::new(["password", "api_token"]));
+    let cr = store
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap();
+
+    let payload = append_tool_call(&store, &cr.session.id);
+
+    let arguments = &payload["arguments"];
+    assert_eq!(arguments["credentials"][0]["password"], REDACTED_VALUE);
+    assert_eq!(arguments["apiToken"], REDACTED_VALUE);
+    assert_eq!(arguments["credentials"][0]["user"], "ops");
+    assert_eq!(arguments["command"], "deploy");
+    assert_eq!(payload["name"], "Bash");
+}
+
+#[test]
+fn append_keeps_payload_keys_without_configured_patterns() {
+    let store = setup();
+    let cr = store
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap();
+
+    let payload = append_tool_call(&store, &cr.session.id);
+
+    assert_eq!(
+        payload["arguments"]["credentials"][0]["password"],
+        "hunter2"
+    );
+    assert_eq!(payload["arguments"]["apiToken"], "tok-plain");
+}
+
+#[test]
+fn token_pattern_leaves_persisted_token_usage_intact() {
+    let store = setup().with_payload_key_redaction(PayloadKeyRedaction::new(["token"]));
+    let cr = store
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap();
+    let sid = &cr.session.id;
+
+    let event = store
+        .append(&AppendOptions {
+            session_id: sid,
+            event_type: EventType::MessageAssistant,
+            payload: serde_json::json!({
+                "content": [{"type": "text", "text": "done"}],
+                "turn": 1,
+                "token": "tok-plain",
+                "tokenUsage": {"inputTokens": 120, "outputTokens": 30}
+            }),
+            parent_id: None,
+            sequence: None,
+        })
+        .unwrap();
+    let conn = store.conn().unwrap();
+    let fetched = EventRepo::get_by_id(&conn, &event.id).unwrap().unwrap();
+    let payload: Value = serde_json::from_str(&fetched.payload).unwrap();
+    drop(conn);
+
+    assert_eq!(payload["token"], REDACTED_VALUE);
+    assert_eq!(
+        payload["tokenUsage"],
+        serde_json::json!({"inputTokens": 120, "outputTokens": 30})
+    );
+}
@@ -0,0 +1,253 @@
+use serde_json::json;
+
+use super::*;
+use crate::domains::session::event_store::redaction::{PayloadKeyRedaction, REDACTED_VALUE};
+
+fn append(store: &EventStore, session_id: &str, event_type: EventType, payload: Value) -> EventRow {
+    store
+        .append(&AppendOptions {
+            session_id,
+            event_type,
+            payload,
+            parent_id: None,
+            sequence: None,
+        })
+        .unwrap()
+}
+
+fn message_texts(store: &EventStore, session_id: &str) -> Vec<String> {
+    store
+        .get_messages_at_head(session_id)
+        .unwrap()
+        .messages_with_event_ids
+        .iter()
+        .map(|message| serde_json::to_string(&message.message).unwrap())
+        .collect()
+}
+
+/// Source session with a deleted message and a blob-backed capability result,
+/// plus a fork of it that continues the conversation.
+fn forked_source(store: &EventStore) -> (String, String, String) {
+    let source = store
+        .create_session(
+            "claude-opus-4-6",
+            "/tmp/archive-source",
+            Some("Source"),
+            None,
+        )
+        .unwrap()
+        .session
+        .id;
+    let hello = append(
+        store,
+        &source,
+        EventType::MessageUser,
+        json!({"content": "Hello"}),
+    );
+    let typo = append(
+        store,
+        &source,
+        EventType::MessageUser,
+        json!({"content": "Helo"}),
+    );
+    append(
+        store,
+        &source,
+        EventType::MessageDeleted,
+        json!({"targetEventId": typo.id, "targetType": "message.user"}),
+    );
+    let blob_id = store
+        .store_blob(b"full capability output", "text/plain")
+        .unwrap();
+    append(
+        store,
+        &source,
+        EventType::CapabilityInvocationCompleted,
+        json!({"invocationId": "inv-1", "content": "full…", "isError": false, "duration": 3, "truncated": true, "blobId": blob_id}),
+    );
+    let fork = store
+        .fork(
+            &hello.id,
+            &ForkOptions {
+                model: None,
+                title: Some("Fork"),
+            },
+        )
+        .unwrap()
+        .session
+        .id;
+    append(
+        store,
+        &fork,
+        EventType::MessageUser,
+        json!({"content": "From the fork"}),
+    );
+    (source, fork, blob_id)
+}
+
+#[test]
+fn forked_session_round_trips_through_a_fresh_store() {
+    let exporter = setup();
+    let (source, fork, blob_id) = forked_source(&exporter);
+    let archive = exporter.export_session(&fork).unwrap();
+    assert_eq!(archive.version, SESSION_ARCHIVE_VERSION);
+    assert_eq!(archive.session_id, fork);
+    let archived_ids = archive
+        .sessions
+        .iter()
+        .map(|archived| archived.session.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(archived_ids, vec![source.as_str(), fork.as_str()]);
+    assert_eq!(archive.blobs.len(), 1);
+    assert_eq!(archive.blobs[0].id, blob_id);
+
+    // The archive is plain JSON: serialize it as another machine would receive it.
+    let wire = serde_json::to_string(&archive).unwrap();
+    let importer = setup();
+    let imported = importer
+        .import_session(&serde_json::from_str(&wire).unwrap())
+        .unwrap();
+
+    assert_ne!(imported.id, fork);
+    assert_eq!(imported.title.as_deref(), Some("Fork"));
+    let original = exporter.get_session(&fork).unwrap().unwrap();
+    assert_eq!(imported.event_count, original.event_count);
+    assert_eq!(imported.message_count, original.message_count);
+    assert_eq!(imported.created_at, original.created_at);
+    assert_eq!(
+        message_texts(&importer, &imported.id),
+        message_texts(&exporter, &fork)
+    );
+
+    let imported_source = importer
+        .get_session(imported.parent_session_id.as_deref().unwrap())
+        .unwrap()
+        .unwrap();
+    assert_ne!(imported_source.id, source);
+    assert_eq!(imported_source.title.as_deref(), Some("Source"));
+    assert_eq!(
+        message_texts(&importer, &imported_source.id),
+        message_texts(&exporter, &source)
+    );
+    let fork_point = imported.fork_from_event_id.as_deref().unwrap();
+    let fork_root = EventRepo::get_by_id(
+        &importer.conn().unwrap(),
+        imported.root_event_id.as_deref().unwrap(),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(fork_root.parent_id.as_deref(), Some(fork_point));
+    assert_eq!(
+        EventRepo::get_by_id(&importer.conn().unwrap(), fork_point)
+            .unwrap()
+            .unwrap()
+            .session_id,
+        imported_source.id
+    );
+
+    // Payload references follow the fresh IDs.
+    let reexported = importer.export_session(&imported.id).unwrap();
+    let events = &reexported.sessions[0].events;
+    let deleted = events
+        .iter()
+        .find(|event| event.event_type == EventType::MessageDeleted)
+        .unwrap();
+    let target = deleted.payload["targetEventId"].as_str().unwrap();
+    assert!(events.iter().any(|event| event.id == target));
+    let completed = events
+        .iter()
+        .find(|event| event.event_type == EventType::CapabilityInvocationCompleted)
+        .unwrap();
+    let new_blob_id = completed.payload["blobId"].as_str().unwrap();
+    assert_eq!(
+        importer.get_blob_content(new_blob_id).unwrap().unwrap(),
+        b"full capability output"
+    );
+    let fork_payload = &reexported.sessions[1].events[0].payload;
+    assert_eq!(fork_payload["sourceSessionId"], imported_source.id);
+    assert_eq!(fork_payload["sourceEventId"], fork_point);
+}
+
+#[test]
+fn importing_the_same_archive_twice_creates_independent_sessions() {
+    let store = setup();
+    let (_, fork, _) = forked_source(&store);
+    let archive = store.export_session(&fork).unwrap();
+    let first = store.import_session(&archive).unwrap();
+    let second = store.import_session(&archive).unwrap();
+    assert_ne!(first.id, second.id);
+    assert_ne!(first.parent_session_id, second.parent_session_id);
+    assert_eq!(
+        message_texts(&store, &first.id),
+        message_texts(&store, &second.id)
+    );
+}
+
+#[test]
+fn import_rejects_broken_event_chains_without_writing() {
+    let exporter = setup();
+    let (_, fork, _) = forked_source(&exporter);
+    let archive = exporter.export_session(&fork).unwrap();
+
+    let mut dangling = archive.clone();
+    dangling.sessions[0].events[2].parent_id = Some("evt_missing".to_owned());
+    let mut unordered = archive.clone();
+    unordered.sessions[0].events[2].sequence = unordered.sessions[0].events[1].sequence;
+    let mut orphaned_fork = archive.clone();
+    orphaned_fork.sessions.remove(0);
+    let mut missing_blob = archive.clone();
+    missing_blob.blobs.clear();
+    let mut wrong_version = archive;
+    wrong_version.version = SESSION_ARCHIVE_VERSION + 1;
+
+    let importer = setup();
+    for (broken, expected) in [
+        (dangling, "breaks the event chain"),
+        (unordered, "not in sequence order"),
+        (orphaned_fork, "does not match its fork link"),
+        (missing_blob, "missing blob"),
+        (wrong_version, "unsupported session archive version"),
+    ] {
+        let error = importer.import_session(&broken).unwrap_err();
+        assert!(error.to_string().contains(expected), "{error}");
+    }
+    assert!(
+        importer
+            .list_sessions(&ListSessionsOptions::default())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn import_remaps_only_id_keys_and_redacts_like_append() {
+    let exporter = setup();
+    let source = exporter
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap()
+        .session
+        .id;
+    let root = exporter
+        .get_session(&source)
+        .unwrap()
+        .unwrap()
+        .root_event_id
+        .unwrap();
+    let _ = append(
+        &exporter,
+        &source,
+        EventType::MessageUser,
+        json!({"content": root.clone(), "arguments": {"apiToken": "tok-plain"}}),
+    );
+    let archive = exporter.export_session(&source).unwrap();
+
+    let importer = setup().with_payload_key_redaction(PayloadKeyRedaction::new(["api_token"]));
+    let imported = importer.import_session(&archive).unwrap();
+    let conn = importer.conn().unwrap();
+    let head = EventRepo::get_by_id(&conn, imported.head_event_id.as_deref().unwrap())
+        .unwrap()
+        .unwrap();
+    let payload: Value = serde_json::from_str(&head.payload).unwrap();
+    assert_eq!(payload["content"], root.as_str());
+    assert_eq!(payload["arguments"]["apiToken"], REDACTED_VALUE);
+}
@@ -498,3 +498,91 @@ fn fork_then_diverge() {
     // Original assistant response NOT in fork ancestors
     assert!(fork_ancestors.iter().all(|e| e.id != assistant_msg.id));
 }
+
+// ── Branch tree ───────────────────────────────────────────────────
+
+fn append_user(store: &EventStore, session_id: &str, content: &str) -> EventRow {
+    store
+        .append(&AppendOptions {
+            session_id,
+            event_type: EventType::MessageUser,
+            payload: serde_json::json!({"content": content}),
+            parent_id: None,
+            sequence: None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn branch_tree_nests_forks_under_their_source() {
+    let store = setup();
+    let cr = store
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap();
+    let first = append_user(&store, &cr.session.id, "first");
+    let second = append_user(&store, &cr.session.id, "second");
+
+    let early = store.fork(&first.id, &ForkOptions::default()).unwrap();
+    append_user(&store, &early.session.id, "early branch");
+    let late = store.fork(&second.id, &ForkOptions::default()).unwrap();
+    let nested = store
+        .fork(&early.fork_event.id, &ForkOptions::default())
+        .unwrap();
+
+    let tree = store.branch_tree(&cr.session.id).unwrap();
+    assert_eq!(tree.session_id, cr.session.id);
+    assert_eq!(tree.fork_from_event_id, None);
+    assert_eq!(tree.event_count, 3);
+    assert_eq!(tree.session_count(), 4);
+
+    let children: Vec<_> = tree
+        .children
+        .iter()
+        .map(|child| {
+            (
+                child.session_id.as_str(),
+                child.fork_from_event_id.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        children,
+        vec![
+            (early.session.id.as_str(), Some(first.id.as_str())),
+            (late.session.id.as_str(), Some(second.id.as_str())),
+        ]
+    );
+    assert_eq!(tree.children[0].event_count, 2);
+    assert_eq!(tree.children[1].event_count, 1);
+    assert!(tree.children[1].children.is_empty());
+
+    let grandchild = &tree.children[0].children;
+    assert_eq!(grandchild.len(), 1);
+    assert_eq!(grandchild[0].session_id, nested.session.id);
+    assert_eq!(
+        grandchild[0].fork_from_event_id.as_deref(),
+        Some(early.fork_event.id.as_str())
+    );
+}
+
+#[test]
+fn branch_tree_is_the_same_from_any_member() {
+    let store = setup();
+    let cr = store
+        .create_session("claude-opus-4-6", "/tmp/project", None, None)
+        .unwrap();
+    let fork = store
+        .fork(&cr.root_event.id, &ForkOptions::default())
+        .unwrap();
+
+    assert_eq!(
+        store.branch_tree(&fork.session.id).unwrap(),
+        store.branch_tree(&cr.session.id).unwrap()
+    );
+}
+
+#[test]
+fn branch_tree_unknown_session_fails() {
+    let store = setup();
+    assert!(store.branch_tree("sess_missing").is_err());
+}
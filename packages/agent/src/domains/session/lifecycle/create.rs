@@ -1,4 +1,6 @@
 use super::{BaseEvent, CreateSessionRequest, Deps, SessionLifecycleService, TronEvent};
+use crate::domains::agent::{CAPABILITY_POLICY_METADATA_KEY, CapabilityPolicy};
+use crate::domains::session::event_store::{AppendOptions, EventType};
 use crate::shared::server::context::run_blocking_task;
 use crate::shared::server::errors::CapabilityError;
 use serde_json::Value;
@@ -38,7 +40,14 @@ impl SessionLifecycleService {
                 title: request.title.clone(),
             });
 
-        deps.orchestrator.init_sequence_counter(&session_id, 0);
+        let mut last_sequence = 0;
+        if let Some(policy) = request.capability_policy.as_ref() {
+            last_sequence = persist_capability_policy(deps, &session_id, policy).await?;
+            deps.orchestrator
+                .restrict_capability_policy(&session_id, policy);
+        }
+        deps.orchestrator
+            .init_sequence_counter(&session_id, last_sequence);
 
         Ok(json!({
             "sessionId": session_id,
@@ -55,3 +64,33 @@ impl SessionLifecycleService {
         }))
     }
 }
+
+/// Record the session's capability policy so resume can restore it.
+/// Returns the sequence of the appended event.
+async fn persist_capability_policy(
+    deps: &Deps,
+    session_id: &str,
+    policy: &CapabilityPolicy,
+) -> Result<i64, CapabilityError> {
+    let event_store = deps.event_store.clone();
+    let session_id = session_id.to_owned();
+    let payload = json!({
+        "key": CAPABILITY_POLICY_METADATA_KEY,
+        "newValue": policy,
+    });
+    run_blocking_task("session.create.capability_policy", move || {
+        event_store
+            .append(&AppendOptions {
+                session_id: &session_id,
+                event_type: EventType::MetadataUpdate,
+                payload,
+                parent_id: None,
+                sequence: None,
+            })
+            .map(|event| event.sequence)
+            .map_err(|error| CapabilityError::Internal {
+                message: error.to_string(),
+            })
+    })
+    .await
+}
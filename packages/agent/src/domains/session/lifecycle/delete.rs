@@ -20,6 +20,9 @@ impl SessionLifecycleService {
 
         deps.orchestrator.remove_sequence_counter(&session_id);
         deps.orchestrator.remove_compaction_handler(&session_id);
+        deps.orchestrator.clear_capability_policy(&session_id);
+        let _ = deps.orchestrator.take_pending_plan(&session_id);
+        deps.orchestrator.clear_context_pressure(&session_id);
 
         let _ = deps
             .orchestrator
@@ -33,12 +33,15 @@
 
 use crate::shared::protocol::events::{BaseEvent, TronEvent};
 
+use crate::domains::agent::CapabilityPolicy;
 use crate::domains::session::Deps;
 
 pub(crate) struct CreateSessionRequest {
     pub(crate) working_directory: String,
     pub(crate) model: String,
     pub(crate) title: Option<String>,
+    /// Restriction applied to every run of the new session.
+    pub(crate) capability_policy: Option<CapabilityPolicy>,
 }
 
 pub(crate) struct SessionLifecycleService;
@@ -1,8 +1,40 @@
+use crate::domains::agent::CapabilityPolicy;
 use crate::domains::session::Deps;
 use crate::shared::server::errors::CapabilityError;
 use crate::shared::server::params::{opt_string, require_string_param};
 use serde_json::Value;
 
+/// Parse `capabilityPolicy: { allowed?: [name], denied?: [name] }`.
+fn capability_policy_param(
+    params: Option<&Value>,
+) -> Result<Option<CapabilityPolicy>, CapabilityError> {
+    let Some(policy) = params.and_then(|p| p.get("capabilityPolicy")) else {
+        return Ok(None);
+    };
+    let names = |key: &str| -> Result<Option<Vec<String>>, CapabilityError> {
+        let Some(value) = policy.get(key) else {
+            return Ok(None);
+        };
+        value
+            .as_array()
+            .and_then(|items| {
+                items
+                    .iter()
+                    .map(|item| item.as_str().map(ToOwned::to_owned))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .map(Some)
+            .ok_or_else(|| CapabilityError::InvalidParams {
+                message: format!("Parameter 'capabilityPolicy.{key}' must be an array of strings"),
+            })
+    };
+    let denied = CapabilityPolicy::deny(names("denied")?.unwrap_or_default());
+    Ok(Some(match names("allowed")? {
+        Some(allowed) => CapabilityPolicy::allow_only(allowed).compose(&denied),
+        None => denied,
+    }))
+}
+
 pub(crate) async fn session_create_value(
     params: Option<&Value>,
     deps: &Deps,
@@ -11,12 +43,14 @@ pub(crate) async fn session_create_value(
     let model =
         opt_string(params, "model").unwrap_or_else(|| "claude-sonnet-4-20250514".to_owned());
     let title = opt_string(params, "title");
+    let capability_policy = capability_policy_param(params)?;
     crate::domains::session::lifecycle::SessionLifecycleService::create(
         deps,
         crate::domains::session::lifecycle::CreateSessionRequest {
             working_directory,
             model,
             title,
+            capability_policy,
         },
     )
     .await
@@ -14,6 +14,7 @@ async fn create_normalizes_home_alias_working_directory() {
             working_directory: "~".to_owned(),
             model: "gpt-5.5".to_owned(),
             title: Some("home alias".to_owned()),
+            capability_policy: None,
         },
     )
     .await
@@ -24,3 +25,88 @@ async fn create_normalizes_home_alias_working_directory() {
     let session = ctx.event_store.get_session(session_id).unwrap().unwrap();
     assert_eq!(session.working_directory, expected);
 }
+
+#[tokio::test]
+async fn create_applies_the_requested_capability_policy_to_the_session() {
+    use crate::domains::agent::CapabilityPolicy;
+
+    let ctx = make_test_context();
+    let deps = Deps::from_test_context(&ctx);
+    let params = serde_json::json!({
+        "workingDirectory": "/tmp",
+        "capabilityPolicy": {
+            "allowed": ["execute"],
+            "denied": ["file_write"]
+        }
+    });
+
+    let response = super::super::session_create_value(Some(&params), &deps)
+        .await
+        .unwrap();
+
+    let session_id = response["sessionId"].as_str().unwrap();
+    let policy = deps.orchestrator.capability_policy(session_id);
+    assert_eq!(
+        policy,
+        CapabilityPolicy::allow_only(["execute"]).compose(&CapabilityPolicy::deny(["file_write"]))
+    );
+    let write = serde_json::Map::from_iter([("operation".to_owned(), "file_write".into())]);
+    assert_eq!(
+        policy.refusal("execute", &write).as_deref(),
+        Some("file_write")
+    );
+    assert_eq!(policy.refusal("execute", &serde_json::Map::new()), None);
+}
+
+#[tokio::test]
+async fn create_rejects_a_malformed_capability_policy() {
+    let ctx = make_test_context();
+    let params = serde_json::json!({
+        "workingDirectory": "/tmp",
+        "capabilityPolicy": { "denied": "file_write" }
+    });
+
+    let error = super::super::session_create_value(Some(&params), &Deps::from_test_context(&ctx))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code(), "INVALID_PARAMS");
+}
+
+#[tokio::test]
+async fn capability_policy_survives_a_restart_and_is_enforced_after_resume() {
+    use crate::domains::agent::{CapabilityPolicy, Orchestrator, SessionManager};
+    use std::sync::Arc;
+
+    let ctx = make_test_context();
+    let deps = Deps::from_test_context(&ctx);
+    let params = serde_json::json!({
+        "workingDirectory": "/tmp",
+        "capabilityPolicy": { "denied": ["file_write"] }
+    });
+    let response = super::super::session_create_value(Some(&params), &deps)
+        .await
+        .unwrap();
+    let session_id = response["sessionId"].as_str().unwrap();
+
+    // A fresh session manager and orchestrator over the same store stand in
+    // for a server restart: nothing survives in memory.
+    let session_manager = Arc::new(SessionManager::new(ctx.event_store.clone()));
+    let orchestrator = Orchestrator::new(session_manager.clone());
+    assert_eq!(
+        orchestrator.capability_policy(session_id),
+        CapabilityPolicy::default()
+    );
+
+    let resumed = session_manager.resume_session(session_id).unwrap();
+    orchestrator.restore_capability_policy(session_id, &resumed.state);
+
+    let policy = orchestrator.capability_policy(session_id);
+    let write = serde_json::Map::from_iter([("operation".to_owned(), "file_write".into())]);
+    assert_eq!(
+        policy.refusal("execute", &write).as_deref(),
+        Some("file_write")
+    );
+    let read = serde_json::Map::from_iter([("operation".to_owned(), "file_read".into())]);
+    assert_eq!(policy.refusal("execute", &read), None);
+}
@@ -622,6 +622,14 @@ fn engine_error_replay_details(error: &EngineError) -> Value {
         EngineError::WorkerTransportFailure { code, message } => {
             json!({"kind": "worker_transport_failure", "code": code, "message": message})
         }
+        EngineError::HandlerTimedOut {
+            function_id,
+            timeout_ms,
+        } => json!({
+            "kind": "handler_timed_out",
+            "functionId": function_id,
+            "timeoutMs": timeout_ms
+        }),
         EngineError::HandlerFailed(message) => {
             json!({"kind": "handler_failed", "message": message})
         }
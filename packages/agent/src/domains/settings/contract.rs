@@ -20,7 +20,7 @@ pub(crate) fn capabilities() -> EngineResult<Vec<CapabilitySpec>> {
             .build()?,
         CapabilityContract::new("settings::update", "settings", EffectClass::ReversibleSideEffect, RiskLevel::High, Some("settings.write"))
             .request_schema(json!({"additionalProperties":false,"properties":{"sessionId":{"type":"string"},"settings":{"additionalProperties":true,"type":"object"},"workspaceId":{"type":"string"}},"required":["settings"],"type":"object"}))
-            .response_schema(json!({"additionalProperties":false,"properties":{"success":{"type":"boolean"}},"required":["success"],"type":"object"}))
+            .response_schema(json!({"additionalProperties":false,"properties":{"changes":{"items":{"additionalProperties":false,"properties":{"newValue":{},"oldValue":{},"path":{"type":"string"}},"required":["path","oldValue","newValue"],"type":"object"},"type":"array"},"success":{"type":"boolean"}},"required":["success","changes"],"type":"object"}))
             .idempotency(IdempotencyContract::caller_system_engine_ledger())
             .compensation(CompensationContract::new(CompensationKind::InverseCommandAvailable, "domain-specific tests preserve current rollback, no-op, or replay behavior"))
             .stream_topics(STREAM_TOPICS.to_vec())
@@ -0,0 +1,130 @@
+//! Structural diff between effective settings snapshots.
+//!
+//! `settings.update` returns the leaf paths that actually changed so clients
+//! can confirm a write without re-fetching the whole settings tree. Paths use
+//! the wire (camelCase) key names joined with `.`; arrays are compared as
+//! whole values because the settings schema replaces arrays on merge.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// One changed leaf in a settings diff.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsChange {
+    /// Dotted wire path, e.g. `context.compactor.maxTokens`.
+    pub path: String,
+    /// Value before the change (`null` when the key was absent).
+    pub old_value: Value,
+    /// Value after the change (`null` when the key was removed).
+    pub new_value: Value,
+}
+
+/// Compute the changed leaf paths between two settings JSON values.
+///
+/// Objects are walked recursively; every other value is compared as a leaf.
+/// Changes are returned in sorted path order.
+pub fn diff_settings(old: &Value, new: &Value) -> Vec<SettingsChange> {
+    let mut changes = Vec::new();
+    diff_into(&mut changes, String::new(), old, new);
+    changes
+}
+
+fn diff_into(changes: &mut Vec<SettingsChange>, path: String, old: &Value, new: &Value) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => diff_objects(changes, &path, old, new),
+        _ if old == new => {}
+        _ => changes.push(SettingsChange {
+            path,
+            old_value: old.clone(),
+            new_value: new.clone(),
+        }),
+    }
+}
+
+fn diff_objects(
+    changes: &mut Vec<SettingsChange>,
+    prefix: &str,
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+) {
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        diff_into(
+            changes,
+            path,
+            old.get(key).unwrap_or(&Value::Null),
+            new.get(key).unwrap_or(&Value::Null),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn single_field_change_reports_old_and_new() {
+        let old = json!({"server": {"defaultModel": "a", "heartbeatIntervalMs": 30000}});
+        let new = json!({"server": {"defaultModel": "b", "heartbeatIntervalMs": 30000}});
+
+        assert_eq!(
+            diff_settings(&old, &new),
+            vec![SettingsChange {
+                path: "server.defaultModel".into(),
+                old_value: json!("a"),
+                new_value: json!("b"),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_op_update_reports_no_changes() {
+        let settings = serde_json::to_value(crate::domains::settings::TronSettings::default())
+            .expect("settings serialize");
+        assert!(diff_settings(&settings, &settings.clone()).is_empty());
+    }
+
+    #[test]
+    fn nested_changes_use_dotted_paths_in_sorted_order() {
+        let old = json!({"context": {"compactor": {"maxTokens": 25000, "preserveRecentCount": 5}}});
+        let new = json!({
+            "context": {"compactor": {"maxTokens": 50000, "preserveRecentCount": 5}},
+            "agent": {"maxTurns": 10}
+        });
+
+        let paths: Vec<_> = diff_settings(&old, &new)
+            .into_iter()
+            .map(|change| (change.path, change.old_value, change.new_value))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("agent".into(), Value::Null, json!({"maxTurns": 10})),
+                (
+                    "context.compactor.maxTokens".into(),
+                    json!(25000),
+                    json!(50000)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrays_compare_as_whole_values() {
+        let old = json!({"storage": {"redactedPayloadKeys": ["token"]}});
+        let new = json!({"storage": {"redactedPayloadKeys": ["token", "secret"]}});
+
+        let changes = diff_settings(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "storage.redactedPayloadKeys");
+    }
+}
@@ -19,6 +19,16 @@ pub enum SettingsError {
     /// A settings value was invalid (e.g., out of range).
     #[error("invalid settings value: {0}")]
     InvalidValue(String),
+    /// An environment override could not be coerced to its setting's type.
+    #[error("invalid environment override {var}={value:?}: expected {expected_type}")]
+    EnvOverride {
+        /// Environment variable name.
+        var: String,
+        /// Description of the accepted type and range.
+        expected_type: String,
+        /// Raw value that failed to parse.
+        value: String,
+    },
 }
 
 /// Result type for settings operations.
@@ -64,6 +74,19 @@ mod tests {
         assert_eq!(err.to_string(), "invalid settings value: port out of range");
     }
 
+    #[test]
+    fn env_override_display_names_var_and_expected_type() {
+        let err = SettingsError::EnvOverride {
+            var: "TRON_HEARTBEAT_INTERVAL".into(),
+            expected_type: "integer".into(),
+            value: "soon".into(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid environment override TRON_HEARTBEAT_INTERVAL=\"soon\": expected integer"
+        );
+    }
+
     #[test]
     fn io_error_from_conversion() {
         let io_err = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
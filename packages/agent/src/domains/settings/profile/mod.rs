@@ -19,7 +19,9 @@
 //!
 //! The global singleton is reloadable: when `settings.update` writes new
 //! values to disk, [`reload_settings_from_path`] swaps the cached value
-//! so all subsequent [`get_settings`] calls return fresh data.
+//! so all subsequent [`get_settings`] calls return fresh data. Components
+//! holding derived config (log level, compaction thresholds) call
+//! [`subscribe_settings_changes`] to be notified of every init and reload.
 //!
 //! # Usage
 //!
@@ -39,6 +41,7 @@
 //! | [`types`] | Server settings schema mirrored by iOS |
 //! | `operations` | Canonical settings capability operations |
 //! | [`db_path_policy`] | Database path guardrails |
+//! | [`diff`] | Structural diff of effective settings returned by `settings.update` |
 //! | [`errors`] | Settings error hierarchy |
 //!
 //! ## Entry Points
@@ -46,6 +49,7 @@
 //! - [`get_settings`] returns the lock-free global settings snapshot.
 //! - [`reload_settings_from_path`] reloads the runtime cache after sparse
 //!   profile writes.
+//! - [`subscribe_settings_changes`] observes every init/reload snapshot.
 //! - [`SettingsStore`] owns strict settings persistence.
 //!
 //! ## Dependency Direction
@@ -66,6 +70,8 @@
 //!   at the settings boundary before callers receive them.
 //! - The global settings cache swaps atomically so readers hold consistent
 //!   snapshots.
+//! - Change notifications are published after the swap, so a subscriber that
+//!   wakes up and calls [`get_settings`] never sees the previous value.
 //!
 //! ## Test Ownership
 //!
@@ -75,12 +81,14 @@
 #![deny(unsafe_code)]
 
 pub mod db_path_policy;
+pub mod diff;
 pub mod errors;
 pub(crate) mod operations;
 pub mod storage;
 pub mod store;
 pub mod types;
 
+pub use diff::{SettingsChange, diff_settings};
 pub use errors::{Result, SettingsError};
 #[cfg(test)]
 use storage::loader::deep_merge;
@@ -91,6 +99,7 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 
 use arc_swap::ArcSwapOption;
+use tokio::sync::watch;
 
 /// Global settings singleton (M31).
 ///
@@ -110,6 +119,32 @@ fn settings_slot() -> &'static ArcSwapOption<TronSettings> {
     SETTINGS.get_or_init(ArcSwapOption::empty)
 }
 
+/// Change-notification channel for the global settings singleton.
+///
+/// Carries the most recently installed snapshot (`None` until the first
+/// init/reload). `watch` keeps only the latest value, so slow subscribers
+/// skip intermediate reloads instead of buffering them.
+static SETTINGS_CHANGES: OnceLock<watch::Sender<Option<Arc<TronSettings>>>> = OnceLock::new();
+
+fn settings_changes() -> &'static watch::Sender<Option<Arc<TronSettings>>> {
+    SETTINGS_CHANGES.get_or_init(|| watch::channel(None).0)
+}
+
+/// Install a snapshot in the global cache and notify subscribers.
+fn install_settings(settings: Arc<TronSettings>) {
+    settings_slot().store(Some(Arc::clone(&settings)));
+    let _ = settings_changes().send_replace(Some(settings));
+}
+
+/// Subscribe to settings changes.
+///
+/// The receiver is marked changed on every [`init_settings`] and
+/// [`reload_settings_from_path`]; the carried value is the snapshot that was
+/// installed. Use `changed().await` to react, then re-read derived config.
+pub fn subscribe_settings_changes() -> watch::Receiver<Option<Arc<TronSettings>>> {
+    settings_changes().subscribe()
+}
+
 /// Get the global settings instance.
 ///
 /// On first call, loads settings from `~/.tron/profiles/user/profile.toml` with env var
@@ -144,7 +179,7 @@ pub fn get_settings() -> Arc<TronSettings> {
 /// Replaces any previously cached settings. Useful for tests and
 /// server startup where the settings path is known.
 pub fn init_settings(settings: TronSettings) {
-    settings_slot().store(Some(Arc::new(settings)));
+    install_settings(Arc::new(settings));
 }
 
 /// Resolve the active sparse user profile settings path.
@@ -168,20 +203,22 @@ pub fn load_settings_from_path(path: &Path) -> Result<TronSettings> {
 }
 
 /// Apply environment overrides to a settings snapshot.
-pub(crate) fn apply_env_overrides(settings: &mut TronSettings) {
-    storage::loader::apply_env_overrides(settings);
+///
+/// Returns overrides that were skipped because they failed to coerce.
+pub(crate) fn apply_env_overrides(settings: &mut TronSettings) -> Vec<SettingsError> {
+    storage::env_overrides::apply_env_overrides(settings)
 }
 
 /// Reload settings from a specific file path.
 ///
 /// Reads the file, deep-merges over defaults, applies env overrides,
 /// and atomically swaps the global cache. All subsequent [`get_settings`]
-/// calls return the new values.
+/// calls return the new values, and [`subscribe_settings_changes`]
+/// receivers are notified.
 ///
 /// Called by settings canonical capability functions after writing sparse profile settings.
 pub fn reload_settings_from_path(path: &Path) -> Result<()> {
-    let new = Arc::new(load_settings_from_path(path)?);
-    settings_slot().store(Some(new));
+    install_settings(Arc::new(load_settings_from_path(path)?));
     tracing::debug!(?path, "settings reloaded from disk");
     Ok(())
 }
@@ -352,6 +389,33 @@ maxTokens = 50000
         reset_settings();
     }
 
+    #[test]
+    fn reload_settings_notifies_change_subscribers() {
+        let _lock = lock_settings();
+        reset_settings();
+        init_settings(TronSettings::default());
+
+        let mut changes = subscribe_settings_changes();
+        assert!(!changes.has_changed().unwrap());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = temp_settings_path(&dir);
+        write_sparse_settings(
+            &path,
+            r#"[settings.context.compactor]
+maxTokens = 42000
+"#,
+        );
+        reload_settings_from_path(&path).unwrap();
+
+        assert!(changes.has_changed().unwrap());
+        let observed = changes.borrow_and_update().clone().unwrap();
+        assert_eq!(observed.context.compactor.max_tokens, 42_000);
+        assert!(Arc::ptr_eq(&observed, &get_settings()));
+
+        reset_settings();
+    }
+
     #[test]
     fn reload_from_missing_sparse_path_uses_managed_defaults() {
         let _lock = lock_settings();
@@ -22,6 +22,7 @@ pub(crate) async fn settings_update_value(
 
     let _operation_guard = crate::domains::settings::SettingsStore::operation_lock().await;
     let previous_sparse = read_sparse_settings_snapshot(deps).await?;
+    let before = effective_settings_value(deps)?;
     run_blocking_task("settings::update", move || {
         crate::domains::settings::SettingsStore::new(settings_path)
             .update(updates)
@@ -29,8 +30,10 @@ pub(crate) async fn settings_update_value(
     })
     .await?;
     reload_profile_runtime_or_rollback(deps, previous_sparse.clone(), "settings::update").await?;
+    let changes =
+        crate::domains::settings::diff_settings(&before, &effective_settings_value(deps)?);
 
-    Ok(json!({ "success": true }))
+    Ok(json!({ "success": true, "changes": changes }))
 }
 
 pub(crate) async fn settings_reset_to_defaults_value(
@@ -55,6 +58,15 @@ pub(crate) async fn settings_reset_to_defaults_value(
     Ok(result)
 }
 
+fn effective_settings_value(deps: &Deps) -> std::result::Result<Value, CapabilityError> {
+    serde_json::to_value(&deps.profile_runtime.current().settings).map_err(|error| {
+        settings_error(crate::domains::settings::SettingsError::json(
+            "encode effective settings",
+            error,
+        ))
+    })
+}
+
 async fn read_sparse_settings_snapshot(deps: &Deps) -> std::result::Result<Value, CapabilityError> {
     let path = deps.settings_path.clone();
     run_blocking_task("settings.readSparseSnapshot", move || {
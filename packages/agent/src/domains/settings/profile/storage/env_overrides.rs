@@ -0,0 +1,234 @@
+//! Environment variable overrides applied after sparse profile merging.
+//!
+//! Each supported variable is coerced strictly; a value that fails to parse
+//! or falls outside its range is skipped and reported as
+//! [`SettingsError::EnvOverride`] instead of failing the whole load.
+
+use crate::domains::settings::errors::{Result, SettingsError};
+use crate::domains::settings::types::TronSettings;
+
+/// Apply environment variable overrides to loaded settings.
+///
+/// Each env var has strict parsing rules:
+/// - Integers must be valid and within the specified range
+/// - Booleans accept: `true`/`1`/`yes`/`on` or `false`/`0`/`no`/`off`
+/// - Invalid values are skipped (file/default value kept) and returned as
+///   [`SettingsError::EnvOverride`] so one bad variable never breaks the
+///   whole load; callers decide how to report them
+pub fn apply_env_overrides(settings: &mut TronSettings) -> Vec<SettingsError> {
+    apply_env_overrides_from(settings, |name| std::env::var(name).ok())
+}
+
+/// Apply environment overrides read through `lookup`.
+///
+/// Returns the overrides that were skipped because they failed to coerce.
+pub fn apply_env_overrides_from(
+    settings: &mut TronSettings,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Vec<SettingsError> {
+    let read = |name: &str| lookup(name).filter(|v| !v.is_empty());
+    let mut skipped = Vec::new();
+
+    // ── Server settings ─────────────────────────────────────────────
+    if let Some(v) = read("TRON_DEFAULT_MODEL") {
+        settings.server.default_model = v;
+    }
+    if let Some(v) = read("TRON_DEFAULT_PROVIDER") {
+        settings.server.default_provider = v;
+    }
+    if let Some(raw) = read("TRON_HEARTBEAT_INTERVAL") {
+        match coerce_env_u64("TRON_HEARTBEAT_INTERVAL", &raw, 1000, 600_000) {
+            Ok(v) => settings.server.heartbeat_interval_ms = v,
+            Err(error) => skipped.push(error),
+        }
+    }
+    // ── API settings ────────────────────────────────────────────────
+    if let Some(v) = read("ANTHROPIC_CLIENT_ID") {
+        settings.api.anthropic.client_id = v;
+    }
+    skipped
+}
+
+// ── Env override coercion ───────────────────────────────────────────────────
+
+fn env_override_error(var: &str, expected_type: impl Into<String>, value: &str) -> SettingsError {
+    SettingsError::EnvOverride {
+        var: var.to_owned(),
+        expected_type: expected_type.into(),
+        value: value.to_owned(),
+    }
+}
+
+/// Coerce an env override to a `u64` within `min..=max`.
+pub fn coerce_env_u64(var: &str, value: &str, min: u64, max: u64) -> Result<u64> {
+    parse_u64_range(value.trim(), min, max)
+        .ok_or_else(|| env_override_error(var, format!("integer in {min}..={max}"), value))
+}
+
+// ── Pure parsing functions (testable without env vars) ──────────────────────
+
+/// Parse a string as a boolean.
+///
+/// Accepts (case-insensitive): `true`/`1`/`yes`/`on` or `false`/`0`/`no`/`off`.
+pub fn parse_bool(val: &str) -> Option<bool> {
+    if val.eq_ignore_ascii_case("true")
+        || val == "1"
+        || val.eq_ignore_ascii_case("yes")
+        || val.eq_ignore_ascii_case("on")
+    {
+        Some(true)
+    } else if val.eq_ignore_ascii_case("false")
+        || val == "0"
+        || val.eq_ignore_ascii_case("no")
+        || val.eq_ignore_ascii_case("off")
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parse a string as a `u16` within a range.
+pub fn parse_u16_range(val: &str, min: u16, max: u16) -> Option<u16> {
+    let n: u16 = val.parse().ok()?;
+    (n >= min && n <= max).then_some(n)
+}
+
+/// Parse a string as a `u64` within a range.
+pub fn parse_u64_range(val: &str, min: u64, max: u64) -> Option<u64> {
+    let n: u64 = val.parse().ok()?;
+    (n >= min && n <= max).then_some(n)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── env overrides ───────────────────────────────────────────────
+
+    fn env(pairs: &[(&'static str, &'static str)]) -> impl Fn(&str) -> Option<String> {
+        let pairs = pairs.to_vec();
+        move |name| {
+            pairs
+                .iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| (*v).to_owned())
+        }
+    }
+
+    #[test]
+    fn env_overrides_apply_valid_values() {
+        let mut settings = TronSettings::default();
+        let skipped = apply_env_overrides_from(
+            &mut settings,
+            env(&[
+                ("TRON_DEFAULT_MODEL", "env-model"),
+                ("TRON_HEARTBEAT_INTERVAL", " 45000 "),
+            ]),
+        );
+        assert!(skipped.is_empty());
+        assert_eq!(settings.server.default_model, "env-model");
+        assert_eq!(settings.server.heartbeat_interval_ms, 45_000);
+    }
+
+    #[test]
+    fn invalid_int_env_override_is_skipped_with_rest_intact() {
+        let mut settings = TronSettings::default();
+        let original_heartbeat = settings.server.heartbeat_interval_ms;
+        let vars = [
+            ("TRON_HEARTBEAT_INTERVAL", "soon"),
+            ("TRON_DEFAULT_PROVIDER", "openai"),
+        ];
+        let skipped = apply_env_overrides_from(&mut settings, env(&vars));
+        assert_eq!(settings.server.heartbeat_interval_ms, original_heartbeat);
+        assert_eq!(settings.server.default_provider, "openai");
+        assert_eq!(skipped.len(), 1);
+        assert!(matches!(
+            &skipped[0],
+            SettingsError::EnvOverride { var, expected_type, value }
+                if var == "TRON_HEARTBEAT_INTERVAL"
+                    && expected_type == "integer in 1000..=600000"
+                    && value == "soon"
+        ));
+    }
+
+    #[test]
+    fn env_coercion_trims_and_range_checks_integers() {
+        assert_eq!(coerce_env_u64("COUNT", " 42 ", 0, 100).unwrap(), 42);
+        for raw in ["4.5", "101", "many"] {
+            let err = coerce_env_u64("COUNT", raw, 0, 100).unwrap_err();
+            assert!(matches!(err, SettingsError::EnvOverride { .. }), "{err}");
+        }
+    }
+
+    // ── parse_bool ──────────────────────────────────────────────────
+
+    #[test]
+    fn parse_bool_true_variants() {
+        for val in &["true", "1", "yes", "on", "TRUE", "Yes", "ON"] {
+            assert_eq!(parse_bool(val), Some(true), "failed for {val}");
+        }
+    }
+
+    #[test]
+    fn parse_bool_false_variants() {
+        for val in &["false", "0", "no", "off", "FALSE", "No", "OFF"] {
+            assert_eq!(parse_bool(val), Some(false), "failed for {val}");
+        }
+    }
+
+    #[test]
+    fn parse_bool_invalid() {
+        assert_eq!(parse_bool("maybe"), None);
+        assert_eq!(parse_bool(""), None);
+        assert_eq!(parse_bool("2"), None);
+    }
+
+    // ── parse_u16_range ─────────────────────────────────────────────
+
+    #[test]
+    fn parse_u16_valid() {
+        assert_eq!(parse_u16_range("9090", 1, 65535), Some(9090));
+        assert_eq!(parse_u16_range("1", 1, 65535), Some(1));
+        assert_eq!(parse_u16_range("65535", 1, 65535), Some(65535));
+    }
+
+    #[test]
+    fn parse_u16_out_of_range() {
+        assert_eq!(parse_u16_range("0", 1, 65535), None);
+    }
+
+    #[test]
+    fn parse_u16_invalid() {
+        assert_eq!(parse_u16_range("not_a_number", 1, 65535), None);
+        assert_eq!(parse_u16_range("", 1, 65535), None);
+        assert_eq!(parse_u16_range("99999", 1, 65535), None);
+    }
+
+    // ── parse_u64_range ─────────────────────────────────────────────
+
+    #[test]
+    fn parse_u64_valid() {
+        assert_eq!(parse_u64_range("30000", 1000, 600_000), Some(30_000));
+        assert_eq!(parse_u64_range("1000", 1000, 600_000), Some(1000));
+    }
+
+    #[test]
+    fn parse_u64_below_min() {
+        assert_eq!(parse_u64_range("500", 1000, 600_000), None);
+    }
+
+    #[test]
+    fn parse_u64_above_max() {
+        assert_eq!(parse_u64_range("700000", 1000, 600_000), None);
+    }
+
+    #[test]
+    fn parse_u64_invalid() {
+        assert_eq!(parse_u64_range("abc", 1000, 600_000), None);
+    }
+}
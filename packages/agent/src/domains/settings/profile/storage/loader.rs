@@ -14,6 +14,8 @@ use tracing::debug;
 use crate::domains::settings::errors::{Result, SettingsError};
 use crate::domains::settings::types::TronSettings;
 
+use super::env_overrides::apply_env_overrides;
+
 /// Resolve the `~/.tron` directory.
 pub fn tron_home_dir() -> PathBuf {
     crate::shared::foundation::paths::tron_home()
@@ -70,7 +72,9 @@ pub fn load_settings_from_path(path: &Path) -> Result<TronSettings> {
         SettingsError::InvalidValue(format!("failed to load settings: {error}"))
     })?;
     settings.validate_strict()?;
-    apply_env_overrides(&mut settings);
+    for error in apply_env_overrides(&mut settings) {
+        tracing::warn!(path = %path.display(), %error, "skipping invalid settings env override");
+    }
     settings.validate();
     settings.validate_strict()?;
     Ok(settings)
@@ -164,79 +168,6 @@ pub fn deep_merge(target: Value, source: Value) -> Value {
     }
 }
 
-/// Apply environment variable overrides to loaded settings.
-///
-/// Each env var has strict parsing rules:
-/// - Integers must be valid and within the specified range
-/// - Booleans accept: `true`/`1`/`yes`/`on` or `false`/`0`/`no`/`off`
-/// - Invalid values are silently ignored (use file/default)
-pub fn apply_env_overrides(settings: &mut TronSettings) {
-    // ── Server settings ─────────────────────────────────────────────
-    if let Some(v) = read_env_string("TRON_DEFAULT_MODEL") {
-        settings.server.default_model = v;
-    }
-    if let Some(v) = read_env_string("TRON_DEFAULT_PROVIDER") {
-        settings.server.default_provider = v;
-    }
-    if let Some(v) = read_env_u64("TRON_HEARTBEAT_INTERVAL", 1000, 600_000) {
-        settings.server.heartbeat_interval_ms = v;
-    }
-    // ── API settings ────────────────────────────────────────────────
-    if let Some(v) = read_env_string("ANTHROPIC_CLIENT_ID") {
-        settings.api.anthropic.client_id = v;
-    }
-}
-
-// ── Pure parsing functions (testable without env vars) ──────────────────────
-
-/// Parse a string as a boolean.
-///
-/// Accepts (case-insensitive): `true`/`1`/`yes`/`on` or `false`/`0`/`no`/`off`.
-pub fn parse_bool(val: &str) -> Option<bool> {
-    if val.eq_ignore_ascii_case("true")
-        || val == "1"
-        || val.eq_ignore_ascii_case("yes")
-        || val.eq_ignore_ascii_case("on")
-    {
-        Some(true)
-    } else if val.eq_ignore_ascii_case("false")
-        || val == "0"
-        || val.eq_ignore_ascii_case("no")
-        || val.eq_ignore_ascii_case("off")
-    {
-        Some(false)
-    } else {
-        None
-    }
-}
-
-/// Parse a string as a `u16` within a range.
-pub fn parse_u16_range(val: &str, min: u16, max: u16) -> Option<u16> {
-    let n: u16 = val.parse().ok()?;
-    (n >= min && n <= max).then_some(n)
-}
-
-/// Parse a string as a `u64` within a range.
-pub fn parse_u64_range(val: &str, min: u64, max: u64) -> Option<u64> {
-    let n: u64 = val.parse().ok()?;
-    (n >= min && n <= max).then_some(n)
-}
-
-// ── Env var readers (thin wrappers) ─────────────────────────────────────────
-
-fn read_env_string(name: &str) -> Option<String> {
-    std::env::var(name).ok().filter(|v| !v.is_empty())
-}
-
-fn read_env_u64(name: &str, min: u64, max: u64) -> Option<u64> {
-    let val = std::env::var(name).ok()?;
-    let result = parse_u64_range(&val, min, max);
-    if result.is_none() {
-        tracing::warn!(key = name, value = %val, "invalid u64 env var, ignoring");
-    }
-    result
-}
-
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -669,71 +600,4 @@ jitterFactor = 5.0
         let settings = load_settings_from_path(&path).unwrap();
         assert!((settings.retry.jitter_factor - 1.0).abs() < f64::EPSILON);
     }
-
-    // ── parse_bool ──────────────────────────────────────────────────
-
-    #[test]
-    fn parse_bool_true_variants() {
-        for val in &["true", "1", "yes", "on", "TRUE", "Yes", "ON"] {
-            assert_eq!(parse_bool(val), Some(true), "failed for {val}");
-        }
-    }
-
-    #[test]
-    fn parse_bool_false_variants() {
-        for val in &["false", "0", "no", "off", "FALSE", "No", "OFF"] {
-            assert_eq!(parse_bool(val), Some(false), "failed for {val}");
-        }
-    }
-
-    #[test]
-    fn parse_bool_invalid() {
-        assert_eq!(parse_bool("maybe"), None);
-        assert_eq!(parse_bool(""), None);
-        assert_eq!(parse_bool("2"), None);
-    }
-
-    // ── parse_u16_range ─────────────────────────────────────────────
-
-    #[test]
-    fn parse_u16_valid() {
-        assert_eq!(parse_u16_range("9090", 1, 65535), Some(9090));
-        assert_eq!(parse_u16_range("1", 1, 65535), Some(1));
-        assert_eq!(parse_u16_range("65535", 1, 65535), Some(65535));
-    }
-
-    #[test]
-    fn parse_u16_out_of_range() {
-        assert_eq!(parse_u16_range("0", 1, 65535), None);
-    }
-
-    #[test]
-    fn parse_u16_invalid() {
-        assert_eq!(parse_u16_range("not_a_number", 1, 65535), None);
-        assert_eq!(parse_u16_range("", 1, 65535), None);
-        assert_eq!(parse_u16_range("99999", 1, 65535), None);
-    }
-
-    // ── parse_u64_range ─────────────────────────────────────────────
-
-    #[test]
-    fn parse_u64_valid() {
-        assert_eq!(parse_u64_range("30000", 1000, 600_000), Some(30_000));
-        assert_eq!(parse_u64_range("1000", 1000, 600_000), Some(1000));
-    }
-
-    #[test]
-    fn parse_u64_below_min() {
-        assert_eq!(parse_u64_range("500", 1000, 600_000), None);
-    }
-
-    #[test]
-    fn parse_u64_above_max() {
-        assert_eq!(parse_u64_range("700000", 1000, 600_000), None);
-    }
-
-    #[test]
-    fn parse_u64_invalid() {
-        assert_eq!(parse_u64_range("abc", 1000, 600_000), None);
-    }
 }
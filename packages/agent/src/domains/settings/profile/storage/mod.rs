@@ -3,13 +3,15 @@
 //! The settings domain stores sparse user overrides under
 //! `~/.tron/profiles/user/profile.toml` and loads managed defaults from the
 //! bundled profile tree. [`loader`] owns filesystem paths, default seeding,
-//! sparse overlay decoding, deep merge, environment overrides, and drift checks
-//! that keep bundled managed defaults aligned with compiled Rust defaults.
+//! sparse overlay decoding, deep merge, and drift checks that keep bundled
+//! managed defaults aligned with compiled Rust defaults. [`env_overrides`]
+//! coerces the supported environment variables applied on top.
 //!
 //! ## Submodules
 //!
 //! | Module | Purpose |
 //! |--------|---------|
+//! | [`env_overrides`] | Coerce supported environment variables onto a merged snapshot and report the ones that were skipped |
 //! | [`loader`] | Resolve settings paths, seed defaults, load sparse overlays, merge defaults, apply environment overrides, and guard managed-default drift |
 //!
 //! ## Entry Points
@@ -35,7 +37,9 @@
 //!
 //! ## Test Ownership
 //!
-//! Loader tests live in [`loader`] because path resolution, sparse overlays,
-//! and env override behavior are storage responsibilities.
+//! Loader tests live in [`loader`] because path resolution and sparse overlays
+//! are storage responsibilities. Env override coercion tests live in
+//! [`env_overrides`].
 
+pub mod env_overrides;
 pub mod loader;
@@ -101,6 +101,9 @@ pub struct OpenAiCodexApiSettings {
     pub token_expiry_buffer_seconds: u64,
     /// Default reasoning effort level.
     pub default_reasoning_effort: ReasoningEffort,
+    /// Completed reasoning summary parts shorter than this many chars are
+    /// dropped instead of streamed (0 keeps every part).
+    pub min_reasoning_summary_chars: usize,
 }
 
 impl Default for OpenAiCodexApiSettings {
@@ -118,6 +121,7 @@ impl Default for OpenAiCodexApiSettings {
             base_url: "https://chatgpt.com/backend-api".to_string(),
             token_expiry_buffer_seconds: 300,
             default_reasoning_effort: ReasoningEffort::Medium,
+            min_reasoning_summary_chars: 24,
         }
     }
 }
@@ -211,6 +215,15 @@ mod tests {
         let o = OpenAiCodexApiSettings::default();
         assert_eq!(o.client_id, "app_EMoamEEZ73f0CkXaXp7hrann");
         assert_eq!(o.default_reasoning_effort, ReasoningEffort::Medium);
+        assert_eq!(o.min_reasoning_summary_chars, 24);
+    }
+
+    #[test]
+    fn openai_codex_min_reasoning_summary_chars_from_json() {
+        let o: OpenAiCodexApiSettings =
+            serde_json::from_value(serde_json::json!({"minReasoningSummaryChars": 0})).unwrap();
+        assert_eq!(o.min_reasoning_summary_chars, 0);
+        assert_eq!(o.default_reasoning_effort, ReasoningEffort::Medium);
     }
 
     #[test]
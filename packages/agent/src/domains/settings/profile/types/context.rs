@@ -5,11 +5,46 @@
 use serde::{Deserialize, Serialize};
 
 /// Container for all context management settings.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct ContextSettings {
     /// Context compaction settings.
     pub compactor: CompactorSettings,
+    /// Token ceiling for the composed system context. Lower-priority
+    /// sections are dropped once it is reached.
+    pub system_context_token_ceiling: u64,
+    /// Ids of system context blocks (e.g. `agent.soul`) that lead provider
+    /// composition and are never dropped by the ceiling.
+    pub pinned_context_blocks: Vec<String>,
+    /// Context-window usage ratios (0.0–1.0) that emit a context warning
+    /// when a turn first reaches them.
+    pub pressure_warning_thresholds: Vec<f64>,
+    /// Strategy that estimates tokens before a provider reports usage.
+    pub token_estimator: TokenEstimatorKind,
+}
+
+impl Default for ContextSettings {
+    fn default() -> Self {
+        Self {
+            compactor: CompactorSettings::default(),
+            system_context_token_ceiling: 32_000,
+            pinned_context_blocks: Vec::new(),
+            pressure_warning_thresholds: vec![0.7, 0.9],
+            token_estimator: TokenEstimatorKind::Chars,
+        }
+    }
+}
+
+/// Token estimation strategy for local context budgeting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenEstimatorKind {
+    /// chars/4 for all text (default).
+    #[default]
+    Chars,
+    /// chars/4 for ASCII and one token per non-ASCII character, which tracks
+    /// CJK and other multibyte scripts more closely.
+    ScriptAware,
 }
 
 /// Context compaction settings.
@@ -55,6 +90,32 @@ impl Default for CompactorSettings {
 mod tests {
     use super::*;
 
+    #[test]
+    fn context_defaults_include_system_context_ceiling() {
+        let c = ContextSettings::default();
+        assert_eq!(c.system_context_token_ceiling, 32_000);
+        let json = serde_json::to_value(&c).unwrap();
+        assert_eq!(json["systemContextTokenCeiling"], 32_000);
+        assert_eq!(json["pinnedContextBlocks"], serde_json::json!([]));
+        assert_eq!(json["tokenEstimator"], "chars");
+        assert_eq!(c.pressure_warning_thresholds, vec![0.7, 0.9]);
+        assert_eq!(
+            json["pressureWarningThresholds"],
+            serde_json::json!([0.7, 0.9])
+        );
+    }
+
+    #[test]
+    fn token_estimator_selects_script_aware() {
+        let c: ContextSettings =
+            serde_json::from_value(serde_json::json!({"tokenEstimator": "scriptAware"})).unwrap();
+        assert_eq!(c.token_estimator, TokenEstimatorKind::ScriptAware);
+        assert!(
+            serde_json::from_value::<ContextSettings>(serde_json::json!({"tokenEstimator": "bpe"}))
+                .is_err()
+        );
+    }
+
     #[test]
     fn compactor_defaults() {
         let c = CompactorSettings::default();
@@ -92,7 +92,9 @@ impl Default for TronSettings {
 impl TronSettings {
     /// Validate invariants that cannot be repaired safely.
     pub fn validate_strict(&self) -> crate::domains::settings::Result<()> {
-        self.server.validate_strict()
+        self.server.validate_strict()?;
+        self.agent.validate_strict()?;
+        self.session.validate_strict()
     }
 
     /// Clamp ratio fields to [0.0, 1.0] and correct invalid invariants.
@@ -118,6 +120,9 @@ impl TronSettings {
         let cs = &mut self.context.compactor;
         clamp_ratio(&mut cs.compaction_threshold, "compaction_threshold");
         clamp_option_ratio(&mut cs.trigger_token_threshold, "trigger_token_threshold");
+        for threshold in &mut self.context.pressure_warning_thresholds {
+            clamp_ratio(threshold, "pressure_warning_thresholds");
+        }
 
         clamp_ratio(&mut self.retry.jitter_factor, "jitter_factor");
     }
@@ -135,6 +140,8 @@ pub struct RetrySettings {
     pub max_delay_ms: u64,
     /// Jitter factor (0.0–1.0) applied to retry delays.
     pub jitter_factor: f64,
+    /// Provider circuit breaker shared by every session. Off by default.
+    pub circuit_breaker: CircuitBreakerSettings,
 }
 
 impl Default for RetrySettings {
@@ -148,6 +155,30 @@ impl Default for RetrySettings {
             base_delay_ms: crate::shared::foundation::retry::DEFAULT_BASE_DELAY_MS,
             max_delay_ms: crate::shared::foundation::retry::DEFAULT_MAX_DELAY_MS,
             jitter_factor: crate::shared::foundation::retry::DEFAULT_JITTER_FACTOR,
+            circuit_breaker: CircuitBreakerSettings::default(),
+        }
+    }
+}
+
+/// Provider circuit-breaker configuration.
+///
+/// After `failureThreshold` consecutive retryable provider failures (5xx,
+/// network, overload), requests to that provider fast-fail for `cooldownMs`
+/// before a single probe is let through. Applied at server startup.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct CircuitBreakerSettings {
+    /// Consecutive provider failures that open the circuit. `0` disables it.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before probing again.
+    pub cooldown_ms: u64,
+}
+
+impl Default for CircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 0,
+            cooldown_ms: 30_000,
         }
     }
 }
@@ -277,6 +308,10 @@ mod tests {
         assert!(json.get("baseDelayMs").is_some());
         assert!(json.get("maxDelayMs").is_some());
         assert!(json.get("jitterFactor").is_some());
+        assert_eq!(
+            json["circuitBreaker"],
+            serde_json::json!({ "failureThreshold": 0, "cooldownMs": 30_000 })
+        );
     }
 
     #[test]
@@ -323,6 +358,14 @@ mod tests {
         assert_eq!(s.context.compactor.trigger_token_threshold, Some(1.0));
     }
 
+    #[test]
+    fn validate_clamps_pressure_warning_thresholds() {
+        let mut s = TronSettings::default();
+        s.context.pressure_warning_thresholds = vec![0.5, 1.5];
+        s.validate();
+        assert_eq!(s.context.pressure_warning_thresholds, vec![0.5, 1.0]);
+    }
+
     #[test]
     fn validate_preserves_valid_values() {
         let mut s = TronSettings::default();
@@ -15,6 +15,9 @@ pub struct ServerSettings {
     /// Must be non-zero before it reaches the runtime because
     /// `tokio::time::interval(Duration::ZERO)` panics.
     pub heartbeat_interval_ms: u64,
+    /// Silence budget in milliseconds before an `/engine` client is closed
+    /// with code 4000. Raised to one heartbeat interval when set lower.
+    pub heartbeat_timeout_ms: u64,
     /// Default LLM model identifier.
     pub default_model: String,
     /// Default LLM provider.
@@ -35,6 +38,7 @@ impl Default for ServerSettings {
     fn default() -> Self {
         Self {
             heartbeat_interval_ms: 30_000,
+            heartbeat_timeout_ms: 90_000,
             default_model: "claude-sonnet-4-6".to_string(),
             default_provider: "anthropic".to_string(),
             default_workspace: None,
@@ -87,11 +91,90 @@ impl Default for TranscriptionSettings {
 pub struct AgentRuntimeSettings {
     /// Maximum number of agentic turns per prompt.
     pub max_turns: u32,
+    /// Session spending ceiling in USD. Once a session's accumulated cost
+    /// reaches it, the next turn is refused. `0` disables the ceiling.
+    pub max_cost_usd: f64,
+    /// Per-model pricing that replaces the built-in pricing table entry for
+    /// the same model id (e.g. negotiated rates or a provider price change).
+    pub pricing_overrides: HashMap<String, ModelPricingOverride>,
+    /// Confine `process_run` shell commands to the authority grant's file
+    /// roots. Off by default; grants with `*` roots are never jailed.
+    pub process_file_root_jail: bool,
 }
 
 impl Default for AgentRuntimeSettings {
     fn default() -> Self {
-        Self { max_turns: 250 }
+        Self {
+            max_turns: 250,
+            max_cost_usd: 0.0,
+            pricing_overrides: HashMap::new(),
+            process_file_root_jail: false,
+        }
+    }
+}
+
+/// Pricing for one model, overriding the built-in table.
+///
+/// Prices are USD per million tokens. Cache multipliers scale the input price;
+/// any left out keep the built-in tier's value for the model, or `1.0` (no
+/// cache discount or surcharge) for models without one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModelPricingOverride {
+    /// Cost per million input tokens.
+    pub input_per_million: f64,
+    /// Cost per million output tokens.
+    pub output_per_million: f64,
+    /// Multiplier for 5-minute TTL cache writes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_5m_multiplier: Option<f64>,
+    /// Multiplier for 1-hour TTL cache writes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_1h_multiplier: Option<f64>,
+    /// Multiplier for cache reads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_multiplier: Option<f64>,
+}
+
+impl ModelPricingOverride {
+    fn validate_strict(&self, model: &str) -> crate::domains::settings::Result<()> {
+        let values = [
+            Some(self.input_per_million),
+            Some(self.output_per_million),
+            self.cache_write_5m_multiplier,
+            self.cache_write_1h_multiplier,
+            self.cache_read_multiplier,
+        ];
+        if values
+            .iter()
+            .flatten()
+            .any(|value| !value.is_finite() || *value < 0.0)
+        {
+            return Err(crate::domains::settings::SettingsError::InvalidValue(
+                format!("agent.pricingOverrides.{model} prices must be non-negative numbers"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl AgentRuntimeSettings {
+    /// Validate invariants that cannot be safely corrected at runtime.
+    pub fn validate_strict(&self) -> crate::domains::settings::Result<()> {
+        if !self.max_cost_usd.is_finite() || self.max_cost_usd < 0.0 {
+            return Err(crate::domains::settings::SettingsError::InvalidValue(
+                "agent.maxCostUsd must be a non-negative number of USD".to_owned(),
+            ));
+        }
+        for (model, pricing) in &self.pricing_overrides {
+            pricing.validate_strict(model)?;
+        }
+        Ok(())
+    }
+
+    /// The configured session cost ceiling, or `None` when disabled.
+    pub fn cost_ceiling_usd(&self) -> Option<f64> {
+        (self.max_cost_usd > 0.0).then_some(self.max_cost_usd)
     }
 }
 
@@ -134,12 +217,16 @@ pub struct LoggingSettings {
     /// Per-module log level overrides. Keys are Rust module/crate names.
     /// Example: `{"ort": "warn"}` suppresses ONNX Runtime info spam.
     pub module_overrides: HashMap<String, LogLevel>,
+    /// Log redacted provider requests, responses, and stream frames at
+    /// `debug` under the `tron::provider_wire` target. Off by default.
+    pub provider_wire_debug: bool,
 }
 
 impl Default for LoggingSettings {
     fn default() -> Self {
         Self {
             module_overrides: HashMap::from([("ort".to_string(), LogLevel::Error)]),
+            provider_wire_debug: false,
         }
     }
 }
@@ -171,6 +258,11 @@ pub struct StorageSettings {
     pub retention_enabled: bool,
     /// Soft cap used by retention reports and future background compaction.
     pub max_database_mb: u64,
+    /// Payload keys whose values are replaced with `[REDACTED]` before events
+    /// are persisted, matched whole (case and `_`/`-` insensitive). Empty (the
+    /// default) disables key redaction; value-shape secret redaction always
+    /// applies.
+    pub redacted_payload_keys: Vec<String>,
 }
 
 impl Default for StorageSettings {
@@ -178,6 +270,7 @@ impl Default for StorageSettings {
         Self {
             retention_enabled: true,
             max_database_mb: 512,
+            redacted_payload_keys: Vec::new(),
         }
     }
 }
@@ -204,11 +297,40 @@ impl Default for TmuxSettings {
 /// Session behavior settings.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
-pub struct SessionSettings {}
+pub struct SessionSettings {
+    /// Seconds without activity before an idle session is stopped and its
+    /// slot freed. Sessions with an active run are never stopped.
+    pub idle_timeout_secs: u64,
+}
 
 impl Default for SessionSettings {
     fn default() -> Self {
-        Self {}
+        Self {
+            idle_timeout_secs: 3_600,
+        }
+    }
+}
+
+impl SessionSettings {
+    /// Minimum allowed idle timeout in seconds.
+    pub const MIN_IDLE_TIMEOUT_SECS: u64 = 60;
+    /// Maximum allowed idle timeout in seconds (one week).
+    pub const MAX_IDLE_TIMEOUT_SECS: u64 = 604_800;
+
+    /// Validate invariants that cannot be safely corrected at runtime.
+    pub fn validate_strict(&self) -> crate::domains::settings::Result<()> {
+        if !(Self::MIN_IDLE_TIMEOUT_SECS..=Self::MAX_IDLE_TIMEOUT_SECS)
+            .contains(&self.idle_timeout_secs)
+        {
+            return Err(crate::domains::settings::SettingsError::InvalidValue(
+                format!(
+                    "session.idleTimeoutSecs must be between {} and {} seconds",
+                    Self::MIN_IDLE_TIMEOUT_SECS,
+                    Self::MAX_IDLE_TIMEOUT_SECS
+                ),
+            ));
+        }
+        Ok(())
     }
 }
 
@@ -224,6 +346,7 @@ mod tests {
     fn server_defaults() {
         let s = ServerSettings::default();
         assert_eq!(s.heartbeat_interval_ms, 30_000);
+        assert_eq!(s.heartbeat_timeout_ms, 90_000);
         assert_eq!(s.default_provider, "anthropic");
         assert_eq!(s.default_model, "claude-sonnet-4-6");
         assert!(s.default_workspace.is_none());
@@ -256,6 +379,7 @@ mod tests {
         let s = ServerSettings::default();
         let json = serde_json::to_value(&s).unwrap();
         assert!(json.get("heartbeatIntervalMs").is_some());
+        assert!(json.get("heartbeatTimeoutMs").is_some());
         assert!(json.get("defaultModel").is_some());
         assert!(json.get("transcription").is_some());
     }
@@ -326,6 +450,52 @@ mod tests {
     fn agent_defaults() {
         let a = AgentRuntimeSettings::default();
         assert_eq!(a.max_turns, 250);
+        assert_eq!(a.max_cost_usd, 0.0);
+        assert_eq!(a.cost_ceiling_usd(), None);
+        assert!(!a.process_file_root_jail);
+    }
+
+    #[test]
+    fn agent_cost_ceiling_validation() {
+        let a = AgentRuntimeSettings {
+            max_cost_usd: 5.0,
+            ..AgentRuntimeSettings::default()
+        };
+        assert!(a.validate_strict().is_ok());
+        assert_eq!(a.cost_ceiling_usd(), Some(5.0));
+
+        for max_cost_usd in [-1.0, f64::NAN, f64::INFINITY] {
+            let a = AgentRuntimeSettings {
+                max_cost_usd,
+                ..AgentRuntimeSettings::default()
+            };
+            let err = a.validate_strict().unwrap_err();
+            assert!(err.to_string().contains("agent.maxCostUsd"));
+        }
+    }
+
+    #[test]
+    fn agent_pricing_override_parses_and_rejects_negative_prices() {
+        let a: AgentRuntimeSettings = serde_json::from_value(serde_json::json!({
+            "pricingOverrides": {
+                "gpt-4.1": {"inputPerMillion": 1.5, "outputPerMillion": 6.0, "cacheReadMultiplier": 0.25}
+            }
+        }))
+        .unwrap();
+        let pricing = &a.pricing_overrides["gpt-4.1"];
+        assert_eq!(pricing.input_per_million, 1.5);
+        assert_eq!(pricing.cache_write_5m_multiplier, None);
+        assert_eq!(pricing.cache_read_multiplier, Some(0.25));
+        assert!(a.validate_strict().is_ok());
+
+        let mut negative = a.clone();
+        negative
+            .pricing_overrides
+            .get_mut("gpt-4.1")
+            .unwrap()
+            .output_per_million = -1.0;
+        let err = negative.validate_strict().unwrap_err();
+        assert!(err.to_string().contains("agent.pricingOverrides.gpt-4.1"));
     }
 
     #[test]
@@ -336,6 +506,7 @@ mod tests {
 
         let roundtrip = serde_json::to_value(&a).unwrap();
         assert_eq!(roundtrip["maxTurns"], 250);
+        assert_eq!(roundtrip["maxCostUsd"], 0.0);
     }
 
     #[test]
@@ -358,13 +529,26 @@ mod tests {
     #[test]
     fn session_defaults() {
         let s = SessionSettings::default();
-        assert_eq!(serde_json::to_value(s).unwrap(), serde_json::json!({}));
+        assert_eq!(
+            serde_json::to_value(s).unwrap(),
+            serde_json::json!({"idleTimeoutSecs": 3600})
+        );
     }
 
     #[test]
     fn empty_session_json_uses_defaults() {
         let s: SessionSettings = serde_json::from_str("{}").unwrap();
-        assert_eq!(serde_json::to_value(s).unwrap(), serde_json::json!({}));
+        assert_eq!(s.idle_timeout_secs, 3_600);
+        assert!(s.validate_strict().is_ok());
+    }
+
+    #[test]
+    fn session_idle_timeout_out_of_range_rejected() {
+        for idle_timeout_secs in [0, 59, 604_801] {
+            let s = SessionSettings { idle_timeout_secs };
+            let err = s.validate_strict().unwrap_err();
+            assert!(err.to_string().contains("session.idleTimeoutSecs"));
+        }
     }
 
     #[test]
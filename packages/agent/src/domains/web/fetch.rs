@@ -38,6 +38,16 @@ const MAX_OUTPUT_BYTES: usize = 100_000;
 const DEFAULT_REDIRECTS: usize = 5;
 const MAX_REDIRECTS: usize = 10;
 pub(super) const MAX_TITLE_BYTES: usize = 512;
+const MAX_ALLOWED_CONTENT_TYPES: usize = 32;
+/// Media types fetched when the request names no `allowedContentTypes`.
+const DEFAULT_ALLOWED_CONTENT_TYPES: &[&str] = &[
+    "text/html",
+    "application/xhtml+xml",
+    "text/plain",
+    "text/markdown",
+    "text/x-markdown",
+    "application/json",
+];
 
 pub(crate) async fn web_fetch_value(
     deps: &Deps,
@@ -98,6 +108,7 @@ pub(crate) async fn web_fetch_value(
         .get(reqwest::header::CONTENT_TYPE)
         .and_then(|value| value.to_str().ok())
         .map(ToOwned::to_owned);
+    ensure_allowed_content_type(content_type.as_deref(), &request.allowed_content_types)?;
     let body = read_bounded_response(response, request.max_response_bytes).await?;
     let response_truncated = body.truncated;
     let captured_sha256 = sha256_hex(&body.bytes);
@@ -210,6 +221,7 @@ struct FetchRequest {
     max_response_bytes: usize,
     max_output_bytes: usize,
     max_redirects: usize,
+    allowed_content_types: Vec<String>,
     idempotency_key: String,
     robots_policy: Option<RobotsPolicyEvidenceRequest>,
 }
@@ -239,6 +251,7 @@ impl FetchRequest {
                 .map(|value| value as usize)
                 .unwrap_or(DEFAULT_REDIRECTS)
                 .clamp(0, MAX_REDIRECTS),
+            allowed_content_types: allowed_content_types(payload)?,
             idempotency_key: optional_string(payload, "idempotencyKey")?
                 .unwrap_or_else(|| "<context>".to_owned()),
             robots_policy: RobotsPolicyEvidenceRequest::parse(payload)?,
@@ -246,6 +259,63 @@ impl FetchRequest {
     }
 }
 
+fn allowed_content_types(payload: &Value) -> Result<Vec<String>, CapabilityError> {
+    let Some(value) = payload.get("allowedContentTypes").filter(|v| !v.is_null()) else {
+        return Ok(DEFAULT_ALLOWED_CONTENT_TYPES
+            .iter()
+            .map(|media_type| (*media_type).to_owned())
+            .collect());
+    };
+    let items = value
+        .as_array()
+        .filter(|items| !items.is_empty() && items.len() <= MAX_ALLOWED_CONTENT_TYPES)
+        .ok_or_else(|| {
+            invalid(format!(
+                "allowedContentTypes must be a non-empty array of at most {MAX_ALLOWED_CONTENT_TYPES} media types"
+            ))
+        })?;
+    items
+        .iter()
+        .map(|item| {
+            item.as_str()
+                .map(|media_type| media_type.trim().to_ascii_lowercase())
+                .filter(|media_type| media_type.contains('/'))
+                .ok_or_else(|| invalid("allowedContentTypes entries must be type/subtype strings"))
+        })
+        .collect()
+}
+
+/// Reject a declared media type outside the allowlist before any body bytes
+/// are read. A missing header is let through and extracted as text.
+fn ensure_allowed_content_type(
+    content_type: Option<&str>,
+    allowed: &[String],
+) -> Result<(), CapabilityError> {
+    let Some(content_type) = content_type else {
+        return Ok(());
+    };
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let permitted = allowed.iter().any(|entry| match entry.strip_suffix("/*") {
+        Some(top_level) => media_type
+            .split_once('/')
+            .is_some_and(|(kind, _)| kind == top_level),
+        None => *entry == media_type,
+    });
+    if permitted {
+        Ok(())
+    } else {
+        Err(invalid(format!(
+            "web_fetch content type {media_type} is not allowed; allowed: {}",
+            allowed.join(", ")
+        )))
+    }
+}
+
 struct BoundedBody {
     bytes: Vec<u8>,
     truncated: bool,
@@ -15,6 +15,10 @@
 //! lifecycle metadata, robots-policy evidence, and `web.lifecycle` events.
 //! URL authority checks must cover initial URLs, every redirect target before
 //! it is followed, and DNS-resolved socket addresses before network I/O.
+//! Fetches also check the response media type against an allowlist (HTML,
+//! plain text, markdown, and JSON unless the request names
+//! `allowedContentTypes`) before reading any body bytes, and stream the body
+//! under the `maxResponseBytes` cap.
 //!
 //! ## Submodules
 //!
@@ -242,6 +242,7 @@ async fn web_fetch_preserves_non_html_content_type_behavior_and_bounds() {
                 "operation": "web_fetch",
                 "url": format!("{}{}", server.uri(), path),
                 "maxOutputBytes": max_output,
+                "allowedContentTypes": ["text/*", "application/*"],
                 "idempotencyKey": format!("web-fetch-content-types-{key}")
             }))
             .await;
@@ -278,6 +279,71 @@ async fn web_fetch_preserves_non_html_content_type_behavior_and_bounds() {
     }
 }
 
+#[tokio::test]
+async fn web_fetch_rejects_disallowed_content_types_and_caps_large_bodies() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/archive.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/zip")
+                .set_body_bytes(vec![0x50, 0x4b, 0x03, 0x04]),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/huge.md"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "text/markdown; charset=utf-8")
+                .set_body_string("# big\n".repeat(4_096)),
+        )
+        .mount(&server)
+        .await;
+
+    let ctx = make_test_context();
+    let fixture = WebFixture::new(&ctx, "web-fetch-allowlist", "declared").await;
+    let rejected = fixture
+        .invoke_error(json!({
+            "operation": "web_fetch",
+            "url": format!("{}/archive.zip", server.uri()),
+            "idempotencyKey": "web-fetch-allowlist-zip"
+        }))
+        .await;
+    assert!(
+        rejected.contains("content type application/zip is not allowed"),
+        "{rejected}"
+    );
+
+    let value = fixture
+        .invoke_ok(json!({
+            "operation": "web_fetch",
+            "url": format!("{}/huge.md", server.uri()),
+            "maxResponseBytes": 64,
+            "idempotencyKey": "web-fetch-allowlist-huge"
+        }))
+        .await;
+    let resource_id = value["details"]["web"]["webSourceResourceId"]
+        .as_str()
+        .expect("resource id");
+    let inspection = ctx
+        .engine_host
+        .inspect_resource(resource_id)
+        .await
+        .expect("inspect")
+        .expect("web source resource");
+    let payload = current_payload(&inspection);
+    assert_eq!(payload["byteEvidence"]["capturedBytes"], json!(64));
+    assert_eq!(
+        payload["byteEvidence"]["responseBytesTruncated"],
+        json!(true)
+    );
+    assert_eq!(
+        payload["textEvidence"]["extractionMode"],
+        json!("plain_text")
+    );
+}
+
 fn assert_safe_title_json(value: &str, secret: &str, oversized_tail: &str) {
     assert!(
         !value.contains(secret),
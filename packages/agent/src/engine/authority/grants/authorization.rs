@@ -1582,23 +1582,32 @@ fn ensure_file_roots(grant: &EngineGrant, invocation: &Invocation) -> Result<()>
         return Ok(());
     }
     for path in paths_from_invocation(invocation)? {
-        let canonical = canonical_payload_path(&path)?;
-        if !grant
-            .file_roots
-            .iter()
-            .filter(|root| root.as_str() != "*")
-            .any(|root| root_allows_path(root, &canonical).unwrap_or(false))
-        {
+        if !file_roots_allow_path(&grant.file_roots, &path)? {
             return Err(EngineError::PolicyViolation(format!(
                 "authority grant {} does not allow file path {}",
                 grant.grant_id,
-                canonical.display()
+                canonical_payload_path(&path)?.display()
             )));
         }
     }
     Ok(())
 }
 
+/// Whether `path` lies inside one of a grant's `file_roots`.
+///
+/// A `*` root allows every path. Otherwise `path` is canonicalized (missing
+/// tails are normalized under their nearest existing ancestor) and compared
+/// against each canonical root.
+pub(crate) fn file_roots_allow_path(file_roots: &[String], path: &Path) -> Result<bool> {
+    if allows_item(file_roots, "*") {
+        return Ok(true);
+    }
+    let canonical = canonical_payload_path(path)?;
+    Ok(file_roots
+        .iter()
+        .any(|root| root_allows_path(root, &canonical).unwrap_or(false)))
+}
+
 fn paths_from_invocation(invocation: &Invocation) -> Result<Vec<PathBuf>> {
     let mut paths = [
         "path",
@@ -47,6 +47,7 @@ mod paths;
 mod policy_hash;
 mod sqlite_codec;
 
+pub(crate) use authorization::file_roots_allow_path;
 pub use model::{
     BOOTSTRAP_GRANT_IDS, ConsumeGrantInvocationBudget, DeriveGrant, EngineGrant, EngineGrantEvent,
     EngineGrantLifecycle, ListGrants,
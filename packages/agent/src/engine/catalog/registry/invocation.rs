@@ -1,6 +1,7 @@
 //! Sync invocation preparation, completion, and idempotency.
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use serde_json::Value;
 
@@ -18,6 +19,9 @@ use crate::engine::kernel::ids::WorkerId;
 use crate::engine::kernel::types::{FunctionDefinition, FunctionRevision, IdempotencyScope};
 use crate::engine::kernel::{policy, schema};
 
+/// Handler calls slower than this are logged by [`PreparedSyncInvocation::run_handler`].
+const SLOW_HANDLER_WARN_AFTER: Duration = Duration::from_secs(30);
+
 /// A sync invocation that passed routing, policy, schema, and idempotency
 /// reservation checks and is ready to execute outside the catalog lock.
 pub(in crate::engine) struct PreparedSyncInvocation {
@@ -31,6 +35,40 @@ pub(in crate::engine) struct PreparedSyncInvocation {
     pub idempotency: Option<IdempotencyReservation>,
 }
 
+impl PreparedSyncInvocation {
+    /// Run the captured handler, bounded by the function's declared timeout.
+    ///
+    /// Calls that outlive [`policy::handler_timeout`] are dropped and fail with
+    /// [`EngineError::HandlerTimedOut`]; calls slower than
+    /// [`SLOW_HANDLER_WARN_AFTER`] are logged with their duration either way.
+    pub(in crate::engine) async fn run_handler(&self) -> Result<Value> {
+        // The duration only feeds the slow-handler log line; it never reaches
+        // the invocation record, so replay stays independent of it.
+        let started = Instant::now();
+        let call = self.handler.invoke(self.invocation.clone());
+        let result = match policy::handler_timeout(&self.function) {
+            Some(limit) => tokio::time::timeout(limit, call).await.unwrap_or_else(|_| {
+                Err(EngineError::HandlerTimedOut {
+                    function_id: self.function.id.to_string(),
+                    timeout_ms: u64::try_from(limit.as_millis()).unwrap_or(u64::MAX),
+                })
+            }),
+            None => call.await,
+        };
+        let elapsed = started.elapsed();
+        if elapsed >= SLOW_HANDLER_WARN_AFTER {
+            tracing::warn!(
+                function_id = %self.function.id,
+                invocation_id = %self.invocation.id,
+                duration_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+                succeeded = result.is_ok(),
+                "engine function handler was slow"
+            );
+        }
+        result
+    }
+}
+
 /// Prepare result for a sync invocation.
 pub(in crate::engine) enum PreparedSyncInvocationDecision {
     /// The handler should be executed outside the catalog lock.
@@ -46,7 +84,7 @@ impl LiveCatalog {
         match self.prepare_sync_invocation(invocation) {
             PreparedSyncInvocationDecision::Finished(result) => *result,
             PreparedSyncInvocationDecision::Execute(prepared) => {
-                let result = prepared.handler.invoke(prepared.invocation.clone()).await;
+                let result = prepared.run_handler().await;
                 self.finish_prepared_sync_invocation(*prepared, result)
             }
         }
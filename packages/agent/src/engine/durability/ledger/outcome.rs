@@ -175,6 +175,17 @@ impl StoredEngineError {
                 message: error.to_string(),
                 details: serde_json::json!({ "code": code, "message": message }),
             },
+            EngineError::HandlerTimedOut {
+                function_id,
+                timeout_ms,
+            } => Self {
+                kind: "handler_timed_out".to_owned(),
+                message: error.to_string(),
+                details: serde_json::json!({
+                    "functionId": function_id,
+                    "timeoutMs": timeout_ms,
+                }),
+            },
             EngineError::HandlerFailed(message) => Self {
                 kind: "handler_failed".to_owned(),
                 message: error.to_string(),
@@ -252,6 +263,21 @@ impl StoredEngineError {
                 details,
             };
         }
+        if self.kind == "handler_timed_out" {
+            return EngineError::HandlerTimedOut {
+                function_id: self
+                    .details
+                    .get("functionId")
+                    .and_then(Value::as_str)
+                    .unwrap_or("stored")
+                    .to_owned(),
+                timeout_ms: self
+                    .details
+                    .get("timeoutMs")
+                    .and_then(Value::as_u64)
+                    .unwrap_or_default(),
+            };
+        }
         if self.kind == "worker_transport_failure" {
             return EngineError::WorkerTransportFailure {
                 code: self
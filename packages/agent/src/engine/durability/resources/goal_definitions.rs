@@ -110,6 +110,7 @@ pub(super) fn goal_question_resource_type_definitions() -> Vec<RegisterResourceT
                     "options": {"type": "array"},
                     "allowFreeForm": {"type": "boolean"},
                     "expiresAt": {"type": ["string", "null"]},
+                    "defaultChoice": {"type": ["string", "null"]},
                     "createdAt": {"type": "string"},
                     "answeredAt": {"type": ["string", "null"]},
                     "cancelledAt": {"type": ["string", "null"]},
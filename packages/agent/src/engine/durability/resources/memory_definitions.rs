@@ -143,6 +143,8 @@ pub(super) fn memory_resource_type_definitions() -> Vec<RegisterResourceType> {
                     "provenance": {"type": "object"},
                     "confidence": {"type": "object"},
                     "sensitivity": {"type": "string"},
+                    "category": {"type": "string"},
+                    "importance": {"type": "integer", "minimum": 0, "maximum": 10},
                     "retention": {"type": "object"},
                     "expiresAt": {"type": "string"},
                     "sourceRefs": {"type": "array"},
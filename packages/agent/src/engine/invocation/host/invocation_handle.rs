@@ -183,7 +183,7 @@ impl EngineHostHandle {
     }
 
     async fn invoke_prepared_handler(&self, prepared: &PreparedSyncInvocation) -> Result<Value> {
-        AssertUnwindSafe(prepared.handler.invoke(prepared.invocation.clone()))
+        AssertUnwindSafe(prepared.run_handler())
             .catch_unwind()
             .await
             .unwrap_or_else(|payload| {
@@ -166,6 +166,15 @@ pub enum EngineError {
         message: String,
     },
 
+    /// The handler did not finish within the function's declared timeout.
+    #[error("handler for {function_id} timed out after {timeout_ms}ms")]
+    HandlerTimedOut {
+        /// Function id.
+        function_id: String,
+        /// Declared handler timeout.
+        timeout_ms: u64,
+    },
+
     /// The handler returned an application failure.
     #[error("handler failed: {0}")]
     HandlerFailed(String),
@@ -9,6 +9,8 @@
 //! runtime actor kinds; public clients, users, and agent contexts remain denied
 //! even if they carry the raw string.
 
+use std::time::Duration;
+
 use crate::engine::catalog::discovery::{ActorContext, ActorKind};
 use crate::engine::invocation::model::{
     CausalContext, Invocation, RUNTIME_METADATA_TRIGGER_DEPTH, RUNTIME_METADATA_TRIGGER_PATH,
@@ -25,6 +27,12 @@ use super::types::{
 /// apply functions while preserving the original actor in the causal ledger.
 pub const ENGINE_INTERNAL_INVOKE_SCOPE: &str = "engine.internal.invoke";
 
+/// Function metadata pointer bounding how long one handler call may run.
+///
+/// The value is a positive integer millisecond count. Functions without it
+/// run unbounded, as before.
+pub const HANDLER_TIMEOUT_METADATA_POINTER: &str = "/delivery/timeoutMs";
+
 /// Validate a function definition before registration.
 pub fn validate_function_registration(function: &FunctionDefinition) -> Result<()> {
     if function.effect_class.requires_idempotency() && function.idempotency.is_none() {
@@ -84,6 +92,15 @@ pub fn validate_function_registration(function: &FunctionDefinition) -> Result<(
         }
     }
 
+    if let Some(timeout) = function.metadata.pointer(HANDLER_TIMEOUT_METADATA_POINTER)
+        && !timeout.as_u64().is_some_and(|timeout_ms| timeout_ms > 0)
+    {
+        return Err(EngineError::PolicyViolation(format!(
+            "function {} handler timeout must be a positive millisecond count",
+            function.id
+        )));
+    }
+
     if function.allowed_delivery_modes.is_empty() {
         return Err(EngineError::PolicyViolation(format!(
             "function {} must allow at least one delivery mode",
@@ -150,6 +167,17 @@ fn is_void_loss_tolerant_target(function: &FunctionDefinition) -> bool {
         && function.risk_level <= RiskLevel::Low
 }
 
+/// Engine-enforced handler timeout declared in function metadata.
+#[must_use]
+pub fn handler_timeout(function: &FunctionDefinition) -> Option<Duration> {
+    function
+        .metadata
+        .pointer(HANDLER_TIMEOUT_METADATA_POINTER)
+        .and_then(serde_json::Value::as_u64)
+        .filter(|timeout_ms| *timeout_ms > 0)
+        .map(Duration::from_millis)
+}
+
 /// Validate invocation policy.
 pub fn validate_invocation(function: &FunctionDefinition, invocation: &Invocation) -> Result<()> {
     if invocation.delivery_mode != DeliveryMode::Sync {
@@ -1,5 +1,7 @@
 //! Function catalog and policy contracts.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -547,6 +549,24 @@ impl FunctionDefinition {
         self
     }
 
+    /// Bound each handler call; the host fails calls that run longer.
+    #[must_use]
+    pub fn with_handler_timeout(mut self, timeout: Duration) -> Self {
+        let mut metadata = match self.metadata.take() {
+            Value::Object(metadata) => metadata,
+            _ => serde_json::Map::new(),
+        };
+        let delivery = metadata
+            .entry("delivery")
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+        if !delivery.is_object() {
+            *delivery = Value::Object(serde_json::Map::new());
+        }
+        delivery["timeoutMs"] = Value::from(u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX));
+        self.metadata = Value::Object(metadata);
+        self
+    }
+
     /// Set provenance.
     #[must_use]
     pub fn with_provenance(mut self, provenance: Provenance) -> Self {
@@ -137,6 +137,7 @@ pub(crate) mod primitives;
 pub(crate) mod runtime;
 
 pub use authority::compensation::{EngineCompensationRecord, EngineCompensationStatus};
+pub(crate) use authority::grants::file_roots_allow_path;
 pub use authority::grants::{
     ConsumeGrantInvocationBudget, DeriveGrant, EngineGrant, EngineGrantEvent, EngineGrantLifecycle,
     ListGrants,
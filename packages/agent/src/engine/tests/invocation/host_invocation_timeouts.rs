@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use super::*;
+
+struct StuckHandler;
+
+#[async_trait]
+impl InProcessFunctionHandler for StuckHandler {
+    async fn invoke(&self, _invocation: Invocation) -> Result<Value> {
+        std::future::pending().await
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn host_fails_handlers_that_outlive_their_declared_timeout() {
+    let handle = super::host::EngineHostHandle::new_in_memory().unwrap();
+    handle
+        .register_worker(worker("w1", "alpha"), true)
+        .await
+        .unwrap();
+    handle
+        .register_function(
+            read_function("alpha::stuck", "w1").with_handler_timeout(Duration::from_millis(250)),
+            Some(Arc::new(StuckHandler)),
+            true,
+        )
+        .await
+        .unwrap();
+    handle
+        .register_function(
+            read_function("alpha::fast", "w1").with_handler_timeout(Duration::from_millis(250)),
+            Some(handler()),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let stuck = handle
+        .invoke(Invocation::new_sync(
+            fid("alpha::stuck"),
+            json!({}),
+            causal(),
+        ))
+        .await;
+    assert!(matches!(
+        stuck.error,
+        Some(EngineError::HandlerTimedOut { ref function_id, timeout_ms: 250 })
+            if function_id == "alpha::stuck"
+    ));
+
+    let fast = handle
+        .invoke(Invocation::new_sync(
+            fid("alpha::fast"),
+            json!({"x": 1}),
+            causal(),
+        ))
+        .await;
+    assert!(fast.error.is_none());
+    assert_eq!(fast.value.unwrap()["echo"]["x"], 1);
+
+    let host = handle.lock().await;
+    let records = host.catalog().invocations();
+    assert_eq!(records.len(), 2);
+    assert!(!records[0].succeeded);
+    assert!(records[1].succeeded);
+}
+
+#[tokio::test(start_paused = true)]
+async fn timed_out_mutation_replays_the_stored_timeout() {
+    let handle = super::host::EngineHostHandle::new_in_memory().unwrap();
+    handle
+        .register_worker(worker("w1", "alpha"), true)
+        .await
+        .unwrap();
+    handle
+        .register_function(
+            write_function("alpha::stuck_write", "w1")
+                .with_handler_timeout(Duration::from_millis(100)),
+            Some(Arc::new(StuckHandler)),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let invoke = || {
+        handle.invoke(Invocation::new_sync(
+            fid("alpha::stuck_write"),
+            json!({}),
+            mutating_causal("timeout-key"),
+        ))
+    };
+    let first = invoke().await;
+    let duplicate = invoke().await;
+
+    assert_eq!(duplicate.replayed_from, Some(first.invocation_id));
+    for result in [first.error, duplicate.error] {
+        assert!(matches!(
+            result,
+            Some(EngineError::HandlerTimedOut {
+                timeout_ms: 100,
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn registration_rejects_non_positive_handler_timeouts() {
+    let mut catalog = LiveCatalog::new();
+    catalog
+        .register_worker(worker("w1", "alpha"), true)
+        .unwrap();
+    for timeout in [json!(0), json!(-5), json!("30s")] {
+        let mut function = read_function("alpha::bounded", "w1");
+        function.metadata = json!({"delivery": {"timeoutMs": timeout}});
+        let error = catalog
+            .register_function(function, Some(handler()), true)
+            .expect_err("invalid timeout");
+        assert!(error.to_string().contains("handler timeout"), "{error}");
+    }
+}
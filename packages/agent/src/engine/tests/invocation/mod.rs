@@ -4,6 +4,7 @@ pub(in crate::engine::tests) use super::fixtures::*;
 
 mod host_invocation;
 mod host_invocation_delegated_budget_ordering;
+mod host_invocation_timeouts;
 mod idempotency;
 mod meta_primitives;
 mod meta_promotion;
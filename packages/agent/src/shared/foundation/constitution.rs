@@ -70,6 +70,24 @@ pub enum ProviderSurface {
     Excluded,
 }
 
+/// Drop order under the system context ceiling; higher priorities are kept
+/// first.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum SectionPriority {
+    /// Environment hints the agent can rediscover.
+    #[default]
+    Low,
+    /// Audit traces.
+    Normal,
+    /// Agent state projection.
+    High,
+    /// The soul seed; dropped only if it alone exceeds the budget.
+    Core,
+}
+
 /// Typed context unit compiled before provider adaptation.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -96,6 +114,14 @@ pub struct ContextBlock {
     pub precedence: u32,
     /// Abstract cache class before provider mapping.
     pub cache_class: ContextCacheClass,
+    /// Drop order under the system context ceiling.
+    #[serde(default)]
+    pub priority: SectionPriority,
+    /// Lead provider composition ahead of the stable group, regardless of
+    /// `cache_class`. Pinned blocks join the stable cache region and are
+    /// never dropped by the system context ceiling.
+    #[serde(default)]
+    pub pinned: bool,
     /// Provider payload surface.
     pub provider_surface: ProviderSurface,
     /// Lifecycle hint.
@@ -330,6 +356,8 @@ pub fn context_block_for_text(
         inclusion_reason: "compiled by primitive context assembly".into(),
         precedence,
         cache_class,
+        priority: SectionPriority::Low,
+        pinned: false,
         provider_surface: ProviderSurface::Instructions,
         lifecycle: "runtime".into(),
         audit_ids: Vec::new(),
@@ -407,3 +407,34 @@ impl tracing::callsite::Callsite for TestCallsite {
         &META
     }
 }
+
+// ── Span correlation ─────────────────────────────────────────────
+
+#[test]
+fn span_trace_id_is_persisted_for_nested_events() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let transport = SqliteTransport::new(create_test_db(), TransportConfig::default());
+    let inner = Arc::clone(&transport.inner);
+    let subscriber = tracing_subscriber::registry().with(transport);
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!("agent_run", trace_id = "run_a").in_scope(|| {
+            tracing::warn!("outer a");
+            tracing::info_span!("tool", component = "Tool").in_scope(|| tracing::warn!("inner a"));
+        });
+        tracing::info_span!("agent_run", trace_id = "run_b").in_scope(|| tracing::warn!("b"));
+    });
+
+    let guard = inner.lock().unwrap();
+    let mut stmt = guard
+        .conn
+        .prepare("SELECT message FROM logs WHERE trace_id = ?1 ORDER BY id")
+        .unwrap();
+    let run_a: Vec<String> = stmt
+        .query_map(["run_a"], |r| r.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(run_a, ["outer a", "inner a"]);
+}
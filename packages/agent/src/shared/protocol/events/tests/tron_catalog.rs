@@ -188,6 +188,10 @@ fn tron_event_all_event_types() {
         },
         TronEvent::SessionArchived { base: base.clone() },
         TronEvent::SessionUnarchived { base: base.clone() },
+        TronEvent::SessionIdleTimeout {
+            base: base.clone(),
+            idle_timeout_secs: 3600,
+        },
         TronEvent::SessionForked {
             base: base.clone(),
             new_session_id: "new-s1".into(),
@@ -417,6 +417,12 @@ tron_events! {
     /// Session deleted.
     SessionDeleted {} => "session_deleted",
 
+    /// Idle session stopped by the orchestrator sweep to free its slot.
+    SessionIdleTimeout {
+        #[serde(rename = "idleTimeoutSecs")]
+        idle_timeout_secs: u64,
+    } => "session.idle_timeout",
+
     /// Session metadata updated (live sync to iOS).
     ///
     /// All stats/model fields are optional so partial updates do not zero out
@@ -13,6 +13,8 @@ use serde_json::Value;
 pub const MEMORY_SCHEMA_VERSION: &str = "tron.memory.v1";
 /// Built-in deterministic engine id for the minimal resource-backed engine.
 pub const RESOURCE_BACKED_MEMORY_ENGINE_ID: &str = "resource-backed-local";
+/// Highest importance a memory record can carry.
+pub const MAX_MEMORY_IMPORTANCE: u8 = 10;
 
 /// Memory engine execution mode.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -174,6 +176,12 @@ pub struct MemoryRecord {
     pub confidence: Value,
     /// Sensitivity classification.
     pub sensitivity: String,
+    /// Optional caller-chosen category such as `preference` or `project`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Optional importance from 0 (trivial) to [`MAX_MEMORY_IMPORTANCE`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub importance: Option<u8>,
     /// Retention policy metadata.
     pub retention: Value,
     /// Optional expiration time.
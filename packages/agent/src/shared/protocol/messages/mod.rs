@@ -220,6 +220,10 @@ pub struct TokenUsage {
     /// Provider-reported total tokens for this model call.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_tokens: Option<u64>,
+    /// Fraction of the prompt served from the prompt cache, when the provider
+    /// reports cache buckets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_hit_ratio: Option<f64>,
     /// Provider type for normalization.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_type: Option<Provider>,
@@ -432,6 +436,14 @@ pub struct Context {
     /// Server origin (e.g. `"localhost:9847"`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub server_origin: Option<String>,
+    /// Token ceiling for the composed system context; `None` uses the
+    /// provider default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_context_token_ceiling: Option<u64>,
+    /// Ids of system context blocks pinned ahead of the stable group and
+    /// always kept under the ceiling.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_context_blocks: Vec<String>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -126,10 +126,12 @@ fn token_usage_serde() {
         thought_tokens: None,
         tool_use_prompt_tokens: None,
         total_tokens: Some(185),
+        cache_hit_ratio: Some(0.25),
         provider_type: Some(Provider::Anthropic),
     };
     let json = serde_json::to_value(&usage).unwrap();
     assert_eq!(json["inputTokens"], 100);
+    assert_eq!(json["cacheHitRatio"], 0.25);
     assert_eq!(json["cacheReadTokens"], 30);
     assert_eq!(json["cachedInputTokens"], 30);
     assert_eq!(json["reasoningOutputTokens"], 5);
@@ -346,6 +348,8 @@ fn context_serde_roundtrip() {
         agent_state_context: None,
         memory_prompt_context: None,
         server_origin: None,
+        system_context_token_ceiling: None,
+        pinned_context_blocks: Vec::new(),
     };
     let json = serde_json::to_string(&ctx).unwrap();
     let back: Context = serde_json::from_str(&json).unwrap();
@@ -9,9 +9,9 @@ use crate::engine::{EngineError, InvocationResult};
 use crate::shared::server::errors::{self as codes, CapabilityError};
 use crate::shared::server::failure::{
     ENGINE_DELIVERY_MODE_NOT_ALLOWED, ENGINE_DOMAIN_FAILURE, ENGINE_HANDLER_FAILED,
-    ENGINE_INVALID_FUNCTION_ID, ENGINE_INVALID_ID, ENGINE_INVALID_SCHEMA, ENGINE_LEDGER_FAILURE,
-    ENGINE_NAMESPACE_DENIED, ENGINE_NOT_ROUTABLE, ENGINE_POLICY_VIOLATION, ENGINE_SCHEMA_VIOLATION,
-    ENGINE_STORED_INVOCATION_ERROR, ENGINE_UNSUPPORTED_DELIVERY_MODE,
+    ENGINE_HANDLER_TIMED_OUT, ENGINE_INVALID_FUNCTION_ID, ENGINE_INVALID_ID, ENGINE_INVALID_SCHEMA,
+    ENGINE_LEDGER_FAILURE, ENGINE_NAMESPACE_DENIED, ENGINE_NOT_ROUTABLE, ENGINE_POLICY_VIOLATION,
+    ENGINE_SCHEMA_VIOLATION, ENGINE_STORED_INVOCATION_ERROR, ENGINE_UNSUPPORTED_DELIVERY_MODE,
     ENGINE_WORKER_TRANSPORT_FAILURE, FailureCategory, FailureEnvelope, FailureOrigin,
 };
 use serde_json::Value;
@@ -269,6 +269,21 @@ pub(crate) fn engine_error_to_failure(error: &EngineError) -> FailureEnvelope {
             FailureOrigin::Engine,
         )
         .with_error_type(Some(ENGINE_WORKER_TRANSPORT_FAILURE.to_owned())),
+        EngineError::HandlerTimedOut {
+            function_id,
+            timeout_ms,
+        } => FailureEnvelope::new(
+            ENGINE_HANDLER_TIMED_OUT,
+            FailureCategory::Engine,
+            error.to_string(),
+            false,
+            true,
+            FailureOrigin::Engine,
+        )
+        .with_details(Some(serde_json::json!({
+            "functionId": function_id,
+            "timeoutMs": timeout_ms,
+        }))),
         EngineError::HandlerFailed(message) => FailureEnvelope::new(
             ENGINE_HANDLER_FAILED,
             FailureCategory::Capability,
@@ -411,8 +426,23 @@ pub(crate) fn map_auth_error(e: AuthError) -> CapabilityError {
             .with_details(Some(serde_json::json!({ "reason": message })))
             .with_suggestion(Some("Re-authenticate the provider.".to_owned())),
         ),
+        A::ReloginRequired { provider, message } => CapabilityError::from_failure(
+            FailureEnvelope::new(
+                codes::AUTH_TOKEN_EXPIRED,
+                FailureCategory::Auth,
+                format!("Refresh token for {provider} was rejected"),
+                false,
+                true,
+                FailureOrigin::Auth,
+            )
+            .with_details(Some(serde_json::json!({
+                "provider": provider,
+                "reason": message,
+            })))
+            .with_suggestion(Some(format!("Run `tron auth {provider}`."))),
+        ),
         A::OAuth { status, message } => {
-            let retryable = matches!(status, 408 | 429 | 502 | 503 | 504);
+            let retryable = matches!(status, 408 | 429 | 500 | 502 | 503 | 504);
             CapabilityError::from_failure(
                 FailureEnvelope::new(
                     codes::AUTH_OAUTH_ERROR,
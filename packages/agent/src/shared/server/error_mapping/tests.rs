@@ -7,9 +7,9 @@ use crate::domains::session::event_store::errors::EventStoreError as E;
 use crate::engine::EngineError;
 use crate::shared::server::errors::{self as codes, CapabilityError};
 use crate::shared::server::failure::{
-    ENGINE_DELIVERY_MODE_NOT_ALLOWED, ENGINE_HANDLER_FAILED, ENGINE_INVALID_FUNCTION_ID,
-    ENGINE_INVALID_ID, ENGINE_INVALID_SCHEMA, ENGINE_LEDGER_FAILURE, ENGINE_NAMESPACE_DENIED,
-    ENGINE_NOT_ROUTABLE, ENGINE_POLICY_VIOLATION, ENGINE_SCHEMA_VIOLATION,
+    ENGINE_DELIVERY_MODE_NOT_ALLOWED, ENGINE_HANDLER_FAILED, ENGINE_HANDLER_TIMED_OUT,
+    ENGINE_INVALID_FUNCTION_ID, ENGINE_INVALID_ID, ENGINE_INVALID_SCHEMA, ENGINE_LEDGER_FAILURE,
+    ENGINE_NAMESPACE_DENIED, ENGINE_NOT_ROUTABLE, ENGINE_POLICY_VIOLATION, ENGINE_SCHEMA_VIOLATION,
     ENGINE_STORED_INVOCATION_ERROR, ENGINE_UNSUPPORTED_DELIVERY_MODE, FailureCategory,
     FailureOrigin,
 };
@@ -171,6 +171,14 @@ fn every_engine_error_variant_has_stable_failure_mapping() {
             "WORKER_DISCONNECTED",
             FailureCategory::Engine,
         ),
+        (
+            EngineError::HandlerTimedOut {
+                function_id: "demo::run".to_owned(),
+                timeout_ms: 5_000,
+            },
+            ENGINE_HANDLER_TIMED_OUT,
+            FailureCategory::Engine,
+        ),
         (
             EngineError::HandlerFailed("boom".to_owned()),
             ENGINE_HANDLER_FAILED,
@@ -372,6 +380,25 @@ fn auth_token_expired_is_typed() {
     assert_eq!(mapped.to_string(), "Auth token expired and refresh failed");
 }
 
+#[test]
+fn auth_relogin_required_is_typed() {
+    let mapped = map_auth_error(A::ReloginRequired {
+        provider: "anthropic".into(),
+        message: "invalid_grant".into(),
+    });
+    let details = assert_embedded_failure(
+        &mapped,
+        codes::AUTH_TOKEN_EXPIRED,
+        FailureCategory::Auth,
+        FailureOrigin::Auth,
+        false,
+        true,
+    );
+    assert_eq!(details["provider"], "anthropic");
+    assert_eq!(details["reason"], "invalid_grant");
+    assert!(mapped.to_string().contains("anthropic"));
+}
+
 #[test]
 fn auth_oauth_error_is_typed() {
     let mapped = map_auth_error(A::OAuth {
@@ -20,6 +20,9 @@ pub const NOT_FOUND: &str = "NOT_FOUND";
 pub const SESSION_NOT_FOUND: &str = "SESSION_NOT_FOUND";
 /// Session is currently processing a prompt from another connection.
 pub const SESSION_BUSY: &str = "SESSION_BUSY";
+/// A plan awaiting approval was dropped by a server restart before it was
+/// approved or declined.
+pub const PLAN_EXPIRED: &str = "PLAN_EXPIRED";
 /// Engine idempotency key replay/conflict could not be accepted.
 pub const IDEMPOTENCY_CONFLICT: &str = "IDEMPOTENCY_CONFLICT";
 /// Engine catalog mutation targeted an item owned by a different worker.
@@ -59,6 +62,16 @@ pub const AUTH_STORAGE_ERROR: &str = "AUTH_STORAGE_ERROR";
 /// Auth provider transport failed before a usable OAuth response.
 pub const AUTH_TRANSPORT_ERROR: &str = "AUTH_TRANSPORT_ERROR";
 
+// ── Typed model-selection errors ─────────────────────────────────────
+//
+// `model::switch` rejects ids before touching the session. A known model whose
+// provider has no stored credential reports `AUTH_NOT_CONFIGURED` instead.
+
+/// Requested model id is not in any provider registry.
+pub const MODEL_UNKNOWN: &str = "MODEL_UNKNOWN";
+/// Model is known but cannot be selected (retired or not on the active plan).
+pub const MODEL_NOT_AVAILABLE: &str = "MODEL_NOT_AVAILABLE";
+
 // ── Version handshake (L6) ──────────────────────────────────────────
 //
 // `system::ping` requires a numeric `protocolVersion` from the client
@@ -210,9 +223,9 @@ fn category_for_capability_code(code: &str) -> FailureCategory {
         INVALID_PARAMS | CLIENT_VERSION_UNSUPPORTED | INVALID_VISIBILITY_PROMOTION => {
             FailureCategory::InvalidRequest
         }
-        SESSION_NOT_FOUND | EVENT_NOT_FOUND | WORKSPACE_NOT_FOUND | BLOB_NOT_FOUND | NOT_FOUND => {
-            FailureCategory::NotFound
-        }
+        SESSION_NOT_FOUND | EVENT_NOT_FOUND | WORKSPACE_NOT_FOUND | BLOB_NOT_FOUND | NOT_FOUND
+        | PLAN_EXPIRED => FailureCategory::NotFound,
+        MODEL_UNKNOWN | MODEL_NOT_AVAILABLE => FailureCategory::InvalidModel,
         NOT_AVAILABLE | EVENT_STORE_BUSY => FailureCategory::Unavailable,
         SESSION_BUSY | IDEMPOTENCY_CONFLICT | ENGINE_OWNER_MISMATCH => FailureCategory::Conflict,
         AUTH_NOT_CONFIGURED | AUTH_TOKEN_EXPIRED | AUTH_OAUTH_ERROR | AUTH_STORAGE_ERROR => {
@@ -231,7 +244,9 @@ fn retry_recover_for_category(category: FailureCategory, code: &str) -> (bool, b
         (FailureCategory::Conflict, IDEMPOTENCY_CONFLICT | ENGINE_OWNER_MISMATCH) => (false, true),
         (FailureCategory::Unavailable, _) => (true, true),
         (FailureCategory::Network, _) => (true, true),
-        (FailureCategory::NotFound, _) | (FailureCategory::InvalidRequest, _) => (false, true),
+        (FailureCategory::NotFound, _)
+        | (FailureCategory::InvalidRequest, _)
+        | (FailureCategory::InvalidModel, _) => (false, true),
         (FailureCategory::Auth, _) => (false, true),
         (FailureCategory::Internal, _) => (false, false),
         _ => (false, false),
@@ -37,6 +37,8 @@ pub const ENGINE_DOMAIN_FAILURE: &str = "ENGINE_DOMAIN_FAILURE";
 pub const ENGINE_WORKER_TRANSPORT_FAILURE: &str = "ENGINE_WORKER_TRANSPORT_FAILURE";
 /// Engine handler returned an application failure.
 pub const ENGINE_HANDLER_FAILED: &str = "ENGINE_HANDLER_FAILED";
+/// Engine handler exceeded its function's declared timeout.
+pub const ENGINE_HANDLER_TIMED_OUT: &str = "ENGINE_HANDLER_TIMED_OUT";
 
 /// Provider HTTP/network failure.
 pub const PROVIDER_HTTP_ERROR: &str = "PROVIDER_HTTP_ERROR";
@@ -60,6 +62,8 @@ pub const PROVIDER_OTHER_ERROR: &str = "PROVIDER_OTHER_ERROR";
 pub const MODEL_RESPONSE_ERROR: &str = "MODEL_RESPONSE_ERROR";
 /// Provider-neutral model responder auth failure.
 pub const MODEL_AUTH_ERROR: &str = "MODEL_AUTH_ERROR";
+/// Model stream ended before an in-flight capability invocation completed.
+pub const MODEL_STREAM_TRUNCATED: &str = "MODEL_STREAM_TRUNCATED";
 /// Provider request audit construction failure.
 pub const MODEL_PROVIDER_REQUEST_AUDIT_FAILED: &str = "MODEL_PROVIDER_REQUEST_AUDIT_FAILED";
 /// Runtime capability execution failure.
@@ -87,6 +91,8 @@ pub const JOURNAL_CREATE_FAILED: &str = "JOURNAL_CREATE_FAILED";
 pub const ASSISTANT_PERSIST_FAILED: &str = "ASSISTANT_PERSIST_FAILED";
 /// Requested model-facing capability primitive is not present in the resolved surface.
 pub const CAPABILITY_PRIMITIVE_NOT_FOUND: &str = "CAPABILITY_PRIMITIVE_NOT_FOUND";
+/// Session capability policy does not permit the requested primitive or operation.
+pub const CAPABILITY_NOT_PERMITTED: &str = "CAPABILITY_NOT_PERMITTED";
 /// Capability execution requires an engine host but none is available.
 pub const CAPABILITY_ENGINE_HOST_UNAVAILABLE: &str = "CAPABILITY_ENGINE_HOST_UNAVAILABLE";
 /// Engine invocation completed without a capability result payload.
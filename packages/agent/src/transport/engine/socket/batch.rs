@@ -0,0 +1,84 @@
+//! Batched `/engine` messages: one frame carrying an array of messages.
+//!
+//! Each element is handled in order exactly as if it had arrived alone, and
+//! the responses come back as one array frame in the same order. Elements
+//! without an `id` are notifications: they run, but contribute no response
+//! element. A `goodbye` element stops the batch after its response.
+
+use serde_json::Value;
+
+use crate::shared::server::errors::INVALID_PARAMS;
+
+use super::EngineWsSession;
+use super::outbound::send_engine_ws_batch;
+use super::wire::protocol_error;
+
+/// Messages accepted in one batch frame.
+pub(super) const MAX_BATCH_MESSAGES: usize = 32;
+
+impl EngineWsSession {
+    pub(super) async fn handle_batch(&mut self, messages: Vec<Value>) -> bool {
+        if messages.is_empty() {
+            return self.send_error(
+                None,
+                protocol_error(INVALID_PARAMS, "engine batch must not be empty", None),
+            );
+        }
+        if messages.len() > MAX_BATCH_MESSAGES {
+            return self.send_error(
+                None,
+                protocol_error(
+                    INVALID_PARAMS,
+                    format!(
+                        "engine batch exceeds maximum size ({} > {MAX_BATCH_MESSAGES} messages)",
+                        messages.len()
+                    ),
+                    None,
+                ),
+            );
+        }
+        let mut responses = Vec::new();
+        let mut keep_open = true;
+        for message in messages {
+            let notification = message
+                .as_object()
+                .is_some_and(|object| object.get("id").is_none_or(Value::is_null));
+            self.set_batch_capture(Some(Vec::new()));
+            keep_open = self.handle_message(message).await;
+            let captured = self.set_batch_capture(None).unwrap_or_default();
+            if !notification {
+                responses.extend(captured);
+            }
+            if !keep_open {
+                break;
+            }
+        }
+        if responses.is_empty() {
+            return keep_open;
+        }
+        send_engine_ws_batch(&self.out_tx, responses) && keep_open
+    }
+
+    /// Route responses into `capture` instead of the socket; returns the
+    /// previous capture.
+    fn set_batch_capture(&self, capture: Option<Vec<Value>>) -> Option<Vec<Value>> {
+        match self.batch_capture.lock() {
+            Ok(mut current) => std::mem::replace(&mut *current, capture),
+            Err(_) => None,
+        }
+    }
+
+    /// Capture `value` when a batch is being handled; hands it back otherwise.
+    pub(super) fn capture_batch_response(&self, value: Value) -> Option<Value> {
+        match self.batch_capture.lock() {
+            Ok(mut current) => match current.as_mut() {
+                Some(responses) => {
+                    responses.push(value);
+                    None
+                }
+                None => Some(value),
+            },
+            Err(_) => Some(value),
+        }
+    }
+}
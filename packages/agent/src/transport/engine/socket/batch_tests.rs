@@ -0,0 +1,101 @@
+use serde_json::{Value, json};
+
+use super::batch::MAX_BATCH_MESSAGES;
+use super::tests::test_session;
+
+fn frame(text: &str) -> Value {
+    serde_json::from_str(text).expect("frame JSON")
+}
+
+#[tokio::test]
+async fn mixed_batch_answers_in_order_and_skips_notifications() {
+    let (mut session, mut rx) = test_session();
+    let batch = json!([
+        {"type": "hello", "id": "h1", "protocolVersion": 1, "sessionId": "s1"},
+        {"type": "heartbeat"},
+        {"type": "discover", "id": "d1", "request": {"namespacePrefix": "engine"}},
+        {"type": "heartbeat", "id": "hb2"}
+    ]);
+    assert!(session.handle_text(&batch.to_string()).await);
+
+    let responses = frame(&rx.recv().await.expect("batch frame"));
+    let responses = responses.as_array().expect("array response");
+    let ids = responses
+        .iter()
+        .map(|response| response["id"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["h1", "d1", "hb2"]);
+    assert_eq!(responses[0]["type"], "hello.ok");
+    assert_eq!(responses[1]["ok"], true);
+    assert!(responses[1]["result"]["functions"].is_array());
+    assert_eq!(responses[2]["type"], "heartbeat.ack");
+    assert_eq!(
+        session.hello.as_ref().unwrap().session_id.as_deref(),
+        Some("s1")
+    );
+    assert!(rx.try_recv().is_err(), "batch answers in a single frame");
+
+    assert!(
+        session
+            .handle_text(&json!([{"type": "heartbeat"}]).to_string())
+            .await
+    );
+    assert!(
+        rx.try_recv().is_err(),
+        "notification-only batch sends nothing"
+    );
+}
+
+#[tokio::test]
+async fn failing_batch_element_does_not_stop_later_elements() {
+    let (mut session, mut rx) = test_session();
+    let batch = json!([
+        {"type": "inspect", "id": "i1", "request": {"kind": "function", "id": "alpha::missing"}},
+        {"type": "no_such_message", "id": "x1"},
+        "not an object",
+        {"type": "discover", "id": "d2", "request": {}}
+    ]);
+    assert!(session.handle_text(&batch.to_string()).await);
+
+    let responses = frame(&rx.recv().await.expect("batch frame"));
+    let responses = responses.as_array().expect("array response");
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["id"], "i1");
+    assert_eq!(responses[0]["ok"], false);
+    assert_eq!(responses[1]["id"], "x1");
+    assert_eq!(responses[1]["ok"], false);
+    assert!(responses[2].get("id").is_none());
+    assert_eq!(responses[2]["ok"], false);
+    assert_eq!(responses[3]["id"], "d2");
+    assert_eq!(responses[3]["ok"], true);
+}
+
+#[tokio::test]
+async fn empty_and_oversized_batches_are_invalid_requests() {
+    let (mut session, mut rx) = test_session();
+    assert!(session.handle_text("[]").await);
+    let response = frame(&rx.recv().await.expect("error frame"));
+    assert_eq!(response["ok"], false);
+    assert_eq!(response["error"]["code"], "INVALID_PARAMS");
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("must not be empty")
+    );
+
+    let oversized = vec![json!({"type": "heartbeat", "id": "hb"}); MAX_BATCH_MESSAGES + 1];
+    assert!(
+        session
+            .handle_text(&Value::Array(oversized).to_string())
+            .await
+    );
+    let response = frame(&rx.recv().await.expect("error frame"));
+    assert_eq!(response["ok"], false);
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("exceeds maximum size")
+    );
+}
@@ -0,0 +1,139 @@
+//! WebSocket heartbeat and idle-disconnect policy for `/engine` clients.
+//!
+//! The server sends a WebSocket ping every [`HeartbeatPolicy::interval`]
+//! (`server.heartbeatIntervalMs`). Any inbound frame (pong, text, or ping)
+//! counts as liveness. A connection that misses
+//! [`HeartbeatPolicy::max_missed_pongs`] consecutive pings — i.e. stays silent
+//! for roughly [`HeartbeatPolicy::timeout`] (`server.heartbeatTimeoutMs`) — is
+//! closed with
+//! [`HEARTBEAT_TIMEOUT_CLOSE_CODE`] so zombie mobile connections do not hold
+//! subscriptions and client slots forever.
+
+use std::time::Duration;
+
+/// Close code sent when a client stops answering heartbeats.
+pub const HEARTBEAT_TIMEOUT_CLOSE_CODE: u16 = 4000;
+/// Close reason sent with [`HEARTBEAT_TIMEOUT_CLOSE_CODE`].
+pub const HEARTBEAT_TIMEOUT_CLOSE_REASON: &str = "heartbeat timeout";
+/// How long the server waits for the client's close reply before dropping
+/// the TCP connection, so a client that wakes up still reads the close code.
+pub(super) const CLOSE_HANDSHAKE_GRACE: Duration = Duration::from_secs(1);
+
+/// Heartbeat cadence and idle-disconnect threshold for one connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeartbeatPolicy {
+    /// Interval between server pings.
+    pub interval: Duration,
+    /// Silence budget before the connection is closed.
+    pub timeout: Duration,
+}
+
+impl Default for HeartbeatPolicy {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(90),
+        }
+    }
+}
+
+impl HeartbeatPolicy {
+    /// Build a policy; a zero interval is clamped to 1ms.
+    #[must_use]
+    pub fn new(interval: Duration, timeout: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            timeout,
+        }
+    }
+
+    /// Consecutive unanswered pings tolerated before closing (at least one).
+    #[must_use]
+    pub fn max_missed_pongs(&self) -> u32 {
+        let missed = self.timeout.as_millis().div_ceil(self.interval.as_millis());
+        u32::try_from(missed).unwrap_or(u32::MAX).max(1)
+    }
+}
+
+/// What the connection loop should do on a heartbeat tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum HeartbeatAction {
+    /// Send another ping and keep waiting.
+    Ping,
+    /// The client missed too many pings; close the socket.
+    Close,
+}
+
+/// Per-connection missed-pong counter.
+#[derive(Debug)]
+pub(super) struct HeartbeatMonitor {
+    max_missed: u32,
+    outstanding: u32,
+}
+
+impl HeartbeatMonitor {
+    pub(super) fn new(policy: HeartbeatPolicy) -> Self {
+        Self {
+            max_missed: policy.max_missed_pongs(),
+            outstanding: 0,
+        }
+    }
+
+    /// Record any inbound frame from the client.
+    pub(super) fn on_activity(&mut self) {
+        self.outstanding = 0;
+    }
+
+    /// Advance one heartbeat interval.
+    pub(super) fn on_tick(&mut self) -> HeartbeatAction {
+        if self.outstanding >= self.max_missed {
+            return HeartbeatAction::Close;
+        }
+        self.outstanding += 1;
+        HeartbeatAction::Ping
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(interval_ms: u64, timeout_ms: u64) -> HeartbeatPolicy {
+        HeartbeatPolicy::new(
+            Duration::from_millis(interval_ms),
+            Duration::from_millis(timeout_ms),
+        )
+    }
+
+    #[test]
+    fn max_missed_pongs_rounds_up_and_is_at_least_one() {
+        assert_eq!(HeartbeatPolicy::default().max_missed_pongs(), 3);
+        assert_eq!(policy(30_000, 100_000).max_missed_pongs(), 4);
+        assert_eq!(policy(30_000, 0).max_missed_pongs(), 1);
+        assert_eq!(policy(0, 10).max_missed_pongs(), 10);
+    }
+
+    #[test]
+    fn silent_connection_closes_after_missed_pongs() {
+        let mut monitor = HeartbeatMonitor::new(policy(10, 30));
+        let actions = (0..4).map(|_| monitor.on_tick()).collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            [
+                HeartbeatAction::Ping,
+                HeartbeatAction::Ping,
+                HeartbeatAction::Ping,
+                HeartbeatAction::Close
+            ]
+        );
+    }
+
+    #[test]
+    fn ponging_connection_stays_open() {
+        let mut monitor = HeartbeatMonitor::new(policy(10, 30));
+        for _ in 0..100 {
+            assert_eq!(monitor.on_tick(), HeartbeatAction::Ping);
+            monitor.on_activity();
+        }
+    }
+}
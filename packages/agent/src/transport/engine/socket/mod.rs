@@ -1,19 +1,25 @@
 //! `/engine` WebSocket protocol over the canonical engine transport envelope.
 //!
 //! This module owns only WebSocket framing, protocol validation, correlation
-//! ids, heartbeat, and stream cursor subscription state. Worker/client
+//! ids, heartbeat, stream cursor subscription state, and reconnect resume
+//! tokens (see `resume.rs`). WebSocket ping/pong liveness and idle disconnect
+//! follow [`HeartbeatPolicy`] (see `liveness.rs`). Worker/client
 //! discover/inspect/watch/invoke/promote messages are translated into
 //! [`crate::transport::engine::EngineTransportRequest`] and then dispatched
 //! through the canonical engine transport path. Public context is limited to
 //! session/workspace/trace correlation; authority scopes and runtime metadata
-//! are not accepted on the wire. Model providers do not receive this transport
-//! surface; they receive only the capability-domain `execute` orchestrator.
+//! are not accepted on the wire. A frame may also carry a JSON array of up to
+//! 32 messages, answered with one array of responses (see `batch.rs`).
+//! `discover` doubles as protocol introspection: each returned function
+//! definition carries its description and request schema. Model providers do
+//! not receive this transport surface; they receive only the capability-domain
+//! `execute` orchestrator.
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use axum::extract::ws::{Message, WebSocket};
+use axum::extract::ws::{CloseFrame, Message, WebSocket};
 use futures::{SinkExt, StreamExt};
 use metrics::counter;
 use serde_json::{Map, Value, json};
@@ -39,11 +45,16 @@ const STREAM_DEFAULT_LIMIT: usize = 100;
 const STREAM_MAX_LIMIT: usize = 500;
 const PUSH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
 
+mod batch;
+mod liveness;
 mod outbound;
+mod resume;
 mod stream_projection;
 mod subscriptions;
 mod wire;
 
+use liveness::{CLOSE_HANDSHAKE_GRACE, HeartbeatAction, HeartbeatMonitor};
+pub use liveness::{HEARTBEAT_TIMEOUT_CLOSE_CODE, HEARTBEAT_TIMEOUT_CLOSE_REASON, HeartbeatPolicy};
 use outbound::{send_engine_ws_value, send_engine_ws_value_async};
 #[cfg(test)]
 use stream_projection::{server_payload_from_stream_event, stream_event_matches_filters};
@@ -89,14 +100,23 @@ pub async fn run_engine_ws_session(
     client_id: String,
     ctx: Arc<ServerRuntimeContext>,
     clients: Arc<EngineClientRegistry>,
+    heartbeat: HeartbeatPolicy,
 ) {
     clients.add();
     counter!("engine_ws_connections_total").increment(1);
     let (mut ws_tx, mut ws_rx) = ws.split();
     let (out_tx, mut out_rx) = mpsc::channel::<String>(OUTBOUND_QUEUE_CAPACITY);
+    let (control_tx, mut control_rx) = mpsc::channel::<Message>(4);
     let writer = tokio::spawn(async move {
-        while let Some(text) = out_rx.recv().await {
-            if ws_tx.send(Message::Text(text.into())).await.is_err() {
+        loop {
+            let message = tokio::select! {
+                biased;
+                Some(control) = control_rx.recv() => control,
+                Some(text) = out_rx.recv() => Message::Text(text.into()),
+                else => break,
+            };
+            let closing = matches!(message, Message::Close(_));
+            if ws_tx.send(message).await.is_err() || closing {
                 break;
             }
         }
@@ -111,7 +131,45 @@ pub async fn run_engine_ws_session(
         cancel.clone(),
     ));
     let mut session = EngineWsSession::new(client_id, ctx, out_tx, subscriptions, cancel.clone());
-    while let Some(frame) = ws_rx.next().await {
+    let mut monitor = HeartbeatMonitor::new(heartbeat);
+    let mut ticker = tokio::time::interval(heartbeat.interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let _ = ticker.tick().await;
+    loop {
+        let frame = tokio::select! {
+            frame = ws_rx.next() => frame,
+            _ = ticker.tick() => {
+                let message = match monitor.on_tick() {
+                    HeartbeatAction::Ping => Message::Ping(Default::default()),
+                    HeartbeatAction::Close => {
+                        tracing::info!(client_id = %session.client_id, "closing idle engine WebSocket");
+                        counter!("engine_ws_heartbeat_timeouts_total").increment(1);
+                        Message::Close(Some(CloseFrame {
+                            code: HEARTBEAT_TIMEOUT_CLOSE_CODE,
+                            reason: HEARTBEAT_TIMEOUT_CLOSE_REASON.into(),
+                        }))
+                    }
+                };
+                let closing = matches!(message, Message::Close(_));
+                if control_tx.send(message).await.is_err() {
+                    break;
+                }
+                if closing {
+                    let _ = tokio::time::timeout(CLOSE_HANDSHAKE_GRACE, async {
+                        while let Some(Ok(frame)) = ws_rx.next().await {
+                            if matches!(frame, Message::Close(_)) {
+                                break;
+                            }
+                        }
+                    })
+                    .await;
+                    break;
+                }
+                continue;
+            }
+        };
+        let Some(frame) = frame else { break };
+        monitor.on_activity();
         match frame {
             Ok(Message::Text(text)) => {
                 if !session.handle_text(&text).await {
@@ -126,6 +184,7 @@ pub async fn run_engine_ws_session(
             }
         }
     }
+    drop(control_tx);
     cancel.cancel();
     session.cleanup().await;
     drop(session);
@@ -141,6 +200,7 @@ struct EngineWsSession {
     subscriptions: Arc<tokio::sync::Mutex<BTreeMap<String, SubscriptionState>>>,
     cancel: CancellationToken,
     hello: Option<HelloState>,
+    batch_capture: std::sync::Mutex<Option<Vec<Value>>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -164,6 +224,7 @@ impl EngineWsSession {
             subscriptions,
             cancel,
             hello: None,
+            batch_capture: std::sync::Mutex::new(None),
         }
     }
 
@@ -194,6 +255,13 @@ impl EngineWsSession {
         if let Err(error) = validate_json_depth(&value, MAX_JSON_DEPTH) {
             return self.send_error(None, error);
         }
+        match value {
+            Value::Array(messages) => self.handle_batch(messages).await,
+            value => self.handle_message(value).await,
+        }
+    }
+
+    async fn handle_message(&mut self, value: Value) -> bool {
         let Some(object) = value.as_object() else {
             return self.send_error(
                 None,
@@ -224,6 +292,7 @@ impl EngineWsSession {
             "subscribe" => self.handle_subscribe(id, value).await,
             "poll" => self.handle_poll(id, value).await,
             "ack" => self.handle_ack(id, value).await,
+            "resume" => self.handle_resume(id, value).await,
             "heartbeat" => self.handle_heartbeat(id, value).await,
             "goodbye" => {
                 let _ = self.send_value(json!({
@@ -448,18 +517,19 @@ impl EngineWsSession {
         result: Value,
         trace_id: Option<String>,
     ) -> bool {
-        send_engine_ws_value_async(
-            &self.out_tx,
-            &self.cancel,
-            json!({
-                "type": "response",
-                "id": id,
-                "ok": true,
-                "result": result,
-                "traceId": trace_id,
-            }),
-        )
-        .await
+        let response = json!({
+            "type": "response",
+            "id": id,
+            "ok": true,
+            "result": result,
+            "traceId": trace_id,
+        });
+        match self.capture_batch_response(response) {
+            Some(response) => {
+                send_engine_ws_value_async(&self.out_tx, &self.cancel, response).await
+            }
+            None => true,
+        }
     }
 
     fn send_error(&self, id: Option<String>, error: CapabilityError) -> bool {
@@ -485,7 +555,10 @@ impl EngineWsSession {
     }
 
     fn send_value(&self, value: Value) -> bool {
-        send_engine_ws_value(&self.out_tx, value)
+        match self.capture_batch_response(value) {
+            Some(value) => send_engine_ws_value(&self.out_tx, value),
+            None => true,
+        }
     }
 
     async fn cleanup(&mut self) {
@@ -501,5 +574,9 @@ impl EngineWsSession {
     }
 }
 
+#[cfg(test)]
+mod batch_tests;
+#[cfg(test)]
+mod resume_tests;
 #[cfg(test)]
 mod tests;
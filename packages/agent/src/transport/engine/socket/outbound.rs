@@ -8,7 +8,23 @@ use tokio_util::sync::CancellationToken;
 pub(super) fn send_engine_ws_value(out_tx: &mpsc::Sender<String>, value: Value) -> bool {
     let mut value = value;
     remove_null_transport_fields(&mut value);
-    let json = match serde_json::to_string(&value) {
+    try_send_engine_ws_json(out_tx, &value)
+}
+
+/// Send batch responses as one array frame.
+pub(super) fn send_engine_ws_batch(out_tx: &mpsc::Sender<String>, responses: Vec<Value>) -> bool {
+    let responses = responses
+        .into_iter()
+        .map(|mut value| {
+            remove_null_transport_fields(&mut value);
+            value
+        })
+        .collect();
+    try_send_engine_ws_json(out_tx, &Value::Array(responses))
+}
+
+fn try_send_engine_ws_json(out_tx: &mpsc::Sender<String>, value: &Value) -> bool {
+    let json = match serde_json::to_string(value) {
         Ok(json) => json,
         Err(error) => {
             tracing::error!(%error, "failed to serialize engine WebSocket response");
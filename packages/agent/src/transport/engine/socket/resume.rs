@@ -0,0 +1,167 @@
+//! Reconnect resume tokens for `/engine` stream subscriptions.
+//!
+//! A resume token captures each live subscription's topic, filters, scope, and
+//! last acknowledged cursor. After a dropped connection the client sends
+//! `resume { token }` on the new socket; the server re-opens every
+//! subscription at its acknowledged cursor so only unacknowledged events are
+//! replayed through the normal poll/push path instead of a full resync.
+//!
+//! The token is opaque base64url JSON. It grants nothing beyond what an
+//! explicit `subscribe` with the same topic, cursor, and context would: stream
+//! visibility is still enforced by the engine on every poll.
+
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::engine::StreamCursor;
+use crate::shared::server::errors::{CapabilityError, INVALID_PARAMS};
+
+use super::EngineWsSession;
+use super::subscriptions::SubscriptionState;
+use super::wire::{ResumeMessage, protocol_error};
+
+const RESUME_TOKEN_VERSION: u32 = 1;
+const INVALID_RESUME_TOKEN: &str = "INVALID_RESUME_TOKEN";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ResumeToken {
+    version: u32,
+    subscriptions: Vec<ResumeEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ResumeEntry {
+    topic: String,
+    cursor: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filters: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace_id: Option<String>,
+}
+
+pub(super) fn encode_resume_token<'a>(
+    subscriptions: impl IntoIterator<Item = &'a SubscriptionState>,
+) -> Result<String, CapabilityError> {
+    let token = ResumeToken {
+        version: RESUME_TOKEN_VERSION,
+        subscriptions: subscriptions
+            .into_iter()
+            .map(|state| ResumeEntry {
+                topic: state.topic.clone(),
+                cursor: state.acked_cursor.0,
+                filters: state.filters.clone(),
+                session_id: state.session_id.clone(),
+                workspace_id: state.workspace_id.clone(),
+            })
+            .collect(),
+    };
+    let json = serde_json::to_vec(&token).map_err(|error| CapabilityError::Internal {
+        message: format!("failed to encode resume token: {error}"),
+    })?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+fn decode_resume_token(token: &str) -> Result<ResumeToken, CapabilityError> {
+    let invalid = |reason: String| {
+        protocol_error(
+            INVALID_RESUME_TOKEN,
+            format!("invalid resume token: {reason}"),
+            None,
+        )
+    };
+    let bytes = URL_SAFE_NO_PAD
+        .decode(token.trim())
+        .map_err(|error| invalid(error.to_string()))?;
+    let token = serde_json::from_slice::<ResumeToken>(&bytes)
+        .map_err(|error| invalid(error.to_string()))?;
+    if token.version != RESUME_TOKEN_VERSION {
+        return Err(invalid(format!("unsupported version {}", token.version)));
+    }
+    Ok(token)
+}
+
+impl EngineWsSession {
+    /// Resume token covering every live subscription on this connection.
+    pub(super) async fn current_resume_token(&self) -> Result<String, CapabilityError> {
+        encode_resume_token(self.subscriptions.lock().await.values())
+    }
+
+    /// Close subscriptions a failed resume already opened, so a rejected
+    /// token leaves the connection as it found it.
+    async fn roll_back_resume(&self, opened: &[String]) {
+        let mut subscriptions = self.subscriptions.lock().await;
+        for subscription_id in opened {
+            let _ = subscriptions.remove(subscription_id);
+            let _ = self
+                .ctx
+                .engine_host
+                .unsubscribe_stream(subscription_id)
+                .await;
+        }
+    }
+
+    pub(super) async fn handle_resume(&mut self, id: Option<String>, value: Value) -> bool {
+        let message = match serde_json::from_value::<ResumeMessage>(value) {
+            Ok(message) => message,
+            Err(error) => {
+                return self.send_error(
+                    id,
+                    protocol_error(INVALID_PARAMS, format!("invalid resume: {error}"), None),
+                );
+            }
+        };
+        let token = match decode_resume_token(&message.token) {
+            Ok(token) => token,
+            Err(error) => return self.send_error(message.id, error),
+        };
+        let mut opened = Vec::with_capacity(token.subscriptions.len());
+        let mut resumed = Vec::with_capacity(token.subscriptions.len());
+        for entry in token.subscriptions {
+            match self
+                .open_subscription(SubscriptionState {
+                    topic: entry.topic,
+                    cursor: StreamCursor(entry.cursor),
+                    acked_cursor: StreamCursor(entry.cursor),
+                    filters: entry.filters,
+                    session_id: entry.session_id,
+                    workspace_id: entry.workspace_id,
+                })
+                .await
+            {
+                Ok((subscription_id, state)) => {
+                    resumed.push(json!({
+                        "subscriptionId": subscription_id,
+                        "topic": state.topic,
+                        "cursor": state.cursor.0,
+                    }));
+                    opened.push(subscription_id);
+                }
+                Err(error) => {
+                    self.roll_back_resume(&opened).await;
+                    return self.send_error(message.id, error);
+                }
+            }
+        }
+        let resume_token = match self.current_resume_token().await {
+            Ok(token) => token,
+            Err(error) => {
+                self.roll_back_resume(&opened).await;
+                return self.send_error(message.id, error);
+            }
+        };
+        self.send_success(
+            message.id,
+            json!({
+                "subscriptions": resumed,
+                "resumeToken": resume_token,
+            }),
+            None,
+        )
+    }
+}
@@ -0,0 +1,138 @@
+use serde_json::{Value, json};
+
+use super::subscriptions::SubscriptionState;
+use super::tests::test_session;
+use super::*;
+use crate::engine::{PublishStreamEvent, VisibilityScope};
+use crate::shared::server::events::ServerEventPayload;
+
+async fn publish(ctx: &ServerRuntimeContext, event_type: &str) -> u64 {
+    ctx.engine_host
+        .publish_stream_event(PublishStreamEvent {
+            topic: "events.session".to_owned(),
+            payload: json!({
+                "serverEvent": ServerEventPayload::new(event_type, Some("s1".to_owned()), None)
+            }),
+            visibility: VisibilityScope::Session,
+            session_id: Some("s1".to_owned()),
+            workspace_id: None,
+            producer: "test".to_owned(),
+            trace_id: None,
+            parent_invocation_id: None,
+        })
+        .await
+        .unwrap()
+        .0
+}
+
+async fn request(
+    session: &mut EngineWsSession,
+    rx: &mut mpsc::Receiver<String>,
+    message: Value,
+) -> Value {
+    assert!(session.handle_text(&message.to_string()).await);
+    serde_json::from_str(&rx.recv().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn resume_token_replays_only_unacknowledged_events() {
+    let (mut first, mut first_rx) = test_session();
+    let ctx = first.ctx.clone();
+    let subscribed = request(
+        &mut first,
+        &mut first_rx,
+        json!({"type": "subscribe", "id": "s", "topic": "events.session", "cursor": 0,
+               "context": {"sessionId": "s1"}}),
+    )
+    .await;
+    let subscription_id = subscribed["result"]["subscriptionId"].as_str().unwrap();
+    for event_type in ["agent.one", "agent.two", "agent.three"] {
+        publish(&ctx, event_type).await;
+    }
+
+    let acked = request(
+        &mut first,
+        &mut first_rx,
+        json!({"type": "ack", "id": "a", "subscriptionId": subscription_id, "cursor": 2}),
+    )
+    .await;
+    let token = acked["result"]["resumeToken"].as_str().unwrap().to_owned();
+    first.cleanup().await;
+    drop(first);
+
+    let (tx, mut rx) = mpsc::channel(OUTBOUND_QUEUE_CAPACITY);
+    let mut second = EngineWsSession::new(
+        "client-1".to_owned(),
+        ctx,
+        tx,
+        Arc::new(tokio::sync::Mutex::new(BTreeMap::new())),
+        CancellationToken::new(),
+    );
+    let resumed = request(
+        &mut second,
+        &mut rx,
+        json!({"type": "resume", "id": "r", "token": token}),
+    )
+    .await;
+    assert_eq!(resumed["ok"], true);
+    let subscription = &resumed["result"]["subscriptions"][0];
+    assert_eq!(subscription["topic"], "events.session");
+    assert_eq!(subscription["cursor"], 2);
+
+    let page = request(
+        &mut second,
+        &mut rx,
+        json!({"type": "poll", "id": "p", "subscriptionId": subscription["subscriptionId"]}),
+    )
+    .await;
+    let cursors = page["result"]["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["cursor"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(cursors, [3]);
+}
+
+#[tokio::test]
+async fn resume_rejects_malformed_token() {
+    let (mut session, mut rx) = test_session();
+    let response = request(
+        &mut session,
+        &mut rx,
+        json!({"type": "resume", "id": "r", "token": "not a token"}),
+    )
+    .await;
+    assert_eq!(response["ok"], false);
+    assert!(response.to_string().contains("INVALID_RESUME_TOKEN"));
+    assert!(session.subscriptions.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn failed_resume_closes_subscriptions_it_already_opened() {
+    let (mut session, mut rx) = test_session();
+    let entry = |topic: &str| SubscriptionState {
+        topic: topic.to_owned(),
+        cursor: StreamCursor(0),
+        acked_cursor: StreamCursor(0),
+        filters: None,
+        session_id: Some("s1".to_owned()),
+        workspace_id: None,
+    };
+    let token = super::resume::encode_resume_token(&[entry("events.session"), entry(" ")]).unwrap();
+
+    let response = request(
+        &mut session,
+        &mut rx,
+        json!({"type": "resume", "id": "r", "token": token}),
+    )
+    .await;
+
+    assert_eq!(response["ok"], false);
+    assert!(
+        response
+            .to_string()
+            .contains("stream topic must not be empty")
+    );
+    assert!(session.subscriptions.lock().await.is_empty());
+}
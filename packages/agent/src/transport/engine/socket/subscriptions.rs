@@ -8,7 +8,8 @@ use tokio_util::sync::CancellationToken;
 use crate::engine::{StreamActorScope, StreamCursor};
 use crate::shared::server::context::ServerRuntimeContext;
 use crate::shared::server::error_mapping::engine_error_to_capability_error;
-use crate::shared::server::errors::INVALID_PARAMS;
+use crate::shared::server::errors::{CapabilityError, INVALID_PARAMS};
+use crate::transport::engine::EngineTransportContext;
 
 use super::outbound::send_engine_ws_value_async;
 use super::stream_projection::{
@@ -20,7 +21,10 @@ use super::{EngineWsSession, PUSH_POLL_INTERVAL, STREAM_MAX_LIMIT};
 #[derive(Clone, Debug)]
 pub(super) struct SubscriptionState {
     pub(super) topic: String,
+    /// Latest cursor delivered (or skipped by filters) to this connection.
     pub(super) cursor: StreamCursor,
+    /// Latest cursor the client acknowledged; resume tokens restart here.
+    pub(super) acked_cursor: StreamCursor,
     pub(super) filters: Option<Value>,
     pub(super) session_id: Option<String>,
     pub(super) workspace_id: Option<String>,
@@ -62,47 +66,68 @@ impl EngineWsSession {
             },
         };
         let context = self.merged_context(message.context);
-        let subscription_id = format!("engine-ws:{}:{}", self.client_id, uuid::Uuid::now_v7());
-        let visibility = visibility_for_context(&context);
-        match self
-            .ctx
-            .engine_host
-            .subscribe_stream(
-                subscription_id.clone(),
-                message.topic.clone(),
-                cursor,
-                visibility,
-                context.session_id.clone(),
-                context.workspace_id.clone(),
-            )
-            .await
-        {
-            Ok(subscription) => {
-                self.subscriptions.lock().await.insert(
-                    subscription_id.clone(),
-                    SubscriptionState {
-                        topic: message.topic.clone(),
-                        cursor,
-                        filters: message.filters,
-                        session_id: context.session_id,
-                        workspace_id: context.workspace_id,
-                    },
-                );
+        let state = SubscriptionState {
+            topic: message.topic,
+            cursor,
+            acked_cursor: cursor,
+            filters: message.filters,
+            session_id: context.session_id,
+            workspace_id: context.workspace_id,
+        };
+        match self.open_subscription(state).await {
+            Ok((subscription_id, state)) => {
+                let resume_token = match self.current_resume_token().await {
+                    Ok(token) => token,
+                    Err(error) => return self.send_error(message.id, error),
+                };
                 self.send_success(
                     message.id,
                     json!({
-                        "subscriptionId": subscription.subscription_id,
-                        "topic": subscription.topic,
-                        "cursor": subscription.cursor.0,
+                        "subscriptionId": subscription_id,
+                        "topic": state.topic,
+                        "cursor": state.cursor.0,
                         "limit": limit,
+                        "resumeToken": resume_token,
                     }),
                     None,
                 )
             }
-            Err(error) => self.send_error(message.id, engine_error_to_capability_error(error)),
+            Err(error) => self.send_error(message.id, error),
         }
     }
 
+    /// Register a live engine stream subscription and track it on this socket.
+    pub(super) async fn open_subscription(
+        &self,
+        state: SubscriptionState,
+    ) -> Result<(String, SubscriptionState), CapabilityError> {
+        let subscription_id = format!("engine-ws:{}:{}", self.client_id, uuid::Uuid::now_v7());
+        let visibility = visibility_for_context(&EngineTransportContext {
+            session_id: state.session_id.clone(),
+            workspace_id: state.workspace_id.clone(),
+            trace_id: None,
+            parent_invocation_id: None,
+        });
+        let _ = self
+            .ctx
+            .engine_host
+            .subscribe_stream(
+                subscription_id.clone(),
+                state.topic.clone(),
+                state.cursor,
+                visibility,
+                state.session_id.clone(),
+                state.workspace_id.clone(),
+            )
+            .await
+            .map_err(engine_error_to_capability_error)?;
+        self.subscriptions
+            .lock()
+            .await
+            .insert(subscription_id.clone(), state.clone());
+        Ok((subscription_id, state))
+    }
+
     pub(super) async fn handle_poll(&mut self, id: Option<String>, value: Value) -> bool {
         let message = match serde_json::from_value::<PollMessage>(value) {
             Ok(message) => message,
@@ -265,7 +290,7 @@ impl EngineWsSession {
                 );
             }
         };
-        {
+        let resume_token = {
             let mut subscriptions = self.subscriptions.lock().await;
             let Some(subscription) = subscriptions.get_mut(&message.subscription_id) else {
                 return self.send_error(
@@ -280,8 +305,15 @@ impl EngineWsSession {
                     ),
                 );
             };
-            subscription.cursor = std::cmp::max(subscription.cursor, StreamCursor(message.cursor));
-        }
+            let acked = StreamCursor(message.cursor);
+            subscription.cursor = std::cmp::max(subscription.cursor, acked);
+            subscription.acked_cursor = std::cmp::max(subscription.acked_cursor, acked);
+            super::resume::encode_resume_token(subscriptions.values())
+        };
+        let resume_token = match resume_token {
+            Ok(token) => token,
+            Err(error) => return self.send_error(message.id, error),
+        };
         if let Err(error) = self
             .ctx
             .engine_host
@@ -296,6 +328,7 @@ impl EngineWsSession {
                 "acknowledged": true,
                 "subscriptionId": message.subscription_id,
                 "cursor": message.cursor,
+                "resumeToken": resume_token,
             }),
             None,
         )
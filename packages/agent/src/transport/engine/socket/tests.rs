@@ -7,7 +7,7 @@ use crate::shared::server::failure::ENGINE_SCHEMA_VIOLATION;
 use crate::shared::server::test_support::make_test_context;
 use serde_json::json;
 
-fn test_session() -> (EngineWsSession, mpsc::Receiver<String>) {
+pub(super) fn test_session() -> (EngineWsSession, mpsc::Receiver<String>) {
     let ctx = Arc::new(make_test_context());
     let (tx, rx) = mpsc::channel(OUTBOUND_QUEUE_CAPACITY);
     (
@@ -351,6 +351,7 @@ async fn ack_response_applies_backpressure_instead_of_closing_socket() {
             SubscriptionState {
                 topic: "events.session".to_owned(),
                 cursor: StreamCursor(0),
+                acked_cursor: StreamCursor(0),
                 filters: None,
                 session_id: Some("s1".to_owned()),
                 workspace_id: None,
@@ -449,6 +450,7 @@ async fn push_subscription_advances_past_filtered_stream_pages() {
         SubscriptionState {
             topic: "events.session".to_owned(),
             cursor: StreamCursor(0),
+            acked_cursor: StreamCursor(0),
             filters: Some(json!({"sessionId": target_session})),
             session_id: Some(target_session.to_owned()),
             workspace_id: None,
@@ -552,6 +554,7 @@ async fn push_subscription_applies_backpressure_to_catch_up_bursts() {
         SubscriptionState {
             topic: "events.session".to_owned(),
             cursor: StreamCursor(0),
+            acked_cursor: StreamCursor(0),
             filters: Some(json!({"sessionId": target_session})),
             session_id: Some(target_session.to_owned()),
             workspace_id: None,
@@ -589,3 +592,35 @@ async fn push_subscription_applies_backpressure_to_catch_up_bursts() {
     push_task.await.unwrap();
     assert_eq!(delivered, total_events);
 }
+
+#[tokio::test]
+async fn discover_describes_each_public_method_with_its_request_schema() {
+    let (mut session, mut rx) = test_session();
+    assert!(
+        session
+            .handle_text(r#"{"type":"discover","id":"d1","request":{"namespacePrefix":"engine"}}"#)
+            .await
+    );
+    let response: Value = serde_json::from_str(&rx.recv().await.unwrap()).unwrap();
+    assert_eq!(response["ok"], true, "{response}");
+    let functions = response["result"]["functions"].as_array().unwrap();
+    let described = |function_id: &str| {
+        functions
+            .iter()
+            .find(|function| function["id"] == function_id)
+            .unwrap_or_else(|| panic!("{function_id} missing from discover"))
+    };
+
+    for spec in crate::transport::engine::contracts::public_engine_transport_specs().unwrap() {
+        let function = described(spec.function_id.as_str());
+        assert!(
+            !function["description"].as_str().unwrap().is_empty(),
+            "{function}"
+        );
+        assert_eq!(function["request_schema"]["type"], "object", "{function}");
+    }
+    assert_eq!(
+        described("engine::inspect")["request_schema"]["required"],
+        json!(["kind", "id"])
+    );
+}
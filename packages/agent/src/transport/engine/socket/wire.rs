@@ -116,6 +116,15 @@ pub(super) struct AckMessage {
     pub(super) cursor: u64,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(super) struct ResumeMessage {
+    #[serde(rename = "type")]
+    pub(super) _message_type: String,
+    pub(super) id: Option<String>,
+    pub(super) token: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub(super) struct HeartbeatMessage {
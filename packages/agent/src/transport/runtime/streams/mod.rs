@@ -11,11 +11,16 @@
 //! the persisted engine stream row and the neutral payload so observability can
 //! follow an agent turn through streamed UI events, capability invocation, queues, and
 //! downstream capabilities.
+//! When the in-process broadcast lags, the dropped events never reach the
+//! durable stream, so the pump publishes one `stream.resync_required` event
+//! (system scope, with the dropped count) telling clients to re-read session
+//! state rather than render a stream with silent gaps.
 
 use std::sync::Arc;
 
 use crate::engine::{EngineHostHandle, InvocationId, PublishStreamEvent, TraceId, VisibilityScope};
 use crate::shared::protocol::events::{TronEvent, TronEventObserver};
+use crate::shared::server::events::ServerEventPayload;
 use serde_json::json;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
@@ -31,6 +36,10 @@ mod streaming;
 mod tron;
 mod turn;
 
+/// Server event published after the pump drops lagged runtime events.
+pub(crate) const RESYNC_REQUIRED_EVENT: &str = "stream.resync_required";
+const EVENTS_TOPIC: &str = "events.session";
+
 /// Projects orchestrator events into engine streams.
 pub struct EngineStreamEventPump {
     rx: broadcast::Receiver<TronEvent>,
@@ -87,6 +96,7 @@ impl EngineStreamEventPump {
                 tracing::debug!(lagged = n, "stream projection lagged");
                 metrics::counter!("stream_projection_lagged_events_total", "source" => "engine_stream_event_pump")
                     .increment(n);
+                self.publish_resync_required(n).await;
                 true
             }
             Err(broadcast::error::RecvError::Closed) => {
@@ -118,7 +128,7 @@ impl EngineStreamEventPump {
         if let Err(error) = self
             .engine_streams
             .publish_stream_event(PublishStreamEvent {
-                topic: "events.session".to_owned(),
+                topic: EVENTS_TOPIC.to_owned(),
                 payload: json!({
                     "serverEvent": projected.server_event.clone(),
                     "streamScope": stream_scope_payload(&projected.scope),
@@ -141,6 +151,37 @@ impl EngineStreamEventPump {
             );
         }
     }
+
+    async fn publish_resync_required(&self, dropped: u64) {
+        let server_event = ServerEventPayload::new(
+            RESYNC_REQUIRED_EVENT,
+            None,
+            Some(json!({
+                "droppedEvents": dropped,
+                "reason": "stream_projection_lagged",
+            })),
+        );
+        if let Err(error) = self
+            .engine_streams
+            .publish_stream_event(PublishStreamEvent {
+                topic: EVENTS_TOPIC.to_owned(),
+                payload: json!({
+                    "serverEvent": server_event,
+                    "streamScope": stream_scope_payload(&StreamScope::All),
+                    "sourceEventType": RESYNC_REQUIRED_EVENT,
+                }),
+                visibility: VisibilityScope::System,
+                session_id: None,
+                workspace_id: None,
+                producer: "agent-runtime".to_owned(),
+                trace_id: None,
+                parent_invocation_id: None,
+            })
+            .await
+        {
+            tracing::warn!(error = %error, "engine stream publish failed; dropping resync signal");
+        }
+    }
 }
 
 fn stream_scope_payload(scope: &StreamScope) -> serde_json::Value {
@@ -91,6 +91,13 @@ pub(super) fn convert(event: &TronEvent) -> Option<ProjectedEvent> {
             };
             Some(global(event, wire_type, Some(json!({}))))
         }
+        TronEvent::SessionIdleTimeout {
+            idle_timeout_secs, ..
+        } => Some(global(
+            event,
+            "session.idle_timeout",
+            Some(json!({ "idleTimeoutSecs": idle_timeout_secs })),
+        )),
         _ => None,
     }
 }
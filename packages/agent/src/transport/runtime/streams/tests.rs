@@ -185,6 +185,61 @@ async fn stream_scope_prevents_cross_session_delivery() {
     assert!(page.events.is_empty());
 }
 
+#[tokio::test]
+async fn lagged_pump_publishes_one_resync_signal_before_remaining_events() {
+    let (tx, rx) = broadcast::channel(2);
+    let host = EngineHostHandle::new_in_memory().unwrap();
+    host.subscribe_stream(
+        "runtime-events".to_owned(),
+        "events.session".to_owned(),
+        StreamCursor(0),
+        VisibilityScope::System,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    for _ in 0..5 {
+        tx.send(agent_start_event("s1")).unwrap();
+    }
+    let cancel = CancellationToken::new();
+    let pump = EngineStreamEventPump::new(rx, host.clone(), cancel.clone(), noop_observer());
+    let handle = tokio::spawn(pump.run());
+
+    let mut events = Vec::new();
+    for _ in 0..50 {
+        events = host
+            .poll_stream(
+                "runtime-events",
+                Some(StreamCursor(0)),
+                10,
+                &StreamActorScope::admin(),
+            )
+            .await
+            .unwrap()
+            .events;
+        if events.len() >= 3 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    cancel.cancel();
+    let _ = handle.await;
+
+    let types = events
+        .iter()
+        .map(|event| event.payload["serverEvent"]["type"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        vec![RESYNC_REQUIRED_EVENT, "agent.start", "agent.start"]
+    );
+    let resync = &events[0];
+    assert_eq!(resync.visibility, VisibilityScope::System);
+    assert_eq!(resync.payload["serverEvent"]["data"]["droppedEvents"], 3);
+    assert_eq!(resync.payload["streamScope"]["kind"], "all");
+}
+
 async fn poll_until_event(
     host: &EngineHostHandle,
     subscription_id: &str,
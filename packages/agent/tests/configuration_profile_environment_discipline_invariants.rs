@@ -180,6 +180,10 @@ fn source_backed_key_configuration_catalog() -> BTreeMap<String, String> {
             "server.heartbeatIntervalMs".to_owned(),
             defaults.server.heartbeat_interval_ms.to_string(),
         ),
+        (
+            "server.heartbeatTimeoutMs".to_owned(),
+            defaults.server.heartbeat_timeout_ms.to_string(),
+        ),
         (
             "server.defaultProvider".to_owned(),
             defaults.server.default_provider,
@@ -214,6 +218,14 @@ fn source_backed_key_configuration_catalog() -> BTreeMap<String, String> {
             "agent.maxTurns".to_owned(),
             defaults.agent.max_turns.to_string(),
         ),
+        (
+            "agent.maxCostUsd".to_owned(),
+            defaults.agent.max_cost_usd.to_string(),
+        ),
+        (
+            "agent.processFileRootJail".to_owned(),
+            defaults.agent.process_file_root_jail.to_string(),
+        ),
         (
             "context.compactor.maxTokens".to_owned(),
             defaults.context.compactor.max_tokens.to_string(),
@@ -247,6 +259,26 @@ fn source_backed_key_configuration_catalog() -> BTreeMap<String, String> {
             "context.compactor.preserveRecentCount".to_owned(),
             defaults.context.compactor.preserve_recent_count.to_string(),
         ),
+        (
+            "context.systemContextTokenCeiling".to_owned(),
+            defaults.context.system_context_token_ceiling.to_string(),
+        ),
+        (
+            "context.pinnedContextBlocks".to_owned(),
+            serde_json::to_string(&defaults.context.pinned_context_blocks).unwrap(),
+        ),
+        (
+            "context.pressureWarningThresholds".to_owned(),
+            serde_json::to_string(&defaults.context.pressure_warning_thresholds).unwrap(),
+        ),
+        (
+            "context.tokenEstimator".to_owned(),
+            serde_json::to_value(defaults.context.token_estimator)
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .to_owned(),
+        ),
         (
             "observability.logLevel".to_owned(),
             defaults.observability.log_level.as_filter_str().to_owned(),
@@ -263,11 +295,26 @@ fn source_backed_key_configuration_catalog() -> BTreeMap<String, String> {
             "storage.maxDatabaseMb".to_owned(),
             defaults.storage.max_database_mb.to_string(),
         ),
+        (
+            "storage.redactedPayloadKeys".to_owned(),
+            serde_json::to_string(&defaults.storage.redacted_payload_keys).unwrap(),
+        ),
         (
             "retry.maxRetries".to_owned(),
             defaults.retry.max_retries.to_string(),
         ),
-        ("session".to_owned(), "{}".to_owned()),
+        (
+            "retry.circuitBreaker.failureThreshold".to_owned(),
+            defaults.retry.circuit_breaker.failure_threshold.to_string(),
+        ),
+        (
+            "retry.circuitBreaker.cooldownMs".to_owned(),
+            defaults.retry.circuit_breaker.cooldown_ms.to_string(),
+        ),
+        (
+            "session.idleTimeoutSecs".to_owned(),
+            defaults.session.idle_timeout_secs.to_string(),
+        ),
     ])
 }
 
@@ -559,7 +606,8 @@ fn environment_override_surface_is_explicit_and_owned() {
         );
     }
 
-    let loader = read_repo_file("packages/agent/src/domains/settings/profile/storage/loader.rs");
+    let env_overrides =
+        read_repo_file("packages/agent/src/domains/settings/profile/storage/env_overrides.rs");
     for required in [
         "TRON_DEFAULT_MODEL",
         "TRON_DEFAULT_PROVIDER",
@@ -568,7 +616,7 @@ fn environment_override_surface_is_explicit_and_owned() {
         "parse_u64_range",
     ] {
         assert!(
-            loader.contains(required),
+            env_overrides.contains(required),
             "settings env override missing {required}"
         );
     }
@@ -684,6 +732,22 @@ fn ios_user_editable_settings_have_decode_update_state_and_ui_coverage() {
             ui_marker: "updateServerSetting(.compactionPreserveRecentCount(newValue))",
             parity_marker: "\"preserveRecentCount\"",
         },
+        EditableSetting {
+            rust_path: "context.pressureWarningThresholds",
+            dto_marker: "let contextPressureWarningThresholds: [Double]",
+            update_marker: "case .contextPressureWarningThresholds(let thresholds)",
+            state_marker: "var contextPressureWarningThresholds: [Double]",
+            ui_marker: "updateServerSetting(.contextPressureWarningThresholds(newValue))",
+            parity_marker: "\"contextPressureWarningThresholds\"",
+        },
+        EditableSetting {
+            rust_path: "context.systemContextTokenCeiling",
+            dto_marker: "let contextSystemContextTokenCeiling: UInt64",
+            update_marker: "case .contextSystemContextTokenCeiling(let tokens)",
+            state_marker: "var contextSystemContextTokenCeiling: UInt64",
+            ui_marker: "updateServerSetting(.contextSystemContextTokenCeiling(newValue))",
+            parity_marker: "\"contextSystemContextTokenCeiling\"",
+        },
         EditableSetting {
             rust_path: "context.compactor.triggerTokenThreshold",
             dto_marker: "let triggerTokenThreshold: Double",
@@ -724,6 +788,30 @@ fn ios_user_editable_settings_have_decode_update_state_and_ui_coverage() {
             ui_marker: "updateServerSetting(.storageMaxDatabaseMb(clamped))",
             parity_marker: "\"storageMaxDatabaseMb\"",
         },
+        EditableSetting {
+            rust_path: "agent.maxCostUsd",
+            dto_marker: "let agentMaxCostUsd: Double",
+            update_marker: "case .agentMaxCostUsd(let dollars)",
+            state_marker: "var agentMaxCostUsd: Double",
+            ui_marker: "updateServerSetting(.agentMaxCostUsd(clamped))",
+            parity_marker: "\"agentMaxCostUsd\"",
+        },
+        EditableSetting {
+            rust_path: "agent.processFileRootJail",
+            dto_marker: "let agentProcessFileRootJail: Bool",
+            update_marker: "case .agentProcessFileRootJail(let enabled)",
+            state_marker: "var agentProcessFileRootJail: Bool",
+            ui_marker: "updateServerSetting(.agentProcessFileRootJail(newValue))",
+            parity_marker: "\"agentProcessFileRootJail\"",
+        },
+        EditableSetting {
+            rust_path: "session.idleTimeoutSecs",
+            dto_marker: "let sessionIdleTimeoutSecs: UInt64",
+            update_marker: "case .sessionIdleTimeoutSecs(let seconds)",
+            state_marker: "var sessionIdleTimeoutSecs: UInt64",
+            ui_marker: "updateServerSetting(.sessionIdleTimeoutSecs(clamped))",
+            parity_marker: "\"sessionIdleTimeoutSecs\"",
+        },
         EditableSetting {
             rust_path: "server.transcription.enabled",
             dto_marker: "let transcriptionEnabled: Bool",
@@ -732,6 +820,22 @@ fn ios_user_editable_settings_have_decode_update_state_and_ui_coverage() {
             ui_marker: "updateServerSetting(.transcriptionEnabled(newValue))",
             parity_marker: "\"transcriptionEnabled\"",
         },
+        EditableSetting {
+            rust_path: "retry.circuitBreaker.failureThreshold",
+            dto_marker: "let retryCircuitBreakerFailureThreshold: UInt32",
+            update_marker: "case .retryCircuitBreakerFailureThreshold(let failures)",
+            state_marker: "var retryCircuitBreakerFailureThreshold: UInt32",
+            ui_marker: "updateServerSetting(.retryCircuitBreakerFailureThreshold(clamped))",
+            parity_marker: "\"retryCircuitBreakerFailureThreshold\"",
+        },
+        EditableSetting {
+            rust_path: "retry.circuitBreaker.cooldownMs",
+            dto_marker: "let retryCircuitBreakerCooldownMs: UInt64",
+            update_marker: "case .retryCircuitBreakerCooldownMs(let milliseconds)",
+            state_marker: "var retryCircuitBreakerCooldownMs: UInt64",
+            ui_marker: "updateServerSetting(.retryCircuitBreakerCooldownMs(clamped))",
+            parity_marker: "\"retryCircuitBreakerCooldownMs\"",
+        },
     ];
 
     let readme_catalog = source_backed_key_configuration_catalog();
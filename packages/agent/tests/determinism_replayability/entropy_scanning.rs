@@ -79,6 +79,7 @@ fn replay_critical_entropy_is_allow_listed() {
                 "packages/agent/src/domains/agent/",
                 "packages/agent/src/domains/auth/oauth/",
                 "packages/agent/src/domains/capability/operations/",
+                "packages/agent/src/domains/goals/expiry.rs",
                 "packages/agent/src/domains/jobs/",
                 "packages/agent/src/domains/model/",
                 "packages/agent/src/domains/settings/",
@@ -86,6 +87,7 @@ fn replay_critical_entropy_is_allow_listed() {
                 "packages/agent/src/domains/session/event_store/sqlite/connection.rs",
                 "packages/agent/src/domains/session/event_store/sqlite/contention.rs",
                 "packages/agent/src/domains/transcription/",
+                "packages/agent/src/engine/catalog/registry/invocation.rs",
                 "packages/agent/src/platform/",
                 "packages/agent/src/shared/server/",
             ],
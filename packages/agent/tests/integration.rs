@@ -40,6 +40,14 @@ fn unique_home(root: &Path) -> PathBuf {
 }
 
 async fn boot_server() -> TestServer {
+    boot_server_with_config(ServerConfig {
+        host: "127.0.0.1".to_owned(),
+        ..ServerConfig::default()
+    })
+    .await
+}
+
+async fn boot_server_with_config(config: ServerConfig) -> TestServer {
     let temp = tempfile::tempdir().unwrap();
     let home = unique_home(temp.path());
     let db_path = temp.path().join("tron.sqlite");
@@ -87,14 +95,7 @@ async fn boot_server() -> TestServer {
     let metrics_handle = metrics_exporter_prometheus::PrometheusBuilder::new()
         .build_recorder()
         .handle();
-    let server = Arc::new(TronServer::new(
-        ServerConfig {
-            host: "127.0.0.1".to_owned(),
-            ..ServerConfig::default()
-        },
-        runtime_context,
-        metrics_handle,
-    ));
+    let server = Arc::new(TronServer::new(config, runtime_context, metrics_handle));
     tron::transport::runtime::EngineRuntimeServices::start(&server);
     let (addr, _handle) = server.listen().await.unwrap();
 
@@ -261,3 +262,64 @@ async fn session_create_reconstruct_and_public_execute_fails_closed() {
 
     runtime.server.shutdown().shutdown();
 }
+
+async fn boot_fast_heartbeat_server() -> TestServer {
+    boot_server_with_config(ServerConfig {
+        host: "127.0.0.1".to_owned(),
+        heartbeat_interval_ms: 100,
+        heartbeat_timeout_ms: 300,
+        ..ServerConfig::default()
+    })
+    .await
+}
+
+#[tokio::test]
+async fn engine_socket_closes_client_that_stops_ponging() {
+    let server = boot_fast_heartbeat_server().await;
+    let mut ws = connect(&server.url, &server.auth_path).await;
+
+    // Not polling the stream means tungstenite never answers the server pings.
+    // The server closes after ~400ms and keeps the socket for a short close
+    // handshake grace, so the client still reads the close code on wake-up.
+    tokio::time::sleep(Duration::from_millis(700)).await;
+
+    let close = loop {
+        match timeout(TIMEOUT, ws.next())
+            .await
+            .expect("timeout waiting for close")
+        {
+            Some(Ok(Message::Close(frame))) => break frame.expect("close frame"),
+            Some(Ok(_)) => {}
+            other => panic!("expected close frame, got {other:?}"),
+        }
+    };
+    assert_eq!(
+        u16::from(close.code),
+        tron::transport::engine::socket::HEARTBEAT_TIMEOUT_CLOSE_CODE
+    );
+    assert_eq!(close.reason.as_str(), "heartbeat timeout");
+}
+
+#[tokio::test]
+async fn engine_socket_keeps_ponging_client_open() {
+    let server = boot_fast_heartbeat_server().await;
+    let mut ws = connect(&server.url, &server.auth_path).await;
+
+    // Reading drives automatic pong replies for every server ping.
+    let deadline = Instant::now() + Duration::from_millis(800);
+    let mut pings = 0;
+    while let Ok(frame) = tokio::time::timeout_at(deadline.into(), ws.next()).await {
+        match frame {
+            Some(Ok(Message::Ping(_))) => pings += 1,
+            other => panic!("connection should stay open, got {other:?}"),
+        }
+    }
+    assert!(pings >= 3, "expected repeated pings, saw {pings}");
+
+    ws.send(Message::Text(
+        json!({"type": "heartbeat", "id": "hb"}).to_string().into(),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(read_json(&mut ws).await["type"], "heartbeat.ack");
+}
@@ -454,6 +454,20 @@ fn settings_parity_sparse_update_and_decode_exception_are_source_guarded() {
             "compactionTriggerTokenThreshold",
             "ContextSettingsPage.swift",
         ),
+        (
+            "pressureWarningThresholds",
+            "contextPressureWarningThresholds",
+            "contextPressureWarningThresholds",
+            "contextPressureWarningThresholds",
+            "ContextSettingsPage.swift",
+        ),
+        (
+            "systemContextTokenCeiling",
+            "contextSystemContextTokenCeiling",
+            "contextSystemContextTokenCeiling",
+            "contextSystemContextTokenCeiling",
+            "ContextSettingsPage.swift",
+        ),
         (
             "logLevel",
             "observabilityLogLevel",
@@ -482,6 +496,41 @@ fn settings_parity_sparse_update_and_decode_exception_are_source_guarded() {
             "storageMaxDatabaseMb",
             "ConnectionSettingsPage.swift",
         ),
+        (
+            "maxCostUsd",
+            "agentMaxCostUsd",
+            "agentMaxCostUsd",
+            "agentMaxCostUsd",
+            "AgentSettingsPage.swift",
+        ),
+        (
+            "processFileRootJail",
+            "agentProcessFileRootJail",
+            "agentProcessFileRootJail",
+            "agentProcessFileRootJail",
+            "AgentSettingsPage.swift",
+        ),
+        (
+            "idleTimeoutSecs",
+            "sessionIdleTimeoutSecs",
+            "sessionIdleTimeoutSecs",
+            "sessionIdleTimeoutSecs",
+            "ConnectionSettingsPage.swift",
+        ),
+        (
+            "failureThreshold",
+            "retryCircuitBreakerFailureThreshold",
+            "retryCircuitBreakerFailureThreshold",
+            "retryCircuitBreakerFailureThreshold",
+            "ConnectionSettingsPage.swift",
+        ),
+        (
+            "cooldownMs",
+            "retryCircuitBreakerCooldownMs",
+            "retryCircuitBreakerCooldownMs",
+            "retryCircuitBreakerCooldownMs",
+            "ConnectionSettingsPage.swift",
+        ),
     ];
 
     for (wire_key, dto_field, state_field, mutation_name, page) in editable_settings {
@@ -523,9 +523,9 @@ fn fresh_session_store_has_no_product_tables_or_old_shape_migrations() {
     assert_absent(
         &migration_runner,
         &[
-            "v002_",
-            "v004_",
-            "v005_",
+            "v002_constitution_audit",
+            "v004_session_profile",
+            "v005_drop_profile_migrations",
             "migrated v001",
             "historical-shape",
             "already recorded v001",
@@ -325,7 +325,7 @@ fn pmc_inventory_is_structured_and_covers_removed_and_retained_surfaces() {
         "packages/agent/src/domains/model/providers/anthropic/types/mod.rs",
         "packages/agent/src/domains/model/providers/anthropic/message_converter/mod.rs",
         "packages/agent/src/domains/model/providers/anthropic/provider/mod.rs",
-        "packages/agent/src/domains/model/providers/google/stream_handler.rs",
+        "packages/agent/src/domains/model/providers/google/stream_handler/mod.rs",
         "packages/agent/src/domains/model/providers/shared/sse.rs",
         "packages/agent/src/domains/model/providers/shared/stream_pipeline.rs",
         "packages/agent/src/engine/authority/leases.rs",
@@ -407,7 +407,7 @@ fn deleted_runtime_residue_remains_absent_and_replacements_remain_owned() {
     }
 
     let google_stream =
-        read_repo_file("packages/agent/src/domains/model/providers/google/stream_handler.rs");
+        read_repo_file("packages/agent/src/domains/model/providers/google/stream_handler/mod.rs");
     for removed in ["completed_tool_ids", "synthesize_done_event"] {
         assert!(
             !google_stream.contains(removed),
@@ -344,7 +344,7 @@ fn provider_family_stream_and_catalog_tests_remain_present() {
             "malformed_arguments_fails_closed",
         ),
         (
-            "packages/agent/src/domains/model/providers/google/stream_handler.rs",
+            "packages/agent/src/domains/model/providers/google/stream_handler/tests/mod.rs",
             "non_object_function_call_arguments_fail_closed",
         ),
         (
@@ -3,17 +3,25 @@ use super::support::*;
 #[test]
 fn sol_observability_recovery_lifecycle_is_source_backed() {
     let server = read_repo_file("packages/agent/src/app/bootstrap/server.rs");
-    for required in [
-        ".route(\"/health\", get(health_handler))",
-        ".route(\"/metrics\", get(metrics_handler))",
-        ".route(\"/health/deep\", get(deep_health_handler))",
-        "health::health_check(state.start_time, connections, sessions)",
-        "run_blocking(\"http.health.deep\"",
-        "health::deep_health_check(",
-        "state.metrics_handle.render()",
+    let liveness = read_repo_file("packages/agent/src/app/bootstrap/server/liveness.rs");
+    let metrics = read_repo_file("packages/agent/src/app/bootstrap/server/metrics_route.rs");
+    for (source, required) in [
+        (&server, ".route(\"/health\", get(health_handler))"),
+        (&server, ".route(\"/metrics\", get(metrics_handler))"),
+        (
+            &server,
+            ".route(\"/health/deep\", get(deep_health_handler))",
+        ),
+        (
+            &liveness,
+            "health::health_check(state.start_time, connections, sessions)",
+        ),
+        (&liveness, "run_blocking(\"http.health.deep\""),
+        (&liveness, "health::deep_health_check("),
+        (&metrics, "state.metrics_handle.render()"),
     ] {
         assert!(
-            server.contains(required),
+            source.contains(required),
             "server observability route missing `{required}`"
         );
     }
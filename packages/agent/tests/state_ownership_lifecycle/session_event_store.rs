@@ -216,7 +216,7 @@ fn sol_session_event_store_lifecycle_is_source_backed() {
         "normalize_working_directory",
         "create_session(&model, &stored_working_directory",
         "TronEvent::SessionCreated",
-        "init_sequence_counter(&session_id, 0)",
+        "init_sequence_counter(&session_id, last_sequence)",
     ] {
         assert!(
             lifecycle_create.contains(required),
@@ -133,6 +133,7 @@ final class EventRegistry: @unchecked Sendable {
         // Server events
         register(ServerRestartingPlugin.self)
         register(AuthUpdatedPlugin.self)
+        register(StreamResyncRequiredPlugin.self)
 
         // Display streaming events
         register(DisplayFramePlugin.self)
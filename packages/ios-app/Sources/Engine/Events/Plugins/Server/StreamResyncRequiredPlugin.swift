@@ -0,0 +1,51 @@
+import Foundation
+
+/// Plugin for handling stream resync notices.
+/// The server broadcasts this global event (no sessionId) when its runtime
+/// event pump lagged and dropped events before they reached the engine
+/// stream. Live deltas may have gaps, so the client re-reads session state.
+enum StreamResyncRequiredPlugin: DispatchableEventPlugin {
+    static let eventType = "stream.resync_required"
+
+    // MARK: - Event Data
+
+    struct EventData: StandardEventData {
+        let type: String
+        let timestamp: String?
+        let data: DataPayload?
+
+        /// Resync notices are global — no session scope.
+        var sessionId: String? { nil }
+
+        struct DataPayload: Decodable, Sendable {
+            let droppedEvents: Int?
+            let reason: String?
+        }
+    }
+
+    // MARK: - Result
+
+    struct Result: EventResult {
+        let droppedEvents: Int
+        let reason: String
+    }
+
+    // MARK: - Protocol Implementation
+
+    static func sessionId(from event: EventData) -> String? {
+        nil
+    }
+
+    static func transform(_ event: EventData) -> (any EventResult)? {
+        Result(
+            droppedEvents: event.data?.droppedEvents ?? 0,
+            reason: event.data?.reason ?? "unknown"
+        )
+    }
+
+    @MainActor
+    static func dispatch(result: any EventResult, context: any EventDispatchTarget) {
+        guard let result = result as? Result else { return }
+        context.handleStreamResyncRequired(result)
+    }
+}
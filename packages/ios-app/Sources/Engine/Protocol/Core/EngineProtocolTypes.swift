@@ -192,6 +192,10 @@ enum EngineErrorCode: String, CaseIterable, Sendable {
     case authOauthError = "AUTH_OAUTH_ERROR"
     case authStorageError = "AUTH_STORAGE_ERROR"
     case authTransportError = "AUTH_TRANSPORT_ERROR"
+
+    // Typed model-selection errors — mirror the server's model.switch validation.
+    case modelUnknown = "MODEL_UNKNOWN"
+    case modelNotAvailable = "MODEL_NOT_AVAILABLE"
 }
 
 /// Structured engine protocol error details.
@@ -12,24 +12,45 @@ struct ServerSettings: Decodable {
     let defaultWorkspace: String?
     let tailscaleIp: String?
 
+    let agentMaxCostUsd: Double
+    let agentProcessFileRootJail: Bool
+
+    let retryCircuitBreakerFailureThreshold: UInt32
+    let retryCircuitBreakerCooldownMs: UInt64
+
     let compaction: CompactionSettings
+    let contextPressureWarningThresholds: [Double]
+    let contextSystemContextTokenCeiling: UInt64
 
     let observabilityLogLevel: String
     let observabilityVerboseRetentionDays: UInt64
     let storageRetentionEnabled: Bool
     let storageMaxDatabaseMb: UInt64
+    let sessionIdleTimeoutSecs: UInt64
     let transcriptionEnabled: Bool
 
     private enum CodingKeys: String, CodingKey {
-        case server, context, observability, storage
+        case server, agent, retry, context, observability, storage, session
     }
 
     private enum ServerKeys: String, CodingKey {
         case defaultProvider, defaultModel, defaultWorkspace, tailscaleIp, transcription
     }
 
+    private enum AgentKeys: String, CodingKey {
+        case maxCostUsd, processFileRootJail
+    }
+
+    private enum RetryKeys: String, CodingKey {
+        case circuitBreaker
+    }
+
+    private enum CircuitBreakerKeys: String, CodingKey {
+        case failureThreshold, cooldownMs
+    }
+
     private enum ContextKeys: String, CodingKey {
-        case compactor
+        case compactor, pressureWarningThresholds, systemContextTokenCeiling
     }
 
     private enum ObservabilityKeys: String, CodingKey {
@@ -40,6 +61,10 @@ struct ServerSettings: Decodable {
         case retentionEnabled, maxDatabaseMb
     }
 
+    private enum SessionKeys: String, CodingKey {
+        case idleTimeoutSecs
+    }
+
     private enum TranscriptionKeys: String, CodingKey {
         case enabled
     }
@@ -62,8 +87,22 @@ struct ServerSettings: Decodable {
             transcriptionEnabled = false
         }
 
+        let agentContainer = try container.nestedContainer(keyedBy: AgentKeys.self, forKey: .agent)
+        agentMaxCostUsd = try agentContainer.decode(Double.self, forKey: .maxCostUsd)
+        agentProcessFileRootJail = try agentContainer.decode(Bool.self, forKey: .processFileRootJail)
+
+        let retryContainer = try container.nestedContainer(keyedBy: RetryKeys.self, forKey: .retry)
+        let circuitBreakerContainer = try retryContainer.nestedContainer(
+            keyedBy: CircuitBreakerKeys.self,
+            forKey: .circuitBreaker
+        )
+        retryCircuitBreakerFailureThreshold = try circuitBreakerContainer.decode(UInt32.self, forKey: .failureThreshold)
+        retryCircuitBreakerCooldownMs = try circuitBreakerContainer.decode(UInt64.self, forKey: .cooldownMs)
+
         let contextContainer = try container.nestedContainer(keyedBy: ContextKeys.self, forKey: .context)
         compaction = try contextContainer.decode(CompactionSettings.self, forKey: .compactor)
+        contextPressureWarningThresholds = try contextContainer.decode([Double].self, forKey: .pressureWarningThresholds)
+        contextSystemContextTokenCeiling = try contextContainer.decode(UInt64.self, forKey: .systemContextTokenCeiling)
 
         let observabilityContainer = try container.nestedContainer(keyedBy: ObservabilityKeys.self, forKey: .observability)
         observabilityLogLevel = try observabilityContainer.decode(String.self, forKey: .logLevel)
@@ -72,6 +111,9 @@ struct ServerSettings: Decodable {
         let storageContainer = try container.nestedContainer(keyedBy: StorageKeys.self, forKey: .storage)
         storageRetentionEnabled = try storageContainer.decode(Bool.self, forKey: .retentionEnabled)
         storageMaxDatabaseMb = try storageContainer.decode(UInt64.self, forKey: .maxDatabaseMb)
+
+        let sessionContainer = try container.nestedContainer(keyedBy: SessionKeys.self, forKey: .session)
+        sessionIdleTimeoutSecs = try sessionContainer.decode(UInt64.self, forKey: .idleTimeoutSecs)
     }
 
     struct CompactionSettings: Decodable {
@@ -97,9 +139,12 @@ struct ServerSettings: Decodable {
 
 struct ServerSettingsUpdate: Encodable {
     var server: ServerUpdate?
+    var agent: AgentUpdate?
+    var retry: RetryUpdate?
     var context: ContextUpdate?
     var observability: ObservabilityUpdate?
     var storage: StorageUpdate?
+    var session: SessionUpdate?
 
     struct ServerUpdate: Encodable {
         var defaultProvider: String?
@@ -113,8 +158,24 @@ struct ServerSettingsUpdate: Encodable {
         var enabled: Bool?
     }
 
+    struct AgentUpdate: Encodable {
+        var maxCostUsd: Double?
+        var processFileRootJail: Bool?
+    }
+
+    struct RetryUpdate: Encodable {
+        var circuitBreaker: CircuitBreakerUpdate?
+
+        struct CircuitBreakerUpdate: Encodable {
+            var failureThreshold: UInt32?
+            var cooldownMs: UInt64?
+        }
+    }
+
     struct ContextUpdate: Encodable {
         var compactor: CompactorUpdate?
+        var pressureWarningThresholds: [Double]?
+        var systemContextTokenCeiling: UInt64?
 
         struct CompactorUpdate: Encodable {
             var preserveRecentCount: Int?
@@ -131,4 +192,8 @@ struct ServerSettingsUpdate: Encodable {
         var retentionEnabled: Bool?
         var maxDatabaseMb: UInt64?
     }
+
+    struct SessionUpdate: Encodable {
+        var idleTimeoutSecs: UInt64?
+    }
 }
@@ -102,10 +102,30 @@ private extension SettingsMutation {
             return ServerSettingsUpdate(server: .init(defaultWorkspace: workspace))
         case .defaultModel(let model):
             return ServerSettingsUpdate(server: .init(defaultModel: model))
+        case .agentMaxCostUsd(let dollars):
+            var update = ServerSettingsUpdate()
+            update.agent = .init(maxCostUsd: dollars)
+            return update
+        case .agentProcessFileRootJail(let enabled):
+            var update = ServerSettingsUpdate()
+            update.agent = .init(processFileRootJail: enabled)
+            return update
+        case .retryCircuitBreakerFailureThreshold(let failures):
+            var update = ServerSettingsUpdate()
+            update.retry = .init(circuitBreaker: .init(failureThreshold: failures))
+            return update
+        case .retryCircuitBreakerCooldownMs(let milliseconds):
+            var update = ServerSettingsUpdate()
+            update.retry = .init(circuitBreaker: .init(cooldownMs: milliseconds))
+            return update
         case .compactionTriggerTokenThreshold(let threshold):
             return ServerSettingsUpdate(context: .init(compactor: .init(triggerTokenThreshold: threshold)))
         case .compactionPreserveRecentCount(let count):
             return ServerSettingsUpdate(context: .init(compactor: .init(preserveRecentCount: count)))
+        case .contextPressureWarningThresholds(let thresholds):
+            return ServerSettingsUpdate(context: .init(pressureWarningThresholds: thresholds))
+        case .contextSystemContextTokenCeiling(let tokens):
+            return ServerSettingsUpdate(context: .init(systemContextTokenCeiling: tokens))
         case .observabilityLogLevel(let level):
             var update = ServerSettingsUpdate()
             update.observability = .init(logLevel: level)
@@ -122,6 +142,10 @@ private extension SettingsMutation {
             var update = ServerSettingsUpdate()
             update.storage = .init(maxDatabaseMb: megabytes)
             return update
+        case .sessionIdleTimeoutSecs(let seconds):
+            var update = ServerSettingsUpdate()
+            update.session = .init(idleTimeoutSecs: seconds)
+            return update
         case .transcriptionEnabled(let enabled):
             return ServerSettingsUpdate(server: .init(transcription: .init(enabled: enabled)))
         }
@@ -36,35 +36,56 @@ struct ServerSettingsSnapshot: Equatable, Sendable {
     let defaultProvider: String
     let defaultModel: String
     let defaultWorkspace: String?
+    let agentMaxCostUsd: Double
+    let agentProcessFileRootJail: Bool
+    let retryCircuitBreakerFailureThreshold: UInt32
+    let retryCircuitBreakerCooldownMs: UInt64
     let compactionPreserveRecentCount: Int
     let compactionTriggerTokenThreshold: Double
+    let contextPressureWarningThresholds: [Double]
+    let contextSystemContextTokenCeiling: UInt64
     let observabilityLogLevel: String
     let observabilityVerboseRetentionDays: UInt64
     let storageRetentionEnabled: Bool
     let storageMaxDatabaseMb: UInt64
+    let sessionIdleTimeoutSecs: UInt64
     let transcriptionEnabled: Bool
 
     init(
         defaultProvider: String,
         defaultModel: String,
         defaultWorkspace: String?,
+        agentMaxCostUsd: Double,
+        agentProcessFileRootJail: Bool,
+        retryCircuitBreakerFailureThreshold: UInt32,
+        retryCircuitBreakerCooldownMs: UInt64,
         compactionPreserveRecentCount: Int,
         compactionTriggerTokenThreshold: Double,
+        contextPressureWarningThresholds: [Double],
+        contextSystemContextTokenCeiling: UInt64,
         observabilityLogLevel: String,
         observabilityVerboseRetentionDays: UInt64,
         storageRetentionEnabled: Bool,
         storageMaxDatabaseMb: UInt64,
+        sessionIdleTimeoutSecs: UInt64,
         transcriptionEnabled: Bool
     ) {
         self.defaultProvider = defaultProvider
         self.defaultModel = defaultModel
         self.defaultWorkspace = defaultWorkspace
+        self.agentMaxCostUsd = agentMaxCostUsd
+        self.agentProcessFileRootJail = agentProcessFileRootJail
+        self.retryCircuitBreakerFailureThreshold = retryCircuitBreakerFailureThreshold
+        self.retryCircuitBreakerCooldownMs = retryCircuitBreakerCooldownMs
         self.compactionPreserveRecentCount = compactionPreserveRecentCount
         self.compactionTriggerTokenThreshold = compactionTriggerTokenThreshold
+        self.contextPressureWarningThresholds = contextPressureWarningThresholds
+        self.contextSystemContextTokenCeiling = contextSystemContextTokenCeiling
         self.observabilityLogLevel = observabilityLogLevel
         self.observabilityVerboseRetentionDays = observabilityVerboseRetentionDays
         self.storageRetentionEnabled = storageRetentionEnabled
         self.storageMaxDatabaseMb = storageMaxDatabaseMb
+        self.sessionIdleTimeoutSecs = sessionIdleTimeoutSecs
         self.transcriptionEnabled = transcriptionEnabled
     }
 
@@ -73,12 +94,19 @@ struct ServerSettingsSnapshot: Equatable, Sendable {
             defaultProvider: settings.defaultProvider,
             defaultModel: settings.defaultModel,
             defaultWorkspace: settings.defaultWorkspace,
+            agentMaxCostUsd: settings.agentMaxCostUsd,
+            agentProcessFileRootJail: settings.agentProcessFileRootJail,
+            retryCircuitBreakerFailureThreshold: settings.retryCircuitBreakerFailureThreshold,
+            retryCircuitBreakerCooldownMs: settings.retryCircuitBreakerCooldownMs,
             compactionPreserveRecentCount: settings.compaction.preserveRecentCount,
             compactionTriggerTokenThreshold: settings.compaction.triggerTokenThreshold,
+            contextPressureWarningThresholds: settings.contextPressureWarningThresholds,
+            contextSystemContextTokenCeiling: settings.contextSystemContextTokenCeiling,
             observabilityLogLevel: settings.observabilityLogLevel,
             observabilityVerboseRetentionDays: settings.observabilityVerboseRetentionDays,
             storageRetentionEnabled: settings.storageRetentionEnabled,
             storageMaxDatabaseMb: settings.storageMaxDatabaseMb,
+            sessionIdleTimeoutSecs: settings.sessionIdleTimeoutSecs,
             transcriptionEnabled: settings.transcriptionEnabled
         )
     }
@@ -90,12 +118,19 @@ enum SettingsMutation {
     case defaultProvider(String)
     case defaultWorkspace(String)
     case defaultModel(String)
+    case agentMaxCostUsd(Double)
+    case agentProcessFileRootJail(Bool)
+    case retryCircuitBreakerFailureThreshold(UInt32)
+    case retryCircuitBreakerCooldownMs(UInt64)
     case compactionTriggerTokenThreshold(Double)
     case compactionPreserveRecentCount(Int)
+    case contextPressureWarningThresholds([Double])
+    case contextSystemContextTokenCeiling(UInt64)
     case observabilityLogLevel(String)
     case observabilityVerboseRetentionDays(UInt64)
     case storageRetentionEnabled(Bool)
     case storageMaxDatabaseMb(UInt64)
+    case sessionIdleTimeoutSecs(UInt64)
     case transcriptionEnabled(Bool)
 }
 
@@ -17,18 +17,21 @@ final class SettingsClient: EngineDomainClient {
         return result
     }
 
-    func update(_ settings: ServerSettingsUpdate, idempotencyKey: EngineIdempotencyKey) async throws {
+    /// Apply a sparse settings update and return the effective fields that changed.
+    @discardableResult
+    func update(_ settings: ServerSettingsUpdate, idempotencyKey: EngineIdempotencyKey) async throws -> [SettingsChange] {
         _ = try requireTransport().requireConnection()
 
         struct UpdateParams: Encodable {
             let settings: ServerSettingsUpdate
         }
 
-        let _: SuccessResult = try await invokeWrite(
+        let result: SettingsUpdateResult = try await invokeWrite(
             "settings::update",
             UpdateParams(settings: settings),
             idempotencyKey: idempotencyKey
         )
+        return result.changes
     }
 
     /// Reset all settings to server defaults and return the new values.
@@ -42,7 +45,16 @@ final class SettingsClient: EngineDomainClient {
     }
 }
 
-/// Simple success result for settings.update
-private struct SuccessResult: Decodable {
+/// One effective settings leaf changed by settings.update.
+struct SettingsChange: Decodable, Equatable {
+    /// Dotted camelCase path, e.g. `context.compactor.maxTokens`.
+    let path: String
+    let oldValue: AnyCodable
+    let newValue: AnyCodable
+}
+
+/// Result for settings.update
+struct SettingsUpdateResult: Decodable {
     let success: Bool
+    let changes: [SettingsChange]
 }
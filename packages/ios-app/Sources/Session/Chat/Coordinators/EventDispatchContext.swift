@@ -34,6 +34,7 @@ import Foundation
 
 @MainActor protocol ServerEventHandler: AnyObject {
     func handleServerRestarting(_ result: ServerRestartingPlugin.Result)
+    func handleStreamResyncRequired(_ result: StreamResyncRequiredPlugin.Result)
 }
 
 @MainActor protocol DisplayStreamEventHandler: AnyObject {
@@ -16,6 +16,10 @@ final class SettingsState {
     var quickSessionWorkspace: String = AppConstants.defaultWorkspace
     var preserveRecentCount: Int = 5
     var triggerTokenThreshold: Double = 0.70
+    var contextPressureWarningThresholds: [Double] = [0.7, 0.9]
+    var contextSystemContextTokenCeiling: UInt64 = 32_000
+    var agentMaxCostUsd: Double = 0
+    var agentProcessFileRootJail: Bool = false
 
     // MARK: - Observability And Storage
 
@@ -23,8 +27,14 @@ final class SettingsState {
     var observabilityVerboseRetentionDays: UInt64 = 7
     var storageRetentionEnabled: Bool = true
     var storageMaxDatabaseMb: UInt64 = 512
+    var sessionIdleTimeoutSecs: UInt64 = 3600
     var transcriptionEnabled: Bool = false
 
+    // MARK: - Provider Health
+
+    var retryCircuitBreakerFailureThreshold: UInt32 = 0
+    var retryCircuitBreakerCooldownMs: UInt64 = 30_000
+
     @ObservationIgnored
     private var lastLoadedSettings: ServerSettingsSnapshot?
 
@@ -136,12 +146,19 @@ final class SettingsState {
         defaultModel = settings.defaultModel
         preserveRecentCount = settings.compactionPreserveRecentCount
         triggerTokenThreshold = settings.compactionTriggerTokenThreshold
+        contextPressureWarningThresholds = settings.contextPressureWarningThresholds
+        contextSystemContextTokenCeiling = settings.contextSystemContextTokenCeiling
+        agentMaxCostUsd = settings.agentMaxCostUsd
+        agentProcessFileRootJail = settings.agentProcessFileRootJail
         quickSessionWorkspace = settings.defaultWorkspace ?? AppConstants.defaultWorkspace
         observabilityLogLevel = settings.observabilityLogLevel
         observabilityVerboseRetentionDays = settings.observabilityVerboseRetentionDays
         storageRetentionEnabled = settings.storageRetentionEnabled
         storageMaxDatabaseMb = settings.storageMaxDatabaseMb
+        sessionIdleTimeoutSecs = settings.sessionIdleTimeoutSecs
         transcriptionEnabled = settings.transcriptionEnabled
+        retryCircuitBreakerFailureThreshold = settings.retryCircuitBreakerFailureThreshold
+        retryCircuitBreakerCooldownMs = settings.retryCircuitBreakerCooldownMs
 
     }
 }
@@ -197,6 +197,20 @@ extension ChatViewModel {
         compactionInProgressMessageId = nil
     }
 
+    func handleStreamResyncRequired(_ result: StreamResyncRequiredPlugin.Result) {
+        logger.warning("Stream resync required: reason=\(result.reason), droppedEvents=\(result.droppedEvents)", category: .events)
+
+        // Events were dropped for this connection, so the live view may be
+        // missing deltas. Re-read the session from the server; a resync or
+        // reconstruction already in flight covers the gap.
+        guard streamResyncTask == nil, !isReconstructing else { return }
+        streamResyncTask = Task { [weak self] in
+            guard let self else { return }
+            await reconnectAndReconstruct()
+            streamResyncTask = nil
+        }
+    }
+
     private func capabilityStatus(
         forRunStatus status: String,
         current: CapabilityInvocationStatus
@@ -112,6 +112,10 @@ final class ChatViewModel {
     private var eventTask: Task<Void, Never>?
     @ObservationIgnored
     private var eventTaskGeneration: UInt64 = 0
+    /// Reconnect started by a stream resync request. Further requests while it
+    /// runs join it instead of starting another; cancelled in deinit.
+    @ObservationIgnored
+    var streamResyncTask: Task<Void, Never>?
     /// ID of the thinking message for the current turn (thinking appears before text response)
     var thinkingMessageId: UUID?
     /// True while reconstruction is in progress — buffers real-time events for replay after
@@ -340,6 +344,7 @@ final class ChatViewModel {
         // assumeIsolated lets the compiler see we can safely access isolated state.
         MainActor.assumeIsolated {
             eventTask?.cancel()
+            streamResyncTask?.cancel()
             for task in observationTasks { task.cancel() }
             for task in backgroundTasks { task.cancel() }
             transcriptionTask?.cancel()
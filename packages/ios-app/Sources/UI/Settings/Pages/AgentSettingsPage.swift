@@ -48,6 +48,8 @@ struct AgentSettingsPage: View {
     private var stackedContent: some View {
         summaryCard
         quickSessionCard
+        spendingCard
+        processesCard
     }
 
     private var landscapeContent: some View {
@@ -55,6 +57,10 @@ struct AgentSettingsPage: View {
             summaryCard
             quickSessionCard
                 .frame(maxWidth: .infinity, alignment: .top)
+            spendingCard
+                .frame(maxWidth: .infinity, alignment: .top)
+            processesCard
+                .frame(maxWidth: .infinity, alignment: .top)
         }
     }
 
@@ -111,6 +117,62 @@ struct AgentSettingsPage: View {
         }
     }
 
+    // MARK: - Spending
+
+    private var spendingCard: some View {
+        VStack(alignment: .leading, spacing: 0) {
+            SettingsSectionHeader(title: AgentSettingsSection.spending.rawValue)
+
+            SettingsCard {
+                SettingsRow(icon: "dollarsign.circle", label: "Session cost limit") {
+                    Stepper(value: Binding(
+                        get: { Int(settingsState.agentMaxCostUsd.rounded()) },
+                        set: { newValue in
+                            let clamped = Double(min(max(newValue, 0), 1000))
+                            settingsState.agentMaxCostUsd = clamped
+                            updateServerSetting(.agentMaxCostUsd(clamped))
+                        }
+                    ), in: 0...1000, step: 1) {
+                        Text(settingsState.agentMaxCostUsd > 0
+                            ? String(format: "$%.0f", settingsState.agentMaxCostUsd)
+                            : "Off")
+                            .font(TronTypography.codeSM)
+                            .foregroundStyle(.tronTextSecondary)
+                    }
+                }
+            }
+
+            SettingsCaption(text: "Once a session has spent this much, the server lets the running turn finish and refuses the next one.")
+        }
+    }
+
+    // MARK: - Processes
+
+    private var processesCard: some View {
+        VStack(alignment: .leading, spacing: 0) {
+            SettingsSectionHeader(title: AgentSettingsSection.processes.rawValue)
+
+            SettingsCard {
+                SettingsRow(icon: "lock.square", label: "Jail to file roots") {
+                    Toggle(
+                        "",
+                        isOn: Binding(
+                            get: { settingsState.agentProcessFileRootJail },
+                            set: { newValue in
+                                settingsState.agentProcessFileRootJail = newValue
+                                updateServerSetting(.agentProcessFileRootJail(newValue))
+                            }
+                        )
+                    )
+                    .labelsHidden()
+                    .tint(.tronEmerald)
+                }
+            }
+
+            SettingsCaption(text: "Shell commands must stay inside the file roots of their authority grant. The check is lexical, not a sandbox.")
+        }
+    }
+
     // MARK: - Shared Row
 
     private func navigationRow(icon: String, label: String, value: String, action: @escaping () -> Void) -> some View {
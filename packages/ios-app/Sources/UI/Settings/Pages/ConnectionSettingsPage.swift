@@ -401,9 +401,56 @@ struct ConnectionSettingsPage: View {
                             .foregroundStyle(.tronTextSecondary)
                     }
                 }
+                SettingsRowDivider()
+                SettingsRow(icon: "moon.zzz", label: "Idle timeout") {
+                    Stepper(value: Binding(
+                        get: { Int(settingsState.sessionIdleTimeoutSecs / 60) },
+                        set: { newValue in
+                            let clamped = UInt64(min(max(newValue, 5), 10080)) * 60
+                            settingsState.sessionIdleTimeoutSecs = clamped
+                            updateServerSetting(.sessionIdleTimeoutSecs(clamped))
+                        }
+                    ), in: 5...10080, step: 5) {
+                        Text("\(settingsState.sessionIdleTimeoutSecs / 60) min")
+                            .font(TronTypography.codeSM)
+                            .foregroundStyle(.tronTextSecondary)
+                    }
+                }
+                SettingsRowDivider()
+                SettingsRow(icon: "bolt.horizontal.circle", label: "Circuit breaker") {
+                    Stepper(value: Binding(
+                        get: { Int(settingsState.retryCircuitBreakerFailureThreshold) },
+                        set: { newValue in
+                            let clamped = UInt32(min(max(newValue, 0), 20))
+                            settingsState.retryCircuitBreakerFailureThreshold = clamped
+                            updateServerSetting(.retryCircuitBreakerFailureThreshold(clamped))
+                        }
+                    ), in: 0...20) {
+                        Text(settingsState.retryCircuitBreakerFailureThreshold == 0
+                            ? "Off"
+                            : "\(settingsState.retryCircuitBreakerFailureThreshold) failures")
+                            .font(TronTypography.codeSM)
+                            .foregroundStyle(.tronTextSecondary)
+                    }
+                }
+                SettingsRowDivider()
+                SettingsRow(icon: "timer", label: "Breaker cooldown") {
+                    Stepper(value: Binding(
+                        get: { Int(settingsState.retryCircuitBreakerCooldownMs / 1000) },
+                        set: { newValue in
+                            let clamped = UInt64(min(max(newValue, 5), 600)) * 1000
+                            settingsState.retryCircuitBreakerCooldownMs = clamped
+                            updateServerSetting(.retryCircuitBreakerCooldownMs(clamped))
+                        }
+                    ), in: 5...600, step: 5) {
+                        Text("\(settingsState.retryCircuitBreakerCooldownMs / 1000)s")
+                            .font(TronTypography.codeSM)
+                            .foregroundStyle(.tronTextSecondary)
+                    }
+                }
             }
 
-            SettingsCaption(text: "The server owns trace records, retained logs, compression, and storage cleanup. iOS only requests the policy.")
+            SettingsCaption(text: "The server owns trace records, retained logs, compression, storage cleanup, and idle-session sweeps. iOS only requests the policy.")
         }
     }
 
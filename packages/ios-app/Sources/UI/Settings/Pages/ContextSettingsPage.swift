@@ -8,6 +8,8 @@ struct ContextSettingsPage: View {
         SettingsPageContainer(title: "Context") {
             summaryCard
             compactionSection
+            systemContextSection
+            warningsSection
         }
     }
 
@@ -87,6 +89,50 @@ struct ContextSettingsPage: View {
         }
     }
 
+    // MARK: - System Context
+
+    private var systemContextSection: some View {
+        VStack(alignment: .leading, spacing: 0) {
+            SettingsSectionHeader(title: "System Context")
+
+            SettingsCard {
+                SettingsRow(icon: "text.alignleft", label: "Token Ceiling") {
+                    SettingsCycleToggle(
+                        options: SystemContextCeilingPreset.options(including: settingsState.contextSystemContextTokenCeiling),
+                        current: settingsState.contextSystemContextTokenCeiling
+                    ) { newValue in
+                        settingsState.contextSystemContextTokenCeiling = newValue
+                        updateServerSetting(.contextSystemContextTokenCeiling(newValue))
+                    }
+                }
+            }
+
+            SettingsCaption(text: "Instructions and agent state sent with each request stay under this budget. Lower-priority sections are dropped first.")
+        }
+    }
+
+    // MARK: - Warnings
+
+    private var warningsSection: some View {
+        VStack(alignment: .leading, spacing: 0) {
+            SettingsSectionHeader(title: "Warnings")
+
+            SettingsCard {
+                SettingsRow(icon: "exclamationmark.triangle", label: "Warn at") {
+                    SettingsCycleToggle(
+                        options: ContextPressureWarningPreset.allCases.map { ($0.thresholds, $0.label) },
+                        current: settingsState.contextPressureWarningThresholds
+                    ) { newValue in
+                        settingsState.contextPressureWarningThresholds = newValue
+                        updateServerSetting(.contextPressureWarningThresholds(newValue))
+                    }
+                }
+            }
+
+            SettingsCaption(text: "A warning appears once each time context usage first reaches one of these levels.")
+        }
+    }
+
     @ViewBuilder
     private func compactionSettingBlock<Content: View>(
         _ setting: ContextCompactionSetting,
@@ -200,6 +200,8 @@ enum MainSettingsFooterLayout {
 
 enum AgentSettingsSection: String, CaseIterable, Sendable {
     case quickSession = "Quick Session"
+    case spending = "Spending"
+    case processes = "Processes"
 }
 
 enum ContextCompactionSetting: CaseIterable, Hashable, Sendable {
@@ -225,6 +227,51 @@ enum ContextCompactionSetting: CaseIterable, Hashable, Sendable {
     }
 }
 
+/// Preset context-usage warning thresholds offered by the Context page.
+enum ContextPressureWarningPreset: CaseIterable, Hashable, Sendable {
+    case standard
+    case late
+    case finalOnly
+    case off
+
+    var thresholds: [Double] {
+        switch self {
+        case .standard:
+            return [0.7, 0.9]
+        case .late:
+            return [0.8, 0.95]
+        case .finalOnly:
+            return [0.9]
+        case .off:
+            return []
+        }
+    }
+
+    var label: String {
+        switch self {
+        case .off:
+            return "Off"
+        default:
+            return thresholds.map { "\(Int(($0 * 100).rounded()))%" }.joined(separator: " · ")
+        }
+    }
+}
+
+/// Preset system context token ceilings offered by the Context page.
+enum SystemContextCeilingPreset {
+    static let tokenCounts: [UInt64] = [8_000, 16_000, 32_000, 64_000, 128_000]
+
+    /// Cycle options, keeping a custom server value selectable in order.
+    static func options(including current: UInt64) -> [(value: UInt64, label: String)] {
+        let counts = tokenCounts.contains(current) ? tokenCounts : (tokenCounts + [current]).sorted()
+        return counts.map { ($0, label(for: $0)) }
+    }
+
+    static func label(for tokens: UInt64) -> String {
+        tokens % 1_000 == 0 ? "\(tokens / 1_000)K" : "\(tokens)"
+    }
+}
+
 enum SettingsDangerZoneAction: CaseIterable, Hashable, Sendable {
     case archiveAllSessions
     case resetAllSettings
@@ -0,0 +1,93 @@
+import XCTest
+@testable import TronMobile
+
+final class StreamResyncRequiredPluginTests: XCTestCase {
+
+    override func setUp() {
+        super.setUp()
+        EventRegistry.shared.clearForTesting()
+    }
+
+    // MARK: - Event Type
+
+    func testEventType() {
+        XCTAssertEqual(StreamResyncRequiredPlugin.eventType, "stream.resync_required")
+    }
+
+    // MARK: - Parsing
+
+    func testParseFullEvent() {
+        EventRegistry.shared.register(StreamResyncRequiredPlugin.self)
+
+        let json = """
+        {
+            "type": "stream.resync_required",
+            "timestamp": "2026-10-01T12:00:00Z",
+            "data": {
+                "droppedEvents": 42,
+                "reason": "stream_projection_lagged"
+            }
+        }
+        """.data(using: .utf8)!
+
+        let result = EventRegistry.shared.parse(type: "stream.resync_required", data: json)
+        XCTAssertNotNil(result)
+
+        if case .plugin(let type, _, let sessionId, _, let transform) = result {
+            XCTAssertEqual(type, "stream.resync_required")
+            XCTAssertNil(sessionId, "stream.resync_required is a global event with no sessionId")
+
+            let eventResult = transform() as? StreamResyncRequiredPlugin.Result
+            XCTAssertEqual(eventResult?.droppedEvents, 42)
+            XCTAssertEqual(eventResult?.reason, "stream_projection_lagged")
+        } else {
+            XCTFail("Expected .plugin case")
+        }
+    }
+
+    func testParseMinimalEvent_defaultValues() {
+        EventRegistry.shared.register(StreamResyncRequiredPlugin.self)
+
+        let json = """
+        {
+            "type": "stream.resync_required"
+        }
+        """.data(using: .utf8)!
+
+        let result = EventRegistry.shared.parse(type: "stream.resync_required", data: json)
+
+        if case .plugin(_, _, _, _, let transform) = result {
+            let eventResult = transform() as? StreamResyncRequiredPlugin.Result
+            XCTAssertEqual(eventResult?.droppedEvents, 0)
+            XCTAssertEqual(eventResult?.reason, "unknown")
+        } else {
+            XCTFail("Expected .plugin case")
+        }
+    }
+
+    // MARK: - Dispatch
+
+    @MainActor
+    func testDispatchCallsHandler() {
+        let mock = MockEventDispatchContext()
+        let result = StreamResyncRequiredPlugin.Result(
+            droppedEvents: 7,
+            reason: "stream_projection_lagged"
+        )
+
+        StreamResyncRequiredPlugin.dispatch(result: result, context: mock)
+
+        XCTAssertEqual(mock.handleStreamResyncRequiredCalledWith?.droppedEvents, 7)
+        XCTAssertEqual(mock.handleStreamResyncRequiredCalledWith?.reason, "stream_projection_lagged")
+    }
+
+    @MainActor
+    func testDispatchIgnoresWrongResultType() {
+        let mock = MockEventDispatchContext()
+        let wrongResult = ServerRestartingPlugin.Result(reason: "deploy", commit: "abc", restartExpectedMs: 5000)
+
+        StreamResyncRequiredPlugin.dispatch(result: wrongResult, context: mock)
+
+        XCTAssertNil(mock.handleStreamResyncRequiredCalledWith)
+    }
+}
@@ -16,8 +16,17 @@ struct ServerSettingsTests {
                 "tailscaleIp": "100.64.0.7",
                 "transcription": { "enabled": true }
             },
+            "agent": {
+                "maxCostUsd": 25,
+                "processFileRootJail": true
+            },
+            "retry": {
+                "circuitBreaker": { "failureThreshold": 5, "cooldownMs": 60000 }
+            },
             "context": {
-                "compactor": { "preserveRecentCount": 3, "triggerTokenThreshold": 0.80 }
+                "compactor": { "preserveRecentCount": 3, "triggerTokenThreshold": 0.80 },
+                "pressureWarningThresholds": [0.8],
+                "systemContextTokenCeiling": 16000
             },
             "observability": {
                 "logLevel": "debug",
@@ -26,6 +35,9 @@ struct ServerSettingsTests {
             "storage": {
                 "retentionEnabled": false,
                 "maxDatabaseMb": 256
+            },
+            "session": {
+                "idleTimeoutSecs": 900
             }
         }
         """
@@ -36,12 +48,19 @@ struct ServerSettingsTests {
         #expect(settings.defaultWorkspace == "/projects")
         #expect(settings.tailscaleIp == "100.64.0.7")
         #expect(settings.transcriptionEnabled == true)
+        #expect(settings.agentMaxCostUsd == 25)
+        #expect(settings.agentProcessFileRootJail == true)
+        #expect(settings.retryCircuitBreakerFailureThreshold == 5)
+        #expect(settings.retryCircuitBreakerCooldownMs == 60000)
         #expect(settings.compaction.preserveRecentCount == 3)
         #expect(settings.compaction.triggerTokenThreshold == 0.80)
+        #expect(settings.contextPressureWarningThresholds == [0.8])
+        #expect(settings.contextSystemContextTokenCeiling == 16000)
         #expect(settings.observabilityLogLevel == "debug")
         #expect(settings.observabilityVerboseRetentionDays == 3)
         #expect(settings.storageRetentionEnabled == false)
         #expect(settings.storageMaxDatabaseMb == 256)
+        #expect(settings.sessionIdleTimeoutSecs == 900)
     }
 
     @Test("decode fixture server payload uses primitive defaults")
@@ -52,12 +71,19 @@ struct ServerSettingsTests {
         #expect(settings.defaultWorkspace == nil)
         #expect(settings.tailscaleIp == nil)
         #expect(settings.transcriptionEnabled == false)
+        #expect(settings.agentMaxCostUsd == 0)
+        #expect(settings.agentProcessFileRootJail == false)
+        #expect(settings.retryCircuitBreakerFailureThreshold == 0)
+        #expect(settings.retryCircuitBreakerCooldownMs == 30000)
         #expect(settings.compaction.preserveRecentCount == 5)
         #expect(settings.compaction.triggerTokenThreshold == 0.70)
+        #expect(settings.contextPressureWarningThresholds == [0.7, 0.9])
+        #expect(settings.contextSystemContextTokenCeiling == 32000)
         #expect(settings.observabilityLogLevel == "info")
         #expect(settings.observabilityVerboseRetentionDays == 7)
         #expect(settings.storageRetentionEnabled == true)
         #expect(settings.storageMaxDatabaseMb == 512)
+        #expect(settings.sessionIdleTimeoutSecs == 3600)
     }
 
     @Test("server key present with only default model")
@@ -95,8 +121,17 @@ struct ServerSettingsTests {
         let json = """
         {
             "server": { "defaultModel": 42 },
+            "agent": {
+                "maxCostUsd": 25,
+                "processFileRootJail": true
+            },
+            "retry": {
+                "circuitBreaker": { "failureThreshold": 5, "cooldownMs": 60000 }
+            },
             "context": {
-                "compactor": { "preserveRecentCount": 3, "triggerTokenThreshold": 0.80 }
+                "compactor": { "preserveRecentCount": 3, "triggerTokenThreshold": 0.80 },
+                "pressureWarningThresholds": [0.8],
+                "systemContextTokenCeiling": 16000
             },
             "observability": {
                 "logLevel": "debug",
@@ -105,6 +140,9 @@ struct ServerSettingsTests {
             "storage": {
                 "retentionEnabled": false,
                 "maxDatabaseMb": 256
+            },
+            "session": {
+                "idleTimeoutSecs": 900
             }
         }
         """
@@ -146,5 +184,85 @@ struct ServerSettingsTests {
         let storage = json["storage"] as? [String: Any]
         #expect(storage?["retentionEnabled"] as? Bool == false)
     }
+    @Test("ServerSettingsUpdate encodes session idle timeout sparsely")
+    func settingsUpdateEncodesSessionIdleTimeout() throws {
+        var update = ServerSettingsUpdate()
+        update.session = .init(idleTimeoutSecs: 900)
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["session"])
+        let session = json["session"] as? [String: Any]
+        #expect(session?["idleTimeoutSecs"] as? Int == 900)
+    }
+
+    @Test("ServerSettingsUpdate encodes agent cost ceiling sparsely")
+    func settingsUpdateEncodesAgentMaxCost() throws {
+        var update = ServerSettingsUpdate()
+        update.agent = .init(maxCostUsd: 25)
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["agent"])
+        let agent = json["agent"] as? [String: Any]
+        #expect(agent?["maxCostUsd"] as? Double == 25)
+    }
+
+    @Test("ServerSettingsUpdate encodes process jail sparsely")
+    func settingsUpdateEncodesProcessFileRootJail() throws {
+        var update = ServerSettingsUpdate()
+        update.agent = .init(processFileRootJail: true)
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["agent"])
+        let agent = json["agent"] as? [String: Any]
+        #expect(agent?.keys.sorted() == ["processFileRootJail"])
+        #expect(agent?["processFileRootJail"] as? Bool == true)
+    }
+
+    @Test("ServerSettingsUpdate encodes context warning thresholds sparsely")
+    func settingsUpdateEncodesPressureWarningThresholds() throws {
+        let update = ServerSettingsUpdate(context: .init(pressureWarningThresholds: [0.8, 0.95]))
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["context"])
+        let context = json["context"] as? [String: Any]
+        #expect(context?.keys.sorted() == ["pressureWarningThresholds"])
+        #expect(context?["pressureWarningThresholds"] as? [Double] == [0.8, 0.95])
+    }
+
+    @Test("ServerSettingsUpdate encodes the system context ceiling sparsely")
+    func settingsUpdateEncodesSystemContextTokenCeiling() throws {
+        let update = ServerSettingsUpdate(context: .init(systemContextTokenCeiling: 64_000))
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["context"])
+        let context = json["context"] as? [String: Any]
+        #expect(context?.keys.sorted() == ["systemContextTokenCeiling"])
+        #expect(context?["systemContextTokenCeiling"] as? UInt64 == 64_000)
+    }
+
+    @Test("ServerSettingsUpdate encodes circuit breaker sparsely")
+    func settingsUpdateEncodesCircuitBreaker() throws {
+        var update = ServerSettingsUpdate()
+        update.retry = .init(circuitBreaker: .init(failureThreshold: 5))
+
+        let data = try JSONEncoder().encode(update)
+        let json = try JSONSerialization.jsonObject(with: data) as! [String: Any]
+
+        #expect(json.keys.sorted() == ["retry"])
+        let retry = json["retry"] as? [String: Any]
+        let circuitBreaker = retry?["circuitBreaker"] as? [String: Any]
+        #expect(circuitBreaker?.keys.sorted() == ["failureThreshold"])
+        #expect(circuitBreaker?["failureThreshold"] as? Int == 5)
+    }
 
 }
@@ -590,6 +590,8 @@ final class EngineProtocolBaseTypesTests: XCTestCase {
             .authOauthError,
             .authStorageError,
             .authTransportError,
+            .modelUnknown,
+            .modelNotAvailable,
         ]
 
         let rawValues = Set(EngineErrorCode.allCases.map(\.rawValue))
@@ -30,4 +30,21 @@ struct SettingsClientTests {
         }
     }
 
+    @Test("update result decodes changed paths")
+    func updateResultDecodesChanges() throws {
+        let json = """
+        {"success":true,"changes":[{"path":"context.compactor.maxTokens","oldValue":25000,"newValue":50000}]}
+        """
+        let result = try JSONDecoder().decode(SettingsUpdateResult.self, from: Data(json.utf8))
+
+        #expect(result.success)
+        #expect(result.changes == [
+            SettingsChange(
+                path: "context.compactor.maxTokens",
+                oldValue: AnyCodable(25000),
+                newValue: AnyCodable(50000)
+            )
+        ])
+    }
+
 }
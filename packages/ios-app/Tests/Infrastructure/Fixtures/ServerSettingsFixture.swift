@@ -25,11 +25,23 @@ enum ServerSettingsFixture {
                     "enabled": false
                 }
             },
+            "agent": {
+                "maxCostUsd": 0,
+                "processFileRootJail": false
+            },
+            "retry": {
+                "circuitBreaker": {
+                    "failureThreshold": 0,
+                    "cooldownMs": 30000
+                }
+            },
             "context": {
                 "compactor": {
                     "preserveRecentCount": 5,
                     "triggerTokenThreshold": 0.70
-                }
+                },
+                "pressureWarningThresholds": [0.7, 0.9],
+                "systemContextTokenCeiling": 32000
             },
             "observability": {
                 "logLevel": "info",
@@ -38,6 +50,9 @@ enum ServerSettingsFixture {
             "storage": {
                 "retentionEnabled": true,
                 "maxDatabaseMb": 512
+            },
+            "session": {
+                "idleTimeoutSecs": 3600
             }
         }
         """
@@ -482,6 +482,11 @@ final class MockEventDispatchContext: EventDispatchTarget {
         handleServerRestartingCalledWith = result
     }
 
+    var handleStreamResyncRequiredCalledWith: StreamResyncRequiredPlugin.Result?
+    func handleStreamResyncRequired(_ result: StreamResyncRequiredPlugin.Result) {
+        handleStreamResyncRequiredCalledWith = result
+    }
+
     // Display streaming
     func handleDisplayFrame(_ result: DisplayFramePlugin.Result) {}
 
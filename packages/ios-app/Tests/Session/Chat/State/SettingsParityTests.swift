@@ -28,15 +28,23 @@ final class SettingsParityTests: XCTestCase {
         "defaultProvider",
         "defaultModel",
         "quickSessionWorkspace",
+        "agentMaxCostUsd",
+        "agentProcessFileRootJail",
         // Context compaction
         "preserveRecentCount",
+        "contextPressureWarningThresholds",
+        "contextSystemContextTokenCeiling",
         "triggerTokenThreshold",
         // Engine diagnostics
         "observabilityLogLevel",
         "observabilityVerboseRetentionDays",
         "storageRetentionEnabled",
         "storageMaxDatabaseMb",
+        "sessionIdleTimeoutSecs",
         "transcriptionEnabled",
+        // Provider health
+        "retryCircuitBreakerFailureThreshold",
+        "retryCircuitBreakerCooldownMs",
     ]
 
     /// Explicit waivers — fields that exist on SettingsState but are
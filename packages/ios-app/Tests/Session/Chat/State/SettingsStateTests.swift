@@ -13,6 +13,12 @@ final class SettingsStateTests: XCTestCase {
         XCTAssertEqual(state.quickSessionWorkspace, AppConstants.defaultWorkspace)
         XCTAssertEqual(state.preserveRecentCount, 5)
         XCTAssertEqual(state.triggerTokenThreshold, 0.70, accuracy: 0.001)
+        XCTAssertEqual(state.contextPressureWarningThresholds, [0.7, 0.9])
+        XCTAssertEqual(state.contextSystemContextTokenCeiling, 32_000)
+        XCTAssertEqual(state.agentMaxCostUsd, 0)
+        XCTAssertFalse(state.agentProcessFileRootJail)
+        XCTAssertEqual(state.retryCircuitBreakerFailureThreshold, 0)
+        XCTAssertEqual(state.retryCircuitBreakerCooldownMs, 30_000)
         XCTAssertFalse(state.isLoaded)
         XCTAssertTrue(state.availableModels.isEmpty)
         XCTAssertFalse(state.isLoadingModels)
@@ -21,6 +27,7 @@ final class SettingsStateTests: XCTestCase {
         XCTAssertEqual(state.observabilityVerboseRetentionDays, 7)
         XCTAssertTrue(state.storageRetentionEnabled)
         XCTAssertEqual(state.storageMaxDatabaseMb, 512)
+        XCTAssertEqual(state.sessionIdleTimeoutSecs, 3600)
         XCTAssertFalse(state.transcriptionEnabled)
     }
 
@@ -53,6 +60,20 @@ final class SettingsStateTests: XCTestCase {
             "retentionEnabled": false,
             "maxDatabaseMb": 256
           },
+          "session": {
+            "idleTimeoutSecs": 1800
+          },
+          "agent": {
+            "maxCostUsd": 12.5,
+            "processFileRootJail": true
+          },
+          "retry": {
+            "circuitBreaker": { "failureThreshold": 4, "cooldownMs": 45000 }
+          },
+          "context": {
+            "pressureWarningThresholds": [0.9],
+            "systemContextTokenCeiling": 64000
+          },
           "server": {
             "transcription": { "enabled": true }
           }
@@ -66,6 +87,13 @@ final class SettingsStateTests: XCTestCase {
         XCTAssertEqual(state.observabilityVerboseRetentionDays, 3)
         XCTAssertFalse(state.storageRetentionEnabled)
         XCTAssertEqual(state.storageMaxDatabaseMb, 256)
+        XCTAssertEqual(state.sessionIdleTimeoutSecs, 1800)
+        XCTAssertEqual(state.agentMaxCostUsd, 12.5)
+        XCTAssertTrue(state.agentProcessFileRootJail)
+        XCTAssertEqual(state.retryCircuitBreakerFailureThreshold, 4)
+        XCTAssertEqual(state.retryCircuitBreakerCooldownMs, 45_000)
+        XCTAssertEqual(state.contextPressureWarningThresholds, [0.9])
+        XCTAssertEqual(state.contextSystemContextTokenCeiling, 64_000)
         XCTAssertTrue(state.transcriptionEnabled)
     }
 
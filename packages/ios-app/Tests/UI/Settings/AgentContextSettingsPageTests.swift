@@ -57,10 +57,12 @@ struct AgentContextSettingsPageTests {
         })
     }
 
-    @Test("agent sheet keeps only quick session settings")
+    @Test("agent sheet keeps only quick session, spending, and process settings")
     func agentSheetKeepsOnlyPrimitiveSections() {
         #expect(AgentSettingsSection.allCases == [
             .quickSession,
+            .spending,
+            .processes,
         ])
     }
 